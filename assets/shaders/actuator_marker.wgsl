@@ -0,0 +1,35 @@
+#import bevy_pbr::view_transformations::position_world_to_clip
+
+struct VertexInput {
+    // 单位半径小球网格的顶点
+    @location(0) position: vec3<f32>,
+    @location(1) normal: vec3<f32>,
+    @location(2) uv: vec2<f32>,
+    // 实例数据：世界坐标的球心（xyz）与半径（w）、颜色
+    @location(3) i_pos_scale: vec4<f32>,
+    @location(4) i_color: vec4<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+};
+
+@vertex
+fn vertex(vertex: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    // 实例数据已在世界坐标中，不再经过网格变换
+    let position = vertex.position * vertex.i_pos_scale.w + vertex.i_pos_scale.xyz;
+    out.clip_position = position_world_to_clip(position);
+
+    // 按球面朝向做简单明暗，便于分辨小球
+    let light_dir = normalize(vec3<f32>(0.3, 0.5, 1.0));
+    let diffuse = max(dot(normalize(vertex.normal), light_dir), 0.0);
+    out.color = vec4<f32>(vertex.i_color.rgb * (0.5 + 0.5 * diffuse), 1.0);
+    return out;
+}
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    return in.color;
+}
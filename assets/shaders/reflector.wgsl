@@ -1,13 +1,30 @@
-#import bevy_pbr::mesh_functions::{get_world_from_local, mesh_position_local_to_clip}
+#import bevy_pbr::mesh_functions::{get_world_from_local, mesh_position_local_to_world, position_world_to_clip}
 
 #import bevy_pbr::{
     mesh_view_bindings::globals,
     // forward_io::VertexOutput,
 }
 
-@group(2) @binding(0) var<storage, read> buffer: array<f32, 9216>;
+// 长度由几何顶点数决定，运行时确定
+@group(2) @binding(0) var<storage, read> buffer: array<f32>;
 @group(2) @binding(1) var<uniform> enable_boundary: u32;
-@group(2) @binding(2) var<uniform> interpolate_algo: u32;
+@group(2) @binding(3) var<uniform> enable_displacement: u32;
+@group(2) @binding(4) var<uniform> displacement_scale: f32;
+// 高亮的面板编号，0xffffffff 表示无
+@group(2) @binding(5) var<uniform> selected_panel: u32;
+// 映射为颜色 0 与 1 的高度（毫米）
+@group(2) @binding(6) var<uniform> height_min: f32;
+@group(2) @binding(7) var<uniform> height_max: f32;
+// 当前调色板的查找纹理，见 colormap/palette.rs
+@group(2) @binding(8) var colormap_texture: texture_1d<f32>;
+@group(2) @binding(9) var colormap_sampler: sampler;
+// 等高线间距（毫米），0 表示不绘制；每 contour_major_every 条为主等高线
+@group(2) @binding(10) var<uniform> contour_interval: f32;
+@group(2) @binding(11) var<uniform> contour_major_every: u32;
+
+// 超出映射范围的截断颜色，与 colormap/mod.rs 一致
+const UNDER_RANGE_COLOR = vec3<f32>(0.35, 0.35, 0.35);
+const OVER_RANGE_COLOR = vec3<f32>(1.0, 0.0, 1.0);
 
 struct VertexInput {
     @builtin(vertex_index) vertex_index: u32,
@@ -17,248 +34,99 @@ struct VertexInput {
     @location(0) position: vec3<f32>,
     @location(1) uv: vec2<f32>,
     @location(2) position_index: u32,
+    // 促动器处的抛物面法线
+    @location(3) normal: vec3<f32>,
+    // 顶点所属面板编号
+    @location(4) panel_index: u32,
 };
 
 struct VertexOutput {
     @builtin(position) clip_position: vec4<f32>,
-    @location(0) color: vec4<f32>,
-    @location(1) uv: vec2<f32>,
-    @location(2) i_height: f32,
+    @location(0) uv: vec2<f32>,
+    @location(1) i_height: f32,
+    @location(2) world_position: vec3<f32>,
+    @location(3) @interpolate(flat) panel_index: u32,
 };
 
-//包含 10 种 rgb color 的 scale 
-// #661325 rgb: 102, 19, 37   divide 255: 0.4, 0.07, 0.15
-// #7E1944 rgb: 126, 25, 68   divide 255: 0.5, 0.1, 0.27
-// #95206B rgb: 149, 32, 107  divide 255: 0.58, 0.12, 0.42
-// #AC2898 rgb: 172, 40, 152  divide 255: 0.67, 0.16, 0.6
-// #B930C2 rgb: 185, 48, 194  divide 255: 0.73, 0.19, 0.76
-// #AC4ACC rgb: 172, 74, 172  divide 255: 0.67, 0.29, 0.67
-// #A464D6 rgb: 164, 100, 214 divide 255: 0.64, 0.39, 0.84
-// #A37EDF rgb: 163, 126, 223 divide 255: 0.64, 0.49, 0.87
-// #A99AE7 rgb: 169, 154, 231 divide 255: 0.66, 0.6, 0.91
-// #B6B6EF rgb: 182, 182, 239 divide 255: 0.71, 0.71, 0.94
-// #D3D9F6 rgb: 211, 217, 246 divide 255: 0.83, 0.85, 0.96
-var<private> scale_0 = array<vec4<f32>, 11>(
-        vec4<f32>(0.4, 0.07, 0.15, 1.0),
-        vec4<f32>(0.5, 0.1, 0.27, 1.0),
-        vec4<f32>(0.58, 0.12, 0.42, 1.0),
-        vec4<f32>(0.67, 0.16, 0.6, 1.0),
-        vec4<f32>(0.73, 0.19, 0.76, 1.0),
-        vec4<f32>(0.67, 0.29, 0.67, 1.0),
-        vec4<f32>(0.64, 0.39, 0.84, 1.0),
-        vec4<f32>(0.64, 0.49, 0.87, 1.0),
-        vec4<f32>(0.66, 0.6, 0.91, 1.0),
-        vec4<f32>(0.71, 0.71, 0.94, 1.0),
-        vec4<f32>(0.83, 0.85, 0.96, 1.0)
-    );
-
-// #7B802F rgb: 123, 128, 47   divide 255: 0.48, 0.5, 0.18
-// #9F9A3D rgb: 159, 154, 61   divide 255: 0.62, 0.6, 0.24
-// #BF9E4B rgb: 191, 158, 75   divide 255: 0.75, 0.62, 0.29
-// #DF9B5A rgb: 223, 155, 90   divide 255: 0.87, 0.61, 0.35
-// #FF9169 rgb: 255, 145, 105  divide 255: 1.0, 0.57, 0.41
-// #FF867B rgb: 255, 134, 123  divide 255: 1.0, 0.53, 0.48
-// #FF8D9A rgb: 255, 141, 154  divide 255: 1.0, 0.55, 0.6
-// #FFA1BD rgb: 255, 161, 189  divide 255: 1.0, 0.63, 0.74
-// #FFB4DA rgb: 255, 180, 218  divide 255: 1.0, 0.71, 0.85
-// #FFC9F0 rgb: 255, 201, 240  divide 255: 1.0, 0.79, 0.94
-// #FFDEFD rgb: 255, 222, 253  divide 255: 1.0, 0.87, 0.99
-var<private> scale_1 = array<vec4<f32>, 11>(
-        vec4<f32>(0.48, 0.5, 0.18, 1.0),
-        vec4<f32>(0.62, 0.6, 0.24, 1.0),
-        vec4<f32>(0.75, 0.62, 0.29, 1.0),
-        vec4<f32>(0.87, 0.61, 0.35, 1.0),
-        vec4<f32>(1.0, 0.57, 0.41, 1.0),
-        vec4<f32>(1.0, 0.53, 0.48, 1.0),
-        vec4<f32>(1.0, 0.55, 0.6, 1.0),
-        vec4<f32>(1.0, 0.63, 0.74, 1.0),
-        vec4<f32>(1.0, 0.71, 0.85, 1.0),
-        vec4<f32>(1.0, 0.79, 0.94, 1.0),
-        vec4<f32>(1.0, 0.87, 0.99, 1.0),
-    );
-
-var<private> scale_2 = array<vec4<f32>, 10>(
-        vec4f(1f, 1f, 1f, 1f),
-        vec4f(1f, 0f, 0f, 1f),
-        vec4f(1f, 0.69f, 0f, 1f),
-        vec4f(1f, 1f, 0f, 1f),
-        vec4f(0f, 0.57f, 0f, 1f),
-        vec4f(0f, 0.96f, 0f, 1f),
-        vec4f(0.37f, 0.65f, 0.93f, 1f),
-        vec4f(0f, 0f, 0.78f, 1f),
-        vec4f(0.47f, 0f, 0.61f, 1f),
-        vec4f(0.2f, 0.2f, 0.2f, 1f),
-    );
-
-fn interpolate_scale_2(i_height: f32) -> vec4<f32> {
-    var h = i_height * 10.0;
-    var low_index: u32 = u32(floor(h));
-    return scale_2[low_index];
-}
-
-fn interpolate_color_0(i_height: f32) -> vec4<f32> {
-    var h = i_height * 10.0;
-    var low_index: u32 = u32(floor(h));
-    var high_index: u32 = u32(ceil(h));
-    low_index = max(u32(0), low_index);
-    high_index = min(u32(10), high_index);
-    return mix(scale_0[low_index], scale_0[high_index], i_height);
-}
-
-fn interpolate_color_1(i_height: f32) -> vec4<f32> {
-    var h = i_height * 10.0;
-    var low_index: u32 = u32(floor(h));
-    var high_index: u32 = u32(ceil(h));
-    low_index = max(u32(0), low_index);
-    high_index = min(u32(10), high_index);
-    return mix(scale_1[low_index], scale_1[high_index], i_height);
-}
-
-// 红 到 紫色 七种颜色
-var<private> colors = array<vec3<f32>, 3>(
-    // 红
-    vec3<f32>(1.0, 0.0, 0.0),
-    // // 橙
-    // vec3<f32>(1.0, 0.5, 0.0),
-    // // 黄
-    vec3<f32>(1.0, 1.0, 0.0),
-    // 绿
-    vec3<f32>(0.0, 1.0, 0.0),
-    // 青
-    // vec3<f32>(0.0, 1.0, 1.0),
-    // 蓝
-    // vec3<f32>(0.0, 0.0, 1.0),
-    // 紫
-    // vec3<f32>(1.0, 0.0, 1.0),
-);
-
-fn interpolate_color(factor: f32) -> vec4<f32> {
-    let segment_count = f32(3 - 1);
-    let segment = factor * segment_count;
-    let low_index = u32(floor(segment));
-    let high_index = u32(ceil(segment));
-    let t = fract(segment);
-    return vec4<f32>(mix(colors[low_index], colors[high_index], t), 1.0);
-}
-
-fn interpolate_color_oklab(factor: f32) -> vec4<f32> {
-
-    // blending is done in a perceptual color space: https://bottosson.github.io/posts/oklab/
-    let red = vec3<f32>(0.627955, 0.224863, 0.125846);
-    let green = vec3<f32>(0.86644, -0.233887, 0.179498);
-    let blue = vec3<f32>(0.701674, 0.274566, -0.169156);
-    let white = vec3<f32>(1.0, 0.0, 0.0);
-    var mixed = vec3<f32>(0.0, 0.0, 0.0);
-    // mix the colors with factor
-    if factor < 0.5 {
-        mixed = mix(red, green, factor * 2.0); // 插值红色和黄色
-    } else {
-        mixed= mix(green, blue, (factor - 0.5) * 2.0); // 插值黄色和绿色
-    }
-
-    return vec4<f32>(oklab_to_linear_srgb(mixed), 1.0);
-}
-
-fn oklab_to_linear_srgb(c: vec3<f32>) -> vec3<f32> {
-    let L = c.x;
-    let a = c.y;
-    let b = c.z;
-
-    let l_ = L + 0.3963377774 * a + 0.2158037573 * b;
-    let m_ = L - 0.1055613458 * a - 0.0638541728 * b;
-    let s_ = L - 0.0894841775 * a - 1.2914855480 * b;
-
-    let l = l_ * l_ * l_;
-    let m = m_ * m_ * m_;
-    let s = s_ * s_ * s_;
-
-    return vec3<f32>(
-        4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
-        -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
-        -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
-    );
-}
-
-
-fn fromRedToGreen(interpolant: f32) -> vec3<f32> {
-    if (interpolant < 0.5) {
-        return vec3<f32>(1.0, 2.0 * interpolant, 0.0);
-    } else {
-        return vec3<f32>(2.0 - 2.0 * interpolant, 1.0, 0.0);
-    }
-}
-
-
-fn fromGreenToBlue(interpolant: f32) -> vec3<f32> {
-    if interpolant < 0.5 {
-        return vec3<f32>(0.0, 1.0, 2.0 * interpolant);
-    } else {
-        return vec3<f32>(0.0, 2.0 - 2.0 * interpolant, 1.0);
-    }
-}
-fn heat5(interpolant: f32) -> vec3<f32> {
-    let inverted_interpolant = interpolant;
-    if inverted_interpolant < 0.5 {
-        let remapped_first_half = 1.0 - 2.0 * inverted_interpolant;
-        return fromGreenToBlue(remapped_first_half);
-    } else {
-        let remapped_second_half = 2.0 - 2.0 * inverted_interpolant;
-        return fromRedToGreen(remapped_second_half);
-    }
-}
-
-fn heat7(interpolant: f32) -> vec3<f32> {
-    if interpolant < 1.0 / 6.0 {
-        let first_segment_interpolant = 6.0 * interpolant;
-        return (1.0 - first_segment_interpolant) * vec3<f32>(0.0, 0.0, 0.0) + first_segment_interpolant * vec3<f32>(0.0, 0.0, 1.0);
-    } else if interpolant < 5.0 / 6.0 {
-        let mid_interpolant = 0.25 * (6.0 * interpolant - 1.0);
-        return heat5(mid_interpolant);
-    } else {
-        let last_segment_interpolant = 6.0 * interpolant - 5.0;
-        return (1.0 - last_segment_interpolant) * vec3<f32>(1.0, 0.0, 0.0) + last_segment_interpolant * vec3<f32>(1.0, 1.0, 1.0);
-    }
-}
-
 @vertex
 fn vertex(vertex: VertexInput) -> VertexOutput {
     var out: VertexOutput;
-    out.clip_position = mesh_position_local_to_clip(
-        get_world_from_local(vertex.instance_index), 
-        vec4<f32>(vertex.position, 1.0)
+    var height = 0.0;
+    if (vertex.position_index < arrayLength(&buffer)) {
+        height = buffer[vertex.position_index];
+    }
+
+    // 位移模式下沿抛物面法线按高度偏移顶点
+    var position = vertex.position;
+    if (enable_displacement == 1u) {
+        position += normalize(vertex.normal) * height * displacement_scale;
+    }
+    let world_position = mesh_position_local_to_world(
+        get_world_from_local(vertex.instance_index),
+        vec4<f32>(position, 1.0)
     );
+    out.clip_position = position_world_to_clip(world_position.xyz);
+    out.world_position = world_position.xyz;
     out.uv = vertex.uv;
-    // 使用 i_height 在 red green 中混合
-    let red = vec4<f32>(1.0, 0.0, 0.0, 1.0);
-    let green = vec4<f32>(0.0, 1.0, 0.0, 1.0);
-    let height = buffer[vertex.position_index % 9216u];
     out.i_height = height;
-    if (interpolate_algo == 0u) {
-        out.color = interpolate_color_0(height);
-    } else if (interpolate_algo == 1u) {
-        out.color = interpolate_color_1(height);
-    } else if (interpolate_algo == 2u) {
-        out.color = interpolate_color(height);
-    } else if (interpolate_algo == 3u) {
-        out.color = interpolate_color_oklab(height);
-    } else if (interpolate_algo == 4u) {
-        out.color = vec4f(heat5(height), 1.0);
-    } else if (interpolate_algo == 5u) {
-        out.color = vec4f(heat7(height), 1.0);
-    } else {
-        out.color = vec4<f32>(0.0, 0.0, 0.0, 1.0);
-    }
+    out.panel_index = vertex.panel_index;
     return out;
 }
 
 @fragment
 fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    // 位移后的面法线由屏幕空间导数重新计算，需在下方非一致控制流之前求导
+    let normal = normalize(cross(dpdx(in.world_position), dpdy(in.world_position)));
+
+    // 按映射范围归一化到 0~1 后查表，纹理采样同样需在一致控制流中进行；
+    // 坐标对齐到首末纹素中心，使 0 与 1 恰好取到调色板两端
+    let value = (in.i_height - height_min) / max(height_max - height_min, 1e-6);
+    let lut_size = f32(textureDimensions(colormap_texture));
+    let lut_coord = (clamp(value, 0.0, 1.0) * (lut_size - 1.0) + 0.5) / lut_size;
+    var mapped = textureSample(colormap_texture, colormap_sampler, lut_coord).rgb;
+
+    // 等高线：到最近整数级的距离除以级数的屏幕导数，得到以像素计的距离
+    let contour_level = in.i_height / max(contour_interval, 1e-6);
+    let contour_width = max(fwidth(contour_level), 1e-6);
+    if (value < 0.0) {
+        mapped = UNDER_RANGE_COLOR;
+    } else if (value > 1.0) {
+        mapped = OVER_RANGE_COLOR;
+    }
+
+    // 查看的面板描白色粗边并提亮
+    let selected = in.panel_index == selected_panel;
+    if (selected && (in.uv.x <= 0.06 || in.uv.y <= 0.06 || in.uv.x >= 0.94 || in.uv.y >= 0.94)) {
+        return vec4<f32>(1.0, 1.0, 1.0, 1.0);
+    }
 
     // 通过 uv 值判断当前片元是否属于边缘，如果是则渲染 border 为黑色
     if (enable_boundary == 1u && (in.uv.x <= 0.015 || in.uv.y <= 0.015 || in.uv.x >= 0.985 || in.uv.y >= 0.985)) {
         return vec4<f32>(0.0, 0.0, 0.0, 1.0);
     }
 
-    let color = in.color.rgb;
+    var color = mapped;
+    if (contour_interval > 0.0) {
+        let nearest_level = round(contour_level);
+        let distance = abs(contour_level - nearest_level) / contour_width;
+        let major = i32(nearest_level) % i32(max(contour_major_every, 1u)) == 0;
+        // 主等高线约 2 像素宽，次等高线约 1 像素宽且较淡
+        let half_width = select(0.5, 1.0, major);
+        let strength = select(0.5, 0.9, major);
+        let coverage = 1.0 - smoothstep(half_width - 0.5, half_width + 0.5, distance);
+        // 线色取与底色对比明显的黑或白
+        let luminance = dot(color, vec3<f32>(0.2126, 0.7152, 0.0722));
+        let line_color = select(vec3<f32>(0.0), vec3<f32>(1.0), luminance < 0.2);
+        color = mix(color, line_color, coverage * strength);
+    }
+    if (enable_displacement == 1u) {
+        // 双面渲染，取法线与光线夹角的绝对值做漫反射
+        let light_dir = normalize(vec3<f32>(0.3, 0.5, 1.0));
+        let diffuse = abs(dot(normal, light_dir));
+        color = color * (0.35 + 0.65 * diffuse);
+    }
+    if (selected) {
+        color = mix(color, vec3<f32>(1.0, 1.0, 1.0), 0.3);
+    }
     return vec4<f32>(color, 1.0);
 }
@@ -0,0 +1,253 @@
+//! 促动器拓扑
+//!
+//! 相邻面板在公共角点处共用同一个促动器。这里把几何中重合的面板角点合并为促动器节点，
+//! 并为每个面板顶点记录其所属促动器编号。高度缓冲按促动器存储，着色器通过顶点上的
+//! 促动器编号读取高度，因此共享同一角点的各面板顶点总是取到同一个值。
+//!
+//! 圆环面板数加倍处（如 32 → 64）外环面板的部分角点落在内环面板外边的中间，形成 T 形连接。
+//! 这些悬挂节点仍是独立的促动器，但内环面板的这条边只由两端的促动器决定，若悬挂节点高度
+//! 与两端不一致，显示的曲面会在此处撕开。上传到 GPU 前用 [`ActuatorTopology::conform`]
+//! 将悬挂节点的高度改为两端促动器按方位角的线性插值；各项分析仍使用数据源给出的原始高度。
+
+use crate::geometry::ReflectorGeometry;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use std::f32::consts::TAU;
+
+/// 判定两个面板角点重合的距离容差（场景单位）
+const COINCIDENT_TOLERANCE: f32 = 1e-4;
+
+#[derive(Resource, Debug, Clone)]
+pub struct ActuatorTopology {
+    /// 每个面板顶点对应的促动器编号
+    vertex_actuators: Vec<u32>,
+    /// 每个促动器的位置
+    positions: Vec<Vec3>,
+    /// 每个促动器所在的圆环边界，0 为最内圈的内边界，k 为第 k - 1 环的外边界
+    boundaries: Vec<u32>,
+    /// 每个促动器处的曲面法线（相邻面板法线的平均，指向抛物面凹侧）
+    normals: Vec<Vec3>,
+    /// 每个促动器代表的面积，取相邻各面板面积的四分之一之和
+    areas: Vec<f32>,
+    /// 落在其它面板边中间的悬挂节点：(节点, 边起点, 边终点, 节点在边上的比例)
+    hanging: Vec<(u32, u32, u32, f32)>,
+}
+
+impl ActuatorTopology {
+    pub fn from_geometry(geometry: &ReflectorGeometry) -> Self {
+        let cell = |p: Vec3| {
+            let q = (p / COINCIDENT_TOLERANCE).floor();
+            (q.x as i64, q.y as i64, q.z as i64)
+        };
+
+        let mut grid: HashMap<(i64, i64, i64), Vec<u32>> = HashMap::default();
+        let mut vertex_actuators = Vec::with_capacity(geometry.vertex_count());
+        let mut positions: Vec<Vec3> = vec![];
+        let mut boundaries = vec![];
+
+        let mut vertex = 0;
+        for (ring, &block) in geometry.ring_blocks().iter().enumerate() {
+            for _ in 0..block {
+                for corner in 0..4 {
+                    let p = Vec3::from_array(geometry.positions()[vertex]);
+                    let (cx, cy, cz) = cell(p);
+                    // 重合点可能落在相邻网格中，检查周围 27 个网格
+                    let existing = (-1..=1)
+                        .flat_map(|dx| {
+                            (-1..=1).flat_map(move |dy| (-1..=1).map(move |dz| (dx, dy, dz)))
+                        })
+                        .filter_map(|(dx, dy, dz)| grid.get(&(cx + dx, cy + dy, cz + dz)))
+                        .flatten()
+                        .copied()
+                        .find(|&id| positions[id as usize].distance(p) <= COINCIDENT_TOLERANCE);
+
+                    let id = existing.unwrap_or_else(|| {
+                        let id = positions.len() as u32;
+                        positions.push(p);
+                        // 角点 0、1 在内边界，2、3 在外边界
+                        boundaries.push(if corner < 2 { ring } else { ring + 1 } as u32);
+                        grid.entry((cx, cy, cz)).or_default().push(id);
+                        id
+                    });
+                    vertex_actuators.push(id);
+                    vertex += 1;
+                }
+            }
+        }
+
+        let mut normals = vec![Vec3::ZERO; positions.len()];
+        let mut areas = vec![0.0; positions.len()];
+        for (panel, corners) in geometry.positions().chunks_exact(4).enumerate() {
+            let [p0, p1, p2, p3] = [0, 1, 2, 3].map(|i| Vec3::from_array(corners[i]));
+            let face_normal = (p3 - p0).cross(p1 - p0).normalize_or_zero();
+            // 四边形面积为两条对角线叉积长度的一半
+            let area = 0.5 * (p2 - p0).cross(p3 - p1).length();
+            for &id in &vertex_actuators[panel * 4..panel * 4 + 4] {
+                normals[id as usize] += face_normal;
+                areas[id as usize] += area / 4.0;
+            }
+        }
+        normals
+            .iter_mut()
+            .for_each(|n| *n = n.normalize_or(Vec3::Z));
+
+        let hanging = find_hanging_nodes(&vertex_actuators, &positions, &boundaries);
+
+        ActuatorTopology {
+            vertex_actuators,
+            positions,
+            boundaries,
+            normals,
+            areas,
+            hanging,
+        }
+    }
+
+    pub fn actuator_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// 每个面板顶点对应的促动器编号，作为网格的 `ATTRIBUTE_POSITION_INDEX`
+    pub fn vertex_actuators(&self) -> &[u32] {
+        &self.vertex_actuators
+    }
+
+    pub fn positions(&self) -> &[Vec3] {
+        &self.positions
+    }
+
+    pub fn boundaries(&self) -> &[u32] {
+        &self.boundaries
+    }
+
+    pub fn normals(&self) -> &[Vec3] {
+        &self.normals
+    }
+
+    pub fn areas(&self) -> &[f32] {
+        &self.areas
+    }
+
+    /// 把悬挂节点的高度改为所在面板边两端高度的插值，使显示的曲面在 T 形连接处连续
+    pub fn conform(&self, heights: &mut [f32]) {
+        for &(node, a, b, t) in &self.hanging {
+            let (Some(&ha), Some(&hb)) = (heights.get(a as usize), heights.get(b as usize)) else {
+                continue;
+            };
+            if let Some(height) = heights.get_mut(node as usize) {
+                *height = ha + (hb - ha) * t;
+            }
+        }
+    }
+
+    /// 距给定位置最近的促动器
+    pub fn nearest(&self, position: Vec3) -> Option<u32> {
+        self.positions
+            .iter()
+            .map(|p| p.distance_squared(position))
+            .enumerate()
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(id, _)| id as u32)
+    }
+}
+
+/// 在每块面板的内外两条圆弧边上查找位于两端之间、同一圆环边界上的其它促动器
+fn find_hanging_nodes(
+    vertex_actuators: &[u32],
+    positions: &[Vec3],
+    boundaries: &[u32],
+) -> Vec<(u32, u32, u32, f32)> {
+    let azimuth = |id: u32| {
+        let p = positions[id as usize];
+        p.y.atan2(p.x)
+    };
+    let mut by_boundary: HashMap<u32, Vec<(u32, f32)>> = HashMap::default();
+    for (id, &boundary) in boundaries.iter().enumerate() {
+        by_boundary
+            .entry(boundary)
+            .or_default()
+            .push((id as u32, azimuth(id as u32)));
+    }
+
+    let mut hanging = vec![];
+    for corners in vertex_actuators.chunks_exact(4) {
+        // 角点 0 → 1 为内边，3 → 2 为外边，均按方位角增大的方向
+        for (a, b) in [(corners[0], corners[1]), (corners[3], corners[2])] {
+            if a == b {
+                continue;
+            }
+            let start = azimuth(a);
+            let span = (azimuth(b) - start).rem_euclid(TAU);
+            let Some(nodes) = by_boundary.get(&boundaries[a as usize]) else {
+                continue;
+            };
+            for &(node, theta) in nodes {
+                let offset = (theta - start).rem_euclid(TAU);
+                if node != a && node != b && offset > 1e-5 && offset < span - 1e-5 {
+                    hanging.push((node, a, b, offset / span));
+                }
+            }
+        }
+    }
+    hanging
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shared_corners_map_to_one_actuator() {
+        let geometry = ReflectorGeometry::default();
+        let topology = ActuatorTopology::from_geometry(&geometry);
+        // 边界 0..=3 各 32 个节点，4..=7 各 64 个，8..=23 各 128 个
+        assert_eq!(topology.actuator_count(), 4 * 32 + 4 * 64 + 16 * 128);
+
+        let ids = topology.vertex_actuators();
+        let mut first = 0;
+        for (ring, &blocks) in geometry.ring_blocks().iter().enumerate() {
+            let blocks = blocks as usize;
+            for j in 0..blocks {
+                let panel = first + j;
+                let next = first + (j + 1) % blocks;
+                // 同一圆环中相邻面板共用径向边的两个角点
+                assert_eq!(ids[panel * 4 + 1], ids[next * 4], "环 {} 块 {}", ring, j);
+                assert_eq!(
+                    ids[panel * 4 + 2],
+                    ids[next * 4 + 3],
+                    "环 {} 块 {}",
+                    ring,
+                    j
+                );
+            }
+            // 面板数相同的相邻圆环共用整条边界
+            if let Some(&outer) = geometry.ring_blocks().get(ring + 1) {
+                if outer as usize == blocks {
+                    for j in 0..blocks {
+                        let (panel, above) = (first + j, first + blocks + j);
+                        assert_eq!(ids[panel * 4 + 3], ids[above * 4]);
+                        assert_eq!(ids[panel * 4 + 2], ids[above * 4 + 1]);
+                    }
+                }
+            }
+            first += blocks;
+        }
+    }
+
+    #[test]
+    fn hanging_nodes_follow_coarse_edges() {
+        let topology = ActuatorTopology::from_geometry(&ReflectorGeometry::default());
+        // 32 → 64 处 32 个、64 → 128 处 64 个悬挂节点
+        assert_eq!(topology.hanging.len(), 32 + 64);
+
+        let mut heights = (0..topology.actuator_count())
+            .map(|i| ((i * 37) % 11) as f32 - 5.0)
+            .collect::<Vec<_>>();
+        topology.conform(&mut heights);
+        for &(node, a, b, t) in &topology.hanging {
+            assert!((t - 0.5).abs() < 1e-3, "悬挂节点 {} 应位于边的中点", node);
+            let expected = 0.5 * (heights[a as usize] + heights[b as usize]);
+            assert!((heights[node as usize] - expected).abs() < 1e-3);
+        }
+    }
+}
@@ -0,0 +1,702 @@
+//! 口径相位与远场方向图
+//!
+//! 把促动器高度插值到口径面的规则网格上，换算为光程差相位 `φ = 2π·2h·n_z/λ`，乘以照明锥削，
+//! 面板未覆盖的网格（内圈以内的中心遮挡与外缘以外）振幅为零。对口径场做二维 FFT 得到远场
+//! 功率方向图，与未变形反射面的方向图比较，给出主波束增益损失、最高旁瓣电平与指向偏差。
+//! 相位图与方向图显示在右下角的副视口中。
+
+use super::fft::{fft2, Complex};
+use super::ruze::{wavelength, ObservingFrequencies, HEIGHT_UNIT_METERS};
+use super::{hud_text_font, AnalysisHud, DisplayedHeights};
+use crate::data_source::HeightSet;
+use crate::{actuator::ActuatorTopology, geometry::ReflectorGeometry, CustomTextFont};
+use bevy::{
+    color::palettes::css::*,
+    image::ImageSampler,
+    math::DVec2,
+    prelude::*,
+    render::{
+        camera::Viewport,
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+        view::RenderLayers,
+    },
+    window::PrimaryWindow,
+};
+use std::f64::consts::PI;
+use std::fmt::{self, Formatter};
+
+/// 口径直径方向的采样数
+const APERTURE_SAMPLES: usize = 128;
+/// FFT 尺寸，口径场四周补零以提高方向图的角分辨率
+const FFT_SIZE: usize = 256;
+/// 寻找主瓣第一零点时每个 FFT 格点的细分数
+const MAIN_LOBE_OVERSAMPLING: usize = 20;
+/// 副视口中显示的方向图中心区域（FFT 格点数）
+const BEAM_VIEW_SAMPLES: usize = 64;
+/// 反射面口径直径（米），用于把场景长度换算为物理长度
+pub const APERTURE_DIAMETER_METERS: f64 = 110.0;
+/// 照明边缘锥削（dB）
+const EDGE_TAPER_DB: f64 = 10.0;
+/// 方向图的最短刷新间隔（秒）
+const UPDATE_INTERVAL_SECS: f64 = 0.25;
+/// 方向图显示的动态范围（dB）
+const BEAM_DYNAMIC_RANGE_DB: f32 = 50.0;
+/// 副视口中每幅图的边长（逻辑像素）
+const BEAM_VIEW_SIZE: f32 = 240.0;
+/// 副视口距窗口右、下边缘的距离（逻辑像素），避开底部控制栏
+const BEAM_VIEW_MARGIN: Vec2 = Vec2::new(12.0, 160.0);
+const BEAM_VIEW_LAYER: usize = 1;
+
+pub struct BeamPlugin;
+
+impl Plugin for BeamPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<BeamView>()
+            .init_resource::<ApertureGrid>()
+            .init_resource::<BeamSettings>()
+            .init_resource::<BeamMetrics>()
+            .add_systems(Startup, setup_beam_view)
+            .add_systems(
+                Update,
+                (
+                    update_beam_pattern
+                        .in_set(HeightSet::Analysis)
+                        .after(super::update_displayed_heights),
+                    (render_beam_images, update_beam_text).run_if(resource_changed::<BeamMetrics>),
+                    update_beam_viewport,
+                    toggle_beam_view.run_if(state_changed::<BeamView>),
+                ),
+            );
+    }
+}
+
+/// 是否显示方向图副视口
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash, States)]
+pub enum BeamView {
+    #[default]
+    Enable,
+    Disable,
+}
+
+impl fmt::Display for BeamView {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BeamView::Enable => write!(f, "显示"),
+            BeamView::Disable => write!(f, "隐藏"),
+        }
+    }
+}
+
+/// 计算方向图使用的观测频率（GHz），默认取观测频率中最高的一个
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct BeamSettings {
+    pub frequency_ghz: f64,
+}
+
+impl FromWorld for BeamSettings {
+    fn from_world(world: &mut World) -> Self {
+        let frequency_ghz = world
+            .get_resource::<ObservingFrequencies>()
+            .and_then(|frequencies| frequencies.iter().copied().reduce(f64::max))
+            .unwrap_or(43.0);
+        BeamSettings { frequency_ghz }
+    }
+}
+
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct BeamMetrics {
+    pub frequency_ghz: f64,
+    /// 主波束峰值相对未变形反射面的增益损失（dB，正值表示损失）
+    pub gain_loss_db: f64,
+    /// 最高旁瓣相对主波束峰值的电平（dB）
+    pub peak_sidelobe_db: f64,
+    /// 主波束指向偏差（角秒）
+    pub pointing_offset_arcsec: DVec2,
+    /// 口径相位（弧度），`APERTURE_SAMPLES²` 个，遮挡处为 None
+    pub phase: Vec<Option<f32>>,
+    /// 方向图中心区域相对未变形峰值的功率（dB），`BEAM_VIEW_SAMPLES²` 个
+    pub power_db: Vec<f32>,
+}
+
+/// 落在某块面板内的口径网格点
+struct AperturePixel {
+    /// 在 `APERTURE_SAMPLES²` 网格中的位置
+    index: usize,
+    actuators: [u32; 3],
+    weights: [f64; 3],
+    /// 沿法线的高度对应的往返光程系数 2·n_z
+    path_factor: f64,
+    amplitude: f64,
+}
+
+/// 口径网格与未变形反射面的方向图参数，几何不变，启动时计算一次
+#[derive(Resource)]
+struct ApertureGrid {
+    pixels: Vec<AperturePixel>,
+    /// 网格间距（米）
+    spacing_meters: f64,
+    /// 未变形反射面的方向图峰值功率
+    ideal_peak: f64,
+    /// 未变形方向图主瓣第一零点的半径（FFT 格点数，可为小数）
+    main_lobe_radius: f64,
+}
+
+impl FromWorld for ApertureGrid {
+    fn from_world(world: &mut World) -> Self {
+        ApertureGrid::new(
+            world.resource::<ReflectorGeometry>(),
+            world.resource::<ActuatorTopology>(),
+        )
+    }
+}
+
+impl ApertureGrid {
+    fn new(geometry: &ReflectorGeometry, topology: &ActuatorTopology) -> Self {
+        let radius = geometry
+            .positions()
+            .iter()
+            .map(|p| Vec2::new(p[0], p[1]).length() as f64)
+            .fold(0.0, f64::max);
+        let spacing = 2.0 * radius / APERTURE_SAMPLES as f64;
+        let edge = 10f64.powf(-EDGE_TAPER_DB / 20.0);
+
+        // 把每块面板拆成两个三角形，光栅化到口径网格上并记录重心坐标
+        let mut pixels = vec![];
+        let mut covered = vec![false; APERTURE_SAMPLES * APERTURE_SAMPLES];
+        let ids = topology.vertex_actuators();
+        for (panel, corners) in geometry.positions().chunks_exact(4).enumerate() {
+            let corner = |i: usize| DVec2::new(corners[i][0] as f64, corners[i][1] as f64);
+            for triangle in [[0, 1, 2], [0, 2, 3]] {
+                let [a, b, c] = triangle.map(corner);
+                let min = a.min(b).min(c);
+                let max = a.max(b).max(c);
+                let cell = |v: f64| (v + radius) / spacing - 0.5;
+                let range = |lo: f64, hi: f64| {
+                    let lo = cell(lo).floor().max(0.0) as usize;
+                    let hi = (cell(hi).ceil().max(0.0) as usize).min(APERTURE_SAMPLES - 1);
+                    lo..=hi
+                };
+                let area = (b - a).perp_dot(c - a);
+                if area.abs() < f64::EPSILON {
+                    continue;
+                }
+                for iy in range(min.y, max.y) {
+                    for ix in range(min.x, max.x) {
+                        let index = iy * APERTURE_SAMPLES + ix;
+                        if covered[index] {
+                            continue;
+                        }
+                        let p = DVec2::new(
+                            (ix as f64 + 0.5) * spacing - radius,
+                            (iy as f64 + 0.5) * spacing - radius,
+                        );
+                        let wa = (b - p).perp_dot(c - p) / area;
+                        let wb = (c - p).perp_dot(a - p) / area;
+                        let wc = 1.0 - wa - wb;
+                        if wa < -1e-9 || wb < -1e-9 || wc < -1e-9 {
+                            continue;
+                        }
+                        covered[index] = true;
+                        let actuators = triangle.map(|i| ids[panel * 4 + i]);
+                        let weights = [wa, wb, wc];
+                        let n_z = actuators
+                            .iter()
+                            .zip(weights)
+                            .map(|(&id, w)| topology.normals()[id as usize].z as f64 * w)
+                            .sum::<f64>();
+                        let r = p.length() / radius;
+                        pixels.push(AperturePixel {
+                            index,
+                            actuators,
+                            weights,
+                            path_factor: 2.0 * n_z,
+                            amplitude: edge + (1.0 - edge) * (1.0 - r * r).max(0.0),
+                        });
+                    }
+                }
+            }
+        }
+
+        ApertureGrid::from_pixels(pixels, spacing * APERTURE_DIAMETER_METERS / (2.0 * radius))
+    }
+
+    /// 由口径网格点计算未变形反射面的方向图参数
+    fn from_pixels(pixels: Vec<AperturePixel>, spacing_meters: f64) -> Self {
+        let mut grid = ApertureGrid {
+            pixels,
+            spacing_meters,
+            ideal_peak: 1.0,
+            main_lobe_radius: 1.0,
+        };
+        grid.ideal_peak = grid.far_field(|_| 0.0)[0];
+        grid.main_lobe_radius = grid.first_null();
+        grid
+    }
+
+    /// 沿 x 方向找到未变形方向图主瓣的第一个极小值（FFT 格点数）。
+    ///
+    /// FFT 只补零到口径的两倍，格点太粗：均匀照明时第一零点与第一旁瓣落在相邻两个格点之间，
+    /// 逐格点找极小值会越过第一旁瓣。这里把口径场沿 y 求和得到 x 方向的投影，再以
+    /// `1 / MAIN_LOBE_OVERSAMPLING` 格点的步长直接计算 `ky = 0` 一行的方向图
+    fn first_null(&self) -> f64 {
+        let mut profile = vec![0.0; APERTURE_SAMPLES];
+        for pixel in &self.pixels {
+            profile[pixel.index % APERTURE_SAMPLES] += pixel.amplitude;
+        }
+        let power = |k: f64| {
+            profile
+                .iter()
+                .enumerate()
+                .map(|(x, &a)| Complex::from_polar(a, -2.0 * PI * k * x as f64 / FFT_SIZE as f64))
+                .fold(Complex::ZERO, |sum, value| sum + value)
+                .norm_sqr()
+        };
+        let step = 1.0 / MAIN_LOBE_OVERSAMPLING as f64;
+        let mut previous = power(0.0);
+        for i in 1..FFT_SIZE / 2 * MAIN_LOBE_OVERSAMPLING {
+            let current = power(i as f64 * step);
+            if current > previous {
+                return (i - 1) as f64 * step;
+            }
+            previous = current;
+        }
+        1.0
+    }
+
+    /// 以各网格点相位计算远场功率方向图，结果未做中心化，零频位于下标 0
+    fn far_field(&self, phase: impl Fn(&AperturePixel) -> f64) -> Vec<f64> {
+        let offset = (FFT_SIZE - APERTURE_SAMPLES) / 2;
+        let mut field = vec![Complex::ZERO; FFT_SIZE * FFT_SIZE];
+        for pixel in &self.pixels {
+            let (ix, iy) = (
+                pixel.index % APERTURE_SAMPLES,
+                pixel.index / APERTURE_SAMPLES,
+            );
+            field[(iy + offset) * FFT_SIZE + ix + offset] =
+                Complex::from_polar(pixel.amplitude, phase(pixel));
+        }
+        fft2(&mut field, FFT_SIZE);
+        field.into_iter().map(Complex::norm_sqr).collect()
+    }
+}
+
+/// FFT 下标对应的有符号频率
+fn signed_bin(k: usize) -> isize {
+    if k < FFT_SIZE / 2 {
+        k as isize
+    } else {
+        k as isize - FFT_SIZE as isize
+    }
+}
+
+fn wrap_bin(k: isize) -> usize {
+    k.rem_euclid(FFT_SIZE as isize) as usize
+}
+
+/// 用峰值及左右两点的抛物线插值得到亚格点偏移
+fn parabolic_offset(left: f64, center: f64, right: f64) -> f64 {
+    let denominator = left - 2.0 * center + right;
+    if denominator.abs() > f64::EPSILON {
+        (0.5 * (left - right) / denominator).clamp(-0.5, 0.5)
+    } else {
+        0.0
+    }
+}
+
+// 高度变化后按最短刷新间隔重算方向图
+#[allow(clippy::too_many_arguments)]
+fn update_beam_pattern(
+    heights: Res<DisplayedHeights>,
+    settings: Res<BeamSettings>,
+    grid: Res<ApertureGrid>,
+    time: Res<Time>,
+    mut metrics: ResMut<BeamMetrics>,
+    mut pending: Local<bool>,
+    mut last_update: Local<Option<f64>>,
+) {
+    *pending |= heights.is_changed() || settings.is_changed();
+    let now = time.elapsed_secs_f64();
+    if !*pending || last_update.is_some_and(|last| now - last < UPDATE_INTERVAL_SECS) {
+        return;
+    }
+    *pending = false;
+    *last_update = Some(now);
+    *metrics = beam_metrics(&grid, &heights, settings.frequency_ghz);
+}
+
+/// 由促动器高度计算口径相位、远场方向图与方向图指标
+fn beam_metrics(grid: &ApertureGrid, heights: &[f32], frequency_ghz: f64) -> BeamMetrics {
+    let wavelength = wavelength(frequency_ghz);
+    let phase_of = |pixel: &AperturePixel| {
+        let h = pixel
+            .actuators
+            .iter()
+            .zip(pixel.weights)
+            .map(|(&id, w)| heights.get(id as usize).copied().unwrap_or(0.0) as f64 * w)
+            .sum::<f64>();
+        2.0 * PI * pixel.path_factor * h * HEIGHT_UNIT_METERS / wavelength
+    };
+
+    let mut phase = vec![None; APERTURE_SAMPLES * APERTURE_SAMPLES];
+    for pixel in &grid.pixels {
+        let wrapped = (phase_of(pixel) + PI).rem_euclid(2.0 * PI) - PI;
+        phase[pixel.index] = Some(wrapped as f32);
+    }
+    let power = grid.far_field(phase_of);
+
+    let (peak_index, peak) =
+        power.iter().copied().enumerate().fold(
+            (0, 0.0),
+            |best, (i, p)| if p > best.1 { (i, p) } else { best },
+        );
+    let (px, py) = (
+        signed_bin(peak_index % FFT_SIZE),
+        signed_bin(peak_index / FFT_SIZE),
+    );
+    let at = |x: isize, y: isize| power[wrap_bin(y) * FFT_SIZE + wrap_bin(x)];
+    let peak_bin = DVec2::new(
+        px as f64 + parabolic_offset(at(px - 1, py), peak, at(px + 1, py)),
+        py as f64 + parabolic_offset(at(px, py - 1), peak, at(px, py + 1)),
+    );
+
+    let sidelobe = power
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| {
+            let d = DVec2::new(
+                signed_bin(i % FFT_SIZE) as f64,
+                signed_bin(i / FFT_SIZE) as f64,
+            ) - peak_bin;
+            d.length() > grid.main_lobe_radius
+        })
+        .map(|(_, &p)| p)
+        .fold(0.0, f64::max);
+
+    // 一个 FFT 格点对应的角度为 λ / (N·Δx)
+    let bin_arcsec = (wavelength / (FFT_SIZE as f64 * grid.spacing_meters)).to_degrees() * 3600.0;
+    let half = BEAM_VIEW_SAMPLES as isize / 2;
+    let power_db = (-half..half)
+        .flat_map(|y| (-half..half).map(move |x| (x, y)))
+        .map(|(x, y)| (10.0 * (at(x, y) / grid.ideal_peak).max(1e-12).log10()) as f32)
+        .collect();
+
+    BeamMetrics {
+        frequency_ghz,
+        gain_loss_db: 10.0 * (grid.ideal_peak / peak.max(f64::MIN_POSITIVE)).log10(),
+        peak_sidelobe_db: 10.0 * (sidelobe.max(f64::MIN_POSITIVE) / peak).log10(),
+        pointing_offset_arcsec: peak_bin * bin_arcsec,
+        phase,
+        power_db,
+    }
+}
+
+#[derive(Resource)]
+struct BeamImages {
+    phase: Handle<Image>,
+    power: Handle<Image>,
+}
+
+#[derive(Component)]
+struct BeamViewCamera;
+
+fn beam_image(size: usize) -> Image {
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: size as u32,
+            height: size as u32,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, 0, 255],
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.sampler = ImageSampler::nearest();
+    image
+}
+
+fn setup_beam_view(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    let layer = RenderLayers::layer(BEAM_VIEW_LAYER);
+    let phase = images.add(beam_image(APERTURE_SAMPLES));
+    let power = images.add(beam_image(BEAM_VIEW_SAMPLES));
+
+    commands.spawn((
+        BeamViewCamera,
+        Camera2d,
+        Camera {
+            order: 1,
+            // 不清屏，背景由下面的半透明底板绘制
+            clear_color: ClearColorConfig::None,
+            ..default()
+        },
+        layer.clone(),
+    ));
+    commands.spawn((
+        Sprite::from_color(
+            BLACK.with_alpha(0.8),
+            Vec2::new(2.0 * BEAM_VIEW_SIZE, BEAM_VIEW_SIZE),
+        ),
+        Transform::from_xyz(0.0, 0.0, -1.0),
+        layer.clone(),
+    ));
+    for (image, x) in [(&phase, -0.5), (&power, 0.5)] {
+        commands.spawn((
+            Sprite {
+                image: image.clone(),
+                custom_size: Some(Vec2::splat(BEAM_VIEW_SIZE - 40.0)),
+                ..default()
+            },
+            Transform::from_xyz(x * BEAM_VIEW_SIZE, -8.0, 0.0),
+            layer.clone(),
+        ));
+    }
+    commands.insert_resource(BeamImages { phase, power });
+}
+
+pub(super) fn spawn_beam_labels(
+    mut commands: Commands,
+    hud: Single<Entity, With<AnalysisHud>>,
+    font: Res<CustomTextFont>,
+) {
+    let text_font = hud_text_font(&font);
+    let layer = RenderLayers::layer(BEAM_VIEW_LAYER);
+    for (label, x) in [("口径相位", -0.5), ("远场方向图 (dB)", 0.5)] {
+        commands.spawn((
+            Text2d::new(label),
+            text_font.clone(),
+            TextColor(WHITE.into()),
+            Transform::from_xyz(x * BEAM_VIEW_SIZE, BEAM_VIEW_SIZE / 2.0 - 14.0, 0.0),
+            layer.clone(),
+        ));
+    }
+    commands.entity(*hud).with_children(|p| {
+        p.spawn((
+            Text::default(),
+            BeamText,
+            text_font,
+            TextColor(WHITE.into()),
+        ));
+    });
+}
+
+// 副视口固定在窗口右下角，随窗口大小调整
+fn update_beam_viewport(
+    window: Single<&Window, With<PrimaryWindow>>,
+    mut camera: Single<&mut Camera, With<BeamViewCamera>>,
+) {
+    let scale = window.scale_factor();
+    let window_size = window.physical_size();
+    let size = (Vec2::new(2.0 * BEAM_VIEW_SIZE, BEAM_VIEW_SIZE) * scale)
+        .as_uvec2()
+        .min(window_size);
+    let margin = (BEAM_VIEW_MARGIN * scale).as_uvec2();
+    let position = window_size.saturating_sub(size).saturating_sub(margin);
+    if size.x == 0 || size.y == 0 {
+        camera.is_active = false;
+        return;
+    }
+    let viewport = Viewport {
+        physical_position: position,
+        physical_size: size,
+        ..default()
+    };
+    if camera
+        .viewport
+        .as_ref()
+        .map(|v| (v.physical_position, v.physical_size))
+        != Some((position, size))
+    {
+        camera.viewport = Some(viewport);
+    }
+}
+
+fn toggle_beam_view(
+    view: Res<State<BeamView>>,
+    mut camera: Single<&mut Camera, With<BeamViewCamera>>,
+) {
+    camera.is_active = *view.get() == BeamView::Enable;
+}
+
+/// 相位着色：负值蓝、正值红，零为白
+fn phase_color(phase: Option<f32>) -> [u8; 4] {
+    let Some(phase) = phase else {
+        return [0, 0, 0, 255];
+    };
+    let t = (phase / std::f32::consts::PI).clamp(-1.0, 1.0);
+    let fade = ((1.0 - t.abs()) * 255.0) as u8;
+    if t < 0.0 {
+        [fade, fade, 255, 255]
+    } else {
+        [255, fade, fade, 255]
+    }
+}
+
+/// 功率着色：黑、蓝、红、黄、白
+fn power_color(db: f32) -> [u8; 4] {
+    let t = (1.0 + db / BEAM_DYNAMIC_RANGE_DB).clamp(0.0, 1.0);
+    let stops = [
+        [0.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0],
+        [1.0, 0.0, 0.0],
+        [1.0, 1.0, 0.0],
+        [1.0, 1.0, 1.0],
+    ];
+    let x = t * (stops.len() - 1) as f32;
+    let i = (x.floor() as usize).min(stops.len() - 2);
+    let f = x - i as f32;
+    let [r, g, b] =
+        [0, 1, 2].map(|c| ((stops[i][c] * (1.0 - f) + stops[i + 1][c] * f) * 255.0) as u8);
+    [r, g, b, 255]
+}
+
+// 图像第一行在上方，而网格 y 轴向上，因此逐行翻转
+fn fill_image(image: &mut Image, size: usize, colors: impl Iterator<Item = [u8; 4]>) {
+    for (i, color) in colors.enumerate() {
+        let (x, y) = (i % size, size - 1 - i / size);
+        let offset = (y * size + x) * 4;
+        image.data[offset..offset + 4].copy_from_slice(&color);
+    }
+}
+
+fn render_beam_images(
+    metrics: Res<BeamMetrics>,
+    beam_images: Res<BeamImages>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    if let Some(image) = images.get_mut(&beam_images.phase) {
+        fill_image(
+            image,
+            APERTURE_SAMPLES,
+            metrics.phase.iter().copied().map(phase_color),
+        );
+    }
+    if let Some(image) = images.get_mut(&beam_images.power) {
+        fill_image(
+            image,
+            BEAM_VIEW_SAMPLES,
+            metrics.power_db.iter().copied().map(power_color),
+        );
+    }
+}
+
+#[derive(Component)]
+pub struct BeamText;
+
+fn update_beam_text(metrics: Res<BeamMetrics>, mut text: Query<&mut Text, With<BeamText>>) {
+    for mut text in text.iter_mut() {
+        text.0 = format!(
+            "远场方向图 ({:.1} GHz)\n主波束增益损失: {:.2} dB\n最高旁瓣: {:.1} dB\n指向偏差: ({:.2}\", {:.2}\")",
+            metrics.frequency_ghz,
+            metrics.gain_loss_db,
+            metrics.peak_sidelobe_db,
+            metrics.pointing_offset_arcsec.x,
+            metrics.pointing_offset_arcsec.y
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FREQUENCY_GHZ: f64 = 43.0;
+
+    // 振幅均匀、无遮挡的圆形口径。每个网格点有自己的“促动器”，高度即该点的表面误差
+    fn uniform_circle() -> ApertureGrid {
+        let center = APERTURE_SAMPLES as f64 / 2.0;
+        let pixels = (0..APERTURE_SAMPLES * APERTURE_SAMPLES)
+            .filter(|index| {
+                let x = (index % APERTURE_SAMPLES) as f64 + 0.5 - center;
+                let y = (index / APERTURE_SAMPLES) as f64 + 0.5 - center;
+                x.hypot(y) <= center
+            })
+            .map(|index| AperturePixel {
+                index,
+                actuators: [index as u32; 3],
+                weights: [1.0, 0.0, 0.0],
+                path_factor: 1.0,
+                amplitude: 1.0,
+            })
+            .collect();
+        ApertureGrid::from_pixels(pixels, 1.0)
+    }
+
+    // 沿 x 方向线性倾斜的表面，使方向图峰值移动 `bins` 个 FFT 格点
+    fn tilt(bins: f64) -> Vec<f32> {
+        let wavelength = wavelength(FREQUENCY_GHZ);
+        (0..APERTURE_SAMPLES * APERTURE_SAMPLES)
+            .map(|index| {
+                let x = (index % APERTURE_SAMPLES) as f64;
+                (bins * x / FFT_SIZE as f64 * wavelength / HEIGHT_UNIT_METERS) as f32
+            })
+            .collect()
+    }
+
+    fn bin_arcsec(grid: &ApertureGrid) -> f64 {
+        (wavelength(FREQUENCY_GHZ) / (FFT_SIZE as f64 * grid.spacing_meters)).to_degrees() * 3600.0
+    }
+
+    #[test]
+    fn perfect_reflector_has_no_loss() {
+        let geometry = ReflectorGeometry::default();
+        let topology = ActuatorTopology::from_geometry(&geometry);
+        let grid = ApertureGrid::new(&geometry, &topology);
+        let metrics = beam_metrics(&grid, &vec![0.0; topology.actuator_count()], FREQUENCY_GHZ);
+        assert!(
+            metrics.gain_loss_db.abs() < 1e-9,
+            "{}",
+            metrics.gain_loss_db
+        );
+        assert!(metrics.pointing_offset_arcsec.length() < 1e-9);
+        assert!(metrics.peak_sidelobe_db < 0.0);
+    }
+
+    #[test]
+    fn uniform_circle_first_sidelobe() {
+        let grid = uniform_circle();
+        // 第一零点在 1.22 λ/D，口径占 FFT 长度的一半
+        let first_null = 1.22 * FFT_SIZE as f64 / APERTURE_SAMPLES as f64;
+        assert!(
+            (grid.main_lobe_radius - first_null).abs() < 0.1,
+            "{}",
+            grid.main_lobe_radius
+        );
+        let metrics = beam_metrics(&grid, &[], FREQUENCY_GHZ);
+        assert!(metrics.gain_loss_db.abs() < 1e-9);
+        assert!(metrics.pointing_offset_arcsec.length() < 1e-9);
+        // 均匀照明圆口径（艾里斑）的第一旁瓣为 -17.6 dB
+        assert!(
+            (metrics.peak_sidelobe_db + 17.6).abs() < 0.5,
+            "{}",
+            metrics.peak_sidelobe_db
+        );
+    }
+
+    #[test]
+    fn linear_tilt_offsets_pointing() {
+        let grid = uniform_circle();
+        for bins in [3.0, -2.0, 1.25] {
+            let metrics = beam_metrics(&grid, &tilt(bins), FREQUENCY_GHZ);
+            let offset = metrics.pointing_offset_arcsec / bin_arcsec(&grid);
+            assert!((offset.x - bins).abs() < 0.1, "{} != {}", offset.x, bins);
+            assert!(offset.y.abs() < 1e-9, "{}", offset.y);
+            // 纯倾斜只移动波束，不改变峰值功率
+            if bins.fract() == 0.0 {
+                assert!(
+                    metrics.gain_loss_db.abs() < 1e-6,
+                    "{}",
+                    metrics.gain_loss_db
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn parabolic_offset_finds_vertex() {
+        let parabola = |x: f64| 4.0 - (x - 0.3) * (x - 0.3);
+        let offset = parabolic_offset(parabola(-1.0), parabola(0.0), parabola(1.0));
+        assert!((offset - 0.3).abs() < 1e-12, "{}", offset);
+        assert_eq!(parabolic_offset(1.0, 1.0, 1.0), 0.0);
+    }
+}
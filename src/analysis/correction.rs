@@ -0,0 +1,650 @@
+//! 促动器校正
+//!
+//! 由测得的高度计算使面形残差最小的促动器指令。促动器沿法线推动所在节点，校正后的高度为
+//! `h + u`。在行程限制 `|u| ≤ s` 下求解
+//!
+//! ```text
+//! min Σ wᵢ (hᵢ + uᵢ)² + λ Σ (uᵢ - uⱼ)²
+//! ```
+//!
+//! 其中 `wᵢ` 为归一化的促动器面积，第二项对面板上相邻的促动器求和，用于抑制相邻指令的
+//! 突变。λ = 0 时各促动器互不耦合，解即为逐个截断 `uᵢ = clamp(-hᵢ, -s, s)`，只有 λ > 0 时
+//! 求解才会在相邻促动器间权衡。问题为带盒约束的凸二次规划，用投影 Gauss-Seidel 迭代求解，
+//! 并以上一次的指令作为初值，连续变化的高度通常只需少量迭代。
+//!
+//! 打开对比视图时，在原反射面右侧并排显示校正后的反射面。
+
+use super::{hud_text_font, AnalysisHud};
+use crate::data_source::{ActuatorHeights, HeightSet};
+use crate::mount::Mounted;
+use crate::{
+    actuator::ActuatorTopology, cli::CliArgs, Block, CustomMaterial, CustomMaterialHandle,
+    CustomTextFont, Reflector,
+};
+use bevy::{color::palettes::css::*, prelude::*, render::storage::ShaderStorageBuffer};
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{
+    fmt::{self, Formatter},
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+/// 默认促动器行程（毫米，单向）
+pub const DEFAULT_STROKE: f32 = 1.0;
+/// 可切换的平滑系数
+pub const SMOOTHNESS_OPTIONS: [f32; 4] = [0.0, 0.1, 1.0, 10.0];
+/// 迭代上限
+const MAX_ITERATIONS: usize = 500;
+/// 相邻两次迭代指令的最大变化小于该值时停止
+const TOLERANCE: f64 = 1e-6;
+/// 对比视图中两个反射面中心到原点的距离（场景单位）
+const SIDE_BY_SIDE_OFFSET: f32 = 9.0;
+
+pub struct CorrectionPlugin;
+
+impl Plugin for CorrectionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<CorrectionView>()
+            .init_resource::<CorrectionSolver>()
+            .init_resource::<CorrectionSettings>()
+            .init_resource::<ActuatorCorrection>()
+            .add_systems(
+                Update,
+                (
+                    update_actuator_correction
+                        .in_set(HeightSet::Analysis)
+                        .run_if(
+                            resource_changed::<ActuatorHeights>
+                                .or(resource_changed::<CorrectionSettings>),
+                        ),
+                    update_correction_text.run_if(resource_changed::<ActuatorCorrection>),
+                ),
+            )
+            .add_systems(OnEnter(CorrectionView::Enable), spawn_corrected_reflector)
+            .add_systems(OnExit(CorrectionView::Enable), despawn_corrected_reflector)
+            .add_systems(
+                Update,
+                (sync_corrected_material, upload_corrected_heights)
+                    .after(HeightSet::Upload)
+                    .run_if(in_state(CorrectionView::Enable)),
+            );
+    }
+}
+
+/// 校正前后并排对比
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash, States)]
+pub enum CorrectionView {
+    Enable,
+    #[default]
+    Disable,
+}
+
+impl fmt::Display for CorrectionView {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CorrectionView::Enable => write!(f, "显示"),
+            CorrectionView::Disable => write!(f, "隐藏"),
+        }
+    }
+}
+
+/// 求解参数，可通过 `--stroke` 与 `--smoothness` 指定初值
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct CorrectionSettings {
+    /// 单向行程限制，与高度同单位
+    pub stroke: f32,
+    /// 平滑正则化系数 λ，0 表示不做平滑
+    pub smoothness: f32,
+}
+
+impl FromWorld for CorrectionSettings {
+    fn from_world(world: &mut World) -> Self {
+        let cli = world.get_resource::<CliArgs>();
+        CorrectionSettings {
+            stroke: cli.and_then(|cli| cli.stroke).unwrap_or(DEFAULT_STROKE),
+            smoothness: cli.and_then(|cli| cli.smoothness).unwrap_or(0.0),
+        }
+    }
+}
+
+/// 最近一次求解的结果
+#[derive(Resource, Debug, Default, Clone)]
+pub struct ActuatorCorrection {
+    /// 按促动器编号存储的指令
+    pub commands: Vec<f32>,
+    /// 施加指令后的高度
+    pub corrected: Vec<f32>,
+    /// 校正前后的面积加权均方根
+    pub rms_before: f32,
+    pub rms_after: f32,
+    /// 达到行程限制的促动器数
+    pub saturated: usize,
+    pub iterations: usize,
+}
+
+/// 求解所需的权重与相邻关系，几何不变，启动时计算一次
+#[derive(Resource)]
+pub struct CorrectionSolver {
+    /// 归一化到均值为 1 的面积权重
+    weights: Vec<f64>,
+    /// 每个促动器在面板边上相邻的促动器
+    neighbours: Vec<Vec<usize>>,
+}
+
+impl FromWorld for CorrectionSolver {
+    fn from_world(world: &mut World) -> Self {
+        CorrectionSolver::new(world.resource::<ActuatorTopology>())
+    }
+}
+
+impl CorrectionSolver {
+    pub fn new(topology: &ActuatorTopology) -> Self {
+        let count = topology.actuator_count();
+        let mean = topology.areas().iter().map(|&a| a as f64).sum::<f64>() / count.max(1) as f64;
+        let weights = topology
+            .areas()
+            .iter()
+            .map(|&a| if mean > 0.0 { a as f64 / mean } else { 1.0 })
+            .collect();
+
+        // 面板角点按 0-1-2-3 依次相连
+        let mut neighbours = vec![vec![]; count];
+        for corners in topology.vertex_actuators().chunks_exact(4) {
+            for k in 0..4 {
+                let (a, b) = (corners[k] as usize, corners[(k + 1) % 4] as usize);
+                if a != b && !neighbours[a].contains(&b) {
+                    neighbours[a].push(b);
+                    neighbours[b].push(a);
+                }
+            }
+        }
+        CorrectionSolver {
+            weights,
+            neighbours,
+        }
+    }
+
+    /// 以 `commands` 为初值求解并写回，返回迭代次数
+    pub fn solve(
+        &self,
+        heights: &[f32],
+        settings: &CorrectionSettings,
+        commands: &mut Vec<f32>,
+    ) -> usize {
+        let stroke = settings.stroke.max(0.0) as f64;
+        let lambda = settings.smoothness.max(0.0) as f64;
+        commands.resize(heights.len(), 0.0);
+
+        for iteration in 1..=MAX_ITERATIONS {
+            let mut max_change: f64 = 0.0;
+            for (i, &h) in heights.iter().enumerate() {
+                let w = self.weights[i];
+                let neighbours = &self.neighbours[i];
+                let sum = neighbours.iter().map(|&j| commands[j] as f64).sum::<f64>();
+                let denominator = w + lambda * neighbours.len() as f64;
+                let u = if denominator > 0.0 {
+                    (-w * h as f64 + lambda * sum) / denominator
+                } else {
+                    0.0
+                };
+                let u = u.clamp(-stroke, stroke);
+                max_change = max_change.max((u - commands[i] as f64).abs());
+                commands[i] = u as f32;
+            }
+            if max_change < TOLERANCE {
+                return iteration;
+            }
+        }
+        MAX_ITERATIONS
+    }
+
+    /// 面积加权均方根
+    pub fn weighted_rms(&self, heights: &[f32]) -> f32 {
+        let (sum, weight) = heights
+            .iter()
+            .zip(&self.weights)
+            .fold((0.0, 0.0), |(s, ws), (&h, &w)| {
+                (s + w * (h as f64).powi(2), ws + w)
+            });
+        if weight > 0.0 {
+            (sum / weight).sqrt() as f32
+        } else {
+            0.0
+        }
+    }
+}
+
+impl ActuatorCorrection {
+    /// 导出指令为 CSV：每行一个促动器，依次为编号、位置与指令
+    pub fn write_csv(&self, path: &Path, topology: &ActuatorTopology) -> io::Result<()> {
+        if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut writer = BufWriter::new(File::create(path)?);
+        writeln!(writer, "actuator,x,y,z,command")?;
+        for (i, (p, u)) in topology.positions().iter().zip(&self.commands).enumerate() {
+            writeln!(writer, "{},{},{},{},{}", i, p.x, p.y, p.z, u)?;
+        }
+        writer.flush()
+    }
+
+    /// 以当前时间命名的默认导出文件
+    pub fn default_path() -> PathBuf {
+        #[cfg(not(target_arch = "wasm32"))]
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        #[cfg(target_arch = "wasm32")]
+        let secs = 0;
+        PathBuf::from(format!("actuator-commands-{}.csv", secs))
+    }
+}
+
+fn update_actuator_correction(
+    heights: Res<ActuatorHeights>,
+    solver: Res<CorrectionSolver>,
+    settings: Res<CorrectionSettings>,
+    mut correction: ResMut<ActuatorCorrection>,
+) {
+    let correction = correction.as_mut();
+    correction.iterations = solver.solve(&heights, &settings, &mut correction.commands);
+    correction.corrected = heights
+        .iter()
+        .zip(&correction.commands)
+        .map(|(h, u)| h + u)
+        .collect();
+    correction.rms_before = solver.weighted_rms(&heights);
+    correction.rms_after = solver.weighted_rms(&correction.corrected);
+    correction.saturated = correction
+        .commands
+        .iter()
+        .filter(|u| u.abs() >= settings.stroke * (1.0 - 1e-4))
+        .count();
+}
+
+/// 对比视图中显示校正后高度的反射面
+#[derive(Component)]
+struct CorrectedReflector;
+
+#[derive(Resource)]
+struct CorrectedMaterialHandle(Handle<CustomMaterial>);
+
+// 复制原反射面的网格，材质除高度缓冲外与原材质一致
+fn spawn_corrected_reflector(
+    mut commands: Commands,
+    mut reflector: Single<(&mut Mounted, &Children), With<Reflector>>,
+    blocks: Query<&Mesh3d, With<Block>>,
+    material_handle: Res<CustomMaterialHandle>,
+    mut materials: ResMut<Assets<CustomMaterial>>,
+    mut buffers: ResMut<Assets<ShaderStorageBuffer>>,
+    correction: Res<ActuatorCorrection>,
+) {
+    let Some(mesh) = reflector
+        .1
+        .iter()
+        .find_map(|&child| blocks.get(child).ok())
+        .cloned()
+    else {
+        return;
+    };
+    let Some(material) = materials.get(&material_handle.0).cloned() else {
+        return;
+    };
+    let buffer = buffers.add(ShaderStorageBuffer::from(correction.corrected.clone()));
+    let handle = materials.add(CustomMaterial { buffer, ..material });
+    commands.insert_resource(CorrectedMaterialHandle(handle.clone()));
+
+    // 两个反射面各自绕平移后的座架原点转动，姿态由天线座系统设置
+    reflector.0.offset.x = -SIDE_BY_SIDE_OFFSET;
+    commands.spawn((
+        CorrectedReflector,
+        mesh,
+        MeshMaterial3d(handle),
+        Transform::default(),
+        Mounted {
+            offset: Vec3::X * SIDE_BY_SIDE_OFFSET,
+        },
+    ));
+}
+
+fn despawn_corrected_reflector(
+    mut commands: Commands,
+    mut reflector: Single<&mut Mounted, With<Reflector>>,
+    corrected: Query<Entity, With<CorrectedReflector>>,
+) {
+    reflector.offset.x = 0.0;
+    for entity in corrected.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    commands.remove_resource::<CorrectedMaterialHandle>();
+}
+
+// 边界、颜色、位移设置与查看的面板跟随原反射面
+fn sync_corrected_material(
+    material_handle: Res<CustomMaterialHandle>,
+    corrected_handle: Option<Res<CorrectedMaterialHandle>>,
+    mut materials: ResMut<Assets<CustomMaterial>>,
+) {
+    let Some(corrected_handle) = corrected_handle else {
+        return;
+    };
+    let Some(source) = materials.get(&material_handle.0).cloned() else {
+        return;
+    };
+    let Some(target) = materials.get(&corrected_handle.0) else {
+        return;
+    };
+    let synced = CustomMaterial {
+        buffer: target.buffer.clone(),
+        ..source
+    };
+    if synced.enable_boundary_render != target.enable_boundary_render
+        || synced.colormap != target.colormap
+        || synced.contour_interval != target.contour_interval
+        || synced.contour_major_every != target.contour_major_every
+        || synced.enable_displacement != target.enable_displacement
+        || synced.displacement_scale != target.displacement_scale
+        || synced.selected_panel != target.selected_panel
+        || synced.height_min != target.height_min
+        || synced.height_max != target.height_max
+    {
+        if let Some(target) = materials.get_mut(&corrected_handle.0) {
+            *target = synced;
+        }
+    }
+}
+
+fn upload_corrected_heights(
+    correction: Res<ActuatorCorrection>,
+    topology: Res<ActuatorTopology>,
+    corrected_handle: Option<Res<CorrectedMaterialHandle>>,
+    materials: Res<Assets<CustomMaterial>>,
+    mut buffers: ResMut<Assets<ShaderStorageBuffer>>,
+) {
+    let Some(corrected_handle) = corrected_handle else {
+        return;
+    };
+    if !correction.is_changed() && !corrected_handle.is_added() {
+        return;
+    }
+    if let Some(buffer) = materials
+        .get(&corrected_handle.0)
+        .and_then(|material| buffers.get_mut(&material.buffer))
+    {
+        let mut corrected = correction.corrected.clone();
+        topology.conform(&mut corrected);
+        buffer.set_data(corrected.as_slice());
+    }
+}
+
+#[derive(Component)]
+pub struct CorrectionText;
+
+pub(super) fn spawn_correction_text(
+    mut commands: Commands,
+    hud: Single<Entity, With<AnalysisHud>>,
+    font: Res<CustomTextFont>,
+) {
+    commands.entity(*hud).with_children(|p| {
+        p.spawn((
+            Text::default(),
+            CorrectionText,
+            hud_text_font(&font),
+            TextColor(WHITE.into()),
+        ));
+    });
+}
+
+fn update_correction_text(
+    correction: Res<ActuatorCorrection>,
+    settings: Res<CorrectionSettings>,
+    mut text: Query<&mut Text, With<CorrectionText>>,
+) {
+    for mut text in text.iter_mut() {
+        text.0 = format!(
+            "促动器校正（行程 ±{:.2} mm，平滑 λ = {}）\nRMS: {:.4} → {:.4} mm\n饱和: {} 个，迭代 {} 次",
+            settings.stroke,
+            settings.smoothness,
+            correction.rms_before,
+            correction.rms_after,
+            correction.saturated,
+            correction.iterations
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::ReflectorGeometry;
+
+    fn solver() -> (ActuatorTopology, CorrectionSolver) {
+        let topology = ActuatorTopology::from_geometry(&ReflectorGeometry::default());
+        let solver = CorrectionSolver::new(&topology);
+        (topology, solver)
+    }
+
+    // 由平滑的指令生成变形，期望解即为该指令
+    fn smooth_commands(topology: &ActuatorTopology) -> Vec<f32> {
+        topology
+            .positions()
+            .iter()
+            .map(|p| 0.3 * (0.4 * p.x).sin() + 0.2 * (0.3 * p.y).cos())
+            .collect()
+    }
+
+    fn assert_all_close(a: &[f32], b: &[f32], tolerance: f32) {
+        for (i, (x, y)) in a.iter().zip(b).enumerate() {
+            assert!((x - y).abs() < tolerance, "促动器 {}: {} != {}", i, x, y);
+        }
+    }
+
+    /// 对每个促动器枚举 {自由, 上限, 下限} 三种状态，在自由的促动器上直接解线性方程组，
+    /// 取满足行程限制且目标函数最小的解。仅用于少量促动器
+    fn enumerate_active_sets(
+        topology: &ActuatorTopology,
+        heights: &[f32],
+        settings: &CorrectionSettings,
+    ) -> Vec<f64> {
+        let n = topology.actuator_count();
+        let (stroke, lambda) = (settings.stroke as f64, settings.smoothness as f64);
+        let mean = topology.areas().iter().map(|&a| a as f64).sum::<f64>() / n as f64;
+        let weights: Vec<f64> = topology.areas().iter().map(|&a| a as f64 / mean).collect();
+        let mut edges = vec![];
+        for corners in topology.vertex_actuators().chunks_exact(4) {
+            for k in 0..4 {
+                let (a, b) = (corners[k] as usize, corners[(k + 1) % 4] as usize);
+                if a != b && !edges.contains(&(a.min(b), a.max(b))) {
+                    edges.push((a.min(b), a.max(b)));
+                }
+            }
+        }
+        // 目标函数 uᵀAu/2 + bᵀu + c 的 A、b
+        let mut a = vec![vec![0.0; n]; n];
+        let mut b = vec![0.0; n];
+        for i in 0..n {
+            a[i][i] = 2.0 * weights[i];
+            b[i] = 2.0 * weights[i] * heights[i] as f64;
+        }
+        for &(i, j) in &edges {
+            a[i][i] += 2.0 * lambda;
+            a[j][j] += 2.0 * lambda;
+            a[i][j] -= 2.0 * lambda;
+            a[j][i] -= 2.0 * lambda;
+        }
+        let objective = |u: &[f64]| {
+            (0..n)
+                .map(|i| weights[i] * (heights[i] as f64 + u[i]).powi(2))
+                .sum::<f64>()
+                + lambda
+                    * edges
+                        .iter()
+                        .map(|&(i, j)| (u[i] - u[j]).powi(2))
+                        .sum::<f64>()
+        };
+
+        let mut best: Option<(f64, Vec<f64>)> = None;
+        for state in 0..3usize.pow(n as u32) {
+            // 0 自由，1 上限，2 下限
+            let mut u = vec![0.0; n];
+            let mut free = vec![];
+            let mut code = state;
+            for (i, value) in u.iter_mut().enumerate() {
+                match code % 3 {
+                    0 => free.push(i),
+                    1 => *value = stroke,
+                    _ => *value = -stroke,
+                }
+                code /= 3;
+            }
+            // 高斯消元求解 A_ff u_f = -b_f - A_fs u_s
+            let m = free.len();
+            let mut system: Vec<Vec<f64>> = free
+                .iter()
+                .map(|&i| {
+                    let mut row: Vec<f64> = free.iter().map(|&j| a[i][j]).collect();
+                    let fixed = (0..n)
+                        .filter(|j| !free.contains(j))
+                        .map(|j| a[i][j] * u[j])
+                        .sum::<f64>();
+                    row.push(-b[i] - fixed);
+                    row
+                })
+                .collect();
+            for col in 0..m {
+                let pivot = (col..m)
+                    .max_by(|&x, &y| system[x][col].abs().total_cmp(&system[y][col].abs()))
+                    .unwrap();
+                system.swap(col, pivot);
+                for row in 0..m {
+                    if row != col {
+                        let factor = system[row][col] / system[col][col];
+                        for k in col..=m {
+                            system[row][k] -= factor * system[col][k];
+                        }
+                    }
+                }
+            }
+            for (k, &i) in free.iter().enumerate() {
+                u[i] = system[k][m] / system[k][k];
+            }
+            if u.iter().any(|x| x.abs() > stroke + 1e-9) {
+                continue;
+            }
+            let value = objective(&u);
+            if best.as_ref().is_none_or(|(v, _)| value < *v) {
+                best = Some((value, u));
+            }
+        }
+        best.unwrap().1
+    }
+
+    #[test]
+    fn matches_active_set_enumeration() {
+        // 两个圆环各 3 块面板，共 9 个促动器，可逐一枚举约束状态
+        let geometry = ReflectorGeometry::builder().ring_blocks([3, 3]).build();
+        let topology = ActuatorTopology::from_geometry(&geometry);
+        assert_eq!(topology.actuator_count(), 9);
+        let solver = CorrectionSolver::new(&topology);
+
+        let heights = [0.9, -0.2, 0.1, -0.8, 0.3, 0.05, 0.6, -0.4, 0.0];
+        let settings = CorrectionSettings {
+            stroke: 0.35,
+            smoothness: 0.5,
+        };
+        let mut commands = vec![];
+        let iterations = solver.solve(&heights, &settings, &mut commands);
+        assert!(iterations < MAX_ITERATIONS);
+
+        let expected = enumerate_active_sets(&topology, &heights, &settings);
+        let expected = expected.iter().map(|&u| u as f32).collect::<Vec<_>>();
+        assert_all_close(&commands, &expected, 1e-4);
+        // 行程限制与平滑项都起作用：既有饱和的促动器，解也不同于逐个截断
+        assert!(commands.iter().any(|u| u.abs() >= 0.35 - 1e-6));
+        assert!(commands
+            .iter()
+            .zip(&heights)
+            .any(|(u, h)| (u - (-h).clamp(-0.35, 0.35)).abs() > 1e-2));
+    }
+
+    #[test]
+    fn stroke_limits_clip_commands() {
+        let (topology, solver) = solver();
+        let heights = smooth_commands(&topology)
+            .iter()
+            .map(|u| 4.0 * u)
+            .collect::<Vec<_>>();
+        let settings = CorrectionSettings {
+            stroke: 0.5,
+            smoothness: 0.0,
+        };
+        let mut commands = vec![];
+        solver.solve(&heights, &settings, &mut commands);
+        let expected = heights
+            .iter()
+            .map(|h| (-h).clamp(-0.5, 0.5))
+            .collect::<Vec<_>>();
+        assert_all_close(&commands, &expected, 1e-6);
+        assert!(heights.iter().any(|h| h.abs() > 0.5));
+    }
+
+    #[test]
+    fn smoothness_keeps_piston_exact() {
+        // 整体平移可被相同的指令完全抵消，平滑项为零，解不受 λ 影响
+        let (topology, solver) = solver();
+        let heights = vec![0.25; topology.actuator_count()];
+        let settings = CorrectionSettings {
+            stroke: DEFAULT_STROKE,
+            smoothness: 10.0,
+        };
+        let mut commands = vec![];
+        solver.solve(&heights, &settings, &mut commands);
+        assert_all_close(&commands, &vec![-0.25; heights.len()], 1e-4);
+    }
+
+    #[test]
+    fn regularised_solution_is_optimal() {
+        // 检查 KKT 条件：未饱和的促动器梯度为零，饱和的促动器梯度方向指向约束外侧
+        let (topology, solver) = solver();
+        let heights = topology
+            .positions()
+            .iter()
+            .enumerate()
+            .map(|(i, p)| 0.5 * p.x.sin() + if i % 7 == 0 { 0.8 } else { 0.0 })
+            .collect::<Vec<_>>();
+        let settings = CorrectionSettings {
+            stroke: 0.6,
+            smoothness: 1.0,
+        };
+        let mut commands = vec![];
+        let iterations = solver.solve(&heights, &settings, &mut commands);
+        assert!(iterations < MAX_ITERATIONS);
+
+        let lambda = settings.smoothness as f64;
+        for (i, &u) in commands.iter().enumerate() {
+            let u = u as f64;
+            let gradient = solver.weights[i] * (heights[i] as f64 + u)
+                + lambda
+                    * solver.neighbours[i]
+                        .iter()
+                        .map(|&j| u - commands[j] as f64)
+                        .sum::<f64>();
+            if u >= 0.6 - 1e-6 {
+                assert!(gradient <= 1e-3, "促动器 {} 梯度 {}", i, gradient);
+            } else if u <= -0.6 + 1e-6 {
+                assert!(gradient >= -1e-3, "促动器 {} 梯度 {}", i, gradient);
+            } else {
+                assert!(gradient.abs() < 1e-3, "促动器 {} 梯度 {}", i, gradient);
+            }
+        }
+
+        let corrected = heights
+            .iter()
+            .zip(&commands)
+            .map(|(h, u)| h + u)
+            .collect::<Vec<_>>();
+        assert!(solver.weighted_rms(&corrected) < solver.weighted_rms(&heights));
+    }
+}
@@ -0,0 +1,182 @@
+//! 基 2 快速傅里叶变换，供远场方向图计算使用
+
+use std::f64::consts::PI;
+use std::ops::{Add, Mul, Sub};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    pub const ZERO: Complex = Complex { re: 0.0, im: 0.0 };
+
+    pub fn new(re: f64, im: f64) -> Self {
+        Complex { re, im }
+    }
+
+    /// 幅值为 `r`、相位为 `phase` 的复数
+    pub fn from_polar(r: f64, phase: f64) -> Self {
+        Complex::new(r * phase.cos(), r * phase.sin())
+    }
+
+    pub fn norm_sqr(self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, rhs: Complex) -> Complex {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+
+    fn sub(self, rhs: Complex) -> Complex {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, rhs: Complex) -> Complex {
+        Complex::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+/// 原地一维正变换，长度必须为 2 的幂
+pub fn fft(data: &mut [Complex]) {
+    let n = data.len();
+    assert!(n.is_power_of_two(), "FFT 长度 {} 不是 2 的幂", n);
+
+    // 位反转重排
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let step = Complex::from_polar(1.0, -2.0 * PI / len as f64);
+        for start in (0..n).step_by(len) {
+            let mut w = Complex::new(1.0, 0.0);
+            for k in 0..len / 2 {
+                let a = data[start + k];
+                let b = data[start + k + len / 2] * w;
+                data[start + k] = a + b;
+                data[start + k + len / 2] = a - b;
+                w = w * step;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+/// 原地二维正变换，`data` 按行存储 `n × n` 个元素
+pub fn fft2(data: &mut [Complex], n: usize) {
+    assert_eq!(data.len(), n * n);
+    for row in data.chunks_exact_mut(n) {
+        fft(row);
+    }
+    let mut column = vec![Complex::ZERO; n];
+    for x in 0..n {
+        for y in 0..n {
+            column[y] = data[y * n + x];
+        }
+        fft(&mut column);
+        for y in 0..n {
+            data[y * n + x] = column[y];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn conjugate(data: &mut [Complex]) {
+        for value in data.iter_mut() {
+            value.im = -value.im;
+        }
+    }
+
+    // 逆变换：ifft(x) = conj(fft(conj(x))) / n
+    fn inverse(data: &mut [Complex]) {
+        conjugate(data);
+        fft(data);
+        conjugate(data);
+        let n = data.len() as f64;
+        for value in data.iter_mut() {
+            *value = Complex::new(value.re / n, value.im / n);
+        }
+    }
+
+    fn assert_close(a: Complex, b: Complex) {
+        assert!(
+            (a.re - b.re).abs() < 1e-9 && (a.im - b.im).abs() < 1e-9,
+            "{:?} != {:?}",
+            a,
+            b
+        );
+    }
+
+    #[test]
+    fn delta_has_flat_spectrum() {
+        let mut data = vec![Complex::ZERO; 16];
+        data[0] = Complex::new(1.0, 0.0);
+        fft(&mut data);
+        for value in data {
+            assert_close(value, Complex::new(1.0, 0.0));
+        }
+    }
+
+    #[test]
+    fn single_tone_lands_in_its_bin() {
+        let n = 64;
+        let bin = 5;
+        let mut data = (0..n)
+            .map(|i| Complex::from_polar(1.0, 2.0 * PI * (bin * i) as f64 / n as f64))
+            .collect::<Vec<_>>();
+        fft(&mut data);
+        for (k, value) in data.into_iter().enumerate() {
+            let expected = if k == bin { n as f64 } else { 0.0 };
+            assert_close(value, Complex::new(expected, 0.0));
+        }
+    }
+
+    #[test]
+    fn round_trip_restores_input() {
+        let original = (0..32)
+            .map(|i| Complex::new((i as f64 * 0.7).sin(), (i as f64 * 0.3).cos() - 0.5))
+            .collect::<Vec<_>>();
+        let mut data = original.clone();
+        fft(&mut data);
+        inverse(&mut data);
+        for (a, b) in data.into_iter().zip(original) {
+            assert_close(a, b);
+        }
+    }
+
+    #[test]
+    fn fft2_of_constant_is_dc_only() {
+        let n = 8;
+        let mut data = vec![Complex::new(1.0, 0.0); n * n];
+        fft2(&mut data, n);
+        assert_close(data[0], Complex::new((n * n) as f64, 0.0));
+        for value in &data[1..] {
+            assert_close(*value, Complex::ZERO);
+        }
+    }
+}
@@ -0,0 +1,269 @@
+//! 最佳拟合抛物面
+//!
+//! 高度中的整体平移、倾斜与焦距变化可以由副反射面补偿，不属于面形误差。这里以促动器面积为
+//! 权重，对沿法线方向的高度做最小二乘拟合，模型在小变形下线性化为
+//!
+//! ```text
+//! Δz = piston + tilt_x·x + tilt_y·y - r²·Δf / (4f²)
+//! h  = n_z·Δz
+//! ```
+//!
+//! 其中 `n_z` 为法线的 z 分量，用于把轴向偏移换算到法线方向。拟合残差即为面形误差。
+//! 拟合前把场景坐标按口径 [`APERTURE_DIAMETER_METERS`] 换算为米、高度由毫米换算为米，
+//! 因此倾斜为弧度，焦距与焦距变化为米。
+
+use super::{beam::APERTURE_DIAMETER_METERS, hud_text_font, AnalysisHud};
+use crate::data_source::{ActuatorHeights, HeightSet};
+use crate::{actuator::ActuatorTopology, CustomTextFont};
+use bevy::{
+    color::palettes::css::*,
+    math::{DMat4, DVec3, DVec4},
+    prelude::*,
+};
+
+pub struct FitPlugin;
+
+impl Plugin for FitPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FitBasis>()
+            .init_resource::<ParaboloidFit>()
+            .init_resource::<ResidualHeights>()
+            .add_systems(
+                Update,
+                (
+                    update_paraboloid_fit
+                        .in_set(HeightSet::Analysis)
+                        .run_if(resource_changed::<ActuatorHeights>),
+                    update_fit_text.run_if(resource_changed::<ParaboloidFit>),
+                ),
+            );
+    }
+}
+
+/// 拟合得到的抛物面参数
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq)]
+pub struct ParaboloidFit {
+    /// 顶点沿轴向的平移（毫米）
+    pub piston: f32,
+    /// 绕 y 轴的倾斜，即 x 方向的斜率（弧度）
+    pub tilt_x: f32,
+    /// 绕 x 轴的倾斜，即 y 方向的斜率（弧度）
+    pub tilt_y: f32,
+    /// 焦距变化（毫米）
+    pub focal_change: f32,
+    /// 由几何估计的名义焦距（米）
+    pub focal_length: f32,
+}
+
+/// 去除最佳拟合抛物面后的高度，按促动器编号存储
+#[derive(Resource, Debug, Default, Clone, Deref, DerefMut)]
+pub struct ResidualHeights(pub Vec<f32>);
+
+/// 各促动器处的拟合基函数，几何不变，启动时计算一次
+#[derive(Resource)]
+pub(super) struct FitBasis {
+    /// 每个促动器的 n_z·[1, x, y, r²]，坐标单位为米
+    basis: Vec<DVec4>,
+    weights: Vec<f64>,
+    /// 加权法方程矩阵的逆，奇异时为 None
+    normal_inverse: Option<DMat4>,
+    focal_length: f64,
+}
+
+impl FromWorld for FitBasis {
+    fn from_world(world: &mut World) -> Self {
+        FitBasis::new(world.resource::<ActuatorTopology>())
+    }
+}
+
+impl FitBasis {
+    fn new(topology: &ActuatorTopology) -> Self {
+        let positions = positions_in_meters(topology.positions());
+        let normal_z = topology.normals().iter().map(|n| n.z as f64);
+        let weights = topology.areas().iter().map(|&a| a as f64).collect();
+        FitBasis::from_points(&positions, normal_z, weights)
+    }
+
+    fn from_points(
+        positions: &[DVec3],
+        normal_z: impl IntoIterator<Item = f64>,
+        weights: Vec<f64>,
+    ) -> Self {
+        let basis = positions
+            .iter()
+            .zip(normal_z)
+            .map(|(p, n_z)| DVec4::new(1.0, p.x, p.y, p.x * p.x + p.y * p.y) * n_z)
+            .collect::<Vec<_>>();
+
+        let mut normal = DMat4::ZERO;
+        for (b, &w) in basis.iter().zip(&weights) {
+            normal += DMat4::from_cols(*b * b.x, *b * b.y, *b * b.z, *b * b.w) * w;
+        }
+        let normal_inverse = (normal.determinant().abs() > f64::EPSILON).then(|| normal.inverse());
+
+        FitBasis {
+            basis,
+            weights,
+            normal_inverse,
+            focal_length: estimate_focal_length(positions),
+        }
+    }
+
+    /// 拟合毫米为单位的高度，返回拟合参数与毫米为单位的残差
+    fn fit(&self, heights: &[f32]) -> (ParaboloidFit, Vec<f32>) {
+        let coefficients = match self.normal_inverse {
+            Some(inverse) if heights.len() == self.basis.len() => {
+                let rhs = heights
+                    .iter()
+                    .zip(&self.basis)
+                    .zip(&self.weights)
+                    .fold(DVec4::ZERO, |acc, ((&h, b), &w)| {
+                        acc + *b * (w * h as f64 * 1e-3)
+                    });
+                inverse * rhs
+            }
+            _ => DVec4::ZERO,
+        };
+
+        let residual = heights
+            .iter()
+            .zip(&self.basis)
+            .map(|(&h, b)| h - (b.dot(coefficients) * 1e3) as f32)
+            .collect();
+
+        let f = self.focal_length;
+        let fit = ParaboloidFit {
+            piston: (coefficients.x * 1e3) as f32,
+            tilt_x: coefficients.y as f32,
+            tilt_y: coefficients.z as f32,
+            focal_change: (-4.0 * f * f * coefficients.w * 1e3) as f32,
+            focal_length: f as f32,
+        };
+        (fit, residual)
+    }
+}
+
+/// 按口径直径把场景坐标换算为米
+fn positions_in_meters(positions: &[Vec3]) -> Vec<DVec3> {
+    let outer = positions
+        .iter()
+        .map(|p| p.truncate().length())
+        .fold(0.0, f32::max);
+    let meters = if outer > 0.0 {
+        APERTURE_DIAMETER_METERS / (2.0 * outer as f64)
+    } else {
+        1.0
+    };
+    positions.iter().map(|p| p.as_dvec3() * meters).collect()
+}
+
+/// 按 z = z0 + r²/(4f) 对促动器位置做最小二乘，估计名义焦距
+fn estimate_focal_length(positions: &[DVec3]) -> f64 {
+    let n = positions.len() as f64;
+    let (mut s_u, mut s_uu, mut s_z, mut s_uz) = (0.0, 0.0, 0.0, 0.0);
+    for p in positions {
+        let u = p.x * p.x + p.y * p.y;
+        let z = p.z;
+        s_u += u;
+        s_uu += u * u;
+        s_z += z;
+        s_uz += u * z;
+    }
+    let slope = (n * s_uz - s_u * s_z) / (n * s_uu - s_u * s_u);
+    if slope.is_finite() && slope != 0.0 {
+        1.0 / (4.0 * slope)
+    } else {
+        0.0
+    }
+}
+
+pub(super) fn update_paraboloid_fit(
+    heights: Res<ActuatorHeights>,
+    basis: Res<FitBasis>,
+    mut fit: ResMut<ParaboloidFit>,
+    mut residual: ResMut<ResidualHeights>,
+) {
+    let (next, next_residual) = basis.fit(&heights);
+    residual.0 = next_residual;
+    fit.set_if_neq(next);
+}
+
+#[derive(Component)]
+pub struct FitText;
+
+pub(super) fn spawn_fit_text(
+    mut commands: Commands,
+    hud: Single<Entity, With<AnalysisHud>>,
+    font: Res<CustomTextFont>,
+) {
+    commands.entity(*hud).with_children(|p| {
+        p.spawn((
+            Text::default(),
+            FitText,
+            hud_text_font(&font),
+            TextColor(WHITE.into()),
+        ));
+    });
+}
+
+fn update_fit_text(fit: Res<ParaboloidFit>, mut text: Query<&mut Text, With<FitText>>) {
+    for mut text in text.iter_mut() {
+        text.0 = format!(
+            "最佳拟合抛物面\n轴向平移: {:.4} mm\n倾斜 X: {:.3} mrad\n倾斜 Y: {:.3} mrad\n焦距变化: {:.4} mm (f = {:.3} m)",
+            fit.piston,
+            fit.tilt_x * 1e3,
+            fit.tilt_y * 1e3,
+            fit.focal_change,
+            fit.focal_length
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::ReflectorGeometry;
+
+    // 由已知参数按线性化模型生成高度，拟合应原样得到这些参数且残差为零
+    #[test]
+    fn recovers_tilt_and_defocus() {
+        let topology = ActuatorTopology::from_geometry(&ReflectorGeometry::default());
+        let basis = FitBasis::new(&topology);
+        let f = basis.focal_length;
+        assert!(f > 0.0, "焦距 {}", f);
+
+        let (piston, tilt_x, tilt_y, focal_change) = (0.05e-3, 2e-4, -1e-4, 3e-3);
+        let heights = positions_in_meters(topology.positions())
+            .iter()
+            .zip(topology.normals())
+            .map(|(p, n)| {
+                let r2 = p.x * p.x + p.y * p.y;
+                let dz = piston + tilt_x * p.x + tilt_y * p.y - r2 * focal_change / (4.0 * f * f);
+                (n.z as f64 * dz * 1e3) as f32
+            })
+            .collect::<Vec<_>>();
+
+        let (fit, residual) = basis.fit(&heights);
+        assert!((fit.piston - 0.05).abs() < 1e-4, "平移 {}", fit.piston);
+        assert!((fit.tilt_x - 2e-4).abs() < 1e-7, "倾斜 X {}", fit.tilt_x);
+        assert!((fit.tilt_y + 1e-4).abs() < 1e-7, "倾斜 Y {}", fit.tilt_y);
+        assert!(
+            (fit.focal_change - 3.0).abs() < 1e-3,
+            "焦距变化 {}",
+            fit.focal_change
+        );
+        assert!(residual.iter().all(|r| r.abs() < 1e-4));
+    }
+
+    // 理想抛物面上的名义焦距按米计
+    #[test]
+    fn estimates_focal_length_in_meters() {
+        let positions = (0..100)
+            .map(|i| {
+                let (x, y) = ((i % 10) as f64 * 5.0 - 22.5, (i / 10) as f64 * 5.0 - 22.5);
+                DVec3::new(x, y, (x * x + y * y) / (4.0 * 33.0))
+            })
+            .collect::<Vec<_>>();
+        assert!((estimate_focal_length(&positions) - 33.0).abs() < 1e-9);
+    }
+}
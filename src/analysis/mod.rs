@@ -0,0 +1,146 @@
+//! 反射面分析
+//!
+//! 分析系统在 [`HeightSet::Analysis`] 中读取数据源写入的 [`ActuatorHeights`]，计算结果以资源
+//! 形式提供给其它系统，并显示在右侧的分析面板中。反射面上显示的高度由 [`HeightDisplay`]
+//! 选择，写入 [`DisplayedHeights`]，统计量与方向图也按显示的高度计算。
+//!
+//! [`HeightSet::Analysis`]: crate::data_source::HeightSet::Analysis
+//! [`ActuatorHeights`]: crate::data_source::ActuatorHeights
+
+pub mod beam;
+pub mod correction;
+mod fft;
+pub mod fit;
+pub mod ruze;
+pub mod statistics;
+pub mod zernike;
+
+use crate::data_source::{ActuatorHeights, HeightSet};
+use crate::gravity::LutCorrection;
+use crate::CustomTextFont;
+use bevy::{color::palettes::css::*, prelude::*, text::FontSmoothing};
+use fit::ResidualHeights;
+use std::fmt::{self, Formatter};
+use zernike::ZernikeDecomposition;
+
+pub struct AnalysisPlugin;
+
+impl Plugin for AnalysisPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<HeightDisplay>()
+            .init_resource::<DisplayedHeights>()
+            .add_systems(
+                Update,
+                update_displayed_heights
+                    .in_set(HeightSet::Analysis)
+                    .after(fit::update_paraboloid_fit)
+                    .after(zernike::update_zernike_decomposition)
+                    .after(crate::gravity::update_lut_correction),
+            )
+            .add_systems(
+                PostStartup,
+                (
+                    setup_analysis_hud,
+                    statistics::spawn_statistics_text,
+                    fit::spawn_fit_text,
+                    ruze::spawn_efficiency_text,
+                    beam::spawn_beam_labels,
+                    zernike::spawn_zernike_panel,
+                    correction::spawn_correction_text,
+                )
+                    .chain(),
+            )
+            .add_plugins((
+                statistics::StatisticsPlugin,
+                fit::FitPlugin,
+                ruze::RuzePlugin,
+                beam::BeamPlugin,
+                zernike::ZernikePlugin,
+                correction::CorrectionPlugin,
+            ));
+    }
+}
+
+/// 反射面显示的高度
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash, States)]
+pub enum HeightDisplay {
+    #[default]
+    Raw,
+    // 去除最佳拟合抛物面后的残差
+    Residual,
+    // 所选 Zernike 模式的重建
+    ZernikeReconstruction,
+    // 去除所选 Zernike 模式后的残差
+    ZernikeResidual,
+    // 当前仰角下查找表给出的指令
+    LutCorrection,
+    // 施加查找表指令后的高度
+    LutCorrected,
+}
+
+impl fmt::Display for HeightDisplay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HeightDisplay::Raw => write!(f, "原始"),
+            HeightDisplay::Residual => write!(f, "残差"),
+            HeightDisplay::ZernikeReconstruction => write!(f, "Zernike 重建"),
+            HeightDisplay::ZernikeResidual => write!(f, "Zernike 残差"),
+            HeightDisplay::LutCorrection => write!(f, "LUT 校正"),
+            HeightDisplay::LutCorrected => write!(f, "LUT 校正后"),
+        }
+    }
+}
+
+/// 按 [`HeightDisplay`] 选出的高度，按促动器编号存储，上传到 GPU 并用于统计
+#[derive(Resource, Debug, Default, Clone, Deref)]
+pub struct DisplayedHeights(pub Vec<f32>);
+
+fn update_displayed_heights(
+    display: Res<State<HeightDisplay>>,
+    heights: Res<ActuatorHeights>,
+    residual: Res<ResidualHeights>,
+    zernike: Res<ZernikeDecomposition>,
+    lut: Res<LutCorrection>,
+    mut displayed: ResMut<DisplayedHeights>,
+) {
+    let source = match display.get() {
+        HeightDisplay::Raw => &heights.0,
+        HeightDisplay::Residual => &residual.0,
+        HeightDisplay::ZernikeReconstruction => &zernike.reconstruction,
+        HeightDisplay::ZernikeResidual => &zernike.residual,
+        HeightDisplay::LutCorrection => &lut.commands,
+        HeightDisplay::LutCorrected => &lut.corrected,
+    };
+    if displayed.0 != *source {
+        displayed.0.clone_from(source);
+    }
+}
+
+/// 右侧分析面板，各项分析在其中添加自己的文本
+#[derive(Component)]
+pub struct AnalysisHud;
+
+fn setup_analysis_hud(mut commands: Commands) {
+    commands.spawn((
+        AnalysisHud,
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(72.0),
+            right: Val::Px(12.0),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(8.0),
+            padding: UiRect::all(Val::Px(8.0)),
+            ..default()
+        },
+        BackgroundColor(BLACK.with_alpha(0.5).into()),
+    ));
+}
+
+/// 分析面板使用的字体
+pub fn hud_text_font(font: &CustomTextFont) -> TextFont {
+    TextFont {
+        font: font.0.clone(),
+        font_size: 16.0,
+        font_smoothing: FontSmoothing::AntiAliased,
+    }
+}
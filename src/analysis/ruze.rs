@@ -0,0 +1,182 @@
+//! Ruze 公式口径效率
+//!
+//! 面形误差为 ε（沿法线方向的 RMS）时，波长 λ 处的口径效率因子为
+//!
+//! ```text
+//! η = exp(-(4πε/λ)²)
+//! ```
+//!
+//! ε 取 [`SurfaceStatistics::weighted_rms`]，因此随原始/残差显示切换。高度缓冲以毫米计。
+
+use super::statistics::{self, SurfaceStatistics};
+use super::{hud_text_font, AnalysisHud};
+use crate::{cli::CliArgs, data_source::HeightSet, CustomTextFont};
+use bevy::{color::palettes::css::*, prelude::*};
+use std::f64::consts::PI;
+
+const SPEED_OF_LIGHT: f64 = 299_792_458.0;
+/// 高度缓冲中一个单位对应的长度（米）
+pub const HEIGHT_UNIT_METERS: f64 = 1e-3;
+/// 未指定 `--frequencies` 时显示的观测频率（GHz）
+pub const DEFAULT_FREQUENCIES_GHZ: [f64; 4] = [1.0, 10.0, 43.0, 115.0];
+
+pub struct RuzePlugin;
+
+impl Plugin for RuzePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ObservingFrequencies>()
+            .init_resource::<ApertureEfficiency>()
+            .add_systems(
+                Update,
+                (
+                    update_aperture_efficiency
+                        .in_set(HeightSet::Analysis)
+                        .after(statistics::update_surface_statistics)
+                        .run_if(
+                            resource_changed::<SurfaceStatistics>
+                                .or(resource_changed::<ObservingFrequencies>),
+                        ),
+                    update_efficiency_text.run_if(resource_changed::<ApertureEfficiency>),
+                )
+                    .chain(),
+            );
+    }
+}
+
+pub fn wavelength(frequency_ghz: f64) -> f64 {
+    SPEED_OF_LIGHT / (frequency_ghz * 1e9)
+}
+
+/// 面形 RMS 为 `rms`、波长为 `wavelength`（同一长度单位）时的口径效率因子
+pub fn ruze_efficiency(rms: f64, wavelength: f64) -> f64 {
+    (-(4.0 * PI * rms / wavelength).powi(2)).exp()
+}
+
+/// 效率因子对应的增益损失（dB，正值表示损失）
+pub fn gain_loss_db(efficiency: f64) -> f64 {
+    -10.0 * efficiency.log10()
+}
+
+/// 需要计算效率的观测频率（GHz），可通过 `--frequencies` 指定
+#[derive(Resource, Debug, Clone, Deref, DerefMut)]
+pub struct ObservingFrequencies(pub Vec<f64>);
+
+impl FromWorld for ObservingFrequencies {
+    fn from_world(world: &mut World) -> Self {
+        let frequencies = world
+            .get_resource::<CliArgs>()
+            .and_then(|cli| cli.frequencies.clone())
+            .unwrap_or_else(|| DEFAULT_FREQUENCIES_GHZ.to_vec());
+        ObservingFrequencies(frequencies)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrequencyEfficiency {
+    pub frequency_ghz: f64,
+    pub efficiency: f64,
+    pub gain_loss_db: f64,
+}
+
+/// 各观测频率下的口径效率，与 [`ObservingFrequencies`] 一一对应
+#[derive(Resource, Debug, Default, Clone, Deref)]
+pub struct ApertureEfficiency(pub Vec<FrequencyEfficiency>);
+
+fn update_aperture_efficiency(
+    statistics: Res<SurfaceStatistics>,
+    frequencies: Res<ObservingFrequencies>,
+    mut efficiency: ResMut<ApertureEfficiency>,
+) {
+    let rms = statistics.weighted_rms as f64 * HEIGHT_UNIT_METERS;
+    efficiency.0 = frequencies
+        .iter()
+        .map(|&frequency_ghz| {
+            let eta = ruze_efficiency(rms, wavelength(frequency_ghz));
+            FrequencyEfficiency {
+                frequency_ghz,
+                efficiency: eta,
+                gain_loss_db: gain_loss_db(eta),
+            }
+        })
+        .collect();
+}
+
+#[derive(Component)]
+pub struct EfficiencyText;
+
+pub(super) fn spawn_efficiency_text(
+    mut commands: Commands,
+    hud: Single<Entity, With<AnalysisHud>>,
+    font: Res<CustomTextFont>,
+) {
+    commands.entity(*hud).with_children(|p| {
+        p.spawn((
+            Text::default(),
+            EfficiencyText,
+            hud_text_font(&font),
+            TextColor(WHITE.into()),
+        ));
+    });
+}
+
+fn update_efficiency_text(
+    efficiency: Res<ApertureEfficiency>,
+    statistics: Res<SurfaceStatistics>,
+    mut text: Query<&mut Text, With<EfficiencyText>>,
+) {
+    let mut label = format!(
+        "Ruze 口径效率 (ε = {:.3} mm)",
+        statistics.weighted_rms as f64 * HEIGHT_UNIT_METERS * 1e3
+    );
+    for item in efficiency.iter() {
+        label.push_str(&format!(
+            "\n{:>6.1} GHz: η = {:.3}, 增益损失 {:.2} dB",
+            item.frequency_ghz, item.efficiency, item.gain_loss_db
+        ));
+    }
+    for mut text in text.iter_mut() {
+        text.0 = label.clone();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64, tolerance: f64) {
+        assert!((a - b).abs() < tolerance, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn perfect_surface() {
+        assert_eq!(ruze_efficiency(0.0, 0.01), 1.0);
+        assert_eq!(gain_loss_db(1.0), 0.0);
+    }
+
+    #[test]
+    fn textbook_fractions_of_wavelength() {
+        // ε = λ/16：η ≈ 0.54，损失约 2.7 dB
+        assert_close(ruze_efficiency(1.0, 16.0), 0.5396, 1e-4);
+        assert_close(gain_loss_db(ruze_efficiency(1.0, 16.0)), 2.679, 1e-3);
+        // ε = λ/20：η ≈ 0.67
+        assert_close(ruze_efficiency(1.0, 20.0), 0.6738, 1e-4);
+        // ε = λ/40：η ≈ 0.91
+        assert_close(ruze_efficiency(1.0, 40.0), 0.9060, 1e-4);
+        // ε = λ/4π：η = 1/e，损失 10·log10(e) ≈ 4.34 dB
+        assert_close(ruze_efficiency(1.0, 4.0 * PI), (-1.0f64).exp(), 1e-12);
+        assert_close(gain_loss_db((-1.0f64).exp()), 4.343, 1e-3);
+    }
+
+    #[test]
+    fn frequency_to_wavelength() {
+        assert_close(wavelength(1.0), 0.2998, 1e-4);
+        assert_close(wavelength(115.0), 2.607e-3, 1e-6);
+    }
+
+    #[test]
+    fn millimetre_rms() {
+        // 0.2 mm RMS 在 43 GHz (λ ≈ 6.97 mm) 下 η ≈ 0.88
+        let eta = ruze_efficiency(0.2e-3, wavelength(43.0));
+        assert_close(eta, 0.878, 1e-3);
+    }
+}
@@ -0,0 +1,219 @@
+//! 面形统计
+//!
+//! 高度即各促动器处相对理想抛物面的偏差，RMS 直接取高度的均方根（不减去均值）。
+//! 面积加权 RMS 以每个促动器代表的面板面积为权重，见 [`ActuatorTopology::areas`]。
+//! 统计量按反射面上显示的高度（[`DisplayedHeights`]）计算。
+
+use super::{hud_text_font, AnalysisHud, DisplayedHeights, HeightDisplay};
+use crate::data_source::HeightSet;
+use crate::{actuator::ActuatorTopology, CustomTextFont};
+use bevy::{color::palettes::css::*, prelude::*};
+
+pub struct StatisticsPlugin;
+
+impl Plugin for StatisticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SurfaceStatistics>().add_systems(
+            Update,
+            (
+                update_surface_statistics
+                    .in_set(HeightSet::Analysis)
+                    .after(super::update_displayed_heights),
+                update_statistics_text.run_if(
+                    resource_changed::<SurfaceStatistics>.or(state_changed::<HeightDisplay>),
+                ),
+            ),
+        );
+    }
+}
+
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq)]
+pub struct SurfaceStatistics {
+    pub rms: f32,
+    pub mean: f32,
+    pub min: f32,
+    pub max: f32,
+    pub peak_to_valley: f32,
+    pub weighted_rms: f32,
+}
+
+impl SurfaceStatistics {
+    /// 按促动器高度与面积权重计算统计量，两者按促动器编号一一对应
+    pub fn compute(heights: &[f32], weights: &[f32]) -> Self {
+        if heights.is_empty() {
+            return SurfaceStatistics::default();
+        }
+        let mut sum = 0.0;
+        let mut sum_sq = 0.0;
+        let mut weighted_sum_sq = 0.0;
+        let mut weight_sum = 0.0;
+        let mut min = f32::INFINITY;
+        let mut max = f32::NEG_INFINITY;
+        for (i, &h) in heights.iter().enumerate() {
+            let h64 = h as f64;
+            sum += h64;
+            sum_sq += h64 * h64;
+            if let Some(&w) = weights.get(i) {
+                weighted_sum_sq += w as f64 * h64 * h64;
+                weight_sum += w as f64;
+            }
+            min = min.min(h);
+            max = max.max(h);
+        }
+        let n = heights.len() as f64;
+        let weighted_rms = if weight_sum > 0.0 {
+            (weighted_sum_sq / weight_sum).sqrt()
+        } else {
+            (sum_sq / n).sqrt()
+        };
+        SurfaceStatistics {
+            rms: (sum_sq / n).sqrt() as f32,
+            mean: (sum / n) as f32,
+            min,
+            max,
+            peak_to_valley: max - min,
+            weighted_rms: weighted_rms as f32,
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct StatisticsText;
+
+pub(super) fn update_surface_statistics(
+    heights: Res<DisplayedHeights>,
+    topology: Res<ActuatorTopology>,
+    mut statistics: ResMut<SurfaceStatistics>,
+) {
+    statistics.set_if_neq(SurfaceStatistics::compute(&heights, topology.areas()));
+}
+
+pub(super) fn spawn_statistics_text(
+    mut commands: Commands,
+    hud: Single<Entity, With<AnalysisHud>>,
+    font: Res<CustomTextFont>,
+) {
+    commands.entity(*hud).with_children(|p| {
+        p.spawn((
+            Text::default(),
+            StatisticsText,
+            hud_text_font(&font),
+            TextColor(WHITE.into()),
+        ));
+    });
+}
+
+fn update_statistics_text(
+    statistics: Res<SurfaceStatistics>,
+    display: Res<State<HeightDisplay>>,
+    mut text: Query<&mut Text, With<StatisticsText>>,
+) {
+    for mut text in text.iter_mut() {
+        text.0 = format!(
+            "面形统计（{}）\nRMS: {:.4}\n面积加权 RMS: {:.4}\n均值: {:.4}\n最小值: {:.4}\n最大值: {:.4}\n峰谷值: {:.4}",
+            display.get(),
+            statistics.rms,
+            statistics.weighted_rms,
+            statistics.mean,
+            statistics.min,
+            statistics.max,
+            statistics.peak_to_valley
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::ReflectorGeometry;
+    use std::f32::consts::TAU;
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-5, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn flat_surface() {
+        let stats = SurfaceStatistics::compute(&[0.0; 16], &[1.0; 16]);
+        assert_eq!(stats, SurfaceStatistics::default());
+    }
+
+    #[test]
+    fn piston_surface() {
+        let stats = SurfaceStatistics::compute(&[0.25; 16], &[1.0; 16]);
+        assert_close(stats.rms, 0.25);
+        assert_close(stats.mean, 0.25);
+        assert_close(stats.weighted_rms, 0.25);
+        assert_close(stats.peak_to_valley, 0.0);
+    }
+
+    #[test]
+    fn alternating_surface() {
+        let heights = (0..100)
+            .map(|i| if i % 2 == 0 { 0.5 } else { -0.5 })
+            .collect::<Vec<_>>();
+        let stats = SurfaceStatistics::compute(&heights, &[1.0; 100]);
+        assert_close(stats.rms, 0.5);
+        assert_close(stats.mean, 0.0);
+        assert_close(stats.min, -0.5);
+        assert_close(stats.max, 0.5);
+        assert_close(stats.peak_to_valley, 1.0);
+    }
+
+    #[test]
+    fn sine_surface() {
+        // 整周期均匀采样的正弦，RMS 为振幅的 1/√2
+        let n = 1000;
+        let heights = (0..n)
+            .map(|i| 2.0 * (TAU * i as f32 / n as f32).sin())
+            .collect::<Vec<_>>();
+        let stats = SurfaceStatistics::compute(&heights, &vec![1.0; n]);
+        assert_close(stats.rms, 2.0 / 2f32.sqrt());
+        assert_close(stats.weighted_rms, stats.rms);
+        assert_close(stats.mean, 0.0);
+    }
+
+    #[test]
+    fn area_weights() {
+        let stats = SurfaceStatistics::compute(&[1.0, 3.0], &[3.0, 1.0]);
+        assert_close(stats.rms, 5f32.sqrt());
+        assert_close(stats.weighted_rms, 3f32.sqrt());
+    }
+
+    /// 抛物面 z = r²/4f 在半径 a 与 b 之间的曲面面积：
+    /// ∫ 2πr·√(1 + r²/4f²) dr = (8πf²/3)·[(1 + r²/4f²)^(3/2)]
+    fn paraboloid_annulus_area(focal_length: f64, a: f64, b: f64) -> f64 {
+        let u = |r: f64| (1.0 + r * r / (4.0 * focal_length * focal_length)).powf(1.5);
+        8.0 * std::f64::consts::PI * focal_length * focal_length / 3.0 * (u(b) - u(a))
+    }
+
+    #[test]
+    fn panel_areas_cover_geometry() {
+        // 默认几何：焦距 4、中心孔半径 0.9、口径 12√2
+        let geometry = ReflectorGeometry::default();
+        let topology = ActuatorTopology::from_geometry(&geometry);
+        let outer = 6.0 * std::f64::consts::SQRT_2;
+        let expected = paraboloid_annulus_area(4.0, 0.9, outer);
+        let total =
+            |topology: &ActuatorTopology| topology.areas().iter().map(|&a| a as f64).sum::<f64>();
+        // 平面面板内接于曲面，面积略小，差异主要来自沿圆周的弦与弧
+        let deficit = (expected - total(&topology)) / expected;
+        assert!(deficit > 0.0 && deficit < 2e-3, "{}", deficit);
+
+        // 面板加密后收敛到解析面积
+        let fine = ReflectorGeometry::builder()
+            .ring_blocks(vec![256; 46])
+            .build();
+        let fine_deficit = (expected - total(&ActuatorTopology::from_geometry(&fine))) / expected;
+        assert!(
+            fine_deficit > 0.0 && fine_deficit < deficit / 4.0,
+            "{}",
+            fine_deficit
+        );
+
+        // 外圈面板更大，均匀高度下加权 RMS 仍等于该高度
+        let heights = vec![0.1; topology.actuator_count()];
+        let stats = SurfaceStatistics::compute(&heights, topology.areas());
+        assert_close(stats.weighted_rms, 0.1);
+    }
+}
@@ -0,0 +1,530 @@
+//! Zernike 多项式分解
+//!
+//! 反射面口径为圆环（内圈内边界到外圈外边界），标准 Zernike 多项式在圆环上并不正交。
+//! 这里在各促动器处按 Noll 编号求值前 [`ZERNIKE_MODES`] 项，再以促动器面积为权重做
+//! Gram-Schmidt 正交化，得到圆环上的正交归一基。系数即为高度在各模式上的投影，
+//! 其平方和等于重建面形的均方值。
+//!
+//! 选中的模式用于重建或从高度中去除，并可反过来由输入的系数生成模拟面形。
+
+use super::{hud_text_font, AnalysisHud};
+use crate::data_source::{ActuatorHeights, HeightSet};
+use crate::{actuator::ActuatorTopology, cli::CliArgs, ButtonID, CustomTextFont};
+use bevy::{color::palettes::css::*, prelude::*};
+
+/// 分解使用的模式数（Noll 编号 1..=21，径向阶数至 5）
+pub const ZERNIKE_MODES: usize = 21;
+/// 输入系数每次调整的步长
+const INPUT_STEP: f32 = 0.05;
+/// 柱状图高度（像素）
+const BAR_HEIGHT: f32 = 80.0;
+
+pub struct ZernikePlugin;
+
+impl Plugin for ZernikePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ZernikeBasis>()
+            .init_resource::<ZernikeDecomposition>()
+            .init_resource::<ZernikeSelection>()
+            .init_resource::<ZernikeInput>()
+            .add_systems(
+                Update,
+                (
+                    update_zernike_decomposition
+                        .in_set(HeightSet::Analysis)
+                        .run_if(
+                            resource_changed::<ActuatorHeights>
+                                .or(resource_changed::<ZernikeSelection>),
+                        ),
+                    update_zernike_bars.run_if(
+                        resource_changed::<ZernikeDecomposition>
+                            .or(resource_changed::<ZernikeSelection>),
+                    ),
+                    update_zernike_input_text.run_if(resource_changed::<ZernikeInput>),
+                ),
+            );
+    }
+}
+
+/// Noll 编号对应的径向阶数 n 与角向频率 m，m 为负表示 sin 项
+pub fn noll_to_nm(j: usize) -> (u32, i32) {
+    assert!(j >= 1, "Noll 编号从 1 开始");
+    let mut n = 0;
+    while (n + 1) * (n + 2) / 2 < j {
+        n += 1;
+    }
+    let p = j - n * (n + 1) / 2;
+    let k = n % 2;
+    let m = ((p + k) / 2 * 2 - k) as i32;
+    let m = if m != 0 && j % 2 == 1 { -m } else { m };
+    (n as u32, m)
+}
+
+/// Noll 归一化的圆域 Zernike 多项式，`rho` 为归一化半径
+pub fn zernike(j: usize, rho: f64, theta: f64) -> f64 {
+    let (n, m) = noll_to_nm(j);
+    let ma = m.unsigned_abs();
+    let factorial = |k: u32| (1..=k).map(f64::from).product::<f64>();
+    let radial = (0..=(n - ma) / 2)
+        .map(|k| {
+            let sign = if k % 2 == 0 { 1.0 } else { -1.0 };
+            sign * factorial(n - k)
+                / (factorial(k) * factorial((n + ma) / 2 - k) * factorial((n - ma) / 2 - k))
+                * rho.powi((n - 2 * k) as i32)
+        })
+        .sum::<f64>();
+    let norm = (f64::from(n) + 1.0).sqrt();
+    match m {
+        0 => norm * radial,
+        m if m > 0 => norm * 2f64.sqrt() * radial * (f64::from(m) * theta).cos(),
+        m => norm * 2f64.sqrt() * radial * (f64::from(-m) * theta).sin(),
+    }
+}
+
+/// 各促动器处正交化后的 Zernike 基，几何不变，启动时计算一次
+#[derive(Resource)]
+pub struct ZernikeBasis {
+    /// `modes[j]` 为第 j + 1 项在各促动器处的取值
+    modes: Vec<Vec<f64>>,
+    /// 归一化的面积权重，和为 1
+    weights: Vec<f64>,
+}
+
+impl FromWorld for ZernikeBasis {
+    fn from_world(world: &mut World) -> Self {
+        ZernikeBasis::new(world.resource::<ActuatorTopology>(), ZERNIKE_MODES)
+    }
+}
+
+impl ZernikeBasis {
+    pub fn new(topology: &ActuatorTopology, count: usize) -> Self {
+        let radius = topology
+            .positions()
+            .iter()
+            .map(|p| p.truncate().length())
+            .fold(0.0, f32::max) as f64;
+        let total = topology.areas().iter().map(|&a| a as f64).sum::<f64>();
+        let weights = topology
+            .areas()
+            .iter()
+            .map(|&a| if total > 0.0 { a as f64 / total } else { 0.0 })
+            .collect::<Vec<_>>();
+        let dot = |a: &[f64], b: &[f64]| {
+            a.iter()
+                .zip(b)
+                .zip(&weights)
+                .map(|((x, y), w)| x * y * w)
+                .sum::<f64>()
+        };
+
+        // 修正 Gram-Schmidt 正交化
+        let mut modes: Vec<Vec<f64>> = Vec::with_capacity(count);
+        for j in 1..=count {
+            let mut mode = topology
+                .positions()
+                .iter()
+                .map(|p| {
+                    let rho = p.truncate().length() as f64 / radius;
+                    zernike(j, rho, (p.y as f64).atan2(p.x as f64))
+                })
+                .collect::<Vec<_>>();
+            for previous in &modes {
+                let projection = dot(&mode, previous);
+                mode.iter_mut()
+                    .zip(previous)
+                    .for_each(|(v, p)| *v -= projection * p);
+            }
+            let norm = dot(&mode, &mode).sqrt();
+            if norm > 1e-9 {
+                mode.iter_mut().for_each(|v| *v /= norm);
+            } else {
+                mode.iter_mut().for_each(|v| *v = 0.0);
+            }
+            modes.push(mode);
+        }
+        ZernikeBasis { modes, weights }
+    }
+
+    pub fn mode_count(&self) -> usize {
+        self.modes.len()
+    }
+
+    /// 高度在各模式上的投影系数
+    pub fn decompose(&self, heights: &[f32]) -> Vec<f32> {
+        self.modes
+            .iter()
+            .map(|mode| {
+                mode.iter()
+                    .zip(heights)
+                    .zip(&self.weights)
+                    .map(|((z, &h), w)| z * h as f64 * w)
+                    .sum::<f64>() as f32
+            })
+            .collect()
+    }
+
+    /// 由系数重建高度，`include` 决定每个模式是否参与
+    pub fn reconstruct(&self, coefficients: &[f32], include: impl Fn(usize) -> bool) -> Vec<f32> {
+        let mut heights = vec![0.0; self.weights.len()];
+        for (j, (mode, &c)) in self.modes.iter().zip(coefficients).enumerate() {
+            if !include(j) || c == 0.0 {
+                continue;
+            }
+            heights
+                .iter_mut()
+                .zip(mode)
+                .for_each(|(h, z)| *h += (c as f64 * z) as f32);
+        }
+        heights
+    }
+}
+
+/// 当前高度的 Zernike 分解，以及所选模式的重建与残差
+#[derive(Resource, Debug, Default, Clone)]
+pub struct ZernikeDecomposition {
+    pub coefficients: Vec<f32>,
+    pub reconstruction: Vec<f32>,
+    pub residual: Vec<f32>,
+}
+
+/// 参与重建（或从高度中去除）的模式，下标为 Noll 编号减一，默认全部选中
+#[derive(Resource, Debug, Clone, Deref, DerefMut)]
+pub struct ZernikeSelection(pub Vec<bool>);
+
+impl Default for ZernikeSelection {
+    fn default() -> Self {
+        ZernikeSelection(vec![true; ZERNIKE_MODES])
+    }
+}
+
+/// 生成 Zernike 模拟面形的输入系数，可通过 `--zernike` 指定初值
+#[derive(Resource, Debug, Clone)]
+pub struct ZernikeInput {
+    pub coefficients: Vec<f32>,
+    /// 当前编辑的模式，下标为 Noll 编号减一
+    pub editing: usize,
+}
+
+impl FromWorld for ZernikeInput {
+    fn from_world(world: &mut World) -> Self {
+        let mut coefficients = vec![0.0; ZERNIKE_MODES];
+        let terms = world
+            .get_resource::<CliArgs>()
+            .and_then(|cli| cli.zernike.clone())
+            .unwrap_or_default();
+        for (j, c) in terms {
+            match coefficients.get_mut(j.wrapping_sub(1)) {
+                Some(slot) => *slot = c,
+                None => warn!("忽略超出范围的 Zernike 项 Z{}（1..={}）", j, ZERNIKE_MODES),
+            }
+        }
+        ZernikeInput {
+            coefficients,
+            editing: 3,
+        }
+    }
+}
+
+impl ZernikeInput {
+    /// 输入系数对应的面形
+    pub fn surface(&self, basis: &ZernikeBasis) -> Vec<f32> {
+        basis.reconstruct(&self.coefficients, |_| true)
+    }
+}
+
+pub(super) fn update_zernike_decomposition(
+    heights: Res<ActuatorHeights>,
+    basis: Res<ZernikeBasis>,
+    selection: Res<ZernikeSelection>,
+    mut decomposition: ResMut<ZernikeDecomposition>,
+) {
+    let coefficients = basis.decompose(&heights);
+    let reconstruction = basis.reconstruct(&coefficients, |j| selection[j]);
+    let residual = heights
+        .iter()
+        .zip(&reconstruction)
+        .map(|(h, r)| h - r)
+        .collect();
+    *decomposition = ZernikeDecomposition {
+        coefficients,
+        reconstruction,
+        residual,
+    };
+}
+
+/// 柱状图中的一列，点击切换该模式是否选中
+#[derive(Component)]
+pub struct ZernikeBar(usize);
+
+#[derive(Component)]
+pub struct ZernikeBarFill(usize);
+
+#[derive(Component)]
+pub struct ZernikeTitleText;
+
+#[derive(Component)]
+pub struct ZernikeInputText;
+
+pub(super) fn spawn_zernike_panel(
+    mut commands: Commands,
+    hud: Single<Entity, With<AnalysisHud>>,
+    font: Res<CustomTextFont>,
+    basis: Res<ZernikeBasis>,
+) {
+    let text_font = hud_text_font(&font);
+    let small_font = TextFont {
+        font_size: 10.0,
+        ..text_font.clone()
+    };
+    commands.entity(*hud).with_children(|p| {
+        p.spawn((
+            Text::default(),
+            ZernikeTitleText,
+            text_font.clone(),
+            TextColor(WHITE.into()),
+        ));
+        p.spawn(Node {
+            column_gap: Val::Px(2.0),
+            ..default()
+        })
+        .with_children(|p1| {
+            for j in 0..basis.mode_count() {
+                p1.spawn((
+                    ZernikeBar(j),
+                    Node {
+                        width: Val::Px(12.0),
+                        height: Val::Px(BAR_HEIGHT),
+                        ..default()
+                    },
+                    BackgroundColor(GRAY.with_alpha(0.3).into()),
+                ))
+                .observe(on_zernike_bar_clicked)
+                .with_children(|p2| {
+                    p2.spawn((
+                        ZernikeBarFill(j),
+                        Node {
+                            position_type: PositionType::Absolute,
+                            width: Val::Percent(100.0),
+                            top: Val::Percent(50.0),
+                            height: Val::Percent(0.0),
+                            ..default()
+                        },
+                        BackgroundColor(RED.into()),
+                    ));
+                    p2.spawn((
+                        Text::new(format!("{}", j + 1)),
+                        small_font.clone(),
+                        TextColor(WHITE.into()),
+                        Node {
+                            position_type: PositionType::Absolute,
+                            bottom: Val::Px(0.0),
+                            ..default()
+                        },
+                    ));
+                });
+            }
+        });
+        p.spawn(Node {
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(4.0),
+            ..default()
+        })
+        .with_children(|p1| {
+            for (label, id) in [
+                ("<", ButtonID::SwitchZernikeModePrev),
+                (">", ButtonID::SwitchZernikeModeNext),
+                ("-", ButtonID::SwitchZernikeValueDecrease),
+                ("+", ButtonID::SwitchZernikeValueIncrease),
+                ("清零", ButtonID::SwitchZernikeReset),
+            ] {
+                crate::spawn_button(
+                    p1,
+                    label,
+                    text_font.clone(),
+                    id,
+                    get_switch_zernike_input_fn(id),
+                );
+            }
+            p1.spawn((
+                Text::default(),
+                ZernikeInputText,
+                text_font.clone(),
+                TextColor(WHITE.into()),
+            ));
+        });
+    });
+}
+
+fn on_zernike_bar_clicked(
+    trigger: Trigger<Pointer<Down>>,
+    bars: Query<&ZernikeBar>,
+    mut selection: ResMut<ZernikeSelection>,
+) {
+    if let Ok(bar) = bars.get(trigger.entity()) {
+        if let Some(selected) = selection.get_mut(bar.0) {
+            *selected = !*selected;
+        }
+    }
+}
+
+fn get_switch_zernike_input_fn(
+    typ: ButtonID,
+) -> impl FnMut(Trigger<Pointer<Down>>, ResMut<ZernikeInput>) {
+    move |_trigger: Trigger<Pointer<Down>>, mut input: ResMut<ZernikeInput>| {
+        let count = input.coefficients.len();
+        let editing = input.editing;
+        match typ {
+            ButtonID::SwitchZernikeModePrev => input.editing = (editing + count - 1) % count,
+            ButtonID::SwitchZernikeModeNext => input.editing = (editing + 1) % count,
+            ButtonID::SwitchZernikeValueDecrease => input.coefficients[editing] -= INPUT_STEP,
+            ButtonID::SwitchZernikeValueIncrease => input.coefficients[editing] += INPUT_STEP,
+            ButtonID::SwitchZernikeReset => input.coefficients.fill(0.0),
+            _ => {}
+        }
+    }
+}
+
+// 柱高按当前最大系数绝对值归一化，正值向上为红，负值向下为蓝
+fn update_zernike_bars(
+    decomposition: Res<ZernikeDecomposition>,
+    selection: Res<ZernikeSelection>,
+    mut fills: Query<(&ZernikeBarFill, &mut Node, &mut BackgroundColor)>,
+    mut bars: Query<(&ZernikeBar, &mut BackgroundColor), Without<ZernikeBarFill>>,
+    mut title: Query<&mut Text, With<ZernikeTitleText>>,
+) {
+    let scale = decomposition
+        .coefficients
+        .iter()
+        .fold(0.0, |max: f32, c| max.max(c.abs()));
+    for (fill, mut node, mut color) in fills.iter_mut() {
+        let c = decomposition
+            .coefficients
+            .get(fill.0)
+            .copied()
+            .unwrap_or(0.0);
+        let fraction = if scale > 0.0 {
+            c.abs() / scale * 50.0
+        } else {
+            0.0
+        };
+        node.height = Val::Percent(fraction);
+        node.top = Val::Percent(if c >= 0.0 { 50.0 - fraction } else { 50.0 });
+        *color = if c >= 0.0 {
+            RED.into()
+        } else {
+            DODGER_BLUE.into()
+        };
+    }
+    for (bar, mut color) in bars.iter_mut() {
+        let selected = selection.get(bar.0).copied().unwrap_or(false);
+        *color = if selected {
+            GRAY.with_alpha(0.6).into()
+        } else {
+            GRAY.with_alpha(0.15).into()
+        };
+    }
+    for mut text in title.iter_mut() {
+        text.0 = format!(
+            "Zernike 系数 (Z1..Z{}, 最大 |c| = {:.4})",
+            decomposition.coefficients.len(),
+            scale
+        );
+    }
+}
+
+fn update_zernike_input_text(
+    input: Res<ZernikeInput>,
+    mut text: Query<&mut Text, With<ZernikeInputText>>,
+) {
+    let (n, m) = noll_to_nm(input.editing + 1);
+    for mut text in text.iter_mut() {
+        text.0 = format!(
+            "输入 Z{} (n={}, m={}) = {:.2}",
+            input.editing + 1,
+            n,
+            m,
+            input.coefficients[input.editing]
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::ReflectorGeometry;
+
+    fn basis() -> ZernikeBasis {
+        let topology = ActuatorTopology::from_geometry(&ReflectorGeometry::default());
+        ZernikeBasis::new(&topology, ZERNIKE_MODES)
+    }
+
+    #[test]
+    fn noll_indices() {
+        let expected = [
+            (0, 0),
+            (1, 1),
+            (1, -1),
+            (2, 0),
+            (2, -2),
+            (2, 2),
+            (3, -1),
+            (3, 1),
+            (3, -3),
+            (3, 3),
+            (4, 0),
+            (4, 2),
+            (4, -2),
+            (4, 4),
+            (4, -4),
+        ];
+        for (j, nm) in expected.into_iter().enumerate() {
+            assert_eq!(noll_to_nm(j + 1), nm, "Noll {}", j + 1);
+        }
+        // 径向阶数 5 的最后一项
+        assert_eq!(noll_to_nm(ZERNIKE_MODES), (5, -5));
+    }
+
+    #[test]
+    fn basis_is_orthonormal_under_area_weights() {
+        let basis = basis();
+        assert_eq!(basis.mode_count(), ZERNIKE_MODES);
+        assert!((basis.weights.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        for (i, a) in basis.modes.iter().enumerate() {
+            for (j, b) in basis.modes.iter().enumerate() {
+                let dot = a
+                    .iter()
+                    .zip(b)
+                    .zip(&basis.weights)
+                    .map(|((x, y), w)| x * y * w)
+                    .sum::<f64>();
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((dot - expected).abs() < 1e-9, "<{}, {}> = {}", i, j, dot);
+            }
+        }
+    }
+
+    #[test]
+    fn decompose_inverts_reconstruct() {
+        let basis = basis();
+        let coefficients = (0..ZERNIKE_MODES)
+            .map(|j| ((j as f32 * 0.37).sin() * 0.5))
+            .collect::<Vec<_>>();
+        let heights = basis.reconstruct(&coefficients, |_| true);
+        let decomposed = basis.decompose(&heights);
+        for (j, (a, b)) in decomposed.iter().zip(&coefficients).enumerate() {
+            assert!((a - b).abs() < 1e-4, "Noll {}: {} != {}", j + 1, a, b);
+        }
+    }
+
+    #[test]
+    fn reconstruct_skips_excluded_modes() {
+        let basis = basis();
+        let coefficients = vec![1.0; ZERNIKE_MODES];
+        let heights = basis.reconstruct(&coefficients, |j| j == 3);
+        let decomposed = basis.decompose(&heights);
+        for (j, c) in decomposed.into_iter().enumerate() {
+            let expected = if j == 3 { 1.0 } else { 0.0 };
+            assert!((c - expected).abs() < 1e-4, "Noll {}: {}", j + 1, c);
+        }
+    }
+}
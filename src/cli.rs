@@ -0,0 +1,281 @@
+//! 命令行参数
+
+use crate::data_source::{fault::Fault, live::LiveFeedConfig};
+use bevy::prelude::*;
+use std::path::PathBuf;
+
+#[derive(Resource, Debug, Default, Clone)]
+pub struct CliArgs {
+    /// `--geometry <path>`：面板几何文件 (.csv / .json)
+    pub geometry: Option<PathBuf>,
+    /// `--live <tcp|udp>://<addr>`：启动时切换到实时数据源并监听该地址
+    pub live: Option<LiveFeedConfig>,
+    /// `--live-timeout <secs>`：实时数据超过该时长未更新视为过期
+    pub live_timeout: Option<f32>,
+    /// `--record <path>`：启动即开始录制高度数据
+    pub record: Option<PathBuf>,
+    /// `--replay <path>`：启动时回放录制文件
+    pub replay: Option<PathBuf>,
+    /// `--export-csv <path>`：把 `--replay` 指定的录制文件导出为 CSV 后退出
+    pub export_csv: Option<PathBuf>,
+    /// `--frequencies <GHz,...>`：计算 Ruze 口径效率的观测频率
+    pub frequencies: Option<Vec<f64>>,
+    /// `--zernike <j=c,...>`：Zernike 模拟面形的初始系数，j 为 Noll 编号
+    pub zernike: Option<Vec<(usize, f32)>>,
+    /// `--stroke <mm>`：促动器单向行程限制
+    pub stroke: Option<f32>,
+    /// `--smoothness <λ>`：校正指令的平滑正则化系数
+    pub smoothness: Option<f32>,
+    /// `--gravity-maps <deg=path,...>`：各仰角下的重力形变图文件
+    pub gravity_maps: Option<Vec<(f32, PathBuf)>>,
+    /// `--source <ra,dec>`：恒星跟踪源的赤经、赤纬（度）
+    pub source: Option<(f32, f32)>,
+    /// `--latitude <deg>`：台址纬度
+    pub latitude: Option<f32>,
+    /// `--wind-seed <n>`：风载模拟中脉动风场的随机种子
+    pub wind_seed: Option<u64>,
+    /// `--faults <id=kind[:value],...>`：按促动器编号注入故障
+    pub faults: Option<Vec<(u32, Fault)>>,
+    /// `--random-faults <count>[,seed]`：按种子随机注入故障
+    pub random_faults: Option<(usize, u64)>,
+    /// `--fault-scenario <path>`：读入保存的故障场景
+    pub fault_scenario: Option<PathBuf>,
+    /// `--color-range <min,max>`：固定颜色映射范围（毫米）
+    pub color_range: Option<(f32, f32)>,
+    /// `--colormap <path,...>`：读入自定义调色板（文本或 PNG）
+    pub colormaps: Option<Vec<PathBuf>>,
+    /// `--contours <mm>[,major_every]`：等高线间距与主等高线间隔条数
+    pub contours: Option<(f32, u32)>,
+}
+
+impl CliArgs {
+    pub fn from_env() -> Result<Self, String> {
+        Self::parse(std::env::args().skip(1))
+    }
+
+    /// 支持 `--flag value` 与 `--flag=value` 两种写法，遇到无效或未知参数时返回错误信息
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut cli = CliArgs::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let (flag, inline_value) = match arg.split_once('=') {
+                Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+                None => (arg, None),
+            };
+            let mut value = || inline_value.clone().or_else(|| args.next());
+            match flag.as_str() {
+                "--geometry" => cli.geometry = value().map(PathBuf::from),
+                "--live" => match value().map(|v| v.parse::<LiveFeedConfig>()) {
+                    Some(Ok(config)) => cli.live = Some(config),
+                    Some(Err(err)) => return Err(format!("--live 参数无效: {}", err)),
+                    None => return Err("--live 缺少地址".to_string()),
+                },
+                "--record" => cli.record = value().map(PathBuf::from),
+                "--replay" => cli.replay = value().map(PathBuf::from),
+                "--export-csv" => cli.export_csv = value().map(PathBuf::from),
+                "--live-timeout" => match value().map(|v| v.parse::<f32>()) {
+                    Some(Ok(timeout)) => cli.live_timeout = Some(timeout),
+                    _ => return Err("--live-timeout 需要以秒为单位的数值".to_string()),
+                },
+                "--frequencies" => match value().map(|v| {
+                    v.split(',')
+                        .map(|f| f.trim().parse::<f64>())
+                        .collect::<Result<Vec<_>, _>>()
+                }) {
+                    Some(Ok(frequencies))
+                        if !frequencies.is_empty() && frequencies.iter().all(|&f| f > 0.0) =>
+                    {
+                        cli.frequencies = Some(frequencies)
+                    }
+                    _ => return Err("--frequencies 需要以逗号分隔的正数频率 (GHz)".to_string()),
+                },
+                "--zernike" => match value().map(|v| {
+                    v.split(',')
+                        .map(|term| {
+                            let (j, c) = term.split_once('=')?;
+                            Some((
+                                j.trim().parse::<usize>().ok()?,
+                                c.trim().parse::<f32>().ok()?,
+                            ))
+                        })
+                        .collect::<Option<Vec<_>>>()
+                }) {
+                    Some(Some(terms)) if !terms.is_empty() => cli.zernike = Some(terms),
+                    _ => {
+                        return Err("--zernike 需要形如 4=0.3,7=-0.1 的 Noll 编号与系数".to_string())
+                    }
+                },
+                "--stroke" => match value().map(|v| v.parse::<f32>()) {
+                    Some(Ok(stroke)) if stroke > 0.0 => cli.stroke = Some(stroke),
+                    _ => return Err("--stroke 需要以毫米为单位的正数".to_string()),
+                },
+                "--smoothness" => match value().map(|v| v.parse::<f32>()) {
+                    Some(Ok(smoothness)) if smoothness >= 0.0 => cli.smoothness = Some(smoothness),
+                    _ => return Err("--smoothness 需要非负数".to_string()),
+                },
+                "--gravity-maps" => match value().map(|v| {
+                    v.split(',')
+                        .map(|term| {
+                            let (elevation, path) = term.split_once('=')?;
+                            Some((
+                                elevation.trim().parse::<f32>().ok()?,
+                                PathBuf::from(path.trim()),
+                            ))
+                        })
+                        .collect::<Option<Vec<_>>>()
+                }) {
+                    Some(Some(maps)) if !maps.is_empty() => cli.gravity_maps = Some(maps),
+                    _ => {
+                        return Err(
+                            "--gravity-maps 需要形如 15=a.txt,45=b.txt,90=c.txt 的仰角与文件"
+                                .to_string(),
+                        )
+                    }
+                },
+                "--source" => match value().and_then(|v| {
+                    let (ra, dec) = v.split_once(',')?;
+                    Some((
+                        ra.trim().parse::<f32>().ok()?,
+                        dec.trim().parse::<f32>().ok()?,
+                    ))
+                }) {
+                    Some((ra, dec)) if (-90.0..=90.0).contains(&dec) => {
+                        cli.source = Some((ra, dec))
+                    }
+                    _ => {
+                        return Err(
+                            "--source 需要以度为单位的赤经、赤纬，如 83.63,22.01".to_string()
+                        )
+                    }
+                },
+                "--latitude" => match value().map(|v| v.parse::<f32>()) {
+                    Some(Ok(latitude)) if (-90.0..=90.0).contains(&latitude) => {
+                        cli.latitude = Some(latitude)
+                    }
+                    _ => return Err("--latitude 需要 -90 到 90 之间的纬度".to_string()),
+                },
+                "--wind-seed" => match value().map(|v| v.parse::<u64>()) {
+                    Some(Ok(seed)) => cli.wind_seed = Some(seed),
+                    _ => return Err("--wind-seed 需要非负整数".to_string()),
+                },
+                "--faults" => match value().map(|v| {
+                    v.split(',')
+                        .map(|term| {
+                            let (id, fault) = term.split_once('=').ok_or(term.to_string())?;
+                            let id = id
+                                .trim()
+                                .parse::<u32>()
+                                .map_err(|_| format!("无效编号 {}", id))?;
+                            Ok((id, fault.parse::<Fault>()?))
+                        })
+                        .collect::<Result<Vec<_>, String>>()
+                }) {
+                    Some(Ok(faults)) if !faults.is_empty() => cli.faults = Some(faults),
+                    Some(Err(err)) => return Err(format!("--faults 参数无效: {}", err)),
+                    _ => {
+                        return Err(
+                            "--faults 需要形如 12=stuck:0.3,40=dead,77=noisy 的编号与故障"
+                                .to_string(),
+                        )
+                    }
+                },
+                "--random-faults" => match value().and_then(|v| {
+                    let (count, seed) = match v.split_once(',') {
+                        Some((count, seed)) => (count, seed.trim().parse::<u64>().ok()?),
+                        None => (v.as_str(), 0),
+                    };
+                    Some((count.trim().parse::<usize>().ok()?, seed))
+                }) {
+                    Some(random) => cli.random_faults = Some(random),
+                    None => {
+                        return Err("--random-faults 需要故障数与可选的种子，如 8,42".to_string())
+                    }
+                },
+                "--fault-scenario" => cli.fault_scenario = value().map(PathBuf::from),
+                "--color-range" => match value().and_then(|v| {
+                    let (min, max) = v.split_once(',')?;
+                    Some((
+                        min.trim().parse::<f32>().ok()?,
+                        max.trim().parse::<f32>().ok()?,
+                    ))
+                }) {
+                    Some((min, max)) if min < max => cli.color_range = Some((min, max)),
+                    _ => {
+                        return Err(
+                            "--color-range 需要以毫米为单位且 min < max 的范围，如 -0.5,0.5"
+                                .to_string(),
+                        )
+                    }
+                },
+                "--colormap" => match value() {
+                    Some(paths) => cli
+                        .colormaps
+                        .get_or_insert_with(Vec::new)
+                        .extend(paths.split(',').map(|path| PathBuf::from(path.trim()))),
+                    None => return Err("--colormap 缺少调色板文件".to_string()),
+                },
+                "--contours" => match value().and_then(|v| {
+                    let (interval, major) = match v.split_once(',') {
+                        Some((interval, major)) => (interval, major.trim().parse::<u32>().ok()?),
+                        None => (v.as_str(), 5),
+                    };
+                    Some((interval.trim().parse::<f32>().ok()?, major))
+                }) {
+                    Some((interval, major)) if interval > 0.0 && major > 0 => {
+                        cli.contours = Some((interval, major))
+                    }
+                    _ => {
+                        return Err(
+                            "--contours 需要以毫米为单位的间距与可选的主等高线间隔，如 0.1,5"
+                                .to_string(),
+                        )
+                    }
+                },
+                _ => return Err(format!("未知参数: {}", flag)),
+            }
+        }
+        Ok(cli)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<CliArgs, String> {
+        CliArgs::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn accepts_both_value_forms() {
+        let cli = parse(&[
+            "--stroke",
+            "2.5",
+            "--contours=0.1,4",
+            "--source=83.63,22.01",
+        ])
+        .unwrap();
+        assert_eq!(cli.stroke, Some(2.5));
+        assert_eq!(cli.contours, Some((0.1, 4)));
+        assert_eq!(cli.source, Some((83.63, 22.01)));
+    }
+
+    #[test]
+    fn rejects_invalid_values() {
+        for args in [
+            &["--stroke", "-1"][..],
+            &["--latitude=91"],
+            &["--color-range", "0.5,-0.5"],
+            &["--faults", "12=melted"],
+            &["--live"],
+        ] {
+            assert!(parse(args).is_err(), "{:?} 应当报错", args);
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_flags() {
+        let err = parse(&["--stroke", "2", "--strok", "3"]).unwrap_err();
+        assert!(err.contains("--strok"), "{}", err);
+    }
+}
@@ -0,0 +1,593 @@
+//! 高度颜色映射与图例
+//!
+//! 反射面着色器把高度按映射范围归一化到 0~1，再在当前调色板烘焙的一维查找纹理中取色。
+//! [`ColormapLibrary`] 保存内置与用户读入的调色板，切换时重新生成查找纹理与色标。
+//! [`HeightColorRange`] 给出映射到 0 与 1 的高度，按 [`ColorRangeMode`]
+//! 取用户设定的固定范围、以零为中心的对称范围或当前帧高度的百分位数，并写入反射面材质；
+//! 超出范围的高度在着色器中以单独的截断颜色显示。图例按此标注以毫米或微米为单位的刻度。
+
+pub mod palette;
+
+use crate::{
+    analysis::DisplayedHeights, cli::CliArgs, data_source::HeightSet, CustomMaterial,
+    CustomMaterialHandle, CustomTextFont,
+};
+use bevy::{
+    color::palettes::css::*,
+    prelude::*,
+    render::{
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+    },
+    text::FontSmoothing,
+};
+use palette::Colormap;
+use std::fmt::{self, Formatter};
+
+/// 色标纹理的采样数
+const LEGEND_SAMPLES: u32 = 256;
+/// 色标在界面上的尺寸（像素）
+const LEGEND_WIDTH: f32 = 20.0;
+const LEGEND_HEIGHT: f32 = 200.0;
+const CLAMP_SWATCH_HEIGHT: f32 = 10.0;
+const CLAMP_SWATCH_GAP: f32 = 2.0;
+/// 目标刻度数，实际刻度取整到 1、2、5 倍的步长
+const TARGET_TICKS: f32 = 4.0;
+/// 刻度文字最多保留的小数位数
+const MAX_TICK_DECIMALS: usize = 6;
+/// 自动范围取当前帧高度的上下百分位数，忽略少数离群促动器
+const AUTO_PERCENTILES: (f32, f32) = (0.02, 0.98);
+/// 范围过窄时的最小跨度（毫米）
+const MIN_RANGE_SPAN: f32 = 1e-4;
+
+/// 低于 / 高于映射范围的截断颜色，与 reflector.wgsl 一致
+pub const UNDER_RANGE_COLOR: LinearRgba = LinearRgba::rgb(0.35, 0.35, 0.35);
+pub const OVER_RANGE_COLOR: LinearRgba = LinearRgba::rgb(1.0, 0.0, 1.0);
+
+pub struct ColormapPlugin;
+
+impl Plugin for ColormapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<ColorRangeMode>()
+            .init_resource::<ColorRangeSettings>()
+            .init_resource::<HeightColorRange>()
+            .init_resource::<ColormapLibrary>()
+            .add_systems(PostStartup, spawn_legend)
+            .add_systems(
+                Update,
+                (
+                    (update_color_range, upload_color_range)
+                        .chain()
+                        .in_set(HeightSet::Upload),
+                    (upload_colormap, update_legend_image)
+                        .run_if(resource_changed::<ColormapLibrary>),
+                    (update_legend_ticks, update_color_range_text)
+                        .run_if(resource_changed::<HeightColorRange>),
+                ),
+            );
+    }
+}
+
+/// 颜色映射的高度范围（毫米），`min` 映射为 0，`max` 映射为 1
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct HeightColorRange {
+    pub min: f32,
+    pub max: f32,
+}
+
+impl Default for HeightColorRange {
+    fn default() -> Self {
+        HeightColorRange { min: 0.0, max: 1.0 }
+    }
+}
+
+impl HeightColorRange {
+    pub fn label(&self) -> String {
+        let (unit, factor) = legend_unit(self);
+        format!(
+            "{:.3} ~ {:.3} {}",
+            self.min * factor,
+            self.max * factor,
+            unit
+        )
+    }
+
+    /// 保证跨度为正，避免着色器除零
+    fn new(min: f32, max: f32) -> Self {
+        let center = (min + max) * 0.5;
+        let half = ((max - min) * 0.5).max(MIN_RANGE_SPAN * 0.5);
+        HeightColorRange {
+            min: center - half,
+            max: center + half,
+        }
+    }
+}
+
+/// 可切换的调色板，`--colormap` 读入的自定义调色板排在内置调色板之后
+#[derive(Resource, Debug)]
+pub struct ColormapLibrary {
+    maps: Vec<Colormap>,
+    active: usize,
+}
+
+impl FromWorld for ColormapLibrary {
+    // 读入了自定义调色板时默认使用第一个
+    fn from_world(world: &mut World) -> Self {
+        let mut maps = palette::presets();
+        let preset_count = maps.len();
+        let mut active = maps
+            .iter()
+            .position(|map| map.name == palette::DEFAULT_PRESET)
+            .unwrap_or_default();
+        let paths = world
+            .get_resource::<CliArgs>()
+            .and_then(|cli| cli.colormaps.clone())
+            .unwrap_or_default();
+        for path in paths {
+            match Colormap::load(&path) {
+                Ok(map) => {
+                    if maps.len() == preset_count {
+                        active = maps.len();
+                    }
+                    maps.push(map);
+                }
+                Err(err) => error!("无法读取调色板 {}: {}", path.display(), err),
+            }
+        }
+        ColormapLibrary { maps, active }
+    }
+}
+
+impl ColormapLibrary {
+    pub fn active(&self) -> &Colormap {
+        &self.maps[self.active]
+    }
+
+    /// 切换到下一个调色板
+    pub fn next(&mut self) -> &Colormap {
+        self.active = (self.active + 1) % self.maps.len();
+        self.active()
+    }
+}
+
+/// 颜色映射范围的取法
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash, States)]
+pub enum ColorRangeMode {
+    /// 用户设定的 [min, max]
+    #[default]
+    Fixed,
+    /// 以零为中心的 ±range
+    Symmetric,
+    /// 当前帧高度的百分位数
+    Auto,
+}
+
+impl fmt::Display for ColorRangeMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColorRangeMode::Fixed => write!(f, "固定"),
+            ColorRangeMode::Symmetric => write!(f, "对称"),
+            ColorRangeMode::Auto => write!(f, "自动"),
+        }
+    }
+}
+
+/// 固定与对称模式下用户设定的范围（毫米）
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct ColorRangeSettings {
+    pub fixed: HeightColorRange,
+    pub symmetric: f32,
+}
+
+impl FromWorld for ColorRangeSettings {
+    fn from_world(world: &mut World) -> Self {
+        let fixed = world
+            .get_resource::<CliArgs>()
+            .and_then(|cli| cli.color_range)
+            .map(|(min, max)| HeightColorRange::new(min, max))
+            .unwrap_or_default();
+        ColorRangeSettings {
+            fixed,
+            symmetric: 0.5,
+        }
+    }
+}
+
+impl ColorRangeSettings {
+    /// 按倍数缩放当前模式的范围，固定范围保持中心不变
+    pub fn scale(&mut self, mode: ColorRangeMode, factor: f32) {
+        match mode {
+            ColorRangeMode::Fixed => {
+                let center = (self.fixed.min + self.fixed.max) * 0.5;
+                let half = (self.fixed.max - self.fixed.min) * 0.5 * factor;
+                self.fixed = HeightColorRange::new(center - half, center + half);
+            }
+            ColorRangeMode::Symmetric => {
+                self.symmetric = (self.symmetric * factor).max(MIN_RANGE_SPAN * 0.5);
+            }
+            ColorRangeMode::Auto => {}
+        }
+    }
+}
+
+/// 排序后取百分位数，`buffer` 复用以免每帧分配
+fn percentile_range(heights: &[f32], buffer: &mut Vec<f32>) -> Option<HeightColorRange> {
+    buffer.clear();
+    buffer.extend(heights.iter().copied().filter(|h| h.is_finite()));
+    if buffer.is_empty() {
+        return None;
+    }
+    buffer.sort_unstable_by(f32::total_cmp);
+    let at = |p: f32| buffer[((buffer.len() - 1) as f32 * p).round() as usize];
+    Some(HeightColorRange::new(
+        at(AUTO_PERCENTILES.0),
+        at(AUTO_PERCENTILES.1),
+    ))
+}
+
+fn update_color_range(
+    mode: Res<State<ColorRangeMode>>,
+    settings: Res<ColorRangeSettings>,
+    heights: Res<DisplayedHeights>,
+    mut range: ResMut<HeightColorRange>,
+    mut buffer: Local<Vec<f32>>,
+) {
+    let next = match mode.get() {
+        ColorRangeMode::Fixed => settings.fixed,
+        ColorRangeMode::Symmetric => HeightColorRange::new(-settings.symmetric, settings.symmetric),
+        ColorRangeMode::Auto => match percentile_range(&heights, &mut buffer) {
+            Some(next) => next,
+            None => return,
+        },
+    };
+    range.set_if_neq(next);
+}
+
+fn upload_color_range(
+    range: Res<HeightColorRange>,
+    material_handle: Res<CustomMaterialHandle>,
+    mut materials: ResMut<Assets<CustomMaterial>>,
+) {
+    let Some(material) = materials.get(&material_handle.0) else {
+        return;
+    };
+    if material.height_min != range.min || material.height_max != range.max {
+        if let Some(material) = materials.get_mut(&material_handle.0) {
+            material.height_min = range.min;
+            material.height_max = range.max;
+        }
+    }
+}
+
+/// 竖直的色标纹理，最上方为 1
+fn legend_image(colormap: &Colormap) -> Image {
+    let data = (0..LEGEND_SAMPLES)
+        .rev()
+        .flat_map(|i| {
+            let t = i as f32 / (LEGEND_SAMPLES - 1) as f32;
+            Srgba::from(colormap.sample(t)).to_u8_array()
+        })
+        .collect();
+    Image::new(
+        Extent3d {
+            width: 1,
+            height: LEGEND_SAMPLES,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD | RenderAssetUsages::MAIN_WORLD,
+    )
+}
+
+/// 取 1、2、5 倍 10 的幂为步长，返回落在范围内的刻度与步长
+pub fn nice_ticks(min: f32, max: f32) -> (Vec<f32>, f32) {
+    let span = max - min;
+    if !(span > 0.0) {
+        return (vec![min], 0.0);
+    }
+    let raw = span / TARGET_TICKS;
+    let magnitude = 10f32.powf(raw.log10().floor());
+    let step = magnitude
+        * match raw / magnitude {
+            n if n < 1.5 => 1.0,
+            n if n < 3.5 => 2.0,
+            n if n < 7.5 => 5.0,
+            _ => 10.0,
+        };
+    let first = (min / step).ceil() as i64;
+    let last = (max / step + 1e-4).floor() as i64;
+    ((first..=last).map(|k| k as f32 * step).collect(), step)
+}
+
+/// 刻度文字的小数位数，足以区分相邻刻度。范围退化为单个刻度时步长为 0，
+/// 按该刻度数值的三位有效数字取
+fn tick_decimals(step: f32, value: f32) -> usize {
+    let resolution = if step > 0.0 { step } else { value.abs() * 0.01 };
+    if !(resolution > 0.0 && resolution.is_finite()) {
+        return 0;
+    }
+    (-resolution.log10().floor()).clamp(0.0, MAX_TICK_DECIMALS as f32) as usize
+}
+
+/// 范围较小时以微米标注，返回单位名称与换算系数
+fn legend_unit(range: &HeightColorRange) -> (&'static str, f32) {
+    if range.min.abs().max(range.max.abs()) < 0.1 {
+        ("μm", 1000.0)
+    } else {
+        ("mm", 1.0)
+    }
+}
+
+#[derive(Resource)]
+struct LegendImage(Handle<Image>);
+
+#[derive(Component)]
+struct LegendTicks;
+
+#[derive(Component)]
+struct LegendTitle;
+
+// 色标在左侧，位于控制栏上方
+fn spawn_legend(
+    mut commands: Commands,
+    font: Res<CustomTextFont>,
+    colormaps: Res<ColormapLibrary>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let text_font = TextFont {
+        font: font.0.clone(),
+        font_size: 14.0,
+        font_smoothing: FontSmoothing::AntiAliased,
+    };
+    let image = images.add(legend_image(colormaps.active()));
+    commands.insert_resource(LegendImage(image.clone()));
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(12.0),
+                bottom: Val::Px(200.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(8.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                ..default()
+            },
+            BackgroundColor(BLACK.with_alpha(0.5).into()),
+            PickingBehavior::IGNORE,
+        ))
+        .with_children(|p| {
+            p.spawn((
+                Text::default(),
+                LegendTitle,
+                text_font.clone(),
+                TextColor(WHITE.into()),
+            ));
+            p.spawn(Node {
+                column_gap: Val::Px(4.0),
+                ..default()
+            })
+            .with_children(|p1| {
+                // 色标上下各接一段截断颜色，表示超出映射范围的高度
+                p1.spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(CLAMP_SWATCH_GAP),
+                    ..default()
+                })
+                .with_children(|p2| {
+                    p2.spawn((
+                        Node {
+                            width: Val::Px(LEGEND_WIDTH),
+                            height: Val::Px(CLAMP_SWATCH_HEIGHT),
+                            ..default()
+                        },
+                        BackgroundColor(OVER_RANGE_COLOR.into()),
+                    ));
+                    p2.spawn((
+                        ImageNode::new(image),
+                        Node {
+                            width: Val::Px(LEGEND_WIDTH),
+                            height: Val::Px(LEGEND_HEIGHT),
+                            ..default()
+                        },
+                    ));
+                    p2.spawn((
+                        Node {
+                            width: Val::Px(LEGEND_WIDTH),
+                            height: Val::Px(CLAMP_SWATCH_HEIGHT),
+                            ..default()
+                        },
+                        BackgroundColor(UNDER_RANGE_COLOR.into()),
+                    ));
+                });
+                p1.spawn((
+                    LegendTicks,
+                    Node {
+                        width: Val::Px(64.0),
+                        height: Val::Px(LEGEND_HEIGHT),
+                        margin: UiRect::top(Val::Px(CLAMP_SWATCH_HEIGHT + CLAMP_SWATCH_GAP)),
+                        ..default()
+                    },
+                ));
+            });
+        });
+}
+
+// 切换调色板时生成新的查找纹理，校正视图的材质随反射面材质同步
+fn upload_colormap(
+    colormaps: Res<ColormapLibrary>,
+    material_handle: Res<CustomMaterialHandle>,
+    mut materials: ResMut<Assets<CustomMaterial>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    if let Some(material) = materials.get_mut(&material_handle.0) {
+        material.colormap = images.add(colormaps.active().lut_image());
+    }
+}
+
+fn update_legend_image(
+    colormaps: Res<ColormapLibrary>,
+    legend: Option<Res<LegendImage>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let Some(image) = legend.and_then(|legend| images.get_mut(&legend.0)) else {
+        return;
+    };
+    *image = legend_image(colormaps.active());
+}
+
+fn update_legend_ticks(
+    mut commands: Commands,
+    range: Res<HeightColorRange>,
+    font: Res<CustomTextFont>,
+    ticks: Single<Entity, With<LegendTicks>>,
+    mut title: Single<&mut Text, With<LegendTitle>>,
+) {
+    let (unit, factor) = legend_unit(&range);
+    title.0 = format!("高度 ({})", unit);
+
+    let (values, step) = nice_ticks(range.min, range.max);
+    let decimals = tick_decimals(step * factor, range.min * factor);
+    let text_font = TextFont {
+        font: font.0.clone(),
+        font_size: 12.0,
+        font_smoothing: FontSmoothing::AntiAliased,
+    };
+    let mut ticks = commands.entity(*ticks);
+    ticks.despawn_descendants();
+    ticks.with_children(|p| {
+        for value in values {
+            let t = if range.max > range.min {
+                (value - range.min) / (range.max - range.min)
+            } else {
+                0.5
+            };
+            // 刻度线与文字垂直居中对齐到刻度位置
+            p.spawn(Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px((1.0 - t) * LEGEND_HEIGHT - 7.0),
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(2.0),
+                ..default()
+            })
+            .with_children(|p1| {
+                p1.spawn((
+                    Node {
+                        width: Val::Px(6.0),
+                        height: Val::Px(1.0),
+                        ..default()
+                    },
+                    BackgroundColor(WHITE.into()),
+                ));
+                p1.spawn((
+                    Text::new(format!("{:.*}", decimals, value * factor)),
+                    text_font.clone(),
+                    TextColor(WHITE.into()),
+                ));
+            });
+        }
+    });
+}
+
+/// 控制栏中显示当前映射范围的文字
+#[derive(Component)]
+pub struct ColorRangeText;
+
+fn update_color_range_text(
+    range: Res<HeightColorRange>,
+    mut texts: Query<&mut Text, With<ColorRangeText>>,
+) {
+    for mut text in texts.iter_mut() {
+        text.0 = range.label();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_ticks(actual: &[f32], expected: &[f32]) {
+        assert_eq!(
+            actual.len(),
+            expected.len(),
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+        for (a, b) in actual.iter().zip(expected) {
+            assert!((a - b).abs() < 1e-5, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn nice_ticks_use_1_2_5_steps() {
+        let (ticks, step) = nice_ticks(0.0, 1.0);
+        assert!((step - 0.2).abs() < 1e-6);
+        assert_ticks(&ticks, &[0.0, 0.2, 0.4, 0.6, 0.8, 1.0]);
+
+        let (ticks, step) = nice_ticks(-0.5, 0.5);
+        assert!((step - 0.2).abs() < 1e-6);
+        assert_ticks(&ticks, &[-0.4, -0.2, 0.0, 0.2, 0.4]);
+
+        let (ticks, step) = nice_ticks(-3.0, 17.0);
+        assert_eq!(step, 5.0);
+        assert_ticks(&ticks, &[0.0, 5.0, 10.0, 15.0]);
+
+        let (ticks, step) = nice_ticks(0.013, 0.052);
+        assert!((step - 0.01).abs() < 1e-6);
+        assert_ticks(&ticks, &[0.02, 0.03, 0.04, 0.05]);
+    }
+
+    #[test]
+    fn nice_ticks_degenerate_range() {
+        assert_eq!(nice_ticks(0.3, 0.3), (vec![0.3], 0.0));
+        assert_eq!(nice_ticks(1.0, 0.0), (vec![1.0], 0.0));
+        let (ticks, step) = nice_ticks(f32::NAN, 1.0);
+        assert_eq!((ticks.len(), step), (1, 0.0));
+    }
+
+    #[test]
+    fn tick_decimals_are_bounded() {
+        assert_eq!(tick_decimals(0.2, 0.0), 1);
+        assert_eq!(tick_decimals(5.0, 0.0), 0);
+        assert_eq!(tick_decimals(0.01, 0.0), 2);
+        assert_eq!(tick_decimals(1e-12, 0.0), MAX_TICK_DECIMALS);
+        // 单个刻度时步长为 0，按数值本身取位数，不会溢出
+        assert_eq!(tick_decimals(0.0, 0.35), 3);
+        assert_eq!(tick_decimals(0.0, 0.0), 0);
+        assert_eq!(tick_decimals(f32::NAN, f32::NAN), 0);
+        let (ticks, step) = nice_ticks(0.3, 0.3);
+        let label = format!("{:.*}", tick_decimals(step, ticks[0]), ticks[0]);
+        assert_eq!(label, "0.300");
+    }
+
+    #[test]
+    fn legend_unit_switches_to_micrometers() {
+        assert_eq!(
+            legend_unit(&HeightColorRange::new(-0.05, 0.05)),
+            ("μm", 1000.0)
+        );
+        assert_eq!(legend_unit(&HeightColorRange::new(-0.5, 0.5)), ("mm", 1.0));
+        // 只要有一端超过 0.1 mm 就以毫米标注
+        assert_eq!(legend_unit(&HeightColorRange::new(-0.2, 0.05)), ("mm", 1.0));
+    }
+
+    #[test]
+    fn percentile_range_ignores_outliers() {
+        let mut buffer = vec![];
+        let mut heights = (0..=100).map(|i| i as f32).collect::<Vec<_>>();
+        heights.reverse();
+        heights.push(f32::NAN);
+        heights.push(f32::INFINITY);
+        let range = percentile_range(&heights, &mut buffer).unwrap();
+        assert_eq!((range.min, range.max), (2.0, 98.0));
+
+        assert!(percentile_range(&[], &mut buffer).is_none());
+        assert!(percentile_range(&[f32::NAN], &mut buffer).is_none());
+        // 所有高度相同时保持最小跨度
+        let range = percentile_range(&[0.5; 10], &mut buffer).unwrap();
+        assert!((range.max - range.min - MIN_RANGE_SPAN).abs() < 1e-6);
+        assert!((range.min + range.max - 1.0).abs() < 1e-6);
+    }
+}
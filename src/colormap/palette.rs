@@ -0,0 +1,510 @@
+//! 调色板
+//!
+//! 调色板是 0~1 上等距排列的一组线性 RGB 颜色，采样时在相邻颜色间线性插值，并烘焙为
+//! 反射面着色器使用的一维查找纹理。内置原先着色器中的各颜色算法以及常用的感知均匀调色板，
+//! 也可从文本文件或 PNG 图片读入自定义调色板。
+
+use bevy::{
+    color::ColorToComponents,
+    image::ImageSampler,
+    prelude::*,
+    render::{
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+    },
+};
+use std::{
+    fs::{self, File},
+    io::{self, BufReader},
+    path::Path,
+};
+
+/// 查找纹理的采样数
+pub const LUT_SIZE: u32 = 256;
+
+#[derive(Debug, Clone)]
+pub struct Colormap {
+    pub name: String,
+    colors: Vec<LinearRgba>,
+}
+
+impl Colormap {
+    pub fn new(name: impl Into<String>, colors: Vec<LinearRgba>) -> Self {
+        Colormap {
+            name: name.into(),
+            colors,
+        }
+    }
+
+    /// 在 [`LUT_SIZE`] 个点上对颜色函数采样
+    fn from_fn(name: &str, f: impl Fn(f32) -> Vec3) -> Self {
+        let colors = (0..LUT_SIZE)
+            .map(|i| {
+                let rgb = f(i as f32 / (LUT_SIZE - 1) as f32);
+                LinearRgba::rgb(rgb.x, rgb.y, rgb.z)
+            })
+            .collect();
+        Colormap::new(name, colors)
+    }
+
+    /// 由 sRGB 十六进制颜色构造
+    fn from_hex(name: &str, hex: &[&str]) -> Self {
+        let colors = hex
+            .iter()
+            .map(|hex| Srgba::hex(hex).expect("内置颜色有效").into())
+            .collect();
+        Colormap::new(name, colors)
+    }
+
+    /// `t`（0~1）处的颜色，超出范围时取端点
+    pub fn sample(&self, t: f32) -> LinearRgba {
+        let last = self.colors.len() - 1;
+        let position = t.clamp(0.0, 1.0) * last as f32;
+        let low = (position.floor() as usize).min(last);
+        let high = (low + 1).min(last);
+        self.colors[low].mix(&self.colors[high], position - low as f32)
+    }
+
+    /// 一维查找纹理，线性过滤、边缘截断
+    pub fn lut_image(&self) -> Image {
+        let data = (0..LUT_SIZE)
+            .flat_map(|i| {
+                let t = i as f32 / (LUT_SIZE - 1) as f32;
+                Srgba::from(self.sample(t)).to_u8_array()
+            })
+            .collect();
+        let mut image = Image::new(
+            Extent3d {
+                width: LUT_SIZE,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D1,
+            data,
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::RENDER_WORLD,
+        );
+        image.sampler = ImageSampler::linear();
+        image
+    }
+
+    /// 按扩展名读入 `.png` 图片或文本调色板，名称取文件名
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.display().to_string());
+        let is_png = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("png"));
+        let colors = if is_png {
+            let image = image::load(BufReader::new(File::open(path)?), image::ImageFormat::Png)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+            parse_png(&image.into_rgba8())
+        } else {
+            parse_text(&fs::read_to_string(path)?)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?
+        };
+        if colors.len() < 2 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "调色板至少需要两个颜色",
+            ));
+        }
+        Ok(Colormap::new(name, colors))
+    }
+}
+
+/// 每行一个 sRGB 颜色：`#rrggbb` 或空格、逗号分隔的三个分量。分量均不超过 1 时按 0~1
+/// 解释，否则按 0~255 解释。空行与 `//` 开头的行忽略
+pub fn parse_text(text: &str) -> Result<Vec<LinearRgba>, String> {
+    // 十六进制颜色已是 0~1 的分量，不参与 0~255 的判断
+    let mut colors = Vec::new();
+    for (number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with("//") {
+            continue;
+        }
+        if line.starts_with('#') {
+            let color = Srgba::hex(line).map_err(|err| format!("第 {} 行: {}", number + 1, err))?;
+            colors.push((color.to_vec3(), true));
+            continue;
+        }
+        let values = line
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|v| !v.is_empty())
+            .map(|v| v.parse::<f32>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| format!("第 {} 行: {}", number + 1, err))?;
+        let [r, g, b] = values[..] else {
+            return Err(format!("第 {} 行需要三个分量", number + 1));
+        };
+        colors.push((Vec3::new(r, g, b), false));
+    }
+    let scale = if colors
+        .iter()
+        .any(|(rgb, hex)| !hex && rgb.max_element() > 1.0)
+    {
+        255.0
+    } else {
+        1.0
+    };
+    Ok(colors
+        .into_iter()
+        .map(|(rgb, hex)| {
+            let rgb = if hex { rgb } else { rgb / scale };
+            Srgba::rgb(rgb.x, rgb.y, rgb.z).into()
+        })
+        .collect())
+}
+
+/// 横向图片取中间一行从左到右，竖向图片取中间一列从下到上
+pub fn parse_png(image: &image::RgbaImage) -> Vec<LinearRgba> {
+    let (width, height) = image.dimensions();
+    let pixel = |x: u32, y: u32| {
+        let [r, g, b, _] = image.get_pixel(x, y).0;
+        LinearRgba::from(Srgba::rgb_u8(r, g, b))
+    };
+    if width >= height {
+        (0..width).map(|x| pixel(x, height / 2)).collect()
+    } else {
+        (0..height).rev().map(|y| pixel(width / 2, y)).collect()
+    }
+}
+
+/// 内置调色板：原先的颜色算法在前，随后是感知均匀与发散调色板
+pub fn presets() -> Vec<Colormap> {
+    vec![
+        Colormap::from_fn("algo_0", |t| interpolate_scale(&SCALE_0, t)),
+        Colormap::from_fn("algo_1", |t| interpolate_scale(&SCALE_1, t)),
+        Colormap::from_fn("algo_normal", interpolate_normal),
+        Colormap::from_fn("algo_oklab", interpolate_oklab),
+        Colormap::from_fn("algo_heat5", heat5),
+        Colormap::from_fn("algo_heat7", heat7),
+        Colormap::from_hex(
+            "viridis",
+            &[
+                "#440154", "#472c7a", "#3b518b", "#2c718e", "#21908d", "#27ad81", "#5cc863",
+                "#aadc32", "#fde725",
+            ],
+        ),
+        Colormap::from_hex(
+            "magma",
+            &[
+                "#000004", "#1c1044", "#4f127b", "#812581", "#b5367a", "#e55064", "#fb8761",
+                "#fec287", "#fcfdbf",
+            ],
+        ),
+        Colormap::from_hex(
+            "cividis",
+            &[
+                "#00224e", "#123570", "#3b496c", "#575d6d", "#707173", "#8a8678", "#a59c74",
+                "#c3b369", "#e1cc55", "#fee838",
+            ],
+        ),
+        Colormap::from_hex(
+            "RdBu",
+            &[
+                "#67001f", "#b2182b", "#d6604d", "#f4a582", "#fddbc7", "#f7f7f7", "#d1e5f0",
+                "#92c5de", "#4393c3", "#2166ac", "#053061",
+            ],
+        ),
+        Colormap::from_fn("turbo", turbo),
+    ]
+}
+
+/// 默认调色板，与原先默认的颜色算法一致
+pub const DEFAULT_PRESET: &str = "algo_normal";
+
+// 以下为原先 reflector.wgsl 中的颜色算法，输出为线性 RGB
+
+const SCALE_0: [[f32; 3]; 11] = [
+    [0.4, 0.07, 0.15],
+    [0.5, 0.1, 0.27],
+    [0.58, 0.12, 0.42],
+    [0.67, 0.16, 0.6],
+    [0.73, 0.19, 0.76],
+    [0.67, 0.29, 0.67],
+    [0.64, 0.39, 0.84],
+    [0.64, 0.49, 0.87],
+    [0.66, 0.6, 0.91],
+    [0.71, 0.71, 0.94],
+    [0.83, 0.85, 0.96],
+];
+
+const SCALE_1: [[f32; 3]; 11] = [
+    [0.48, 0.5, 0.18],
+    [0.62, 0.6, 0.24],
+    [0.75, 0.62, 0.29],
+    [0.87, 0.61, 0.35],
+    [1.0, 0.57, 0.41],
+    [1.0, 0.53, 0.48],
+    [1.0, 0.55, 0.6],
+    [1.0, 0.63, 0.74],
+    [1.0, 0.71, 0.85],
+    [1.0, 0.79, 0.94],
+    [1.0, 0.87, 0.99],
+];
+
+const NORMAL_COLORS: [[f32; 3]; 3] = [[1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 0.0]];
+
+fn mix(a: Vec3, b: Vec3, t: f32) -> Vec3 {
+    a + (b - a) * t
+}
+
+// 注意原算法以 t 而不是段内位置做插值，这里保持一致
+fn interpolate_scale(scale: &[[f32; 3]; 11], t: f32) -> Vec3 {
+    let h = t * 10.0;
+    let low = (h.floor().max(0.0) as usize).min(10);
+    let high = (h.ceil().max(0.0) as usize).min(10);
+    mix(Vec3::from(scale[low]), Vec3::from(scale[high]), t)
+}
+
+fn interpolate_normal(t: f32) -> Vec3 {
+    let segment = t * (NORMAL_COLORS.len() - 1) as f32;
+    let low = (segment.floor().max(0.0) as usize).min(NORMAL_COLORS.len() - 1);
+    let high = (segment.ceil().max(0.0) as usize).min(NORMAL_COLORS.len() - 1);
+    mix(
+        Vec3::from(NORMAL_COLORS[low]),
+        Vec3::from(NORMAL_COLORS[high]),
+        segment.fract(),
+    )
+}
+
+fn interpolate_oklab(t: f32) -> Vec3 {
+    // blending is done in a perceptual color space: https://bottosson.github.io/posts/oklab/
+    let red = Vec3::new(0.627955, 0.224863, 0.125846);
+    let green = Vec3::new(0.86644, -0.233887, 0.179498);
+    let blue = Vec3::new(0.701674, 0.274566, -0.169156);
+    let c = if t < 0.5 {
+        mix(red, green, t * 2.0)
+    } else {
+        mix(green, blue, (t - 0.5) * 2.0)
+    };
+    let l_ = c.x + 0.396_337_8 * c.y + 0.215_803_76 * c.z;
+    let m_ = c.x - 0.105_561_35 * c.y - 0.063_854_17 * c.z;
+    let s_ = c.x - 0.089_484_18 * c.y - 1.291_485_5 * c.z;
+    let (l, m, s) = (l_.powi(3), m_.powi(3), s_.powi(3));
+    Vec3::new(
+        4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+        -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+        -0.004_196_086 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+    )
+}
+
+fn red_to_green(t: f32) -> Vec3 {
+    if t < 0.5 {
+        Vec3::new(1.0, 2.0 * t, 0.0)
+    } else {
+        Vec3::new(2.0 - 2.0 * t, 1.0, 0.0)
+    }
+}
+
+fn green_to_blue(t: f32) -> Vec3 {
+    if t < 0.5 {
+        Vec3::new(0.0, 1.0, 2.0 * t)
+    } else {
+        Vec3::new(0.0, 2.0 - 2.0 * t, 1.0)
+    }
+}
+
+fn heat5(t: f32) -> Vec3 {
+    if t < 0.5 {
+        green_to_blue(1.0 - 2.0 * t)
+    } else {
+        red_to_green(2.0 - 2.0 * t)
+    }
+}
+
+fn heat7(t: f32) -> Vec3 {
+    if t < 1.0 / 6.0 {
+        mix(Vec3::ZERO, Vec3::Z, 6.0 * t)
+    } else if t < 5.0 / 6.0 {
+        heat5(0.25 * (6.0 * t - 1.0))
+    } else {
+        mix(Vec3::X, Vec3::ONE, 6.0 * t - 5.0)
+    }
+}
+
+// Turbo 的多项式近似：https://ai.googleblog.com/2019/08/turbo-improved-rainbow-colormap-for.html
+// 多项式给出 sRGB 颜色，这里转换为线性 RGB
+fn turbo(t: f32) -> Vec3 {
+    const RED: [f32; 6] = [
+        0.135_721_38,
+        4.615_392_6,
+        -42.660_323,
+        132.131_08,
+        -152.942_4,
+        59.286_38,
+    ];
+    const GREEN: [f32; 6] = [
+        0.091_402_61,
+        2.194_188_4,
+        4.842_966_6,
+        -14.185_033,
+        4.277_298_6,
+        2.829_566,
+    ];
+    const BLUE: [f32; 6] = [
+        0.106_673_3,
+        12.641_946,
+        -60.582_047,
+        110.362_77,
+        -89.903_11,
+        27.348_25,
+    ];
+    let poly = |c: &[f32; 6]| c.iter().rev().fold(0.0, |acc, &k| acc * t + k);
+    let srgb = Srgba::rgb(
+        poly(&RED).clamp(0.0, 1.0),
+        poly(&GREEN).clamp(0.0, 1.0),
+        poly(&BLUE).clamp(0.0, 1.0),
+    );
+    LinearRgba::from(srgb).to_vec3()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_color(actual: LinearRgba, expected: LinearRgba) {
+        let (a, b) = (actual.to_vec3(), expected.to_vec3());
+        assert!(a.abs_diff_eq(b, 1e-3), "{:?} != {:?}", actual, expected);
+    }
+
+    fn srgb(r: f32, g: f32, b: f32) -> LinearRgba {
+        Srgba::rgb(r, g, b).into()
+    }
+
+    #[test]
+    fn parses_hex_colors() {
+        let colors = parse_text("#ff0000\n#00FF00\n#0000ff").unwrap();
+        assert_eq!(colors.len(), 3);
+        assert_color(colors[0], LinearRgba::RED);
+        assert_color(colors[1], LinearRgba::GREEN);
+        assert_color(colors[2], LinearRgba::BLUE);
+    }
+
+    #[test]
+    fn detects_component_range() {
+        // 分量均不超过 1 时按 0~1 解释
+        let colors = parse_text("0 0 0\n0.5, 0.5, 0.5\n1 1 1").unwrap();
+        assert_color(colors[0], LinearRgba::BLACK);
+        assert_color(colors[1], srgb(0.5, 0.5, 0.5));
+        assert_color(colors[2], LinearRgba::WHITE);
+
+        // 任一分量超过 1 时整份调色板按 0~255 解释，包括分量都不超过 1 的行
+        let colors = parse_text("1 1 1\n255,128,0").unwrap();
+        assert_color(colors[0], srgb(1.0 / 255.0, 1.0 / 255.0, 1.0 / 255.0));
+        assert_color(colors[1], srgb(1.0, 128.0 / 255.0, 0.0));
+    }
+
+    #[test]
+    fn hex_lines_do_not_affect_range_detection() {
+        let colors = parse_text("#ffffff\n0.5 0.5 0.5").unwrap();
+        assert_color(colors[0], LinearRgba::WHITE);
+        assert_color(colors[1], srgb(0.5, 0.5, 0.5));
+
+        let colors = parse_text("#ffffff\n128 128 128").unwrap();
+        assert_color(colors[0], LinearRgba::WHITE);
+        assert_color(colors[1], srgb(128.0 / 255.0, 128.0 / 255.0, 128.0 / 255.0));
+    }
+
+    #[test]
+    fn skips_comments_and_blank_lines() {
+        let text = "// 自定义调色板\n\n  0 0 0  \n   \n// 中间的注释\n#ffffff\n";
+        let colors = parse_text(text).unwrap();
+        assert_eq!(colors.len(), 2);
+        assert_color(colors[0], LinearRgba::BLACK);
+        assert_color(colors[1], LinearRgba::WHITE);
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        // 行号计入注释与空行
+        assert_eq!(
+            parse_text("// 注释\n\n0 0").unwrap_err(),
+            "第 3 行需要三个分量"
+        );
+        assert_eq!(
+            parse_text("0 0 0\n1 1 1 1").unwrap_err(),
+            "第 2 行需要三个分量"
+        );
+        assert!(parse_text("0 0 x").unwrap_err().starts_with("第 1 行: "));
+        assert!(parse_text("#12345").unwrap_err().starts_with("第 1 行: "));
+    }
+
+    #[test]
+    fn png_orientation() {
+        let colors = [[255, 0, 0, 255], [0, 255, 0, 255], [0, 0, 255, 255]];
+        let expected = [LinearRgba::RED, LinearRgba::GREEN, LinearRgba::BLUE];
+
+        // 横向图片取中间一行，从左到右
+        let horizontal = image::RgbaImage::from_fn(3, 2, |x, y| {
+            image::Rgba(if y == 1 { colors[x as usize] } else { [0; 4] })
+        });
+        let parsed = parse_png(&horizontal);
+        assert_eq!(parsed.len(), 3);
+        for (actual, expected) in parsed.into_iter().zip(expected) {
+            assert_color(actual, expected);
+        }
+
+        // 竖向图片取中间一列，从下到上
+        let vertical = image::RgbaImage::from_fn(3, 3, |x, y| {
+            image::Rgba(if x == 1 {
+                colors[2 - y as usize]
+            } else {
+                [0; 4]
+            })
+        });
+        let vertical = image::imageops::crop_imm(&vertical, 0, 0, 2, 3).to_image();
+        let parsed = parse_png(&vertical);
+        assert_eq!(parsed.len(), 3);
+        for (actual, expected) in parsed.into_iter().zip(expected) {
+            assert_color(actual, expected);
+        }
+    }
+
+    #[test]
+    fn presets_keep_legacy_endpoints() {
+        let presets = presets();
+        let preset = |name: &str| {
+            presets
+                .iter()
+                .find(|colormap| colormap.name == name)
+                .unwrap_or_else(|| panic!("缺少调色板 {}", name))
+        };
+        assert!(presets
+            .iter()
+            .any(|colormap| colormap.name == DEFAULT_PRESET));
+        // 原先 reflector.wgsl 中各颜色算法在 0 与 1 处输出的线性 RGB
+        let legacy = [
+            ("algo_0", [0.4, 0.07, 0.15], [0.83, 0.85, 0.96]),
+            ("algo_1", [0.48, 0.5, 0.18], [1.0, 0.87, 0.99]),
+            ("algo_normal", [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]),
+            // 原算法中名为 blue 的 Oklab 端点实际是品红
+            ("algo_oklab", [1.0, 0.0, 0.0], [1.0, 0.0, 1.0]),
+            ("algo_heat5", [0.0, 0.0, 1.0], [1.0, 0.0, 0.0]),
+            ("algo_heat7", [0.0, 0.0, 0.0], [1.0, 1.0, 1.0]),
+        ];
+        for (name, low, high) in legacy {
+            let colormap = preset(name);
+            assert_color(
+                colormap.sample(0.0),
+                LinearRgba::from_f32_array_no_alpha(low),
+            );
+            assert_color(
+                colormap.sample(1.0),
+                LinearRgba::from_f32_array_no_alpha(high),
+            );
+        }
+        // 原算法的中点，落在两个采样点之间，误差在一个采样间隔内
+        let midpoints = [
+            ("algo_normal", Vec3::new(1.0, 1.0, 0.0)),
+            ("algo_oklab", Vec3::Y),
+        ];
+        for (name, expected) in midpoints {
+            let actual = preset(name).sample(0.5).to_vec3();
+            assert!(actual.abs_diff_eq(expected, 1e-2), "{}: {:?}", name, actual);
+        }
+    }
+}
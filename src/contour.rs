@@ -0,0 +1,306 @@
+//! 等高线
+//!
+//! 等高线在反射面片元着色器中按插值后的高度绘制：高度除以间距的小数部分接近整数处着色，
+//! 线宽以屏幕像素计，每 [`ContourSettings::major_every`] 条为一条加粗的主等高线。块边界与
+//! 查看面板的描边优先于等高线。开启标注时，在主等高线穿过促动器连线的位置放置高度文字，
+//! 文字随相机与反射面姿态每帧重新投影。
+
+use crate::{
+    actuator::ActuatorTopology, analysis::DisplayedHeights, cli::CliArgs,
+    helpers::camera_controller::CameraController, CustomMaterial, CustomMaterialHandle,
+    CustomTextFont, Reflector,
+};
+use bevy::{color::palettes::css::*, prelude::*, text::FontSmoothing, utils::HashSet};
+use std::fmt::{self, Formatter};
+
+/// 默认等高线间距（毫米）
+const DEFAULT_INTERVAL: f32 = 0.1;
+/// 默认每隔几条为主等高线
+const DEFAULT_MAJOR_EVERY: u32 = 5;
+/// 同时显示的标注数上限
+const MAX_LABELS: usize = 48;
+/// 同一高度的相邻标注之间的最小距离，相对口径半径
+const LABEL_SPACING: f32 = 0.3;
+
+pub struct ContourPlugin;
+
+impl Plugin for ContourPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<ContourRender>()
+            .init_state::<ContourLabels>()
+            .init_resource::<ContourSettings>()
+            .init_resource::<ContourEdges>()
+            .add_systems(PostStartup, spawn_contour_labels)
+            .add_systems(
+                Update,
+                (
+                    upload_contour_settings.run_if(
+                        resource_changed::<ContourSettings>.or(state_changed::<ContourRender>),
+                    ),
+                    update_contour_labels,
+                ),
+            );
+    }
+}
+
+/// 等高线显示开关
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash, States)]
+pub enum ContourRender {
+    Enable,
+    #[default]
+    Disable,
+}
+
+impl fmt::Display for ContourRender {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContourRender::Enable => write!(f, "显示"),
+            ContourRender::Disable => write!(f, "隐藏"),
+        }
+    }
+}
+
+/// 主等高线高度标注开关，仅在显示等高线时生效
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash, States)]
+pub enum ContourLabels {
+    Enable,
+    #[default]
+    Disable,
+}
+
+impl fmt::Display for ContourLabels {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContourLabels::Enable => write!(f, "显示"),
+            ContourLabels::Disable => write!(f, "隐藏"),
+        }
+    }
+}
+
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct ContourSettings {
+    /// 相邻等高线的高度差（毫米）
+    pub interval: f32,
+    /// 每隔几条为主等高线
+    pub major_every: u32,
+}
+
+impl FromWorld for ContourSettings {
+    fn from_world(world: &mut World) -> Self {
+        let (interval, major_every) = world
+            .get_resource::<CliArgs>()
+            .and_then(|cli| cli.contours)
+            .unwrap_or((DEFAULT_INTERVAL, DEFAULT_MAJOR_EVERY));
+        ContourSettings {
+            interval,
+            major_every,
+        }
+    }
+}
+
+impl ContourSettings {
+    /// 主等高线的高度差
+    pub fn major_interval(&self) -> f32 {
+        self.interval * self.major_every.max(1) as f32
+    }
+
+    /// 按倍数调整间距
+    pub fn scale(&mut self, factor: f32) {
+        self.interval = (self.interval * factor).max(1e-4);
+    }
+
+    pub fn label(&self) -> String {
+        format_height(self.interval, self.interval)
+    }
+}
+
+/// 按间距选择毫米或微米标注高度
+fn format_height(height: f32, interval: f32) -> String {
+    if interval < 0.1 {
+        format!("{:.1} μm", height * 1000.0)
+    } else {
+        format!("{:.2} mm", height)
+    }
+}
+
+/// 控制栏中显示等高线间距的文字
+#[derive(Component)]
+pub struct ContourIntervalText;
+
+/// 写入材质的等高线间距，0 表示不绘制
+fn material_interval(render: ContourRender, settings: &ContourSettings) -> f32 {
+    match render {
+        ContourRender::Enable => settings.interval,
+        ContourRender::Disable => 0.0,
+    }
+}
+
+fn upload_contour_settings(
+    render: Res<State<ContourRender>>,
+    settings: Res<ContourSettings>,
+    material_handle: Res<CustomMaterialHandle>,
+    mut materials: ResMut<Assets<CustomMaterial>>,
+    mut texts: Query<&mut Text, With<ContourIntervalText>>,
+) {
+    if let Some(material) = materials.get_mut(&material_handle.0) {
+        material.contour_interval = material_interval(*render.get(), &settings);
+        material.contour_major_every = settings.major_every.max(1);
+    }
+    for mut text in texts.iter_mut() {
+        text.0 = settings.label();
+    }
+}
+
+/// 各面板边上的促动器对，去除相邻面板共用的边
+#[derive(Resource, Debug)]
+struct ContourEdges {
+    edges: Vec<(u32, u32)>,
+    /// 口径半径（场景单位），用于标注间距
+    radius: f32,
+}
+
+impl FromWorld for ContourEdges {
+    fn from_world(world: &mut World) -> Self {
+        let topology = world.resource::<ActuatorTopology>();
+        let mut edges = HashSet::default();
+        for corners in topology.vertex_actuators().chunks_exact(4) {
+            for i in 0..4 {
+                let (a, b) = (corners[i], corners[(i + 1) % 4]);
+                if a != b {
+                    edges.insert((a.min(b), a.max(b)));
+                }
+            }
+        }
+        let mut edges: Vec<_> = edges.into_iter().collect();
+        edges.sort_unstable();
+        let radius = topology
+            .positions()
+            .iter()
+            .map(|p| p.truncate().length())
+            .fold(0.0, f32::max);
+        ContourEdges { edges, radius }
+    }
+}
+
+impl ContourEdges {
+    /// 主等高线与促动器连线的交点（局部坐标）及其高度。同一高度的交点彼此至少相距
+    /// [`LABEL_SPACING`] 倍口径半径，避免标注挤在一起；总数不超过 [`MAX_LABELS`]
+    fn label_points(
+        &self,
+        topology: &ActuatorTopology,
+        heights: &[f32],
+        major_interval: f32,
+        offset: impl Fn(u32, f32) -> Vec3,
+    ) -> Vec<(Vec3, f32)> {
+        let spacing = LABEL_SPACING * self.radius;
+        let mut points: Vec<(Vec3, f32)> = Vec::new();
+        for &(a, b) in &self.edges {
+            let (Some(&ha), Some(&hb)) = (heights.get(a as usize), heights.get(b as usize)) else {
+                continue;
+            };
+            let (la, lb) = (ha / major_interval, hb / major_interval);
+            // 相邻促动器之间一般只跨过一条主等高线
+            let level = la.max(lb).floor();
+            if level < la.min(lb) || la == lb || !level.is_finite() {
+                continue;
+            }
+            let t = (level - la) / (lb - la);
+            let height = level * major_interval;
+            let [pa, pb] = [a, b].map(|id| topology.positions()[id as usize]);
+            let position = pa.lerp(pb, t) + offset(a, height).lerp(offset(b, height), t);
+            let crowded = points.iter().any(|&(p, h)| {
+                (h - height).abs() < major_interval * 0.5 && p.distance(position) < spacing
+            });
+            if !crowded {
+                points.push((position, height));
+            }
+        }
+        // 超出上限时等间隔抽取，使标注分布到整个口径
+        let step = points.len().div_ceil(MAX_LABELS).max(1);
+        points.into_iter().step_by(step).collect()
+    }
+}
+
+#[derive(Component)]
+struct ContourLabel;
+
+// 预先生成固定数量的标注，每帧只更新位置与文字
+fn spawn_contour_labels(mut commands: Commands, font: Res<CustomTextFont>) {
+    let text_font = TextFont {
+        font: font.0.clone(),
+        font_size: 12.0,
+        font_smoothing: FontSmoothing::AntiAliased,
+    };
+    for _ in 0..MAX_LABELS {
+        commands.spawn((
+            ContourLabel,
+            Text::default(),
+            text_font.clone(),
+            TextColor(WHITE.into()),
+            Node {
+                position_type: PositionType::Absolute,
+                padding: UiRect::horizontal(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(BLACK.with_alpha(0.5).into()),
+            Visibility::Hidden,
+            PickingBehavior::IGNORE,
+        ));
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn update_contour_labels(
+    render: Res<State<ContourRender>>,
+    labels_state: Res<State<ContourLabels>>,
+    settings: Res<ContourSettings>,
+    edges: Res<ContourEdges>,
+    topology: Res<ActuatorTopology>,
+    heights: Res<DisplayedHeights>,
+    material_handle: Res<CustomMaterialHandle>,
+    materials: Res<Assets<CustomMaterial>>,
+    camera: Single<(&Camera, &GlobalTransform), With<CameraController>>,
+    reflector: Single<&GlobalTransform, With<Reflector>>,
+    mut labels: Query<(&mut Text, &mut Node, &mut Visibility), With<ContourLabel>>,
+) {
+    let enabled = *render.get() == ContourRender::Enable
+        && *labels_state.get() == ContourLabels::Enable
+        && settings.interval > 0.0;
+    let points = match materials.get(&material_handle.0) {
+        Some(material) if enabled => {
+            // 几何位移模式下标注随曲面沿法线移动
+            let displacement = match material.enable_displacement {
+                1 => material.displacement_scale,
+                _ => 0.0,
+            };
+            edges.label_points(
+                &topology,
+                &heights,
+                settings.major_interval(),
+                |id, height| topology.normals()[id as usize] * height * displacement,
+            )
+        }
+        _ => Vec::new(),
+    };
+
+    let (camera, camera_transform) = *camera;
+    let mut points = points.into_iter().filter_map(|(local, height)| {
+        let world = reflector.transform_point(local);
+        let viewport = camera.world_to_viewport(camera_transform, world).ok()?;
+        Some((viewport, height))
+    });
+    for (mut text, mut node, mut visibility) in labels.iter_mut() {
+        match points.next() {
+            Some((viewport, height)) => {
+                let label = format_height(height, settings.major_interval());
+                if text.0 != label {
+                    text.0 = label;
+                }
+                node.left = Val::Px(viewport.x);
+                node.top = Val::Px(viewport.y);
+                *visibility = Visibility::Inherited;
+            }
+            None => *visibility = Visibility::Hidden,
+        }
+    }
+}
@@ -0,0 +1,603 @@
+//! 促动器故障注入
+//!
+//! 位于数据源与分析之间：在 [`HeightSet::Fault`] 中对数据源写入的 [`ActuatorHeights`]
+//! 施加故障，之后的分析、上传与录制看到的都是带故障的高度，用于检验监测功能。
+//! 数据源运行前先把有故障的促动器恢复为施加故障前的高度，数据源只更新部分促动器
+//! （如实时数据的稀疏帧）时，故障不会在上一帧的结果上重复叠加。
+//!
+//! 故障可以在反射面上点选促动器注入、由 `--faults` 按编号指定，或由 `--random-faults`
+//! 按种子随机注入。当前的故障可以保存为命名的场景文件，再由 `--fault-scenario` 读入：
+//!
+//! ```json
+//! {
+//!   "name": "ring-3-stuck",
+//!   "faults": [
+//!     { "actuator": 12, "kind": "stuck", "value": 0.3 },
+//!     { "actuator": 40, "kind": "dead" },
+//!     { "actuator": 77, "kind": "noisy", "sigma": 0.05 },
+//!     { "actuator": 100, "kind": "offset", "bias": 0.2 }
+//!   ]
+//! }
+//! ```
+
+use super::{ActuatorHeights, HeightSet};
+use crate::helpers::rng::SplitMix64;
+use crate::{actuator::ActuatorTopology, cli::CliArgs, Block};
+use bevy::{color::palettes::css::*, prelude::*};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Formatter},
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 点选注入噪声、偏置故障时的默认参数（毫米）
+pub const DEFAULT_NOISE_SIGMA: f32 = 0.05;
+pub const DEFAULT_OFFSET_BIAS: f32 = 0.2;
+/// 控制栏按钮每次随机注入的故障数
+pub const RANDOM_FAULT_COUNT: usize = 8;
+/// 控制栏按钮保存场景的目录
+const SCENARIO_DIR: &str = "fault-scenarios";
+
+pub struct FaultPlugin;
+
+impl Plugin for FaultPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<FaultPicking>()
+            .init_resource::<ActuatorFaults>()
+            .init_resource::<UnfaultedHeights>()
+            .add_systems(PostStartup, load_cli_faults)
+            .add_systems(
+                Update,
+                (
+                    restore_unfaulted_heights.before(HeightSet::Source),
+                    apply_faults.in_set(HeightSet::Fault),
+                    update_fault_status_text.run_if(resource_changed::<ActuatorFaults>),
+                ),
+            )
+            .add_observer(on_actuator_clicked);
+    }
+}
+
+/// 单个促动器的故障
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Fault {
+    // 卡在固定高度
+    Stuck { value: f32 },
+    // 失效，读数为零
+    Dead,
+    // 叠加标准差为 sigma 的高斯噪声
+    Noisy { sigma: f32 },
+    // 叠加固定偏置
+    Offset { bias: f32 },
+}
+
+impl fmt::Display for Fault {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Fault::Stuck { value } => write!(f, "卡死 {:.3} mm", value),
+            Fault::Dead => write!(f, "失效"),
+            Fault::Noisy { sigma } => write!(f, "噪声 σ={:.3} mm", sigma),
+            Fault::Offset { bias } => write!(f, "偏置 {:+.3} mm", bias),
+        }
+    }
+}
+
+/// 命令行写法：`stuck:<mm>`、`dead`、`noisy[:<σ>]`、`offset[:<mm>]`
+impl FromStr for Fault {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, param) = match s.split_once(':') {
+            Some((kind, param)) => (kind.trim(), Some(param.trim())),
+            None => (s.trim(), None),
+        };
+        let param = |default: Option<f32>| match param {
+            Some(v) => v.parse::<f32>().map_err(|_| format!("无效数值 {}", v)),
+            None => default.ok_or_else(|| format!("{} 需要数值", kind)),
+        };
+        match kind {
+            "stuck" => Ok(Fault::Stuck {
+                value: param(None)?,
+            }),
+            "dead" => Ok(Fault::Dead),
+            "noisy" => Ok(Fault::Noisy {
+                sigma: param(Some(DEFAULT_NOISE_SIGMA))?,
+            }),
+            "offset" => Ok(Fault::Offset {
+                bias: param(Some(DEFAULT_OFFSET_BIAS))?,
+            }),
+            _ => Err(format!("未知故障类型 {}", kind)),
+        }
+    }
+}
+
+impl Fault {
+    fn apply(&self, height: f32, rng: &mut SplitMix64) -> f32 {
+        match *self {
+            Fault::Stuck { value } => value,
+            Fault::Dead => 0.0,
+            Fault::Noisy { sigma } => height + sigma * gaussian(rng),
+            Fault::Offset { bias } => height + bias,
+        }
+    }
+
+    /// 促动器标记上表示该故障的颜色
+    pub fn marker_color(&self) -> Color {
+        match self {
+            Fault::Stuck { .. } => BLUE.into(),
+            Fault::Dead => BLACK.into(),
+            Fault::Noisy { .. } => YELLOW.into(),
+            Fault::Offset { .. } => FUCHSIA.into(),
+        }
+    }
+}
+
+/// 标准正态分布（Box–Muller）
+fn gaussian(rng: &mut SplitMix64) -> f32 {
+    let u1 = 1.0 - rng.next_f64();
+    let u2 = rng.next_f64();
+    ((-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()) as f32
+}
+
+/// 点选促动器时注入的故障类型，禁用时点选无效
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash, States)]
+pub enum FaultPicking {
+    #[default]
+    Disable,
+    Stuck,
+    Dead,
+    Noisy,
+    Offset,
+}
+
+impl fmt::Display for FaultPicking {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FaultPicking::Disable => write!(f, "禁用"),
+            FaultPicking::Stuck => write!(f, "卡死"),
+            FaultPicking::Dead => write!(f, "失效"),
+            FaultPicking::Noisy => write!(f, "噪声"),
+            FaultPicking::Offset => write!(f, "偏置"),
+        }
+    }
+}
+
+/// 场景文件内容
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FaultScenario {
+    pub name: String,
+    pub faults: Vec<FaultEntry>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct FaultEntry {
+    pub actuator: u32,
+    #[serde(flatten)]
+    pub fault: Fault,
+}
+
+impl FaultScenario {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let text = fs::read_to_string(path)?;
+        serde_json::from_str(&text).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        let text = serde_json::to_string_pretty(self)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        fs::write(path, text)
+    }
+}
+
+/// 当前注入的故障，按促动器编号存储
+#[derive(Resource, Debug, Default)]
+pub struct ActuatorFaults {
+    faults: BTreeMap<u32, Fault>,
+    /// 场景名，保存时作为文件名
+    pub name: Option<String>,
+    /// 下一次随机注入使用的种子
+    pub seed: u64,
+}
+
+impl ActuatorFaults {
+    pub fn len(&self) -> usize {
+        self.faults.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.faults.is_empty()
+    }
+
+    pub fn get(&self, actuator: u32) -> Option<&Fault> {
+        self.faults.get(&actuator)
+    }
+
+    pub fn insert(&mut self, actuator: u32, fault: Fault) {
+        self.faults.insert(actuator, fault);
+    }
+
+    pub fn remove(&mut self, actuator: u32) -> Option<Fault> {
+        self.faults.remove(&actuator)
+    }
+
+    pub fn clear(&mut self) {
+        self.faults.clear();
+        self.name = None;
+    }
+
+    /// 按种子随机选取 `count` 个无故障的促动器，注入随机类型的故障。卡死值取当前高度
+    pub fn inject_random(&mut self, count: usize, heights: &[f32], seed: u64) {
+        let actuator_count = heights.len();
+        if actuator_count == 0 {
+            return;
+        }
+        let mut rng = SplitMix64::new(seed);
+        for _ in 0..count.min(actuator_count) {
+            // 跳过已有故障的促动器，保证注入数量
+            let actuator = loop {
+                let id = (rng.next_u64() % actuator_count as u64) as u32;
+                if !self.faults.contains_key(&id) || self.faults.len() >= actuator_count {
+                    break id;
+                }
+            };
+            let fault = match rng.next_u64() % 4 {
+                0 => Fault::Stuck {
+                    value: heights[actuator as usize],
+                },
+                1 => Fault::Dead,
+                2 => Fault::Noisy {
+                    sigma: DEFAULT_NOISE_SIGMA,
+                },
+                _ => Fault::Offset {
+                    bias: DEFAULT_OFFSET_BIAS,
+                },
+            };
+            self.faults.insert(actuator, fault);
+        }
+    }
+
+    pub fn scenario(&self, name: &str) -> FaultScenario {
+        FaultScenario {
+            name: name.to_string(),
+            faults: self
+                .faults
+                .iter()
+                .map(|(&actuator, &fault)| FaultEntry { actuator, fault })
+                .collect(),
+        }
+    }
+
+    pub fn load_scenario(&mut self, scenario: FaultScenario) {
+        self.faults = scenario
+            .faults
+            .iter()
+            .map(|entry| (entry.actuator, entry.fault))
+            .collect();
+        self.name = Some(scenario.name);
+    }
+
+    /// 保存为 `fault-scenarios/<场景名>.json`，未命名时以当前时间命名
+    pub fn save_scenario(&mut self) -> io::Result<PathBuf> {
+        let name = self.name.clone().unwrap_or_else(|| {
+            #[cfg(not(target_arch = "wasm32"))]
+            let secs = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs());
+            #[cfg(target_arch = "wasm32")]
+            let secs = 0;
+            format!("scenario-{}", secs)
+        });
+        let path = Path::new(SCENARIO_DIR).join(format!("{}.json", name));
+        self.scenario(&name).save(&path)?;
+        self.name = Some(name);
+        Ok(path)
+    }
+}
+
+fn load_cli_faults(
+    cli: Res<CliArgs>,
+    heights: Res<ActuatorHeights>,
+    mut faults: ResMut<ActuatorFaults>,
+) {
+    if let Some(path) = &cli.fault_scenario {
+        match FaultScenario::load(path) {
+            Ok(scenario) => {
+                info!(
+                    "读入故障场景 {}: {} 个故障",
+                    scenario.name,
+                    scenario.faults.len()
+                );
+                faults.load_scenario(scenario);
+            }
+            Err(err) => error!("无法读取故障场景 {}: {}", path.display(), err),
+        }
+    }
+    for &(actuator, fault) in cli.faults.iter().flatten() {
+        faults.insert(actuator, fault);
+    }
+    if let Some((count, seed)) = cli.random_faults {
+        faults.inject_random(count, &heights, seed);
+        faults.seed = seed.wrapping_add(1);
+    }
+}
+
+/// 施加故障前的高度，以及上一次施加故障后各故障促动器的高度
+#[derive(Resource, Debug, Default)]
+struct UnfaultedHeights {
+    raw: Vec<f32>,
+    applied: Vec<(u32, f32)>,
+}
+
+// 把有故障的促动器恢复为施加故障前的高度，使数据源在未施加故障的高度上更新。
+// 恢复不算作数据源的更新，数据源本帧未更新时由 apply_faults 写回故障后的高度
+fn restore_unfaulted_heights(
+    mut heights: ResMut<ActuatorHeights>,
+    unfaulted: Res<UnfaultedHeights>,
+) {
+    if unfaulted.raw.len() != heights.len() {
+        return;
+    }
+    let heights = heights.bypass_change_detection();
+    for &(actuator, _) in &unfaulted.applied {
+        heights[actuator as usize] = unfaulted.raw[actuator as usize];
+    }
+}
+
+// 数据源更新时保存原始高度，故障或原始高度变化时重新施加故障。
+// 本系统自身对高度的修改不会在下一次运行时被视为变化
+fn apply_faults(
+    mut heights: ResMut<ActuatorHeights>,
+    faults: Res<ActuatorFaults>,
+    mut unfaulted: ResMut<UnfaultedHeights>,
+    mut rng: Local<SplitMix64>,
+) {
+    // 噪声故障每帧重新采样，即使数据源没有更新
+    let noisy = faults
+        .faults
+        .values()
+        .any(|fault| matches!(fault, Fault::Noisy { .. }));
+    let unfaulted = unfaulted.as_mut();
+    if heights.is_changed() {
+        unfaulted.raw.clone_from(&heights.0);
+    } else if !faults.is_changed() && !noisy {
+        let heights = heights.bypass_change_detection();
+        for &(actuator, value) in &unfaulted.applied {
+            if let Some(height) = heights.get_mut(actuator as usize) {
+                *height = value;
+            }
+        }
+        return;
+    }
+    heights.0.clone_from(&unfaulted.raw);
+    unfaulted.applied.clear();
+    for (&actuator, fault) in &faults.faults {
+        if let Some(height) = heights.0.get_mut(actuator as usize) {
+            *height = fault.apply(*height, &mut rng);
+            unfaulted.applied.push((actuator, *height));
+        }
+    }
+}
+
+// 点选模式下点击反射面，取最近的促动器：已有故障则移除，否则注入所选类型的故障
+fn on_actuator_clicked(
+    trigger: Trigger<Pointer<Down>>,
+    picking: Res<State<FaultPicking>>,
+    blocks: Query<&GlobalTransform, With<Block>>,
+    topology: Res<ActuatorTopology>,
+    heights: Res<ActuatorHeights>,
+    mut faults: ResMut<ActuatorFaults>,
+) {
+    let Ok(transform) = blocks.get(trigger.entity()) else {
+        return;
+    };
+    let Some(position) = trigger.event().hit.position else {
+        return;
+    };
+    let local = transform.affine().inverse().transform_point3(position);
+    let Some(actuator) = topology.nearest(local) else {
+        return;
+    };
+    let fault = match picking.get() {
+        FaultPicking::Disable => return,
+        FaultPicking::Stuck => Fault::Stuck {
+            value: heights.get(actuator as usize).copied().unwrap_or(0.0),
+        },
+        FaultPicking::Dead => Fault::Dead,
+        FaultPicking::Noisy => Fault::Noisy {
+            sigma: DEFAULT_NOISE_SIGMA,
+        },
+        FaultPicking::Offset => Fault::Offset {
+            bias: DEFAULT_OFFSET_BIAS,
+        },
+    };
+    match faults.remove(actuator) {
+        Some(removed) => info!("促动器 {} 移除故障: {}", actuator, removed),
+        None => {
+            info!("促动器 {} 注入故障: {}", actuator, fault);
+            faults.insert(actuator, fault);
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct FaultStatusText;
+
+fn update_fault_status_text(
+    faults: Res<ActuatorFaults>,
+    mut text: Query<&mut Text, With<FaultStatusText>>,
+) {
+    let label = match &faults.name {
+        _ if faults.is_empty() => "无故障".to_string(),
+        Some(name) => format!("故障 {} 个 ({})", faults.len(), name),
+        None => format!("故障 {} 个", faults.len()),
+    };
+    for mut text in text.iter_mut() {
+        *text = Text::new(label.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 下一帧数据源写入的稀疏更新
+    #[derive(Resource, Default)]
+    struct SparseUpdate(Vec<(usize, f32)>);
+
+    fn write_sparse_update(mut update: ResMut<SparseUpdate>, mut heights: ResMut<ActuatorHeights>) {
+        for (actuator, value) in update.0.drain(..) {
+            heights[actuator] = value;
+        }
+    }
+
+    fn fault_app(heights: Vec<f32>) -> App {
+        let mut app = App::new();
+        app.insert_resource(ActuatorHeights(heights))
+            .init_resource::<SparseUpdate>()
+            .init_resource::<ActuatorFaults>()
+            .init_resource::<UnfaultedHeights>()
+            .configure_sets(Update, (HeightSet::Source, HeightSet::Fault).chain())
+            .add_systems(
+                Update,
+                (
+                    restore_unfaulted_heights.before(HeightSet::Source),
+                    write_sparse_update
+                        .in_set(HeightSet::Source)
+                        .run_if(|update: Res<SparseUpdate>| !update.0.is_empty()),
+                    apply_faults.in_set(HeightSet::Fault),
+                ),
+            );
+        app
+    }
+
+    fn push_update(app: &mut App, actuator: usize, value: f32) {
+        app.world_mut()
+            .resource_mut::<SparseUpdate>()
+            .0
+            .push((actuator, value));
+    }
+
+    #[test]
+    fn sparse_updates_apply_offset_once() {
+        let mut app = fault_app(vec![1.0, 2.0, 3.0]);
+        app.world_mut()
+            .resource_mut::<ActuatorFaults>()
+            .insert(0, Fault::Offset { bias: 0.5 });
+        app.update();
+        assert_eq!(app.world().resource::<ActuatorHeights>().0, [1.5, 2.0, 3.0]);
+
+        // 两帧只更新促动器 2 的稀疏数据
+        for value in [4.0, 5.0] {
+            push_update(&mut app, 2, value);
+            app.update();
+            assert_eq!(
+                app.world().resource::<ActuatorHeights>().0,
+                [1.5, 2.0, value]
+            );
+        }
+
+        // 数据源没有更新时保持带故障的高度
+        app.update();
+        assert_eq!(app.world().resource::<ActuatorHeights>().0, [1.5, 2.0, 5.0]);
+
+        // 更新有故障的促动器时，偏置叠加在新的高度上
+        push_update(&mut app, 0, 10.0);
+        app.update();
+        assert_eq!(
+            app.world().resource::<ActuatorHeights>().0,
+            [10.5, 2.0, 5.0]
+        );
+
+        app.world_mut().resource_mut::<ActuatorFaults>().clear();
+        app.update();
+        assert_eq!(
+            app.world().resource::<ActuatorHeights>().0,
+            [10.0, 2.0, 5.0]
+        );
+    }
+
+    #[test]
+    fn noise_does_not_accumulate() {
+        let mut app = fault_app(vec![0.0, 0.0]);
+        app.world_mut()
+            .resource_mut::<ActuatorFaults>()
+            .insert(1, Fault::Noisy { sigma: 0.1 });
+        let mut values = vec![];
+        for _ in 0..200 {
+            push_update(&mut app, 0, 0.0);
+            app.update();
+            values.push(app.world().resource::<ActuatorHeights>()[1]);
+        }
+        // 每帧围绕原始高度重新采样，而不是随机游走
+        assert!(values.iter().all(|v| v.abs() < 0.6));
+        let mean = values.iter().sum::<f32>() / values.len() as f32;
+        assert!(mean.abs() < 0.05, "均值 {}", mean);
+        assert!(values.windows(2).any(|w| w[0] != w[1]));
+    }
+
+    #[test]
+    fn parses_faults() {
+        assert_eq!("stuck:0.3".parse(), Ok(Fault::Stuck { value: 0.3 }));
+        assert_eq!("dead".parse(), Ok(Fault::Dead));
+        assert_eq!(
+            "noisy".parse(),
+            Ok(Fault::Noisy {
+                sigma: DEFAULT_NOISE_SIGMA
+            })
+        );
+        assert_eq!(" noisy : 0.1 ".parse(), Ok(Fault::Noisy { sigma: 0.1 }));
+        assert_eq!(
+            "offset".parse(),
+            Ok(Fault::Offset {
+                bias: DEFAULT_OFFSET_BIAS
+            })
+        );
+        assert_eq!("offset:-0.2".parse(), Ok(Fault::Offset { bias: -0.2 }));
+        assert!("stuck".parse::<Fault>().is_err());
+        assert!("offset:abc".parse::<Fault>().is_err());
+        assert!("broken".parse::<Fault>().is_err());
+    }
+
+    #[test]
+    fn scenario_round_trip() {
+        let mut faults = ActuatorFaults::default();
+        faults.insert(12, Fault::Stuck { value: 0.3 });
+        faults.insert(40, Fault::Dead);
+        faults.insert(77, Fault::Noisy { sigma: 0.05 });
+        faults.insert(100, Fault::Offset { bias: -0.2 });
+
+        let path =
+            std::env::temp_dir().join(format!("fault-scenario-test-{}.json", std::process::id()));
+        faults.scenario("ring-3").save(&path).unwrap();
+        let scenario = FaultScenario::load(&path);
+        let _ = fs::remove_file(&path);
+
+        let mut loaded = ActuatorFaults::default();
+        loaded.load_scenario(scenario.unwrap());
+        assert_eq!(loaded.name.as_deref(), Some("ring-3"));
+        assert_eq!(loaded.faults, faults.faults);
+    }
+
+    #[test]
+    fn random_injection_is_deterministic() {
+        let heights = (0..100).map(|i| i as f32 * 0.01).collect::<Vec<_>>();
+        let inject = |seed| {
+            let mut faults = ActuatorFaults::default();
+            faults.inject_random(8, &heights, seed);
+            faults.faults
+        };
+        assert_eq!(inject(7).len(), 8);
+        assert_eq!(inject(7), inject(7));
+        assert_ne!(inject(7), inject(8));
+    }
+}
@@ -0,0 +1,815 @@
+//! 实时高度数据：监听本地 TCP / UDP 端口，接收控制软件推送的促动器高度
+//!
+//! 帧格式（小端）：
+//!
+//! ```text
+//! "RH"   2 字节帧头
+//! kind   u8，1 = 全帧，2 = 稀疏更新
+//! count  u32
+//! 全帧   count 个 f32，按促动器编号顺序
+//! 稀疏   count 组 (u32 促动器编号, f32 高度)
+//! ```
+//!
+//! TCP 连接断开后可重新连接；流中不完整的帧会缓存到下一次读取，帧头错误时向后查找下一个帧头。
+//! UDP 每个数据报独立解析，数据报末尾的不完整帧直接丢弃。
+//!
+//! 监听线程只在数据源为实时数据时运行，切换到其它数据源时停止并释放端口。主循环来不及处理
+//! 时，监听线程把新到的帧合并到待处理的更新中而不是排队：全帧取代之前的全部更新，稀疏更新
+//! 按促动器编号覆盖，积压的内存有上限且不会丢失只发送过一次的稀疏值。连接状态与错误消息
+//! 经有容量上限的通道传递。
+
+use super::{ActuatorHeights, DataSource, HeightSet};
+use crate::cli::CliArgs;
+use bevy::prelude::*;
+use std::{
+    collections::BTreeMap,
+    fmt, io,
+    net::SocketAddr,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, SyncSender, TryRecvError, TrySendError},
+        Arc, Mutex,
+    },
+    thread::JoinHandle,
+    time::Duration,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use std::{
+    io::Read,
+    net::{TcpListener, UdpSocket},
+};
+
+const FRAME_MAGIC: [u8; 2] = *b"RH";
+const FRAME_HEADER_LEN: usize = 7;
+const FRAME_KIND_FULL: u8 = 1;
+const FRAME_KIND_SPARSE: u8 = 2;
+/// 单帧最多包含的数据项，防止错误的 count 导致无限等待或超大内存分配
+const MAX_FRAME_ITEMS: usize = 1 << 20;
+/// 监听线程到主循环的状态消息通道容量（消息数）
+const LIVE_CHANNEL_BOUND: usize = 16;
+/// 监听线程检查停止标志的间隔
+#[cfg(not(target_arch = "wasm32"))]
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+pub struct LiveFeedPlugin;
+
+impl Plugin for LiveFeedPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LiveFeedStatus>()
+            .add_systems(OnEnter(DataSource::Live), start_live_feed)
+            .add_systems(OnExit(DataSource::Live), stop_live_feed)
+            .add_systems(
+                Update,
+                (
+                    receive_live_heights
+                        .in_set(HeightSet::Source)
+                        .run_if(in_state(DataSource::Live)),
+                    update_live_status_text,
+                ),
+            );
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LiveProtocol {
+    Tcp,
+    Udp,
+}
+
+impl fmt::Display for LiveProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LiveProtocol::Tcp => write!(f, "tcp"),
+            LiveProtocol::Udp => write!(f, "udp"),
+        }
+    }
+}
+
+/// 实时数据配置，命令行写法 `--live tcp://127.0.0.1:9000` 或 `--live udp://0.0.0.0:9000`
+#[derive(Debug, Clone)]
+pub struct LiveFeedConfig {
+    pub protocol: LiveProtocol,
+    pub addr: SocketAddr,
+    /// 超过该时长（秒）未收到数据视为过期
+    pub stale_timeout: f32,
+}
+
+impl Default for LiveFeedConfig {
+    fn default() -> Self {
+        LiveFeedConfig {
+            protocol: LiveProtocol::Tcp,
+            addr: SocketAddr::from(([127, 0, 0, 1], 9000)),
+            stale_timeout: 2.0,
+        }
+    }
+}
+
+impl FromStr for LiveFeedConfig {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (protocol, addr) = match s.split_once("://") {
+            Some(("tcp", addr)) => (LiveProtocol::Tcp, addr),
+            Some(("udp", addr)) => (LiveProtocol::Udp, addr),
+            Some((scheme, _)) => return Err(format!("不支持的协议 {}，应为 tcp 或 udp", scheme)),
+            None => (LiveProtocol::Tcp, s),
+        };
+        let addr = addr
+            .parse::<SocketAddr>()
+            .map_err(|err| format!("无效的地址 {}: {}", addr, err))?;
+        Ok(LiveFeedConfig {
+            protocol,
+            addr,
+            ..default()
+        })
+    }
+}
+
+/// 一帧高度更新
+#[derive(Debug, Clone, PartialEq)]
+pub enum HeightFrame {
+    // 全部促动器的高度
+    Full(Vec<f32>),
+    // 部分促动器的 (编号, 高度)
+    Sparse(Vec<(u32, f32)>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FrameDecodeError {
+    BadMagic { skipped: usize },
+    UnknownKind(u8),
+    TooManyItems(usize),
+}
+
+impl fmt::Display for FrameDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FrameDecodeError::BadMagic { skipped } => {
+                write!(f, "帧头错误，跳过 {} 字节", skipped)
+            }
+            FrameDecodeError::UnknownKind(kind) => write!(f, "未知的帧类型 {}", kind),
+            FrameDecodeError::TooManyItems(count) => write!(f, "帧数据项过多: {}", count),
+        }
+    }
+}
+
+/// 从字节流中切分帧，不完整的帧保留到下次 [`FrameDecoder::push`] 之后继续解析
+#[derive(Debug, Default)]
+pub struct FrameDecoder {
+    buf: Vec<u8>,
+}
+
+impl FrameDecoder {
+    pub fn push(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+
+    pub fn clear(&mut self) {
+        self.buf.clear();
+    }
+
+    /// 取出下一帧；数据不足一帧时返回 `None`
+    pub fn next_frame(&mut self) -> Option<Result<HeightFrame, FrameDecodeError>> {
+        if self.buf.len() < FRAME_MAGIC.len() {
+            return None;
+        }
+        if self.buf[..2] != FRAME_MAGIC {
+            // 向后查找下一个帧头重新同步
+            let skipped = self
+                .buf
+                .windows(2)
+                .position(|w| w == FRAME_MAGIC)
+                .unwrap_or(self.buf.len() - 1);
+            self.buf.drain(..skipped);
+            return Some(Err(FrameDecodeError::BadMagic { skipped }));
+        }
+        if self.buf.len() < FRAME_HEADER_LEN {
+            return None;
+        }
+
+        let kind = self.buf[2];
+        let count = u32::from_le_bytes(self.buf[3..7].try_into().unwrap()) as usize;
+        let item_len = match kind {
+            FRAME_KIND_FULL => 4,
+            FRAME_KIND_SPARSE => 8,
+            _ => {
+                self.buf.drain(..FRAME_MAGIC.len());
+                return Some(Err(FrameDecodeError::UnknownKind(kind)));
+            }
+        };
+        if count > MAX_FRAME_ITEMS {
+            self.buf.drain(..FRAME_MAGIC.len());
+            return Some(Err(FrameDecodeError::TooManyItems(count)));
+        }
+        let frame_len = FRAME_HEADER_LEN + count * item_len;
+        if self.buf.len() < frame_len {
+            return None;
+        }
+
+        let payload = &self.buf[FRAME_HEADER_LEN..frame_len];
+        let word = |chunk: &[u8]| <[u8; 4]>::try_from(chunk).unwrap();
+        let frame = match kind {
+            FRAME_KIND_FULL => HeightFrame::Full(
+                payload
+                    .chunks_exact(4)
+                    .map(|c| f32::from_le_bytes(word(c)))
+                    .collect(),
+            ),
+            _ => HeightFrame::Sparse(
+                payload
+                    .chunks_exact(8)
+                    .map(|c| {
+                        (
+                            u32::from_le_bytes(word(&c[..4])),
+                            f32::from_le_bytes(word(&c[4..])),
+                        )
+                    })
+                    .collect(),
+            ),
+        };
+        self.buf.drain(..frame_len);
+        Some(Ok(frame))
+    }
+}
+
+#[derive(Debug)]
+enum LiveMessage {
+    Connected(SocketAddr),
+    Disconnected(SocketAddr),
+    Error(String),
+}
+
+/// 发送一条错误消息，通道已满时丢弃；接收端已关闭时返回 false
+fn send_error(sender: &SyncSender<LiveMessage>, message: String) -> bool {
+    !matches!(
+        sender.try_send(LiveMessage::Error(message)),
+        Err(TrySendError::Disconnected(_))
+    )
+}
+
+/// 监听线程收到、主循环尚未写入高度的帧，合并后保存
+#[derive(Debug, Default)]
+struct PendingFrames {
+    full: Option<Vec<f32>>,
+    sparse: BTreeMap<u32, f32>,
+    /// 合并进来的帧数
+    count: u64,
+}
+
+type SharedFrames = Arc<Mutex<PendingFrames>>;
+
+impl PendingFrames {
+    fn push(&mut self, frame: HeightFrame) {
+        match frame {
+            HeightFrame::Full(values) => {
+                self.full = Some(values);
+                self.sparse.clear();
+            }
+            HeightFrame::Sparse(values) => self.sparse.extend(values),
+        }
+        self.count += 1;
+    }
+
+    /// 把合并后的更新写入高度并清空，返回写入的帧数。与逐帧写入的结果相同
+    fn apply(&mut self, heights: &mut ActuatorHeights) -> u64 {
+        let pending = std::mem::take(self);
+        let mut applied = false;
+        if let Some(values) = pending.full {
+            applied |= apply_frame(heights, HeightFrame::Full(values));
+        }
+        if !pending.sparse.is_empty() {
+            let values = pending.sparse.into_iter().collect();
+            applied |= apply_frame(heights, HeightFrame::Sparse(values));
+        }
+        if applied {
+            pending.count
+        } else {
+            0
+        }
+    }
+}
+
+/// 把解码结果合并到待处理的帧，解码错误经通道报告；接收端已关闭时返回 false
+fn forward_frames(
+    decoder: &mut FrameDecoder,
+    peer: SocketAddr,
+    pending: &SharedFrames,
+    sender: &SyncSender<LiveMessage>,
+) -> bool {
+    while let Some(frame) = decoder.next_frame() {
+        match frame {
+            Ok(frame) => pending.lock().unwrap().push(frame),
+            Err(err) => {
+                if !send_error(sender, format!("{}: {}", peer, err)) {
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
+
+/// 后台监听线程，drop 时通知线程退出并等待其释放端口
+struct LiveListener {
+    addr: SocketAddr,
+    shutdown: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for LiveListener {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// 后台监听线程发来的帧与消息
+#[derive(Resource)]
+struct LiveFeed {
+    config: LiveFeedConfig,
+    pending: SharedFrames,
+    receiver: Mutex<Receiver<LiveMessage>>,
+    _listener: LiveListener,
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct LiveFeedStatus {
+    pub listening: Option<String>,
+    pub connections: usize,
+    pub frames: u64,
+    pub last_frame_secs: Option<f32>,
+    pub stale: bool,
+}
+
+#[derive(Component)]
+pub struct LiveStatusText;
+
+fn start_live_feed(mut commands: Commands, cli: Res<CliArgs>, mut status: ResMut<LiveFeedStatus>) {
+    let mut config = cli.live.clone().unwrap_or_default();
+    if let Some(timeout) = cli.live_timeout {
+        config.stale_timeout = timeout;
+    }
+    let (sender, receiver) = mpsc::sync_channel(LIVE_CHANNEL_BOUND);
+    let pending = SharedFrames::default();
+    match spawn_listener(&config, pending.clone(), sender) {
+        Ok(listener) => {
+            status.listening = Some(format!("{}://{}", config.protocol, listener.addr));
+            commands.insert_resource(LiveFeed {
+                config,
+                pending,
+                receiver: Mutex::new(receiver),
+                _listener: listener,
+            });
+        }
+        Err(err) => {
+            warn!("实时数据: 监听 {} 失败: {}", config.addr, err);
+            status.listening = None;
+        }
+    }
+}
+
+// 移除资源即停止监听线程，已建立的连接在下一次读取超时后关闭
+fn stop_live_feed(mut commands: Commands, mut status: ResMut<LiveFeedStatus>) {
+    commands.remove_resource::<LiveFeed>();
+    *status = LiveFeedStatus::default();
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn spawn_listener(
+    config: &LiveFeedConfig,
+    pending: SharedFrames,
+    sender: SyncSender<LiveMessage>,
+) -> io::Result<LiveListener> {
+    let shutdown = Arc::new(AtomicBool::new(false));
+    let flag = shutdown.clone();
+    let (addr, thread) = match config.protocol {
+        LiveProtocol::Tcp => {
+            let listener = TcpListener::bind(config.addr)?;
+            listener.set_nonblocking(true)?;
+            let addr = listener.local_addr()?;
+            (
+                addr,
+                std::thread::spawn(move || run_tcp(listener, &pending, &sender, &flag)),
+            )
+        }
+        LiveProtocol::Udp => {
+            let socket = UdpSocket::bind(config.addr)?;
+            socket.set_read_timeout(Some(POLL_INTERVAL))?;
+            let addr = socket.local_addr()?;
+            (
+                addr,
+                std::thread::spawn(move || run_udp(socket, &pending, &sender, &flag)),
+            )
+        }
+    };
+    Ok(LiveListener {
+        addr,
+        shutdown,
+        thread: Some(thread),
+    })
+}
+
+#[cfg(target_arch = "wasm32")]
+fn spawn_listener(
+    _config: &LiveFeedConfig,
+    _pending: SharedFrames,
+    _sender: SyncSender<LiveMessage>,
+) -> io::Result<LiveListener> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "Web 版本不支持实时数据",
+    ))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn is_timeout(err: &io::Error) -> bool {
+    matches!(
+        err.kind(),
+        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut | io::ErrorKind::Interrupted
+    )
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn run_tcp(
+    listener: TcpListener,
+    pending: &SharedFrames,
+    sender: &SyncSender<LiveMessage>,
+    shutdown: &Arc<AtomicBool>,
+) {
+    while !shutdown.load(Ordering::Relaxed) {
+        let (mut stream, peer) = match listener.accept() {
+            Ok(accepted) => accepted,
+            Err(err) if is_timeout(&err) => {
+                std::thread::sleep(POLL_INTERVAL);
+                continue;
+            }
+            Err(err) => {
+                send_error(sender, err.to_string());
+                continue;
+            }
+        };
+        // 接受的连接继承了非阻塞设置，改为带超时的阻塞读取以便检查停止标志
+        if stream.set_nonblocking(false).is_err()
+            || stream.set_read_timeout(Some(POLL_INTERVAL)).is_err()
+        {
+            continue;
+        }
+        let pending = pending.clone();
+        let sender = sender.clone();
+        let shutdown = shutdown.clone();
+        // 每个连接一个线程，连接断开后线程退出，监听线程继续接受新的连接
+        std::thread::spawn(move || {
+            // 连接状态消息不可丢失，否则连接数会计错
+            if sender.send(LiveMessage::Connected(peer)).is_err() {
+                return;
+            }
+            let mut decoder = FrameDecoder::default();
+            let mut buf = [0u8; 64 * 1024];
+            while !shutdown.load(Ordering::Relaxed) {
+                match stream.read(&mut buf) {
+                    Ok(0) => break,
+                    Ok(n) => decoder.push(&buf[..n]),
+                    Err(err) if is_timeout(&err) => continue,
+                    Err(_) => break,
+                }
+                if !forward_frames(&mut decoder, peer, &pending, &sender) {
+                    return;
+                }
+            }
+            let _ = sender.send(LiveMessage::Disconnected(peer));
+        });
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn run_udp(
+    socket: UdpSocket,
+    pending: &SharedFrames,
+    sender: &SyncSender<LiveMessage>,
+    shutdown: &Arc<AtomicBool>,
+) {
+    let mut decoder = FrameDecoder::default();
+    let mut buf = vec![0u8; 64 * 1024];
+    while !shutdown.load(Ordering::Relaxed) {
+        let (n, peer) = match socket.recv_from(&mut buf) {
+            Ok(received) => received,
+            Err(err) if is_timeout(&err) => continue,
+            Err(err) => {
+                send_error(sender, err.to_string());
+                return;
+            }
+        };
+        decoder.clear();
+        decoder.push(&buf[..n]);
+        if !forward_frames(&mut decoder, peer, pending, sender) {
+            return;
+        }
+    }
+}
+
+fn receive_live_heights(
+    feed: Option<Res<LiveFeed>>,
+    mut heights: ResMut<ActuatorHeights>,
+    mut status: ResMut<LiveFeedStatus>,
+    time: Res<Time>,
+) {
+    let Some(feed) = feed else {
+        return;
+    };
+    let now = time.elapsed_secs();
+    {
+        let mut pending = feed.pending.lock().unwrap();
+        if pending.count > 0 {
+            let frames = pending.apply(&mut heights);
+            if frames > 0 {
+                status.frames += frames;
+                status.last_frame_secs = Some(now);
+            }
+        }
+    }
+    let receiver = feed.receiver.lock().unwrap();
+    loop {
+        match receiver.try_recv() {
+            Ok(LiveMessage::Connected(peer)) => {
+                info!("实时数据已连接: {}", peer);
+                status.connections += 1;
+            }
+            Ok(LiveMessage::Disconnected(peer)) => {
+                info!("实时数据已断开: {}", peer);
+                status.connections = status.connections.saturating_sub(1);
+            }
+            Ok(LiveMessage::Error(err)) => warn!("实时数据: {}", err),
+            Err(TryRecvError::Empty) => break,
+            // 监听线程已退出（如端口被占用）
+            Err(TryRecvError::Disconnected) => {
+                status.listening = None;
+                break;
+            }
+        }
+    }
+
+    // 超时未收到数据时标记为过期，保留最后一帧
+    let stale = status
+        .last_frame_secs
+        .is_none_or(|last| now - last > feed.config.stale_timeout);
+    if stale != status.stale {
+        if stale && status.last_frame_secs.is_some() {
+            warn!("实时数据超过 {:.1}s 未更新", feed.config.stale_timeout);
+        }
+        status.stale = stale;
+    }
+}
+
+/// 把一帧写入促动器高度，帧与促动器数不匹配时丢弃
+fn apply_frame(heights: &mut ActuatorHeights, frame: HeightFrame) -> bool {
+    match frame {
+        HeightFrame::Full(values) => {
+            if values.len() != heights.len() {
+                warn!(
+                    "实时数据全帧长度 {} 与促动器数 {} 不一致，已丢弃",
+                    values.len(),
+                    heights.len()
+                );
+                return false;
+            }
+            heights.0 = values;
+        }
+        HeightFrame::Sparse(values) => {
+            let count = heights.len();
+            let mut out_of_range = 0;
+            for (id, value) in values {
+                match heights.get_mut(id as usize) {
+                    Some(height) => *height = value,
+                    None => out_of_range += 1,
+                }
+            }
+            if out_of_range > 0 {
+                warn!(
+                    "实时数据中 {} 个促动器编号超出范围 (共 {} 个促动器)",
+                    out_of_range, count
+                );
+            }
+        }
+    }
+    true
+}
+
+fn update_live_status_text(
+    status: Res<LiveFeedStatus>,
+    data_source: Res<State<DataSource>>,
+    mut text: Query<&mut Text, With<LiveStatusText>>,
+) {
+    if !status.is_changed() && !data_source.is_changed() {
+        return;
+    }
+    let label = match (data_source.get(), &status.listening) {
+        (DataSource::Mock | DataSource::Playback, _) => String::new(),
+        (DataSource::Live, None) => "未监听".to_string(),
+        (DataSource::Live, Some(addr)) => format!(
+            "{} 连接: {} 帧: {}{}",
+            addr,
+            status.connections,
+            status.frames,
+            if status.stale { " (数据过期)" } else { "" }
+        ),
+    };
+    for mut text in text.iter_mut() {
+        *text = Text::new(label.clone());
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use std::{
+        io::Write,
+        net::{TcpStream, UdpSocket},
+        time::Instant,
+    };
+
+    const RECV_TIMEOUT: Duration = Duration::from_secs(2);
+
+    fn full_frame(values: &[f32]) -> Vec<u8> {
+        let mut bytes = b"RH".to_vec();
+        bytes.push(1);
+        bytes.extend((values.len() as u32).to_le_bytes());
+        for value in values {
+            bytes.extend(value.to_le_bytes());
+        }
+        bytes
+    }
+
+    fn sparse_frame(values: &[(u32, f32)]) -> Vec<u8> {
+        let mut bytes = b"RH".to_vec();
+        bytes.push(2);
+        bytes.extend((values.len() as u32).to_le_bytes());
+        for (id, value) in values {
+            bytes.extend(id.to_le_bytes());
+            bytes.extend(value.to_le_bytes());
+        }
+        bytes
+    }
+
+    fn start(protocol: LiveProtocol) -> (LiveListener, SharedFrames, Receiver<LiveMessage>) {
+        let config = LiveFeedConfig {
+            protocol,
+            addr: SocketAddr::from(([127, 0, 0, 1], 0)),
+            ..default()
+        };
+        let (sender, receiver) = mpsc::sync_channel(LIVE_CHANNEL_BOUND);
+        let pending = SharedFrames::default();
+        let listener = spawn_listener(&config, pending.clone(), sender).expect("绑定回环地址失败");
+        (listener, pending, receiver)
+    }
+
+    fn recv(receiver: &Receiver<LiveMessage>) -> LiveMessage {
+        receiver
+            .recv_timeout(RECV_TIMEOUT)
+            .expect("等待实时数据超时")
+    }
+
+    /// 等待 `frames` 帧到达并写入高度
+    fn apply_frames(pending: &SharedFrames, heights: &mut ActuatorHeights, frames: u64) {
+        let deadline = Instant::now() + RECV_TIMEOUT;
+        let mut applied = 0;
+        while applied < frames {
+            let mut pending = pending.lock().unwrap();
+            if pending.count > 0 {
+                applied += pending.apply(heights);
+                continue;
+            }
+            drop(pending);
+            assert!(Instant::now() < deadline, "等待实时数据超时");
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        assert_eq!(applied, frames);
+    }
+
+    #[test]
+    fn tcp_frames_and_reconnect() {
+        let (listener, pending, receiver) = start(LiveProtocol::Tcp);
+        let mut heights = ActuatorHeights(vec![0.0; 4]);
+
+        let mut stream = TcpStream::connect(listener.addr).unwrap();
+        assert!(matches!(recv(&receiver), LiveMessage::Connected(_)));
+
+        stream
+            .write_all(&full_frame(&[1.0, 2.0, 3.0, 4.0]))
+            .unwrap();
+        apply_frames(&pending, &mut heights, 1);
+        assert_eq!(heights.0, [1.0, 2.0, 3.0, 4.0]);
+
+        stream
+            .write_all(&sparse_frame(&[(1, -2.0), (3, 0.5)]))
+            .unwrap();
+        apply_frames(&pending, &mut heights, 1);
+        assert_eq!(heights.0, [1.0, -2.0, 3.0, 0.5]);
+
+        // 一帧分两次写入，解码器应等到数据完整后再输出
+        let frame = full_frame(&[5.0, 6.0, 7.0, 8.0]);
+        let (head, tail) = frame.split_at(7);
+        stream.write_all(head).unwrap();
+        stream.flush().unwrap();
+        std::thread::sleep(Duration::from_millis(100));
+        assert_eq!(pending.lock().unwrap().count, 0, "不完整的帧不应被输出");
+        stream.write_all(tail).unwrap();
+        apply_frames(&pending, &mut heights, 1);
+        assert_eq!(heights.0, [5.0, 6.0, 7.0, 8.0]);
+
+        drop(stream);
+        assert!(matches!(recv(&receiver), LiveMessage::Disconnected(_)));
+
+        // 断开后重新连接
+        let mut stream = TcpStream::connect(listener.addr).unwrap();
+        assert!(matches!(recv(&receiver), LiveMessage::Connected(_)));
+        stream.write_all(&sparse_frame(&[(0, 9.0)])).unwrap();
+        apply_frames(&pending, &mut heights, 1);
+        assert_eq!(heights.0, [9.0, 6.0, 7.0, 8.0]);
+    }
+
+    #[test]
+    fn udp_datagram_with_multiple_frames() {
+        let (listener, pending, receiver) = start(LiveProtocol::Udp);
+        let mut heights = ActuatorHeights(vec![0.0; 3]);
+
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let mut datagram = full_frame(&[1.0, 2.0, 3.0]);
+        datagram.extend(sparse_frame(&[(2, -1.0)]));
+        socket.send_to(&datagram, listener.addr).unwrap();
+
+        apply_frames(&pending, &mut heights, 2);
+        assert_eq!(heights.0, [1.0, 2.0, -1.0]);
+        drop(receiver);
+    }
+
+    #[test]
+    fn flooded_sparse_frames_are_merged() {
+        let (listener, pending, receiver) = start(LiveProtocol::Tcp);
+        let mut heights = ActuatorHeights(vec![0.0; 64]);
+
+        let mut stream = TcpStream::connect(listener.addr).unwrap();
+        assert!(matches!(
+            receiver.recv_timeout(RECV_TIMEOUT),
+            Ok(LiveMessage::Connected(_))
+        ));
+        // 远超通道容量的稀疏帧，全部发送完之前主循环不读取；第 i 帧把促动器 i % 64 设为 i
+        let frames = 64 * LIVE_CHANNEL_BOUND as u64;
+        for i in 0..frames {
+            stream
+                .write_all(&sparse_frame(&[((i % 64) as u32, i as f32)]))
+                .unwrap();
+        }
+        apply_frames(&pending, &mut heights, frames);
+        // 每个促动器为最后一次发送的值
+        let expected = (0..64)
+            .map(|id| (frames - 64 + id) as f32)
+            .collect::<Vec<_>>();
+        assert_eq!(heights.0, expected);
+    }
+
+    #[test]
+    fn pending_frames_match_sequential_application() {
+        let frames = [
+            HeightFrame::Sparse(vec![(0, 9.0)]),
+            HeightFrame::Full(vec![1.0, 2.0, 3.0]),
+            HeightFrame::Sparse(vec![(1, -2.0), (2, 5.0)]),
+            HeightFrame::Sparse(vec![(2, 6.0), (7, 1.0)]),
+        ];
+        let mut sequential = ActuatorHeights(vec![0.0; 3]);
+        for frame in frames.clone() {
+            apply_frame(&mut sequential, frame);
+        }
+
+        let mut pending = PendingFrames::default();
+        for frame in frames {
+            pending.push(frame);
+        }
+        let mut merged = ActuatorHeights(vec![0.0; 3]);
+        assert_eq!(pending.apply(&mut merged), 4);
+        assert_eq!(merged.0, sequential.0);
+        assert_eq!(merged.0, [1.0, -2.0, 6.0]);
+        assert_eq!(pending.count, 0);
+    }
+
+    #[test]
+    fn full_frame_with_wrong_length_is_dropped() {
+        let mut heights = ActuatorHeights(vec![1.0; 3]);
+        assert!(!apply_frame(&mut heights, HeightFrame::Full(vec![0.0; 2])));
+        assert_eq!(heights.0, [1.0; 3]);
+    }
+
+    #[test]
+    fn listener_releases_port_on_drop() {
+        let (listener, _, receiver) = start(LiveProtocol::Tcp);
+        let addr = listener.addr;
+        drop(listener);
+        assert!(matches!(
+            receiver.recv_timeout(RECV_TIMEOUT),
+            Err(mpsc::RecvTimeoutError::Disconnected)
+        ));
+        assert!(TcpListener::bind(addr).is_ok(), "停止后端口应被释放");
+    }
+}
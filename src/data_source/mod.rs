@@ -0,0 +1,73 @@
+//! 高度数据源
+//!
+//! 各数据源（模拟函数、实时数据等）在 [`HeightSet::Source`] 中写入 [`ActuatorHeights`]，
+//! 在 [`HeightSet::Fault`] 中施加注入的促动器故障，在 [`HeightSet::Analysis`] 中计算面形
+//! 指标，随后在 [`HeightSet::Upload`] 中统一上传到反射面材质的高度缓冲。
+
+pub mod fault;
+pub mod live;
+pub mod recording;
+
+use bevy::prelude::*;
+use std::fmt::{self, Formatter};
+
+pub struct DataSourcePlugin;
+
+impl Plugin for DataSourcePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<DataSource>()
+            .init_resource::<ActuatorHeights>()
+            .configure_sets(
+                Update,
+                (
+                    HeightSet::Source,
+                    HeightSet::Fault,
+                    HeightSet::Analysis,
+                    HeightSet::Upload,
+                )
+                    .chain(),
+            )
+            .add_plugins((
+                live::LiveFeedPlugin,
+                recording::RecordingPlugin,
+                fault::FaultPlugin,
+            ));
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash, States)]
+pub enum DataSource {
+    // 模拟函数生成的数据
+    #[default]
+    Mock,
+    // 本地端口接收的实时数据
+    Live,
+    // 回放录制文件
+    Playback,
+}
+
+impl fmt::Display for DataSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DataSource::Mock => write!(f, "模拟"),
+            DataSource::Live => write!(f, "实时"),
+            DataSource::Playback => write!(f, "回放"),
+        }
+    }
+}
+
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HeightSet {
+    // 数据源写入 ActuatorHeights
+    Source,
+    // 对 ActuatorHeights 施加注入的促动器故障
+    Fault,
+    // 根据 ActuatorHeights 计算面形指标
+    Analysis,
+    // ActuatorHeights 上传到 GPU 并录制
+    Upload,
+}
+
+/// 当前各促动器的高度，按促动器编号存储
+#[derive(Resource, Debug, Default, Clone, Deref, DerefMut)]
+pub struct ActuatorHeights(pub Vec<f32>);
@@ -0,0 +1,511 @@
+//! 高度数据录制与回放
+//!
+//! 录制文件格式（小端）：
+//!
+//! ```text
+//! "RHRC"          4 字节文件头
+//! version         u16，当前为 1
+//! actuator_count  u32
+//! 之后每帧：timestamp f64（距录制开始的秒数）+ actuator_count 个 f32
+//! ```
+
+use super::{ActuatorHeights, DataSource, HeightSet};
+use crate::timeline::SimulationClock;
+use crate::{actuator::ActuatorTopology, cli::CliArgs};
+use bevy::prelude::*;
+use std::{
+    fmt, fs,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const RECORDING_MAGIC: [u8; 4] = *b"RHRC";
+const RECORDING_VERSION: u16 = 1;
+const RECORDING_HEADER_LEN: usize = 10;
+/// 控制栏按钮开始录制时，文件保存到该目录
+const RECORDING_DIR: &str = "recordings";
+
+pub struct RecordingPlugin;
+
+impl Plugin for RecordingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Recorder>()
+            .add_systems(Startup, start_cli_recording)
+            .add_systems(OnEnter(DataSource::Playback), load_playback)
+            .add_systems(
+                Update,
+                (
+                    sample_playback
+                        .in_set(HeightSet::Source)
+                        .run_if(in_state(DataSource::Playback)),
+                    record_heights.in_set(HeightSet::Upload),
+                    update_recording_status_text,
+                ),
+            );
+    }
+}
+
+#[derive(Debug)]
+pub enum RecordingError {
+    Io(io::Error),
+    BadMagic,
+    UnsupportedVersion(u16),
+    Truncated,
+    ActuatorCountMismatch { recording: usize, geometry: usize },
+}
+
+impl fmt::Display for RecordingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecordingError::Io(err) => write!(f, "{}", err),
+            RecordingError::BadMagic => write!(f, "不是高度录制文件"),
+            RecordingError::UnsupportedVersion(version) => {
+                write!(f, "不支持的录制文件版本 {}", version)
+            }
+            RecordingError::Truncated => write!(f, "录制文件不完整"),
+            RecordingError::ActuatorCountMismatch {
+                recording,
+                geometry,
+            } => write!(
+                f,
+                "录制文件包含 {} 个促动器，当前几何有 {} 个",
+                recording, geometry
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RecordingError {}
+
+impl From<io::Error> for RecordingError {
+    fn from(err: io::Error) -> Self {
+        RecordingError::Io(err)
+    }
+}
+
+/// 读入内存的录制数据
+#[derive(Debug, Clone)]
+pub struct Recording {
+    pub actuator_count: usize,
+    pub timestamps: Vec<f64>,
+    frames: Vec<f32>,
+}
+
+impl Recording {
+    pub fn load(path: &Path) -> Result<Self, RecordingError> {
+        let bytes = fs::read(path)?;
+        if bytes.len() < RECORDING_HEADER_LEN {
+            return Err(RecordingError::Truncated);
+        }
+        if bytes[..4] != RECORDING_MAGIC {
+            return Err(RecordingError::BadMagic);
+        }
+        let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+        if version != RECORDING_VERSION {
+            return Err(RecordingError::UnsupportedVersion(version));
+        }
+        let actuator_count = u32::from_le_bytes(bytes[6..10].try_into().unwrap()) as usize;
+
+        let frame_len = 8 + actuator_count * 4;
+        let body = &bytes[RECORDING_HEADER_LEN..];
+        if body.len() % frame_len != 0 {
+            return Err(RecordingError::Truncated);
+        }
+        let mut timestamps = Vec::with_capacity(body.len() / frame_len);
+        let mut frames = Vec::with_capacity(body.len() / frame_len * actuator_count);
+        for frame in body.chunks_exact(frame_len) {
+            timestamps.push(f64::from_le_bytes(frame[..8].try_into().unwrap()));
+            frames.extend(
+                frame[8..]
+                    .chunks_exact(4)
+                    .map(|c| f32::from_le_bytes(c.try_into().unwrap())),
+            );
+        }
+        Ok(Recording {
+            actuator_count,
+            timestamps,
+            frames,
+        })
+    }
+
+    pub fn frame_count(&self) -> usize {
+        self.timestamps.len()
+    }
+
+    pub fn duration(&self) -> f64 {
+        self.timestamps.last().copied().unwrap_or(0.0)
+    }
+
+    pub fn frame(&self, index: usize) -> &[f32] {
+        &self.frames[index * self.actuator_count..(index + 1) * self.actuator_count]
+    }
+
+    /// 取时刻 `t` 的高度，在相邻两帧之间线性插值
+    pub fn sample(&self, t: f64) -> Vec<f32> {
+        if self.timestamps.is_empty() {
+            return vec![0.0; self.actuator_count];
+        }
+        let next = self.timestamps.partition_point(|&ts| ts <= t);
+        if next == 0 {
+            return self.frame(0).to_vec();
+        }
+        if next == self.frame_count() {
+            return self.frame(next - 1).to_vec();
+        }
+        let (t0, t1) = (self.timestamps[next - 1], self.timestamps[next]);
+        let w = if t1 > t0 {
+            ((t - t0) / (t1 - t0)) as f32
+        } else {
+            0.0
+        };
+        self.frame(next - 1)
+            .iter()
+            .zip(self.frame(next))
+            .map(|(a, b)| a + (b - a) * w)
+            .collect()
+    }
+
+    /// 导出为 CSV：每行一帧，首列为时间，之后按促动器编号排列
+    pub fn write_csv(&self, path: &Path) -> io::Result<()> {
+        let mut writer = BufWriter::new(fs::File::create(path)?);
+        write!(writer, "time")?;
+        for id in 0..self.actuator_count {
+            write!(writer, ",a{}", id)?;
+        }
+        writeln!(writer)?;
+        for (index, timestamp) in self.timestamps.iter().enumerate() {
+            write!(writer, "{}", timestamp)?;
+            for value in self.frame(index) {
+                write!(writer, ",{}", value)?;
+            }
+            writeln!(writer)?;
+        }
+        writer.flush()
+    }
+}
+
+/// 正在写入的录制文件
+struct RecordingWriter {
+    writer: BufWriter<fs::File>,
+    path: PathBuf,
+    actuator_count: usize,
+    start_secs: f64,
+    frames: u64,
+}
+
+impl RecordingWriter {
+    fn create(path: &Path, actuator_count: usize, start_secs: f64) -> io::Result<Self> {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        let mut writer = BufWriter::new(fs::File::create(path)?);
+        writer.write_all(&RECORDING_MAGIC)?;
+        writer.write_all(&RECORDING_VERSION.to_le_bytes())?;
+        writer.write_all(&(actuator_count as u32).to_le_bytes())?;
+        Ok(RecordingWriter {
+            writer,
+            path: path.to_path_buf(),
+            actuator_count,
+            start_secs,
+            frames: 0,
+        })
+    }
+
+    fn write_frame(&mut self, now_secs: f64, heights: &[f32]) -> io::Result<()> {
+        self.writer
+            .write_all(&(now_secs - self.start_secs).to_le_bytes())?;
+        for value in heights {
+            self.writer.write_all(&value.to_le_bytes())?;
+        }
+        self.frames += 1;
+        Ok(())
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct Recorder {
+    session: Option<RecordingWriter>,
+    /// 最近一次录制的文件，用作回放的默认来源
+    pub last_path: Option<PathBuf>,
+}
+
+impl Recorder {
+    pub fn is_recording(&self) -> bool {
+        self.session.is_some()
+    }
+
+    pub fn start(&mut self, path: &Path, actuator_count: usize, now_secs: f64) {
+        self.stop();
+        match RecordingWriter::create(path, actuator_count, now_secs) {
+            Ok(session) => {
+                info!("开始录制: {}", path.display());
+                self.session = Some(session);
+            }
+            Err(err) => error!("无法创建录制文件 {}: {}", path.display(), err),
+        }
+    }
+
+    pub fn stop(&mut self) {
+        if let Some(mut session) = self.session.take() {
+            if let Err(err) = session.writer.flush() {
+                error!("写入录制文件 {} 失败: {}", session.path.display(), err);
+            }
+            info!(
+                "录制结束: {} ({} 帧)",
+                session.path.display(),
+                session.frames
+            );
+            self.last_path = Some(session.path);
+        }
+    }
+
+    /// 以当前时间命名的默认录制文件
+    pub fn default_path() -> PathBuf {
+        #[cfg(not(target_arch = "wasm32"))]
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        #[cfg(target_arch = "wasm32")]
+        let secs = 0;
+        Path::new(RECORDING_DIR).join(format!("heights-{}.rhrec", secs))
+    }
+}
+
+/// 正在回放的录制文件，回放进度由 [`SimulationClock`] 决定
+#[derive(Resource)]
+pub struct Playback {
+    pub recording: Recording,
+    pub path: PathBuf,
+}
+
+#[derive(Component)]
+pub struct RecordingStatusText;
+
+// 启动时高度缓冲可能尚未按几何初始化，文件的促动器数取自拓扑
+fn start_cli_recording(
+    cli: Res<CliArgs>,
+    topology: Res<ActuatorTopology>,
+    mut recorder: ResMut<Recorder>,
+    time: Res<Time>,
+) {
+    if let Some(path) = &cli.record {
+        recorder.start(path, topology.actuator_count(), time.elapsed_secs_f64());
+    }
+}
+
+fn record_heights(heights: Res<ActuatorHeights>, mut recorder: ResMut<Recorder>, time: Res<Time>) {
+    if !heights.is_changed() {
+        return;
+    }
+    let Some(session) = recorder.session.as_mut() else {
+        return;
+    };
+    if session.actuator_count != heights.len() {
+        return;
+    }
+    if let Err(err) = session.write_frame(time.elapsed_secs_f64(), &heights) {
+        error!("写入录制文件失败: {}", err);
+        recorder.stop();
+    }
+}
+
+/// 进入回放时加载录制文件：优先使用本次运行最近一次录制的文件，其次为 `--replay` 指定的文件。
+/// 时间轴长度随之设为录制时长
+fn load_playback(
+    mut commands: Commands,
+    cli: Res<CliArgs>,
+    recorder: Res<Recorder>,
+    heights: Res<ActuatorHeights>,
+    playback: Option<Res<Playback>>,
+    mut clock: ResMut<SimulationClock>,
+    mut next_data_source: ResMut<NextState<DataSource>>,
+) {
+    let path = recorder.last_path.as_ref().or(cli.replay.as_ref());
+    let Some(path) = path else {
+        warn!("没有可回放的录制文件，请先录制或使用 --replay 指定");
+        next_data_source.set(DataSource::Mock);
+        return;
+    };
+    if let Some(playback) = playback.filter(|playback| &playback.path == path) {
+        clock.set_duration(playback.recording.duration());
+        return;
+    }
+    let recording = Recording::load(path).and_then(|recording| {
+        if recording.actuator_count == heights.len() {
+            Ok(recording)
+        } else {
+            Err(RecordingError::ActuatorCountMismatch {
+                recording: recording.actuator_count,
+                geometry: heights.len(),
+            })
+        }
+    });
+    match recording {
+        Ok(recording) => {
+            info!(
+                "回放 {}: {} 帧, {:.1}s",
+                path.display(),
+                recording.frame_count(),
+                recording.duration()
+            );
+            clock.set_duration(recording.duration());
+            clock.seek(0.0);
+            commands.insert_resource(Playback {
+                recording,
+                path: path.clone(),
+            });
+        }
+        Err(err) => {
+            error!("无法加载录制文件 {}: {}", path.display(), err);
+            next_data_source.set(DataSource::Mock);
+        }
+    }
+}
+
+fn sample_playback(
+    playback: Option<Res<Playback>>,
+    clock: Res<SimulationClock>,
+    mut heights: ResMut<ActuatorHeights>,
+) {
+    let Some(playback) = playback else {
+        return;
+    };
+    if playback.is_changed() || clock.is_changed() {
+        heights.0 = playback.recording.sample(clock.elapsed());
+    }
+}
+
+fn update_recording_status_text(
+    recorder: Res<Recorder>,
+    playback: Option<Res<Playback>>,
+    data_source: Res<State<DataSource>>,
+    mut text: Query<&mut Text, With<RecordingStatusText>>,
+) {
+    let mut label = String::new();
+    if let Some(session) = &recorder.session {
+        label.push_str(&format!("● 录制中 {} 帧", session.frames));
+    }
+    if let (DataSource::Playback, Some(playback)) = (data_source.get(), &playback) {
+        if !label.is_empty() {
+            label.push(' ');
+        }
+        let name = playback
+            .path
+            .file_name()
+            .unwrap_or(playback.path.as_os_str());
+        label.push_str(&format!("回放 {}", name.to_string_lossy()));
+    }
+    for mut text in text.iter_mut() {
+        if text.0 != label {
+            text.0 = label.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 测试用的临时文件，drop 时删除
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str) -> Self {
+            TempFile(std::env::temp_dir().join(format!(
+                "recording-test-{}-{}.rhrec",
+                std::process::id(),
+                name
+            )))
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    fn write_recording(file: &TempFile, frames: &[(f64, [f32; 3])]) {
+        let mut writer = RecordingWriter::create(&file.0, 3, 10.0).unwrap();
+        for (t, heights) in frames {
+            writer.write_frame(10.0 + t, heights).unwrap();
+        }
+        writer.writer.flush().unwrap();
+    }
+
+    fn sample_recording() -> Recording {
+        Recording {
+            actuator_count: 2,
+            timestamps: vec![0.0, 1.0, 3.0],
+            frames: vec![0.0, 10.0, 1.0, 20.0, 3.0, 0.0],
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let file = TempFile::new("round-trip");
+        write_recording(&file, &[(0.0, [1.0, 2.0, 3.0]), (0.5, [-1.0, 0.0, 0.25])]);
+        let recording = Recording::load(&file.0).unwrap();
+        assert_eq!(recording.actuator_count, 3);
+        assert_eq!(recording.timestamps, [0.0, 0.5]);
+        assert_eq!(recording.frame(0), [1.0, 2.0, 3.0]);
+        assert_eq!(recording.frame(1), [-1.0, 0.0, 0.25]);
+        assert_eq!(recording.duration(), 0.5);
+    }
+
+    #[test]
+    fn rejects_bad_files() {
+        let file = TempFile::new("bad");
+        write_recording(&file, &[(0.0, [1.0, 2.0, 3.0])]);
+        let bytes = fs::read(&file.0).unwrap();
+
+        // 最后一帧缺少数据
+        fs::write(&file.0, &bytes[..bytes.len() - 1]).unwrap();
+        assert!(matches!(
+            Recording::load(&file.0),
+            Err(RecordingError::Truncated)
+        ));
+        // 文件头不完整
+        fs::write(&file.0, &bytes[..6]).unwrap();
+        assert!(matches!(
+            Recording::load(&file.0),
+            Err(RecordingError::Truncated)
+        ));
+
+        let mut bad_magic = bytes.clone();
+        bad_magic[0] = b'X';
+        fs::write(&file.0, &bad_magic).unwrap();
+        assert!(matches!(
+            Recording::load(&file.0),
+            Err(RecordingError::BadMagic)
+        ));
+
+        let mut bad_version = bytes.clone();
+        bad_version[4..6].copy_from_slice(&2u16.to_le_bytes());
+        fs::write(&file.0, &bad_version).unwrap();
+        assert!(matches!(
+            Recording::load(&file.0),
+            Err(RecordingError::UnsupportedVersion(2))
+        ));
+    }
+
+    #[test]
+    fn sample_interpolates_between_frames() {
+        let recording = sample_recording();
+        assert_eq!(recording.sample(0.0), [0.0, 10.0]);
+        assert_eq!(recording.sample(0.5), [0.5, 15.0]);
+        assert_eq!(recording.sample(1.0), [1.0, 20.0]);
+        assert_eq!(recording.sample(2.0), [2.0, 10.0]);
+    }
+
+    #[test]
+    fn sample_clamps_outside_recording() {
+        let recording = sample_recording();
+        assert_eq!(recording.sample(-1.0), [0.0, 10.0]);
+        assert_eq!(recording.sample(3.0), [3.0, 0.0]);
+        assert_eq!(recording.sample(100.0), [3.0, 0.0]);
+    }
+}
@@ -0,0 +1,546 @@
+//! 从外部文件加载面板几何
+//!
+//! 支持两种格式：
+//!
+//! CSV，每行一个面板角点，`corner` 取 0..4，顺序与 [`ReflectorGeometry`] 一致：
+//!
+//! ```text
+//! ring,sector,corner,x,y,z
+//! 0,0,0,0.9,0.0,0.050625
+//! ```
+//!
+//! JSON，`ring_blocks` 可省略，若给出则需与各圆环实际面板数一致：
+//!
+//! ```json
+//! {
+//!     "ring_blocks": [32, 32],
+//!     "panels": [
+//!         { "ring": 0, "sector": 0, "corners": [[0.9, 0.0, 0.05], [..], [..], [..]] }
+//!     ]
+//! }
+//! ```
+
+use super::ReflectorGeometry;
+use bevy::prelude::info;
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// 放入 `assets/` 目录即可自动加载的几何文件（按顺序查找）
+pub const ASSET_GEOMETRY_FILES: [&str; 2] = ["geometry/panels.json", "geometry/panels.csv"];
+
+#[derive(Debug)]
+pub enum GeometryLoadError {
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    UnsupportedFormat(PathBuf),
+    Csv {
+        line: usize,
+        message: String,
+    },
+    Json(serde_json::Error),
+    Empty,
+    NonQuadPanel {
+        ring: u32,
+        sector: u32,
+        corners: usize,
+    },
+    DuplicatePanel {
+        ring: u32,
+        sector: u32,
+    },
+    DuplicateCorner {
+        ring: u32,
+        sector: u32,
+        corner: u32,
+    },
+    MissingRing(u32),
+    MissingSector {
+        ring: u32,
+        sector: u32,
+    },
+    RingCountMismatch {
+        ring: u32,
+        declared: u32,
+        found: u32,
+    },
+    RingTotalMismatch {
+        declared: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for GeometryLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeometryLoadError::Io { path, source } => {
+                write!(f, "无法读取几何文件 {}: {}", path.display(), source)
+            }
+            GeometryLoadError::UnsupportedFormat(path) => {
+                write!(
+                    f,
+                    "不支持的几何文件格式 {}（仅支持 .csv / .json）",
+                    path.display()
+                )
+            }
+            GeometryLoadError::Csv { line, message } => {
+                write!(f, "CSV 第 {} 行: {}", line, message)
+            }
+            GeometryLoadError::Json(err) => write!(f, "JSON 解析失败: {}", err),
+            GeometryLoadError::Empty => write!(f, "几何文件中没有任何面板"),
+            GeometryLoadError::NonQuadPanel {
+                ring,
+                sector,
+                corners,
+            } => write!(
+                f,
+                "面板 (环 {}, 块 {}) 有 {} 个角点，面板必须为四边形",
+                ring, sector, corners
+            ),
+            GeometryLoadError::DuplicatePanel { ring, sector } => {
+                write!(f, "面板 (环 {}, 块 {}) 重复定义", ring, sector)
+            }
+            GeometryLoadError::DuplicateCorner {
+                ring,
+                sector,
+                corner,
+            } => write!(
+                f,
+                "面板 (环 {}, 块 {}) 的角点 {} 重复定义",
+                ring, sector, corner
+            ),
+            GeometryLoadError::MissingRing(ring) => write!(f, "缺少圆环 {}", ring),
+            GeometryLoadError::MissingSector { ring, sector } => {
+                write!(
+                    f,
+                    "圆环 {} 缺少面板 {}，块编号必须从 0 连续编号",
+                    ring, sector
+                )
+            }
+            GeometryLoadError::RingCountMismatch {
+                ring,
+                declared,
+                found,
+            } => write!(
+                f,
+                "圆环 {} 声明了 {} 块面板，实际有 {} 块",
+                ring, declared, found
+            ),
+            GeometryLoadError::RingTotalMismatch { declared, found } => write!(
+                f,
+                "ring_blocks 声明了 {} 个圆环，实际有 {} 个",
+                declared, found
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GeometryLoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GeometryLoadError::Io { source, .. } => Some(source),
+            GeometryLoadError::Json(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for GeometryLoadError {
+    fn from(err: serde_json::Error) -> Self {
+        GeometryLoadError::Json(err)
+    }
+}
+
+/// 按文件扩展名选择格式加载几何文件
+pub fn load_geometry_file(path: &Path) -> Result<ReflectorGeometry, GeometryLoadError> {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_ascii_lowercase());
+    let read = || {
+        fs::read_to_string(path).map_err(|source| GeometryLoadError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    };
+    match extension.as_deref() {
+        Some("csv") => parse_csv(&read()?),
+        Some("json") => parse_json(&read()?),
+        _ => Err(GeometryLoadError::UnsupportedFormat(path.to_path_buf())),
+    }
+}
+
+/// 查找 `assets/` 目录下的几何文件
+#[cfg(not(target_arch = "wasm32"))]
+pub fn find_asset_geometry_file() -> Option<PathBuf> {
+    let base = bevy::asset::io::file::FileAssetReader::get_base_path().join("assets");
+    ASSET_GEOMETRY_FILES
+        .iter()
+        .map(|file| base.join(file))
+        .find(|path| path.is_file())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn find_asset_geometry_file() -> Option<PathBuf> {
+    None
+}
+
+pub fn parse_csv(content: &str) -> Result<ReflectorGeometry, GeometryLoadError> {
+    let mut panels = PanelTable::default();
+    for (index, line) in content.lines().enumerate() {
+        let line_no = index + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields = line.split(',').map(str::trim).collect::<Vec<_>>();
+        // 表头
+        if fields[0].eq_ignore_ascii_case("ring") {
+            continue;
+        }
+        if fields.len() != 6 {
+            return Err(GeometryLoadError::Csv {
+                line: line_no,
+                message: format!(
+                    "需要 6 列 (ring,sector,corner,x,y,z)，实际 {} 列",
+                    fields.len()
+                ),
+            });
+        }
+        let int = |i: usize| {
+            fields[i]
+                .parse::<u32>()
+                .map_err(|err| GeometryLoadError::Csv {
+                    line: line_no,
+                    message: format!("第 {} 列 \"{}\" 不是非负整数: {}", i + 1, fields[i], err),
+                })
+        };
+        let float = |i: usize| {
+            fields[i]
+                .parse::<f32>()
+                .map_err(|err| GeometryLoadError::Csv {
+                    line: line_no,
+                    message: format!("第 {} 列 \"{}\" 不是数字: {}", i + 1, fields[i], err),
+                })
+        };
+        panels.insert_corner(int(0)?, int(1)?, int(2)?, [float(3)?, float(4)?, float(5)?])?;
+    }
+    panels.into_geometry(None)
+}
+
+#[derive(Deserialize)]
+struct JsonGeometry {
+    ring_blocks: Option<Vec<u32>>,
+    panels: Vec<JsonPanel>,
+}
+
+#[derive(Deserialize)]
+struct JsonPanel {
+    ring: u32,
+    sector: u32,
+    corners: Vec<[f32; 3]>,
+}
+
+pub fn parse_json(content: &str) -> Result<ReflectorGeometry, GeometryLoadError> {
+    let json: JsonGeometry = serde_json::from_str(content)?;
+    let mut panels = PanelTable::default();
+    for panel in json.panels {
+        if panel.corners.len() != 4 {
+            return Err(GeometryLoadError::NonQuadPanel {
+                ring: panel.ring,
+                sector: panel.sector,
+                corners: panel.corners.len(),
+            });
+        }
+        if panels.0.contains_key(&(panel.ring, panel.sector)) {
+            return Err(GeometryLoadError::DuplicatePanel {
+                ring: panel.ring,
+                sector: panel.sector,
+            });
+        }
+        for (corner, position) in panel.corners.into_iter().enumerate() {
+            panels.insert_corner(panel.ring, panel.sector, corner as u32, position)?;
+        }
+    }
+    panels.into_geometry(json.ring_blocks.as_deref())
+}
+
+/// 以 (环, 块) 为键收集面板角点
+#[derive(Default)]
+struct PanelTable(BTreeMap<(u32, u32), [Option<[f32; 3]>; 4]>);
+
+impl PanelTable {
+    fn insert_corner(
+        &mut self,
+        ring: u32,
+        sector: u32,
+        corner: u32,
+        position: [f32; 3],
+    ) -> Result<(), GeometryLoadError> {
+        let corners = self.0.entry((ring, sector)).or_default();
+        let Some(slot) = corners.get_mut(corner as usize) else {
+            return Err(GeometryLoadError::NonQuadPanel {
+                ring,
+                sector,
+                corners: corner as usize + 1,
+            });
+        };
+        if slot.is_some() {
+            return Err(GeometryLoadError::DuplicateCorner {
+                ring,
+                sector,
+                corner,
+            });
+        }
+        *slot = Some(position);
+        Ok(())
+    }
+
+    fn into_geometry(
+        self,
+        declared_blocks: Option<&[u32]>,
+    ) -> Result<ReflectorGeometry, GeometryLoadError> {
+        if self.0.is_empty() {
+            return Err(GeometryLoadError::Empty);
+        }
+
+        let mut ring_blocks: Vec<u32> = vec![];
+        let mut positions = Vec::with_capacity(self.0.len() * 4);
+        // BTreeMap 按 (环, 块) 排序，依次检查编号是否连续
+        for ((ring, sector), corners) in self.0 {
+            if ring as usize >= ring_blocks.len() {
+                if ring as usize > ring_blocks.len() {
+                    return Err(GeometryLoadError::MissingRing(ring_blocks.len() as u32));
+                }
+                ring_blocks.push(0);
+            }
+            let expected = ring_blocks[ring as usize];
+            if sector != expected {
+                return Err(GeometryLoadError::MissingSector {
+                    ring,
+                    sector: expected,
+                });
+            }
+            let found = corners.iter().flatten().count();
+            if found != 4 {
+                return Err(GeometryLoadError::NonQuadPanel {
+                    ring,
+                    sector,
+                    corners: found,
+                });
+            }
+            positions.extend(corners.into_iter().flatten());
+            ring_blocks[ring as usize] += 1;
+        }
+
+        if let Some(declared) = declared_blocks {
+            if declared.len() != ring_blocks.len() {
+                return Err(GeometryLoadError::RingTotalMismatch {
+                    declared: declared.len(),
+                    found: ring_blocks.len(),
+                });
+            }
+            for (ring, (&declared, &found)) in declared.iter().zip(&ring_blocks).enumerate() {
+                if declared != found {
+                    return Err(GeometryLoadError::RingCountMismatch {
+                        ring: ring as u32,
+                        declared,
+                        found,
+                    });
+                }
+            }
+        }
+
+        Ok(ReflectorGeometry {
+            positions,
+            ring_blocks,
+        })
+    }
+}
+
+/// 选择几何来源：优先使用命令行 `--geometry` 指定的文件，其次为 `assets/` 中的几何文件，
+/// 均未提供时按默认参数生成
+pub fn resolve_geometry(path: Option<&Path>) -> Result<ReflectorGeometry, GeometryLoadError> {
+    match path
+        .map(Path::to_path_buf)
+        .or_else(find_asset_geometry_file)
+    {
+        Some(path) => {
+            let geometry = load_geometry_file(&path)?;
+            info!(
+                "已从 {} 加载 {} 个圆环、{} 块面板",
+                path.display(),
+                geometry.ring_count(),
+                geometry.panel_count()
+            );
+            Ok(geometry)
+        }
+        None => Ok(ReflectorGeometry::default()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 两个圆环：内环 2 块、外环 1 块
+    const CSV: &str = "\
+ring,sector,corner,x,y,z
+0,0,0,1.0,0.0,0.1
+0,0,1,0.0,1.0,0.1
+0,0,2,0.0,2.0,0.4
+0,0,3,2.0,0.0,0.4
+# 注释行与空行被忽略
+
+0,1,0,0.0,1.0,0.1
+0,1,1,-1.0,0.0,0.1
+0,1,2,-2.0,0.0,0.4
+0,1,3,0.0,2.0,0.4
+1,0,3,2.0,0.0,0.4
+1,0,2,-2.0,0.0,0.4
+1,0,1,-3.0,0.0,0.9
+1,0,0,3.0,0.0,0.9
+";
+
+    fn json(ring_blocks: &str, panels: &[(u32, u32, usize)]) -> String {
+        let panels = panels
+            .iter()
+            .map(|&(ring, sector, corners)| {
+                let corners = vec!["[1.0, 2.0, 3.0]"; corners].join(", ");
+                format!(
+                    r#"{{ "ring": {}, "sector": {}, "corners": [{}] }}"#,
+                    ring, sector, corners
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(r#"{{ {} "panels": [{}] }}"#, ring_blocks, panels)
+    }
+
+    #[test]
+    fn parses_csv() {
+        let geometry = parse_csv(CSV).unwrap();
+        assert_eq!(geometry.ring_blocks(), [2, 1]);
+        assert_eq!(geometry.vertex_count(), 12);
+        assert_eq!(geometry.positions()[1], [0.0, 1.0, 0.1]);
+        // 角点按编号排列，与文件中的行序无关
+        assert_eq!(geometry.positions()[8], [3.0, 0.0, 0.9]);
+        assert_eq!(geometry.positions()[11], [2.0, 0.0, 0.4]);
+    }
+
+    #[test]
+    fn parses_json() {
+        let content = json(
+            r#""ring_blocks": [2, 1],"#,
+            &[(0, 0, 4), (0, 1, 4), (1, 0, 4)],
+        );
+        let geometry = parse_json(&content).unwrap();
+        assert_eq!(geometry.ring_blocks(), [2, 1]);
+        assert_eq!(geometry.panel_count(), 3);
+
+        // ring_blocks 可省略
+        let content = json("", &[(0, 0, 4)]);
+        assert_eq!(parse_json(&content).unwrap().ring_blocks(), [1]);
+    }
+
+    #[test]
+    fn rejects_non_quad_panel() {
+        let content = json("", &[(0, 0, 3)]);
+        assert!(matches!(
+            parse_json(&content),
+            Err(GeometryLoadError::NonQuadPanel { corners: 3, .. })
+        ));
+        // CSV 中缺少角点 3
+        let csv = CSV.replace("1,0,3,2.0,0.0,0.4\n", "");
+        assert!(matches!(
+            parse_csv(&csv),
+            Err(GeometryLoadError::NonQuadPanel {
+                ring: 1,
+                sector: 0,
+                corners: 3
+            })
+        ));
+        // 角点编号超出 0..4
+        let csv = format!("{}1,0,4,0.0,0.0,0.0\n", CSV);
+        assert!(matches!(
+            parse_csv(&csv),
+            Err(GeometryLoadError::NonQuadPanel { corners: 5, .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_duplicate_panel() {
+        let content = json("", &[(0, 0, 4), (0, 0, 4)]);
+        assert!(matches!(
+            parse_json(&content),
+            Err(GeometryLoadError::DuplicatePanel { ring: 0, sector: 0 })
+        ));
+        let csv = format!("{}0,1,2,0.0,0.0,0.0\n", CSV);
+        assert!(matches!(
+            parse_csv(&csv),
+            Err(GeometryLoadError::DuplicateCorner {
+                ring: 0,
+                sector: 1,
+                corner: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn rejects_ring_count_mismatch() {
+        let content = json(
+            r#""ring_blocks": [2, 2],"#,
+            &[(0, 0, 4), (0, 1, 4), (1, 0, 4)],
+        );
+        assert!(matches!(
+            parse_json(&content),
+            Err(GeometryLoadError::RingCountMismatch {
+                ring: 1,
+                declared: 2,
+                found: 1
+            })
+        ));
+        let content = json(r#""ring_blocks": [1],"#, &[(0, 0, 4), (1, 0, 4)]);
+        assert!(matches!(
+            parse_json(&content),
+            Err(GeometryLoadError::RingTotalMismatch {
+                declared: 1,
+                found: 2
+            })
+        ));
+        let content = json("", &[(0, 0, 4), (2, 0, 4)]);
+        assert!(matches!(
+            parse_json(&content),
+            Err(GeometryLoadError::MissingRing(1))
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_numbers() {
+        let csv = CSV.replace("0,1,1,-1.0,", "0,1,1,-1.o,");
+        assert!(matches!(
+            parse_csv(&csv),
+            Err(GeometryLoadError::Csv { line: 9, .. })
+        ));
+        let csv = CSV.replace("1,0,0,3.0", "-1,0,0,3.0");
+        assert!(matches!(
+            parse_csv(&csv),
+            Err(GeometryLoadError::Csv { line: 15, .. })
+        ));
+        let csv = CSV.replace("0,0,2,0.0,2.0,0.4", "0,0,2,0.0,2.0");
+        assert!(matches!(
+            parse_csv(&csv),
+            Err(GeometryLoadError::Csv { line: 4, .. })
+        ));
+        let content = json("", &[(0, 0, 4)]).replace("2.0", "2.0.0");
+        assert!(matches!(
+            parse_json(&content),
+            Err(GeometryLoadError::Json(_))
+        ));
+    }
+}
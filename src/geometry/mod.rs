@@ -0,0 +1,214 @@
+//! 主反射面几何生成
+//!
+//! 反射面由若干同心圆环组成，每个圆环沿方位角等分为若干块面板。
+//! 每块面板为一个四边形，顶点顺序为：内圈起始角、内圈结束角、外圈结束角、外圈起始角，
+//! `setup()` 与各模拟函数均依赖此顺序（每 4 个顶点为一块面板）。
+
+pub mod loader;
+
+use bevy::prelude::*;
+use std::f64::consts::{SQRT_2, TAU};
+
+/// 反射面几何：面板顶点坐标及每个圆环的面板数
+#[derive(Resource, Debug, Clone)]
+pub struct ReflectorGeometry {
+    positions: Vec<[f32; 3]>,
+    ring_blocks: Vec<u32>,
+}
+
+impl ReflectorGeometry {
+    pub fn builder() -> ReflectorGeometryBuilder {
+        ReflectorGeometryBuilder::default()
+    }
+
+    /// 面板顶点坐标，每 4 个顶点为一块面板
+    pub fn positions(&self) -> &[[f32; 3]] {
+        &self.positions
+    }
+
+    /// 由内向外每个圆环的面板数
+    pub fn ring_blocks(&self) -> &[u32] {
+        &self.ring_blocks
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn panel_count(&self) -> usize {
+        self.positions.len() / 4
+    }
+
+    pub fn ring_count(&self) -> usize {
+        self.ring_blocks.len()
+    }
+}
+
+impl Default for ReflectorGeometry {
+    fn default() -> Self {
+        ReflectorGeometry::builder().build()
+    }
+}
+
+/// [`ReflectorGeometry`] 构建器
+///
+/// 默认参数与最初手工生成的顶点表一致：口径 12√2、焦距 4、中心孔半径 0.9，
+/// 由内向外 4 环 32 块、4 环 64 块、15 环 128 块。
+#[derive(Debug, Clone)]
+pub struct ReflectorGeometryBuilder {
+    diameter: f64,
+    focal_length: f64,
+    inner_radius: f64,
+    ring_blocks: Vec<u32>,
+}
+
+impl Default for ReflectorGeometryBuilder {
+    fn default() -> Self {
+        let mut ring_blocks = vec![32; 4];
+        ring_blocks.extend([64; 4]);
+        ring_blocks.extend([128; 15]);
+        ReflectorGeometryBuilder {
+            diameter: 12.0 * SQRT_2,
+            focal_length: 4.0,
+            inner_radius: 0.9,
+            ring_blocks,
+        }
+    }
+}
+
+impl ReflectorGeometryBuilder {
+    /// 反射面口径（外缘直径）
+    pub fn diameter(mut self, diameter: f64) -> Self {
+        self.diameter = diameter;
+        self
+    }
+
+    /// 抛物面焦距
+    pub fn focal_length(mut self, focal_length: f64) -> Self {
+        self.focal_length = focal_length;
+        self
+    }
+
+    /// 以焦径比 f/D 设置焦距，需在 [`Self::diameter`] 之后调用
+    pub fn focal_ratio(mut self, focal_ratio: f64) -> Self {
+        self.focal_length = focal_ratio * self.diameter;
+        self
+    }
+
+    /// 中心孔半径
+    pub fn inner_radius(mut self, inner_radius: f64) -> Self {
+        self.inner_radius = inner_radius;
+        self
+    }
+
+    /// 由内向外每个圆环的面板数
+    pub fn ring_blocks(mut self, ring_blocks: impl Into<Vec<u32>>) -> Self {
+        self.ring_blocks = ring_blocks.into();
+        self
+    }
+
+    pub fn build(self) -> ReflectorGeometry {
+        assert!(self.focal_length > 0.0, "焦距必须为正数");
+        assert!(
+            self.diameter * 0.5 > self.inner_radius && self.inner_radius >= 0.0,
+            "中心孔半径必须小于反射面半径"
+        );
+        assert!(
+            self.ring_blocks.iter().all(|&n| n > 0),
+            "每个圆环至少包含一块面板"
+        );
+
+        let outer_radius = self.diameter * 0.5;
+        let ring_width = (outer_radius - self.inner_radius) / self.ring_blocks.len() as f64;
+        // 抛物面 z = r² / 4f
+        let point = |r: f64, theta: f64| {
+            [
+                (r * theta.cos()) as f32,
+                (r * theta.sin()) as f32,
+                (r * r / (4.0 * self.focal_length)) as f32,
+            ]
+        };
+
+        let panel_count = self.ring_blocks.iter().sum::<u32>() as usize;
+        let mut positions = Vec::with_capacity(panel_count * 4);
+        for (i, &block) in self.ring_blocks.iter().enumerate() {
+            let r_inner = self.inner_radius + i as f64 * ring_width;
+            let r_outer = self.inner_radius + (i + 1) as f64 * ring_width;
+            for j in 0..block {
+                let theta_start = TAU * j as f64 / block as f64;
+                let theta_end = TAU * (j + 1) as f64 / block as f64;
+                positions.push(point(r_inner, theta_start));
+                positions.push(point(r_inner, theta_end));
+                positions.push(point(r_outer, theta_end));
+                positions.push(point(r_outer, theta_start));
+            }
+        }
+
+        ReflectorGeometry {
+            positions,
+            ring_blocks: self.ring_blocks,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 最初手工生成的顶点表（已删除的 object.rs）中每个圆环抽取的一块面板：(面板序号, 四个顶点)
+    #[rustfmt::skip]
+    const LEGACY_PANELS: [(usize, [[f32; 3]; 4]); 23] = [
+        (0, [[0.9, 0.0, 0.050625], [0.8827068, 0.1755813, 0.050625], [1.206165, 0.2399211, 0.09452471], [1.229795, 0.0, 0.09452471]]),
+        (43, [[-0.6832374, 1.022537, 0.09452471], [-0.8695963, 0.8695963, 0.09452471], [-1.102796, 1.102796, 0.15202], [-0.8664616, 1.296751, 0.15202]]),
+        (86, [[-0.5968291, -1.440873, 0.15202], [-0.3042609, -1.529623, 0.15202], [-0.3686006, -1.853081, 0.2231109], [-0.7230362, -1.745564, 0.2231109]]),
+        (97, [[1.853081, 0.3686006, 0.2231109], [1.745564, 0.7230362, 0.2231109], [2.050254, 0.8492432, 0.3077973], [2.176538, 0.4329404, 0.3077973]]),
+        (172, [[-0.8492432, -2.050254, 0.3077973], [-0.6441938, -2.123622, 0.3077973], [-0.7399282, -2.439216, 0.4060793], [-0.9754502, -2.354945, 0.4060793]]),
+        (247, [[1.617052, -1.970384, 0.4060793], [1.802397, -1.802397, 0.4060793], [2.035597, -2.035597, 0.517957], [1.826272, -2.225319, 0.517957]]),
+        (258, [[2.823454, 0.56162, 0.517957], [2.75481, 0.8356625, 0.517957], [3.070404, 0.9313969, 0.6434301], [3.146912, 0.6259598, 0.6434301]]),
+        (333, [[0.9313969, 3.070404, 0.6434301], [0.6259598, 3.146912, 0.6434301], [0.6902995, 3.47037, 0.7824989], [1.027131, 3.385998, 0.7824989]]),
+        (472, [[-1.354071, -3.269017, 0.7824989], [-1.192037, -3.331521, 0.7824989], [-1.303142, -3.642037, 0.9351633], [-1.480278, -3.573708, 0.9351633]]),
+        (611, [[0.567576, -3.826287, 0.9351633], [0.7546393, -3.793828, 0.9351633], [0.8189791, -4.117286, 1.101423], [0.615967, -4.152512, 1.101423]]),
+        (750, [[2.66315, -3.245058, 1.101423], [2.81917, -3.110475, 1.101423], [3.040647, -3.354836, 1.281279], [2.87237, -3.499993, 1.281279]]),
+        (889, [[4.26307, -1.525351, 1.281279], [4.33278, -1.314334, 1.281279], [4.648374, -1.410069, 1.47473], [4.573586, -1.636455, 1.47473]]),
+        (900, [[4.764202, 0.9476587, 1.47473], [4.711964, 1.180285, 1.47473], [5.031875, 1.260419, 1.681776], [5.08766, 1.011998, 1.681776]]),
+        (1039, [[3.84356, 3.4836, 1.681776], [3.667998, 3.667998, 1.681776], [3.901198, 3.901198, 1.902419], [4.087922, 3.705077, 1.902419]]),
+        (1178, [[1.601538, 5.279562, 1.902419], [1.340553, 5.351786, 1.902419], [1.420686, 5.671698, 2.136657], [1.697272, 5.595156, 2.136657]]),
+        (1317, [[-1.420686, 5.671698, 2.136657], [-1.697272, 5.595156, 2.136657], [-1.793006, 5.91075, 2.38449], [-1.50082, 5.991609, 2.38449]]),
+        (1456, [[-4.367599, 4.367599, 2.38449], [-4.576646, 4.14803, 2.38449], [-4.821008, 4.369507, 2.645919], [-4.600799, 4.600799, 2.645919]]),
+        (1595, [[-6.31152, 1.580954, 2.645919], [-6.381492, 1.269358, 2.645919], [-6.704949, 1.333697, 2.920943], [-6.631432, 1.661087, 2.920943]]),
+        (1734, [[-6.541938, -1.984475, 2.920943], [-6.436684, -2.303083, 2.920943], [-6.747201, -2.414187, 3.209564], [-6.857532, -2.08021, 3.209564]]),
+        (1873, [[-4.81246, -5.309731, 3.209564], [-4.546127, -5.539472, 3.209564], [-4.755347, -5.794407, 3.511779], [-5.033937, -5.554093, 3.511779]]),
+        (2012, [[-1.462377, -7.351865, 3.511779], [-1.099877, -7.414765, 3.511779], [-1.148267, -7.74099, 3.827591], [-1.526717, -7.675323, 3.827591]]),
+        (2151, [[2.636396, -7.368234, 3.827591], [2.994763, -7.229996, 3.827591], [3.12097, -7.534687, 4.156998], [2.747501, -7.67875, 4.156998]]),
+        (2290, [[6.304276, -5.173786, 4.156998], [6.550548, -4.858218, 4.156998], [6.815442, -5.054676, 4.5], [6.559211, -5.383006, 4.5]]),
+    ];
+
+    const LEGACY_RING_BLOCKS: [u32; 23] = [
+        32, 32, 32, 32, 64, 64, 64, 64, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+        128, 128, 128,
+    ];
+
+    #[test]
+    fn default_matches_legacy_table() {
+        let geometry = ReflectorGeometry::builder().build();
+        assert_eq!(geometry.vertex_count(), 9216);
+        assert_eq!(geometry.ring_blocks(), LEGACY_RING_BLOCKS);
+        assert_eq!(geometry.panel_count(), 2304);
+
+        for (panel, corners) in LEGACY_PANELS {
+            for (k, expected) in corners.iter().enumerate() {
+                let actual = geometry.positions()[panel * 4 + k];
+                for axis in 0..3 {
+                    assert!(
+                        (actual[axis] - expected[axis]).abs() < 1e-5,
+                        "面板 {} 顶点 {} 与原顶点表不一致: {:?} != {:?}",
+                        panel,
+                        k,
+                        actual,
+                        expected
+                    );
+                }
+            }
+        }
+    }
+}
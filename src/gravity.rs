@@ -0,0 +1,345 @@
+//! 随仰角变化的重力形变
+//!
+//! 反射面在不同仰角下的重力下垂不同，这正是需要主动面的原因。模型保存若干仰角下的形变图
+//! （按促动器编号存储的高度），任意仰角的形变由相邻两幅图按仰角线性插值得到。形变图可以
+//! 通过 `--gravity-maps` 从文件读取，否则使用内置的解析近似。
+//!
+//! 主动面按查找表（LUT）在每个仰角施加与预期形变相反的指令，受促动器行程限制。
+
+use crate::analysis::correction::CorrectionSettings;
+use crate::data_source::{ActuatorHeights, HeightSet};
+use crate::{actuator::ActuatorTopology, cli::CliArgs};
+use bevy::{color::palettes::css::*, prelude::*};
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+/// 内置形变图的仰角（度）
+pub const BUILTIN_ELEVATIONS: [f32; 3] = [15.0, 45.0, 90.0];
+/// 调整面板时的装配仰角（度），此时重力形变为零
+const RIGGING_ELEVATION: f32 = 45.0;
+
+pub struct GravityPlugin;
+
+impl Plugin for GravityPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Elevation(RIGGING_ELEVATION))
+            .init_resource::<GravityModel>()
+            .init_resource::<LutCorrection>()
+            .add_systems(
+                Update,
+                (
+                    update_lut_correction.in_set(HeightSet::Analysis).run_if(
+                        resource_changed::<Elevation>
+                            .or(resource_changed::<ActuatorHeights>)
+                            .or(resource_changed::<CorrectionSettings>),
+                    ),
+                    update_gravity_text.run_if(resource_changed::<Elevation>),
+                ),
+            );
+    }
+}
+
+/// 反射面当前仰角（度），由天线座的实际指向驱动
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Deref, DerefMut)]
+pub struct Elevation(pub f32);
+
+/// 各仰角下的重力形变图，按仰角升序排列
+#[derive(Resource, Debug, Clone)]
+pub struct GravityModel {
+    maps: Vec<(f32, Vec<f32>)>,
+}
+
+impl FromWorld for GravityModel {
+    fn from_world(world: &mut World) -> Self {
+        let topology = world.resource::<ActuatorTopology>();
+        let files = world
+            .get_resource::<CliArgs>()
+            .and_then(|cli| cli.gravity_maps.clone());
+        match files {
+            Some(files) => {
+                GravityModel::load(&files, topology.actuator_count()).unwrap_or_else(|err| {
+                    error!("读取重力形变图失败，改用内置模型: {}", err);
+                    GravityModel::builtin(topology)
+                })
+            }
+            None => GravityModel::builtin(topology),
+        }
+    }
+}
+
+impl GravityModel {
+    pub fn new(mut maps: Vec<(f32, Vec<f32>)>) -> Self {
+        maps.sort_by(|a, b| a.0.total_cmp(&b.0));
+        GravityModel { maps }
+    }
+
+    /// 解析近似：轴向分量（焦距变化为主）随 sin(仰角) 变化，横向分量（像散与彗差）随
+    /// cos(仰角) 变化，均以装配仰角为零点。俯仰轴沿 x 方向。单位为毫米。
+    pub fn builtin(topology: &ActuatorTopology) -> Self {
+        let radius = topology
+            .positions()
+            .iter()
+            .map(|p| p.truncate().length())
+            .fold(0.0, f32::max);
+        let (sin_rig, cos_rig) = RIGGING_ELEVATION.to_radians().sin_cos();
+        let maps = BUILTIN_ELEVATIONS
+            .iter()
+            .map(|&elevation| {
+                let (sin, cos) = elevation.to_radians().sin_cos();
+                let heights = topology
+                    .positions()
+                    .iter()
+                    .map(|p| {
+                        let rho = p.truncate().length() / radius;
+                        let theta = p.y.atan2(p.x);
+                        let axial = 1.2 * rho.powi(2) - 0.4 * rho.powi(4);
+                        let lateral = 0.6 * rho.powi(2) * (2.0 * theta).cos()
+                            + 0.4 * rho.powi(3) * theta.sin();
+                        (sin - sin_rig) * axial + (cos - cos_rig) * lateral
+                    })
+                    .collect();
+                (elevation, heights)
+            })
+            .collect();
+        GravityModel::new(maps)
+    }
+
+    /// 从文件读取形变图，每个文件按促动器编号依次给出高度，以空白或逗号分隔，`#` 开始注释
+    pub fn load(files: &[(f32, PathBuf)], actuator_count: usize) -> io::Result<Self> {
+        let maps = files
+            .iter()
+            .map(|(elevation, path)| Ok((*elevation, read_map(path, actuator_count)?)))
+            .collect::<io::Result<Vec<_>>>()?;
+        if maps.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "没有形变图"));
+        }
+        Ok(GravityModel::new(maps))
+    }
+
+    /// 给定仰角下的形变，超出形变图范围时取最近的一幅
+    pub fn sag(&self, elevation: f32) -> Vec<f32> {
+        let Some(upper) = self.maps.iter().position(|(e, _)| *e >= elevation) else {
+            return self.maps.last().map(|(_, m)| m.clone()).unwrap_or_default();
+        };
+        if upper == 0 {
+            return self.maps[0].1.clone();
+        }
+        let (e0, m0) = &self.maps[upper - 1];
+        let (e1, m1) = &self.maps[upper];
+        let t = (elevation - e0) / (e1 - e0);
+        m0.iter().zip(m1).map(|(a, b)| a + (b - a) * t).collect()
+    }
+}
+
+fn read_map(path: &Path, actuator_count: usize) -> io::Result<Vec<f32>> {
+    let text = std::fs::read_to_string(path)?;
+    let invalid = |msg: String| io::Error::new(io::ErrorKind::InvalidData, msg);
+    let heights = text
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or(""))
+        .flat_map(|line| line.split(|c: char| c == ',' || c.is_whitespace()))
+        .filter(|v| !v.is_empty())
+        .map(|v| {
+            v.parse::<f32>()
+                .map_err(|_| invalid(format!("{}: 无效数值 {}", path.display(), v)))
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+    if heights.len() != actuator_count {
+        return Err(invalid(format!(
+            "{}: 有 {} 个高度，促动器为 {} 个",
+            path.display(),
+            heights.len(),
+            actuator_count
+        )));
+    }
+    Ok(heights)
+}
+
+/// 当前仰角下查找表给出的指令，以及对当前高度施加指令后的结果
+#[derive(Resource, Debug, Default, Clone)]
+pub struct LutCorrection {
+    pub commands: Vec<f32>,
+    pub corrected: Vec<f32>,
+}
+
+pub(crate) fn update_lut_correction(
+    elevation: Res<Elevation>,
+    model: Res<GravityModel>,
+    settings: Res<CorrectionSettings>,
+    heights: Res<ActuatorHeights>,
+    mut lut: ResMut<LutCorrection>,
+) {
+    if elevation.is_changed() || settings.is_changed() || lut.commands.is_empty() {
+        lut.commands = model
+            .sag(**elevation)
+            .iter()
+            .map(|s| (-s).clamp(-settings.stroke, settings.stroke))
+            .collect();
+    }
+    let corrected = heights
+        .iter()
+        .zip(&lut.commands)
+        .map(|(h, u)| h + u)
+        .collect();
+    lut.corrected = corrected;
+}
+
+#[derive(Component)]
+struct GravityText;
+
+pub fn spawn_gravity_text(parent: &mut ChildBuilder<'_>, text_font: TextFont) {
+    parent.spawn((
+        Text::default(),
+        GravityText,
+        text_font,
+        TextColor(BLACK.into()),
+    ));
+}
+
+fn update_gravity_text(
+    elevation: Res<Elevation>,
+    model: Res<GravityModel>,
+    mut text: Query<&mut Text, With<GravityText>>,
+) {
+    let sag = model.sag(**elevation);
+    let rms = (sag.iter().map(|h| h * h).sum::<f32>() / sag.len().max(1) as f32).sqrt();
+    for mut text in text.iter_mut() {
+        text.0 = format!("重力形变 RMS {:.3} mm", rms);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::ReflectorGeometry;
+
+    /// 测试用的临时文件，drop 时删除
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str, contents: &str) -> Self {
+            let path = std::env::temp_dir().join(format!(
+                "gravity-test-{}-{}.txt",
+                std::process::id(),
+                name
+            ));
+            std::fs::write(&path, contents).unwrap();
+            TempFile(path)
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    fn assert_close(actual: &[f32], expected: &[f32]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, b) in actual.iter().zip(expected) {
+            assert!((a - b).abs() < 1e-5, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    // 乱序给出，构造时按仰角排序
+    fn model() -> GravityModel {
+        GravityModel::new(vec![
+            (90.0, vec![3.0, -1.0]),
+            (15.0, vec![-2.0, 1.0]),
+            (45.0, vec![0.0, 0.0]),
+        ])
+    }
+
+    #[test]
+    fn sag_at_table_elevations() {
+        let model = model();
+        assert_close(&model.sag(15.0), &[-2.0, 1.0]);
+        assert_close(&model.sag(45.0), &[0.0, 0.0]);
+        assert_close(&model.sag(90.0), &[3.0, -1.0]);
+    }
+
+    #[test]
+    fn sag_interpolates_between_elevations() {
+        let model = model();
+        assert_close(&model.sag(30.0), &[-1.0, 0.5]);
+        assert_close(&model.sag(60.0), &[1.0, -1.0 / 3.0]);
+        assert_close(&model.sag(78.75), &[2.25, -0.75]);
+    }
+
+    #[test]
+    fn sag_clamps_outside_table() {
+        let model = model();
+        assert_close(&model.sag(5.0), &[-2.0, 1.0]);
+        assert_close(&model.sag(-90.0), &[-2.0, 1.0]);
+        assert_close(&model.sag(95.0), &[3.0, -1.0]);
+        assert!(GravityModel::new(vec![]).sag(45.0).is_empty());
+    }
+
+    #[test]
+    fn builtin_has_no_sag_at_rigging_elevation() {
+        let topology = ActuatorTopology::from_geometry(&ReflectorGeometry::default());
+        let model = GravityModel::builtin(&topology);
+        let sag = model.sag(RIGGING_ELEVATION);
+        assert_eq!(sag.len(), topology.actuator_count());
+        assert!(sag.iter().all(|s| s.abs() < 1e-6));
+        assert!(model.sag(15.0).iter().any(|s| s.abs() > 0.1));
+        assert!(model.sag(90.0).iter().any(|s| s.abs() > 0.1));
+    }
+
+    #[test]
+    fn loads_maps_from_files() {
+        let low = TempFile::new("low", "# 15 度\n1.0, 2.0\n3.0 # 行尾注释\n");
+        let high = TempFile::new("high", "0 0 0");
+        let model =
+            GravityModel::load(&[(90.0, high.0.clone()), (15.0, low.0.clone())], 3).unwrap();
+        assert_close(&model.sag(15.0), &[1.0, 2.0, 3.0]);
+        assert_close(&model.sag(52.5), &[0.5, 1.0, 1.5]);
+    }
+
+    #[test]
+    fn rejects_bad_map_files() {
+        let short = TempFile::new("short", "1 2");
+        let err = GravityModel::load(&[(15.0, short.0.clone())], 3).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(
+            err.to_string().contains("有 2 个高度，促动器为 3 个"),
+            "{}",
+            err
+        );
+
+        let long = TempFile::new("long", "1 2 3 4");
+        assert!(GravityModel::load(&[(15.0, long.0.clone())], 3).is_err());
+
+        let malformed = TempFile::new("malformed", "1 x 3");
+        let err = GravityModel::load(&[(15.0, malformed.0.clone())], 3).unwrap_err();
+        assert!(err.to_string().contains("无效数值 x"), "{}", err);
+
+        assert!(GravityModel::load(&[], 3).is_err());
+    }
+
+    #[test]
+    fn lut_commands_oppose_sag_within_stroke() {
+        let mut app = App::new();
+        app.insert_resource(Elevation(90.0))
+            .insert_resource(model())
+            .insert_resource(CorrectionSettings {
+                stroke: 2.0,
+                smoothness: 0.0,
+            })
+            .insert_resource(ActuatorHeights(vec![0.5, 0.5]))
+            .init_resource::<LutCorrection>()
+            .add_systems(Update, update_lut_correction);
+        app.update();
+        let lut = app.world().resource::<LutCorrection>();
+        assert_close(&lut.commands, &[-2.0, 1.0]);
+        assert_close(&lut.corrected, &[-1.5, 1.5]);
+
+        app.world_mut().resource_mut::<Elevation>().0 = 45.0;
+        app.update();
+        let lut = app.world().resource::<LutCorrection>();
+        assert_close(&lut.commands, &[0.0, 0.0]);
+        assert_close(&lut.corrected, &[0.5, 0.5]);
+    }
+}
@@ -1,2 +1,3 @@
 pub mod camera_controller;
-pub mod widgets;
\ No newline at end of file
+pub mod rng;
+pub mod widgets;
@@ -0,0 +1,381 @@
+//! 面板拾取与查看
+//!
+//! 鼠标悬停在反射面上时，在指针旁显示所在面板的信息；点击面板将其固定，再次点击同一块面板
+//! 取消固定。信息包括面板所在圆环与扇区、四个角点的促动器编号、各角点的测量高度与校正
+//! 指令，以及面板平均高度的最近历史。被查看的面板在着色器中高亮。
+
+use crate::analysis::correction::ActuatorCorrection;
+use crate::data_source::{
+    fault::{ActuatorFaults, FaultPicking},
+    ActuatorHeights, HeightSet,
+};
+use crate::geometry::ReflectorGeometry;
+use crate::{
+    actuator::ActuatorTopology, Block, CustomMaterial, CustomMaterialHandle, CustomTextFont,
+};
+use bevy::{color::palettes::css::*, prelude::*, text::FontSmoothing};
+use std::collections::VecDeque;
+
+/// 材质中表示没有被查看面板的值
+pub const NO_PANEL: u32 = u32::MAX;
+/// 保留的历史帧数
+const HISTORY_LEN: usize = 60;
+/// 历史柱状图的高度（像素）
+const HISTORY_HEIGHT: f32 = 40.0;
+/// 信息面板相对指针的偏移（像素）
+const TOOLTIP_OFFSET: Vec2 = Vec2::new(16.0, 16.0);
+
+pub struct InspectPlugin;
+
+impl Plugin for InspectPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PanelInspector>()
+            .init_resource::<PanelLocator>()
+            .init_resource::<HeightHistory>()
+            .add_systems(PostStartup, spawn_inspect_panel)
+            .add_systems(
+                Update,
+                (
+                    record_height_history
+                        .in_set(HeightSet::Analysis)
+                        .run_if(resource_changed::<ActuatorHeights>),
+                    (update_inspect_panel, update_history_chart)
+                        .after(HeightSet::Analysis)
+                        .run_if(
+                            resource_changed::<PanelInspector>
+                                .or(resource_changed::<HeightHistory>),
+                        ),
+                    update_selected_panel.run_if(resource_changed::<PanelInspector>),
+                ),
+            )
+            .add_observer(on_panel_hovered)
+            .add_observer(on_panel_out)
+            .add_observer(on_panel_clicked);
+    }
+}
+
+/// 悬停与固定的面板，固定的面板优先显示
+#[derive(Resource, Debug, Default)]
+pub struct PanelInspector {
+    pub hovered: Option<usize>,
+    pub selected: Option<usize>,
+    /// 信息面板的位置（窗口逻辑像素）
+    anchor: Vec2,
+}
+
+impl PanelInspector {
+    pub fn panel(&self) -> Option<usize> {
+        self.selected.or(self.hovered)
+    }
+}
+
+/// 由反射面局部坐标查找面板
+#[derive(Resource, Debug)]
+pub struct PanelLocator {
+    /// 每块面板四个角点在口径面（xy）上的投影
+    quads: Vec<[Vec2; 4]>,
+    /// 每块面板所在的圆环与扇区
+    rings: Vec<(usize, usize)>,
+}
+
+impl FromWorld for PanelLocator {
+    fn from_world(world: &mut World) -> Self {
+        PanelLocator::new(world.resource::<ReflectorGeometry>())
+    }
+}
+
+impl PanelLocator {
+    pub fn new(geometry: &ReflectorGeometry) -> Self {
+        let quads = geometry
+            .positions()
+            .chunks_exact(4)
+            .map(|corners| [0, 1, 2, 3].map(|i| Vec2::new(corners[i][0], corners[i][1])))
+            .collect();
+        let rings = geometry
+            .ring_blocks()
+            .iter()
+            .enumerate()
+            .flat_map(|(ring, &blocks)| (0..blocks as usize).map(move |sector| (ring, sector)))
+            .collect();
+        PanelLocator { quads, rings }
+    }
+
+    /// 反射面是 xy 平面上的单值曲面，按投影判断点落在哪块面板内；
+    /// 恰好落在缝隙中时取中心最近的面板
+    pub fn locate(&self, local: Vec3) -> Option<usize> {
+        let p = local.truncate();
+        let inside = |quad: &[Vec2; 4]| {
+            let sides = (0..4).map(|i| {
+                let (a, b) = (quad[i], quad[(i + 1) % 4]);
+                (b - a).perp_dot(p - a)
+            });
+            let (mut positive, mut negative) = (false, false);
+            for side in sides {
+                positive |= side > 0.0;
+                negative |= side < 0.0;
+            }
+            !(positive && negative)
+        };
+        self.quads.iter().position(inside).or_else(|| {
+            self.quads
+                .iter()
+                .map(|quad| (quad.iter().sum::<Vec2>() / 4.0).distance_squared(p))
+                .enumerate()
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(panel, _)| panel)
+        })
+    }
+
+    pub fn ring_sector(&self, panel: usize) -> (usize, usize) {
+        self.rings[panel]
+    }
+}
+
+/// 最近若干帧的高度，用于显示面板的历史
+#[derive(Resource, Debug, Default)]
+pub struct HeightHistory(VecDeque<Vec<f32>>);
+
+fn record_height_history(heights: Res<ActuatorHeights>, mut history: ResMut<HeightHistory>) {
+    if history.0.len() == HISTORY_LEN {
+        history.0.pop_front();
+    }
+    history.0.push_back(heights.0.clone());
+}
+
+impl HeightHistory {
+    /// 给定促动器平均高度的历史，由旧到新
+    fn mean(&self, actuators: &[u32]) -> Vec<f32> {
+        self.0
+            .iter()
+            .map(|frame| {
+                actuators
+                    .iter()
+                    .filter_map(|&id| frame.get(id as usize))
+                    .sum::<f32>()
+                    / actuators.len() as f32
+            })
+            .collect()
+    }
+}
+
+fn value_range(values: &[f32]) -> (f32, f32) {
+    if values.is_empty() {
+        return (0.0, 0.0);
+    }
+    values
+        .iter()
+        .fold((f32::MAX, f32::MIN), |(lo, hi), &v| (lo.min(v), hi.max(v)))
+}
+
+// 指针在反射面上移动时更新悬停的面板
+fn on_panel_hovered(
+    trigger: Trigger<Pointer<Move>>,
+    blocks: Query<&GlobalTransform, With<Block>>,
+    locator: Res<PanelLocator>,
+    mut inspector: ResMut<PanelInspector>,
+) {
+    let Ok(transform) = blocks.get(trigger.entity()) else {
+        return;
+    };
+    let Some(position) = trigger.event().hit.position else {
+        return;
+    };
+    let local = transform.affine().inverse().transform_point3(position);
+    let hovered = locator.locate(local);
+    if inspector.hovered != hovered {
+        inspector.hovered = hovered;
+    }
+    if inspector.selected.is_none() {
+        inspector.anchor = trigger.event().pointer_location.position + TOOLTIP_OFFSET;
+    }
+}
+
+fn on_panel_out(
+    trigger: Trigger<Pointer<Out>>,
+    blocks: Query<(), With<Block>>,
+    mut inspector: ResMut<PanelInspector>,
+) {
+    if blocks.contains(trigger.entity()) {
+        inspector.hovered = None;
+    }
+}
+
+// 点击固定悬停的面板，再次点击同一块面板取消固定。点选故障时点击用于注入故障
+fn on_panel_clicked(
+    trigger: Trigger<Pointer<Down>>,
+    blocks: Query<(), With<Block>>,
+    picking: Res<State<FaultPicking>>,
+    mut inspector: ResMut<PanelInspector>,
+) {
+    if !blocks.contains(trigger.entity()) || *picking.get() != FaultPicking::Disable {
+        return;
+    }
+    if inspector.selected.is_some() && inspector.selected == inspector.hovered {
+        inspector.selected = None;
+    } else {
+        inspector.selected = inspector.hovered;
+        inspector.anchor = trigger.event().pointer_location.position + TOOLTIP_OFFSET;
+    }
+}
+
+fn update_selected_panel(
+    inspector: Res<PanelInspector>,
+    material_handle: Res<CustomMaterialHandle>,
+    mut materials: ResMut<Assets<CustomMaterial>>,
+) {
+    let panel = inspector.panel().map_or(NO_PANEL, |panel| panel as u32);
+    if materials
+        .get(&material_handle.0)
+        .is_some_and(|material| material.selected_panel != panel)
+    {
+        if let Some(material) = materials.get_mut(&material_handle.0) {
+            material.selected_panel = panel;
+        }
+    }
+}
+
+#[derive(Component)]
+struct InspectPanel;
+
+#[derive(Component)]
+struct InspectText;
+
+#[derive(Component)]
+struct HistoryBar(usize);
+
+fn spawn_inspect_panel(mut commands: Commands, font: Res<CustomTextFont>) {
+    let text_font = TextFont {
+        font: font.0.clone(),
+        font_size: 16.0,
+        font_smoothing: FontSmoothing::AntiAliased,
+    };
+    // 面板不参与拾取，避免挡住其下的反射面
+    commands
+        .spawn((
+            InspectPanel,
+            Node {
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                ..default()
+            },
+            BackgroundColor(BLACK.with_alpha(0.7).into()),
+            Visibility::Hidden,
+            PickingBehavior::IGNORE,
+        ))
+        .with_children(|p| {
+            p.spawn((
+                Text::default(),
+                InspectText,
+                text_font,
+                TextColor(WHITE.into()),
+                PickingBehavior::IGNORE,
+            ));
+            p.spawn((
+                Node {
+                    height: Val::Px(HISTORY_HEIGHT),
+                    align_items: AlignItems::FlexEnd,
+                    column_gap: Val::Px(1.0),
+                    ..default()
+                },
+                BackgroundColor(GRAY.with_alpha(0.3).into()),
+                PickingBehavior::IGNORE,
+            ))
+            .with_children(|p1| {
+                for i in 0..HISTORY_LEN {
+                    p1.spawn((
+                        HistoryBar(i),
+                        Node {
+                            width: Val::Px(3.0),
+                            height: Val::Px(0.0),
+                            ..default()
+                        },
+                        BackgroundColor(ORANGE.into()),
+                        PickingBehavior::IGNORE,
+                    ));
+                }
+            });
+        });
+}
+
+fn update_inspect_panel(
+    inspector: Res<PanelInspector>,
+    locator: Res<PanelLocator>,
+    topology: Res<ActuatorTopology>,
+    heights: Res<ActuatorHeights>,
+    correction: Res<ActuatorCorrection>,
+    faults: Res<ActuatorFaults>,
+    history: Res<HeightHistory>,
+    mut panel: Single<(&mut Node, &mut Visibility), With<InspectPanel>>,
+    mut text: Single<&mut Text, With<InspectText>>,
+) {
+    let (node, visibility) = &mut *panel;
+    let Some(index) = inspector.panel() else {
+        **visibility = Visibility::Hidden;
+        return;
+    };
+    **visibility = Visibility::Visible;
+    node.left = Val::Px(inspector.anchor.x);
+    node.top = Val::Px(inspector.anchor.y);
+
+    let (ring, sector) = locator.ring_sector(index);
+    let mut label = format!(
+        "面板 {}{}\n环 {}  扇区 {}\n促动器  测量 (mm)  指令 (mm)\n",
+        index,
+        if inspector.selected.is_some() {
+            " (已固定)"
+        } else {
+            ""
+        },
+        ring,
+        sector
+    );
+    let actuators = &topology.vertex_actuators()[index * 4..index * 4 + 4];
+    for &id in actuators {
+        let measured = heights.get(id as usize).copied().unwrap_or_default();
+        let command = correction.commands.get(id as usize).copied();
+        label.push_str(&format!(
+            "{:>5}  {:+.4}  {}",
+            id,
+            measured,
+            command.map_or("-".to_string(), |u| format!("{:+.4}", u))
+        ));
+        if let Some(fault) = faults.get(id) {
+            label.push_str(&format!("  [{}]", fault));
+        }
+        label.push('\n');
+    }
+    let means = history.mean(actuators);
+    let (min, max) = value_range(&means);
+    label.push_str(&format!(
+        "平均高度历史 {} 帧: {:+.4} ~ {:+.4} mm",
+        means.len(),
+        min,
+        max
+    ));
+    text.0 = label;
+}
+
+fn update_history_chart(
+    inspector: Res<PanelInspector>,
+    topology: Res<ActuatorTopology>,
+    history: Res<HeightHistory>,
+    mut bars: Query<(&HistoryBar, &mut Node)>,
+) {
+    let Some(index) = inspector.panel() else {
+        return;
+    };
+    let means = history.mean(&topology.vertex_actuators()[index * 4..index * 4 + 4]);
+    let (min, max) = value_range(&means);
+    // 历史不足时靠右对齐，最新一帧在最右侧
+    let skip = HISTORY_LEN - means.len();
+    for (bar, mut node) in bars.iter_mut() {
+        let height = match bar.0.checked_sub(skip).map(|i| means[i]) {
+            None => 0.0,
+            Some(_) if max - min <= f32::EPSILON => HISTORY_HEIGHT / 2.0,
+            Some(v) => 2.0 + (HISTORY_HEIGHT - 2.0) * (v - min) / (max - min),
+        };
+        node.height = Val::Px(height);
+    }
+}
@@ -7,24 +7,66 @@ use bevy::{render::mesh::MeshVertexAttribute, window::PrimaryWindow};
 use std::io::Cursor;
 use winit::window::Icon;
 
+mod actuator;
+mod analysis;
+mod cli;
+mod colormap;
+mod contour;
+mod data_source;
+mod geometry;
+mod gravity;
 mod helpers;
-mod object;
-
+mod inspect;
+mod markers;
+mod mount;
+mod thermal;
+mod timeline;
+mod wind;
+
+use actuator::ActuatorTopology;
+use analysis::{
+    beam::{BeamSettings, BeamView},
+    correction::{ActuatorCorrection, CorrectionSettings, CorrectionView, SMOOTHNESS_OPTIONS},
+    ruze::ObservingFrequencies,
+    zernike::{ZernikeBasis, ZernikeInput},
+    AnalysisPlugin, DisplayedHeights, HeightDisplay,
+};
+use cli::CliArgs;
+use colormap::{
+    ColorRangeMode, ColorRangeSettings, ColorRangeText, ColormapLibrary, ColormapPlugin,
+    HeightColorRange,
+};
+use contour::{ContourIntervalText, ContourLabels, ContourPlugin, ContourRender, ContourSettings};
+use data_source::{
+    fault::{ActuatorFaults, FaultPicking, FaultStatusText, RANDOM_FAULT_COUNT},
+    live::LiveStatusText,
+    recording::{Playback, Recorder, Recording, RecordingStatusText},
+    ActuatorHeights, DataSource, DataSourcePlugin, HeightSet,
+};
+use geometry::ReflectorGeometry;
+use gravity::{Elevation, GravityModel, GravityPlugin};
 use helpers::camera_controller::{CameraController, CameraControllerPlugin};
+use inspect::InspectPlugin;
+use markers::{MarkerPlugin, MarkerRender};
+use mount::{Mount, MountMode, MountPlugin, Mounted};
 use std::{
     fmt::{self, Formatter},
     vec,
 };
+use thermal::{ThermalModel, ThermalPlugin, ThermalSettings};
+use timeline::{SimulationClock, TimelinePlugin};
+use wind::{WindModel, WindPlugin, WindSettings};
 
 use bevy::{
     color::palettes::{css::*, tailwind::*},
     ecs::system::IntoObserverSystem,
     input::common_conditions::input_just_pressed,
     math::vec3,
+    picking::mesh_picking::{MeshPickingPlugin, MeshPickingSettings, RayCastPickable},
     prelude::*,
     render::{
         camera::{Exposure, PhysicalCameraParameters},
-        mesh::{Indices, PrimitiveTopology},
+        mesh::{Indices, PrimitiveTopology, VertexAttributeValues},
         render_asset::RenderAssetUsages,
         render_resource::{AsBindGroup, ShaderRef},
         storage::ShaderStorageBuffer,
@@ -34,6 +76,42 @@ use bevy::{
 };
 
 fn main() {
+    let cli = match CliArgs::from_env() {
+        Ok(cli) => cli,
+        Err(err) => {
+            eprintln!("命令行参数错误: {}", err);
+            std::process::exit(1);
+        }
+    };
+    let geometry = match geometry::loader::resolve_geometry(cli.geometry.as_deref()) {
+        Ok(geometry) => geometry,
+        Err(err) => {
+            eprintln!("加载反射面几何失败: {}", err);
+            std::process::exit(1);
+        }
+    };
+
+    // 仅导出录制文件为 CSV，不启动界面
+    if let Some(csv_path) = &cli.export_csv {
+        let Some(replay_path) = &cli.replay else {
+            eprintln!("--export-csv 需要配合 --replay 指定录制文件");
+            std::process::exit(1);
+        };
+        match Recording::load(replay_path)
+            .map_err(|err| err.to_string())
+            .and_then(|recording| recording.write_csv(csv_path).map_err(|err| err.to_string()))
+        {
+            Ok(()) => {
+                println!("已导出 {}", csv_path.display());
+                return;
+            }
+            Err(err) => {
+                eprintln!("导出 CSV 失败: {}", err);
+                std::process::exit(1);
+            }
+        }
+    }
+
     let mut app = App::new();
 
     let mut builder = DefaultPlugins.set(WindowPlugin {
@@ -56,6 +134,12 @@ fn main() {
 
     app.add_plugins(MaterialPlugin::<CustomMaterial>::default())
         .add_plugins(CameraControllerPlugin)
+        // 只在主相机与带标记的实体（促动器）之间求交，避免每帧对参考平面等求交
+        .add_plugins(MeshPickingPlugin)
+        .insert_resource(MeshPickingSettings {
+            require_markers: true,
+            ..default()
+        })
         .insert_resource(Parameters(PhysicalCameraParameters {
             aperture_f_stops: 32.0,
             shutter_speed_s: 1.0 / 125.0,
@@ -64,13 +148,44 @@ fn main() {
         }))
         .init_state::<MockingDataFn>()
         .init_state::<MockingState>()
-        .init_state::<MockingInterpolateAlgo>()
         .init_state::<BoundaryRender>()
+        .init_state::<DisplacementRender>()
         .init_state::<ReferencePlaneRender>()
         .insert_resource(MockingSpeed(0.5))
+        .insert_resource(DisplacementScale(0.3))
+        .insert_resource(ActuatorTopology::from_geometry(&geometry))
+        .insert_resource(geometry)
+        // 指定 --live / --replay 时启动即进入对应数据源
+        .insert_state(if cli.live.is_some() {
+            DataSource::Live
+        } else if cli.replay.is_some() {
+            DataSource::Playback
+        } else {
+            DataSource::Mock
+        })
+        .insert_resource(cli)
+        .add_plugins((
+            DataSourcePlugin,
+            TimelinePlugin,
+            GravityPlugin,
+            MountPlugin,
+            ThermalPlugin,
+            WindPlugin,
+            InspectPlugin,
+            MarkerPlugin,
+            ColormapPlugin,
+            ContourPlugin,
+            AnalysisPlugin,
+        ))
         .add_systems(
             Startup,
-            (setup, setup_instruction, setup_control_ui).chain(),
+            (
+                setup,
+                check_height_buffer,
+                setup_instruction,
+                setup_control_ui,
+            )
+                .chain(),
         )
         .add_systems(
             Update,
@@ -78,7 +193,21 @@ fn main() {
                 button_system,
                 update_exposure,
                 toggle_text_visibility.run_if(input_just_pressed(KeyCode::KeyH)),
-                update.run_if(in_state(MockingState::Start)),
+                update_mocking_state_text.run_if(state_changed::<MockingState>),
+                update.in_set(HeightSet::Source).run_if(
+                    in_state(DataSource::Mock).and(
+                        resource_changed::<SimulationClock>
+                            .or(resource_changed::<ZernikeInput>)
+                            .or(resource_changed::<Elevation>)
+                            .or(resource_changed::<ThermalSettings>)
+                            .or(resource_changed::<WindSettings>)
+                            .or(resource_changed::<Mount>)
+                            .or(state_changed::<MockingDataFn>),
+                    ),
+                ),
+                upload_heights
+                    .in_set(HeightSet::Upload)
+                    .run_if(resource_changed::<DisplayedHeights>),
                 // rotate_camera3d,
             ),
         );
@@ -124,6 +253,10 @@ struct Parameters(PhysicalCameraParameters);
 #[derive(Component)]
 struct Block;
 
+/// 反射面（促动器与面板）的根节点
+#[derive(Component)]
+struct Reflector;
+
 #[derive(Component)]
 struct ReferencePlane;
 
@@ -141,6 +274,10 @@ enum MockingDataFn {
     Mock2 = 2,
     Mock3 = 3,
     Mock4 = 4,
+    Zernike = 5,
+    Gravity = 6,
+    Thermal = 7,
+    Wind = 8,
 }
 
 impl fmt::Display for MockingDataFn {
@@ -150,6 +287,10 @@ impl fmt::Display for MockingDataFn {
             MockingDataFn::Mock2 => write!(f, "模拟 2"),
             MockingDataFn::Mock3 => write!(f, "模拟 3"),
             MockingDataFn::Mock4 => write!(f, "模拟 4"),
+            MockingDataFn::Zernike => write!(f, "Zernike"),
+            MockingDataFn::Gravity => write!(f, "重力形变"),
+            MockingDataFn::Thermal => write!(f, "温度梯度"),
+            MockingDataFn::Wind => write!(f, "风载"),
         }
     }
 }
@@ -158,45 +299,41 @@ impl fmt::Display for MockingDataFn {
 struct MockingSpeed(f32);
 
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash, States)]
-enum MockingInterpolateAlgo {
-    Interpolate_0 = 0,
-    Interpolate_1 = 1,
+enum BoundaryRender {
     #[default]
-    Interpolate_Normal = 2,
-    Interpolate_Oklab = 3,
-    Interpolate_Heat5 = 4,
-    Interpolate_Heat7 = 5,
+    Disable = 0,
+    Enable = 1,
 }
 
-impl fmt::Display for MockingInterpolateAlgo {
+impl fmt::Display for BoundaryRender {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            MockingInterpolateAlgo::Interpolate_0 => write!(f, "algo_0"),
-            MockingInterpolateAlgo::Interpolate_1 => write!(f, "algo_1"),
-            MockingInterpolateAlgo::Interpolate_Normal => write!(f, "algo_normal"),
-            MockingInterpolateAlgo::Interpolate_Oklab => write!(f, "algo_oklab"),
-            MockingInterpolateAlgo::Interpolate_Heat5 => write!(f, "algo_heat5"),
-            MockingInterpolateAlgo::Interpolate_Heat7 => write!(f, "algo_heat7"),
+            BoundaryRender::Disable => write!(f, "禁用"),
+            BoundaryRender::Enable => write!(f, "启用"),
         }
     }
 }
 
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash, States)]
-enum BoundaryRender {
+enum DisplacementRender {
     #[default]
     Disable = 0,
     Enable = 1,
 }
 
-impl fmt::Display for BoundaryRender {
+impl fmt::Display for DisplacementRender {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            BoundaryRender::Disable => write!(f, "禁用"),
-            BoundaryRender::Enable => write!(f, "启用"),
+            DisplacementRender::Disable => write!(f, "禁用"),
+            DisplacementRender::Enable => write!(f, "启用"),
         }
     }
 }
 
+// 几何位移放大系数：高度缓冲中每单位高度对应的场景位移
+#[derive(Resource, Default, Deref, DerefMut)]
+struct DisplacementScale(f32);
+
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash, States)]
 enum ReferencePlaneRender {
     #[default]
@@ -216,6 +353,9 @@ impl fmt::Display for ReferencePlaneRender {
 const ATTRIBUTE_POSITION_INDEX: MeshVertexAttribute =
     MeshVertexAttribute::new("PositionIndex", 988540917, VertexFormat::Uint32);
 
+const ATTRIBUTE_PANEL_INDEX: MeshVertexAttribute =
+    MeshVertexAttribute::new("PanelIndex", 988540918, VertexFormat::Uint32);
+
 // Holds a handle to the custom material
 #[derive(Resource)]
 struct CustomMaterialHandle(Handle<CustomMaterial>);
@@ -230,9 +370,37 @@ struct CustomMaterial {
     #[uniform(1)]
     enable_boundary_render: u32,
 
-    // 颜色算法选择
-    #[uniform(2)]
-    interpolate_algo: u32,
+    // 是否沿法线按高度位移顶点
+    #[uniform(3)]
+    enable_displacement: u32,
+
+    // 位移放大系数
+    #[uniform(4)]
+    displacement_scale: f32,
+
+    // 高亮的面板编号，无则为 inspect::NO_PANEL
+    #[uniform(5)]
+    selected_panel: u32,
+
+    // 映射为颜色 0 与 1 的高度（毫米），由 ColormapPlugin 按范围模式更新
+    #[uniform(6)]
+    height_min: f32,
+
+    #[uniform(7)]
+    height_max: f32,
+
+    // 当前调色板烘焙的一维查找纹理
+    #[texture(8, dimension = "1d")]
+    #[sampler(9)]
+    colormap: Handle<Image>,
+
+    // 等高线间距（毫米），0 表示不绘制
+    #[uniform(10)]
+    contour_interval: f32,
+
+    // 每隔几条为主等高线
+    #[uniform(11)]
+    contour_major_every: u32,
 }
 
 impl Material for CustomMaterial {
@@ -261,6 +429,8 @@ impl Material for CustomMaterial {
             Mesh::ATTRIBUTE_POSITION.at_shader_location(0),
             Mesh::ATTRIBUTE_UV_0.at_shader_location(1),
             ATTRIBUTE_POSITION_INDEX.at_shader_location(2),
+            Mesh::ATTRIBUTE_NORMAL.at_shader_location(3),
+            ATTRIBUTE_PANEL_INDEX.at_shader_location(4),
         ])?;
         descriptor.vertex.buffers = vec![vertex_layout];
         Ok(())
@@ -274,9 +444,16 @@ fn setup(
     mut meshes: ResMut<Assets<Mesh>>,
     mut buffers: ResMut<Assets<ShaderStorageBuffer>>,
     boundary_render: Res<State<BoundaryRender>>,
+    displacement_render: Res<State<DisplacementRender>>,
+    displacement_scale: Res<DisplacementScale>,
     reference_plane_render: Res<State<ReferencePlaneRender>>,
     asset_server: Res<AssetServer>,
     mut images: ResMut<Assets<Image>>,
+    geometry: Res<ReflectorGeometry>,
+    topology: Res<ActuatorTopology>,
+    color_range: Res<HeightColorRange>,
+    colormaps: Res<ColormapLibrary>,
+    contour_settings: Res<ContourSettings>,
 ) {
     // 加载自定义字体
     let font = asset_server.load("fonts/FangZhenHeiTi.ttf");
@@ -286,6 +463,9 @@ fn setup(
     // add camera
     commands.spawn((
         Camera3d::default(),
+        // 方向图副视口也是一个相机，界面始终绘制在主相机上
+        IsDefaultUiCamera,
+        RayCastPickable,
         CameraController {
             enabled: false,
             ..default()
@@ -310,12 +490,13 @@ fn setup(
             cull_mode: None,
             ..default()
         })),
+        // 地平面，低于反射面在最低俯仰时的下缘
         Transform::from_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2))
-            .with_translation(Vec3::new(0.0, 0.0, -8.0)),
+            .with_translation(Vec3::new(0.0, 0.0, -9.0)),
     ));
 
     // setup mesh
-    let positions = &object::vertices;
+    let positions = geometry.positions();
     let mut index = 0; // position index
     let mut indices = vec![];
     // 创建顶点颜色数据
@@ -335,8 +516,11 @@ fn setup(
             _ => [0.0, 1.0],
         })
         .collect::<Vec<[f32; 2]>>();
-    let normal = (0..positions.len())
-        .map(|i| [0.0, 1.0, 0.0])
+    // 顶点法线取所属促动器处的曲面法线，保证共享角点的顶点沿同一方向位移
+    let normal = topology
+        .vertex_actuators()
+        .iter()
+        .map(|&id| topology.normals()[id as usize].to_array())
         .collect::<Vec<[f32; 3]>>();
 
     while index < positions.len() {
@@ -358,17 +542,17 @@ fn setup(
         index += 4;
     }
 
-    // buffer
-    let buffer = buffers.add(ShaderStorageBuffer::from(
-        (0..positions.len())
-            .map(|i| match i % 4 {
-                0 => 0.0,
-                1 => 0.33,
-                2 => 0.66,
-                _ => 1.0,
-            })
-            .collect::<Vec<f32>>(),
-    ));
+    // buffer，按促动器存储高度
+    let heights = (0..topology.actuator_count())
+        .map(|i| match i % 4 {
+            0 => 0.0,
+            1 => 0.33,
+            2 => 0.66,
+            _ => 1.0,
+        })
+        .collect::<Vec<f32>>();
+    let buffer = buffers.add(ShaderStorageBuffer::from(heights.clone()));
+    commands.insert_resource(ActuatorHeights(heights));
 
     // 是否允许边界渲染
     let enable_boundary = match *boundary_render.get() {
@@ -376,11 +560,24 @@ fn setup(
         BoundaryRender::Disable => 0,
     };
 
+    // 是否启用几何位移
+    let enable_displacement = match *displacement_render.get() {
+        DisplacementRender::Enable => 1,
+        DisplacementRender::Disable => 0,
+    };
+
     // Create the custom material with the storage buffer
     let custom_material = CustomMaterial {
         buffer: buffer,
         enable_boundary_render: enable_boundary,
-        interpolate_algo: MockingInterpolateAlgo::Interpolate_Normal as u32,
+        enable_displacement,
+        displacement_scale: displacement_scale.0,
+        selected_panel: inspect::NO_PANEL,
+        height_min: color_range.min,
+        height_max: color_range.max,
+        colormap: images.add(colormaps.active().lut_image()),
+        contour_interval: 0.0,
+        contour_major_every: contour_settings.major_every,
     };
 
     let material_handle = custom_materials.add(custom_material);
@@ -388,42 +585,141 @@ fn setup(
 
     // 抛物面(网格顶点 + 面)
     commands
-        .spawn((Node { ..default() }, Transform::from_xyz(0.0, 0.0, -3.0)))
+        .spawn((
+            Reflector,
+            Mounted::default(),
+            Node { ..default() },
+            Transform::from_translation(mount::DISH_VERTEX),
+        ))
         .with_children(|p| {
-            // 顶点
-            for i in 0..positions.len() {
-                p.spawn((
-                    Mesh3d(meshes.add(Sphere::new(0.03).mesh().uv(4, 4))),
-                    MeshMaterial3d(materials.add(StandardMaterial {
-                        base_color: ORANGE.into(),
-                        alpha_mode: AlphaMode::Blend,
-                        ..default()
-                    })),
-                    Transform::from_translation(Vec3::from_array(positions[i])),
-                ));
-            }
-
+            // 促动器标记由 MarkerPlugin 以实例化小球添加到反射面下
             // 反射面
-            let mesh = create_mesh(positions, indices, colors, uv, normal);
+            let mesh = create_mesh(
+                positions,
+                topology.vertex_actuators(),
+                indices,
+                colors,
+                uv,
+                normal,
+            );
             p.spawn((
                 Block,
+                RayCastPickable,
                 Mesh3d(meshes.add(mesh)),
                 MeshMaterial3d(material_handle.clone()),
             ));
         });
 }
 
+// 启动检查：高度缓冲长度须与促动器数一致，且反射面网格的所有顶点索引都落在缓冲范围内
+fn check_height_buffer(
+    topology: Res<ActuatorTopology>,
+    material_handle: Res<CustomMaterialHandle>,
+    materials: Res<Assets<CustomMaterial>>,
+    buffers: Res<Assets<ShaderStorageBuffer>>,
+    meshes: Res<Assets<Mesh>>,
+    blocks: Query<&Mesh3d, With<Block>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let material = materials.get(&material_handle.0).unwrap();
+    let buffer_len = buffers
+        .get(&material.buffer)
+        .and_then(|buffer| buffer.data.as_ref())
+        .map_or(0, |data| data.len() / size_of::<f32>());
+
+    for mesh in blocks.iter().filter_map(|mesh| meshes.get(&mesh.0)) {
+        let max_index = match mesh.attribute(ATTRIBUTE_POSITION_INDEX) {
+            Some(VertexAttributeValues::Uint32(indices)) => indices.iter().copied().max(),
+            _ => None,
+        };
+        let result = if buffer_len != topology.actuator_count() {
+            Err(format!(
+                "高度缓冲长度 {} 与促动器数 {} 不一致",
+                buffer_len,
+                topology.actuator_count()
+            ))
+        } else if max_index.is_none_or(|index| index as usize >= buffer_len) {
+            Err(format!(
+                "反射面网格顶点索引 {:?} 超出高度缓冲长度 {}",
+                max_index, buffer_len
+            ))
+        } else {
+            Ok(())
+        };
+        if let Err(err) = result {
+            error!("{}", err);
+            exit.send(AppExit::error());
+        }
+    }
+}
+
 #[derive(Component, Clone, Copy)]
 enum ButtonID {
     SwitchMockingState,
     SwitchMockingFn,
+    SwitchDataSource,
+    SwitchRecording,
+    SwitchExportCsv,
+    SwitchTimelinePlay,
+    SwitchTimelineStepBackward,
+    SwitchTimelineStepForward,
+    SwitchTimelineLoop,
+    SwitchTimelineAddMarker,
+    SwitchTimelineNextMarker,
+    SwitchTimelineClearMarkers,
     SwitchMockingBoundary,
+    SwitchHeightDisplay,
+    SwitchBeamFrequency,
+    SwitchBeamView,
+    SwitchZernikeModePrev,
+    SwitchZernikeModeNext,
+    SwitchZernikeValueDecrease,
+    SwitchZernikeValueIncrease,
+    SwitchZernikeReset,
+    SwitchCorrectionView,
+    SwitchCorrectionSmoothness,
+    SwitchExportCommands,
+    SwitchMountMode,
+    SwitchFaultPicking,
+    SwitchFaultRandom,
+    SwitchFaultClear,
+    SwitchFaultSave,
+    SwitchThermalGradient,
+    SwitchThermalDeltaDecrease,
+    SwitchThermalDeltaIncrease,
+    SwitchThermalSunAzimuthDecrease,
+    SwitchThermalSunAzimuthIncrease,
+    SwitchThermalSunElevationDecrease,
+    SwitchThermalSunElevationIncrease,
+    SwitchThermalPanelExpansionDecrease,
+    SwitchThermalPanelExpansionIncrease,
+    SwitchThermalBackingExpansionDecrease,
+    SwitchThermalBackingExpansionIncrease,
+    SwitchWindSpeedDecrease,
+    SwitchWindSpeedIncrease,
+    SwitchWindDirectionDecrease,
+    SwitchWindDirectionIncrease,
+    SwitchWindTurbulenceDecrease,
+    SwitchWindTurbulenceIncrease,
+    SwitchWindSeedDecrease,
+    SwitchWindSeedIncrease,
+    SwitchDisplacement,
+    SwitchDisplacementScaleDecrease,
+    SwitchDisplacementScaleIncrease,
     SwitchHelp,
     SwitchReferencePlaneRender,
+    SwitchMarkerRender,
     SwitchSpeedDecrease,
     SwitchSpeedIncrease,
     SwitchSpeedReset,
     SwitchColorAlgo,
+    SwitchColorRangeMode,
+    SwitchColorRangeDecrease,
+    SwitchColorRangeIncrease,
+    SwitchContourRender,
+    SwitchContourIntervalDecrease,
+    SwitchContourIntervalIncrease,
+    SwitchContourLabels,
     SwitchCameraLeft,
     SwitchCameraRight,
     SwitchCameraUp,
@@ -436,12 +732,22 @@ enum ButtonID {
 #[derive(Component)]
 struct SpeedText;
 
+#[derive(Component)]
+struct DisplacementScaleText;
+
 fn setup_control_ui(
     mut commands: Commands,
     custom_font_handle: Res<CustomTextFont>,
     camera_control: Single<&CameraController>,
     reference_plane_render: Res<State<ReferencePlaneRender>>,
     speed: Res<MockingSpeed>,
+    displacement_scale: Res<DisplacementScale>,
+    data_source: Res<State<DataSource>>,
+    beam_settings: Res<BeamSettings>,
+    correction_settings: Res<CorrectionSettings>,
+    color_range: Res<HeightColorRange>,
+    colormaps: Res<ColormapLibrary>,
+    contour_settings: Res<ContourSettings>,
 ) {
     let text_font = TextFont {
         font: (&custom_font_handle.0).clone(),
@@ -476,34 +782,29 @@ fn setup_control_ui(
                 ButtonID::SwitchMockingState,
                 on_switch_mocking_state_clicked,
             );
-            // 添加 切换模拟函数 按钮
-            spawn_button(
-                p,
-                format!("Shader函数: {}", MockingDataFn::Mock1).as_str(),
-                text_font.clone(),
-                ButtonID::SwitchMockingFn,
-                on_switch_mocking_fn_clicked,
-            );
-
-            // 添加 切换模拟函数 按钮
-            spawn_button(
-                p,
-                format!("颜色算法: {}", MockingInterpolateAlgo::Interpolate_Normal).as_str(),
-                text_font.clone(),
-                ButtonID::SwitchColorAlgo,
-                on_switch_mocking_interpolate_algo_clicked,
-            );
-
-            // 添加 切换模拟函数 按钮
+            // 添加 切换数据源 按钮及实时数据状态
             spawn_button(
                 p,
-                format!("块边界: {}", BoundaryRender::Disable).as_str(),
+                format!("数据源: {}", data_source.get()).as_str(),
                 text_font.clone(),
-                ButtonID::SwitchMockingBoundary,
-                on_switch_boundary_clicked,
+                ButtonID::SwitchDataSource,
+                on_switch_data_source_clicked,
             );
-
-            // 添加模拟速度控制
+            p.spawn((
+                Text::default(),
+                LiveStatusText,
+                TextFont {
+                    font_size: 16.0,
+                    font: text_font.font.clone(),
+                    ..default()
+                },
+                TextColor(BLUE.into()),
+                Node {
+                    align_self: AlignSelf::Center,
+                    ..default()
+                },
+            ));
+            // 添加 录制 与 回放 控制
             p.spawn((
                 Node {
                     position_type: PositionType::Relative,
@@ -518,65 +819,117 @@ fn setup_control_ui(
                 BackgroundColor(SILVER.with_alpha(0.8).into()),
             ))
             .with_children(|p1| {
-                // 添加 "速度+" 控制按钮
                 spawn_button(
                     p1,
-                    "速度 -",
+                    "录制 开始/停止",
                     text_font.clone(),
-                    ButtonID::SwitchSpeedDecrease,
-                    get_switch_speed_fn(ButtonID::SwitchSpeedDecrease),
+                    ButtonID::SwitchRecording,
+                    on_switch_recording_clicked,
+                );
+                spawn_button(
+                    p1,
+                    "导出 CSV",
+                    text_font.clone(),
+                    ButtonID::SwitchExportCsv,
+                    on_export_csv_clicked,
                 );
                 p1.spawn((
-                    Text::new(format!("{:.4}", speed.0)),
-                    SpeedText,
+                    Text::default(),
+                    RecordingStatusText,
                     TextFont {
-                        font_size: 20.0,
+                        font_size: 16.0,
                         font: text_font.font.clone(),
                         ..default()
                     },
-                    TextColor(BLUE.into()),
+                    TextColor(RED.into()),
                 ));
+            });
+            // 添加 时间轴：播放/暂停、单步、循环、进度条与标记
+            p.spawn((
+                Node {
+                    position_type: PositionType::Relative,
+                    width: Val::Auto,
+                    height: Val::Auto,
+                    padding: UiRect::horizontal(Val::Px(10.0)),
+                    justify_content: JustifyContent::FlexStart,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(10.0),
+                    ..default()
+                },
+                BackgroundColor(SILVER.with_alpha(0.8).into()),
+            ))
+            .with_children(|p1| {
                 spawn_button(
                     p1,
-                    "速度 +",
+                    "播放/暂停",
                     text_font.clone(),
-                    ButtonID::SwitchSpeedDecrease,
-                    get_switch_speed_fn(ButtonID::SwitchSpeedIncrease),
+                    ButtonID::SwitchTimelinePlay,
+                    get_switch_timeline_fn(ButtonID::SwitchTimelinePlay),
                 );
                 spawn_button(
                     p1,
-                    "重置",
+                    "单步 -",
                     text_font.clone(),
-                    ButtonID::SwitchSpeedReset,
-                    get_switch_speed_fn(ButtonID::SwitchSpeedReset),
+                    ButtonID::SwitchTimelineStepBackward,
+                    get_switch_timeline_fn(ButtonID::SwitchTimelineStepBackward),
+                );
+                spawn_button(
+                    p1,
+                    "单步 +",
+                    text_font.clone(),
+                    ButtonID::SwitchTimelineStepForward,
+                    get_switch_timeline_fn(ButtonID::SwitchTimelineStepForward),
+                );
+                spawn_button(
+                    p1,
+                    "循环",
+                    text_font.clone(),
+                    ButtonID::SwitchTimelineLoop,
+                    get_switch_timeline_fn(ButtonID::SwitchTimelineLoop),
+                );
+                timeline::spawn_timeline_bar(p1, text_font.clone());
+                spawn_button(
+                    p1,
+                    "添加标记",
+                    text_font.clone(),
+                    ButtonID::SwitchTimelineAddMarker,
+                    get_switch_timeline_fn(ButtonID::SwitchTimelineAddMarker),
+                );
+                spawn_button(
+                    p1,
+                    "下一标记",
+                    text_font.clone(),
+                    ButtonID::SwitchTimelineNextMarker,
+                    get_switch_timeline_fn(ButtonID::SwitchTimelineNextMarker),
+                );
+                spawn_button(
+                    p1,
+                    "清除标记",
+                    text_font.clone(),
+                    ButtonID::SwitchTimelineClearMarkers,
+                    get_switch_timeline_fn(ButtonID::SwitchTimelineClearMarkers),
                 );
             });
 
-            // 添加 帮助 按钮
+            // 添加 切换模拟函数 按钮
             spawn_button(
                 p,
-                format!(
-                    "相机控制: {}",
-                    match camera_control.enabled {
-                        true => "启用",
-                        false => "禁用",
-                    }
-                )
-                .as_str(),
+                format!("Shader函数: {}", MockingDataFn::Mock1).as_str(),
                 text_font.clone(),
-                ButtonID::SwitchHelp,
-                on_switch_camera_control_clicked,
+                ButtonID::SwitchMockingFn,
+                on_switch_mocking_fn_clicked,
             );
 
+            // 添加 切换调色板 按钮
             spawn_button(
                 p,
-                format!("参考面: {}", reference_plane_render.get()).as_str(),
+                format!("颜色映射: {}", colormaps.active().name).as_str(),
                 text_font.clone(),
-                ButtonID::SwitchReferencePlaneRender,
-                on_switch_reference_plane_render_clicked,
+                ButtonID::SwitchColorAlgo,
+                on_switch_colormap_clicked,
             );
 
-            // 添加相机转动控制
+            // 添加 颜色映射范围 模式切换及范围缩放
             p.spawn((
                 Node {
                     position_type: PositionType::Relative,
@@ -591,79 +944,449 @@ fn setup_control_ui(
                 BackgroundColor(SILVER.with_alpha(0.8).into()),
             ))
             .with_children(|p1| {
-                // 添加 "左" 控制按钮
                 spawn_button(
                     p1,
-                    "左",
+                    format!("色标范围: {}", ColorRangeMode::Fixed).as_str(),
                     text_font.clone(),
-                    ButtonID::SwitchCameraLeft,
-                    get_switch_camera_orientation_fn(ButtonID::SwitchCameraLeft),
+                    ButtonID::SwitchColorRangeMode,
+                    on_switch_color_range_mode_clicked,
                 );
                 spawn_button(
                     p1,
-                    "右",
+                    "范围 -",
                     text_font.clone(),
-                    ButtonID::SwitchCameraRight,
-                    get_switch_camera_orientation_fn(ButtonID::SwitchCameraRight),
+                    ButtonID::SwitchColorRangeDecrease,
+                    get_switch_color_range_fn(ButtonID::SwitchColorRangeDecrease),
                 );
+                p1.spawn((
+                    Text::new(color_range.label()),
+                    ColorRangeText,
+                    TextFont {
+                        font_size: 20.0,
+                        font: text_font.font.clone(),
+                        ..default()
+                    },
+                    TextColor(BLUE.into()),
+                ));
                 spawn_button(
                     p1,
-                    "上",
+                    "范围 +",
                     text_font.clone(),
-                    ButtonID::SwitchCameraRight,
-                    get_switch_camera_orientation_fn(ButtonID::SwitchCameraUp),
+                    ButtonID::SwitchColorRangeIncrease,
+                    get_switch_color_range_fn(ButtonID::SwitchColorRangeIncrease),
                 );
+            });
+
+            // 添加 切换模拟函数 按钮
+            spawn_button(
+                p,
+                format!("块边界: {}", BoundaryRender::Disable).as_str(),
+                text_font.clone(),
+                ButtonID::SwitchMockingBoundary,
+                on_switch_boundary_clicked,
+            );
+
+            // 添加 等高线 开关、间距及标注控制
+            p.spawn((
+                Node {
+                    position_type: PositionType::Relative,
+                    width: Val::Auto,
+                    height: Val::Auto,
+                    padding: UiRect::horizontal(Val::Px(10.0)),
+                    justify_content: JustifyContent::FlexStart,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(10.0),
+                    ..default()
+                },
+                BackgroundColor(SILVER.with_alpha(0.8).into()),
+            ))
+            .with_children(|p1| {
                 spawn_button(
                     p1,
-                    "下",
+                    format!("等高线: {}", ContourRender::Disable).as_str(),
                     text_font.clone(),
-                    ButtonID::SwitchCameraLeft,
-                    get_switch_camera_orientation_fn(ButtonID::SwitchCameraDown),
+                    ButtonID::SwitchContourRender,
+                    on_switch_contour_render_clicked,
                 );
                 spawn_button(
                     p1,
-                    "初始视角",
+                    "间距 -",
                     text_font.clone(),
-                    ButtonID::SwitchSpeedReset,
-                    get_switch_camera_orientation_fn(ButtonID::SwitchCameraResetInit),
+                    ButtonID::SwitchContourIntervalDecrease,
+                    get_switch_contour_interval_fn(ButtonID::SwitchContourIntervalDecrease),
                 );
+                p1.spawn((
+                    Text::new(contour_settings.label()),
+                    ContourIntervalText,
+                    TextFont {
+                        font_size: 20.0,
+                        font: text_font.font.clone(),
+                        ..default()
+                    },
+                    TextColor(BLUE.into()),
+                ));
                 spawn_button(
                     p1,
-                    "Z-UP",
+                    "间距 +",
                     text_font.clone(),
-                    ButtonID::SwitchSpeedReset,
-                    get_switch_camera_orientation_fn(ButtonID::SwitchCameraResetZ),
+                    ButtonID::SwitchContourIntervalIncrease,
+                    get_switch_contour_interval_fn(ButtonID::SwitchContourIntervalIncrease),
                 );
                 spawn_button(
                     p1,
-                    "Y-UP",
+                    format!("标注: {}", ContourLabels::Disable).as_str(),
                     text_font.clone(),
-                    ButtonID::SwitchSpeedReset,
-                    get_switch_camera_orientation_fn(ButtonID::SwitchCameraResetY),
+                    ButtonID::SwitchContourLabels,
+                    on_switch_contour_labels_clicked,
                 );
             });
 
-            // 添加 帮助 按钮
+            // 添加 原始/残差 显示切换按钮
             spawn_button(
                 p,
-                "帮助",
+                format!("显示: {}", HeightDisplay::Raw).as_str(),
                 text_font.clone(),
-                ButtonID::SwitchHelp,
-                on_switch_help_clicked,
+                ButtonID::SwitchHeightDisplay,
+                on_switch_height_display_clicked,
             );
-        });
-}
 
-const NORMAL_BUTTON: Srgba = BLUE_500;
-const HOVERED_BUTTON: Srgba = RED_300;
-const PRESSED_BUTTON: Srgba = GREEN_500;
+            // 添加 方向图 副视口开关与频率切换按钮
+            spawn_button(
+                p,
+                format!("方向图: {}", BeamView::Enable).as_str(),
+                text_font.clone(),
+                ButtonID::SwitchBeamView,
+                on_switch_beam_view_clicked,
+            );
+            spawn_button(
+                p,
+                format!("波束频率: {} GHz", beam_settings.frequency_ghz).as_str(),
+                text_font.clone(),
+                ButtonID::SwitchBeamFrequency,
+                on_switch_beam_frequency_clicked,
+            );
 
-fn button_system(
-    mut interaction_query: Query<
-        (
-            &Interaction,
-            &mut BackgroundColor,
-            &mut BorderColor,
+            // 添加 促动器校正 对比视图、平滑系数与指令导出按钮
+            spawn_button(
+                p,
+                format!("校正对比: {}", CorrectionView::Enable).as_str(),
+                text_font.clone(),
+                ButtonID::SwitchCorrectionView,
+                on_switch_correction_view_clicked,
+            );
+            spawn_button(
+                p,
+                format!("平滑: {}", correction_settings.smoothness).as_str(),
+                text_font.clone(),
+                ButtonID::SwitchCorrectionSmoothness,
+                on_switch_correction_smoothness_clicked,
+            );
+            spawn_button(
+                p,
+                "导出指令",
+                text_font.clone(),
+                ButtonID::SwitchExportCommands,
+                on_export_commands_clicked,
+            );
+
+            // 添加 天线座 指向模式、方位俯仰滑块与重力形变读数
+            p.spawn((
+                Node {
+                    position_type: PositionType::Relative,
+                    width: Val::Auto,
+                    height: Val::Auto,
+                    padding: UiRect::horizontal(Val::Px(10.0)),
+                    justify_content: JustifyContent::FlexStart,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(10.0),
+                    ..default()
+                },
+                BackgroundColor(SILVER.with_alpha(0.8).into()),
+            ))
+            .with_children(|p1| {
+                spawn_button(
+                    p1,
+                    format!("指向: {}", MountMode::Manual).as_str(),
+                    text_font.clone(),
+                    ButtonID::SwitchMountMode,
+                    on_switch_mount_mode_clicked,
+                );
+                mount::spawn_mount_sliders(p1, text_font.clone());
+                gravity::spawn_gravity_text(p1, text_font.clone());
+            });
+
+            // 添加 故障注入：点选类型、随机注入、清除、保存场景与故障数
+            p.spawn((
+                Node {
+                    position_type: PositionType::Relative,
+                    width: Val::Auto,
+                    height: Val::Auto,
+                    padding: UiRect::horizontal(Val::Px(10.0)),
+                    justify_content: JustifyContent::FlexStart,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(10.0),
+                    ..default()
+                },
+                BackgroundColor(SILVER.with_alpha(0.8).into()),
+            ))
+            .with_children(|p1| {
+                spawn_button(
+                    p1,
+                    format!("点选故障: {}", FaultPicking::Disable).as_str(),
+                    text_font.clone(),
+                    ButtonID::SwitchFaultPicking,
+                    on_switch_fault_picking_clicked,
+                );
+                spawn_button(
+                    p1,
+                    "随机注入",
+                    text_font.clone(),
+                    ButtonID::SwitchFaultRandom,
+                    get_switch_fault_fn(ButtonID::SwitchFaultRandom),
+                );
+                spawn_button(
+                    p1,
+                    "清除故障",
+                    text_font.clone(),
+                    ButtonID::SwitchFaultClear,
+                    get_switch_fault_fn(ButtonID::SwitchFaultClear),
+                );
+                spawn_button(
+                    p1,
+                    "保存场景",
+                    text_font.clone(),
+                    ButtonID::SwitchFaultSave,
+                    get_switch_fault_fn(ButtonID::SwitchFaultSave),
+                );
+                p1.spawn((
+                    Text::default(),
+                    FaultStatusText,
+                    TextFont {
+                        font_size: 16.0,
+                        font: text_font.font.clone(),
+                        ..default()
+                    },
+                    TextColor(RED.into()),
+                ));
+            });
+
+            // 添加 几何位移 开关及放大系数控制
+            p.spawn((
+                Node {
+                    position_type: PositionType::Relative,
+                    width: Val::Auto,
+                    height: Val::Auto,
+                    padding: UiRect::horizontal(Val::Px(10.0)),
+                    justify_content: JustifyContent::FlexStart,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(10.0),
+                    ..default()
+                },
+                BackgroundColor(SILVER.with_alpha(0.8).into()),
+            ))
+            .with_children(|p1| {
+                spawn_button(
+                    p1,
+                    format!("几何位移: {}", DisplacementRender::Disable).as_str(),
+                    text_font.clone(),
+                    ButtonID::SwitchDisplacement,
+                    on_switch_displacement_clicked,
+                );
+                spawn_button(
+                    p1,
+                    "放大 -",
+                    text_font.clone(),
+                    ButtonID::SwitchDisplacementScaleDecrease,
+                    get_switch_displacement_scale_fn(ButtonID::SwitchDisplacementScaleDecrease),
+                );
+                p1.spawn((
+                    Text::new(format!("x{:.3}", displacement_scale.0)),
+                    DisplacementScaleText,
+                    TextFont {
+                        font_size: 20.0,
+                        font: text_font.font.clone(),
+                        ..default()
+                    },
+                    TextColor(BLUE.into()),
+                ));
+                spawn_button(
+                    p1,
+                    "放大 +",
+                    text_font.clone(),
+                    ButtonID::SwitchDisplacementScaleIncrease,
+                    get_switch_displacement_scale_fn(ButtonID::SwitchDisplacementScaleIncrease),
+                );
+            });
+
+            // 添加模拟速度控制
+            p.spawn((
+                Node {
+                    position_type: PositionType::Relative,
+                    width: Val::Auto,
+                    height: Val::Auto,
+                    padding: UiRect::horizontal(Val::Px(10.0)),
+                    justify_content: JustifyContent::FlexStart,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(10.0),
+                    ..default()
+                },
+                BackgroundColor(SILVER.with_alpha(0.8).into()),
+            ))
+            .with_children(|p1| {
+                // 添加 "速度+" 控制按钮
+                spawn_button(
+                    p1,
+                    "速度 -",
+                    text_font.clone(),
+                    ButtonID::SwitchSpeedDecrease,
+                    get_switch_speed_fn(ButtonID::SwitchSpeedDecrease),
+                );
+                p1.spawn((
+                    Text::new(format!("{:.4}", speed.0)),
+                    SpeedText,
+                    TextFont {
+                        font_size: 20.0,
+                        font: text_font.font.clone(),
+                        ..default()
+                    },
+                    TextColor(BLUE.into()),
+                ));
+                spawn_button(
+                    p1,
+                    "速度 +",
+                    text_font.clone(),
+                    ButtonID::SwitchSpeedDecrease,
+                    get_switch_speed_fn(ButtonID::SwitchSpeedIncrease),
+                );
+                spawn_button(
+                    p1,
+                    "重置",
+                    text_font.clone(),
+                    ButtonID::SwitchSpeedReset,
+                    get_switch_speed_fn(ButtonID::SwitchSpeedReset),
+                );
+            });
+
+            // 添加 帮助 按钮
+            spawn_button(
+                p,
+                format!(
+                    "相机控制: {}",
+                    match camera_control.enabled {
+                        true => "启用",
+                        false => "禁用",
+                    }
+                )
+                .as_str(),
+                text_font.clone(),
+                ButtonID::SwitchHelp,
+                on_switch_camera_control_clicked,
+            );
+
+            spawn_button(
+                p,
+                format!("参考面: {}", reference_plane_render.get()).as_str(),
+                text_font.clone(),
+                ButtonID::SwitchReferencePlaneRender,
+                on_switch_reference_plane_render_clicked,
+            );
+
+            spawn_button(
+                p,
+                format!("促动器: {}", MarkerRender::Enable).as_str(),
+                text_font.clone(),
+                ButtonID::SwitchMarkerRender,
+                on_switch_marker_render_clicked,
+            );
+
+            // 添加相机转动控制
+            p.spawn((
+                Node {
+                    position_type: PositionType::Relative,
+                    width: Val::Auto,
+                    height: Val::Auto,
+                    padding: UiRect::horizontal(Val::Px(10.0)),
+                    justify_content: JustifyContent::FlexStart,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(10.0),
+                    ..default()
+                },
+                BackgroundColor(SILVER.with_alpha(0.8).into()),
+            ))
+            .with_children(|p1| {
+                // 添加 "左" 控制按钮
+                spawn_button(
+                    p1,
+                    "左",
+                    text_font.clone(),
+                    ButtonID::SwitchCameraLeft,
+                    get_switch_camera_orientation_fn(ButtonID::SwitchCameraLeft),
+                );
+                spawn_button(
+                    p1,
+                    "右",
+                    text_font.clone(),
+                    ButtonID::SwitchCameraRight,
+                    get_switch_camera_orientation_fn(ButtonID::SwitchCameraRight),
+                );
+                spawn_button(
+                    p1,
+                    "上",
+                    text_font.clone(),
+                    ButtonID::SwitchCameraRight,
+                    get_switch_camera_orientation_fn(ButtonID::SwitchCameraUp),
+                );
+                spawn_button(
+                    p1,
+                    "下",
+                    text_font.clone(),
+                    ButtonID::SwitchCameraLeft,
+                    get_switch_camera_orientation_fn(ButtonID::SwitchCameraDown),
+                );
+                spawn_button(
+                    p1,
+                    "初始视角",
+                    text_font.clone(),
+                    ButtonID::SwitchSpeedReset,
+                    get_switch_camera_orientation_fn(ButtonID::SwitchCameraResetInit),
+                );
+                spawn_button(
+                    p1,
+                    "Z-UP",
+                    text_font.clone(),
+                    ButtonID::SwitchSpeedReset,
+                    get_switch_camera_orientation_fn(ButtonID::SwitchCameraResetZ),
+                );
+                spawn_button(
+                    p1,
+                    "Y-UP",
+                    text_font.clone(),
+                    ButtonID::SwitchSpeedReset,
+                    get_switch_camera_orientation_fn(ButtonID::SwitchCameraResetY),
+                );
+            });
+
+            // 添加 帮助 按钮
+            spawn_button(
+                p,
+                "帮助",
+                text_font.clone(),
+                ButtonID::SwitchHelp,
+                on_switch_help_clicked,
+            );
+        });
+}
+
+const NORMAL_BUTTON: Srgba = BLUE_500;
+const HOVERED_BUTTON: Srgba = RED_300;
+const PRESSED_BUTTON: Srgba = GREEN_500;
+
+fn button_system(
+    mut interaction_query: Query<
+        (
+            &Interaction,
+            &mut BackgroundColor,
+            &mut BorderColor,
             &Children,
         ),
         (Changed<Interaction>, With<Button>),
@@ -740,7 +1463,23 @@ fn on_switch_mocking_fn_clicked(
                     next_data_fn.set(MockingDataFn::Mock4);
                     *text = Text::new(format!("Shader 函数: {}", MockingDataFn::Mock4));
                 }
-                MockingDataFn::Mock4 => {
+                MockingDataFn::Mock4 => {
+                    next_data_fn.set(MockingDataFn::Zernike);
+                    *text = Text::new(format!("Shader 函数: {}", MockingDataFn::Zernike));
+                }
+                MockingDataFn::Zernike => {
+                    next_data_fn.set(MockingDataFn::Gravity);
+                    *text = Text::new(format!("Shader 函数: {}", MockingDataFn::Gravity));
+                }
+                MockingDataFn::Gravity => {
+                    next_data_fn.set(MockingDataFn::Thermal);
+                    *text = Text::new(format!("Shader 函数: {}", MockingDataFn::Thermal));
+                }
+                MockingDataFn::Thermal => {
+                    next_data_fn.set(MockingDataFn::Wind);
+                    *text = Text::new(format!("Shader 函数: {}", MockingDataFn::Wind));
+                }
+                MockingDataFn::Wind => {
                     next_data_fn.set(MockingDataFn::Mock1);
                     *text = Text::new(format!("Shader 函数: {}", MockingDataFn::Mock1));
                 }
@@ -749,73 +1488,165 @@ fn on_switch_mocking_fn_clicked(
     }
 }
 
-fn on_switch_mocking_state_clicked(
+fn on_switch_data_source_clicked(
     trigger: Trigger<Pointer<Down>>,
-    mocking_state: Res<State<MockingState>>,
-    mut next_mocking_state: ResMut<NextState<MockingState>>,
+    data_source: Res<State<DataSource>>,
+    mut next_data_source: ResMut<NextState<DataSource>>,
     query: Query<&Children>,
     mut text_query: Query<&mut Text>,
 ) {
     if let Ok(children) = query.get(trigger.entity()) {
         if let Ok(mut text) = text_query.get_mut(children[0]) {
-            match mocking_state.get() {
-                MockingState::Start => {
-                    *text = Text::new("模拟状态: 停止");
-                    next_mocking_state.set(MockingState::Stop);
-                }
+            let next = match data_source.get() {
+                DataSource::Mock => DataSource::Live,
+                DataSource::Live => DataSource::Playback,
+                DataSource::Playback => DataSource::Mock,
+            };
+            *text = Text::new(format!("数据源: {}", next));
+            next_data_source.set(next);
+        }
+    }
+}
+
+// 录制状态显示在 RecordingStatusText 中
+fn on_switch_recording_clicked(
+    _trigger: Trigger<Pointer<Down>>,
+    mut recorder: ResMut<Recorder>,
+    heights: Res<ActuatorHeights>,
+    time: Res<Time>,
+) {
+    if recorder.is_recording() {
+        recorder.stop();
+    } else {
+        recorder.start(
+            &Recorder::default_path(),
+            heights.len(),
+            time.elapsed_secs_f64(),
+        );
+    }
+}
+
+// 播放/暂停与“切换模拟状态”一致，都切换 MockingState
+fn get_switch_timeline_fn(
+    typ: ButtonID,
+) -> impl FnMut(
+    Trigger<Pointer<Down>>,
+    ResMut<SimulationClock>,
+    Option<Res<Playback>>,
+    Res<State<DataSource>>,
+    Res<State<MockingState>>,
+    ResMut<NextState<MockingState>>,
+) {
+    move |_trigger: Trigger<Pointer<Down>>,
+          mut clock: ResMut<SimulationClock>,
+          playback: Option<Res<Playback>>,
+          data_source: Res<State<DataSource>>,
+          mocking_state: Res<State<MockingState>>,
+          mut next_mocking_state: ResMut<NextState<MockingState>>| {
+        match typ {
+            ButtonID::SwitchTimelinePlay => match mocking_state.get() {
+                MockingState::Start => next_mocking_state.set(MockingState::Stop),
                 MockingState::Stop => {
-                    *text = Text::new("模拟状态: 开始");
+                    // 播放到结尾后再次播放从头开始
+                    if !clock.looping && clock.elapsed() >= clock.duration() {
+                        clock.seek(0.0);
+                    }
                     next_mocking_state.set(MockingState::Start);
                 }
+            },
+            ButtonID::SwitchTimelineStepBackward | ButtonID::SwitchTimelineStepForward => {
+                let playback = playback
+                    .as_deref()
+                    .filter(|_| *data_source.get() == DataSource::Playback);
+                let forward = matches!(typ, ButtonID::SwitchTimelineStepForward);
+                timeline::step_clock(&mut clock, playback, forward);
+            }
+            ButtonID::SwitchTimelineLoop => {
+                clock.looping = !clock.looping;
+            }
+            ButtonID::SwitchTimelineAddMarker => {
+                clock.add_marker();
+            }
+            ButtonID::SwitchTimelineNextMarker => {
+                if let Some(t) = clock.next_marker() {
+                    clock.seek(t);
+                }
+            }
+            ButtonID::SwitchTimelineClearMarkers => {
+                clock.clear_markers();
             }
+            _ => {}
+        }
+    }
+}
+
+// 把正在回放或最近一次录制的文件导出为同名 CSV
+fn on_export_csv_clicked(
+    _trigger: Trigger<Pointer<Down>>,
+    playback: Option<Res<Playback>>,
+    recorder: Res<Recorder>,
+) {
+    let path = match (&playback, &recorder.last_path) {
+        (Some(playback), _) => playback.path.clone(),
+        (None, Some(path)) => path.clone(),
+        (None, None) => {
+            warn!("没有可导出的录制文件");
+            return;
+        }
+    };
+    let csv_path = path.with_extension("csv");
+    match Recording::load(&path)
+        .map_err(|err| err.to_string())
+        .and_then(|recording| {
+            recording
+                .write_csv(&csv_path)
+                .map_err(|err| err.to_string())
+        }) {
+        Ok(()) => info!("已导出 {}", csv_path.display()),
+        Err(err) => error!("导出 CSV 失败: {}", err),
+    }
+}
+
+// 按钮文字由 update_mocking_state_text 更新
+fn on_switch_mocking_state_clicked(
+    _trigger: Trigger<Pointer<Down>>,
+    mocking_state: Res<State<MockingState>>,
+    mut next_mocking_state: ResMut<NextState<MockingState>>,
+) {
+    match mocking_state.get() {
+        MockingState::Start => next_mocking_state.set(MockingState::Stop),
+        MockingState::Stop => next_mocking_state.set(MockingState::Start),
+    }
+}
+
+// 时间轴的播放/暂停也会切换模拟状态，统一在此同步按钮文字
+fn update_mocking_state_text(
+    mocking_state: Res<State<MockingState>>,
+    buttons: Query<(&ButtonID, &Children)>,
+    mut text_query: Query<&mut Text>,
+) {
+    for (id, children) in buttons.iter() {
+        if !matches!(id, ButtonID::SwitchMockingState) {
+            continue;
+        }
+        if let Ok(mut text) = text_query.get_mut(children[0]) {
+            *text = Text::new(match mocking_state.get() {
+                MockingState::Start => "模拟状态: 开始",
+                MockingState::Stop => "模拟状态: 停止",
+            });
         }
     }
 }
 
-fn on_switch_mocking_interpolate_algo_clicked(
+fn on_switch_colormap_clicked(
     trigger: Trigger<Pointer<Down>>,
-    mocking_algo: Res<State<MockingInterpolateAlgo>>,
-    mut next_mocking_algo: ResMut<NextState<MockingInterpolateAlgo>>,
+    mut colormaps: ResMut<ColormapLibrary>,
     query: Query<&Children>,
     mut text_query: Query<&mut Text>,
-    mut custom_materials: ResMut<Assets<CustomMaterial>>,
-    custom_material_handle: Res<CustomMaterialHandle>,
 ) {
-    let custom_material = custom_materials.get_mut(&custom_material_handle.0).unwrap();
     if let Ok(children) = query.get(trigger.entity()) {
         if let Ok(mut text) = text_query.get_mut(children[0]) {
-            match mocking_algo.get() {
-                MockingInterpolateAlgo::Interpolate_0 => {
-                    *text = Text::new("颜色算法: Interpolate_1");
-                    next_mocking_algo.set(MockingInterpolateAlgo::Interpolate_1);
-                    custom_material.interpolate_algo = 1;
-                }
-                MockingInterpolateAlgo::Interpolate_1 => {
-                    *text = Text::new("颜色算法: Interpolate_Normal");
-                    next_mocking_algo.set(MockingInterpolateAlgo::Interpolate_Normal);
-                    custom_material.interpolate_algo = 2;
-                }
-                MockingInterpolateAlgo::Interpolate_Normal => {
-                    *text = Text::new("颜色算法: Interpolate_Oklab");
-                    next_mocking_algo.set(MockingInterpolateAlgo::Interpolate_Oklab);
-                    custom_material.interpolate_algo = 3;
-                }
-                MockingInterpolateAlgo::Interpolate_Oklab => {
-                    *text = Text::new("颜色算法: Interpolate_Heat5");
-                    next_mocking_algo.set(MockingInterpolateAlgo::Interpolate_Heat5);
-                    custom_material.interpolate_algo = 4;
-                }
-                MockingInterpolateAlgo::Interpolate_Heat5 => {
-                    *text = Text::new("颜色算法: Interpolate_Heat7");
-                    next_mocking_algo.set(MockingInterpolateAlgo::Interpolate_Heat7);
-                    custom_material.interpolate_algo = 5;
-                }
-                MockingInterpolateAlgo::Interpolate_Heat7 => {
-                    *text = Text::new("颜色算法: Interpolate_0");
-                    next_mocking_algo.set(MockingInterpolateAlgo::Interpolate_0);
-                    custom_material.interpolate_algo = 0;
-                }
-            }
+            *text = Text::new(format!("颜色映射: {}", colormaps.next().name));
         }
     }
 }
@@ -849,6 +1680,325 @@ fn on_switch_boundary_clicked(
     }
 }
 
+fn on_switch_contour_render_clicked(
+    trigger: Trigger<Pointer<Down>>,
+    render: Res<State<ContourRender>>,
+    mut next_render: ResMut<NextState<ContourRender>>,
+    query: Query<&Children>,
+    mut text_query: Query<&mut Text>,
+) {
+    if let Ok(children) = query.get(trigger.entity()) {
+        if let Ok(mut text) = text_query.get_mut(children[0]) {
+            let next = match render.get() {
+                ContourRender::Enable => ContourRender::Disable,
+                ContourRender::Disable => ContourRender::Enable,
+            };
+            *text = Text::new(format!("等高线: {}", next));
+            next_render.set(next);
+        }
+    }
+}
+
+fn get_switch_contour_interval_fn(
+    typ: ButtonID,
+) -> impl FnMut(Trigger<Pointer<Down>>, ResMut<ContourSettings>) {
+    move |_trigger: Trigger<Pointer<Down>>, mut settings: ResMut<ContourSettings>| {
+        match typ {
+            ButtonID::SwitchContourIntervalDecrease => settings.scale(0.5),
+            ButtonID::SwitchContourIntervalIncrease => settings.scale(2.0),
+            _ => {}
+        };
+    }
+}
+
+fn on_switch_contour_labels_clicked(
+    trigger: Trigger<Pointer<Down>>,
+    labels: Res<State<ContourLabels>>,
+    mut next_labels: ResMut<NextState<ContourLabels>>,
+    query: Query<&Children>,
+    mut text_query: Query<&mut Text>,
+) {
+    if let Ok(children) = query.get(trigger.entity()) {
+        if let Ok(mut text) = text_query.get_mut(children[0]) {
+            let next = match labels.get() {
+                ContourLabels::Enable => ContourLabels::Disable,
+                ContourLabels::Disable => ContourLabels::Enable,
+            };
+            *text = Text::new(format!("标注: {}", next));
+            next_labels.set(next);
+        }
+    }
+}
+
+fn on_switch_height_display_clicked(
+    trigger: Trigger<Pointer<Down>>,
+    display: Res<State<HeightDisplay>>,
+    mut next_display: ResMut<NextState<HeightDisplay>>,
+    query: Query<&Children>,
+    mut text_query: Query<&mut Text>,
+) {
+    if let Ok(children) = query.get(trigger.entity()) {
+        if let Ok(mut text) = text_query.get_mut(children[0]) {
+            let next = match display.get() {
+                HeightDisplay::Raw => HeightDisplay::Residual,
+                HeightDisplay::Residual => HeightDisplay::ZernikeReconstruction,
+                HeightDisplay::ZernikeReconstruction => HeightDisplay::ZernikeResidual,
+                HeightDisplay::ZernikeResidual => HeightDisplay::LutCorrection,
+                HeightDisplay::LutCorrection => HeightDisplay::LutCorrected,
+                HeightDisplay::LutCorrected => HeightDisplay::Raw,
+            };
+            *text = Text::new(format!("显示: {}", next));
+            next_display.set(next);
+        }
+    }
+}
+
+fn on_switch_beam_view_clicked(
+    trigger: Trigger<Pointer<Down>>,
+    view: Res<State<BeamView>>,
+    mut next_view: ResMut<NextState<BeamView>>,
+    query: Query<&Children>,
+    mut text_query: Query<&mut Text>,
+) {
+    if let Ok(children) = query.get(trigger.entity()) {
+        if let Ok(mut text) = text_query.get_mut(children[0]) {
+            let next = match view.get() {
+                BeamView::Enable => BeamView::Disable,
+                BeamView::Disable => BeamView::Enable,
+            };
+            *text = Text::new(format!("方向图: {}", next));
+            next_view.set(next);
+        }
+    }
+}
+
+// 在观测频率之间循环切换方向图的计算频率
+fn on_switch_beam_frequency_clicked(
+    trigger: Trigger<Pointer<Down>>,
+    frequencies: Res<ObservingFrequencies>,
+    mut settings: ResMut<BeamSettings>,
+    query: Query<&Children>,
+    mut text_query: Query<&mut Text>,
+) {
+    if frequencies.is_empty() {
+        return;
+    }
+    let next = frequencies
+        .iter()
+        .position(|&f| f == settings.frequency_ghz)
+        .map_or(0, |i| (i + 1) % frequencies.len());
+    settings.frequency_ghz = frequencies[next];
+    if let Ok(children) = query.get(trigger.entity()) {
+        if let Ok(mut text) = text_query.get_mut(children[0]) {
+            *text = Text::new(format!("波束频率: {} GHz", settings.frequency_ghz));
+        }
+    }
+}
+
+fn on_switch_correction_view_clicked(
+    trigger: Trigger<Pointer<Down>>,
+    view: Res<State<CorrectionView>>,
+    mut next_view: ResMut<NextState<CorrectionView>>,
+    query: Query<&Children>,
+    mut text_query: Query<&mut Text>,
+) {
+    if let Ok(children) = query.get(trigger.entity()) {
+        if let Ok(mut text) = text_query.get_mut(children[0]) {
+            let next = match view.get() {
+                CorrectionView::Enable => CorrectionView::Disable,
+                CorrectionView::Disable => CorrectionView::Enable,
+            };
+            *text = Text::new(format!("校正对比: {}", next));
+            next_view.set(next);
+        }
+    }
+}
+
+fn on_switch_correction_smoothness_clicked(
+    trigger: Trigger<Pointer<Down>>,
+    mut settings: ResMut<CorrectionSettings>,
+    query: Query<&Children>,
+    mut text_query: Query<&mut Text>,
+) {
+    let next = SMOOTHNESS_OPTIONS
+        .iter()
+        .position(|&s| s == settings.smoothness)
+        .map_or(0, |i| (i + 1) % SMOOTHNESS_OPTIONS.len());
+    settings.smoothness = SMOOTHNESS_OPTIONS[next];
+    if let Ok(children) = query.get(trigger.entity()) {
+        if let Ok(mut text) = text_query.get_mut(children[0]) {
+            *text = Text::new(format!("平滑: {}", settings.smoothness));
+        }
+    }
+}
+
+fn on_export_commands_clicked(
+    _trigger: Trigger<Pointer<Down>>,
+    correction: Res<ActuatorCorrection>,
+    topology: Res<ActuatorTopology>,
+) {
+    let path = ActuatorCorrection::default_path();
+    match correction.write_csv(&path, &topology) {
+        Ok(()) => info!("已导出促动器指令 {}", path.display()),
+        Err(err) => error!("导出促动器指令失败: {}", err),
+    }
+}
+
+fn on_switch_fault_picking_clicked(
+    trigger: Trigger<Pointer<Down>>,
+    picking: Res<State<FaultPicking>>,
+    mut next_picking: ResMut<NextState<FaultPicking>>,
+    query: Query<&Children>,
+    mut text_query: Query<&mut Text>,
+) {
+    if let Ok(children) = query.get(trigger.entity()) {
+        if let Ok(mut text) = text_query.get_mut(children[0]) {
+            let next = match picking.get() {
+                FaultPicking::Disable => FaultPicking::Stuck,
+                FaultPicking::Stuck => FaultPicking::Dead,
+                FaultPicking::Dead => FaultPicking::Noisy,
+                FaultPicking::Noisy => FaultPicking::Offset,
+                FaultPicking::Offset => FaultPicking::Disable,
+            };
+            *text = Text::new(format!("点选故障: {}", next));
+            next_picking.set(next);
+        }
+    }
+}
+
+fn get_switch_fault_fn(
+    typ: ButtonID,
+) -> impl FnMut(Trigger<Pointer<Down>>, Res<ActuatorHeights>, ResMut<ActuatorFaults>) {
+    move |_trigger: Trigger<Pointer<Down>>,
+          heights: Res<ActuatorHeights>,
+          mut faults: ResMut<ActuatorFaults>| match typ {
+        ButtonID::SwitchFaultRandom => {
+            let seed = faults.seed;
+            faults.inject_random(RANDOM_FAULT_COUNT, &heights, seed);
+            faults.seed = seed.wrapping_add(1);
+        }
+        ButtonID::SwitchFaultClear => faults.clear(),
+        ButtonID::SwitchFaultSave => match faults.save_scenario() {
+            Ok(path) => info!("已保存故障场景 {}", path.display()),
+            Err(err) => error!("保存故障场景失败: {}", err),
+        },
+        _ => {}
+    }
+}
+
+fn on_switch_mount_mode_clicked(
+    trigger: Trigger<Pointer<Down>>,
+    mode: Res<State<MountMode>>,
+    mut next_mode: ResMut<NextState<MountMode>>,
+    query: Query<&Children>,
+    mut text_query: Query<&mut Text>,
+) {
+    if let Ok(children) = query.get(trigger.entity()) {
+        if let Ok(mut text) = text_query.get_mut(children[0]) {
+            let next = match mode.get() {
+                MountMode::Manual => MountMode::Sidereal,
+                MountMode::Sidereal => MountMode::Raster,
+                MountMode::Raster => MountMode::Manual,
+            };
+            *text = Text::new(format!("指向: {}", next));
+            next_mode.set(next);
+        }
+    }
+}
+
+fn on_switch_displacement_clicked(
+    trigger: Trigger<Pointer<Down>>,
+    displacement_state: Res<State<DisplacementRender>>,
+    mut next_displacement_state: ResMut<NextState<DisplacementRender>>,
+    query: Query<&Children>,
+    mut text_query: Query<&mut Text>,
+    mut custom_materials: ResMut<Assets<CustomMaterial>>,
+    custom_material_handle: Res<CustomMaterialHandle>,
+) {
+    let custom_material = custom_materials.get_mut(&custom_material_handle.0).unwrap();
+    if let Ok(children) = query.get(trigger.entity()) {
+        if let Ok(mut text) = text_query.get_mut(children[0]) {
+            match *displacement_state.get() {
+                DisplacementRender::Enable => {
+                    *text = Text::new("几何位移: 禁用");
+                    next_displacement_state.set(DisplacementRender::Disable);
+                    custom_material.enable_displacement = 0;
+                }
+                DisplacementRender::Disable => {
+                    *text = Text::new("几何位移: 启用");
+                    next_displacement_state.set(DisplacementRender::Enable);
+                    custom_material.enable_displacement = 1;
+                }
+            }
+        }
+    }
+}
+
+fn get_switch_displacement_scale_fn(
+    typ: ButtonID,
+) -> impl FnMut(
+    Trigger<Pointer<Down>>,
+    Single<&mut Text, With<DisplacementScaleText>>,
+    ResMut<DisplacementScale>,
+    ResMut<Assets<CustomMaterial>>,
+    Res<CustomMaterialHandle>,
+) {
+    move |_trigger: Trigger<Pointer<Down>>,
+          mut text: Single<&mut Text, With<DisplacementScaleText>>,
+          mut scale: ResMut<DisplacementScale>,
+          mut custom_materials: ResMut<Assets<CustomMaterial>>,
+          custom_material_handle: Res<CustomMaterialHandle>| {
+        match typ {
+            ButtonID::SwitchDisplacementScaleDecrease => {
+                scale.0 *= 0.5;
+            }
+            ButtonID::SwitchDisplacementScaleIncrease => {
+                scale.0 *= 2.0;
+            }
+            _ => {}
+        };
+        if let Some(custom_material) = custom_materials.get_mut(&custom_material_handle.0) {
+            custom_material.displacement_scale = scale.0;
+        }
+        **text = Text::new(format!("x{:.3}", scale.0));
+    }
+}
+
+fn on_switch_color_range_mode_clicked(
+    trigger: Trigger<Pointer<Down>>,
+    mode: Res<State<ColorRangeMode>>,
+    mut next_mode: ResMut<NextState<ColorRangeMode>>,
+    query: Query<&Children>,
+    mut text_query: Query<&mut Text>,
+) {
+    if let Ok(children) = query.get(trigger.entity()) {
+        if let Ok(mut text) = text_query.get_mut(children[0]) {
+            let next = match mode.get() {
+                ColorRangeMode::Fixed => ColorRangeMode::Symmetric,
+                ColorRangeMode::Symmetric => ColorRangeMode::Auto,
+                ColorRangeMode::Auto => ColorRangeMode::Fixed,
+            };
+            *text = Text::new(format!("色标范围: {}", next));
+            next_mode.set(next);
+        }
+    }
+}
+
+// 自动模式下范围由高度决定，缩放按钮不起作用
+fn get_switch_color_range_fn(
+    typ: ButtonID,
+) -> impl FnMut(Trigger<Pointer<Down>>, Res<State<ColorRangeMode>>, ResMut<ColorRangeSettings>) {
+    move |_trigger: Trigger<Pointer<Down>>,
+          mode: Res<State<ColorRangeMode>>,
+          mut settings: ResMut<ColorRangeSettings>| {
+        match typ {
+            ButtonID::SwitchColorRangeDecrease => settings.scale(*mode.get(), 0.5),
+            ButtonID::SwitchColorRangeIncrease => settings.scale(*mode.get(), 2.0),
+            _ => {}
+        };
+    }
+}
+
 fn on_switch_reference_plane_render_clicked(
     trigger: Trigger<Pointer<Down>>,
     reference_plane_state: Res<State<ReferencePlaneRender>>,
@@ -879,6 +2029,25 @@ fn on_switch_reference_plane_render_clicked(
     }
 }
 
+fn on_switch_marker_render_clicked(
+    trigger: Trigger<Pointer<Down>>,
+    marker_render: Res<State<MarkerRender>>,
+    mut next_marker_render: ResMut<NextState<MarkerRender>>,
+    query: Query<&Children>,
+    mut text_query: Query<&mut Text>,
+) {
+    if let Ok(children) = query.get(trigger.entity()) {
+        if let Ok(mut text) = text_query.get_mut(children[0]) {
+            let next = match marker_render.get() {
+                MarkerRender::Enable => MarkerRender::Disable,
+                MarkerRender::Disable => MarkerRender::Enable,
+            };
+            *text = Text::new(format!("促动器: {}", next));
+            next_marker_render.set(next);
+        }
+    }
+}
+
 fn on_switch_help_clicked(
     trigger: Trigger<Pointer<Down>>,
     mut query: Query<&mut Visibility, With<InstructionText>>,
@@ -949,10 +2118,14 @@ fn get_switch_speed_fn(
     }
 }
 
+// 预设视角相对反射面定义，随天线座的指向一起转动
 fn get_switch_camera_orientation_fn(
     typ: ButtonID,
-) -> impl FnMut(Trigger<Pointer<Down>>, Single<&mut Transform, With<Camera>>) {
-    move |trigger: Trigger<Pointer<Down>>, mut camera: Single<&mut Transform, With<Camera>>| {
+) -> impl FnMut(Trigger<Pointer<Down>>, Single<&mut Transform, With<Camera3d>>, Res<Mount>) {
+    move |trigger: Trigger<Pointer<Down>>,
+          mut camera: Single<&mut Transform, With<Camera3d>>,
+          mount: Res<Mount>| {
+        let rotation = mount.rotation();
         match typ {
             ButtonID::SwitchCameraLeft => {
                 // Rotate camera left
@@ -972,18 +2145,18 @@ fn get_switch_camera_orientation_fn(
             }
             ButtonID::SwitchCameraResetZ => {
                 // Reset camera position
-                camera.translation = CAMERA_INITIAL_POSITION;
-                camera.look_at(Vec3::ZERO, Vec3::Z);
+                camera.translation = rotation * CAMERA_INITIAL_POSITION;
+                camera.look_at(Vec3::ZERO, rotation * Vec3::Z);
             }
             ButtonID::SwitchCameraResetY => {
                 // Reset camera position
-                camera.translation = CAMERA_INITIAL_POSITION_Z;
-                camera.look_at(Vec3::ZERO, Vec3::Z);
+                camera.translation = rotation * CAMERA_INITIAL_POSITION_Z;
+                camera.look_at(Vec3::ZERO, rotation * Vec3::Z);
             }
             ButtonID::SwitchCameraResetInit => {
                 // Reset camera position
-                camera.translation = CAMERA_INITIAL_POSITION_INIT;
-                camera.look_at(Vec3::ZERO, Vec3::Z);
+                camera.translation = rotation * CAMERA_INITIAL_POSITION_INIT;
+                camera.look_at(Vec3::ZERO, rotation * Vec3::Z);
             }
             _ => {}
         }
@@ -1097,6 +2270,11 @@ fn setup_instruction(
                 text_font.clone(),
                 TextColor(RED.into()),
             ));
+            p.spawn((
+                TextSpan::new("方向键 - 调整天线方位/俯仰\n"),
+                text_font.clone(),
+                TextColor(RED.into()),
+            ));
             p.spawn((
                 TextSpan::new("---------------\n"),
                 text_font.clone(),
@@ -1160,6 +2338,7 @@ fn update_exposure(
 
 fn create_mesh(
     positions: &[[f32; 3]],
+    actuators: &[u32],
     indices: Vec<u32>,
     colors: Vec<[f32; 4]>,
     uv: Vec<[f32; 2]>,
@@ -1173,9 +2352,14 @@ fn create_mesh(
     .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uv)
     .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normal)
     .with_inserted_indices(Indices::U32(indices))
+    // 顶点所属促动器编号，着色器据此读取高度缓冲
+    .with_inserted_attribute(ATTRIBUTE_POSITION_INDEX, actuators.to_vec())
+    // 顶点所属面板编号，每 4 个顶点为一块面板，用于高亮查看的面板
     .with_inserted_attribute(
-        ATTRIBUTE_POSITION_INDEX,
-        (0..positions.len() as u32).collect::<Vec<u32>>(),
+        ATTRIBUTE_PANEL_INDEX,
+        (0..positions.len() as u32)
+            .map(|i| i / 4)
+            .collect::<Vec<u32>>(),
     )
 }
 
@@ -1190,27 +2374,49 @@ fn toggle_text_visibility(mut query: Query<&mut Visibility, With<InstructionText
 }
 
 fn update(
-    time: Res<Time>,
+    clock: Res<SimulationClock>,
+    mut heights: ResMut<ActuatorHeights>,
+    data_fn: Res<State<MockingDataFn>>,
+    geometry: Res<ReflectorGeometry>,
+    topology: Res<ActuatorTopology>,
+    zernike_basis: Res<ZernikeBasis>,
+    zernike_input: Res<ZernikeInput>,
+    gravity: Res<GravityModel>,
+    elevation: Res<Elevation>,
+    thermal: Res<ThermalModel>,
+    thermal_settings: Res<ThermalSettings>,
+    mount: Res<Mount>,
+    wind: Res<WindModel>,
+    wind_settings: Res<WindSettings>,
+) {
+    let t = clock.elapsed() as f32;
+    heights.0 = match data_fn.get() {
+        MockingDataFn::Mock1 => (0..topology.actuator_count() as i32)
+            .map(|i| mock1(t, i))
+            .collect::<Vec<f32>>(),
+        MockingDataFn::Mock2 => mock5(t, &topology, geometry.ring_count()),
+        MockingDataFn::Mock3 => mock3(t, &topology, geometry.ring_count()),
+        MockingDataFn::Mock4 => mock4(t, &topology, geometry.ring_count()),
+        MockingDataFn::Zernike => zernike_input.surface(&zernike_basis),
+        MockingDataFn::Gravity => gravity.sag(**elevation),
+        MockingDataFn::Thermal => thermal.surface(&thermal_settings, &mount),
+        MockingDataFn::Wind => wind.surface(&wind_settings, clock.elapsed()),
+    };
+}
+
+// 把反射面上显示的高度写入 GPU 高度缓冲
+fn upload_heights(
+    heights: Res<DisplayedHeights>,
+    topology: Res<ActuatorTopology>,
     material_handle: Res<CustomMaterialHandle>,
-    mut materials: ResMut<Assets<CustomMaterial>>,
+    materials: Res<Assets<CustomMaterial>>,
     mut buffers: ResMut<Assets<ShaderStorageBuffer>>,
-    data_fn: Res<State<MockingDataFn>>,
-    speed: Res<MockingSpeed>,
 ) {
-    let material = materials.get_mut(&material_handle.0).unwrap();
+    let material = materials.get(&material_handle.0).unwrap();
     let buffer = buffers.get_mut(&material.buffer).unwrap();
-    let t = time.elapsed_secs() * speed.0;
-    buffer.set_data(
-        (match data_fn.get() {
-            MockingDataFn::Mock1 => (0..object::vertices.len() as i32)
-                .map(|i| mock1(t, i))
-                .collect::<Vec<f32>>(),
-            MockingDataFn::Mock2 => mock5(t),
-            MockingDataFn::Mock3 => mock3(t),
-            MockingDataFn::Mock4 => mock4(t),
-        })
-        .as_slice(),
-    );
+    let mut heights = heights.0.clone();
+    topology.conform(&mut heights);
+    buffer.set_data(heights.as_slice());
 }
 
 fn rotate_camera3d(
@@ -1261,68 +2467,34 @@ fn mock2(t: f32, i: i32) -> f32 {
     }
 }
 
-fn mock3(t: f32) -> Vec<f32> {
-    let mut result = [0.0; object::vertices.len()];
-    let mut index = 0;
-    let step = std::f32::consts::TAU / object::dounat_blocks.len() as f32;
-    for (i, &block) in object::dounat_blocks.iter().enumerate() {
-        for j in 0..block {
-            let low_h =
-                (ops::sin(t + i as f32 * step + (j as f32) * std::f32::consts::TAU / block as f32)
-                    + 1.0)
-                    * 0.5;
-            let high_h = (ops::sin(
-                t + (i as f32 + 1.0) * step + (j as f32) * std::f32::consts::TAU / block as f32,
-            ) + 1.0)
-                * 0.5;
-            result[index] = low_h;
-            result[index + 1] = low_h;
-            result[index + 2] = high_h;
-            result[index + 3] = high_h;
-            index += 4;
-        }
-    }
-    return result.to_vec();
+fn mock3(t: f32, topology: &ActuatorTopology, ring_count: usize) -> Vec<f32> {
+    let step = std::f32::consts::TAU / ring_count as f32;
+    topology
+        .positions()
+        .iter()
+        .zip(topology.boundaries())
+        .map(|(p, &k)| (ops::sin(t + k as f32 * step + ops::atan2(p.y, p.x)) + 1.0) * 0.5)
+        .collect()
 }
 
-fn mock5(t: f32) -> Vec<f32> {
-    let mut result = [0.0; object::vertices.len()];
-    let mut index = 0;
-    let step = std::f32::consts::TAU / object::dounat_blocks.len() as f32;
-    for (i, &block) in object::dounat_blocks.iter().enumerate() {
-        for j in 0..block {
-            let low_h = (ops::sin(t + i as f32 * step) + 1.0) * 0.5;
-            let high_h = (ops::sin(t + (i as f32 + 1.0) * step) + 1.0) * 0.5;
-            result[index] = low_h;
-            result[index + 1] = low_h;
-            result[index + 2] = high_h;
-            result[index + 3] = high_h;
-            index += 4;
-        }
-    }
-    return result.to_vec();
+fn mock5(t: f32, topology: &ActuatorTopology, ring_count: usize) -> Vec<f32> {
+    let step = std::f32::consts::TAU / ring_count as f32;
+    topology
+        .boundaries()
+        .iter()
+        .map(|&k| (ops::sin(t + k as f32 * step) + 1.0) * 0.5)
+        .collect()
 }
-fn mock4(t: f32) -> Vec<f32> {
-    let mut result = [0.0; object::vertices.len()];
-    let mut index = 0;
-    let step = std::f32::consts::TAU / object::dounat_blocks.len() as f32;
-    for (i, &block) in object::dounat_blocks.iter().enumerate() {
-        let t0 = if i % 2 == 0 { t } else { -t };
-        for j in 0..block {
-            let low_h = (ops::sin(
-                t0 + i as f32 * step + (j as f32) * std::f32::consts::TAU / block as f32,
-            ) + 1.0)
-                * 0.5;
-            let high_h = (ops::sin(
-                t0 + (i as f32 + 1.0) * step + (j as f32) * std::f32::consts::TAU / block as f32,
-            ) + 1.0)
-                * 0.5;
-            result[index] = low_h;
-            result[index + 1] = low_h;
-            result[index + 2] = high_h;
-            result[index + 3] = high_h;
-            index += 4;
-        }
-    }
-    return result.to_vec();
+
+fn mock4(t: f32, topology: &ActuatorTopology, ring_count: usize) -> Vec<f32> {
+    let step = std::f32::consts::TAU / ring_count as f32;
+    topology
+        .positions()
+        .iter()
+        .zip(topology.boundaries())
+        .map(|(p, &k)| {
+            let t0 = if k % 2 == 0 { t } else { -t };
+            (ops::sin(t0 + k as f32 * step + ops::atan2(p.y, p.x)) + 1.0) * 0.5
+        })
+        .collect()
 }
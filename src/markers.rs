@@ -0,0 +1,509 @@
+//! 促动器标记
+//!
+//! 所有促动器标记用 GPU 实例化一次绘制：只有一个小球网格，每个促动器是一个实例。实例数据
+//! （球心位置、缩放与颜色）按促动器编号排列成实例顶点缓冲，由自定义的绘制命令以促动器数
+//! 作为实例数绘制，着色器按实例数据平移、缩放小球。
+//!
+//! 实例数据在 CPU 上计算：球心是促动器位置，几何位移模式下再沿法线移动与反射面相同的
+//! 距离；颜色与缩放区分促动器状态（如注入的故障）。提取到渲染世界时把球心变换到世界坐标，
+//! 标记因此跟随反射面随座架转动。
+//!
+//! 隐藏标记时只隐藏正常的促动器，有故障的促动器标记始终显示，故障不会随标记一起被隐藏。
+//! 标记不参与拾取，点选故障时拾取的是反射面，再取离点击位置最近的促动器。
+
+use crate::analysis::DisplayedHeights;
+use crate::data_source::{fault::ActuatorFaults, HeightSet};
+use crate::{actuator::ActuatorTopology, DisplacementRender, DisplacementScale, Reflector};
+use bevy::{
+    color::{palettes::css::*, ColorToComponents},
+    core_pipeline::core_3d::Transparent3d,
+    ecs::{
+        query::QueryItem,
+        system::{lifetimeless::*, SystemParamItem},
+    },
+    pbr::{
+        MeshPipeline, MeshPipelineKey, RenderMeshInstances, SetMeshBindGroup, SetMeshViewBindGroup,
+    },
+    prelude::*,
+    render::{
+        extract_component::{ExtractComponent, ExtractComponentPlugin},
+        mesh::{
+            allocator::MeshAllocator, MeshVertexBufferLayoutRef, RenderMesh, RenderMeshBufferInfo,
+        },
+        render_asset::RenderAssets,
+        render_phase::{
+            AddRenderCommand, DrawFunctions, PhaseItem, PhaseItemExtraIndex, RenderCommand,
+            RenderCommandResult, SetItemPipeline, TrackedRenderPass, ViewSortedRenderPhases,
+        },
+        render_resource::*,
+        renderer::RenderDevice,
+        sync_world::MainEntity,
+        view::{ExtractedView, NoFrustumCulling},
+        Render, RenderApp, RenderSet,
+    },
+};
+use std::fmt::{self, Formatter};
+
+const SHADER_ASSET_PATH: &str = "shaders/actuator_marker.wgsl";
+
+/// 标记小球的半径（场景单位）
+const MARKER_RADIUS: f32 = 0.03;
+/// 有故障的促动器标记的放大倍数
+const FAULT_MARKER_SCALE: f32 = 3.0;
+
+pub struct MarkerPlugin;
+
+impl Plugin for MarkerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(ExtractComponentPlugin::<MarkerInstances>::default())
+            .init_state::<MarkerRender>()
+            .add_systems(PostStartup, spawn_actuator_markers)
+            .add_systems(
+                Update,
+                update_marker_instances.in_set(HeightSet::Upload).run_if(
+                    resource_changed::<DisplayedHeights>
+                        .or(resource_changed::<ActuatorFaults>)
+                        .or(resource_changed::<DisplacementScale>)
+                        .or(state_changed::<DisplacementRender>)
+                        .or(state_changed::<MarkerRender>),
+                ),
+            );
+        app.sub_app_mut(RenderApp)
+            .add_render_command::<Transparent3d, DrawMarkers>()
+            .init_resource::<SpecializedMeshPipelines<MarkerPipeline>>()
+            .add_systems(
+                Render,
+                (
+                    queue_markers.in_set(RenderSet::QueueMeshes),
+                    prepare_marker_buffers.in_set(RenderSet::PrepareResources),
+                ),
+            );
+    }
+
+    fn finish(&self, app: &mut App) {
+        app.sub_app_mut(RenderApp).init_resource::<MarkerPipeline>();
+    }
+}
+
+/// 促动器标记显示开关
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash, States)]
+pub enum MarkerRender {
+    #[default]
+    Enable,
+    Disable,
+}
+
+impl fmt::Display for MarkerRender {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MarkerRender::Enable => write!(f, "显示"),
+            MarkerRender::Disable => write!(f, "隐藏"),
+        }
+    }
+}
+
+/// 促动器标记实体，网格是单个小球
+#[derive(Component)]
+pub struct ActuatorMarkers;
+
+/// 一个促动器标记实例，布局与着色器的实例顶点属性一致
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+struct MarkerInstance {
+    position: Vec3,
+    scale: f32,
+    color: [f32; 4],
+}
+
+impl MarkerInstance {
+    const SIZE: u64 = size_of::<[f32; 8]>() as u64;
+
+    fn to_array(self) -> [f32; 8] {
+        let [x, y, z] = self.position.to_array();
+        let [r, g, b, a] = self.color;
+        [x, y, z, self.scale, r, g, b, a]
+    }
+}
+
+/// 按促动器编号排列的标记实例。主世界中球心在反射面局部坐标，提取到渲染世界后为世界坐标
+#[derive(Component, Clone, Deref)]
+struct MarkerInstances(Vec<MarkerInstance>);
+
+impl ExtractComponent for MarkerInstances {
+    type QueryData = (&'static MarkerInstances, &'static GlobalTransform);
+    type QueryFilter = ();
+    type Out = Self;
+
+    fn extract_component((instances, transform): QueryItem<'_, Self::QueryData>) -> Option<Self> {
+        let scale = transform.compute_transform().scale.max_element();
+        Some(MarkerInstances(
+            instances
+                .iter()
+                .map(|instance| MarkerInstance {
+                    position: transform.transform_point(instance.position),
+                    scale: instance.scale * scale,
+                    ..*instance
+                })
+                .collect(),
+        ))
+    }
+}
+
+/// 计算每个促动器的标记实例。`displacement` 为几何位移的放大系数，未启用时为 `None`；
+/// 隐藏标记时正常促动器的缩放为 0，不产生片元
+fn marker_instances(
+    topology: &ActuatorTopology,
+    heights: &[f32],
+    displacement: Option<f32>,
+    faults: &ActuatorFaults,
+    render: &MarkerRender,
+) -> Vec<MarkerInstance> {
+    let normal_scale = match render {
+        MarkerRender::Enable => MARKER_RADIUS,
+        MarkerRender::Disable => 0.0,
+    };
+    // 与上传到反射面的高度一致，悬挂节点处的标记落在粗边上
+    let mut heights = heights.to_vec();
+    heights.resize(topology.actuator_count(), 0.0);
+    topology.conform(&mut heights);
+    topology
+        .positions()
+        .iter()
+        .zip(topology.normals())
+        .zip(heights)
+        .enumerate()
+        .map(|(id, ((position, normal), height))| {
+            let (color, scale) = match faults.get(id as u32) {
+                Some(fault) => (fault.marker_color(), MARKER_RADIUS * FAULT_MARKER_SCALE),
+                None => (ORANGE.into(), normal_scale),
+            };
+            MarkerInstance {
+                position: *position
+                    + displacement.map_or(Vec3::ZERO, |scale| normal.normalize() * height * scale),
+                scale,
+                color: LinearRgba::from(color).to_f32_array(),
+            }
+        })
+        .collect()
+}
+
+fn displacement(render: &DisplacementRender, scale: &DisplacementScale) -> Option<f32> {
+    match render {
+        DisplacementRender::Enable => Some(scale.0),
+        DisplacementRender::Disable => None,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn spawn_actuator_markers(
+    mut commands: Commands,
+    reflector: Single<Entity, With<Reflector>>,
+    topology: Res<ActuatorTopology>,
+    heights: Res<DisplayedHeights>,
+    faults: Res<ActuatorFaults>,
+    render: Res<State<MarkerRender>>,
+    displacement_render: Res<State<DisplacementRender>>,
+    displacement_scale: Res<DisplacementScale>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    let mesh = Sphere::new(1.0)
+        .mesh()
+        .ico(1)
+        .expect("1 次细分的二十面体总是有效");
+    let markers = commands
+        .spawn((
+            ActuatorMarkers,
+            Mesh3d(meshes.add(mesh)),
+            MarkerInstances(marker_instances(
+                &topology,
+                &heights,
+                displacement(displacement_render.get(), &displacement_scale),
+                &faults,
+                render.get(),
+            )),
+            marker_visibility(render.get(), &faults),
+            // 实例分布在整个反射面上，小球网格的包围盒不能代表实例，不做视锥剔除
+            NoFrustumCulling,
+        ))
+        .id();
+    commands.entity(*reflector).add_child(markers);
+}
+
+fn update_marker_instances(
+    topology: Res<ActuatorTopology>,
+    heights: Res<DisplayedHeights>,
+    faults: Res<ActuatorFaults>,
+    render: Res<State<MarkerRender>>,
+    displacement_render: Res<State<DisplacementRender>>,
+    displacement_scale: Res<DisplacementScale>,
+    mut markers: Query<(&mut MarkerInstances, &mut Visibility), With<ActuatorMarkers>>,
+) {
+    for (mut instances, mut visibility) in markers.iter_mut() {
+        visibility.set_if_neq(marker_visibility(render.get(), &faults));
+        instances.0 = marker_instances(
+            &topology,
+            &heights,
+            displacement(displacement_render.get(), &displacement_scale),
+            &faults,
+            render.get(),
+        );
+    }
+}
+
+// 隐藏标记且没有故障时才隐藏整个实体
+fn marker_visibility(render: &MarkerRender, faults: &ActuatorFaults) -> Visibility {
+    match render {
+        MarkerRender::Disable if faults.is_empty() => Visibility::Hidden,
+        _ => Visibility::Inherited,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn queue_markers(
+    transparent_3d_draw_functions: Res<DrawFunctions<Transparent3d>>,
+    marker_pipeline: Res<MarkerPipeline>,
+    mut pipelines: ResMut<SpecializedMeshPipelines<MarkerPipeline>>,
+    pipeline_cache: Res<PipelineCache>,
+    meshes: Res<RenderAssets<RenderMesh>>,
+    render_mesh_instances: Res<RenderMeshInstances>,
+    markers: Query<(Entity, &MainEntity), With<MarkerInstances>>,
+    mut transparent_render_phases: ResMut<ViewSortedRenderPhases<Transparent3d>>,
+    views: Query<(Entity, &ExtractedView, &Msaa)>,
+) {
+    let draw_markers = transparent_3d_draw_functions.read().id::<DrawMarkers>();
+
+    for (view_entity, view, msaa) in &views {
+        let Some(transparent_phase) = transparent_render_phases.get_mut(&view_entity) else {
+            continue;
+        };
+        let view_key = MeshPipelineKey::from_msaa_samples(msaa.samples())
+            | MeshPipelineKey::from_hdr(view.hdr);
+        let rangefinder = view.rangefinder3d();
+        for (entity, main_entity) in &markers {
+            let Some(mesh_instance) = render_mesh_instances.render_mesh_queue_data(*main_entity)
+            else {
+                continue;
+            };
+            let Some(mesh) = meshes.get(mesh_instance.mesh_asset_id) else {
+                continue;
+            };
+            let key =
+                view_key | MeshPipelineKey::from_primitive_topology(mesh.primitive_topology());
+            let pipeline =
+                match pipelines.specialize(&pipeline_cache, &marker_pipeline, key, &mesh.layout) {
+                    Ok(pipeline) => pipeline,
+                    Err(err) => {
+                        error!("促动器标记管线特化失败: {}", err);
+                        continue;
+                    }
+                };
+            transparent_phase.add(Transparent3d {
+                entity: (entity, *main_entity),
+                pipeline,
+                draw_function: draw_markers,
+                distance: rangefinder.distance_translation(&mesh_instance.translation),
+                batch_range: 0..1,
+                extra_index: PhaseItemExtraIndex::NONE,
+            });
+        }
+    }
+}
+
+/// 渲染世界中的实例顶点缓冲
+#[derive(Component)]
+struct MarkerInstanceBuffer {
+    buffer: Buffer,
+    length: usize,
+}
+
+fn prepare_marker_buffers(
+    mut commands: Commands,
+    markers: Query<(Entity, &MarkerInstances)>,
+    render_device: Res<RenderDevice>,
+) {
+    for (entity, instances) in &markers {
+        let contents = instances
+            .iter()
+            .flat_map(|instance| instance.to_array())
+            .flat_map(f32::to_ne_bytes)
+            .collect::<Vec<u8>>();
+        let buffer = render_device.create_buffer_with_data(&BufferInitDescriptor {
+            label: Some("actuator marker instance buffer"),
+            contents: &contents,
+            usage: BufferUsages::VERTEX | BufferUsages::COPY_DST,
+        });
+        commands.entity(entity).insert(MarkerInstanceBuffer {
+            buffer,
+            length: instances.len(),
+        });
+    }
+}
+
+#[derive(Resource)]
+struct MarkerPipeline {
+    shader: Handle<Shader>,
+    mesh_pipeline: MeshPipeline,
+}
+
+impl FromWorld for MarkerPipeline {
+    fn from_world(world: &mut World) -> Self {
+        MarkerPipeline {
+            shader: world.load_asset(SHADER_ASSET_PATH),
+            mesh_pipeline: world.resource::<MeshPipeline>().clone(),
+        }
+    }
+}
+
+impl SpecializedMeshPipeline for MarkerPipeline {
+    type Key = MeshPipelineKey;
+
+    fn specialize(
+        &self,
+        key: Self::Key,
+        layout: &MeshVertexBufferLayoutRef,
+    ) -> Result<RenderPipelineDescriptor, SpecializedMeshPipelineError> {
+        let mut descriptor = self.mesh_pipeline.specialize(key, layout)?;
+        descriptor.label = Some("actuator_marker_pipeline".into());
+        descriptor.vertex.shader = self.shader.clone();
+        // 位置 0-2 是小球网格的顶点坐标、法线与 UV
+        descriptor.vertex.buffers.push(VertexBufferLayout {
+            array_stride: MarkerInstance::SIZE,
+            step_mode: VertexStepMode::Instance,
+            attributes: vec![
+                VertexAttribute {
+                    format: VertexFormat::Float32x4,
+                    offset: 0,
+                    shader_location: 3,
+                },
+                VertexAttribute {
+                    format: VertexFormat::Float32x4,
+                    offset: VertexFormat::Float32x4.size(),
+                    shader_location: 4,
+                },
+            ],
+        });
+        if let Some(fragment) = descriptor.fragment.as_mut() {
+            fragment.shader = self.shader.clone();
+        }
+        Ok(descriptor)
+    }
+}
+
+type DrawMarkers = (
+    SetItemPipeline,
+    SetMeshViewBindGroup<0>,
+    SetMeshBindGroup<1>,
+    DrawMeshInstanced,
+);
+
+/// 以实例缓冲的长度作为实例数绘制小球网格
+struct DrawMeshInstanced;
+
+impl<P: PhaseItem> RenderCommand<P> for DrawMeshInstanced {
+    type Param = (
+        SRes<RenderAssets<RenderMesh>>,
+        SRes<RenderMeshInstances>,
+        SRes<MeshAllocator>,
+    );
+    type ViewQuery = ();
+    type ItemQuery = Read<MarkerInstanceBuffer>;
+
+    #[inline]
+    fn render<'w>(
+        item: &P,
+        _view: (),
+        instance_buffer: Option<&'w MarkerInstanceBuffer>,
+        (meshes, render_mesh_instances, mesh_allocator): SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        let mesh_allocator = mesh_allocator.into_inner();
+
+        let Some(mesh_instance) = render_mesh_instances.render_mesh_queue_data(item.main_entity())
+        else {
+            return RenderCommandResult::Skip;
+        };
+        let Some(gpu_mesh) = meshes.into_inner().get(mesh_instance.mesh_asset_id) else {
+            return RenderCommandResult::Skip;
+        };
+        let Some(instance_buffer) = instance_buffer else {
+            return RenderCommandResult::Skip;
+        };
+        let Some(vertex_buffer_slice) =
+            mesh_allocator.mesh_vertex_slice(&mesh_instance.mesh_asset_id)
+        else {
+            return RenderCommandResult::Skip;
+        };
+
+        pass.set_vertex_buffer(0, vertex_buffer_slice.buffer.slice(..));
+        pass.set_vertex_buffer(1, instance_buffer.buffer.slice(..));
+
+        let instances = 0..instance_buffer.length as u32;
+        match &gpu_mesh.buffer_info {
+            RenderMeshBufferInfo::Indexed {
+                index_format,
+                count,
+            } => {
+                let Some(index_buffer_slice) =
+                    mesh_allocator.mesh_index_slice(&mesh_instance.mesh_asset_id)
+                else {
+                    return RenderCommandResult::Skip;
+                };
+                pass.set_index_buffer(index_buffer_slice.buffer.slice(..), 0, *index_format);
+                pass.draw_indexed(
+                    index_buffer_slice.range.start..(index_buffer_slice.range.start + count),
+                    vertex_buffer_slice.range.start as i32,
+                    instances,
+                );
+            }
+            RenderMeshBufferInfo::NonIndexed => {
+                pass.draw(vertex_buffer_slice.range, instances);
+            }
+        }
+        RenderCommandResult::Success
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_source::fault::Fault;
+    use crate::geometry::ReflectorGeometry;
+
+    fn topology() -> ActuatorTopology {
+        ActuatorTopology::from_geometry(&ReflectorGeometry::default())
+    }
+
+    #[test]
+    fn hidden_markers_keep_faults_visible() {
+        let topology = topology();
+        let mut faults = ActuatorFaults::default();
+        faults.insert(5, Fault::Dead);
+        let instances = marker_instances(&topology, &[], None, &faults, &MarkerRender::Disable);
+        assert_eq!(instances.len(), topology.actuator_count());
+        assert_eq!(instances[5].scale, MARKER_RADIUS * FAULT_MARKER_SCALE);
+        assert!(instances
+            .iter()
+            .enumerate()
+            .all(|(id, instance)| id == 5 || instance.scale == 0.0));
+    }
+
+    #[test]
+    fn displacement_moves_markers_along_normal() {
+        let topology = topology();
+        let faults = ActuatorFaults::default();
+        let heights = vec![0.5; topology.actuator_count()];
+        let flat = marker_instances(&topology, &heights, None, &faults, &MarkerRender::Enable);
+        let moved = marker_instances(
+            &topology,
+            &heights,
+            Some(2.0),
+            &faults,
+            &MarkerRender::Enable,
+        );
+        for (id, (flat, moved)) in flat.iter().zip(&moved).enumerate() {
+            assert_eq!(flat.position, topology.positions()[id]);
+            let expected = flat.position + topology.normals()[id].normalize() * 1.0;
+            assert!((moved.position - expected).length() < 1e-5, "促动器 {}", id);
+        }
+    }
+}
@@ -0,0 +1,631 @@
+//! 天线座
+//!
+//! 反射面安装在方位-俯仰座架上。方位角从北（+y）经东（+x）计量，俯仰角从地平面计量，
+//! 俯仰 90° 时反射面轴线指向天顶（+z）。两轴都绕场景原点转动，原点位于反射面顶点上方，
+//! 与相机的观察中心重合。
+//!
+//! 目标指向可以由滑块、方向键给出，也可以由脚本轨迹给出：对某一赤经赤纬源做恒星跟踪，
+//! 或在当前指向附近做栅格扫描。实际指向以有限的速度向目标转动，目标超出限位时被截断。
+//!
+//! 脚本轨迹按 [`MountTrack::time`] 计时，它与 [`SimulationClock`] 同速前进、同时暂停，但不随
+//! 时间轴循环或跳转回绕，否则每次回绕时天空会倒退，天线座随之反向转动。
+//!
+//! [`SimulationClock`]: crate::timeline::SimulationClock
+
+use crate::gravity::Elevation;
+use crate::{cli::CliArgs, MockingSpeed, MockingState};
+use bevy::{color::palettes::css::*, prelude::*};
+use std::fmt::{self, Formatter};
+
+/// 反射面顶点在座架坐标系中的位置
+pub const DISH_VERTEX: Vec3 = Vec3::new(0.0, 0.0, -3.0);
+/// 默认台址纬度（度）
+pub const DEFAULT_LATITUDE: f32 = 43.6;
+/// 恒星时相对太阳时的速率（度/秒）
+const SIDEREAL_RATE: f64 = 360.985_647_366_29 / 86400.0;
+/// 方向键每秒改变的目标角度（度）
+const KEYBOARD_RATE: f32 = 10.0;
+
+pub struct MountPlugin;
+
+impl Plugin for MountPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<MountMode>()
+            .init_resource::<MountLimits>()
+            .init_resource::<MountTrack>()
+            .insert_resource(Mount::ZENITH)
+            .insert_resource(MountTarget(Mount::ZENITH))
+            .add_systems(OnEnter(MountMode::Raster), start_raster)
+            .add_systems(
+                Update,
+                (
+                    advance_track_time.run_if(in_state(MockingState::Start)),
+                    (
+                        steer_with_keyboard.run_if(in_state(MountMode::Manual)),
+                        track_source.run_if(in_state(MountMode::Sidereal)),
+                        scan_raster.run_if(in_state(MountMode::Raster)),
+                    ),
+                    slew,
+                    apply_mount_pose,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                update_mount_ui
+                    .run_if(resource_changed::<Mount>.or(resource_changed::<MountTarget>)),
+            );
+    }
+}
+
+/// 目标指向的来源
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash, States)]
+pub enum MountMode {
+    #[default]
+    Manual,
+    // 对赤经赤纬源做恒星跟踪
+    Sidereal,
+    // 在进入该模式时的指向附近做栅格扫描
+    Raster,
+}
+
+impl fmt::Display for MountMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MountMode::Manual => write!(f, "手动"),
+            MountMode::Sidereal => write!(f, "恒星跟踪"),
+            MountMode::Raster => write!(f, "栅格扫描"),
+        }
+    }
+}
+
+/// 方位与俯仰（度）
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct Mount {
+    pub azimuth: f32,
+    pub elevation: f32,
+}
+
+impl Mount {
+    pub const ZENITH: Mount = Mount {
+        azimuth: 0.0,
+        elevation: 90.0,
+    };
+
+    /// 把反射面从指向天顶转到当前指向的旋转
+    pub fn rotation(&self) -> Quat {
+        Quat::from_rotation_z(-self.azimuth.to_radians())
+            * Quat::from_rotation_x(-(90.0 - self.elevation).to_radians())
+    }
+}
+
+/// 目标指向，已按限位截断
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Deref, DerefMut)]
+pub struct MountTarget(pub Mount);
+
+/// 限位与最大转速
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct MountLimits {
+    /// 方位行程，超过一圈以便跨越正北时无需回绕
+    pub azimuth: (f32, f32),
+    pub elevation: (f32, f32),
+    /// 最大转速（度/秒）
+    pub azimuth_rate: f32,
+    pub elevation_rate: f32,
+}
+
+impl Default for MountLimits {
+    fn default() -> Self {
+        MountLimits {
+            azimuth: (-270.0, 270.0),
+            elevation: (5.0, 90.0),
+            azimuth_rate: 1.0,
+            elevation_rate: 0.5,
+        }
+    }
+}
+
+impl MountLimits {
+    /// 截断到限位内；方位取行程内与 `current` 最近的等价角，行程内没有等价角时取角度上
+    /// 较近的限位
+    pub fn clamp(&self, target: Mount, current: &Mount) -> Mount {
+        let (min, max) = self.azimuth;
+        let first = ((min - target.azimuth) / 360.0).ceil();
+        let last = ((max - target.azimuth) / 360.0).floor();
+        let azimuth = if first <= last {
+            let turns = ((current.azimuth - target.azimuth) / 360.0)
+                .round()
+                .clamp(first, last);
+            target.azimuth + turns * 360.0
+        } else {
+            let gap = |limit: f32| {
+                let d = (target.azimuth - limit).rem_euclid(360.0);
+                d.min(360.0 - d)
+            };
+            if gap(min) <= gap(max) {
+                min
+            } else {
+                max
+            }
+        };
+        Mount {
+            azimuth,
+            elevation: target.elevation.clamp(self.elevation.0, self.elevation.1),
+        }
+    }
+}
+
+/// 脚本轨迹的参数
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct MountTrack {
+    /// 跟踪源的赤经、赤纬（度）
+    pub right_ascension: f32,
+    pub declination: f32,
+    /// 台址纬度（度）
+    pub latitude: f32,
+    /// 轨迹时刻为零时的地方恒星时（度）
+    pub sidereal_time: f32,
+    /// 轨迹时刻（秒），单调递增
+    pub time: f64,
+    pub raster: RasterScan,
+}
+
+impl FromWorld for MountTrack {
+    fn from_world(world: &mut World) -> Self {
+        let cli = world.get_resource::<CliArgs>();
+        // 默认为蟹状星云，仿真开始时位于中天前一小时
+        let (right_ascension, declination) =
+            cli.and_then(|cli| cli.source).unwrap_or((83.63, 22.01));
+        MountTrack {
+            right_ascension,
+            declination,
+            latitude: cli.and_then(|cli| cli.latitude).unwrap_or(DEFAULT_LATITUDE),
+            sidereal_time: right_ascension - 15.0,
+            time: 0.0,
+            raster: RasterScan::default(),
+        }
+    }
+}
+
+impl MountTrack {
+    /// 轨迹时刻 `t`（秒）时跟踪源的方位与俯仰
+    pub fn source_position(&self, t: f64) -> Mount {
+        let lst = self.sidereal_time as f64 + SIDEREAL_RATE * t;
+        let hour_angle = (lst - self.right_ascension as f64).to_radians();
+        let (sin_dec, cos_dec) = (self.declination as f64).to_radians().sin_cos();
+        let (sin_lat, cos_lat) = (self.latitude as f64).to_radians().sin_cos();
+        let sin_el = sin_lat * sin_dec + cos_lat * cos_dec * hour_angle.cos();
+        let azimuth = (-cos_dec * hour_angle.sin())
+            .atan2(sin_dec * cos_lat - cos_dec * hour_angle.cos() * sin_lat);
+        Mount {
+            azimuth: azimuth.to_degrees().rem_euclid(360.0) as f32,
+            elevation: sin_el.clamp(-1.0, 1.0).asin().to_degrees() as f32,
+        }
+    }
+}
+
+/// 沿方位往返的栅格扫描，方位偏移按天球上的距离计
+#[derive(Debug, Clone, Copy)]
+pub struct RasterScan {
+    pub width: f32,
+    pub height: f32,
+    pub rows: u32,
+    /// 扫描速度（度/秒）
+    pub speed: f32,
+    pub center: Mount,
+    /// 开始扫描的轨迹时刻
+    pub start: f64,
+}
+
+impl Default for RasterScan {
+    fn default() -> Self {
+        RasterScan {
+            width: 4.0,
+            height: 2.0,
+            rows: 9,
+            speed: 0.2,
+            center: Mount::ZENITH,
+            start: 0.0,
+        }
+    }
+}
+
+impl RasterScan {
+    /// 开始扫描后 `t` 秒的指向，扫完所有行后从头开始
+    pub fn position(&self, t: f64) -> Mount {
+        let row_time = (self.width / self.speed) as f64;
+        let rows = self.rows.max(1);
+        let row = (t / row_time).floor().rem_euclid(rows as f64) as u32;
+        let along = (t.rem_euclid(row_time) / row_time) as f32;
+        let along = if row % 2 == 0 { along } else { 1.0 - along };
+        let cross = if rows > 1 {
+            row as f32 / (rows - 1) as f32 - 0.5
+        } else {
+            0.0
+        };
+        let elevation = self.center.elevation + cross * self.height;
+        let cos_el = elevation.to_radians().cos().max(0.05);
+        Mount {
+            azimuth: self.center.azimuth + (along - 0.5) * self.width / cos_el,
+            elevation,
+        }
+    }
+}
+
+/// 随天线座转动的物体，`offset` 为座架原点在场景中的位置
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct Mounted {
+    pub offset: Vec3,
+}
+
+fn steer_with_keyboard(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    limits: Res<MountLimits>,
+    mount: Res<Mount>,
+    mut target: ResMut<MountTarget>,
+) {
+    let axis = |negative, positive| {
+        keys.pressed(positive) as i32 as f32 - keys.pressed(negative) as i32 as f32
+    };
+    let step = KEYBOARD_RATE * time.delta_secs();
+    let azimuth = axis(KeyCode::ArrowLeft, KeyCode::ArrowRight) * step;
+    let elevation = axis(KeyCode::ArrowDown, KeyCode::ArrowUp) * step;
+    if azimuth == 0.0 && elevation == 0.0 {
+        return;
+    }
+    let next = Mount {
+        azimuth: target.azimuth + azimuth,
+        elevation: target.elevation + elevation,
+    };
+    target.set_if_neq(MountTarget(limits.clamp(next, &mount)));
+}
+
+// 与 SimulationClock 的 advance_clock 同速前进
+fn advance_track_time(mut track: ResMut<MountTrack>, speed: Res<MockingSpeed>, time: Res<Time>) {
+    track.time += time.delta_secs_f64() * speed.0 as f64;
+}
+
+fn track_source(
+    track: Res<MountTrack>,
+    limits: Res<MountLimits>,
+    mount: Res<Mount>,
+    mut target: ResMut<MountTarget>,
+) {
+    let position = track.source_position(track.time);
+    target.set_if_neq(MountTarget(limits.clamp(position, &mount)));
+}
+
+fn start_raster(mount: Res<Mount>, mut track: ResMut<MountTrack>) {
+    track.raster.center = *mount;
+    track.raster.start = track.time;
+}
+
+fn scan_raster(
+    track: Res<MountTrack>,
+    limits: Res<MountLimits>,
+    mount: Res<Mount>,
+    mut target: ResMut<MountTarget>,
+) {
+    let position = track.raster.position(track.time - track.raster.start);
+    target.set_if_neq(MountTarget(limits.clamp(position, &mount)));
+}
+
+// 两轴各自以不超过最大转速的速度转向目标
+fn slew(
+    time: Res<Time>,
+    limits: Res<MountLimits>,
+    target: Res<MountTarget>,
+    mut mount: ResMut<Mount>,
+    mut elevation: ResMut<Elevation>,
+) {
+    let dt = time.delta_secs();
+    let step = |from: f32, to: f32, rate: f32| from + (to - from).clamp(-rate * dt, rate * dt);
+    let next = Mount {
+        azimuth: step(mount.azimuth, target.azimuth, limits.azimuth_rate),
+        elevation: step(mount.elevation, target.elevation, limits.elevation_rate),
+    };
+    mount.set_if_neq(next);
+    elevation.set_if_neq(Elevation(mount.elevation));
+}
+
+fn apply_mount_pose(mount: Res<Mount>, mut mounted: Query<(Ref<Mounted>, &mut Transform)>) {
+    let rotation = mount.rotation();
+    for (mounted, mut transform) in mounted.iter_mut() {
+        if mount.is_changed() || mounted.is_changed() {
+            transform.rotation = rotation;
+            transform.translation = mounted.offset + rotation * DISH_VERTEX;
+        }
+    }
+}
+
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+enum MountAxis {
+    Azimuth,
+    Elevation,
+}
+
+#[derive(Component)]
+struct MountSlider(MountAxis);
+
+#[derive(Component)]
+struct MountSliderFill(MountAxis);
+
+#[derive(Component)]
+struct MountSliderTarget(MountAxis);
+
+#[derive(Component)]
+struct MountText;
+
+/// 方位、俯仰滑块与指向读数；滑块填充为实际指向，细线为目标指向
+pub fn spawn_mount_sliders(parent: &mut ChildBuilder<'_>, text_font: TextFont) {
+    parent.spawn((
+        Text::default(),
+        MountText,
+        text_font.clone(),
+        TextColor(BLACK.into()),
+    ));
+    for axis in [MountAxis::Azimuth, MountAxis::Elevation] {
+        parent.spawn((
+            Text::new(match axis {
+                MountAxis::Azimuth => "方位",
+                MountAxis::Elevation => "俯仰",
+            }),
+            text_font.clone(),
+            TextColor(BLACK.into()),
+        ));
+        parent
+            .spawn((
+                MountSlider(axis),
+                Node {
+                    width: Val::Px(120.0),
+                    height: Val::Px(16.0),
+                    ..default()
+                },
+                BackgroundColor(DIM_GRAY.into()),
+            ))
+            .observe(on_mount_slider_pressed)
+            .observe(on_mount_slider_dragged)
+            .with_children(|p| {
+                p.spawn((
+                    MountSliderFill(axis),
+                    Node {
+                        width: Val::Percent(0.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(ORANGE.into()),
+                ));
+                p.spawn((
+                    MountSliderTarget(axis),
+                    Node {
+                        position_type: PositionType::Absolute,
+                        width: Val::Px(2.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(YELLOW.into()),
+                ));
+            });
+    }
+}
+
+fn on_mount_slider_pressed(
+    trigger: Trigger<Pointer<Down>>,
+    sliders: Query<(&MountSlider, &ComputedNode, &GlobalTransform)>,
+    limits: Res<MountLimits>,
+    target: ResMut<MountTarget>,
+    next_mode: ResMut<NextState<MountMode>>,
+) {
+    set_target_from_pointer(
+        trigger.entity(),
+        trigger.pointer_location.position,
+        sliders,
+        limits,
+        target,
+        next_mode,
+    );
+}
+
+fn on_mount_slider_dragged(
+    trigger: Trigger<Pointer<Drag>>,
+    sliders: Query<(&MountSlider, &ComputedNode, &GlobalTransform)>,
+    limits: Res<MountLimits>,
+    target: ResMut<MountTarget>,
+    next_mode: ResMut<NextState<MountMode>>,
+) {
+    set_target_from_pointer(
+        trigger.entity(),
+        trigger.pointer_location.position,
+        sliders,
+        limits,
+        target,
+        next_mode,
+    );
+}
+
+// 指针位置为逻辑像素，节点的位置与大小为物理像素；拖动滑块即切换为手动指向
+fn set_target_from_pointer(
+    slider: Entity,
+    pointer: Vec2,
+    sliders: Query<(&MountSlider, &ComputedNode, &GlobalTransform)>,
+    limits: Res<MountLimits>,
+    mut target: ResMut<MountTarget>,
+    mut next_mode: ResMut<NextState<MountMode>>,
+) {
+    let Ok((slider, node, transform)) = sliders.get(slider) else {
+        return;
+    };
+    let scale = node.inverse_scale_factor();
+    let width = node.size().x * scale;
+    if width <= 0.0 {
+        return;
+    }
+    let left = transform.translation().x * scale - width / 2.0;
+    let fraction = ((pointer.x - left) / width).clamp(0.0, 1.0);
+    let (min, max) = axis_range(&limits, slider.0);
+    let value = min + fraction * (max - min);
+    match slider.0 {
+        MountAxis::Azimuth => target.azimuth = value,
+        MountAxis::Elevation => target.elevation = value,
+    }
+    next_mode.set(MountMode::Manual);
+}
+
+fn axis_range(limits: &MountLimits, axis: MountAxis) -> (f32, f32) {
+    match axis {
+        MountAxis::Azimuth => limits.azimuth,
+        MountAxis::Elevation => limits.elevation,
+    }
+}
+
+fn update_mount_ui(
+    mount: Res<Mount>,
+    target: Res<MountTarget>,
+    limits: Res<MountLimits>,
+    mut text: Query<&mut Text, With<MountText>>,
+    mut fills: Query<(&MountSliderFill, &mut Node), Without<MountSliderTarget>>,
+    mut targets: Query<(&MountSliderTarget, &mut Node), Without<MountSliderFill>>,
+) {
+    let fraction = |axis: MountAxis, m: &Mount| {
+        let (min, max) = axis_range(&limits, axis);
+        let value = match axis {
+            MountAxis::Azimuth => m.azimuth,
+            MountAxis::Elevation => m.elevation,
+        };
+        ((value - min) / (max - min)).clamp(0.0, 1.0) * 100.0
+    };
+    for mut text in text.iter_mut() {
+        text.0 = format!(
+            "指向 {:.2}° / {:.2}° → {:.2}° / {:.2}°",
+            mount.azimuth, mount.elevation, target.azimuth, target.elevation
+        );
+    }
+    for (fill, mut node) in fills.iter_mut() {
+        node.width = Val::Percent(fraction(fill.0, &mount));
+    }
+    for (marker, mut node) in targets.iter_mut() {
+        node.left = Val::Percent(fraction(marker.0, &target));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn track(declination: f32, latitude: f32) -> MountTrack {
+        MountTrack {
+            right_ascension: 83.63,
+            declination,
+            latitude,
+            // 轨迹时刻为零时源正好中天
+            sidereal_time: 83.63,
+            time: 0.0,
+            raster: RasterScan::default(),
+        }
+    }
+
+    fn assert_close(actual: f32, expected: f32, tolerance: f32) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn source_transits_on_meridian() {
+        // 赤纬低于纬度的源在正南中天
+        let position = track(22.01, 43.6).source_position(0.0);
+        assert_close(position.elevation, 90.0 - (43.6 - 22.01), 1e-3);
+        assert_close(position.azimuth, 180.0, 1e-3);
+
+        // 赤纬高于纬度的源在正北中天
+        let position = track(60.0, 43.6).source_position(0.0);
+        assert_close(position.elevation, 90.0 - (60.0 - 43.6), 1e-3);
+        let azimuth = if position.azimuth > 180.0 {
+            position.azimuth - 360.0
+        } else {
+            position.azimuth
+        };
+        assert_close(azimuth, 0.0, 1e-3);
+    }
+
+    #[test]
+    fn source_moves_east_to_west() {
+        let track = track(22.01, 43.6);
+        // 时角变化 15° 所需的时间
+        let hour = 15.0 / SIDEREAL_RATE;
+        let before = track.source_position(-hour);
+        let after = track.source_position(hour);
+        // 中天前后对称：高度相同，方位关于正南对称，中天前在东侧
+        assert_close(before.elevation, after.elevation, 1e-3);
+        assert_close(before.azimuth + after.azimuth, 360.0, 1e-3);
+        assert!(before.azimuth < 180.0 && after.azimuth > 180.0);
+        assert!(before.elevation < track.source_position(0.0).elevation);
+    }
+
+    #[test]
+    fn clamp_picks_nearest_azimuth_turn() {
+        let limits = MountLimits::default();
+        let at = |azimuth| Mount {
+            azimuth,
+            elevation: 45.0,
+        };
+        // 跨过正北时取与当前方位最近的等价角
+        assert_close(limits.clamp(at(10.0), &at(200.0)).azimuth, 10.0, 1e-4);
+        // 最近的等价角 370° 超出方位行程时，改用行程内的等价角
+        assert_close(limits.clamp(at(10.0), &at(350.0)).azimuth, 10.0, 1e-4);
+        // 行程内没有等价角时截断
+        let narrow = MountLimits {
+            azimuth: (0.0, 180.0),
+            ..limits
+        };
+        assert_close(narrow.clamp(at(200.0), &at(90.0)).azimuth, 180.0, 1e-4);
+        assert_close(narrow.clamp(at(-20.0), &at(90.0)).azimuth, 0.0, 1e-4);
+        assert_close(limits.clamp(at(-100.0), &at(250.0)).azimuth, 260.0, 1e-4);
+        assert_close(limits.clamp(at(180.0), &at(-170.0)).azimuth, -180.0, 1e-4);
+        assert_close(limits.clamp(at(10.0), &at(-5.0)).azimuth, 10.0, 1e-4);
+        // 俯仰截断到限位内
+        let low = Mount {
+            azimuth: 0.0,
+            elevation: -10.0,
+        };
+        assert_close(limits.clamp(low, &low).elevation, 5.0, 1e-4);
+    }
+
+    #[test]
+    fn raster_stays_within_scan_area() {
+        let raster = RasterScan {
+            center: Mount {
+                azimuth: 100.0,
+                elevation: 60.0,
+            },
+            ..default()
+        };
+        let row_time = (raster.width / raster.speed) as f64;
+        let half_width = |elevation: f32| 0.5 * raster.width / elevation.to_radians().cos();
+
+        // 第一行方位递增，第二行反向
+        let start = raster.position(0.0);
+        assert_close(start.elevation, 60.0 - 0.5 * raster.height, 1e-3);
+        assert_close(start.azimuth, 100.0 - half_width(start.elevation), 1e-3);
+        assert_close(raster.position(0.5 * row_time).azimuth, 100.0, 1e-3);
+        let second = raster.position(row_time);
+        assert!(second.elevation > start.elevation);
+        assert_close(second.azimuth, 100.0 + half_width(second.elevation), 1e-2);
+
+        // 扫完所有行后从头开始
+        let cycle = row_time * raster.rows as f64;
+        assert_close(raster.position(cycle).azimuth, start.azimuth, 1e-3);
+        assert_close(raster.position(cycle).elevation, start.elevation, 1e-3);
+
+        // 任意时刻（包括负值）都不超出扫描范围
+        for i in -200..200 {
+            let position = raster.position(i as f64 * 3.7);
+            assert!((position.azimuth - 100.0).abs() <= half_width(position.elevation) + 1e-3);
+            assert!((position.elevation - 60.0).abs() <= 0.5 * raster.height + 1e-3);
+        }
+    }
+}
@@ -1,10817 +0,0 @@
-#![allow(non_upper_case_globals)]
-#![allow(dead_code)]
-pub const vertices: [[f32; 3]; 9216] = [
-    [0.9, 0.0, 0.050625],
-    [0.8827067523629074, 0.17558128981451543, 0.050625],
-    [1.2061646793007326, 0.23992107180943467, 0.09452470964317669],
-    [1.2297948423581988, 0.0, 0.09452470964317669],
-    [0.8827067523629074, 0.17558128981451543, 0.050625],
-    [0.831491579260158, 0.3444150891285808, 0.050625],
-    [1.1361822840426843, 0.47062211137852, 0.09452470964317669],
-    [1.2061646793007326, 0.23992107180943467, 0.09452470964317669],
-    [0.831491579260158, 0.3444150891285808, 0.050625],
-    [0.7483226510722907, 0.500013209717642, 0.050625],
-    [1.0225370407872414, 0.6832374071352494, 0.09452470964317669],
-    [1.1361822840426843, 0.47062211137852, 0.09452470964317669],
-    [0.7483226510722907, 0.500013209717642, 0.050625],
-    [0.6363961030678928, 0.6363961030678928, 0.050625],
-    [0.8695962724997236, 0.8695962724997236, 0.09452470964317669],
-    [1.0225370407872414, 0.6832374071352494, 0.09452470964317669],
-    [0.6363961030678928, 0.6363961030678928, 0.050625],
-    [0.5000132097176421, 0.7483226510722907, 0.050625],
-    [0.6832374071352496, 1.0225370407872414, 0.09452470964317669],
-    [0.8695962724997236, 0.8695962724997236, 0.09452470964317669],
-    [0.5000132097176421, 0.7483226510722907, 0.050625],
-    [0.3444150891285809, 0.831491579260158, 0.050625],
-    [0.4706221113785201, 1.1361822840426843, 0.09452470964317669],
-    [0.6832374071352496, 1.0225370407872414, 0.09452470964317669],
-    [0.3444150891285809, 0.831491579260158, 0.050625],
-    [0.1755812898145155, 0.8827067523629074, 0.050625],
-    [0.23992107180943478, 1.2061646793007326, 0.09452470964317669],
-    [0.4706221113785201, 1.1361822840426843, 0.09452470964317669],
-    [0.1755812898145155, 0.8827067523629074, 0.050625],
-    [5.5109105961630896e-17, 0.9, 0.050625],
-    [
-        7.53032158650946e-17,
-        1.2297948423581988,
-        0.09452470964317669,
-    ],
-    [0.23992107180943478, 1.2061646793007326, 0.09452470964317669],
-    [5.5109105961630896e-17, 0.9, 0.050625],
-    [-0.17558128981451537, 0.8827067523629074, 0.050625],
-    [
-        -0.23992107180943462,
-        1.2061646793007326,
-        0.09452470964317669,
-    ],
-    [
-        7.53032158650946e-17,
-        1.2297948423581988,
-        0.09452470964317669,
-    ],
-    [-0.17558128981451537, 0.8827067523629074, 0.050625],
-    [-0.34441508912858076, 0.831491579260158, 0.050625],
-    [
-        -0.47062211137851995,
-        1.1361822840426843,
-        0.09452470964317669,
-    ],
-    [
-        -0.23992107180943462,
-        1.2061646793007326,
-        0.09452470964317669,
-    ],
-    [-0.34441508912858076, 0.831491579260158, 0.050625],
-    [-0.5000132097176417, 0.7483226510722908, 0.050625],
-    [-0.6832374071352492, 1.0225370407872414, 0.09452470964317669],
-    [
-        -0.47062211137851995,
-        1.1361822840426843,
-        0.09452470964317669,
-    ],
-    [-0.5000132097176417, 0.7483226510722908, 0.050625],
-    [-0.6363961030678927, 0.6363961030678928, 0.050625],
-    [-0.8695962724997235, 0.8695962724997236, 0.09452470964317669],
-    [-0.6832374071352492, 1.0225370407872414, 0.09452470964317669],
-    [-0.6363961030678927, 0.6363961030678928, 0.050625],
-    [-0.7483226510722908, 0.500013209717642, 0.050625],
-    [-1.0225370407872414, 0.6832374071352494, 0.09452470964317669],
-    [-0.8695962724997235, 0.8695962724997236, 0.09452470964317669],
-    [-0.7483226510722908, 0.500013209717642, 0.050625],
-    [-0.831491579260158, 0.34441508912858093, 0.050625],
-    [
-        -1.1361822840426843,
-        0.47062211137852017,
-        0.09452470964317669,
-    ],
-    [-1.0225370407872414, 0.6832374071352494, 0.09452470964317669],
-    [-0.831491579260158, 0.34441508912858093, 0.050625],
-    [-0.8827067523629074, 0.17558128981451576, 0.050625],
-    [
-        -1.2061646793007326,
-        0.23992107180943512,
-        0.09452470964317669,
-    ],
-    [
-        -1.1361822840426843,
-        0.47062211137852017,
-        0.09452470964317669,
-    ],
-    [-0.8827067523629074, 0.17558128981451576, 0.050625],
-    [-0.9, 1.1021821192326179e-16, 0.050625],
-    [
-        -1.2297948423581988,
-        1.506064317301892e-16,
-        0.09452470964317669,
-    ],
-    [
-        -1.2061646793007326,
-        0.23992107180943512,
-        0.09452470964317669,
-    ],
-    [-0.9, 1.1021821192326179e-16, 0.050625],
-    [-0.8827067523629074, -0.17558128981451554, 0.050625],
-    [
-        -1.2061646793007326,
-        -0.2399210718094348,
-        0.09452470964317669,
-    ],
-    [
-        -1.2297948423581988,
-        1.506064317301892e-16,
-        0.09452470964317669,
-    ],
-    [-0.8827067523629074, -0.17558128981451554, 0.050625],
-    [-0.8314915792601582, -0.3444150891285807, 0.050625],
-    [
-        -1.1361822840426845,
-        -0.4706221113785199,
-        0.09452470964317669,
-    ],
-    [
-        -1.2061646793007326,
-        -0.2399210718094348,
-        0.09452470964317669,
-    ],
-    [-0.8314915792601582, -0.3444150891285807, 0.050625],
-    [-0.7483226510722909, -0.5000132097176417, 0.050625],
-    [
-        -1.0225370407872416,
-        -0.6832374071352492,
-        0.09452470964317669,
-    ],
-    [
-        -1.1361822840426845,
-        -0.4706221113785199,
-        0.09452470964317669,
-    ],
-    [-0.7483226510722909, -0.5000132097176417, 0.050625],
-    [-0.636396103067893, -0.6363961030678927, 0.050625],
-    [
-        -0.8695962724997238,
-        -0.8695962724997235,
-        0.09452470964317669,
-    ],
-    [
-        -1.0225370407872416,
-        -0.6832374071352492,
-        0.09452470964317669,
-    ],
-    [-0.636396103067893, -0.6363961030678927, 0.050625],
-    [-0.500013209717642, -0.7483226510722907, 0.050625],
-    [
-        -0.6832374071352494,
-        -1.0225370407872414,
-        0.09452470964317669,
-    ],
-    [
-        -0.8695962724997238,
-        -0.8695962724997235,
-        0.09452470964317669,
-    ],
-    [-0.500013209717642, -0.7483226510722907, 0.050625],
-    [-0.3444150891285813, -0.8314915792601579, 0.050625],
-    [-0.4706221113785207, -1.136182284042684, 0.09452470964317669],
-    [
-        -0.6832374071352494,
-        -1.0225370407872414,
-        0.09452470964317669,
-    ],
-    [-0.3444150891285813, -0.8314915792601579, 0.050625],
-    [-0.1755812898145158, -0.8827067523629073, 0.050625],
-    [
-        -0.2399210718094352,
-        -1.2061646793007323,
-        0.09452470964317669,
-    ],
-    [-0.4706221113785207, -1.136182284042684, 0.09452470964317669],
-    [-0.1755812898145158, -0.8827067523629073, 0.050625],
-    [-1.6532731788489269e-16, -0.9, 0.050625],
-    [
-        -2.2590964759528377e-16,
-        -1.2297948423581988,
-        0.09452470964317669,
-    ],
-    [
-        -0.2399210718094352,
-        -1.2061646793007323,
-        0.09452470964317669,
-    ],
-    [-1.6532731788489269e-16, -0.9, 0.050625],
-    [0.17558128981451548, -0.8827067523629074, 0.050625],
-    [
-        0.23992107180943475,
-        -1.2061646793007326,
-        0.09452470964317669,
-    ],
-    [
-        -2.2590964759528377e-16,
-        -1.2297948423581988,
-        0.09452470964317669,
-    ],
-    [0.17558128981451548, -0.8827067523629074, 0.050625],
-    [0.344415089128581, -0.8314915792601579, 0.050625],
-    [0.4706221113785203, -1.136182284042684, 0.09452470964317669],
-    [
-        0.23992107180943475,
-        -1.2061646793007326,
-        0.09452470964317669,
-    ],
-    [0.344415089128581, -0.8314915792601579, 0.050625],
-    [0.5000132097176416, -0.7483226510722909, 0.050625],
-    [0.683237407135249, -1.0225370407872416, 0.09452470964317669],
-    [0.4706221113785203, -1.136182284042684, 0.09452470964317669],
-    [0.5000132097176416, -0.7483226510722909, 0.050625],
-    [0.6363961030678926, -0.636396103067893, 0.050625],
-    [0.8695962724997234, -0.8695962724997238, 0.09452470964317669],
-    [0.683237407135249, -1.0225370407872416, 0.09452470964317669],
-    [0.6363961030678926, -0.636396103067893, 0.050625],
-    [0.7483226510722907, -0.500013209717642, 0.050625],
-    [1.0225370407872414, -0.6832374071352494, 0.09452470964317669],
-    [0.8695962724997234, -0.8695962724997238, 0.09452470964317669],
-    [0.7483226510722907, -0.500013209717642, 0.050625],
-    [0.8314915792601579, -0.3444150891285814, 0.050625],
-    [1.136182284042684, -0.4706221113785208, 0.09452470964317669],
-    [1.0225370407872414, -0.6832374071352494, 0.09452470964317669],
-    [0.8314915792601579, -0.3444150891285814, 0.050625],
-    [0.8827067523629073, -0.17558128981451585, 0.050625],
-    [
-        1.2061646793007323,
-        -0.23992107180943525,
-        0.09452470964317669,
-    ],
-    [1.136182284042684, -0.4706221113785208, 0.09452470964317669],
-    [0.8827067523629073, -0.17558128981451585, 0.050625],
-    [0.9, -2.2043642384652358e-16, 0.050625],
-    [
-        1.2297948423581988,
-        -3.012128634603784e-16,
-        0.09452470964317669,
-    ],
-    [
-        1.2061646793007323,
-        -0.23992107180943525,
-        0.09452470964317669,
-    ],
-    [1.2297948423581988, 0.0, 0.09452470964317669],
-    [1.2061646793007326, 0.23992107180943467, 0.09452470964317669],
-    [1.5296226062385574, 0.30426085380435386, 0.15201999904211197],
-    [1.5595896847163973, 0.0, 0.15201999904211197],
-    [1.2061646793007326, 0.23992107180943467, 0.09452470964317669],
-    [1.1361822840426843, 0.47062211137852, 0.09452470964317669],
-    [1.44087298882521, 0.5968291336284591, 0.15201999904211197],
-    [1.5296226062385574, 0.30426085380435386, 0.15201999904211197],
-    [1.1361822840426843, 0.47062211137852, 0.09452470964317669],
-    [1.0225370407872414, 0.6832374071352494, 0.09452470964317669],
-    [1.2967514305021917, 0.8664616045528567, 0.15201999904211197],
-    [1.44087298882521, 0.5968291336284591, 0.15201999904211197],
-    [1.0225370407872414, 0.6832374071352494, 0.09452470964317669],
-    [0.8695962724997236, 0.8695962724997236, 0.09452470964317669],
-    [1.1027964419315541, 1.1027964419315541, 0.15201999904211197],
-    [1.2967514305021917, 0.8664616045528567, 0.15201999904211197],
-    [0.8695962724997236, 0.8695962724997236, 0.09452470964317669],
-    [0.6832374071352496, 1.0225370407872414, 0.09452470964317669],
-    [0.8664616045528569, 1.2967514305021917, 0.15201999904211197],
-    [1.1027964419315541, 1.1027964419315541, 0.15201999904211197],
-    [0.6832374071352496, 1.0225370407872414, 0.09452470964317669],
-    [0.4706221113785201, 1.1361822840426843, 0.09452470964317669],
-    [0.5968291336284592, 1.44087298882521, 0.15201999904211197],
-    [0.8664616045528569, 1.2967514305021917, 0.15201999904211197],
-    [0.4706221113785201, 1.1361822840426843, 0.09452470964317669],
-    [0.23992107180943478, 1.2061646793007326, 0.09452470964317669],
-    [0.30426085380435397, 1.5296226062385574, 0.15201999904211197],
-    [0.5968291336284592, 1.44087298882521, 0.15201999904211197],
-    [0.23992107180943478, 1.2061646793007326, 0.09452470964317669],
-    [
-        7.53032158650946e-17,
-        1.2297948423581988,
-        0.09452470964317669,
-    ],
-    [
-        9.549732576855829e-17,
-        1.5595896847163973,
-        0.15201999904211197,
-    ],
-    [0.30426085380435397, 1.5296226062385574, 0.15201999904211197],
-    [
-        7.53032158650946e-17,
-        1.2297948423581988,
-        0.09452470964317669,
-    ],
-    [
-        -0.23992107180943462,
-        1.2061646793007326,
-        0.09452470964317669,
-    ],
-    [-0.3042608538043538, 1.5296226062385574, 0.15201999904211197],
-    [
-        9.549732576855829e-17,
-        1.5595896847163973,
-        0.15201999904211197,
-    ],
-    [
-        -0.23992107180943462,
-        1.2061646793007326,
-        0.09452470964317669,
-    ],
-    [
-        -0.47062211137851995,
-        1.1361822840426843,
-        0.09452470964317669,
-    ],
-    [-0.596829133628459, 1.44087298882521, 0.15201999904211197],
-    [-0.3042608538043538, 1.5296226062385574, 0.15201999904211197],
-    [
-        -0.47062211137851995,
-        1.1361822840426843,
-        0.09452470964317669,
-    ],
-    [-0.6832374071352492, 1.0225370407872414, 0.09452470964317669],
-    [-0.8664616045528564, 1.2967514305021917, 0.15201999904211197],
-    [-0.596829133628459, 1.44087298882521, 0.15201999904211197],
-    [-0.6832374071352492, 1.0225370407872414, 0.09452470964317669],
-    [-0.8695962724997235, 0.8695962724997236, 0.09452470964317669],
-    [-1.1027964419315541, 1.1027964419315541, 0.15201999904211197],
-    [-0.8664616045528564, 1.2967514305021917, 0.15201999904211197],
-    [-0.8695962724997235, 0.8695962724997236, 0.09452470964317669],
-    [-1.0225370407872414, 0.6832374071352494, 0.09452470964317669],
-    [-1.2967514305021917, 0.8664616045528567, 0.15201999904211197],
-    [-1.1027964419315541, 1.1027964419315541, 0.15201999904211197],
-    [-1.0225370407872414, 0.6832374071352494, 0.09452470964317669],
-    [
-        -1.1361822840426843,
-        0.47062211137852017,
-        0.09452470964317669,
-    ],
-    [-1.44087298882521, 0.5968291336284592, 0.15201999904211197],
-    [-1.2967514305021917, 0.8664616045528567, 0.15201999904211197],
-    [
-        -1.1361822840426843,
-        0.47062211137852017,
-        0.09452470964317669,
-    ],
-    [
-        -1.2061646793007326,
-        0.23992107180943512,
-        0.09452470964317669,
-    ],
-    [-1.5296226062385574, 0.3042608538043544, 0.15201999904211197],
-    [-1.44087298882521, 0.5968291336284592, 0.15201999904211197],
-    [
-        -1.2061646793007326,
-        0.23992107180943512,
-        0.09452470964317669,
-    ],
-    [
-        -1.2297948423581988,
-        1.506064317301892e-16,
-        0.09452470964317669,
-    ],
-    [
-        -1.5595896847163973,
-        1.9099465153711658e-16,
-        0.15201999904211197,
-    ],
-    [-1.5296226062385574, 0.3042608538043544, 0.15201999904211197],
-    [
-        -1.2297948423581988,
-        1.506064317301892e-16,
-        0.09452470964317669,
-    ],
-    [
-        -1.2061646793007326,
-        -0.2399210718094348,
-        0.09452470964317669,
-    ],
-    [-1.5296226062385574, -0.304260853804354, 0.15201999904211197],
-    [
-        -1.5595896847163973,
-        1.9099465153711658e-16,
-        0.15201999904211197,
-    ],
-    [
-        -1.2061646793007326,
-        -0.2399210718094348,
-        0.09452470964317669,
-    ],
-    [
-        -1.1361822840426845,
-        -0.4706221113785199,
-        0.09452470964317669,
-    ],
-    [
-        -1.4408729888252103,
-        -0.5968291336284589,
-        0.15201999904211197,
-    ],
-    [-1.5296226062385574, -0.304260853804354, 0.15201999904211197],
-    [
-        -1.1361822840426845,
-        -0.4706221113785199,
-        0.09452470964317669,
-    ],
-    [
-        -1.0225370407872416,
-        -0.6832374071352492,
-        0.09452470964317669,
-    ],
-    [
-        -1.2967514305021919,
-        -0.8664616045528564,
-        0.15201999904211197,
-    ],
-    [
-        -1.4408729888252103,
-        -0.5968291336284589,
-        0.15201999904211197,
-    ],
-    [
-        -1.0225370407872416,
-        -0.6832374071352492,
-        0.09452470964317669,
-    ],
-    [
-        -0.8695962724997238,
-        -0.8695962724997235,
-        0.09452470964317669,
-    ],
-    [
-        -1.1027964419315543,
-        -1.1027964419315541,
-        0.15201999904211197,
-    ],
-    [
-        -1.2967514305021919,
-        -0.8664616045528564,
-        0.15201999904211197,
-    ],
-    [
-        -0.8695962724997238,
-        -0.8695962724997235,
-        0.09452470964317669,
-    ],
-    [
-        -0.6832374071352494,
-        -1.0225370407872414,
-        0.09452470964317669,
-    ],
-    [
-        -0.8664616045528567,
-        -1.2967514305021917,
-        0.15201999904211197,
-    ],
-    [
-        -1.1027964419315543,
-        -1.1027964419315541,
-        0.15201999904211197,
-    ],
-    [
-        -0.6832374071352494,
-        -1.0225370407872414,
-        0.09452470964317669,
-    ],
-    [-0.4706221113785207, -1.136182284042684, 0.09452470964317669],
-    [-0.59682913362846, -1.4408729888252099, 0.15201999904211197],
-    [
-        -0.8664616045528567,
-        -1.2967514305021917,
-        0.15201999904211197,
-    ],
-    [-0.4706221113785207, -1.136182284042684, 0.09452470964317669],
-    [
-        -0.2399210718094352,
-        -1.2061646793007323,
-        0.09452470964317669,
-    ],
-    [
-        -0.3042608538043545,
-        -1.5296226062385572,
-        0.15201999904211197,
-    ],
-    [-0.59682913362846, -1.4408729888252099, 0.15201999904211197],
-    [
-        -0.2399210718094352,
-        -1.2061646793007323,
-        0.09452470964317669,
-    ],
-    [
-        -2.2590964759528377e-16,
-        -1.2297948423581988,
-        0.09452470964317669,
-    ],
-    [
-        -2.8649197730567485e-16,
-        -1.5595896847163973,
-        0.15201999904211197,
-    ],
-    [
-        -0.3042608538043545,
-        -1.5296226062385572,
-        0.15201999904211197,
-    ],
-    [
-        -2.2590964759528377e-16,
-        -1.2297948423581988,
-        0.09452470964317669,
-    ],
-    [
-        0.23992107180943475,
-        -1.2061646793007326,
-        0.09452470964317669,
-    ],
-    [
-        0.30426085380435397,
-        -1.5296226062385574,
-        0.15201999904211197,
-    ],
-    [
-        -2.8649197730567485e-16,
-        -1.5595896847163973,
-        0.15201999904211197,
-    ],
-    [
-        0.23992107180943475,
-        -1.2061646793007326,
-        0.09452470964317669,
-    ],
-    [0.4706221113785203, -1.136182284042684, 0.09452470964317669],
-    [0.5968291336284595, -1.44087298882521, 0.15201999904211197],
-    [
-        0.30426085380435397,
-        -1.5296226062385574,
-        0.15201999904211197,
-    ],
-    [0.4706221113785203, -1.136182284042684, 0.09452470964317669],
-    [0.683237407135249, -1.0225370407872416, 0.09452470964317669],
-    [0.8664616045528561, -1.2967514305021919, 0.15201999904211197],
-    [0.5968291336284595, -1.44087298882521, 0.15201999904211197],
-    [0.683237407135249, -1.0225370407872416, 0.09452470964317669],
-    [0.8695962724997234, -0.8695962724997238, 0.09452470964317669],
-    [1.102796441931554, -1.1027964419315543, 0.15201999904211197],
-    [0.8664616045528561, -1.2967514305021919, 0.15201999904211197],
-    [0.8695962724997234, -0.8695962724997238, 0.09452470964317669],
-    [1.0225370407872414, -0.6832374071352494, 0.09452470964317669],
-    [1.2967514305021917, -0.8664616045528567, 0.15201999904211197],
-    [1.102796441931554, -1.1027964419315543, 0.15201999904211197],
-    [1.0225370407872414, -0.6832374071352494, 0.09452470964317669],
-    [1.136182284042684, -0.4706221113785208, 0.09452470964317669],
-    [1.4408729888252099, -0.59682913362846, 0.15201999904211197],
-    [1.2967514305021917, -0.8664616045528567, 0.15201999904211197],
-    [1.136182284042684, -0.4706221113785208, 0.09452470964317669],
-    [
-        1.2061646793007323,
-        -0.23992107180943525,
-        0.09452470964317669,
-    ],
-    [1.5296226062385572, -0.3042608538043546, 0.15201999904211197],
-    [1.4408729888252099, -0.59682913362846, 0.15201999904211197],
-    [
-        1.2061646793007323,
-        -0.23992107180943525,
-        0.09452470964317669,
-    ],
-    [
-        1.2297948423581988,
-        -3.012128634603784e-16,
-        0.09452470964317669,
-    ],
-    [
-        1.5595896847163973,
-        -3.8198930307423315e-16,
-        0.15201999904211197,
-    ],
-    [1.5296226062385572, -0.3042608538043546, 0.15201999904211197],
-    [1.5595896847163973, 0.0, 0.15201999904211197],
-    [1.5296226062385574, 0.30426085380435386, 0.15201999904211197],
-    [1.8530805331763827, 0.36860063579927316, 0.22311086819680595],
-    [1.8893845270745961, 0.0, 0.22311086819680595],
-    [1.5296226062385574, 0.30426085380435386, 0.15201999904211197],
-    [1.44087298882521, 0.5968291336284591, 0.15201999904211197],
-    [1.7455636936077366, 0.7230361558783983, 0.22311086819680595],
-    [1.8530805331763827, 0.36860063579927316, 0.22311086819680595],
-    [1.44087298882521, 0.5968291336284591, 0.15201999904211197],
-    [1.2967514305021917, 0.8664616045528567, 0.15201999904211197],
-    [1.5709658202171422, 1.0496858019704642, 0.22311086819680595],
-    [1.7455636936077366, 0.7230361558783983, 0.22311086819680595],
-    [1.2967514305021917, 0.8664616045528567, 0.15201999904211197],
-    [1.1027964419315541, 1.1027964419315541, 0.15201999904211197],
-    [1.3359966113633852, 1.3359966113633852, 0.22311086819680595],
-    [1.5709658202171422, 1.0496858019704642, 0.22311086819680595],
-    [1.1027964419315541, 1.1027964419315541, 0.15201999904211197],
-    [0.8664616045528569, 1.2967514305021917, 0.15201999904211197],
-    [1.0496858019704645, 1.5709658202171422, 0.22311086819680595],
-    [1.3359966113633852, 1.3359966113633852, 0.22311086819680595],
-    [0.8664616045528569, 1.2967514305021917, 0.15201999904211197],
-    [0.5968291336284592, 1.44087298882521, 0.15201999904211197],
-    [0.7230361558783984, 1.7455636936077366, 0.22311086819680595],
-    [1.0496858019704645, 1.5709658202171422, 0.22311086819680595],
-    [0.5968291336284592, 1.44087298882521, 0.15201999904211197],
-    [0.30426085380435397, 1.5296226062385574, 0.15201999904211197],
-    [0.3686006357992733, 1.8530805331763827, 0.22311086819680595],
-    [0.7230361558783984, 1.7455636936077366, 0.22311086819680595],
-    [0.30426085380435397, 1.5296226062385574, 0.15201999904211197],
-    [
-        9.549732576855829e-17,
-        1.5595896847163973,
-        0.15201999904211197,
-    ],
-    [
-        1.15691435672022e-16,
-        1.8893845270745961,
-        0.22311086819680595,
-    ],
-    [0.3686006357992733, 1.8530805331763827, 0.22311086819680595],
-    [
-        9.549732576855829e-17,
-        1.5595896847163973,
-        0.15201999904211197,
-    ],
-    [-0.3042608538043538, 1.5296226062385574, 0.15201999904211197],
-    [
-        -0.36860063579927305,
-        1.8530805331763827,
-        0.22311086819680595,
-    ],
-    [
-        1.15691435672022e-16,
-        1.8893845270745961,
-        0.22311086819680595,
-    ],
-    [-0.3042608538043538, 1.5296226062385574, 0.15201999904211197],
-    [-0.596829133628459, 1.44087298882521, 0.15201999904211197],
-    [-0.7230361558783982, 1.7455636936077366, 0.22311086819680595],
-    [
-        -0.36860063579927305,
-        1.8530805331763827,
-        0.22311086819680595,
-    ],
-    [-0.596829133628459, 1.44087298882521, 0.15201999904211197],
-    [-0.8664616045528564, 1.2967514305021917, 0.15201999904211197],
-    [-1.0496858019704638, 1.5709658202171424, 0.22311086819680595],
-    [-0.7230361558783982, 1.7455636936077366, 0.22311086819680595],
-    [-0.8664616045528564, 1.2967514305021917, 0.15201999904211197],
-    [-1.1027964419315541, 1.1027964419315541, 0.15201999904211197],
-    [-1.335996611363385, 1.3359966113633852, 0.22311086819680595],
-    [-1.0496858019704638, 1.5709658202171424, 0.22311086819680595],
-    [-1.1027964419315541, 1.1027964419315541, 0.15201999904211197],
-    [-1.2967514305021917, 0.8664616045528567, 0.15201999904211197],
-    [-1.5709658202171424, 1.0496858019704642, 0.22311086819680595],
-    [-1.335996611363385, 1.3359966113633852, 0.22311086819680595],
-    [-1.2967514305021917, 0.8664616045528567, 0.15201999904211197],
-    [-1.44087298882521, 0.5968291336284592, 0.15201999904211197],
-    [-1.7455636936077366, 0.7230361558783985, 0.22311086819680595],
-    [-1.5709658202171424, 1.0496858019704642, 0.22311086819680595],
-    [-1.44087298882521, 0.5968291336284592, 0.15201999904211197],
-    [-1.5296226062385574, 0.3042608538043544, 0.15201999904211197],
-    [-1.8530805331763827, 0.3686006357992738, 0.22311086819680595],
-    [-1.7455636936077366, 0.7230361558783985, 0.22311086819680595],
-    [-1.5296226062385574, 0.3042608538043544, 0.15201999904211197],
-    [
-        -1.5595896847163973,
-        1.9099465153711658e-16,
-        0.15201999904211197,
-    ],
-    [
-        -1.8893845270745961,
-        2.31382871344044e-16,
-        0.22311086819680595,
-    ],
-    [-1.8530805331763827, 0.3686006357992738, 0.22311086819680595],
-    [
-        -1.5595896847163973,
-        1.9099465153711658e-16,
-        0.15201999904211197,
-    ],
-    [-1.5296226062385574, -0.304260853804354, 0.15201999904211197],
-    [
-        -1.8530805331763827,
-        -0.3686006357992733,
-        0.22311086819680595,
-    ],
-    [
-        -1.8893845270745961,
-        2.31382871344044e-16,
-        0.22311086819680595,
-    ],
-    [-1.5296226062385574, -0.304260853804354, 0.15201999904211197],
-    [
-        -1.4408729888252103,
-        -0.5968291336284589,
-        0.15201999904211197,
-    ],
-    [
-        -1.7455636936077368,
-        -0.7230361558783982,
-        0.22311086819680595,
-    ],
-    [
-        -1.8530805331763827,
-        -0.3686006357992733,
-        0.22311086819680595,
-    ],
-    [
-        -1.4408729888252103,
-        -0.5968291336284589,
-        0.15201999904211197,
-    ],
-    [
-        -1.2967514305021919,
-        -0.8664616045528564,
-        0.15201999904211197,
-    ],
-    [
-        -1.5709658202171426,
-        -1.0496858019704638,
-        0.22311086819680595,
-    ],
-    [
-        -1.7455636936077368,
-        -0.7230361558783982,
-        0.22311086819680595,
-    ],
-    [
-        -1.2967514305021919,
-        -0.8664616045528564,
-        0.15201999904211197,
-    ],
-    [
-        -1.1027964419315543,
-        -1.1027964419315541,
-        0.15201999904211197,
-    ],
-    [-1.3359966113633854, -1.335996611363385, 0.22311086819680595],
-    [
-        -1.5709658202171426,
-        -1.0496858019704638,
-        0.22311086819680595,
-    ],
-    [
-        -1.1027964419315543,
-        -1.1027964419315541,
-        0.15201999904211197,
-    ],
-    [
-        -0.8664616045528567,
-        -1.2967514305021917,
-        0.15201999904211197,
-    ],
-    [
-        -1.0496858019704642,
-        -1.5709658202171422,
-        0.22311086819680595,
-    ],
-    [-1.3359966113633854, -1.335996611363385, 0.22311086819680595],
-    [
-        -0.8664616045528567,
-        -1.2967514305021917,
-        0.15201999904211197,
-    ],
-    [-0.59682913362846, -1.4408729888252099, 0.15201999904211197],
-    [-0.7230361558783994, -1.745563693607736, 0.22311086819680595],
-    [
-        -1.0496858019704642,
-        -1.5709658202171422,
-        0.22311086819680595,
-    ],
-    [-0.59682913362846, -1.4408729888252099, 0.15201999904211197],
-    [
-        -0.3042608538043545,
-        -1.5296226062385572,
-        0.15201999904211197,
-    ],
-    [
-        -0.36860063579927393,
-        -1.8530805331763824,
-        0.22311086819680595,
-    ],
-    [-0.7230361558783994, -1.745563693607736, 0.22311086819680595],
-    [
-        -0.3042608538043545,
-        -1.5296226062385572,
-        0.15201999904211197,
-    ],
-    [
-        -2.8649197730567485e-16,
-        -1.5595896847163973,
-        0.15201999904211197,
-    ],
-    [
-        -3.47074307016066e-16,
-        -1.8893845270745961,
-        0.22311086819680595,
-    ],
-    [
-        -0.36860063579927393,
-        -1.8530805331763824,
-        0.22311086819680595,
-    ],
-    [
-        -2.8649197730567485e-16,
-        -1.5595896847163973,
-        0.15201999904211197,
-    ],
-    [
-        0.30426085380435397,
-        -1.5296226062385574,
-        0.15201999904211197,
-    ],
-    [
-        0.36860063579927327,
-        -1.8530805331763827,
-        0.22311086819680595,
-    ],
-    [
-        -3.47074307016066e-16,
-        -1.8893845270745961,
-        0.22311086819680595,
-    ],
-    [
-        0.30426085380435397,
-        -1.5296226062385574,
-        0.15201999904211197,
-    ],
-    [0.5968291336284595, -1.44087298882521, 0.15201999904211197],
-    [0.7230361558783988, -1.7455636936077363, 0.22311086819680595],
-    [
-        0.36860063579927327,
-        -1.8530805331763827,
-        0.22311086819680595,
-    ],
-    [0.5968291336284595, -1.44087298882521, 0.15201999904211197],
-    [0.8664616045528561, -1.2967514305021919, 0.15201999904211197],
-    [1.0496858019704636, -1.5709658202171426, 0.22311086819680595],
-    [0.7230361558783988, -1.7455636936077363, 0.22311086819680595],
-    [0.8664616045528561, -1.2967514305021919, 0.15201999904211197],
-    [1.102796441931554, -1.1027964419315543, 0.15201999904211197],
-    [1.3359966113633848, -1.3359966113633854, 0.22311086819680595],
-    [1.0496858019704636, -1.5709658202171426, 0.22311086819680595],
-    [1.102796441931554, -1.1027964419315543, 0.15201999904211197],
-    [1.2967514305021917, -0.8664616045528567, 0.15201999904211197],
-    [1.5709658202171422, -1.0496858019704642, 0.22311086819680595],
-    [1.3359966113633848, -1.3359966113633854, 0.22311086819680595],
-    [1.2967514305021917, -0.8664616045528567, 0.15201999904211197],
-    [1.4408729888252099, -0.59682913362846, 0.15201999904211197],
-    [1.745563693607736, -0.7230361558783995, 0.22311086819680595],
-    [1.5709658202171422, -1.0496858019704642, 0.22311086819680595],
-    [1.4408729888252099, -0.59682913362846, 0.15201999904211197],
-    [1.5296226062385572, -0.3042608538043546, 0.15201999904211197],
-    [
-        1.8530805331763824,
-        -0.36860063579927405,
-        0.22311086819680595,
-    ],
-    [1.745563693607736, -0.7230361558783995, 0.22311086819680595],
-    [1.5296226062385572, -0.3042608538043546, 0.15201999904211197],
-    [
-        1.5595896847163973,
-        -3.8198930307423315e-16,
-        0.15201999904211197,
-    ],
-    [
-        1.8893845270745961,
-        -4.62765742688088e-16,
-        0.22311086819680595,
-    ],
-    [
-        1.8530805331763824,
-        -0.36860063579927405,
-        0.22311086819680595,
-    ],
-    [1.8893845270745961, 0.0, 0.22311086819680595],
-    [1.8530805331763827, 0.36860063579927316, 0.22311086819680595],
-    [2.1765384601142075, 0.43294041779419234, 0.3077973171072585],
-    [2.2191793694327946, 0.0, 0.3077973171072585],
-    [1.8530805331763827, 0.36860063579927316, 0.22311086819680595],
-    [1.7455636936077366, 0.7230361558783983, 0.22311086819680595],
-    [2.0502543983902624, 0.8492431781283374, 0.3077973171072585],
-    [2.1765384601142075, 0.43294041779419234, 0.3077973171072585],
-    [1.7455636936077366, 0.7230361558783983, 0.22311086819680595],
-    [1.5709658202171422, 1.0496858019704642, 0.22311086819680595],
-    [1.8451802099320924, 1.2329099993880714, 0.3077973171072585],
-    [2.0502543983902624, 0.8492431781283374, 0.3077973171072585],
-    [1.5709658202171422, 1.0496858019704642, 0.22311086819680595],
-    [1.3359966113633852, 1.3359966113633852, 0.22311086819680595],
-    [1.5691967807952156, 1.5691967807952156, 0.3077973171072585],
-    [1.8451802099320924, 1.2329099993880714, 0.3077973171072585],
-    [1.3359966113633852, 1.3359966113633852, 0.22311086819680595],
-    [1.0496858019704645, 1.5709658202171422, 0.22311086819680595],
-    [1.2329099993880717, 1.8451802099320924, 0.3077973171072585],
-    [1.5691967807952156, 1.5691967807952156, 0.3077973171072585],
-    [1.0496858019704645, 1.5709658202171422, 0.22311086819680595],
-    [0.7230361558783984, 1.7455636936077366, 0.22311086819680595],
-    [0.8492431781283376, 2.0502543983902624, 0.3077973171072585],
-    [1.2329099993880717, 1.8451802099320924, 0.3077973171072585],
-    [0.7230361558783984, 1.7455636936077366, 0.22311086819680595],
-    [0.3686006357992733, 1.8530805331763827, 0.22311086819680595],
-    [0.4329404177941925, 2.1765384601142075, 0.3077973171072585],
-    [0.8492431781283376, 2.0502543983902624, 0.3077973171072585],
-    [0.3686006357992733, 1.8530805331763827, 0.22311086819680595],
-    [
-        1.15691435672022e-16,
-        1.8893845270745961,
-        0.22311086819680595,
-    ],
-    [
-        1.3588554557548568e-16,
-        2.2191793694327946,
-        0.3077973171072585,
-    ],
-    [0.4329404177941925, 2.1765384601142075, 0.3077973171072585],
-    [
-        1.15691435672022e-16,
-        1.8893845270745961,
-        0.22311086819680595,
-    ],
-    [
-        -0.36860063579927305,
-        1.8530805331763827,
-        0.22311086819680595,
-    ],
-    [-0.43294041779419223, 2.1765384601142075, 0.3077973171072585],
-    [
-        1.3588554557548568e-16,
-        2.2191793694327946,
-        0.3077973171072585,
-    ],
-    [
-        -0.36860063579927305,
-        1.8530805331763827,
-        0.22311086819680595,
-    ],
-    [-0.7230361558783982, 1.7455636936077366, 0.22311086819680595],
-    [-0.8492431781283373, 2.0502543983902624, 0.3077973171072585],
-    [-0.43294041779419223, 2.1765384601142075, 0.3077973171072585],
-    [-0.7230361558783982, 1.7455636936077366, 0.22311086819680595],
-    [-1.0496858019704638, 1.5709658202171424, 0.22311086819680595],
-    [-1.232909999388071, 1.8451802099320929, 0.3077973171072585],
-    [-0.8492431781283373, 2.0502543983902624, 0.3077973171072585],
-    [-1.0496858019704638, 1.5709658202171424, 0.22311086819680595],
-    [-1.335996611363385, 1.3359966113633852, 0.22311086819680595],
-    [-1.5691967807952154, 1.5691967807952156, 0.3077973171072585],
-    [-1.232909999388071, 1.8451802099320929, 0.3077973171072585],
-    [-1.335996611363385, 1.3359966113633852, 0.22311086819680595],
-    [-1.5709658202171424, 1.0496858019704642, 0.22311086819680595],
-    [-1.8451802099320929, 1.2329099993880714, 0.3077973171072585],
-    [-1.5691967807952154, 1.5691967807952156, 0.3077973171072585],
-    [-1.5709658202171424, 1.0496858019704642, 0.22311086819680595],
-    [-1.7455636936077366, 0.7230361558783985, 0.22311086819680595],
-    [-2.0502543983902624, 0.8492431781283377, 0.3077973171072585],
-    [-1.8451802099320929, 1.2329099993880714, 0.3077973171072585],
-    [-1.7455636936077366, 0.7230361558783985, 0.22311086819680595],
-    [-1.8530805331763827, 0.3686006357992738, 0.22311086819680595],
-    [-2.1765384601142075, 0.4329404177941931, 0.3077973171072585],
-    [-2.0502543983902624, 0.8492431781283377, 0.3077973171072585],
-    [-1.8530805331763827, 0.3686006357992738, 0.22311086819680595],
-    [
-        -1.8893845270745961,
-        2.31382871344044e-16,
-        0.22311086819680595,
-    ],
-    [
-        -2.2191793694327946,
-        2.7177109115097136e-16,
-        0.3077973171072585,
-    ],
-    [-2.1765384601142075, 0.4329404177941931, 0.3077973171072585],
-    [
-        -1.8893845270745961,
-        2.31382871344044e-16,
-        0.22311086819680595,
-    ],
-    [
-        -1.8530805331763827,
-        -0.3686006357992733,
-        0.22311086819680595,
-    ],
-    [
-        -2.1765384601142075,
-        -0.43294041779419257,
-        0.3077973171072585,
-    ],
-    [
-        -2.2191793694327946,
-        2.7177109115097136e-16,
-        0.3077973171072585,
-    ],
-    [
-        -1.8530805331763827,
-        -0.3686006357992733,
-        0.22311086819680595,
-    ],
-    [
-        -1.7455636936077368,
-        -0.7230361558783982,
-        0.22311086819680595,
-    ],
-    [-2.050254398390263, -0.8492431781283372, 0.3077973171072585],
-    [
-        -2.1765384601142075,
-        -0.43294041779419257,
-        0.3077973171072585,
-    ],
-    [
-        -1.7455636936077368,
-        -0.7230361558783982,
-        0.22311086819680595,
-    ],
-    [
-        -1.5709658202171426,
-        -1.0496858019704638,
-        0.22311086819680595,
-    ],
-    [-1.845180209932093, -1.232909999388071, 0.3077973171072585],
-    [-2.050254398390263, -0.8492431781283372, 0.3077973171072585],
-    [
-        -1.5709658202171426,
-        -1.0496858019704638,
-        0.22311086819680595,
-    ],
-    [-1.3359966113633854, -1.335996611363385, 0.22311086819680595],
-    [-1.5691967807952159, -1.5691967807952154, 0.3077973171072585],
-    [-1.845180209932093, -1.232909999388071, 0.3077973171072585],
-    [-1.3359966113633854, -1.335996611363385, 0.22311086819680595],
-    [
-        -1.0496858019704642,
-        -1.5709658202171422,
-        0.22311086819680595,
-    ],
-    [-1.2329099993880714, -1.8451802099320924, 0.3077973171072585],
-    [-1.5691967807952159, -1.5691967807952154, 0.3077973171072585],
-    [
-        -1.0496858019704642,
-        -1.5709658202171422,
-        0.22311086819680595,
-    ],
-    [-0.7230361558783994, -1.745563693607736, 0.22311086819680595],
-    [-0.8492431781283387, -2.050254398390262, 0.3077973171072585],
-    [-1.2329099993880714, -1.8451802099320924, 0.3077973171072585],
-    [-0.7230361558783994, -1.745563693607736, 0.22311086819680595],
-    [
-        -0.36860063579927393,
-        -1.8530805331763824,
-        0.22311086819680595,
-    ],
-    [
-        -0.43294041779419323,
-        -2.1765384601142075,
-        0.3077973171072585,
-    ],
-    [-0.8492431781283387, -2.050254398390262, 0.3077973171072585],
-    [
-        -0.36860063579927393,
-        -1.8530805331763824,
-        0.22311086819680595,
-    ],
-    [
-        -3.47074307016066e-16,
-        -1.8893845270745961,
-        0.22311086819680595,
-    ],
-    [
-        -4.07656636726457e-16,
-        -2.2191793694327946,
-        0.3077973171072585,
-    ],
-    [
-        -0.43294041779419323,
-        -2.1765384601142075,
-        0.3077973171072585,
-    ],
-    [
-        -3.47074307016066e-16,
-        -1.8893845270745961,
-        0.22311086819680595,
-    ],
-    [
-        0.36860063579927327,
-        -1.8530805331763827,
-        0.22311086819680595,
-    ],
-    [0.43294041779419246, -2.1765384601142075, 0.3077973171072585],
-    [
-        -4.07656636726457e-16,
-        -2.2191793694327946,
-        0.3077973171072585,
-    ],
-    [
-        0.36860063579927327,
-        -1.8530805331763827,
-        0.22311086819680595,
-    ],
-    [0.7230361558783988, -1.7455636936077363, 0.22311086819680595],
-    [0.8492431781283379, -2.050254398390262, 0.3077973171072585],
-    [0.43294041779419246, -2.1765384601142075, 0.3077973171072585],
-    [0.7230361558783988, -1.7455636936077363, 0.22311086819680595],
-    [1.0496858019704636, -1.5709658202171426, 0.22311086819680595],
-    [1.2329099993880708, -1.845180209932093, 0.3077973171072585],
-    [0.8492431781283379, -2.050254398390262, 0.3077973171072585],
-    [1.0496858019704636, -1.5709658202171426, 0.22311086819680595],
-    [1.3359966113633848, -1.3359966113633854, 0.22311086819680595],
-    [1.5691967807952152, -1.5691967807952159, 0.3077973171072585],
-    [1.2329099993880708, -1.845180209932093, 0.3077973171072585],
-    [1.3359966113633848, -1.3359966113633854, 0.22311086819680595],
-    [1.5709658202171422, -1.0496858019704642, 0.22311086819680595],
-    [1.8451802099320924, -1.2329099993880714, 0.3077973171072585],
-    [1.5691967807952152, -1.5691967807952159, 0.3077973171072585],
-    [1.5709658202171422, -1.0496858019704642, 0.22311086819680595],
-    [1.745563693607736, -0.7230361558783995, 0.22311086819680595],
-    [2.050254398390262, -0.8492431781283388, 0.3077973171072585],
-    [1.8451802099320924, -1.2329099993880714, 0.3077973171072585],
-    [1.745563693607736, -0.7230361558783995, 0.22311086819680595],
-    [
-        1.8530805331763824,
-        -0.36860063579927405,
-        0.22311086819680595,
-    ],
-    [2.1765384601142075, -0.4329404177941934, 0.3077973171072585],
-    [2.050254398390262, -0.8492431781283388, 0.3077973171072585],
-    [
-        1.8530805331763824,
-        -0.36860063579927405,
-        0.22311086819680595,
-    ],
-    [
-        1.8893845270745961,
-        -4.62765742688088e-16,
-        0.22311086819680595,
-    ],
-    [
-        2.2191793694327946,
-        -5.435421823019427e-16,
-        0.3077973171072585,
-    ],
-    [2.1765384601142075, -0.4329404177941934, 0.3077973171072585],
-    [2.2191793694327946, 0.0, 0.3077973171072585],
-    [2.208493414205554, 0.21751761567016004, 0.3077973171072585],
-    [2.5367002042556983, 0.24984316301354895, 0.40607934577346977],
-    [2.5489742117909935, 0.0, 0.40607934577346977],
-    [2.208493414205554, 0.21751761567016004, 0.3077973171072585],
-    [2.1765384601142075, 0.43294041779419234, 0.3077973171072585],
-    [2.499996387052033, 0.4972801997891116, 0.40607934577346977],
-    [2.5367002042556983, 0.24984316301354895, 0.40607934577346977],
-    [2.1765384601142075, 0.43294041779419234, 0.3077973171072585],
-    [2.1236222508350098, 0.64419376702556, 0.3077973171072585],
-    [2.4392162380040157, 0.739928156399696, 0.40607934577346977],
-    [2.499996387052033, 0.4972801997891116, 0.40607934577346977],
-    [2.1236222508350098, 0.64419376702556, 0.3077973171072585],
-    [2.0502543983902624, 0.8492431781283374, 0.3077973171072585],
-    [2.354945103172789, 0.9754502003782767, 0.40607934577346977],
-    [2.4392162380040157, 0.739928156399696, 0.40607934577346977],
-    [2.0502543983902624, 0.8492431781283374, 0.3077973171072585],
-    [1.9571414753059555, 1.0461139131821944, 0.3077973171072585],
-    [2.2479945596540647, 1.2015781256918938, 0.40607934577346977],
-    [2.354945103172789, 0.9754502003782767, 0.40607934577346977],
-    [1.9571414753059555, 1.0461139131821944, 0.3077973171072585],
-    [1.8451802099320924, 1.2329099993880714, 0.3077973171072585],
-    [2.1193945996470434, 1.416134196805679, 0.40607934577346977],
-    [2.2479945596540647, 1.2015781256918938, 0.40607934577346977],
-    [1.8451802099320924, 1.2329099993880714, 0.3077973171072585],
-    [1.7154488504584773, 1.4078324883226785, 0.3077973171072585],
-    [1.970383711066481, 1.617052121466528, 0.40607934577346977],
-    [2.1193945996470434, 1.416134196805679, 0.40607934577346977],
-    [1.7154488504584773, 1.4078324883226785, 0.3077973171072585],
-    [1.5691967807952156, 1.5691967807952156, 0.3077973171072585],
-    [1.8023969502270465, 1.8023969502270465, 0.40607934577346977],
-    [1.970383711066481, 1.617052121466528, 0.40607934577346977],
-    [1.5691967807952156, 1.5691967807952156, 0.3077973171072585],
-    [1.4078324883226785, 1.715448850458477, 0.3077973171072585],
-    [1.617052121466528, 1.9703837110664808, 0.40607934577346977],
-    [1.8023969502270465, 1.8023969502270465, 0.40607934577346977],
-    [1.4078324883226785, 1.715448850458477, 0.3077973171072585],
-    [1.2329099993880717, 1.8451802099320924, 0.3077973171072585],
-    [1.4161341968056793, 2.1193945996470434, 0.40607934577346977],
-    [1.617052121466528, 1.9703837110664808, 0.40607934577346977],
-    [1.2329099993880717, 1.8451802099320924, 0.3077973171072585],
-    [1.0461139131821948, 1.9571414753059553, 0.3077973171072585],
-    [1.2015781256918943, 2.2479945596540643, 0.40607934577346977],
-    [1.4161341968056793, 2.1193945996470434, 0.40607934577346977],
-    [1.0461139131821948, 1.9571414753059553, 0.3077973171072585],
-    [0.8492431781283376, 2.0502543983902624, 0.3077973171072585],
-    [0.9754502003782768, 2.354945103172789, 0.40607934577346977],
-    [1.2015781256918943, 2.2479945596540643, 0.40607934577346977],
-    [0.8492431781283376, 2.0502543983902624, 0.3077973171072585],
-    [0.64419376702556, 2.12362225083501, 0.3077973171072585],
-    [0.739928156399696, 2.439216238004016, 0.40607934577346977],
-    [0.9754502003782768, 2.354945103172789, 0.40607934577346977],
-    [0.64419376702556, 2.12362225083501, 0.3077973171072585],
-    [0.4329404177941925, 2.1765384601142075, 0.3077973171072585],
-    [0.4972801997891118, 2.499996387052033, 0.40607934577346977],
-    [0.739928156399696, 2.439216238004016, 0.40607934577346977],
-    [0.4329404177941925, 2.1765384601142075, 0.3077973171072585],
-    [0.2175176156701604, 2.2084934142055537, 0.3077973171072585],
-    [0.24984316301354936, 2.5367002042556983, 0.40607934577346977],
-    [0.4972801997891118, 2.499996387052033, 0.40607934577346977],
-    [0.2175176156701604, 2.2084934142055537, 0.3077973171072585],
-    [
-        1.3588554557548568e-16,
-        2.2191793694327946,
-        0.3077973171072585,
-    ],
-    [
-        1.5607965547894938e-16,
-        2.5489742117909935,
-        0.40607934577346977,
-    ],
-    [0.24984316301354936, 2.5367002042556983, 0.40607934577346977],
-    [
-        1.3588554557548568e-16,
-        2.2191793694327946,
-        0.3077973171072585,
-    ],
-    [-0.21751761567016012, 2.208493414205554, 0.3077973171072585],
-    [
-        -0.24984316301354906,
-        2.5367002042556983,
-        0.40607934577346977,
-    ],
-    [
-        1.5607965547894938e-16,
-        2.5489742117909935,
-        0.40607934577346977,
-    ],
-    [-0.21751761567016012, 2.208493414205554, 0.3077973171072585],
-    [-0.43294041779419223, 2.1765384601142075, 0.3077973171072585],
-    [-0.4972801997891115, 2.499996387052033, 0.40607934577346977],
-    [
-        -0.24984316301354906,
-        2.5367002042556983,
-        0.40607934577346977,
-    ],
-    [-0.43294041779419223, 2.1765384601142075, 0.3077973171072585],
-    [-0.6441937670255596, 2.12362225083501, 0.3077973171072585],
-    [-0.7399281563996957, 2.439216238004016, 0.40607934577346977],
-    [-0.4972801997891115, 2.499996387052033, 0.40607934577346977],
-    [-0.6441937670255596, 2.12362225083501, 0.3077973171072585],
-    [-0.8492431781283373, 2.0502543983902624, 0.3077973171072585],
-    [-0.9754502003782766, 2.354945103172789, 0.40607934577346977],
-    [-0.7399281563996957, 2.439216238004016, 0.40607934577346977],
-    [-0.8492431781283373, 2.0502543983902624, 0.3077973171072585],
-    [-1.0461139131821946, 1.9571414753059555, 0.3077973171072585],
-    [-1.2015781256918938, 2.2479945596540647, 0.40607934577346977],
-    [-0.9754502003782766, 2.354945103172789, 0.40607934577346977],
-    [-1.0461139131821946, 1.9571414753059555, 0.3077973171072585],
-    [-1.232909999388071, 1.8451802099320929, 0.3077973171072585],
-    [-1.4161341968056784, 2.1193945996470434, 0.40607934577346977],
-    [-1.2015781256918938, 2.2479945596540647, 0.40607934577346977],
-    [-1.232909999388071, 1.8451802099320929, 0.3077973171072585],
-    [-1.4078324883226783, 1.7154488504584775, 0.3077973171072585],
-    [-1.6170521214665277, 1.9703837110664812, 0.40607934577346977],
-    [-1.4161341968056784, 2.1193945996470434, 0.40607934577346977],
-    [-1.4078324883226783, 1.7154488504584775, 0.3077973171072585],
-    [-1.5691967807952154, 1.5691967807952156, 0.3077973171072585],
-    [-1.8023969502270463, 1.8023969502270465, 0.40607934577346977],
-    [-1.6170521214665277, 1.9703837110664812, 0.40607934577346977],
-    [-1.5691967807952154, 1.5691967807952156, 0.3077973171072585],
-    [-1.7154488504584773, 1.4078324883226785, 0.3077973171072585],
-    [-1.970383711066481, 1.617052121466528, 0.40607934577346977],
-    [-1.8023969502270463, 1.8023969502270465, 0.40607934577346977],
-    [-1.7154488504584773, 1.4078324883226785, 0.3077973171072585],
-    [-1.8451802099320929, 1.2329099993880714, 0.3077973171072585],
-    [-2.1193945996470434, 1.416134196805679, 0.40607934577346977],
-    [-1.970383711066481, 1.617052121466528, 0.40607934577346977],
-    [-1.8451802099320929, 1.2329099993880714, 0.3077973171072585],
-    [-1.9571414753059553, 1.0461139131821948, 0.3077973171072585],
-    [-2.2479945596540643, 1.2015781256918943, 0.40607934577346977],
-    [-2.1193945996470434, 1.416134196805679, 0.40607934577346977],
-    [-1.9571414753059553, 1.0461139131821948, 0.3077973171072585],
-    [-2.0502543983902624, 0.8492431781283377, 0.3077973171072585],
-    [-2.354945103172789, 0.975450200378277, 0.40607934577346977],
-    [-2.2479945596540643, 1.2015781256918943, 0.40607934577346977],
-    [-2.0502543983902624, 0.8492431781283377, 0.3077973171072585],
-    [-2.1236222508350098, 0.6441937670255601, 0.3077973171072585],
-    [-2.4392162380040157, 0.7399281563996962, 0.40607934577346977],
-    [-2.354945103172789, 0.975450200378277, 0.40607934577346977],
-    [-2.1236222508350098, 0.6441937670255601, 0.3077973171072585],
-    [-2.1765384601142075, 0.4329404177941931, 0.3077973171072585],
-    [-2.499996387052033, 0.49728019978911253, 0.40607934577346977],
-    [-2.4392162380040157, 0.7399281563996962, 0.40607934577346977],
-    [-2.1765384601142075, 0.4329404177941931, 0.3077973171072585],
-    [-2.2084934142055537, 0.21751761567016054, 0.3077973171072585],
-    [-2.5367002042556983, 0.2498431630135495, 0.40607934577346977],
-    [-2.499996387052033, 0.49728019978911253, 0.40607934577346977],
-    [-2.2084934142055537, 0.21751761567016054, 0.3077973171072585],
-    [
-        -2.2191793694327946,
-        2.7177109115097136e-16,
-        0.3077973171072585,
-    ],
-    [
-        -2.5489742117909935,
-        3.1215931095789876e-16,
-        0.40607934577346977,
-    ],
-    [-2.5367002042556983, 0.2498431630135495, 0.40607934577346977],
-    [
-        -2.2191793694327946,
-        2.7177109115097136e-16,
-        0.3077973171072585,
-    ],
-    [-2.208493414205554, -0.21751761567016, 0.3077973171072585],
-    [
-        -2.5367002042556983,
-        -0.2498431630135489,
-        0.40607934577346977,
-    ],
-    [
-        -2.5489742117909935,
-        3.1215931095789876e-16,
-        0.40607934577346977,
-    ],
-    [-2.208493414205554, -0.21751761567016, 0.3077973171072585],
-    [
-        -2.1765384601142075,
-        -0.43294041779419257,
-        0.3077973171072585,
-    ],
-    [
-        -2.499996387052033,
-        -0.49728019978911187,
-        0.40607934577346977,
-    ],
-    [
-        -2.5367002042556983,
-        -0.2498431630135489,
-        0.40607934577346977,
-    ],
-    [
-        -2.1765384601142075,
-        -0.43294041779419257,
-        0.3077973171072585,
-    ],
-    [-2.12362225083501, -0.6441937670255595, 0.3077973171072585],
-    [-2.439216238004016, -0.7399281563996954, 0.40607934577346977],
-    [
-        -2.499996387052033,
-        -0.49728019978911187,
-        0.40607934577346977,
-    ],
-    [-2.12362225083501, -0.6441937670255595, 0.3077973171072585],
-    [-2.050254398390263, -0.8492431781283372, 0.3077973171072585],
-    [-2.354945103172789, -0.9754502003782765, 0.40607934577346977],
-    [-2.439216238004016, -0.7399281563996954, 0.40607934577346977],
-    [-2.050254398390263, -0.8492431781283372, 0.3077973171072585],
-    [-1.9571414753059555, -1.0461139131821944, 0.3077973171072585],
-    [
-        -2.2479945596540647,
-        -1.2015781256918938,
-        0.40607934577346977,
-    ],
-    [-2.354945103172789, -0.9754502003782765, 0.40607934577346977],
-    [-1.9571414753059555, -1.0461139131821944, 0.3077973171072585],
-    [-1.845180209932093, -1.232909999388071, 0.3077973171072585],
-    [-2.119394599647044, -1.4161341968056784, 0.40607934577346977],
-    [
-        -2.2479945596540647,
-        -1.2015781256918938,
-        0.40607934577346977,
-    ],
-    [-1.845180209932093, -1.232909999388071, 0.3077973171072585],
-    [-1.7154488504584775, -1.407832488322678, 0.3077973171072585],
-    [
-        -1.9703837110664812,
-        -1.6170521214665274,
-        0.40607934577346977,
-    ],
-    [-2.119394599647044, -1.4161341968056784, 0.40607934577346977],
-    [-1.7154488504584775, -1.407832488322678, 0.3077973171072585],
-    [-1.5691967807952159, -1.5691967807952154, 0.3077973171072585],
-    [-1.802396950227047, -1.8023969502270463, 0.40607934577346977],
-    [
-        -1.9703837110664812,
-        -1.6170521214665274,
-        0.40607934577346977,
-    ],
-    [-1.5691967807952159, -1.5691967807952154, 0.3077973171072585],
-    [-1.4078324883226794, -1.7154488504584766, 0.3077973171072585],
-    [
-        -1.6170521214665292,
-        -1.9703837110664801,
-        0.40607934577346977,
-    ],
-    [-1.802396950227047, -1.8023969502270463, 0.40607934577346977],
-    [-1.4078324883226794, -1.7154488504584766, 0.3077973171072585],
-    [-1.2329099993880714, -1.8451802099320924, 0.3077973171072585],
-    [-1.416134196805679, -2.1193945996470434, 0.40607934577346977],
-    [
-        -1.6170521214665292,
-        -1.9703837110664801,
-        0.40607934577346977,
-    ],
-    [-1.2329099993880714, -1.8451802099320924, 0.3077973171072585],
-    [-1.046113913182195, -1.9571414753059553, 0.3077973171072585],
-    [
-        -1.2015781256918943,
-        -2.2479945596540643,
-        0.40607934577346977,
-    ],
-    [-1.416134196805679, -2.1193945996470434, 0.40607934577346977],
-    [-1.046113913182195, -1.9571414753059553, 0.3077973171072585],
-    [-0.8492431781283387, -2.050254398390262, 0.3077973171072585],
-    [-0.9754502003782781, -2.354945103172788, 0.40607934577346977],
-    [
-        -1.2015781256918943,
-        -2.2479945596540643,
-        0.40607934577346977,
-    ],
-    [-0.8492431781283387, -2.050254398390262, 0.3077973171072585],
-    [-0.6441937670255603, -2.1236222508350098, 0.3077973171072585],
-    [
-        -0.7399281563996963,
-        -2.4392162380040157,
-        0.40607934577346977,
-    ],
-    [-0.9754502003782781, -2.354945103172788, 0.40607934577346977],
-    [-0.6441937670255603, -2.1236222508350098, 0.3077973171072585],
-    [
-        -0.43294041779419323,
-        -2.1765384601142075,
-        0.3077973171072585,
-    ],
-    [
-        -0.49728019978911264,
-        -2.4999963870520325,
-        0.40607934577346977,
-    ],
-    [
-        -0.7399281563996963,
-        -2.4392162380040157,
-        0.40607934577346977,
-    ],
-    [
-        -0.43294041779419323,
-        -2.1765384601142075,
-        0.3077973171072585,
-    ],
-    [-0.2175176156701597, -2.208493414205554, 0.3077973171072585],
-    [
-        -0.24984316301354856,
-        -2.5367002042556983,
-        0.40607934577346977,
-    ],
-    [
-        -0.49728019978911264,
-        -2.4999963870520325,
-        0.40607934577346977,
-    ],
-    [-0.2175176156701597, -2.208493414205554, 0.3077973171072585],
-    [
-        -4.07656636726457e-16,
-        -2.2191793694327946,
-        0.3077973171072585,
-    ],
-    [
-        -4.682389664368481e-16,
-        -2.5489742117909935,
-        0.40607934577346977,
-    ],
-    [
-        -0.24984316301354856,
-        -2.5367002042556983,
-        0.40607934577346977,
-    ],
-    [
-        -4.07656636726457e-16,
-        -2.2191793694327946,
-        0.3077973171072585,
-    ],
-    [0.2175176156701589, -2.208493414205554, 0.3077973171072585],
-    [
-        0.24984316301354764,
-        -2.5367002042556983,
-        0.40607934577346977,
-    ],
-    [
-        -4.682389664368481e-16,
-        -2.5489742117909935,
-        0.40607934577346977,
-    ],
-    [0.2175176156701589, -2.208493414205554, 0.3077973171072585],
-    [0.43294041779419246, -2.1765384601142075, 0.3077973171072585],
-    [0.49728019978911175, -2.499996387052033, 0.40607934577346977],
-    [
-        0.24984316301354764,
-        -2.5367002042556983,
-        0.40607934577346977,
-    ],
-    [0.43294041779419246, -2.1765384601142075, 0.3077973171072585],
-    [0.6441937670255594, -2.12362225083501, 0.3077973171072585],
-    [0.7399281563996953, -2.439216238004016, 0.40607934577346977],
-    [0.49728019978911175, -2.499996387052033, 0.40607934577346977],
-    [0.6441937670255594, -2.12362225083501, 0.3077973171072585],
-    [0.8492431781283379, -2.050254398390262, 0.3077973171072585],
-    [0.9754502003782772, -2.3549451031727884, 0.40607934577346977],
-    [0.7399281563996953, -2.439216238004016, 0.40607934577346977],
-    [0.8492431781283379, -2.050254398390262, 0.3077973171072585],
-    [1.0461139131821944, -1.9571414753059555, 0.3077973171072585],
-    [1.2015781256918936, -2.2479945596540647, 0.40607934577346977],
-    [0.9754502003782772, -2.3549451031727884, 0.40607934577346977],
-    [1.0461139131821944, -1.9571414753059555, 0.3077973171072585],
-    [1.2329099993880708, -1.845180209932093, 0.3077973171072585],
-    [1.4161341968056782, -2.119394599647044, 0.40607934577346977],
-    [1.2015781256918936, -2.2479945596540647, 0.40607934577346977],
-    [1.2329099993880708, -1.845180209932093, 0.3077973171072585],
-    [1.4078324883226787, -1.715448850458477, 0.3077973171072585],
-    [1.6170521214665283, -1.9703837110664808, 0.40607934577346977],
-    [1.4161341968056782, -2.119394599647044, 0.40607934577346977],
-    [1.4078324883226787, -1.715448850458477, 0.3077973171072585],
-    [1.5691967807952152, -1.5691967807952159, 0.3077973171072585],
-    [1.802396950227046, -1.802396950227047, 0.40607934577346977],
-    [1.6170521214665283, -1.9703837110664808, 0.40607934577346977],
-    [1.5691967807952152, -1.5691967807952159, 0.3077973171072585],
-    [1.7154488504584764, -1.4078324883226794, 0.3077973171072585],
-    [1.97038371106648, -1.6170521214665292, 0.40607934577346977],
-    [1.802396950227046, -1.802396950227047, 0.40607934577346977],
-    [1.7154488504584764, -1.4078324883226794, 0.3077973171072585],
-    [1.8451802099320924, -1.2329099993880714, 0.3077973171072585],
-    [2.1193945996470434, -1.416134196805679, 0.40607934577346977],
-    [1.97038371106648, -1.6170521214665292, 0.40607934577346977],
-    [1.8451802099320924, -1.2329099993880714, 0.3077973171072585],
-    [1.957141475305955, -1.046113913182195, 0.3077973171072585],
-    [2.2479945596540643, -1.2015781256918945, 0.40607934577346977],
-    [2.1193945996470434, -1.416134196805679, 0.40607934577346977],
-    [1.957141475305955, -1.046113913182195, 0.3077973171072585],
-    [2.050254398390262, -0.8492431781283388, 0.3077973171072585],
-    [2.354945103172788, -0.9754502003782782, 0.40607934577346977],
-    [2.2479945596540643, -1.2015781256918945, 0.40607934577346977],
-    [2.050254398390262, -0.8492431781283388, 0.3077973171072585],
-    [2.1236222508350098, -0.6441937670255604, 0.3077973171072585],
-    [2.4392162380040157, -0.7399281563996964, 0.40607934577346977],
-    [2.354945103172788, -0.9754502003782782, 0.40607934577346977],
-    [2.1236222508350098, -0.6441937670255604, 0.3077973171072585],
-    [2.1765384601142075, -0.4329404177941934, 0.3077973171072585],
-    [2.4999963870520325, -0.4972801997891128, 0.40607934577346977],
-    [2.4392162380040157, -0.7399281563996964, 0.40607934577346977],
-    [2.1765384601142075, -0.4329404177941934, 0.3077973171072585],
-    [2.208493414205554, -0.21751761567015981, 0.3077973171072585],
-    [2.5367002042556983, -0.2498431630135487, 0.40607934577346977],
-    [2.4999963870520325, -0.4972801997891128, 0.40607934577346977],
-    [2.208493414205554, -0.21751761567015981, 0.3077973171072585],
-    [
-        2.2191793694327946,
-        -5.435421823019427e-16,
-        0.3077973171072585,
-    ],
-    [
-        2.5489742117909935,
-        -6.243186219157975e-16,
-        0.40607934577346977,
-    ],
-    [2.5367002042556983, -0.2498431630135487, 0.40607934577346977],
-    [2.5489742117909935, 0.0, 0.40607934577346977],
-    [2.5367002042556983, 0.24984316301354895, 0.40607934577346977],
-    [2.8649069943058425, 0.2821687103569378, 0.5179569541954395],
-    [2.878769054149192, 0.0, 0.5179569541954395],
-    [2.5367002042556983, 0.24984316301354895, 0.40607934577346977],
-    [2.499996387052033, 0.4972801997891116, 0.40607934577346977],
-    [2.8234543139898576, 0.5616199817840308, 0.5179569541954395],
-    [2.8649069943058425, 0.2821687103569378, 0.5179569541954395],
-    [2.499996387052033, 0.4972801997891116, 0.40607934577346977],
-    [2.4392162380040157, 0.739928156399696, 0.40607934577346977],
-    [2.754810225173021, 0.8356625457738319, 0.5179569541954395],
-    [2.8234543139898576, 0.5616199817840308, 0.5179569541954395],
-    [2.4392162380040157, 0.739928156399696, 0.40607934577346977],
-    [2.354945103172789, 0.9754502003782767, 0.40607934577346977],
-    [2.6596358079553144, 1.1016572226282157, 0.5179569541954395],
-    [2.754810225173021, 0.8356625457738319, 0.5179569541954395],
-    [2.354945103172789, 0.9754502003782767, 0.40607934577346977],
-    [2.2479945596540647, 1.2015781256918938, 0.40607934577346977],
-    [2.5388476440021734, 1.3570423382015928, 0.5179569541954395],
-    [2.6596358079553144, 1.1016572226282157, 0.5179569541954395],
-    [2.2479945596540647, 1.2015781256918938, 0.40607934577346977],
-    [2.1193945996470434, 1.416134196805679, 0.40607934577346977],
-    [2.3936089893619936, 1.5993583942232863, 0.5179569541954395],
-    [2.5388476440021734, 1.3570423382015928, 0.5179569541954395],
-    [2.1193945996470434, 1.416134196805679, 0.40607934577346977],
-    [1.970383711066481, 1.617052121466528, 0.40607934577346977],
-    [2.2253185716744843, 1.8262717546103773, 0.5179569541954395],
-    [2.3936089893619936, 1.5993583942232863, 0.5179569541954395],
-    [1.970383711066481, 1.617052121466528, 0.40607934577346977],
-    [1.8023969502270465, 1.8023969502270465, 0.40607934577346977],
-    [2.035597119658877, 2.035597119658877, 0.5179569541954395],
-    [2.2253185716744843, 1.8262717546103773, 0.5179569541954395],
-    [1.8023969502270465, 1.8023969502270465, 0.40607934577346977],
-    [1.617052121466528, 1.9703837110664808, 0.40607934577346977],
-    [1.8262717546103773, 2.225318571674484, 0.5179569541954395],
-    [2.035597119658877, 2.035597119658877, 0.5179569541954395],
-    [1.617052121466528, 1.9703837110664808, 0.40607934577346977],
-    [1.4161341968056793, 2.1193945996470434, 0.40607934577346977],
-    [1.5993583942232867, 2.3936089893619936, 0.5179569541954395],
-    [1.8262717546103773, 2.225318571674484, 0.5179569541954395],
-    [1.4161341968056793, 2.1193945996470434, 0.40607934577346977],
-    [1.2015781256918943, 2.2479945596540643, 0.40607934577346977],
-    [1.3570423382015933, 2.538847644002173, 0.5179569541954395],
-    [1.5993583942232867, 2.3936089893619936, 0.5179569541954395],
-    [1.2015781256918943, 2.2479945596540643, 0.40607934577346977],
-    [0.9754502003782768, 2.354945103172789, 0.40607934577346977],
-    [1.101657222628216, 2.6596358079553144, 0.5179569541954395],
-    [1.3570423382015933, 2.538847644002173, 0.5179569541954395],
-    [0.9754502003782768, 2.354945103172789, 0.40607934577346977],
-    [0.739928156399696, 2.439216238004016, 0.40607934577346977],
-    [0.8356625457738319, 2.754810225173021, 0.5179569541954395],
-    [1.101657222628216, 2.6596358079553144, 0.5179569541954395],
-    [0.739928156399696, 2.439216238004016, 0.40607934577346977],
-    [0.4972801997891118, 2.499996387052033, 0.40607934577346977],
-    [0.561619981784031, 2.8234543139898576, 0.5179569541954395],
-    [0.8356625457738319, 2.754810225173021, 0.5179569541954395],
-    [0.4972801997891118, 2.499996387052033, 0.40607934577346977],
-    [0.24984316301354936, 2.5367002042556983, 0.40607934577346977],
-    [0.28216871035693825, 2.864906994305842, 0.5179569541954395],
-    [0.561619981784031, 2.8234543139898576, 0.5179569541954395],
-    [0.24984316301354936, 2.5367002042556983, 0.40607934577346977],
-    [
-        1.5607965547894938e-16,
-        2.5489742117909935,
-        0.40607934577346977,
-    ],
-    [
-        1.7627376538241306e-16,
-        2.878769054149192,
-        0.5179569541954395,
-    ],
-    [0.28216871035693825, 2.864906994305842, 0.5179569541954395],
-    [
-        1.5607965547894938e-16,
-        2.5489742117909935,
-        0.40607934577346977,
-    ],
-    [
-        -0.24984316301354906,
-        2.5367002042556983,
-        0.40607934577346977,
-    ],
-    [-0.2821687103569379, 2.8649069943058425, 0.5179569541954395],
-    [
-        1.7627376538241306e-16,
-        2.878769054149192,
-        0.5179569541954395,
-    ],
-    [
-        -0.24984316301354906,
-        2.5367002042556983,
-        0.40607934577346977,
-    ],
-    [-0.4972801997891115, 2.499996387052033, 0.40607934577346977],
-    [-0.5616199817840306, 2.8234543139898576, 0.5179569541954395],
-    [-0.2821687103569379, 2.8649069943058425, 0.5179569541954395],
-    [-0.4972801997891115, 2.499996387052033, 0.40607934577346977],
-    [-0.7399281563996957, 2.439216238004016, 0.40607934577346977],
-    [-0.8356625457738315, 2.754810225173021, 0.5179569541954395],
-    [-0.5616199817840306, 2.8234543139898576, 0.5179569541954395],
-    [-0.7399281563996957, 2.439216238004016, 0.40607934577346977],
-    [-0.9754502003782766, 2.354945103172789, 0.40607934577346977],
-    [-1.1016572226282155, 2.6596358079553144, 0.5179569541954395],
-    [-0.8356625457738315, 2.754810225173021, 0.5179569541954395],
-    [-0.9754502003782766, 2.354945103172789, 0.40607934577346977],
-    [-1.2015781256918938, 2.2479945596540647, 0.40607934577346977],
-    [-1.357042338201593, 2.5388476440021734, 0.5179569541954395],
-    [-1.1016572226282155, 2.6596358079553144, 0.5179569541954395],
-    [-1.2015781256918938, 2.2479945596540647, 0.40607934577346977],
-    [-1.4161341968056784, 2.1193945996470434, 0.40607934577346977],
-    [-1.5993583942232856, 2.3936089893619936, 0.5179569541954395],
-    [-1.357042338201593, 2.5388476440021734, 0.5179569541954395],
-    [-1.4161341968056784, 2.1193945996470434, 0.40607934577346977],
-    [-1.6170521214665277, 1.9703837110664812, 0.40607934577346977],
-    [-1.826271754610377, 2.2253185716744848, 0.5179569541954395],
-    [-1.5993583942232856, 2.3936089893619936, 0.5179569541954395],
-    [-1.6170521214665277, 1.9703837110664812, 0.40607934577346977],
-    [-1.8023969502270463, 1.8023969502270465, 0.40607934577346977],
-    [-2.0355971196588767, 2.035597119658877, 0.5179569541954395],
-    [-1.826271754610377, 2.2253185716744848, 0.5179569541954395],
-    [-1.8023969502270463, 1.8023969502270465, 0.40607934577346977],
-    [-1.970383711066481, 1.617052121466528, 0.40607934577346977],
-    [-2.2253185716744843, 1.8262717546103773, 0.5179569541954395],
-    [-2.0355971196588767, 2.035597119658877, 0.5179569541954395],
-    [-1.970383711066481, 1.617052121466528, 0.40607934577346977],
-    [-2.1193945996470434, 1.416134196805679, 0.40607934577346977],
-    [-2.3936089893619936, 1.5993583942232863, 0.5179569541954395],
-    [-2.2253185716744843, 1.8262717546103773, 0.5179569541954395],
-    [-2.1193945996470434, 1.416134196805679, 0.40607934577346977],
-    [-2.2479945596540643, 1.2015781256918943, 0.40607934577346977],
-    [-2.538847644002173, 1.3570423382015933, 0.5179569541954395],
-    [-2.3936089893619936, 1.5993583942232863, 0.5179569541954395],
-    [-2.2479945596540643, 1.2015781256918943, 0.40607934577346977],
-    [-2.354945103172789, 0.975450200378277, 0.40607934577346977],
-    [-2.6596358079553144, 1.1016572226282162, 0.5179569541954395],
-    [-2.538847644002173, 1.3570423382015933, 0.5179569541954395],
-    [-2.354945103172789, 0.975450200378277, 0.40607934577346977],
-    [-2.4392162380040157, 0.7399281563996962, 0.40607934577346977],
-    [-2.754810225173021, 0.8356625457738321, 0.5179569541954395],
-    [-2.6596358079553144, 1.1016572226282162, 0.5179569541954395],
-    [-2.4392162380040157, 0.7399281563996962, 0.40607934577346977],
-    [-2.499996387052033, 0.49728019978911253, 0.40607934577346977],
-    [-2.8234543139898576, 0.5616199817840318, 0.5179569541954395],
-    [-2.754810225173021, 0.8356625457738321, 0.5179569541954395],
-    [-2.499996387052033, 0.49728019978911253, 0.40607934577346977],
-    [-2.5367002042556983, 0.2498431630135495, 0.40607934577346977],
-    [-2.864906994305842, 0.2821687103569384, 0.5179569541954395],
-    [-2.8234543139898576, 0.5616199817840318, 0.5179569541954395],
-    [-2.5367002042556983, 0.2498431630135495, 0.40607934577346977],
-    [
-        -2.5489742117909935,
-        3.1215931095789876e-16,
-        0.40607934577346977,
-    ],
-    [
-        -2.878769054149192,
-        3.525475307648261e-16,
-        0.5179569541954395,
-    ],
-    [-2.864906994305842, 0.2821687103569384, 0.5179569541954395],
-    [
-        -2.5489742117909935,
-        3.1215931095789876e-16,
-        0.40607934577346977,
-    ],
-    [
-        -2.5367002042556983,
-        -0.2498431630135489,
-        0.40607934577346977,
-    ],
-    [
-        -2.8649069943058425,
-        -0.28216871035693775,
-        0.5179569541954395,
-    ],
-    [
-        -2.878769054149192,
-        3.525475307648261e-16,
-        0.5179569541954395,
-    ],
-    [
-        -2.5367002042556983,
-        -0.2498431630135489,
-        0.40607934577346977,
-    ],
-    [
-        -2.499996387052033,
-        -0.49728019978911187,
-        0.40607934577346977,
-    ],
-    [-2.8234543139898576, -0.5616199817840312, 0.5179569541954395],
-    [
-        -2.8649069943058425,
-        -0.28216871035693775,
-        0.5179569541954395,
-    ],
-    [
-        -2.499996387052033,
-        -0.49728019978911187,
-        0.40607934577346977,
-    ],
-    [-2.439216238004016, -0.7399281563996954, 0.40607934577346977],
-    [-2.754810225173021, -0.8356625457738314, 0.5179569541954395],
-    [-2.8234543139898576, -0.5616199817840312, 0.5179569541954395],
-    [-2.439216238004016, -0.7399281563996954, 0.40607934577346977],
-    [-2.354945103172789, -0.9754502003782765, 0.40607934577346977],
-    [-2.659635807955315, -1.1016572226282155, 0.5179569541954395],
-    [-2.754810225173021, -0.8356625457738314, 0.5179569541954395],
-    [-2.354945103172789, -0.9754502003782765, 0.40607934577346977],
-    [
-        -2.2479945596540647,
-        -1.2015781256918938,
-        0.40607934577346977,
-    ],
-    [-2.5388476440021734, -1.3570423382015928, 0.5179569541954395],
-    [-2.659635807955315, -1.1016572226282155, 0.5179569541954395],
-    [
-        -2.2479945596540647,
-        -1.2015781256918938,
-        0.40607934577346977,
-    ],
-    [-2.119394599647044, -1.4161341968056784, 0.40607934577346977],
-    [-2.393608989361994, -1.5993583942232856, 0.5179569541954395],
-    [-2.5388476440021734, -1.3570423382015928, 0.5179569541954395],
-    [-2.119394599647044, -1.4161341968056784, 0.40607934577346977],
-    [
-        -1.9703837110664812,
-        -1.6170521214665274,
-        0.40607934577346977,
-    ],
-    [-2.2253185716744848, -1.8262717546103766, 0.5179569541954395],
-    [-2.393608989361994, -1.5993583942232856, 0.5179569541954395],
-    [
-        -1.9703837110664812,
-        -1.6170521214665274,
-        0.40607934577346977,
-    ],
-    [-1.802396950227047, -1.8023969502270463, 0.40607934577346977],
-    [-2.0355971196588776, -2.0355971196588767, 0.5179569541954395],
-    [-2.2253185716744848, -1.8262717546103766, 0.5179569541954395],
-    [-1.802396950227047, -1.8023969502270463, 0.40607934577346977],
-    [
-        -1.6170521214665292,
-        -1.9703837110664801,
-        0.40607934577346977,
-    ],
-    [-1.8262717546103786, -2.2253185716744834, 0.5179569541954395],
-    [-2.0355971196588776, -2.0355971196588767, 0.5179569541954395],
-    [
-        -1.6170521214665292,
-        -1.9703837110664801,
-        0.40607934577346977,
-    ],
-    [-1.416134196805679, -2.1193945996470434, 0.40607934577346977],
-    [-1.5993583942232863, -2.3936089893619936, 0.5179569541954395],
-    [-1.8262717546103786, -2.2253185716744834, 0.5179569541954395],
-    [-1.416134196805679, -2.1193945996470434, 0.40607934577346977],
-    [
-        -1.2015781256918943,
-        -2.2479945596540643,
-        0.40607934577346977,
-    ],
-    [-1.3570423382015935, -2.538847644002173, 0.5179569541954395],
-    [-1.5993583942232863, -2.3936089893619936, 0.5179569541954395],
-    [
-        -1.2015781256918943,
-        -2.2479945596540643,
-        0.40607934577346977,
-    ],
-    [-0.9754502003782781, -2.354945103172788, 0.40607934577346977],
-    [-1.1016572226282173, -2.659635807955314, 0.5179569541954395],
-    [-1.3570423382015935, -2.538847644002173, 0.5179569541954395],
-    [-0.9754502003782781, -2.354945103172788, 0.40607934577346977],
-    [
-        -0.7399281563996963,
-        -2.4392162380040157,
-        0.40607934577346977,
-    ],
-    [-0.8356625457738323, -2.754810225173021, 0.5179569541954395],
-    [-1.1016572226282173, -2.659635807955314, 0.5179569541954395],
-    [
-        -0.7399281563996963,
-        -2.4392162380040157,
-        0.40607934577346977,
-    ],
-    [
-        -0.49728019978911264,
-        -2.4999963870520325,
-        0.40607934577346977,
-    ],
-    [-0.5616199817840319, -2.823454313989857, 0.5179569541954395],
-    [-0.8356625457738323, -2.754810225173021, 0.5179569541954395],
-    [
-        -0.49728019978911264,
-        -2.4999963870520325,
-        0.40607934577346977,
-    ],
-    [
-        -0.24984316301354856,
-        -2.5367002042556983,
-        0.40607934577346977,
-    ],
-    [
-        -0.28216871035693736,
-        -2.8649069943058425,
-        0.5179569541954395,
-    ],
-    [-0.5616199817840319, -2.823454313989857, 0.5179569541954395],
-    [
-        -0.24984316301354856,
-        -2.5367002042556983,
-        0.40607934577346977,
-    ],
-    [
-        -4.682389664368481e-16,
-        -2.5489742117909935,
-        0.40607934577346977,
-    ],
-    [
-        -5.288212961472392e-16,
-        -2.878769054149192,
-        0.5179569541954395,
-    ],
-    [
-        -0.28216871035693736,
-        -2.8649069943058425,
-        0.5179569541954395,
-    ],
-    [
-        -4.682389664368481e-16,
-        -2.5489742117909935,
-        0.40607934577346977,
-    ],
-    [
-        0.24984316301354764,
-        -2.5367002042556983,
-        0.40607934577346977,
-    ],
-    [0.2821687103569363, -2.8649069943058425, 0.5179569541954395],
-    [
-        -5.288212961472392e-16,
-        -2.878769054149192,
-        0.5179569541954395,
-    ],
-    [
-        0.24984316301354764,
-        -2.5367002042556983,
-        0.40607934577346977,
-    ],
-    [0.49728019978911175, -2.499996387052033, 0.40607934577346977],
-    [0.5616199817840309, -2.8234543139898576, 0.5179569541954395],
-    [0.2821687103569363, -2.8649069943058425, 0.5179569541954395],
-    [0.49728019978911175, -2.499996387052033, 0.40607934577346977],
-    [0.7399281563996953, -2.439216238004016, 0.40607934577346977],
-    [0.8356625457738311, -2.754810225173021, 0.5179569541954395],
-    [0.5616199817840309, -2.8234543139898576, 0.5179569541954395],
-    [0.7399281563996953, -2.439216238004016, 0.40607934577346977],
-    [0.9754502003782772, -2.3549451031727884, 0.40607934577346977],
-    [1.1016572226282164, -2.6596358079553144, 0.5179569541954395],
-    [0.8356625457738311, -2.754810225173021, 0.5179569541954395],
-    [0.9754502003782772, -2.3549451031727884, 0.40607934577346977],
-    [1.2015781256918936, -2.2479945596540647, 0.40607934577346977],
-    [1.3570423382015926, -2.5388476440021734, 0.5179569541954395],
-    [1.1016572226282164, -2.6596358079553144, 0.5179569541954395],
-    [1.2015781256918936, -2.2479945596540647, 0.40607934577346977],
-    [1.4161341968056782, -2.119394599647044, 0.40607934577346977],
-    [1.5993583942232854, -2.393608989361994, 0.5179569541954395],
-    [1.3570423382015926, -2.5388476440021734, 0.5179569541954395],
-    [1.4161341968056782, -2.119394599647044, 0.40607934577346977],
-    [1.6170521214665283, -1.9703837110664808, 0.40607934577346977],
-    [1.8262717546103775, -2.225318571674484, 0.5179569541954395],
-    [1.5993583942232854, -2.393608989361994, 0.5179569541954395],
-    [1.6170521214665283, -1.9703837110664808, 0.40607934577346977],
-    [1.802396950227046, -1.802396950227047, 0.40607934577346977],
-    [2.0355971196588767, -2.0355971196588776, 0.5179569541954395],
-    [1.8262717546103775, -2.225318571674484, 0.5179569541954395],
-    [1.802396950227046, -1.802396950227047, 0.40607934577346977],
-    [1.97038371106648, -1.6170521214665292, 0.40607934577346977],
-    [2.225318571674483, -1.8262717546103786, 0.5179569541954395],
-    [2.0355971196588767, -2.0355971196588776, 0.5179569541954395],
-    [1.97038371106648, -1.6170521214665292, 0.40607934577346977],
-    [2.1193945996470434, -1.416134196805679, 0.40607934577346977],
-    [2.3936089893619936, -1.5993583942232863, 0.5179569541954395],
-    [2.225318571674483, -1.8262717546103786, 0.5179569541954395],
-    [2.1193945996470434, -1.416134196805679, 0.40607934577346977],
-    [2.2479945596540643, -1.2015781256918945, 0.40607934577346977],
-    [2.538847644002173, -1.3570423382015935, 0.5179569541954395],
-    [2.3936089893619936, -1.5993583942232863, 0.5179569541954395],
-    [2.2479945596540643, -1.2015781256918945, 0.40607934577346977],
-    [2.354945103172788, -0.9754502003782782, 0.40607934577346977],
-    [2.659635807955314, -1.1016572226282175, 0.5179569541954395],
-    [2.538847644002173, -1.3570423382015935, 0.5179569541954395],
-    [2.354945103172788, -0.9754502003782782, 0.40607934577346977],
-    [2.4392162380040157, -0.7399281563996964, 0.40607934577346977],
-    [2.754810225173021, -0.8356625457738325, 0.5179569541954395],
-    [2.659635807955314, -1.1016572226282175, 0.5179569541954395],
-    [2.4392162380040157, -0.7399281563996964, 0.40607934577346977],
-    [2.4999963870520325, -0.4972801997891128, 0.40607934577346977],
-    [2.823454313989857, -0.5616199817840322, 0.5179569541954395],
-    [2.754810225173021, -0.8356625457738325, 0.5179569541954395],
-    [2.4999963870520325, -0.4972801997891128, 0.40607934577346977],
-    [2.5367002042556983, -0.2498431630135487, 0.40607934577346977],
-    [2.8649069943058425, -0.2821687103569375, 0.5179569541954395],
-    [2.823454313989857, -0.5616199817840322, 0.5179569541954395],
-    [2.5367002042556983, -0.2498431630135487, 0.40607934577346977],
-    [
-        2.5489742117909935,
-        -6.243186219157975e-16,
-        0.40607934577346977,
-    ],
-    [
-        2.878769054149192,
-        -7.050950615296522e-16,
-        0.5179569541954395,
-    ],
-    [2.8649069943058425, -0.2821687103569375, 0.5179569541954395],
-    [2.878769054149192, 0.0, 0.5179569541954395],
-    [2.8649069943058425, 0.2821687103569378, 0.5179569541954395],
-    [3.1931137843559863, 0.31449425770032663, 0.643430142373168],
-    [3.2085638965073904, 0.0, 0.643430142373168],
-    [2.8649069943058425, 0.2821687103569378, 0.5179569541954395],
-    [2.8234543139898576, 0.5616199817840308, 0.5179569541954395],
-    [3.1469122409276826, 0.62595976377895, 0.643430142373168],
-    [3.1931137843559863, 0.31449425770032663, 0.643430142373168],
-    [2.8234543139898576, 0.5616199817840308, 0.5179569541954395],
-    [2.754810225173021, 0.8356625457738319, 0.5179569541954395],
-    [3.070404212342026, 0.9313969351479678, 0.643430142373168],
-    [3.1469122409276826, 0.62595976377895, 0.643430142373168],
-    [2.754810225173021, 0.8356625457738319, 0.5179569541954395],
-    [2.6596358079553144, 1.1016572226282157, 0.5179569541954395],
-    [2.9643265127378404, 1.227864244878155, 0.643430142373168],
-    [3.070404212342026, 0.9313969351479678, 0.643430142373168],
-    [2.6596358079553144, 1.1016572226282157, 0.5179569541954395],
-    [2.5388476440021734, 1.3570423382015928, 0.5179569541954395],
-    [2.829700728350282, 1.5125065507112918, 0.643430142373168],
-    [2.9643265127378404, 1.227864244878155, 0.643430142373168],
-    [2.5388476440021734, 1.3570423382015928, 0.5179569541954395],
-    [2.3936089893619936, 1.5993583942232863, 0.5179569541954395],
-    [2.667823379076944, 1.7825825916408935, 0.643430142373168],
-    [2.829700728350282, 1.5125065507112918, 0.643430142373168],
-    [2.3936089893619936, 1.5993583942232863, 0.5179569541954395],
-    [2.2253185716744843, 1.8262717546103773, 0.5179569541954395],
-    [2.480253432282488, 2.0354913877542264, 0.643430142373168],
-    [2.667823379076944, 1.7825825916408935, 0.643430142373168],
-    [2.2253185716744843, 1.8262717546103773, 0.5179569541954395],
-    [2.035597119658877, 2.035597119658877, 0.5179569541954395],
-    [2.2687972890907075, 2.2687972890907075, 0.643430142373168],
-    [2.480253432282488, 2.0354913877542264, 0.643430142373168],
-    [2.035597119658877, 2.035597119658877, 0.5179569541954395],
-    [1.8262717546103773, 2.225318571674484, 0.5179569541954395],
-    [2.0354913877542264, 2.4802534322824874, 0.643430142373168],
-    [2.2687972890907075, 2.2687972890907075, 0.643430142373168],
-    [1.8262717546103773, 2.225318571674484, 0.5179569541954395],
-    [1.5993583942232867, 2.3936089893619936, 0.5179569541954395],
-    [1.782582591640894, 2.667823379076944, 0.643430142373168],
-    [2.0354913877542264, 2.4802534322824874, 0.643430142373168],
-    [1.5993583942232867, 2.3936089893619936, 0.5179569541954395],
-    [1.3570423382015933, 2.538847644002173, 0.5179569541954395],
-    [1.5125065507112925, 2.829700728350282, 0.643430142373168],
-    [1.782582591640894, 2.667823379076944, 0.643430142373168],
-    [1.3570423382015933, 2.538847644002173, 0.5179569541954395],
-    [1.101657222628216, 2.6596358079553144, 0.5179569541954395],
-    [1.227864244878155, 2.9643265127378404, 0.643430142373168],
-    [1.5125065507112925, 2.829700728350282, 0.643430142373168],
-    [1.101657222628216, 2.6596358079553144, 0.5179569541954395],
-    [0.8356625457738319, 2.754810225173021, 0.5179569541954395],
-    [0.9313969351479678, 3.0704042123420265, 0.643430142373168],
-    [1.227864244878155, 2.9643265127378404, 0.643430142373168],
-    [0.8356625457738319, 2.754810225173021, 0.5179569541954395],
-    [0.561619981784031, 2.8234543139898576, 0.5179569541954395],
-    [0.6259597637789502, 3.1469122409276826, 0.643430142373168],
-    [0.9313969351479678, 3.0704042123420265, 0.643430142373168],
-    [0.561619981784031, 2.8234543139898576, 0.5179569541954395],
-    [0.28216871035693825, 2.864906994305842, 0.5179569541954395],
-    [0.3144942577003272, 3.1931137843559863, 0.643430142373168],
-    [0.6259597637789502, 3.1469122409276826, 0.643430142373168],
-    [0.28216871035693825, 2.864906994305842, 0.5179569541954395],
-    [
-        1.7627376538241306e-16,
-        2.878769054149192,
-        0.5179569541954395,
-    ],
-    [
-        1.9646787528587676e-16,
-        3.2085638965073904,
-        0.643430142373168,
-    ],
-    [0.3144942577003272, 3.1931137843559863, 0.643430142373168],
-    [
-        1.7627376538241306e-16,
-        2.878769054149192,
-        0.5179569541954395,
-    ],
-    [-0.2821687103569379, 2.8649069943058425, 0.5179569541954395],
-    [-0.3144942577003268, 3.1931137843559863, 0.643430142373168],
-    [
-        1.9646787528587676e-16,
-        3.2085638965073904,
-        0.643430142373168,
-    ],
-    [-0.2821687103569379, 2.8649069943058425, 0.5179569541954395],
-    [-0.5616199817840306, 2.8234543139898576, 0.5179569541954395],
-    [-0.6259597637789498, 3.1469122409276826, 0.643430142373168],
-    [-0.3144942577003268, 3.1931137843559863, 0.643430142373168],
-    [-0.5616199817840306, 2.8234543139898576, 0.5179569541954395],
-    [-0.8356625457738315, 2.754810225173021, 0.5179569541954395],
-    [-0.9313969351479674, 3.0704042123420265, 0.643430142373168],
-    [-0.6259597637789498, 3.1469122409276826, 0.643430142373168],
-    [-0.8356625457738315, 2.754810225173021, 0.5179569541954395],
-    [-1.1016572226282155, 2.6596358079553144, 0.5179569541954395],
-    [-1.2278642448781547, 2.9643265127378404, 0.643430142373168],
-    [-0.9313969351479674, 3.0704042123420265, 0.643430142373168],
-    [-1.1016572226282155, 2.6596358079553144, 0.5179569541954395],
-    [-1.357042338201593, 2.5388476440021734, 0.5179569541954395],
-    [-1.512506550711292, 2.829700728350282, 0.643430142373168],
-    [-1.2278642448781547, 2.9643265127378404, 0.643430142373168],
-    [-1.357042338201593, 2.5388476440021734, 0.5179569541954395],
-    [-1.5993583942232856, 2.3936089893619936, 0.5179569541954395],
-    [-1.7825825916408928, 2.667823379076944, 0.643430142373168],
-    [-1.512506550711292, 2.829700728350282, 0.643430142373168],
-    [-1.5993583942232856, 2.3936089893619936, 0.5179569541954395],
-    [-1.826271754610377, 2.2253185716744848, 0.5179569541954395],
-    [-2.035491387754226, 2.4802534322824883, 0.643430142373168],
-    [-1.7825825916408928, 2.667823379076944, 0.643430142373168],
-    [-1.826271754610377, 2.2253185716744848, 0.5179569541954395],
-    [-2.0355971196588767, 2.035597119658877, 0.5179569541954395],
-    [-2.2687972890907075, 2.2687972890907075, 0.643430142373168],
-    [-2.035491387754226, 2.4802534322824883, 0.643430142373168],
-    [-2.0355971196588767, 2.035597119658877, 0.5179569541954395],
-    [-2.2253185716744843, 1.8262717546103773, 0.5179569541954395],
-    [-2.480253432282488, 2.0354913877542264, 0.643430142373168],
-    [-2.2687972890907075, 2.2687972890907075, 0.643430142373168],
-    [-2.2253185716744843, 1.8262717546103773, 0.5179569541954395],
-    [-2.3936089893619936, 1.5993583942232863, 0.5179569541954395],
-    [-2.667823379076944, 1.7825825916408935, 0.643430142373168],
-    [-2.480253432282488, 2.0354913877542264, 0.643430142373168],
-    [-2.3936089893619936, 1.5993583942232863, 0.5179569541954395],
-    [-2.538847644002173, 1.3570423382015933, 0.5179569541954395],
-    [-2.829700728350282, 1.5125065507112925, 0.643430142373168],
-    [-2.667823379076944, 1.7825825916408935, 0.643430142373168],
-    [-2.538847644002173, 1.3570423382015933, 0.5179569541954395],
-    [-2.6596358079553144, 1.1016572226282162, 0.5179569541954395],
-    [-2.9643265127378404, 1.2278642448781552, 0.643430142373168],
-    [-2.829700728350282, 1.5125065507112925, 0.643430142373168],
-    [-2.6596358079553144, 1.1016572226282162, 0.5179569541954395],
-    [-2.754810225173021, 0.8356625457738321, 0.5179569541954395],
-    [-3.070404212342026, 0.9313969351479681, 0.643430142373168],
-    [-2.9643265127378404, 1.2278642448781552, 0.643430142373168],
-    [-2.754810225173021, 0.8356625457738321, 0.5179569541954395],
-    [-2.8234543139898576, 0.5616199817840318, 0.5179569541954395],
-    [-3.1469122409276826, 0.6259597637789511, 0.643430142373168],
-    [-3.070404212342026, 0.9313969351479681, 0.643430142373168],
-    [-2.8234543139898576, 0.5616199817840318, 0.5179569541954395],
-    [-2.864906994305842, 0.2821687103569384, 0.5179569541954395],
-    [-3.1931137843559863, 0.31449425770032735, 0.643430142373168],
-    [-3.1469122409276826, 0.6259597637789511, 0.643430142373168],
-    [-2.864906994305842, 0.2821687103569384, 0.5179569541954395],
-    [
-        -2.878769054149192,
-        3.525475307648261e-16,
-        0.5179569541954395,
-    ],
-    [
-        -3.2085638965073904,
-        3.929357505717535e-16,
-        0.643430142373168,
-    ],
-    [-3.1931137843559863, 0.31449425770032735, 0.643430142373168],
-    [
-        -2.878769054149192,
-        3.525475307648261e-16,
-        0.5179569541954395,
-    ],
-    [
-        -2.8649069943058425,
-        -0.28216871035693775,
-        0.5179569541954395,
-    ],
-    [-3.1931137843559863, -0.31449425770032663, 0.643430142373168],
-    [
-        -3.2085638965073904,
-        3.929357505717535e-16,
-        0.643430142373168,
-    ],
-    [
-        -2.8649069943058425,
-        -0.28216871035693775,
-        0.5179569541954395,
-    ],
-    [-2.8234543139898576, -0.5616199817840312, 0.5179569541954395],
-    [-3.1469122409276826, -0.6259597637789504, 0.643430142373168],
-    [-3.1931137843559863, -0.31449425770032663, 0.643430142373168],
-    [-2.8234543139898576, -0.5616199817840312, 0.5179569541954395],
-    [-2.754810225173021, -0.8356625457738314, 0.5179569541954395],
-    [-3.0704042123420265, -0.9313969351479672, 0.643430142373168],
-    [-3.1469122409276826, -0.6259597637789504, 0.643430142373168],
-    [-2.754810225173021, -0.8356625457738314, 0.5179569541954395],
-    [-2.659635807955315, -1.1016572226282155, 0.5179569541954395],
-    [-2.964326512737841, -1.2278642448781545, 0.643430142373168],
-    [-3.0704042123420265, -0.9313969351479672, 0.643430142373168],
-    [-2.659635807955315, -1.1016572226282155, 0.5179569541954395],
-    [-2.5388476440021734, -1.3570423382015928, 0.5179569541954395],
-    [-2.829700728350282, -1.5125065507112918, 0.643430142373168],
-    [-2.964326512737841, -1.2278642448781545, 0.643430142373168],
-    [-2.5388476440021734, -1.3570423382015928, 0.5179569541954395],
-    [-2.393608989361994, -1.5993583942232856, 0.5179569541954395],
-    [-2.6678233790769443, -1.7825825916408928, 0.643430142373168],
-    [-2.829700728350282, -1.5125065507112918, 0.643430142373168],
-    [-2.393608989361994, -1.5993583942232856, 0.5179569541954395],
-    [-2.2253185716744848, -1.8262717546103766, 0.5179569541954395],
-    [-2.4802534322824883, -2.035491387754226, 0.643430142373168],
-    [-2.6678233790769443, -1.7825825916408928, 0.643430142373168],
-    [-2.2253185716744848, -1.8262717546103766, 0.5179569541954395],
-    [-2.0355971196588776, -2.0355971196588767, 0.5179569541954395],
-    [-2.268797289090708, -2.2687972890907075, 0.643430142373168],
-    [-2.4802534322824883, -2.035491387754226, 0.643430142373168],
-    [-2.0355971196588776, -2.0355971196588767, 0.5179569541954395],
-    [-1.8262717546103786, -2.2253185716744834, 0.5179569541954395],
-    [-2.0354913877542278, -2.4802534322824865, 0.643430142373168],
-    [-2.268797289090708, -2.2687972890907075, 0.643430142373168],
-    [-1.8262717546103786, -2.2253185716744834, 0.5179569541954395],
-    [-1.5993583942232863, -2.3936089893619936, 0.5179569541954395],
-    [-1.7825825916408935, -2.667823379076944, 0.643430142373168],
-    [-2.0354913877542278, -2.4802534322824865, 0.643430142373168],
-    [-1.5993583942232863, -2.3936089893619936, 0.5179569541954395],
-    [-1.3570423382015935, -2.538847644002173, 0.5179569541954395],
-    [-1.5125065507112925, -2.829700728350282, 0.643430142373168],
-    [-1.7825825916408935, -2.667823379076944, 0.643430142373168],
-    [-1.3570423382015935, -2.538847644002173, 0.5179569541954395],
-    [-1.1016572226282173, -2.659635807955314, 0.5179569541954395],
-    [-1.2278642448781567, -2.9643265127378395, 0.643430142373168],
-    [-1.5125065507112925, -2.829700728350282, 0.643430142373168],
-    [-1.1016572226282173, -2.659635807955314, 0.5179569541954395],
-    [-0.8356625457738323, -2.754810225173021, 0.5179569541954395],
-    [-0.9313969351479683, -3.070404212342026, 0.643430142373168],
-    [-1.2278642448781567, -2.9643265127378395, 0.643430142373168],
-    [-0.8356625457738323, -2.754810225173021, 0.5179569541954395],
-    [-0.5616199817840319, -2.823454313989857, 0.5179569541954395],
-    [-0.6259597637789514, -3.146912240927682, 0.643430142373168],
-    [-0.9313969351479683, -3.070404212342026, 0.643430142373168],
-    [-0.5616199817840319, -2.823454313989857, 0.5179569541954395],
-    [
-        -0.28216871035693736,
-        -2.8649069943058425,
-        0.5179569541954395,
-    ],
-    [-0.3144942577003262, -3.1931137843559863, 0.643430142373168],
-    [-0.6259597637789514, -3.146912240927682, 0.643430142373168],
-    [
-        -0.28216871035693736,
-        -2.8649069943058425,
-        0.5179569541954395,
-    ],
-    [
-        -5.288212961472392e-16,
-        -2.878769054149192,
-        0.5179569541954395,
-    ],
-    [
-        -5.894036258576303e-16,
-        -3.2085638965073904,
-        0.643430142373168,
-    ],
-    [-0.3144942577003262, -3.1931137843559863, 0.643430142373168],
-    [
-        -5.288212961472392e-16,
-        -2.878769054149192,
-        0.5179569541954395,
-    ],
-    [0.2821687103569363, -2.8649069943058425, 0.5179569541954395],
-    [0.314494257700325, -3.1931137843559863, 0.643430142373168],
-    [
-        -5.894036258576303e-16,
-        -3.2085638965073904,
-        0.643430142373168,
-    ],
-    [0.2821687103569363, -2.8649069943058425, 0.5179569541954395],
-    [0.5616199817840309, -2.8234543139898576, 0.5179569541954395],
-    [0.6259597637789501, -3.1469122409276826, 0.643430142373168],
-    [0.314494257700325, -3.1931137843559863, 0.643430142373168],
-    [0.5616199817840309, -2.8234543139898576, 0.5179569541954395],
-    [0.8356625457738311, -2.754810225173021, 0.5179569541954395],
-    [0.931396935147967, -3.0704042123420265, 0.643430142373168],
-    [0.6259597637789501, -3.1469122409276826, 0.643430142373168],
-    [0.8356625457738311, -2.754810225173021, 0.5179569541954395],
-    [1.1016572226282164, -2.6596358079553144, 0.5179569541954395],
-    [1.2278642448781556, -2.96432651273784, 0.643430142373168],
-    [0.931396935147967, -3.0704042123420265, 0.643430142373168],
-    [1.1016572226282164, -2.6596358079553144, 0.5179569541954395],
-    [1.3570423382015926, -2.5388476440021734, 0.5179569541954395],
-    [1.5125065507112916, -2.829700728350282, 0.643430142373168],
-    [1.2278642448781556, -2.96432651273784, 0.643430142373168],
-    [1.3570423382015926, -2.5388476440021734, 0.5179569541954395],
-    [1.5993583942232854, -2.393608989361994, 0.5179569541954395],
-    [1.7825825916408926, -2.6678233790769443, 0.643430142373168],
-    [1.5125065507112916, -2.829700728350282, 0.643430142373168],
-    [1.5993583942232854, -2.393608989361994, 0.5179569541954395],
-    [1.8262717546103775, -2.225318571674484, 0.5179569541954395],
-    [2.035491387754227, -2.4802534322824874, 0.643430142373168],
-    [1.7825825916408926, -2.6678233790769443, 0.643430142373168],
-    [1.8262717546103775, -2.225318571674484, 0.5179569541954395],
-    [2.0355971196588767, -2.0355971196588776, 0.5179569541954395],
-    [2.268797289090707, -2.268797289090708, 0.643430142373168],
-    [2.035491387754227, -2.4802534322824874, 0.643430142373168],
-    [2.0355971196588767, -2.0355971196588776, 0.5179569541954395],
-    [2.225318571674483, -1.8262717546103786, 0.5179569541954395],
-    [2.4802534322824865, -2.0354913877542278, 0.643430142373168],
-    [2.268797289090707, -2.268797289090708, 0.643430142373168],
-    [2.225318571674483, -1.8262717546103786, 0.5179569541954395],
-    [2.3936089893619936, -1.5993583942232863, 0.5179569541954395],
-    [2.667823379076944, -1.7825825916408935, 0.643430142373168],
-    [2.4802534322824865, -2.0354913877542278, 0.643430142373168],
-    [2.3936089893619936, -1.5993583942232863, 0.5179569541954395],
-    [2.538847644002173, -1.3570423382015935, 0.5179569541954395],
-    [2.8297007283502817, -1.5125065507112927, 0.643430142373168],
-    [2.667823379076944, -1.7825825916408935, 0.643430142373168],
-    [2.538847644002173, -1.3570423382015935, 0.5179569541954395],
-    [2.659635807955314, -1.1016572226282175, 0.5179569541954395],
-    [2.9643265127378395, -1.2278642448781567, 0.643430142373168],
-    [2.8297007283502817, -1.5125065507112927, 0.643430142373168],
-    [2.659635807955314, -1.1016572226282175, 0.5179569541954395],
-    [2.754810225173021, -0.8356625457738325, 0.5179569541954395],
-    [3.070404212342026, -0.9313969351479684, 0.643430142373168],
-    [2.9643265127378395, -1.2278642448781567, 0.643430142373168],
-    [2.754810225173021, -0.8356625457738325, 0.5179569541954395],
-    [2.823454313989857, -0.5616199817840322, 0.5179569541954395],
-    [3.146912240927682, -0.6259597637789515, 0.643430142373168],
-    [3.070404212342026, -0.9313969351479684, 0.643430142373168],
-    [2.823454313989857, -0.5616199817840322, 0.5179569541954395],
-    [2.8649069943058425, -0.2821687103569375, 0.5179569541954395],
-    [3.1931137843559863, -0.31449425770032635, 0.643430142373168],
-    [3.146912240927682, -0.6259597637789515, 0.643430142373168],
-    [2.8649069943058425, -0.2821687103569375, 0.5179569541954395],
-    [
-        2.878769054149192,
-        -7.050950615296522e-16,
-        0.5179569541954395,
-    ],
-    [3.2085638965073904, -7.85871501143507e-16, 0.643430142373168],
-    [3.1931137843559863, -0.31449425770032635, 0.643430142373168],
-    [3.2085638965073904, 0.0, 0.643430142373168],
-    [3.1931137843559863, 0.31449425770032663, 0.643430142373168],
-    [3.521320574406131, 0.34681980504371557, 0.7824989103066552],
-    [3.5383587388655893, 0.0, 0.7824989103066552],
-    [3.1931137843559863, 0.31449425770032663, 0.643430142373168],
-    [3.1469122409276826, 0.62595976377895, 0.643430142373168],
-    [3.4703701678655077, 0.6902995457738692, 0.7824989103066552],
-    [3.521320574406131, 0.34681980504371557, 0.7824989103066552],
-    [3.1469122409276826, 0.62595976377895, 0.643430142373168],
-    [3.070404212342026, 0.9313969351479678, 0.643430142373168],
-    [3.385998199511032, 1.0271313245221039, 0.7824989103066552],
-    [3.4703701678655077, 0.6902995457738692, 0.7824989103066552],
-    [3.070404212342026, 0.9313969351479678, 0.643430142373168],
-    [2.9643265127378404, 1.227864244878155, 0.643430142373168],
-    [3.269017217520367, 1.3540712671280941, 0.7824989103066552],
-    [3.385998199511032, 1.0271313245221039, 0.7824989103066552],
-    [2.9643265127378404, 1.227864244878155, 0.643430142373168],
-    [2.829700728350282, 1.5125065507112918, 0.643430142373168],
-    [3.1205538126983914, 1.667970763220991, 0.7824989103066552],
-    [3.269017217520367, 1.3540712671280941, 0.7824989103066552],
-    [2.829700728350282, 1.5125065507112918, 0.643430142373168],
-    [2.667823379076944, 1.7825825916408935, 0.643430142373168],
-    [2.9420377687918946, 1.9658067890585011, 0.7824989103066552],
-    [3.1205538126983914, 1.667970763220991, 0.7824989103066552],
-    [2.667823379076944, 1.7825825916408935, 0.643430142373168],
-    [2.480253432282488, 2.0354913877542264, 0.643430142373168],
-    [2.7351882928904914, 2.244711020898076, 0.7824989103066552],
-    [2.9420377687918946, 1.9658067890585011, 0.7824989103066552],
-    [2.480253432282488, 2.0354913877542264, 0.643430142373168],
-    [2.2687972890907075, 2.2687972890907075, 0.643430142373168],
-    [2.501997458522539, 2.501997458522539, 0.7824989103066552],
-    [2.7351882928904914, 2.244711020898076, 0.7824989103066552],
-    [2.2687972890907075, 2.2687972890907075, 0.643430142373168],
-    [2.0354913877542264, 2.4802534322824874, 0.643430142373168],
-    [2.244711020898076, 2.735188292890491, 0.7824989103066552],
-    [2.501997458522539, 2.501997458522539, 0.7824989103066552],
-    [2.0354913877542264, 2.4802534322824874, 0.643430142373168],
-    [1.782582591640894, 2.667823379076944, 0.643430142373168],
-    [1.9658067890585016, 2.9420377687918946, 0.7824989103066552],
-    [2.244711020898076, 2.735188292890491, 0.7824989103066552],
-    [1.782582591640894, 2.667823379076944, 0.643430142373168],
-    [1.5125065507112925, 2.829700728350282, 0.643430142373168],
-    [1.6679707632209917, 3.120553812698391, 0.7824989103066552],
-    [1.9658067890585016, 2.9420377687918946, 0.7824989103066552],
-    [1.5125065507112925, 2.829700728350282, 0.643430142373168],
-    [1.227864244878155, 2.9643265127378404, 0.643430142373168],
-    [1.3540712671280943, 3.269017217520367, 0.7824989103066552],
-    [1.6679707632209917, 3.120553812698391, 0.7824989103066552],
-    [1.227864244878155, 2.9643265127378404, 0.643430142373168],
-    [0.9313969351479678, 3.0704042123420265, 0.643430142373168],
-    [1.0271313245221039, 3.3859981995110324, 0.7824989103066552],
-    [1.3540712671280943, 3.269017217520367, 0.7824989103066552],
-    [0.9313969351479678, 3.0704042123420265, 0.643430142373168],
-    [0.6259597637789502, 3.1469122409276826, 0.643430142373168],
-    [0.6902995457738695, 3.4703701678655077, 0.7824989103066552],
-    [1.0271313245221039, 3.3859981995110324, 0.7824989103066552],
-    [0.6259597637789502, 3.1469122409276826, 0.643430142373168],
-    [0.3144942577003272, 3.1931137843559863, 0.643430142373168],
-    [0.3468198050437161, 3.5213205744061304, 0.7824989103066552],
-    [0.6902995457738695, 3.4703701678655077, 0.7824989103066552],
-    [0.3144942577003272, 3.1931137843559863, 0.643430142373168],
-    [
-        1.9646787528587676e-16,
-        3.2085638965073904,
-        0.643430142373168,
-    ],
-    [
-        2.1666198518934046e-16,
-        3.5383587388655893,
-        0.7824989103066552,
-    ],
-    [0.3468198050437161, 3.5213205744061304, 0.7824989103066552],
-    [
-        1.9646787528587676e-16,
-        3.2085638965073904,
-        0.643430142373168,
-    ],
-    [-0.3144942577003268, 3.1931137843559863, 0.643430142373168],
-    [-0.3468198050437157, 3.521320574406131, 0.7824989103066552],
-    [
-        2.1666198518934046e-16,
-        3.5383587388655893,
-        0.7824989103066552,
-    ],
-    [-0.3144942577003268, 3.1931137843559863, 0.643430142373168],
-    [-0.6259597637789498, 3.1469122409276826, 0.643430142373168],
-    [-0.6902995457738691, 3.4703701678655077, 0.7824989103066552],
-    [-0.3468198050437157, 3.521320574406131, 0.7824989103066552],
-    [-0.6259597637789498, 3.1469122409276826, 0.643430142373168],
-    [-0.9313969351479674, 3.0704042123420265, 0.643430142373168],
-    [-1.0271313245221034, 3.3859981995110324, 0.7824989103066552],
-    [-0.6902995457738691, 3.4703701678655077, 0.7824989103066552],
-    [-0.9313969351479674, 3.0704042123420265, 0.643430142373168],
-    [-1.2278642448781547, 2.9643265127378404, 0.643430142373168],
-    [-1.354071267128094, 3.269017217520367, 0.7824989103066552],
-    [-1.0271313245221034, 3.3859981995110324, 0.7824989103066552],
-    [-1.2278642448781547, 2.9643265127378404, 0.643430142373168],
-    [-1.512506550711292, 2.829700728350282, 0.643430142373168],
-    [-1.6679707632209912, 3.1205538126983914, 0.7824989103066552],
-    [-1.354071267128094, 3.269017217520367, 0.7824989103066552],
-    [-1.512506550711292, 2.829700728350282, 0.643430142373168],
-    [-1.7825825916408928, 2.667823379076944, 0.643430142373168],
-    [-1.9658067890585003, 2.9420377687918946, 0.7824989103066552],
-    [-1.6679707632209912, 3.1205538126983914, 0.7824989103066552],
-    [-1.7825825916408928, 2.667823379076944, 0.643430142373168],
-    [-2.035491387754226, 2.4802534322824883, 0.643430142373168],
-    [-2.244711020898076, 2.735188292890492, 0.7824989103066552],
-    [-1.9658067890585003, 2.9420377687918946, 0.7824989103066552],
-    [-2.035491387754226, 2.4802534322824883, 0.643430142373168],
-    [-2.2687972890907075, 2.2687972890907075, 0.643430142373168],
-    [-2.5019974585225384, 2.501997458522539, 0.7824989103066552],
-    [-2.244711020898076, 2.735188292890492, 0.7824989103066552],
-    [-2.2687972890907075, 2.2687972890907075, 0.643430142373168],
-    [-2.480253432282488, 2.0354913877542264, 0.643430142373168],
-    [-2.7351882928904914, 2.244711020898076, 0.7824989103066552],
-    [-2.5019974585225384, 2.501997458522539, 0.7824989103066552],
-    [-2.480253432282488, 2.0354913877542264, 0.643430142373168],
-    [-2.667823379076944, 1.7825825916408935, 0.643430142373168],
-    [-2.9420377687918946, 1.9658067890585011, 0.7824989103066552],
-    [-2.7351882928904914, 2.244711020898076, 0.7824989103066552],
-    [-2.667823379076944, 1.7825825916408935, 0.643430142373168],
-    [-2.829700728350282, 1.5125065507112925, 0.643430142373168],
-    [-3.120553812698391, 1.6679707632209917, 0.7824989103066552],
-    [-2.9420377687918946, 1.9658067890585011, 0.7824989103066552],
-    [-2.829700728350282, 1.5125065507112925, 0.643430142373168],
-    [-2.9643265127378404, 1.2278642448781552, 0.643430142373168],
-    [-3.269017217520367, 1.3540712671280946, 0.7824989103066552],
-    [-3.120553812698391, 1.6679707632209917, 0.7824989103066552],
-    [-2.9643265127378404, 1.2278642448781552, 0.643430142373168],
-    [-3.070404212342026, 0.9313969351479681, 0.643430142373168],
-    [-3.385998199511032, 1.027131324522104, 0.7824989103066552],
-    [-3.269017217520367, 1.3540712671280946, 0.7824989103066552],
-    [-3.070404212342026, 0.9313969351479681, 0.643430142373168],
-    [-3.1469122409276826, 0.6259597637789511, 0.643430142373168],
-    [-3.4703701678655077, 0.6902995457738705, 0.7824989103066552],
-    [-3.385998199511032, 1.027131324522104, 0.7824989103066552],
-    [-3.1469122409276826, 0.6259597637789511, 0.643430142373168],
-    [-3.1931137843559863, 0.31449425770032735, 0.643430142373168],
-    [-3.5213205744061304, 0.34681980504371634, 0.7824989103066552],
-    [-3.4703701678655077, 0.6902995457738705, 0.7824989103066552],
-    [-3.1931137843559863, 0.31449425770032735, 0.643430142373168],
-    [
-        -3.2085638965073904,
-        3.929357505717535e-16,
-        0.643430142373168,
-    ],
-    [
-        -3.5383587388655893,
-        4.3332397037868093e-16,
-        0.7824989103066552,
-    ],
-    [-3.5213205744061304, 0.34681980504371634, 0.7824989103066552],
-    [
-        -3.2085638965073904,
-        3.929357505717535e-16,
-        0.643430142373168,
-    ],
-    [-3.1931137843559863, -0.31449425770032663, 0.643430142373168],
-    [-3.521320574406131, -0.3468198050437155, 0.7824989103066552],
-    [
-        -3.5383587388655893,
-        4.3332397037868093e-16,
-        0.7824989103066552,
-    ],
-    [-3.1931137843559863, -0.31449425770032663, 0.643430142373168],
-    [-3.1469122409276826, -0.6259597637789504, 0.643430142373168],
-    [-3.4703701678655077, -0.6902995457738696, 0.7824989103066552],
-    [-3.521320574406131, -0.3468198050437155, 0.7824989103066552],
-    [-3.1469122409276826, -0.6259597637789504, 0.643430142373168],
-    [-3.0704042123420265, -0.9313969351479672, 0.643430142373168],
-    [-3.3859981995110324, -1.0271313245221032, 0.7824989103066552],
-    [-3.4703701678655077, -0.6902995457738696, 0.7824989103066552],
-    [-3.0704042123420265, -0.9313969351479672, 0.643430142373168],
-    [-2.964326512737841, -1.2278642448781545, 0.643430142373168],
-    [-3.2690172175203673, -1.3540712671280937, 0.7824989103066552],
-    [-3.3859981995110324, -1.0271313245221032, 0.7824989103066552],
-    [-2.964326512737841, -1.2278642448781545, 0.643430142373168],
-    [-2.829700728350282, -1.5125065507112918, 0.643430142373168],
-    [-3.1205538126983914, -1.667970763220991, 0.7824989103066552],
-    [-3.2690172175203673, -1.3540712671280937, 0.7824989103066552],
-    [-2.829700728350282, -1.5125065507112918, 0.643430142373168],
-    [-2.6678233790769443, -1.7825825916408928, 0.643430142373168],
-    [-2.942037768791895, -1.9658067890585003, 0.7824989103066552],
-    [-3.1205538126983914, -1.667970763220991, 0.7824989103066552],
-    [-2.6678233790769443, -1.7825825916408928, 0.643430142373168],
-    [-2.4802534322824883, -2.035491387754226, 0.643430142373168],
-    [-2.735188292890492, -2.2447110208980754, 0.7824989103066552],
-    [-2.942037768791895, -1.9658067890585003, 0.7824989103066552],
-    [-2.4802534322824883, -2.035491387754226, 0.643430142373168],
-    [-2.268797289090708, -2.2687972890907075, 0.643430142373168],
-    [-2.501997458522539, -2.5019974585225384, 0.7824989103066552],
-    [-2.735188292890492, -2.2447110208980754, 0.7824989103066552],
-    [-2.268797289090708, -2.2687972890907075, 0.643430142373168],
-    [-2.0354913877542278, -2.4802534322824865, 0.643430142373168],
-    [-2.2447110208980776, -2.7351882928904905, 0.7824989103066552],
-    [-2.501997458522539, -2.5019974585225384, 0.7824989103066552],
-    [-2.0354913877542278, -2.4802534322824865, 0.643430142373168],
-    [-1.7825825916408935, -2.667823379076944, 0.643430142373168],
-    [-1.9658067890585011, -2.9420377687918946, 0.7824989103066552],
-    [-2.2447110208980776, -2.7351882928904905, 0.7824989103066552],
-    [-1.7825825916408935, -2.667823379076944, 0.643430142373168],
-    [-1.5125065507112925, -2.829700728350282, 0.643430142373168],
-    [-1.667970763220992, -3.120553812698391, 0.7824989103066552],
-    [-1.9658067890585011, -2.9420377687918946, 0.7824989103066552],
-    [-1.5125065507112925, -2.829700728350282, 0.643430142373168],
-    [-1.2278642448781567, -2.9643265127378395, 0.643430142373168],
-    [-1.3540712671280961, -3.269017217520366, 0.7824989103066552],
-    [-1.667970763220992, -3.120553812698391, 0.7824989103066552],
-    [-1.2278642448781567, -2.9643265127378395, 0.643430142373168],
-    [-0.9313969351479683, -3.070404212342026, 0.643430142373168],
-    [-1.0271313245221043, -3.385998199511032, 0.7824989103066552],
-    [-1.3540712671280961, -3.269017217520366, 0.7824989103066552],
-    [-0.9313969351479683, -3.070404212342026, 0.643430142373168],
-    [-0.6259597637789514, -3.146912240927682, 0.643430142373168],
-    [-0.6902995457738708, -3.4703701678655072, 0.7824989103066552],
-    [-1.0271313245221043, -3.385998199511032, 0.7824989103066552],
-    [-0.6259597637789514, -3.146912240927682, 0.643430142373168],
-    [-0.3144942577003262, -3.1931137843559863, 0.643430142373168],
-    [-0.346819805043715, -3.521320574406131, 0.7824989103066552],
-    [-0.6902995457738708, -3.4703701678655072, 0.7824989103066552],
-    [-0.3144942577003262, -3.1931137843559863, 0.643430142373168],
-    [
-        -5.894036258576303e-16,
-        -3.2085638965073904,
-        0.643430142373168,
-    ],
-    [
-        -6.499859555680213e-16,
-        -3.5383587388655893,
-        0.7824989103066552,
-    ],
-    [-0.346819805043715, -3.521320574406131, 0.7824989103066552],
-    [
-        -5.894036258576303e-16,
-        -3.2085638965073904,
-        0.643430142373168,
-    ],
-    [0.314494257700325, -3.1931137843559863, 0.643430142373168],
-    [0.34681980504371374, -3.521320574406131, 0.7824989103066552],
-    [
-        -6.499859555680213e-16,
-        -3.5383587388655893,
-        0.7824989103066552,
-    ],
-    [0.314494257700325, -3.1931137843559863, 0.643430142373168],
-    [0.6259597637789501, -3.1469122409276826, 0.643430142373168],
-    [0.6902995457738694, -3.4703701678655077, 0.7824989103066552],
-    [0.34681980504371374, -3.521320574406131, 0.7824989103066552],
-    [0.6259597637789501, -3.1469122409276826, 0.643430142373168],
-    [0.931396935147967, -3.0704042123420265, 0.643430142373168],
-    [1.027131324522103, -3.3859981995110324, 0.7824989103066552],
-    [0.6902995457738694, -3.4703701678655077, 0.7824989103066552],
-    [0.931396935147967, -3.0704042123420265, 0.643430142373168],
-    [1.2278642448781556, -2.96432651273784, 0.643430142373168],
-    [1.354071267128095, -3.2690172175203664, 0.7824989103066552],
-    [1.027131324522103, -3.3859981995110324, 0.7824989103066552],
-    [1.2278642448781556, -2.96432651273784, 0.643430142373168],
-    [1.5125065507112916, -2.829700728350282, 0.643430142373168],
-    [1.6679707632209908, -3.1205538126983914, 0.7824989103066552],
-    [1.354071267128095, -3.2690172175203664, 0.7824989103066552],
-    [1.5125065507112916, -2.829700728350282, 0.643430142373168],
-    [1.7825825916408926, -2.6678233790769443, 0.643430142373168],
-    [1.9658067890585, -2.942037768791895, 0.7824989103066552],
-    [1.6679707632209908, -3.1205538126983914, 0.7824989103066552],
-    [1.7825825916408926, -2.6678233790769443, 0.643430142373168],
-    [2.035491387754227, -2.4802534322824874, 0.643430142373168],
-    [2.2447110208980763, -2.735188292890491, 0.7824989103066552],
-    [1.9658067890585, -2.942037768791895, 0.7824989103066552],
-    [2.035491387754227, -2.4802534322824874, 0.643430142373168],
-    [2.268797289090707, -2.268797289090708, 0.643430142373168],
-    [2.501997458522538, -2.501997458522539, 0.7824989103066552],
-    [2.2447110208980763, -2.735188292890491, 0.7824989103066552],
-    [2.268797289090707, -2.268797289090708, 0.643430142373168],
-    [2.4802534322824865, -2.0354913877542278, 0.643430142373168],
-    [2.73518829289049, -2.2447110208980776, 0.7824989103066552],
-    [2.501997458522538, -2.501997458522539, 0.7824989103066552],
-    [2.4802534322824865, -2.0354913877542278, 0.643430142373168],
-    [2.667823379076944, -1.7825825916408935, 0.643430142373168],
-    [2.9420377687918946, -1.9658067890585011, 0.7824989103066552],
-    [2.73518829289049, -2.2447110208980776, 0.7824989103066552],
-    [2.667823379076944, -1.7825825916408935, 0.643430142373168],
-    [2.8297007283502817, -1.5125065507112927, 0.643430142373168],
-    [3.120553812698391, -1.6679707632209921, 0.7824989103066552],
-    [2.9420377687918946, -1.9658067890585011, 0.7824989103066552],
-    [2.8297007283502817, -1.5125065507112927, 0.643430142373168],
-    [2.9643265127378395, -1.2278642448781567, 0.643430142373168],
-    [3.269017217520366, -1.3540712671280963, 0.7824989103066552],
-    [3.120553812698391, -1.6679707632209921, 0.7824989103066552],
-    [2.9643265127378395, -1.2278642448781567, 0.643430142373168],
-    [3.070404212342026, -0.9313969351479684, 0.643430142373168],
-    [3.385998199511032, -1.0271313245221045, 0.7824989103066552],
-    [3.269017217520366, -1.3540712671280963, 0.7824989103066552],
-    [3.070404212342026, -0.9313969351479684, 0.643430142373168],
-    [3.146912240927682, -0.6259597637789515, 0.643430142373168],
-    [3.4703701678655072, -0.6902995457738709, 0.7824989103066552],
-    [3.385998199511032, -1.0271313245221045, 0.7824989103066552],
-    [3.146912240927682, -0.6259597637789515, 0.643430142373168],
-    [3.1931137843559863, -0.31449425770032635, 0.643430142373168],
-    [3.521320574406131, -0.3468198050437152, 0.7824989103066552],
-    [3.4703701678655072, -0.6902995457738709, 0.7824989103066552],
-    [3.1931137843559863, -0.31449425770032635, 0.643430142373168],
-    [3.2085638965073904, -7.85871501143507e-16, 0.643430142373168],
-    [
-        3.5383587388655893,
-        -8.666479407573619e-16,
-        0.7824989103066552,
-    ],
-    [3.521320574406131, -0.3468198050437152, 0.7824989103066552],
-    [3.5383587388655893, 0.0, 0.7824989103066552],
-    [3.5340966308028148, 0.17361903425223027, 0.7824989103066552],
-    [3.863494220830085, 0.18980130017192454, 0.935163257995901],
-    [3.868153581223788, 0.0, 0.935163257995901],
-    [3.5340966308028148, 0.17361903425223027, 0.7824989103066552],
-    [3.521320574406131, 0.34681980504371557, 0.7824989103066552],
-    [3.8495273644562755, 0.37914535238710445, 0.935163257995901],
-    [3.863494220830085, 0.18980130017192454, 0.935163257995901],
-    [3.521320574406131, 0.34681980504371557, 0.7824989103066552],
-    [3.5000613483144476, 0.5191850565470234, 0.7824989103066552],
-    [3.826286659482716, 0.5675760102391731, 0.935163257995901],
-    [3.8495273644562755, 0.37914535238710445, 0.935163257995901],
-    [3.5000613483144476, 0.5191850565470234, 0.7824989103066552],
-    [3.4703701678655077, 0.6902995457738692, 0.7824989103066552],
-    [3.793828094803333, 0.7546393277687885, 0.935163257995901],
-    [3.826286659482716, 0.5675760102391731, 0.935163257995901],
-    [3.4703701678655077, 0.6902995457738692, 0.7824989103066552],
-    [3.432318561713654, 0.8597510429318468, 0.7824989103066552],
-    [3.7522298659434745, 0.9398846530592104, 0.935163257995901],
-    [3.793828094803333, 0.7546393277687885, 0.935163257995901],
-    [3.432318561713654, 0.8597510429318468, 0.7824989103066552],
-    [3.385998199511032, 1.0271313245221039, 0.7824989103066552],
-    [3.701592186680038, 1.12286571389624, 0.935163257995901],
-    [3.7522298659434745, 0.9398846530592104, 0.935163257995901],
-    [3.385998199511032, 1.0271313245221039, 0.7824989103066552],
-    [3.3315206710673735, 1.192037156785509, 0.7824989103066552],
-    [3.642037047617706, 1.303141692877073, 0.935163257995901],
-    [3.701592186680038, 1.12286571389624, 0.935163257995901],
-    [3.3315206710673735, 1.192037156785509, 0.7824989103066552],
-    [3.269017217520367, 1.3540712671280941, 0.7824989103066552],
-    [3.573707922302893, 1.4802782893780333, 0.935163257995901],
-    [3.642037047617706, 1.303141692877073, 0.935163257995901],
-    [3.269017217520367, 1.3540712671280941, 0.7824989103066552],
-    [3.1986384151642624, 1.512843301185532, 0.7824989103066552],
-    [3.496769421583408, 1.653848765822817, 0.935163257995901],
-    [3.573707922302893, 1.4802782893780333, 0.935163257995901],
-    [3.1986384151642624, 1.512843301185532, 0.7824989103066552],
-    [3.1205538126983914, 1.667970763220991, 0.7824989103066552],
-    [3.4114068970465006, 1.8234349757306905, 0.935163257995901],
-    [3.496769421583408, 1.653848765822817, 0.935163257995901],
-    [3.1205538126983914, 1.667970763220991, 0.7824989103066552],
-    [3.034951522769498, 1.8190799375908664, 0.7824989103066552],
-    [3.3178259944906543, 1.9886283710679875, 0.935163257995901],
-    [3.4114068970465006, 1.8234349757306905, 0.935163257995901],
-    [3.034951522769498, 1.8190799375908664, 0.7824989103066552],
-    [2.9420377687918946, 1.9658067890585011, 0.7824989103066552],
-    [3.216252158506845, 2.1490309864761086, 0.935163257995901],
-    [3.3178259944906543, 1.9886283710679875, 0.935163257995901],
-    [2.9420377687918946, 1.9658067890585011, 0.7824989103066552],
-    [2.8420363881371977, 2.1077978397869552, 0.7824989103066552],
-    [3.106930089362775, 2.304256398004926, 0.935163257995901],
-    [3.216252158506845, 2.1490309864761086, 0.935163257995901],
-    [2.8420363881371977, 2.1077978397869552, 0.7824989103066552],
-    [2.7351882928904914, 2.244711020898076, 0.7824989103066552],
-    [2.9901231534984953, 2.4539306540419257, 0.935163257995901],
-    [3.106930089362775, 2.304256398004926, 0.935163257995901],
-    [2.7351882928904914, 2.244711020898076, 0.7824989103066552],
-    [2.6217508894720125, 2.376216496546389, 0.7824989103066552],
-    [2.8661127490535816, 2.597693176194398, 0.935163257995901],
-    [2.9901231534984953, 2.4539306540419257, 0.935163257995901],
-    [2.6217508894720125, 2.376216496546389, 0.7824989103066552],
-    [2.501997458522539, 2.501997458522539, 0.7824989103066552],
-    [2.7351976279543697, 2.7351976279543697, 0.935163257995901],
-    [2.8661127490535816, 2.597693176194398, 0.935163257995901],
-    [2.501997458522539, 2.501997458522539, 0.7824989103066552],
-    [2.376216496546389, 2.621750889472012, 0.7824989103066552],
-    [2.597693176194398, 2.866112749053581, 0.935163257995901],
-    [2.7351976279543697, 2.7351976279543697, 0.935163257995901],
-    [2.376216496546389, 2.621750889472012, 0.7824989103066552],
-    [2.244711020898076, 2.735188292890491, 0.7824989103066552],
-    [2.4539306540419257, 2.990123153498495, 0.935163257995901],
-    [2.597693176194398, 2.866112749053581, 0.935163257995901],
-    [2.244711020898076, 2.735188292890491, 0.7824989103066552],
-    [2.1077978397869557, 2.8420363881371977, 0.7824989103066552],
-    [2.3042563980049264, 3.1069300893627747, 0.935163257995901],
-    [2.4539306540419257, 2.990123153498495, 0.935163257995901],
-    [2.1077978397869557, 2.8420363881371977, 0.7824989103066552],
-    [1.9658067890585016, 2.9420377687918946, 0.7824989103066552],
-    [2.149030986476109, 3.216252158506845, 0.935163257995901],
-    [2.3042563980049264, 3.1069300893627747, 0.935163257995901],
-    [1.9658067890585016, 2.9420377687918946, 0.7824989103066552],
-    [1.8190799375908664, 3.034951522769498, 0.7824989103066552],
-    [1.9886283710679875, 3.3178259944906543, 0.935163257995901],
-    [2.149030986476109, 3.216252158506845, 0.935163257995901],
-    [1.8190799375908664, 3.034951522769498, 0.7824989103066552],
-    [1.6679707632209917, 3.120553812698391, 0.7824989103066552],
-    [1.823434975730691, 3.4114068970465, 0.935163257995901],
-    [1.9886283710679875, 3.3178259944906543, 0.935163257995901],
-    [1.6679707632209917, 3.120553812698391, 0.7824989103066552],
-    [1.5128433011855325, 3.1986384151642624, 0.7824989103066552],
-    [1.6538487658228174, 3.496769421583408, 0.935163257995901],
-    [1.823434975730691, 3.4114068970465, 0.935163257995901],
-    [1.5128433011855325, 3.1986384151642624, 0.7824989103066552],
-    [1.3540712671280943, 3.269017217520367, 0.7824989103066552],
-    [1.4802782893780335, 3.573707922302893, 0.935163257995901],
-    [1.6538487658228174, 3.496769421583408, 0.935163257995901],
-    [1.3540712671280943, 3.269017217520367, 0.7824989103066552],
-    [1.192037156785509, 3.3315206710673735, 0.7824989103066552],
-    [1.303141692877073, 3.642037047617706, 0.935163257995901],
-    [1.4802782893780335, 3.573707922302893, 0.935163257995901],
-    [1.192037156785509, 3.3315206710673735, 0.7824989103066552],
-    [1.0271313245221039, 3.3859981995110324, 0.7824989103066552],
-    [1.12286571389624, 3.7015921866800383, 0.935163257995901],
-    [1.303141692877073, 3.642037047617706, 0.935163257995901],
-    [1.0271313245221039, 3.3859981995110324, 0.7824989103066552],
-    [0.8597510429318471, 3.432318561713654, 0.7824989103066552],
-    [0.9398846530592109, 3.7522298659434745, 0.935163257995901],
-    [1.12286571389624, 3.7015921866800383, 0.935163257995901],
-    [0.8597510429318471, 3.432318561713654, 0.7824989103066552],
-    [0.6902995457738695, 3.4703701678655077, 0.7824989103066552],
-    [0.7546393277687888, 3.793828094803333, 0.935163257995901],
-    [0.9398846530592109, 3.7522298659434745, 0.935163257995901],
-    [0.6902995457738695, 3.4703701678655077, 0.7824989103066552],
-    [0.5191850565470234, 3.5000613483144476, 0.7824989103066552],
-    [0.5675760102391731, 3.826286659482716, 0.935163257995901],
-    [0.7546393277687888, 3.793828094803333, 0.935163257995901],
-    [0.5191850565470234, 3.5000613483144476, 0.7824989103066552],
-    [0.3468198050437161, 3.5213205744061304, 0.7824989103066552],
-    [0.37914535238710506, 3.849527364456275, 0.935163257995901],
-    [0.5675760102391731, 3.826286659482716, 0.935163257995901],
-    [0.3468198050437161, 3.5213205744061304, 0.7824989103066552],
-    [0.17361903425223066, 3.5340966308028148, 0.7824989103066552],
-    [0.18980130017192495, 3.863494220830085, 0.935163257995901],
-    [0.37914535238710506, 3.849527364456275, 0.935163257995901],
-    [0.17361903425223066, 3.5340966308028148, 0.7824989103066552],
-    [
-        2.1666198518934046e-16,
-        3.5383587388655893,
-        0.7824989103066552,
-    ],
-    [2.368560950928042e-16, 3.868153581223788, 0.935163257995901],
-    [0.18980130017192495, 3.863494220830085, 0.935163257995901],
-    [
-        2.1666198518934046e-16,
-        3.5383587388655893,
-        0.7824989103066552,
-    ],
-    [-0.17361903425223024, 3.5340966308028148, 0.7824989103066552],
-    [-0.1898013001719245, 3.863494220830085, 0.935163257995901],
-    [2.368560950928042e-16, 3.868153581223788, 0.935163257995901],
-    [-0.17361903425223024, 3.5340966308028148, 0.7824989103066552],
-    [-0.3468198050437157, 3.521320574406131, 0.7824989103066552],
-    [-0.3791453523871046, 3.8495273644562755, 0.935163257995901],
-    [-0.1898013001719245, 3.863494220830085, 0.935163257995901],
-    [-0.3468198050437157, 3.521320574406131, 0.7824989103066552],
-    [-0.519185056547023, 3.5000613483144476, 0.7824989103066552],
-    [-0.5675760102391727, 3.826286659482716, 0.935163257995901],
-    [-0.3791453523871046, 3.8495273644562755, 0.935163257995901],
-    [-0.519185056547023, 3.5000613483144476, 0.7824989103066552],
-    [-0.6902995457738691, 3.4703701678655077, 0.7824989103066552],
-    [-0.7546393277687883, 3.793828094803333, 0.935163257995901],
-    [-0.5675760102391727, 3.826286659482716, 0.935163257995901],
-    [-0.6902995457738691, 3.4703701678655077, 0.7824989103066552],
-    [-0.8597510429318468, 3.432318561713654, 0.7824989103066552],
-    [-0.9398846530592104, 3.7522298659434745, 0.935163257995901],
-    [-0.7546393277687883, 3.793828094803333, 0.935163257995901],
-    [-0.8597510429318468, 3.432318561713654, 0.7824989103066552],
-    [-1.0271313245221034, 3.3859981995110324, 0.7824989103066552],
-    [-1.1228657138962392, 3.7015921866800383, 0.935163257995901],
-    [-0.9398846530592104, 3.7522298659434745, 0.935163257995901],
-    [-1.0271313245221034, 3.3859981995110324, 0.7824989103066552],
-    [-1.1920371567855086, 3.3315206710673735, 0.7824989103066552],
-    [-1.3031416928770725, 3.642037047617706, 0.935163257995901],
-    [-1.1228657138962392, 3.7015921866800383, 0.935163257995901],
-    [-1.1920371567855086, 3.3315206710673735, 0.7824989103066552],
-    [-1.354071267128094, 3.269017217520367, 0.7824989103066552],
-    [-1.480278289378033, 3.573707922302893, 0.935163257995901],
-    [-1.3031416928770725, 3.642037047617706, 0.935163257995901],
-    [-1.354071267128094, 3.269017217520367, 0.7824989103066552],
-    [-1.5128433011855313, 3.198638415164263, 0.7824989103066552],
-    [-1.653848765822816, 3.4967694215834086, 0.935163257995901],
-    [-1.480278289378033, 3.573707922302893, 0.935163257995901],
-    [-1.5128433011855313, 3.198638415164263, 0.7824989103066552],
-    [-1.6679707632209912, 3.1205538126983914, 0.7824989103066552],
-    [-1.8234349757306907, 3.4114068970465006, 0.935163257995901],
-    [-1.653848765822816, 3.4967694215834086, 0.935163257995901],
-    [-1.6679707632209912, 3.1205538126983914, 0.7824989103066552],
-    [-1.819079937590866, 3.034951522769498, 0.7824989103066552],
-    [-1.988628371067987, 3.3178259944906543, 0.935163257995901],
-    [-1.8234349757306907, 3.4114068970465006, 0.935163257995901],
-    [-1.819079937590866, 3.034951522769498, 0.7824989103066552],
-    [-1.9658067890585003, 2.9420377687918946, 0.7824989103066552],
-    [-2.1490309864761077, 3.2162521585068453, 0.935163257995901],
-    [-1.988628371067987, 3.3178259944906543, 0.935163257995901],
-    [-1.9658067890585003, 2.9420377687918946, 0.7824989103066552],
-    [-2.1077978397869552, 2.8420363881371977, 0.7824989103066552],
-    [-2.304256398004926, 3.106930089362775, 0.935163257995901],
-    [-2.1490309864761077, 3.2162521585068453, 0.935163257995901],
-    [-2.1077978397869552, 2.8420363881371977, 0.7824989103066552],
-    [-2.244711020898076, 2.735188292890492, 0.7824989103066552],
-    [-2.4539306540419252, 2.990123153498496, 0.935163257995901],
-    [-2.304256398004926, 3.106930089362775, 0.935163257995901],
-    [-2.244711020898076, 2.735188292890492, 0.7824989103066552],
-    [-2.3762164965463892, 2.621750889472012, 0.7824989103066552],
-    [-2.5976931761943987, 2.8661127490535807, 0.935163257995901],
-    [-2.4539306540419252, 2.990123153498496, 0.935163257995901],
-    [-2.3762164965463892, 2.621750889472012, 0.7824989103066552],
-    [-2.5019974585225384, 2.501997458522539, 0.7824989103066552],
-    [-2.7351976279543693, 2.7351976279543697, 0.935163257995901],
-    [-2.5976931761943987, 2.8661127490535807, 0.935163257995901],
-    [-2.5019974585225384, 2.501997458522539, 0.7824989103066552],
-    [-2.6217508894720116, 2.3762164965463897, 0.7824989103066552],
-    [-2.8661127490535803, 2.597693176194399, 0.935163257995901],
-    [-2.7351976279543693, 2.7351976279543697, 0.935163257995901],
-    [-2.6217508894720116, 2.3762164965463897, 0.7824989103066552],
-    [-2.7351882928904914, 2.244711020898076, 0.7824989103066552],
-    [-2.9901231534984953, 2.4539306540419257, 0.935163257995901],
-    [-2.8661127490535803, 2.597693176194399, 0.935163257995901],
-    [-2.7351882928904914, 2.244711020898076, 0.7824989103066552],
-    [-2.8420363881371977, 2.1077978397869557, 0.7824989103066552],
-    [-3.1069300893627747, 2.3042563980049264, 0.935163257995901],
-    [-2.9901231534984953, 2.4539306540419257, 0.935163257995901],
-    [-2.8420363881371977, 2.1077978397869557, 0.7824989103066552],
-    [-2.9420377687918946, 1.9658067890585011, 0.7824989103066552],
-    [-3.2162521585068453, 2.1490309864761086, 0.935163257995901],
-    [-3.1069300893627747, 2.3042563980049264, 0.935163257995901],
-    [-2.9420377687918946, 1.9658067890585011, 0.7824989103066552],
-    [-3.0349515227694974, 1.8190799375908668, 0.7824989103066552],
-    [-3.3178259944906543, 1.988628371067988, 0.935163257995901],
-    [-3.2162521585068453, 2.1490309864761086, 0.935163257995901],
-    [-3.0349515227694974, 1.8190799375908668, 0.7824989103066552],
-    [-3.120553812698391, 1.6679707632209917, 0.7824989103066552],
-    [-3.4114068970465, 1.823434975730691, 0.935163257995901],
-    [-3.3178259944906543, 1.988628371067988, 0.935163257995901],
-    [-3.120553812698391, 1.6679707632209917, 0.7824989103066552],
-    [-3.1986384151642624, 1.512843301185532, 0.7824989103066552],
-    [-3.496769421583408, 1.6538487658228167, 0.935163257995901],
-    [-3.4114068970465, 1.823434975730691, 0.935163257995901],
-    [-3.1986384151642624, 1.512843301185532, 0.7824989103066552],
-    [-3.269017217520367, 1.3540712671280946, 0.7824989103066552],
-    [-3.573707922302893, 1.4802782893780337, 0.935163257995901],
-    [-3.496769421583408, 1.6538487658228167, 0.935163257995901],
-    [-3.269017217520367, 1.3540712671280946, 0.7824989103066552],
-    [-3.3315206710673735, 1.19203715678551, 0.7824989103066552],
-    [-3.6420370476177055, 1.303141692877074, 0.935163257995901],
-    [-3.573707922302893, 1.4802782893780337, 0.935163257995901],
-    [-3.3315206710673735, 1.19203715678551, 0.7824989103066552],
-    [-3.385998199511032, 1.027131324522104, 0.7824989103066552],
-    [-3.701592186680038, 1.1228657138962401, 0.935163257995901],
-    [-3.6420370476177055, 1.303141692877074, 0.935163257995901],
-    [-3.385998199511032, 1.027131324522104, 0.7824989103066552],
-    [-3.432318561713654, 0.8597510429318475, 0.7824989103066552],
-    [-3.7522298659434745, 0.9398846530592112, 0.935163257995901],
-    [-3.701592186680038, 1.1228657138962401, 0.935163257995901],
-    [-3.432318561713654, 0.8597510429318475, 0.7824989103066552],
-    [-3.4703701678655077, 0.6902995457738705, 0.7824989103066552],
-    [-3.793828094803333, 0.75463932776879, 0.935163257995901],
-    [-3.7522298659434745, 0.9398846530592112, 0.935163257995901],
-    [-3.4703701678655077, 0.6902995457738705, 0.7824989103066552],
-    [-3.5000613483144476, 0.5191850565470235, 0.7824989103066552],
-    [-3.826286659482716, 0.5675760102391734, 0.935163257995901],
-    [-3.793828094803333, 0.75463932776879, 0.935163257995901],
-    [-3.5000613483144476, 0.5191850565470235, 0.7824989103066552],
-    [-3.5213205744061304, 0.34681980504371634, 0.7824989103066552],
-    [-3.849527364456275, 0.3791453523871053, 0.935163257995901],
-    [-3.826286659482716, 0.5675760102391734, 0.935163257995901],
-    [-3.5213205744061304, 0.34681980504371634, 0.7824989103066552],
-    [-3.5340966308028148, 0.17361903425223008, 0.7824989103066552],
-    [-3.863494220830085, 0.18980130017192434, 0.935163257995901],
-    [-3.849527364456275, 0.3791453523871053, 0.935163257995901],
-    [-3.5340966308028148, 0.17361903425223008, 0.7824989103066552],
-    [
-        -3.5383587388655893,
-        4.3332397037868093e-16,
-        0.7824989103066552,
-    ],
-    [-3.868153581223788, 4.737121901856084e-16, 0.935163257995901],
-    [-3.863494220830085, 0.18980130017192434, 0.935163257995901],
-    [
-        -3.5383587388655893,
-        4.3332397037868093e-16,
-        0.7824989103066552,
-    ],
-    [
-        -3.5340966308028148,
-        -0.17361903425222922,
-        0.7824989103066552,
-    ],
-    [-3.863494220830085, -0.1898013001719234, 0.935163257995901],
-    [-3.868153581223788, 4.737121901856084e-16, 0.935163257995901],
-    [
-        -3.5340966308028148,
-        -0.17361903425222922,
-        0.7824989103066552,
-    ],
-    [-3.521320574406131, -0.3468198050437155, 0.7824989103066552],
-    [-3.8495273644562755, -0.3791453523871044, 0.935163257995901],
-    [-3.863494220830085, -0.1898013001719234, 0.935163257995901],
-    [-3.521320574406131, -0.3468198050437155, 0.7824989103066552],
-    [-3.5000613483144476, -0.5191850565470227, 0.7824989103066552],
-    [-3.826286659482716, -0.5675760102391725, 0.935163257995901],
-    [-3.8495273644562755, -0.3791453523871044, 0.935163257995901],
-    [-3.5000613483144476, -0.5191850565470227, 0.7824989103066552],
-    [-3.4703701678655077, -0.6902995457738696, 0.7824989103066552],
-    [-3.793828094803333, -0.754639327768789, 0.935163257995901],
-    [-3.826286659482716, -0.5675760102391725, 0.935163257995901],
-    [-3.4703701678655077, -0.6902995457738696, 0.7824989103066552],
-    [-3.432318561713654, -0.8597510429318466, 0.7824989103066552],
-    [-3.7522298659434745, -0.9398846530592102, 0.935163257995901],
-    [-3.793828094803333, -0.754639327768789, 0.935163257995901],
-    [-3.432318561713654, -0.8597510429318466, 0.7824989103066552],
-    [-3.3859981995110324, -1.0271313245221032, 0.7824989103066552],
-    [-3.7015921866800383, -1.1228657138962392, 0.935163257995901],
-    [-3.7522298659434745, -0.9398846530592102, 0.935163257995901],
-    [-3.3859981995110324, -1.0271313245221032, 0.7824989103066552],
-    [-3.3315206710673735, -1.1920371567855093, 0.7824989103066552],
-    [-3.642037047617706, -1.3031416928770732, 0.935163257995901],
-    [-3.7015921866800383, -1.1228657138962392, 0.935163257995901],
-    [-3.3315206710673735, -1.1920371567855093, 0.7824989103066552],
-    [-3.2690172175203673, -1.3540712671280937, 0.7824989103066552],
-    [-3.5737079223028934, -1.4802782893780329, 0.935163257995901],
-    [-3.642037047617706, -1.3031416928770732, 0.935163257995901],
-    [-3.2690172175203673, -1.3540712671280937, 0.7824989103066552],
-    [-3.198638415164263, -1.5128433011855311, 0.7824989103066552],
-    [-3.4967694215834086, -1.6538487658228158, 0.935163257995901],
-    [-3.5737079223028934, -1.4802782893780329, 0.935163257995901],
-    [-3.198638415164263, -1.5128433011855311, 0.7824989103066552],
-    [-3.1205538126983914, -1.667970763220991, 0.7824989103066552],
-    [-3.4114068970465006, -1.8234349757306905, 0.935163257995901],
-    [-3.4967694215834086, -1.6538487658228158, 0.935163257995901],
-    [-3.1205538126983914, -1.667970763220991, 0.7824989103066552],
-    [-3.034951522769498, -1.819079937590866, 0.7824989103066552],
-    [-3.3178259944906543, -1.988628371067987, 0.935163257995901],
-    [-3.4114068970465006, -1.8234349757306905, 0.935163257995901],
-    [-3.034951522769498, -1.819079937590866, 0.7824989103066552],
-    [-2.942037768791895, -1.9658067890585003, 0.7824989103066552],
-    [-3.2162521585068458, -2.1490309864761077, 0.935163257995901],
-    [-3.3178259944906543, -1.988628371067987, 0.935163257995901],
-    [-2.942037768791895, -1.9658067890585003, 0.7824989103066552],
-    [-2.8420363881371977, -2.107797839786955, 0.7824989103066552],
-    [-3.106930089362775, -2.3042563980049255, 0.935163257995901],
-    [-3.2162521585068458, -2.1490309864761077, 0.935163257995901],
-    [-2.8420363881371977, -2.107797839786955, 0.7824989103066552],
-    [-2.735188292890492, -2.2447110208980754, 0.7824989103066552],
-    [-2.990123153498496, -2.453930654041925, 0.935163257995901],
-    [-3.106930089362775, -2.3042563980049255, 0.935163257995901],
-    [-2.735188292890492, -2.2447110208980754, 0.7824989103066552],
-    [-2.621750889472012, -2.3762164965463892, 0.7824989103066552],
-    [-2.866112749053581, -2.5976931761943987, 0.935163257995901],
-    [-2.990123153498496, -2.453930654041925, 0.935163257995901],
-    [-2.621750889472012, -2.3762164965463892, 0.7824989103066552],
-    [-2.501997458522539, -2.5019974585225384, 0.7824989103066552],
-    [-2.73519762795437, -2.7351976279543693, 0.935163257995901],
-    [-2.866112749053581, -2.5976931761943987, 0.935163257995901],
-    [-2.501997458522539, -2.5019974585225384, 0.7824989103066552],
-    [-2.37621649654639, -2.6217508894720116, 0.7824989103066552],
-    [-2.5976931761943995, -2.8661127490535803, 0.935163257995901],
-    [-2.73519762795437, -2.7351976279543693, 0.935163257995901],
-    [-2.37621649654639, -2.6217508894720116, 0.7824989103066552],
-    [-2.2447110208980776, -2.7351882928904905, 0.7824989103066552],
-    [-2.4539306540419275, -2.990123153498494, 0.935163257995901],
-    [-2.5976931761943995, -2.8661127490535803, 0.935163257995901],
-    [-2.2447110208980776, -2.7351882928904905, 0.7824989103066552],
-    [-2.1077978397869543, -2.842036388137198, 0.7824989103066552],
-    [-2.304256398004925, -3.1069300893627756, 0.935163257995901],
-    [-2.4539306540419275, -2.990123153498494, 0.935163257995901],
-    [-2.1077978397869543, -2.842036388137198, 0.7824989103066552],
-    [-1.9658067890585011, -2.9420377687918946, 0.7824989103066552],
-    [-2.1490309864761086, -3.216252158506845, 0.935163257995901],
-    [-2.304256398004925, -3.1069300893627756, 0.935163257995901],
-    [-1.9658067890585011, -2.9420377687918946, 0.7824989103066552],
-    [-1.8190799375908668, -3.0349515227694974, 0.7824989103066552],
-    [-1.988628371067988, -3.3178259944906543, 0.935163257995901],
-    [-2.1490309864761086, -3.216252158506845, 0.935163257995901],
-    [-1.8190799375908668, -3.0349515227694974, 0.7824989103066552],
-    [-1.667970763220992, -3.120553812698391, 0.7824989103066552],
-    [-1.8234349757306911, -3.4114068970465, 0.935163257995901],
-    [-1.988628371067988, -3.3178259944906543, 0.935163257995901],
-    [-1.667970763220992, -3.120553812698391, 0.7824989103066552],
-    [-1.5128433011855336, -3.1986384151642615, 0.7824989103066552],
-    [-1.6538487658228185, -3.4967694215834073, 0.935163257995901],
-    [-1.8234349757306911, -3.4114068970465, 0.935163257995901],
-    [-1.5128433011855336, -3.1986384151642615, 0.7824989103066552],
-    [-1.3540712671280961, -3.269017217520366, 0.7824989103066552],
-    [-1.4802782893780355, -3.573707922302892, 0.935163257995901],
-    [-1.6538487658228185, -3.4967694215834073, 0.935163257995901],
-    [-1.3540712671280961, -3.269017217520366, 0.7824989103066552],
-    [-1.1920371567855086, -3.3315206710673735, 0.7824989103066552],
-    [-1.3031416928770725, -3.642037047617706, 0.935163257995901],
-    [-1.4802782893780355, -3.573707922302892, 0.935163257995901],
-    [-1.1920371567855086, -3.3315206710673735, 0.7824989103066552],
-    [-1.0271313245221043, -3.385998199511032, 0.7824989103066552],
-    [-1.1228657138962403, -3.701592186680038, 0.935163257995901],
-    [-1.3031416928770725, -3.642037047617706, 0.935163257995901],
-    [-1.0271313245221043, -3.385998199511032, 0.7824989103066552],
-    [-0.8597510429318477, -3.432318561713654, 0.7824989103066552],
-    [-0.9398846530592114, -3.7522298659434745, 0.935163257995901],
-    [-1.1228657138962403, -3.701592186680038, 0.935163257995901],
-    [-0.8597510429318477, -3.432318561713654, 0.7824989103066552],
-    [-0.6902995457738708, -3.4703701678655072, 0.7824989103066552],
-    [-0.7546393277687902, -3.7938280948033327, 0.935163257995901],
-    [-0.9398846530592114, -3.7522298659434745, 0.935163257995901],
-    [-0.6902995457738708, -3.4703701678655072, 0.7824989103066552],
-    [-0.5191850565470253, -3.500061348314447, 0.7824989103066552],
-    [-0.5675760102391753, -3.8262866594827156, 0.935163257995901],
-    [-0.7546393277687902, -3.7938280948033327, 0.935163257995901],
-    [-0.5191850565470253, -3.500061348314447, 0.7824989103066552],
-    [-0.346819805043715, -3.521320574406131, 0.7824989103066552],
-    [-0.37914535238710384, -3.8495273644562755, 0.935163257995901],
-    [-0.5675760102391753, -3.8262866594827156, 0.935163257995901],
-    [-0.346819805043715, -3.521320574406131, 0.7824989103066552],
-    [-0.1736190342522303, -3.5340966308028148, 0.7824989103066552],
-    [-0.1898013001719246, -3.863494220830085, 0.935163257995901],
-    [-0.37914535238710384, -3.8495273644562755, 0.935163257995901],
-    [-0.1736190342522303, -3.5340966308028148, 0.7824989103066552],
-    [
-        -6.499859555680213e-16,
-        -3.5383587388655893,
-        0.7824989103066552,
-    ],
-    [
-        -7.105682852784125e-16,
-        -3.868153581223788,
-        0.935163257995901,
-    ],
-    [-0.1898013001719246, -3.863494220830085, 0.935163257995901],
-    [
-        -6.499859555680213e-16,
-        -3.5383587388655893,
-        0.7824989103066552,
-    ],
-    [0.173619034252229, -3.5340966308028148, 0.7824989103066552],
-    [0.18980130017192315, -3.863494220830085, 0.935163257995901],
-    [
-        -7.105682852784125e-16,
-        -3.868153581223788,
-        0.935163257995901,
-    ],
-    [0.173619034252229, -3.5340966308028148, 0.7824989103066552],
-    [0.34681980504371374, -3.521320574406131, 0.7824989103066552],
-    [0.37914535238710245, -3.8495273644562755, 0.935163257995901],
-    [0.18980130017192315, -3.863494220830085, 0.935163257995901],
-    [0.34681980504371374, -3.521320574406131, 0.7824989103066552],
-    [0.5191850565470241, -3.500061348314447, 0.7824989103066552],
-    [0.5675760102391738, -3.8262866594827156, 0.935163257995901],
-    [0.37914535238710245, -3.8495273644562755, 0.935163257995901],
-    [0.5191850565470241, -3.500061348314447, 0.7824989103066552],
-    [0.6902995457738694, -3.4703701678655077, 0.7824989103066552],
-    [0.7546393277687887, -3.793828094803333, 0.935163257995901],
-    [0.5675760102391738, -3.8262866594827156, 0.935163257995901],
-    [0.6902995457738694, -3.4703701678655077, 0.7824989103066552],
-    [0.8597510429318463, -3.432318561713654, 0.7824989103066552],
-    [0.93988465305921, -3.7522298659434745, 0.935163257995901],
-    [0.7546393277687887, -3.793828094803333, 0.935163257995901],
-    [0.8597510429318463, -3.432318561713654, 0.7824989103066552],
-    [1.027131324522103, -3.3859981995110324, 0.7824989103066552],
-    [1.122865713896239, -3.7015921866800383, 0.935163257995901],
-    [0.93988465305921, -3.7522298659434745, 0.935163257995901],
-    [1.027131324522103, -3.3859981995110324, 0.7824989103066552],
-    [1.1920371567855075, -3.331520671067374, 0.7824989103066552],
-    [1.3031416928770712, -3.6420370476177064, 0.935163257995901],
-    [1.122865713896239, -3.7015921866800383, 0.935163257995901],
-    [1.1920371567855075, -3.331520671067374, 0.7824989103066552],
-    [1.354071267128095, -3.2690172175203664, 0.7824989103066552],
-    [1.4802782893780342, -3.5737079223028925, 0.935163257995901],
-    [1.3031416928770712, -3.6420370476177064, 0.935163257995901],
-    [1.354071267128095, -3.2690172175203664, 0.7824989103066552],
-    [1.5128433011855322, -3.1986384151642624, 0.7824989103066552],
-    [1.6538487658228171, -3.496769421583408, 0.935163257995901],
-    [1.4802782893780342, -3.5737079223028925, 0.935163257995901],
-    [1.5128433011855322, -3.1986384151642624, 0.7824989103066552],
-    [1.6679707632209908, -3.1205538126983914, 0.7824989103066552],
-    [1.8234349757306902, -3.4114068970465006, 0.935163257995901],
-    [1.6538487658228171, -3.496769421583408, 0.935163257995901],
-    [1.6679707632209908, -3.1205538126983914, 0.7824989103066552],
-    [1.819079937590866, -3.0349515227694983, 0.7824989103066552],
-    [1.988628371067987, -3.3178259944906547, 0.935163257995901],
-    [1.8234349757306902, -3.4114068970465006, 0.935163257995901],
-    [1.819079937590866, -3.0349515227694983, 0.7824989103066552],
-    [1.9658067890585, -2.942037768791895, 0.7824989103066552],
-    [2.1490309864761072, -3.2162521585068458, 0.935163257995901],
-    [1.988628371067987, -3.3178259944906547, 0.935163257995901],
-    [1.9658067890585, -2.942037768791895, 0.7824989103066552],
-    [2.107797839786953, -2.842036388137199, 0.7824989103066552],
-    [2.3042563980049238, -3.1069300893627765, 0.935163257995901],
-    [2.1490309864761072, -3.2162521585068458, 0.935163257995901],
-    [2.107797839786953, -2.842036388137199, 0.7824989103066552],
-    [2.2447110208980763, -2.735188292890491, 0.7824989103066552],
-    [2.453930654041926, -2.990123153498495, 0.935163257995901],
-    [2.3042563980049238, -3.1069300893627765, 0.935163257995901],
-    [2.2447110208980763, -2.735188292890491, 0.7824989103066552],
-    [2.376216496546389, -2.621750889472012, 0.7824989103066552],
-    [2.597693176194398, -2.866112749053581, 0.935163257995901],
-    [2.453930654041926, -2.990123153498495, 0.935163257995901],
-    [2.376216496546389, -2.621750889472012, 0.7824989103066552],
-    [2.501997458522538, -2.501997458522539, 0.7824989103066552],
-    [2.735197627954369, -2.73519762795437, 0.935163257995901],
-    [2.597693176194398, -2.866112749053581, 0.935163257995901],
-    [2.501997458522538, -2.501997458522539, 0.7824989103066552],
-    [2.6217508894720116, -2.37621649654639, 0.7824989103066552],
-    [2.8661127490535803, -2.5976931761943995, 0.935163257995901],
-    [2.735197627954369, -2.73519762795437, 0.935163257995901],
-    [2.6217508894720116, -2.37621649654639, 0.7824989103066552],
-    [2.73518829289049, -2.2447110208980776, 0.7824989103066552],
-    [2.9901231534984936, -2.4539306540419275, 0.935163257995901],
-    [2.8661127490535803, -2.5976931761943995, 0.935163257995901],
-    [2.73518829289049, -2.2447110208980776, 0.7824989103066552],
-    [2.842036388137198, -2.107797839786955, 0.7824989103066552],
-    [3.1069300893627756, -2.3042563980049255, 0.935163257995901],
-    [2.9901231534984936, -2.4539306540419275, 0.935163257995901],
-    [2.842036388137198, -2.107797839786955, 0.7824989103066552],
-    [2.9420377687918946, -1.9658067890585011, 0.7824989103066552],
-    [3.216252158506845, -2.1490309864761086, 0.935163257995901],
-    [3.1069300893627756, -2.3042563980049255, 0.935163257995901],
-    [2.9420377687918946, -1.9658067890585011, 0.7824989103066552],
-    [3.0349515227694974, -1.8190799375908673, 0.7824989103066552],
-    [3.3178259944906543, -1.9886283710679884, 0.935163257995901],
-    [3.216252158506845, -2.1490309864761086, 0.935163257995901],
-    [3.0349515227694974, -1.8190799375908673, 0.7824989103066552],
-    [3.120553812698391, -1.6679707632209921, 0.7824989103066552],
-    [3.4114068970464997, -1.8234349757306914, 0.935163257995901],
-    [3.3178259944906543, -1.9886283710679884, 0.935163257995901],
-    [3.120553812698391, -1.6679707632209921, 0.7824989103066552],
-    [3.1986384151642615, -1.5128433011855338, 0.7824989103066552],
-    [3.4967694215834073, -1.6538487658228187, 0.935163257995901],
-    [3.4114068970464997, -1.8234349757306914, 0.935163257995901],
-    [3.1986384151642615, -1.5128433011855338, 0.7824989103066552],
-    [3.269017217520366, -1.3540712671280963, 0.7824989103066552],
-    [3.573707922302892, -1.4802782893780357, 0.935163257995901],
-    [3.4967694215834073, -1.6538487658228187, 0.935163257995901],
-    [3.269017217520366, -1.3540712671280963, 0.7824989103066552],
-    [3.3315206710673735, -1.1920371567855088, 0.7824989103066552],
-    [3.642037047617706, -1.3031416928770727, 0.935163257995901],
-    [3.573707922302892, -1.4802782893780357, 0.935163257995901],
-    [3.3315206710673735, -1.1920371567855088, 0.7824989103066552],
-    [3.385998199511032, -1.0271313245221045, 0.7824989103066552],
-    [3.701592186680038, -1.1228657138962406, 0.935163257995901],
-    [3.642037047617706, -1.3031416928770727, 0.935163257995901],
-    [3.385998199511032, -1.0271313245221045, 0.7824989103066552],
-    [3.432318561713654, -0.8597510429318478, 0.7824989103066552],
-    [3.7522298659434745, -0.9398846530592116, 0.935163257995901],
-    [3.701592186680038, -1.1228657138962406, 0.935163257995901],
-    [3.432318561713654, -0.8597510429318478, 0.7824989103066552],
-    [3.4703701678655072, -0.6902995457738709, 0.7824989103066552],
-    [3.7938280948033327, -0.7546393277687904, 0.935163257995901],
-    [3.7522298659434745, -0.9398846530592116, 0.935163257995901],
-    [3.4703701678655072, -0.6902995457738709, 0.7824989103066552],
-    [3.500061348314447, -0.5191850565470256, 0.7824989103066552],
-    [3.8262866594827156, -0.5675760102391756, 0.935163257995901],
-    [3.7938280948033327, -0.7546393277687904, 0.935163257995901],
-    [3.500061348314447, -0.5191850565470256, 0.7824989103066552],
-    [3.521320574406131, -0.3468198050437152, 0.7824989103066552],
-    [3.8495273644562755, -0.37914535238710406, 0.935163257995901],
-    [3.8262866594827156, -0.5675760102391756, 0.935163257995901],
-    [3.521320574406131, -0.3468198050437152, 0.7824989103066552],
-    [3.5340966308028148, -0.17361903425223052, 0.7824989103066552],
-    [3.863494220830085, -0.1898013001719248, 0.935163257995901],
-    [3.8495273644562755, -0.37914535238710406, 0.935163257995901],
-    [3.5340966308028148, -0.17361903425223052, 0.7824989103066552],
-    [
-        3.5383587388655893,
-        -8.666479407573619e-16,
-        0.7824989103066552,
-    ],
-    [3.868153581223788, -9.474243803712168e-16, 0.935163257995901],
-    [3.863494220830085, -0.1898013001719248, 0.935163257995901],
-    [3.868153581223788, 0.0, 0.935163257995901],
-    [3.863494220830085, 0.18980130017192454, 0.935163257995901],
-    [4.192891810857355, 0.20598356609161877, 1.1014231854409053],
-    [4.197948423581987, 0.0, 1.1014231854409053],
-    [3.863494220830085, 0.18980130017192454, 0.935163257995901],
-    [3.8495273644562755, 0.37914535238710445, 0.935163257995901],
-    [4.177734154506419, 0.4114708997304933, 1.1014231854409053],
-    [4.192891810857355, 0.20598356609161877, 1.1014231854409053],
-    [3.8495273644562755, 0.37914535238710445, 0.935163257995901],
-    [3.826286659482716, 0.5675760102391731, 0.935163257995901],
-    [4.152511970650984, 0.6159669639313228, 1.1014231854409053],
-    [4.177734154506419, 0.4114708997304933, 1.1014231854409053],
-    [3.826286659482716, 0.5675760102391731, 0.935163257995901],
-    [3.793828094803333, 0.7546393277687885, 0.935163257995901],
-    [4.117286021741158, 0.8189791097637077, 1.1014231854409053],
-    [4.152511970650984, 0.6159669639313228, 1.1014231854409053],
-    [3.793828094803333, 0.7546393277687885, 0.935163257995901],
-    [3.7522298659434745, 0.9398846530592104, 0.935163257995901],
-    [4.072141170173294, 1.0200182631865742, 1.1014231854409053],
-    [4.117286021741158, 0.8189791097637077, 1.1014231854409053],
-    [3.7522298659434745, 0.9398846530592104, 0.935163257995901],
-    [3.701592186680038, 1.12286571389624, 0.935163257995901],
-    [4.017186173849043, 1.218600103270376, 1.1014231854409053],
-    [4.072141170173294, 1.0200182631865742, 1.1014231854409053],
-    [3.701592186680038, 1.12286571389624, 0.935163257995901],
-    [3.642037047617706, 1.303141692877073, 0.935163257995901],
-    [3.9525534241680376, 1.4142462289686368, 1.1014231854409053],
-    [4.017186173849043, 1.218600103270376, 1.1014231854409053],
-    [3.642037047617706, 1.303141692877073, 0.935163257995901],
-    [3.573707922302893, 1.4802782893780333, 0.935163257995901],
-    [3.878398627085419, 1.6064853116279725, 1.1014231854409053],
-    [3.9525534241680376, 1.4142462289686368, 1.1014231854409053],
-    [3.573707922302893, 1.4802782893780333, 0.935163257995901],
-    [3.496769421583408, 1.653848765822817, 0.935163257995901],
-    [3.7949004280025536, 1.7948542304601016, 1.1014231854409053],
-    [3.878398627085419, 1.6064853116279725, 1.1014231854409053],
-    [3.496769421583408, 1.653848765822817, 0.935163257995901],
-    [3.4114068970465006, 1.8234349757306905, 0.935163257995901],
-    [3.7022599813946098, 1.9788991882403895, 1.1014231854409053],
-    [3.7949004280025536, 1.7948542304601016, 1.1014231854409053],
-    [3.4114068970465006, 1.8234349757306905, 0.935163257995901],
-    [3.3178259944906543, 1.9886283710679875, 0.935163257995901],
-    [3.6007004662118107, 2.1581768045451084, 1.1014231854409053],
-    [3.7022599813946098, 1.9788991882403895, 1.1014231854409053],
-    [3.3178259944906543, 1.9886283710679875, 0.935163257995901],
-    [3.216252158506845, 2.1490309864761086, 0.935163257995901],
-    [3.490466548221795, 2.3322551838937158, 1.1014231854409053],
-    [3.6007004662118107, 2.1581768045451084, 1.1014231854409053],
-    [3.216252158506845, 2.1490309864761086, 0.935163257995901],
-    [3.106930089362775, 2.304256398004926, 0.935163257995901],
-    [3.371823790588352, 2.5007149562228963, 1.1014231854409053],
-    [3.490466548221795, 2.3322551838937158, 1.1014231854409053],
-    [3.106930089362775, 2.304256398004926, 0.935163257995901],
-    [2.9901231534984953, 2.4539306540419257, 0.935163257995901],
-    [3.2450580141064984, 2.663150287185775, 1.1014231854409053],
-    [3.371823790588352, 2.5007149562228963, 1.1014231854409053],
-    [2.9901231534984953, 2.4539306540419257, 0.935163257995901],
-    [2.8661127490535816, 2.597693176194398, 0.935163257995901],
-    [3.11047460863515, 2.819169855842407, 1.1014231854409053],
-    [3.2450580141064984, 2.663150287185775, 1.1014231854409053],
-    [2.8661127490535816, 2.597693176194398, 0.935163257995901],
-    [2.7351976279543697, 2.7351976279543697, 0.935163257995901],
-    [2.9683977973862, 2.9683977973862, 1.1014231854409053],
-    [3.11047460863515, 2.819169855842407, 1.1014231854409053],
-    [2.7351976279543697, 2.7351976279543697, 0.935163257995901],
-    [2.597693176194398, 2.866112749053581, 0.935163257995901],
-    [2.819169855842407, 3.1104746086351494, 1.1014231854409053],
-    [2.9683977973862, 2.9683977973862, 1.1014231854409053],
-    [2.597693176194398, 2.866112749053581, 0.935163257995901],
-    [2.4539306540419257, 2.990123153498495, 0.935163257995901],
-    [2.663150287185775, 3.245058014106498, 1.1014231854409053],
-    [2.819169855842407, 3.1104746086351494, 1.1014231854409053],
-    [2.4539306540419257, 2.990123153498495, 0.935163257995901],
-    [2.3042563980049264, 3.1069300893627747, 0.935163257995901],
-    [2.5007149562228967, 3.3718237905883517, 1.1014231854409053],
-    [2.663150287185775, 3.245058014106498, 1.1014231854409053],
-    [2.3042563980049264, 3.1069300893627747, 0.935163257995901],
-    [2.149030986476109, 3.216252158506845, 0.935163257995901],
-    [2.332255183893716, 3.490466548221795, 1.1014231854409053],
-    [2.5007149562228967, 3.3718237905883517, 1.1014231854409053],
-    [2.149030986476109, 3.216252158506845, 0.935163257995901],
-    [1.9886283710679875, 3.3178259944906543, 0.935163257995901],
-    [2.1581768045451084, 3.6007004662118107, 1.1014231854409053],
-    [2.332255183893716, 3.490466548221795, 1.1014231854409053],
-    [1.9886283710679875, 3.3178259944906543, 0.935163257995901],
-    [1.823434975730691, 3.4114068970465, 0.935163257995901],
-    [1.9788991882403901, 3.7022599813946093, 1.1014231854409053],
-    [2.1581768045451084, 3.6007004662118107, 1.1014231854409053],
-    [1.823434975730691, 3.4114068970465, 0.935163257995901],
-    [1.6538487658228174, 3.496769421583408, 0.935163257995901],
-    [1.794854230460102, 3.7949004280025536, 1.1014231854409053],
-    [1.9788991882403901, 3.7022599813946093, 1.1014231854409053],
-    [1.6538487658228174, 3.496769421583408, 0.935163257995901],
-    [1.4802782893780335, 3.573707922302893, 0.935163257995901],
-    [1.6064853116279727, 3.878398627085419, 1.1014231854409053],
-    [1.794854230460102, 3.7949004280025536, 1.1014231854409053],
-    [1.4802782893780335, 3.573707922302893, 0.935163257995901],
-    [1.303141692877073, 3.642037047617706, 0.935163257995901],
-    [1.4142462289686368, 3.9525534241680376, 1.1014231854409053],
-    [1.6064853116279727, 3.878398627085419, 1.1014231854409053],
-    [1.303141692877073, 3.642037047617706, 0.935163257995901],
-    [1.12286571389624, 3.7015921866800383, 0.935163257995901],
-    [1.218600103270376, 4.017186173849043, 1.1014231854409053],
-    [1.4142462289686368, 3.9525534241680376, 1.1014231854409053],
-    [1.12286571389624, 3.7015921866800383, 0.935163257995901],
-    [0.9398846530592109, 3.7522298659434745, 0.935163257995901],
-    [1.0200182631865746, 4.072141170173294, 1.1014231854409053],
-    [1.218600103270376, 4.017186173849043, 1.1014231854409053],
-    [0.9398846530592109, 3.7522298659434745, 0.935163257995901],
-    [0.7546393277687888, 3.793828094803333, 0.935163257995901],
-    [0.818979109763708, 4.117286021741158, 1.1014231854409053],
-    [1.0200182631865746, 4.072141170173294, 1.1014231854409053],
-    [0.7546393277687888, 3.793828094803333, 0.935163257995901],
-    [0.5675760102391731, 3.826286659482716, 0.935163257995901],
-    [0.6159669639313228, 4.152511970650984, 1.1014231854409053],
-    [0.818979109763708, 4.117286021741158, 1.1014231854409053],
-    [0.5675760102391731, 3.826286659482716, 0.935163257995901],
-    [0.37914535238710506, 3.849527364456275, 0.935163257995901],
-    [0.411470899730494, 4.177734154506419, 1.1014231854409053],
-    [0.6159669639313228, 4.152511970650984, 1.1014231854409053],
-    [0.37914535238710506, 3.849527364456275, 0.935163257995901],
-    [0.18980130017192495, 3.863494220830085, 0.935163257995901],
-    [0.20598356609161925, 4.192891810857355, 1.1014231854409053],
-    [0.411470899730494, 4.177734154506419, 1.1014231854409053],
-    [0.18980130017192495, 3.863494220830085, 0.935163257995901],
-    [2.368560950928042e-16, 3.868153581223788, 0.935163257995901],
-    [
-        2.5705020499626787e-16,
-        4.197948423581987,
-        1.1014231854409053,
-    ],
-    [0.20598356609161925, 4.192891810857355, 1.1014231854409053],
-    [2.368560950928042e-16, 3.868153581223788, 0.935163257995901],
-    [-0.1898013001719245, 3.863494220830085, 0.935163257995901],
-    [-0.20598356609161875, 4.192891810857355, 1.1014231854409053],
-    [
-        2.5705020499626787e-16,
-        4.197948423581987,
-        1.1014231854409053,
-    ],
-    [-0.1898013001719245, 3.863494220830085, 0.935163257995901],
-    [-0.3791453523871046, 3.8495273644562755, 0.935163257995901],
-    [-0.4114708997304935, 4.177734154506419, 1.1014231854409053],
-    [-0.20598356609161875, 4.192891810857355, 1.1014231854409053],
-    [-0.3791453523871046, 3.8495273644562755, 0.935163257995901],
-    [-0.5675760102391727, 3.826286659482716, 0.935163257995901],
-    [-0.6159669639313223, 4.152511970650984, 1.1014231854409053],
-    [-0.4114708997304935, 4.177734154506419, 1.1014231854409053],
-    [-0.5675760102391727, 3.826286659482716, 0.935163257995901],
-    [-0.7546393277687883, 3.793828094803333, 0.935163257995901],
-    [-0.8189791097637075, 4.117286021741158, 1.1014231854409053],
-    [-0.6159669639313223, 4.152511970650984, 1.1014231854409053],
-    [-0.7546393277687883, 3.793828094803333, 0.935163257995901],
-    [-0.9398846530592104, 3.7522298659434745, 0.935163257995901],
-    [-1.0200182631865742, 4.072141170173294, 1.1014231854409053],
-    [-0.8189791097637075, 4.117286021741158, 1.1014231854409053],
-    [-0.9398846530592104, 3.7522298659434745, 0.935163257995901],
-    [-1.1228657138962392, 3.7015921866800383, 0.935163257995901],
-    [-1.2186001032703753, 4.017186173849043, 1.1014231854409053],
-    [-1.0200182631865742, 4.072141170173294, 1.1014231854409053],
-    [-1.1228657138962392, 3.7015921866800383, 0.935163257995901],
-    [-1.3031416928770725, 3.642037047617706, 0.935163257995901],
-    [-1.4142462289686364, 3.9525534241680376, 1.1014231854409053],
-    [-1.2186001032703753, 4.017186173849043, 1.1014231854409053],
-    [-1.3031416928770725, 3.642037047617706, 0.935163257995901],
-    [-1.480278289378033, 3.573707922302893, 0.935163257995901],
-    [-1.6064853116279723, 3.878398627085419, 1.1014231854409053],
-    [-1.4142462289686364, 3.9525534241680376, 1.1014231854409053],
-    [-1.480278289378033, 3.573707922302893, 0.935163257995901],
-    [-1.653848765822816, 3.4967694215834086, 0.935163257995901],
-    [-1.7948542304601007, 3.794900428002554, 1.1014231854409053],
-    [-1.6064853116279723, 3.878398627085419, 1.1014231854409053],
-    [-1.653848765822816, 3.4967694215834086, 0.935163257995901],
-    [-1.8234349757306907, 3.4114068970465006, 0.935163257995901],
-    [-1.9788991882403897, 3.7022599813946098, 1.1014231854409053],
-    [-1.7948542304601007, 3.794900428002554, 1.1014231854409053],
-    [-1.8234349757306907, 3.4114068970465006, 0.935163257995901],
-    [-1.988628371067987, 3.3178259944906543, 0.935163257995901],
-    [-2.1581768045451075, 3.6007004662118107, 1.1014231854409053],
-    [-1.9788991882403897, 3.7022599813946098, 1.1014231854409053],
-    [-1.988628371067987, 3.3178259944906543, 0.935163257995901],
-    [-2.1490309864761077, 3.2162521585068453, 0.935163257995901],
-    [-2.332255183893715, 3.490466548221796, 1.1014231854409053],
-    [-2.1581768045451075, 3.6007004662118107, 1.1014231854409053],
-    [-2.1490309864761077, 3.2162521585068453, 0.935163257995901],
-    [-2.304256398004926, 3.106930089362775, 0.935163257995901],
-    [-2.5007149562228963, 3.371823790588352, 1.1014231854409053],
-    [-2.332255183893715, 3.490466548221796, 1.1014231854409053],
-    [-2.304256398004926, 3.106930089362775, 0.935163257995901],
-    [-2.4539306540419252, 2.990123153498496, 0.935163257995901],
-    [-2.663150287185774, 3.245058014106499, 1.1014231854409053],
-    [-2.5007149562228963, 3.371823790588352, 1.1014231854409053],
-    [-2.4539306540419252, 2.990123153498496, 0.935163257995901],
-    [-2.5976931761943987, 2.8661127490535807, 0.935163257995901],
-    [-2.8191698558424076, 3.110474608635149, 1.1014231854409053],
-    [-2.663150287185774, 3.245058014106499, 1.1014231854409053],
-    [-2.5976931761943987, 2.8661127490535807, 0.935163257995901],
-    [-2.7351976279543693, 2.7351976279543697, 0.935163257995901],
-    [-2.9683977973861997, 2.9683977973862, 1.1014231854409053],
-    [-2.8191698558424076, 3.110474608635149, 1.1014231854409053],
-    [-2.7351976279543693, 2.7351976279543697, 0.935163257995901],
-    [-2.8661127490535803, 2.597693176194399, 0.935163257995901],
-    [-3.1104746086351485, 2.819169855842408, 1.1014231854409053],
-    [-2.9683977973861997, 2.9683977973862, 1.1014231854409053],
-    [-2.8661127490535803, 2.597693176194399, 0.935163257995901],
-    [-2.9901231534984953, 2.4539306540419257, 0.935163257995901],
-    [-3.2450580141064984, 2.663150287185775, 1.1014231854409053],
-    [-3.1104746086351485, 2.819169855842408, 1.1014231854409053],
-    [-2.9901231534984953, 2.4539306540419257, 0.935163257995901],
-    [-3.1069300893627747, 2.3042563980049264, 0.935163257995901],
-    [-3.3718237905883517, 2.5007149562228967, 1.1014231854409053],
-    [-3.2450580141064984, 2.663150287185775, 1.1014231854409053],
-    [-3.1069300893627747, 2.3042563980049264, 0.935163257995901],
-    [-3.2162521585068453, 2.1490309864761086, 0.935163257995901],
-    [-3.490466548221796, 2.3322551838937158, 1.1014231854409053],
-    [-3.3718237905883517, 2.5007149562228967, 1.1014231854409053],
-    [-3.2162521585068453, 2.1490309864761086, 0.935163257995901],
-    [-3.3178259944906543, 1.988628371067988, 0.935163257995901],
-    [-3.6007004662118103, 2.158176804545109, 1.1014231854409053],
-    [-3.490466548221796, 2.3322551838937158, 1.1014231854409053],
-    [-3.3178259944906543, 1.988628371067988, 0.935163257995901],
-    [-3.4114068970465, 1.823434975730691, 0.935163257995901],
-    [-3.7022599813946093, 1.9788991882403901, 1.1014231854409053],
-    [-3.6007004662118103, 2.158176804545109, 1.1014231854409053],
-    [-3.4114068970465, 1.823434975730691, 0.935163257995901],
-    [-3.496769421583408, 1.6538487658228167, 0.935163257995901],
-    [-3.7949004280025536, 1.7948542304601014, 1.1014231854409053],
-    [-3.7022599813946093, 1.9788991882403901, 1.1014231854409053],
-    [-3.496769421583408, 1.6538487658228167, 0.935163257995901],
-    [-3.573707922302893, 1.4802782893780337, 0.935163257995901],
-    [-3.878398627085419, 1.606485311627973, 1.1014231854409053],
-    [-3.7949004280025536, 1.7948542304601014, 1.1014231854409053],
-    [-3.573707922302893, 1.4802782893780337, 0.935163257995901],
-    [-3.6420370476177055, 1.303141692877074, 0.935163257995901],
-    [-3.952553424168037, 1.414246228968638, 1.1014231854409053],
-    [-3.878398627085419, 1.606485311627973, 1.1014231854409053],
-    [-3.6420370476177055, 1.303141692877074, 0.935163257995901],
-    [-3.701592186680038, 1.1228657138962401, 0.935163257995901],
-    [-4.017186173849043, 1.2186001032703762, 1.1014231854409053],
-    [-3.952553424168037, 1.414246228968638, 1.1014231854409053],
-    [-3.701592186680038, 1.1228657138962401, 0.935163257995901],
-    [-3.7522298659434745, 0.9398846530592112, 0.935163257995901],
-    [-4.072141170173294, 1.0200182631865748, 1.1014231854409053],
-    [-4.017186173849043, 1.2186001032703762, 1.1014231854409053],
-    [-3.7522298659434745, 0.9398846530592112, 0.935163257995901],
-    [-3.793828094803333, 0.75463932776879, 0.935163257995901],
-    [-4.117286021741158, 0.8189791097637092, 1.1014231854409053],
-    [-4.072141170173294, 1.0200182631865748, 1.1014231854409053],
-    [-3.793828094803333, 0.75463932776879, 0.935163257995901],
-    [-3.826286659482716, 0.5675760102391734, 0.935163257995901],
-    [-4.152511970650984, 0.615966963931323, 1.1014231854409053],
-    [-4.117286021741158, 0.8189791097637092, 1.1014231854409053],
-    [-3.826286659482716, 0.5675760102391734, 0.935163257995901],
-    [-3.849527364456275, 0.3791453523871053, 0.935163257995901],
-    [-4.177734154506419, 0.4114708997304942, 1.1014231854409053],
-    [-4.152511970650984, 0.615966963931323, 1.1014231854409053],
-    [-3.849527364456275, 0.3791453523871053, 0.935163257995901],
-    [-3.863494220830085, 0.18980130017192434, 0.935163257995901],
-    [-4.192891810857355, 0.20598356609161858, 1.1014231854409053],
-    [-4.177734154506419, 0.4114708997304942, 1.1014231854409053],
-    [-3.863494220830085, 0.18980130017192434, 0.935163257995901],
-    [-3.868153581223788, 4.737121901856084e-16, 0.935163257995901],
-    [
-        -4.197948423581987,
-        5.141004099925357e-16,
-        1.1014231854409053,
-    ],
-    [-4.192891810857355, 0.20598356609161858, 1.1014231854409053],
-    [-3.868153581223788, 4.737121901856084e-16, 0.935163257995901],
-    [-3.863494220830085, -0.1898013001719234, 0.935163257995901],
-    [-4.192891810857355, -0.20598356609161755, 1.1014231854409053],
-    [
-        -4.197948423581987,
-        5.141004099925357e-16,
-        1.1014231854409053,
-    ],
-    [-3.863494220830085, -0.1898013001719234, 0.935163257995901],
-    [-3.8495273644562755, -0.3791453523871044, 0.935163257995901],
-    [-4.177734154506419, -0.4114708997304932, 1.1014231854409053],
-    [-4.192891810857355, -0.20598356609161755, 1.1014231854409053],
-    [-3.8495273644562755, -0.3791453523871044, 0.935163257995901],
-    [-3.826286659482716, -0.5675760102391725, 0.935163257995901],
-    [-4.152511970650984, -0.6159669639313221, 1.1014231854409053],
-    [-4.177734154506419, -0.4114708997304932, 1.1014231854409053],
-    [-3.826286659482716, -0.5675760102391725, 0.935163257995901],
-    [-3.793828094803333, -0.754639327768789, 0.935163257995901],
-    [-4.117286021741158, -0.8189791097637081, 1.1014231854409053],
-    [-4.152511970650984, -0.6159669639313221, 1.1014231854409053],
-    [-3.793828094803333, -0.754639327768789, 0.935163257995901],
-    [-3.7522298659434745, -0.9398846530592102, 0.935163257995901],
-    [-4.072141170173294, -1.0200182631865737, 1.1014231854409053],
-    [-4.117286021741158, -0.8189791097637081, 1.1014231854409053],
-    [-3.7522298659434745, -0.9398846530592102, 0.935163257995901],
-    [-3.7015921866800383, -1.1228657138962392, 0.935163257995901],
-    [-4.017186173849043, -1.218600103270375, 1.1014231854409053],
-    [-4.072141170173294, -1.0200182631865737, 1.1014231854409053],
-    [-3.7015921866800383, -1.1228657138962392, 0.935163257995901],
-    [-3.642037047617706, -1.3031416928770732, 0.935163257995901],
-    [-3.9525534241680376, -1.414246228968637, 1.1014231854409053],
-    [-4.017186173849043, -1.218600103270375, 1.1014231854409053],
-    [-3.642037047617706, -1.3031416928770732, 0.935163257995901],
-    [-3.5737079223028934, -1.4802782893780329, 0.935163257995901],
-    [-3.8783986270854194, -1.606485311627972, 1.1014231854409053],
-    [-3.9525534241680376, -1.414246228968637, 1.1014231854409053],
-    [-3.5737079223028934, -1.4802782893780329, 0.935163257995901],
-    [-3.4967694215834086, -1.6538487658228158, 0.935163257995901],
-    [-3.794900428002554, -1.7948542304601005, 1.1014231854409053],
-    [-3.8783986270854194, -1.606485311627972, 1.1014231854409053],
-    [-3.4967694215834086, -1.6538487658228158, 0.935163257995901],
-    [-3.4114068970465006, -1.8234349757306905, 0.935163257995901],
-    [-3.7022599813946098, -1.9788991882403895, 1.1014231854409053],
-    [-3.794900428002554, -1.7948542304601005, 1.1014231854409053],
-    [-3.4114068970465006, -1.8234349757306905, 0.935163257995901],
-    [-3.3178259944906543, -1.988628371067987, 0.935163257995901],
-    [-3.6007004662118107, -2.1581768045451075, 1.1014231854409053],
-    [-3.7022599813946098, -1.9788991882403895, 1.1014231854409053],
-    [-3.3178259944906543, -1.988628371067987, 0.935163257995901],
-    [-3.2162521585068458, -2.1490309864761077, 0.935163257995901],
-    [-3.4904665482217965, -2.332255183893715, 1.1014231854409053],
-    [-3.6007004662118107, -2.1581768045451075, 1.1014231854409053],
-    [-3.2162521585068458, -2.1490309864761077, 0.935163257995901],
-    [-3.106930089362775, -2.3042563980049255, 0.935163257995901],
-    [-3.371823790588352, -2.500714956222896, 1.1014231854409053],
-    [-3.4904665482217965, -2.332255183893715, 1.1014231854409053],
-    [-3.106930089362775, -2.3042563980049255, 0.935163257995901],
-    [-2.990123153498496, -2.453930654041925, 0.935163257995901],
-    [-3.245058014106499, -2.6631502871857737, 1.1014231854409053],
-    [-3.371823790588352, -2.500714956222896, 1.1014231854409053],
-    [-2.990123153498496, -2.453930654041925, 0.935163257995901],
-    [-2.866112749053581, -2.5976931761943987, 0.935163257995901],
-    [-3.1104746086351494, -2.8191698558424076, 1.1014231854409053],
-    [-3.245058014106499, -2.6631502871857737, 1.1014231854409053],
-    [-2.866112749053581, -2.5976931761943987, 0.935163257995901],
-    [-2.73519762795437, -2.7351976279543693, 0.935163257995901],
-    [-2.9683977973862006, -2.9683977973861997, 1.1014231854409053],
-    [-3.1104746086351494, -2.8191698558424076, 1.1014231854409053],
-    [-2.73519762795437, -2.7351976279543693, 0.935163257995901],
-    [-2.5976931761943995, -2.8661127490535803, 0.935163257995901],
-    [-2.8191698558424085, -3.1104746086351485, 1.1014231854409053],
-    [-2.9683977973862006, -2.9683977973861997, 1.1014231854409053],
-    [-2.5976931761943995, -2.8661127490535803, 0.935163257995901],
-    [-2.4539306540419275, -2.990123153498494, 0.935163257995901],
-    [-2.663150287185777, -3.245058014106497, 1.1014231854409053],
-    [-2.8191698558424085, -3.1104746086351485, 1.1014231854409053],
-    [-2.4539306540419275, -2.990123153498494, 0.935163257995901],
-    [-2.304256398004925, -3.1069300893627756, 0.935163257995901],
-    [-2.5007149562228954, -3.3718237905883526, 1.1014231854409053],
-    [-2.663150287185777, -3.245058014106497, 1.1014231854409053],
-    [-2.304256398004925, -3.1069300893627756, 0.935163257995901],
-    [-2.1490309864761086, -3.216252158506845, 0.935163257995901],
-    [-2.3322551838937158, -3.490466548221795, 1.1014231854409053],
-    [-2.5007149562228954, -3.3718237905883526, 1.1014231854409053],
-    [-2.1490309864761086, -3.216252158506845, 0.935163257995901],
-    [-1.988628371067988, -3.3178259944906543, 0.935163257995901],
-    [-2.158176804545109, -3.6007004662118103, 1.1014231854409053],
-    [-2.3322551838937158, -3.490466548221795, 1.1014231854409053],
-    [-1.988628371067988, -3.3178259944906543, 0.935163257995901],
-    [-1.8234349757306911, -3.4114068970465, 0.935163257995901],
-    [-1.9788991882403903, -3.7022599813946093, 1.1014231854409053],
-    [-2.158176804545109, -3.6007004662118103, 1.1014231854409053],
-    [-1.8234349757306911, -3.4114068970465, 0.935163257995901],
-    [-1.6538487658228185, -3.4967694215834073, 0.935163257995901],
-    [-1.7948542304601034, -3.7949004280025522, 1.1014231854409053],
-    [-1.9788991882403903, -3.7022599813946093, 1.1014231854409053],
-    [-1.6538487658228185, -3.4967694215834073, 0.935163257995901],
-    [-1.4802782893780355, -3.573707922302892, 0.935163257995901],
-    [-1.6064853116279747, -3.878398627085418, 1.1014231854409053],
-    [-1.7948542304601034, -3.7949004280025522, 1.1014231854409053],
-    [-1.4802782893780355, -3.573707922302892, 0.935163257995901],
-    [-1.3031416928770725, -3.642037047617706, 0.935163257995901],
-    [-1.4142462289686364, -3.9525534241680376, 1.1014231854409053],
-    [-1.6064853116279747, -3.878398627085418, 1.1014231854409053],
-    [-1.3031416928770725, -3.642037047617706, 0.935163257995901],
-    [-1.1228657138962403, -3.701592186680038, 0.935163257995901],
-    [-1.2186001032703764, -4.017186173849043, 1.1014231854409053],
-    [-1.4142462289686364, -3.9525534241680376, 1.1014231854409053],
-    [-1.1228657138962403, -3.701592186680038, 0.935163257995901],
-    [-0.9398846530592114, -3.7522298659434745, 0.935163257995901],
-    [-1.020018263186575, -4.072141170173294, 1.1014231854409053],
-    [-1.2186001032703764, -4.017186173849043, 1.1014231854409053],
-    [-0.9398846530592114, -3.7522298659434745, 0.935163257995901],
-    [-0.7546393277687902, -3.7938280948033327, 0.935163257995901],
-    [-0.8189791097637095, -4.117286021741157, 1.1014231854409053],
-    [-1.020018263186575, -4.072141170173294, 1.1014231854409053],
-    [-0.7546393277687902, -3.7938280948033327, 0.935163257995901],
-    [-0.5675760102391753, -3.8262866594827156, 0.935163257995901],
-    [-0.6159669639313251, -4.152511970650983, 1.1014231854409053],
-    [-0.8189791097637095, -4.117286021741157, 1.1014231854409053],
-    [-0.5675760102391753, -3.8262866594827156, 0.935163257995901],
-    [-0.37914535238710384, -3.8495273644562755, 0.935163257995901],
-    [-0.41147089973049267, -4.177734154506419, 1.1014231854409053],
-    [-0.6159669639313251, -4.152511970650983, 1.1014231854409053],
-    [-0.37914535238710384, -3.8495273644562755, 0.935163257995901],
-    [-0.1898013001719246, -3.863494220830085, 0.935163257995901],
-    [-0.20598356609161883, -4.192891810857355, 1.1014231854409053],
-    [-0.41147089973049267, -4.177734154506419, 1.1014231854409053],
-    [-0.1898013001719246, -3.863494220830085, 0.935163257995901],
-    [
-        -7.105682852784125e-16,
-        -3.868153581223788,
-        0.935163257995901,
-    ],
-    [
-        -7.711506149888035e-16,
-        -4.197948423581987,
-        1.1014231854409053,
-    ],
-    [-0.20598356609161883, -4.192891810857355, 1.1014231854409053],
-    [
-        -7.105682852784125e-16,
-        -3.868153581223788,
-        0.935163257995901,
-    ],
-    [0.18980130017192315, -3.863494220830085, 0.935163257995901],
-    [0.20598356609161728, -4.192891810857355, 1.1014231854409053],
-    [
-        -7.711506149888035e-16,
-        -4.197948423581987,
-        1.1014231854409053,
-    ],
-    [0.18980130017192315, -3.863494220830085, 0.935163257995901],
-    [0.37914535238710245, -3.8495273644562755, 0.935163257995901],
-    [0.41147089973049117, -4.177734154506419, 1.1014231854409053],
-    [0.20598356609161728, -4.192891810857355, 1.1014231854409053],
-    [0.37914535238710245, -3.8495273644562755, 0.935163257995901],
-    [0.5675760102391738, -3.8262866594827156, 0.935163257995901],
-    [0.6159669639313237, -4.152511970650983, 1.1014231854409053],
-    [0.41147089973049117, -4.177734154506419, 1.1014231854409053],
-    [0.5675760102391738, -3.8262866594827156, 0.935163257995901],
-    [0.7546393277687887, -3.793828094803333, 0.935163257995901],
-    [0.8189791097637079, -4.117286021741158, 1.1014231854409053],
-    [0.6159669639313237, -4.152511970650983, 1.1014231854409053],
-    [0.7546393277687887, -3.793828094803333, 0.935163257995901],
-    [0.93988465305921, -3.7522298659434745, 0.935163257995901],
-    [1.0200182631865735, -4.072141170173294, 1.1014231854409053],
-    [0.8189791097637079, -4.117286021741158, 1.1014231854409053],
-    [0.93988465305921, -3.7522298659434745, 0.935163257995901],
-    [1.122865713896239, -3.7015921866800383, 0.935163257995901],
-    [1.2186001032703748, -4.017186173849043, 1.1014231854409053],
-    [1.0200182631865735, -4.072141170173294, 1.1014231854409053],
-    [1.122865713896239, -3.7015921866800383, 0.935163257995901],
-    [1.3031416928770712, -3.6420370476177064, 0.935163257995901],
-    [1.4142462289686348, -3.952553424168038, 1.1014231854409053],
-    [1.2186001032703748, -4.017186173849043, 1.1014231854409053],
-    [1.3031416928770712, -3.6420370476177064, 0.935163257995901],
-    [1.4802782893780342, -3.5737079223028925, 0.935163257995901],
-    [1.6064853116279734, -3.8783986270854185, 1.1014231854409053],
-    [1.4142462289686348, -3.952553424168038, 1.1014231854409053],
-    [1.4802782893780342, -3.5737079223028925, 0.935163257995901],
-    [1.6538487658228171, -3.496769421583408, 0.935163257995901],
-    [1.7948542304601018, -3.7949004280025536, 1.1014231854409053],
-    [1.6064853116279734, -3.8783986270854185, 1.1014231854409053],
-    [1.6538487658228171, -3.496769421583408, 0.935163257995901],
-    [1.8234349757306902, -3.4114068970465006, 0.935163257995901],
-    [1.9788991882403892, -3.7022599813946098, 1.1014231854409053],
-    [1.7948542304601018, -3.7949004280025536, 1.1014231854409053],
-    [1.8234349757306902, -3.4114068970465006, 0.935163257995901],
-    [1.988628371067987, -3.3178259944906547, 0.935163257995901],
-    [2.1581768045451075, -3.6007004662118116, 1.1014231854409053],
-    [1.9788991882403892, -3.7022599813946098, 1.1014231854409053],
-    [1.988628371067987, -3.3178259944906547, 0.935163257995901],
-    [2.1490309864761072, -3.2162521585068458, 0.935163257995901],
-    [2.3322551838937144, -3.4904665482217965, 1.1014231854409053],
-    [2.1581768045451075, -3.6007004662118116, 1.1014231854409053],
-    [2.1490309864761072, -3.2162521585068458, 0.935163257995901],
-    [2.3042563980049238, -3.1069300893627765, 0.935163257995901],
-    [2.500714956222894, -3.371823790588354, 1.1014231854409053],
-    [2.3322551838937144, -3.4904665482217965, 1.1014231854409053],
-    [2.3042563980049238, -3.1069300893627765, 0.935163257995901],
-    [2.453930654041926, -2.990123153498495, 0.935163257995901],
-    [2.6631502871857755, -3.245058014106498, 1.1014231854409053],
-    [2.500714956222894, -3.371823790588354, 1.1014231854409053],
-    [2.453930654041926, -2.990123153498495, 0.935163257995901],
-    [2.597693176194398, -2.866112749053581, 0.935163257995901],
-    [2.819169855842407, -3.1104746086351494, 1.1014231854409053],
-    [2.6631502871857755, -3.245058014106498, 1.1014231854409053],
-    [2.597693176194398, -2.866112749053581, 0.935163257995901],
-    [2.735197627954369, -2.73519762795437, 0.935163257995901],
-    [2.9683977973861992, -2.9683977973862006, 1.1014231854409053],
-    [2.819169855842407, -3.1104746086351494, 1.1014231854409053],
-    [2.735197627954369, -2.73519762795437, 0.935163257995901],
-    [2.8661127490535803, -2.5976931761943995, 0.935163257995901],
-    [3.1104746086351485, -2.8191698558424085, 1.1014231854409053],
-    [2.9683977973861992, -2.9683977973862006, 1.1014231854409053],
-    [2.8661127490535803, -2.5976931761943995, 0.935163257995901],
-    [2.9901231534984936, -2.4539306540419275, 0.935163257995901],
-    [3.2450580141064966, -2.663150287185777, 1.1014231854409053],
-    [3.1104746086351485, -2.8191698558424085, 1.1014231854409053],
-    [2.9901231534984936, -2.4539306540419275, 0.935163257995901],
-    [3.1069300893627756, -2.3042563980049255, 0.935163257995901],
-    [3.3718237905883526, -2.500714956222896, 1.1014231854409053],
-    [3.2450580141064966, -2.663150287185777, 1.1014231854409053],
-    [3.1069300893627756, -2.3042563980049255, 0.935163257995901],
-    [3.216252158506845, -2.1490309864761086, 0.935163257995901],
-    [3.490466548221795, -2.3322551838937158, 1.1014231854409053],
-    [3.3718237905883526, -2.500714956222896, 1.1014231854409053],
-    [3.216252158506845, -2.1490309864761086, 0.935163257995901],
-    [3.3178259944906543, -1.9886283710679884, 0.935163257995901],
-    [3.6007004662118103, -2.1581768045451093, 1.1014231854409053],
-    [3.490466548221795, -2.3322551838937158, 1.1014231854409053],
-    [3.3178259944906543, -1.9886283710679884, 0.935163257995901],
-    [3.4114068970464997, -1.8234349757306914, 0.935163257995901],
-    [3.7022599813946084, -1.9788991882403906, 1.1014231854409053],
-    [3.6007004662118103, -2.1581768045451093, 1.1014231854409053],
-    [3.4114068970464997, -1.8234349757306914, 0.935163257995901],
-    [3.4967694215834073, -1.6538487658228187, 0.935163257995901],
-    [3.7949004280025522, -1.7948542304601036, 1.1014231854409053],
-    [3.7022599813946084, -1.9788991882403906, 1.1014231854409053],
-    [3.4967694215834073, -1.6538487658228187, 0.935163257995901],
-    [3.573707922302892, -1.4802782893780357, 0.935163257995901],
-    [3.878398627085418, -1.606485311627975, 1.1014231854409053],
-    [3.7949004280025522, -1.7948542304601036, 1.1014231854409053],
-    [3.573707922302892, -1.4802782893780357, 0.935163257995901],
-    [3.642037047617706, -1.3031416928770727, 0.935163257995901],
-    [3.9525534241680376, -1.4142462289686366, 1.1014231854409053],
-    [3.878398627085418, -1.606485311627975, 1.1014231854409053],
-    [3.642037047617706, -1.3031416928770727, 0.935163257995901],
-    [3.701592186680038, -1.1228657138962406, 0.935163257995901],
-    [4.017186173849043, -1.2186001032703766, 1.1014231854409053],
-    [3.9525534241680376, -1.4142462289686366, 1.1014231854409053],
-    [3.701592186680038, -1.1228657138962406, 0.935163257995901],
-    [3.7522298659434745, -0.9398846530592116, 0.935163257995901],
-    [4.072141170173294, -1.0200182631865753, 1.1014231854409053],
-    [4.017186173849043, -1.2186001032703766, 1.1014231854409053],
-    [3.7522298659434745, -0.9398846530592116, 0.935163257995901],
-    [3.7938280948033327, -0.7546393277687904, 0.935163257995901],
-    [4.117286021741157, -0.8189791097637097, 1.1014231854409053],
-    [4.072141170173294, -1.0200182631865753, 1.1014231854409053],
-    [3.7938280948033327, -0.7546393277687904, 0.935163257995901],
-    [3.8262866594827156, -0.5675760102391756, 0.935163257995901],
-    [4.152511970650983, -0.6159669639313254, 1.1014231854409053],
-    [4.117286021741157, -0.8189791097637097, 1.1014231854409053],
-    [3.8262866594827156, -0.5675760102391756, 0.935163257995901],
-    [3.8495273644562755, -0.37914535238710406, 0.935163257995901],
-    [4.177734154506419, -0.4114708997304929, 1.1014231854409053],
-    [4.152511970650983, -0.6159669639313254, 1.1014231854409053],
-    [3.8495273644562755, -0.37914535238710406, 0.935163257995901],
-    [3.863494220830085, -0.1898013001719248, 0.935163257995901],
-    [4.192891810857355, -0.20598356609161908, 1.1014231854409053],
-    [4.177734154506419, -0.4114708997304929, 1.1014231854409053],
-    [3.863494220830085, -0.1898013001719248, 0.935163257995901],
-    [3.868153581223788, -9.474243803712168e-16, 0.935163257995901],
-    [
-        4.197948423581987,
-        -1.0282008199850715e-15,
-        1.1014231854409053,
-    ],
-    [4.192891810857355, -0.20598356609161908, 1.1014231854409053],
-    [4.197948423581987, 0.0, 1.1014231854409053],
-    [4.192891810857355, 0.20598356609161877, 1.1014231854409053],
-    [4.522289400884625, 0.22216583201131304, 1.2812786926416686],
-    [4.5277432659401855, 0.0, 1.2812786926416686],
-    [4.192891810857355, 0.20598356609161877, 1.1014231854409053],
-    [4.177734154506419, 0.4114708997304933, 1.1014231854409053],
-    [4.5059409445565635, 0.4437964470738822, 1.2812786926416686],
-    [4.522289400884625, 0.22216583201131304, 1.2812786926416686],
-    [4.177734154506419, 0.4114708997304933, 1.1014231854409053],
-    [4.152511970650984, 0.6159669639313228, 1.1014231854409053],
-    [4.478737281819252, 0.6643579176234725, 1.2812786926416686],
-    [4.5059409445565635, 0.4437964470738822, 1.2812786926416686],
-    [4.152511970650984, 0.6159669639313228, 1.1014231854409053],
-    [4.117286021741158, 0.8189791097637077, 1.1014231854409053],
-    [4.440743948678983, 0.883318891758627, 1.2812786926416686],
-    [4.478737281819252, 0.6643579176234725, 1.2812786926416686],
-    [4.117286021741158, 0.8189791097637077, 1.1014231854409053],
-    [4.072141170173294, 1.0200182631865742, 1.1014231854409053],
-    [4.3920524744031155, 1.1001518733139377, 1.2812786926416686],
-    [4.440743948678983, 0.883318891758627, 1.2812786926416686],
-    [4.072141170173294, 1.0200182631865742, 1.1014231854409053],
-    [4.017186173849043, 1.218600103270376, 1.1014231854409053],
-    [4.332780161018049, 1.314334492644512, 1.2812786926416686],
-    [4.3920524744031155, 1.1001518733139377, 1.2812786926416686],
-    [4.017186173849043, 1.218600103270376, 1.1014231854409053],
-    [3.9525534241680376, 1.4142462289686368, 1.1014231854409053],
-    [4.26306980071837, 1.5253507650602007, 1.2812786926416686],
-    [4.332780161018049, 1.314334492644512, 1.2812786926416686],
-    [3.9525534241680376, 1.4142462289686368, 1.1014231854409053],
-    [3.878398627085419, 1.6064853116279725, 1.1014231854409053],
-    [4.183089331867945, 1.7326923338779117, 1.2812786926416686],
-    [4.26306980071837, 1.5253507650602007, 1.2812786926416686],
-    [3.878398627085419, 1.6064853116279725, 1.1014231854409053],
-    [3.7949004280025536, 1.7948542304601016, 1.1014231854409053],
-    [4.093031434421699, 1.9358596950973865, 1.2812786926416686],
-    [4.183089331867945, 1.7326923338779117, 1.2812786926416686],
-    [3.7949004280025536, 1.7948542304601016, 1.1014231854409053],
-    [3.7022599813946098, 1.9788991882403895, 1.1014231854409053],
-    [3.993113065742719, 2.1343634007500887, 1.2812786926416686],
-    [4.093031434421699, 1.9358596950973865, 1.2812786926416686],
-    [3.7022599813946098, 1.9788991882403895, 1.1014231854409053],
-    [3.6007004662118107, 2.1581768045451084, 1.1014231854409053],
-    [3.8835749379329676, 2.327725238022229, 1.2812786926416686],
-    [3.993113065742719, 2.1343634007500887, 1.2812786926416686],
-    [3.6007004662118107, 2.1581768045451084, 1.1014231854409053],
-    [3.490466548221795, 2.3322551838937158, 1.1014231854409053],
-    [3.764680937936746, 2.5154793813113234, 1.2812786926416686],
-    [3.8835749379329676, 2.327725238022229, 1.2812786926416686],
-    [3.490466548221795, 2.3322551838937158, 1.1014231854409053],
-    [3.371823790588352, 2.5007149562228963, 1.1014231854409053],
-    [3.6367174918139296, 2.697173514440867, 1.2812786926416686],
-    [3.764680937936746, 2.5154793813113234, 1.2812786926416686],
-    [3.371823790588352, 2.5007149562228963, 1.1014231854409053],
-    [3.2450580141064984, 2.663150287185775, 1.1014231854409053],
-    [3.4999928747145024, 2.8723699203296245, 1.2812786926416686],
-    [3.6367174918139296, 2.697173514440867, 1.2812786926416686],
-    [3.2450580141064984, 2.663150287185775, 1.1014231854409053],
-    [3.11047460863515, 2.819169855842407, 1.1014231854409053],
-    [3.354836468216719, 3.040646535490416, 1.2812786926416686],
-    [3.4999928747145024, 2.8723699203296245, 1.2812786926416686],
-    [3.11047460863515, 2.819169855842407, 1.1014231854409053],
-    [2.9683977973862, 2.9683977973862, 1.1014231854409053],
-    [3.201597966818031, 3.201597966818031, 1.2812786926416686],
-    [3.354836468216719, 3.040646535490416, 1.2812786926416686],
-    [2.9683977973862, 2.9683977973862, 1.1014231854409053],
-    [2.819169855842407, 3.1104746086351494, 1.1014231854409053],
-    [3.040646535490416, 3.3548364682167184, 1.2812786926416686],
-    [3.201597966818031, 3.201597966818031, 1.2812786926416686],
-    [2.819169855842407, 3.1104746086351494, 1.1014231854409053],
-    [2.663150287185775, 3.245058014106498, 1.1014231854409053],
-    [2.8723699203296245, 3.499992874714502, 1.2812786926416686],
-    [3.040646535490416, 3.3548364682167184, 1.2812786926416686],
-    [2.663150287185775, 3.245058014106498, 1.1014231854409053],
-    [2.5007149562228967, 3.3718237905883517, 1.1014231854409053],
-    [2.697173514440868, 3.636717491813929, 1.2812786926416686],
-    [2.8723699203296245, 3.499992874714502, 1.2812786926416686],
-    [2.5007149562228967, 3.3718237905883517, 1.1014231854409053],
-    [2.332255183893716, 3.490466548221795, 1.1014231854409053],
-    [2.515479381311324, 3.764680937936746, 1.2812786926416686],
-    [2.697173514440868, 3.636717491813929, 1.2812786926416686],
-    [2.332255183893716, 3.490466548221795, 1.1014231854409053],
-    [2.1581768045451084, 3.6007004662118107, 1.1014231854409053],
-    [2.327725238022229, 3.8835749379329676, 1.2812786926416686],
-    [2.515479381311324, 3.764680937936746, 1.2812786926416686],
-    [2.1581768045451084, 3.6007004662118107, 1.1014231854409053],
-    [1.9788991882403901, 3.7022599813946093, 1.1014231854409053],
-    [2.1343634007500896, 3.993113065742718, 1.2812786926416686],
-    [2.327725238022229, 3.8835749379329676, 1.2812786926416686],
-    [1.9788991882403901, 3.7022599813946093, 1.1014231854409053],
-    [1.794854230460102, 3.7949004280025536, 1.1014231854409053],
-    [1.935859695097387, 4.093031434421699, 1.2812786926416686],
-    [2.1343634007500896, 3.993113065742718, 1.2812786926416686],
-    [1.794854230460102, 3.7949004280025536, 1.1014231854409053],
-    [1.6064853116279727, 3.878398627085419, 1.1014231854409053],
-    [1.7326923338779119, 4.183089331867945, 1.2812786926416686],
-    [1.935859695097387, 4.093031434421699, 1.2812786926416686],
-    [1.6064853116279727, 3.878398627085419, 1.1014231854409053],
-    [1.4142462289686368, 3.9525534241680376, 1.1014231854409053],
-    [1.5253507650602007, 4.26306980071837, 1.2812786926416686],
-    [1.7326923338779119, 4.183089331867945, 1.2812786926416686],
-    [1.4142462289686368, 3.9525534241680376, 1.1014231854409053],
-    [1.218600103270376, 4.017186173849043, 1.1014231854409053],
-    [1.314334492644512, 4.332780161018049, 1.2812786926416686],
-    [1.5253507650602007, 4.26306980071837, 1.2812786926416686],
-    [1.218600103270376, 4.017186173849043, 1.1014231854409053],
-    [1.0200182631865746, 4.072141170173294, 1.1014231854409053],
-    [1.1001518733139384, 4.3920524744031155, 1.2812786926416686],
-    [1.314334492644512, 4.332780161018049, 1.2812786926416686],
-    [1.0200182631865746, 4.072141170173294, 1.1014231854409053],
-    [0.818979109763708, 4.117286021741158, 1.1014231854409053],
-    [0.8833188917586273, 4.440743948678983, 1.2812786926416686],
-    [1.1001518733139384, 4.3920524744031155, 1.2812786926416686],
-    [0.818979109763708, 4.117286021741158, 1.1014231854409053],
-    [0.6159669639313228, 4.152511970650984, 1.1014231854409053],
-    [0.6643579176234725, 4.478737281819252, 1.2812786926416686],
-    [0.8833188917586273, 4.440743948678983, 1.2812786926416686],
-    [0.6159669639313228, 4.152511970650984, 1.1014231854409053],
-    [0.411470899730494, 4.177734154506419, 1.1014231854409053],
-    [0.44379644707388294, 4.5059409445565635, 1.2812786926416686],
-    [0.6643579176234725, 4.478737281819252, 1.2812786926416686],
-    [0.411470899730494, 4.177734154506419, 1.1014231854409053],
-    [0.20598356609161925, 4.192891810857355, 1.1014231854409053],
-    [0.22216583201131354, 4.522289400884625, 1.2812786926416686],
-    [0.44379644707388294, 4.5059409445565635, 1.2812786926416686],
-    [0.20598356609161925, 4.192891810857355, 1.1014231854409053],
-    [
-        2.5705020499626787e-16,
-        4.197948423581987,
-        1.1014231854409053,
-    ],
-    [
-        2.7724431489973155e-16,
-        4.5277432659401855,
-        1.2812786926416686,
-    ],
-    [0.22216583201131354, 4.522289400884625, 1.2812786926416686],
-    [
-        2.5705020499626787e-16,
-        4.197948423581987,
-        1.1014231854409053,
-    ],
-    [-0.20598356609161875, 4.192891810857355, 1.1014231854409053],
-    [-0.222165832011313, 4.522289400884625, 1.2812786926416686],
-    [
-        2.7724431489973155e-16,
-        4.5277432659401855,
-        1.2812786926416686,
-    ],
-    [-0.20598356609161875, 4.192891810857355, 1.1014231854409053],
-    [-0.4114708997304935, 4.177734154506419, 1.1014231854409053],
-    [-0.4437964470738824, 4.5059409445565635, 1.2812786926416686],
-    [-0.222165832011313, 4.522289400884625, 1.2812786926416686],
-    [-0.4114708997304935, 4.177734154506419, 1.1014231854409053],
-    [-0.6159669639313223, 4.152511970650984, 1.1014231854409053],
-    [-0.6643579176234721, 4.478737281819252, 1.2812786926416686],
-    [-0.4437964470738824, 4.5059409445565635, 1.2812786926416686],
-    [-0.6159669639313223, 4.152511970650984, 1.1014231854409053],
-    [-0.8189791097637075, 4.117286021741158, 1.1014231854409053],
-    [-0.8833188917586268, 4.440743948678983, 1.2812786926416686],
-    [-0.6643579176234721, 4.478737281819252, 1.2812786926416686],
-    [-0.8189791097637075, 4.117286021741158, 1.1014231854409053],
-    [-1.0200182631865742, 4.072141170173294, 1.1014231854409053],
-    [-1.1001518733139377, 4.3920524744031155, 1.2812786926416686],
-    [-0.8833188917586268, 4.440743948678983, 1.2812786926416686],
-    [-1.0200182631865742, 4.072141170173294, 1.1014231854409053],
-    [-1.2186001032703753, 4.017186173849043, 1.1014231854409053],
-    [-1.3143344926445113, 4.332780161018049, 1.2812786926416686],
-    [-1.1001518733139377, 4.3920524744031155, 1.2812786926416686],
-    [-1.2186001032703753, 4.017186173849043, 1.1014231854409053],
-    [-1.4142462289686364, 3.9525534241680376, 1.1014231854409053],
-    [-1.5253507650602003, 4.26306980071837, 1.2812786926416686],
-    [-1.3143344926445113, 4.332780161018049, 1.2812786926416686],
-    [-1.4142462289686364, 3.9525534241680376, 1.1014231854409053],
-    [-1.6064853116279723, 3.878398627085419, 1.1014231854409053],
-    [-1.7326923338779114, 4.183089331867945, 1.2812786926416686],
-    [-1.5253507650602003, 4.26306980071837, 1.2812786926416686],
-    [-1.6064853116279723, 3.878398627085419, 1.1014231854409053],
-    [-1.7948542304601007, 3.794900428002554, 1.1014231854409053],
-    [-1.9358596950973856, 4.093031434421699, 1.2812786926416686],
-    [-1.7326923338779114, 4.183089331867945, 1.2812786926416686],
-    [-1.7948542304601007, 3.794900428002554, 1.1014231854409053],
-    [-1.9788991882403897, 3.7022599813946098, 1.1014231854409053],
-    [-2.134363400750089, 3.993113065742719, 1.2812786926416686],
-    [-1.9358596950973856, 4.093031434421699, 1.2812786926416686],
-    [-1.9788991882403897, 3.7022599813946098, 1.1014231854409053],
-    [-2.1581768045451075, 3.6007004662118107, 1.1014231854409053],
-    [-2.3277252380222286, 3.8835749379329676, 1.2812786926416686],
-    [-2.134363400750089, 3.993113065742719, 1.2812786926416686],
-    [-2.1581768045451075, 3.6007004662118107, 1.1014231854409053],
-    [-2.332255183893715, 3.490466548221796, 1.1014231854409053],
-    [-2.5154793813113225, 3.7646809379367463, 1.2812786926416686],
-    [-2.3277252380222286, 3.8835749379329676, 1.2812786926416686],
-    [-2.332255183893715, 3.490466548221796, 1.1014231854409053],
-    [-2.5007149562228963, 3.371823790588352, 1.1014231854409053],
-    [-2.697173514440867, 3.6367174918139296, 1.2812786926416686],
-    [-2.5154793813113225, 3.7646809379367463, 1.2812786926416686],
-    [-2.5007149562228963, 3.371823790588352, 1.1014231854409053],
-    [-2.663150287185774, 3.245058014106499, 1.1014231854409053],
-    [-2.872369920329624, 3.499992874714503, 1.2812786926416686],
-    [-2.697173514440867, 3.6367174918139296, 1.2812786926416686],
-    [-2.663150287185774, 3.245058014106499, 1.1014231854409053],
-    [-2.8191698558424076, 3.110474608635149, 1.1014231854409053],
-    [-3.0406465354904166, 3.354836468216718, 1.2812786926416686],
-    [-2.872369920329624, 3.499992874714503, 1.2812786926416686],
-    [-2.8191698558424076, 3.110474608635149, 1.1014231854409053],
-    [-2.9683977973861997, 2.9683977973862, 1.1014231854409053],
-    [-3.2015979668180305, 3.201597966818031, 1.2812786926416686],
-    [-3.0406465354904166, 3.354836468216718, 1.2812786926416686],
-    [-2.9683977973861997, 2.9683977973862, 1.1014231854409053],
-    [-3.1104746086351485, 2.819169855842408, 1.1014231854409053],
-    [-3.354836468216717, 3.0406465354904175, 1.2812786926416686],
-    [-3.2015979668180305, 3.201597966818031, 1.2812786926416686],
-    [-3.1104746086351485, 2.819169855842408, 1.1014231854409053],
-    [-3.2450580141064984, 2.663150287185775, 1.1014231854409053],
-    [-3.4999928747145024, 2.8723699203296245, 1.2812786926416686],
-    [-3.354836468216717, 3.0406465354904175, 1.2812786926416686],
-    [-3.2450580141064984, 2.663150287185775, 1.1014231854409053],
-    [-3.3718237905883517, 2.5007149562228967, 1.1014231854409053],
-    [-3.636717491813929, 2.697173514440868, 1.2812786926416686],
-    [-3.4999928747145024, 2.8723699203296245, 1.2812786926416686],
-    [-3.3718237905883517, 2.5007149562228967, 1.1014231854409053],
-    [-3.490466548221796, 2.3322551838937158, 1.1014231854409053],
-    [-3.7646809379367463, 2.5154793813113234, 1.2812786926416686],
-    [-3.636717491813929, 2.697173514440868, 1.2812786926416686],
-    [-3.490466548221796, 2.3322551838937158, 1.1014231854409053],
-    [-3.6007004662118103, 2.158176804545109, 1.1014231854409053],
-    [-3.883574937932967, 2.3277252380222295, 1.2812786926416686],
-    [-3.7646809379367463, 2.5154793813113234, 1.2812786926416686],
-    [-3.6007004662118103, 2.158176804545109, 1.1014231854409053],
-    [-3.7022599813946093, 1.9788991882403901, 1.1014231854409053],
-    [-3.993113065742718, 2.1343634007500896, 1.2812786926416686],
-    [-3.883574937932967, 2.3277252380222295, 1.2812786926416686],
-    [-3.7022599813946093, 1.9788991882403901, 1.1014231854409053],
-    [-3.7949004280025536, 1.7948542304601014, 1.1014231854409053],
-    [-4.093031434421699, 1.9358596950973863, 1.2812786926416686],
-    [-3.993113065742718, 2.1343634007500896, 1.2812786926416686],
-    [-3.7949004280025536, 1.7948542304601014, 1.1014231854409053],
-    [-3.878398627085419, 1.606485311627973, 1.1014231854409053],
-    [-4.183089331867945, 1.732692333877912, 1.2812786926416686],
-    [-4.093031434421699, 1.9358596950973863, 1.2812786926416686],
-    [-3.878398627085419, 1.606485311627973, 1.1014231854409053],
-    [-3.952553424168037, 1.414246228968638, 1.1014231854409053],
-    [-4.263069800718369, 1.525350765060202, 1.2812786926416686],
-    [-4.183089331867945, 1.732692333877912, 1.2812786926416686],
-    [-3.952553424168037, 1.414246228968638, 1.1014231854409053],
-    [-4.017186173849043, 1.2186001032703762, 1.1014231854409053],
-    [-4.332780161018049, 1.3143344926445122, 1.2812786926416686],
-    [-4.263069800718369, 1.525350765060202, 1.2812786926416686],
-    [-4.017186173849043, 1.2186001032703762, 1.1014231854409053],
-    [-4.072141170173294, 1.0200182631865748, 1.1014231854409053],
-    [-4.3920524744031155, 1.1001518733139386, 1.2812786926416686],
-    [-4.332780161018049, 1.3143344926445122, 1.2812786926416686],
-    [-4.072141170173294, 1.0200182631865748, 1.1014231854409053],
-    [-4.117286021741158, 0.8189791097637092, 1.1014231854409053],
-    [-4.440743948678983, 0.8833188917586287, 1.2812786926416686],
-    [-4.3920524744031155, 1.1001518733139386, 1.2812786926416686],
-    [-4.117286021741158, 0.8189791097637092, 1.1014231854409053],
-    [-4.152511970650984, 0.615966963931323, 1.1014231854409053],
-    [-4.478737281819252, 0.6643579176234728, 1.2812786926416686],
-    [-4.440743948678983, 0.8833188917586287, 1.2812786926416686],
-    [-4.152511970650984, 0.615966963931323, 1.1014231854409053],
-    [-4.177734154506419, 0.4114708997304942, 1.1014231854409053],
-    [-4.5059409445565635, 0.4437964470738832, 1.2812786926416686],
-    [-4.478737281819252, 0.6643579176234728, 1.2812786926416686],
-    [-4.177734154506419, 0.4114708997304942, 1.1014231854409053],
-    [-4.192891810857355, 0.20598356609161858, 1.1014231854409053],
-    [-4.522289400884625, 0.22216583201131282, 1.2812786926416686],
-    [-4.5059409445565635, 0.4437964470738832, 1.2812786926416686],
-    [-4.192891810857355, 0.20598356609161858, 1.1014231854409053],
-    [
-        -4.197948423581987,
-        5.141004099925357e-16,
-        1.1014231854409053,
-    ],
-    [
-        -4.5277432659401855,
-        5.544886297994631e-16,
-        1.2812786926416686,
-    ],
-    [-4.522289400884625, 0.22216583201131282, 1.2812786926416686],
-    [
-        -4.197948423581987,
-        5.141004099925357e-16,
-        1.1014231854409053,
-    ],
-    [-4.192891810857355, -0.20598356609161755, 1.1014231854409053],
-    [-4.522289400884625, -0.2221658320113117, 1.2812786926416686],
-    [
-        -4.5277432659401855,
-        5.544886297994631e-16,
-        1.2812786926416686,
-    ],
-    [-4.192891810857355, -0.20598356609161755, 1.1014231854409053],
-    [-4.177734154506419, -0.4114708997304932, 1.1014231854409053],
-    [
-        -4.5059409445565635,
-        -0.44379644707388216,
-        1.2812786926416686,
-    ],
-    [-4.522289400884625, -0.2221658320113117, 1.2812786926416686],
-    [-4.177734154506419, -0.4114708997304932, 1.1014231854409053],
-    [-4.152511970650984, -0.6159669639313221, 1.1014231854409053],
-    [-4.478737281819252, -0.6643579176234718, 1.2812786926416686],
-    [
-        -4.5059409445565635,
-        -0.44379644707388216,
-        1.2812786926416686,
-    ],
-    [-4.152511970650984, -0.6159669639313221, 1.1014231854409053],
-    [-4.117286021741158, -0.8189791097637081, 1.1014231854409053],
-    [-4.440743948678983, -0.8833188917586275, 1.2812786926416686],
-    [-4.478737281819252, -0.6643579176234718, 1.2812786926416686],
-    [-4.117286021741158, -0.8189791097637081, 1.1014231854409053],
-    [-4.072141170173294, -1.0200182631865737, 1.1014231854409053],
-    [-4.3920524744031155, -1.1001518733139375, 1.2812786926416686],
-    [-4.440743948678983, -0.8833188917586275, 1.2812786926416686],
-    [-4.072141170173294, -1.0200182631865737, 1.1014231854409053],
-    [-4.017186173849043, -1.218600103270375, 1.1014231854409053],
-    [-4.332780161018049, -1.3143344926445109, 1.2812786926416686],
-    [-4.3920524744031155, -1.1001518733139375, 1.2812786926416686],
-    [-4.017186173849043, -1.218600103270375, 1.1014231854409053],
-    [-3.9525534241680376, -1.414246228968637, 1.1014231854409053],
-    [-4.26306980071837, -1.525350765060201, 1.2812786926416686],
-    [-4.332780161018049, -1.3143344926445109, 1.2812786926416686],
-    [-3.9525534241680376, -1.414246228968637, 1.1014231854409053],
-    [-3.8783986270854194, -1.606485311627972, 1.1014231854409053],
-    [-4.183089331867945, -1.7326923338779112, 1.2812786926416686],
-    [-4.26306980071837, -1.525350765060201, 1.2812786926416686],
-    [-3.8783986270854194, -1.606485311627972, 1.1014231854409053],
-    [-3.794900428002554, -1.7948542304601005, 1.1014231854409053],
-    [-4.093031434421699, -1.9358596950973852, 1.2812786926416686],
-    [-4.183089331867945, -1.7326923338779112, 1.2812786926416686],
-    [-3.794900428002554, -1.7948542304601005, 1.1014231854409053],
-    [-3.7022599813946098, -1.9788991882403895, 1.1014231854409053],
-    [-3.993113065742719, -2.1343634007500887, 1.2812786926416686],
-    [-4.093031434421699, -1.9358596950973852, 1.2812786926416686],
-    [-3.7022599813946098, -1.9788991882403895, 1.1014231854409053],
-    [-3.6007004662118107, -2.1581768045451075, 1.1014231854409053],
-    [-3.8835749379329676, -2.3277252380222286, 1.2812786926416686],
-    [-3.993113065742719, -2.1343634007500887, 1.2812786926416686],
-    [-3.6007004662118107, -2.1581768045451075, 1.1014231854409053],
-    [-3.4904665482217965, -2.332255183893715, 1.1014231854409053],
-    [-3.764680937936747, -2.5154793813113225, 1.2812786926416686],
-    [-3.8835749379329676, -2.3277252380222286, 1.2812786926416686],
-    [-3.4904665482217965, -2.332255183893715, 1.1014231854409053],
-    [-3.371823790588352, -2.500714956222896, 1.1014231854409053],
-    [-3.6367174918139296, -2.6971735144408666, 1.2812786926416686],
-    [-3.764680937936747, -2.5154793813113225, 1.2812786926416686],
-    [-3.371823790588352, -2.500714956222896, 1.1014231854409053],
-    [-3.245058014106499, -2.6631502871857737, 1.1014231854409053],
-    [-3.499992874714503, -2.8723699203296236, 1.2812786926416686],
-    [-3.6367174918139296, -2.6971735144408666, 1.2812786926416686],
-    [-3.245058014106499, -2.6631502871857737, 1.1014231854409053],
-    [-3.1104746086351494, -2.8191698558424076, 1.1014231854409053],
-    [-3.3548364682167184, -3.0406465354904166, 1.2812786926416686],
-    [-3.499992874714503, -2.8723699203296236, 1.2812786926416686],
-    [-3.1104746086351494, -2.8191698558424076, 1.1014231854409053],
-    [-2.9683977973862006, -2.9683977973861997, 1.1014231854409053],
-    [-3.2015979668180314, -3.2015979668180305, 1.2812786926416686],
-    [-3.3548364682167184, -3.0406465354904166, 1.2812786926416686],
-    [-2.9683977973862006, -2.9683977973861997, 1.1014231854409053],
-    [-2.8191698558424085, -3.1104746086351485, 1.1014231854409053],
-    [-3.040646535490418, -3.354836468216717, 1.2812786926416686],
-    [-3.2015979668180314, -3.2015979668180305, 1.2812786926416686],
-    [-2.8191698558424085, -3.1104746086351485, 1.1014231854409053],
-    [-2.663150287185777, -3.245058014106497, 1.1014231854409053],
-    [-2.8723699203296262, -3.4999928747145006, 1.2812786926416686],
-    [-3.040646535490418, -3.354836468216717, 1.2812786926416686],
-    [-2.663150287185777, -3.245058014106497, 1.1014231854409053],
-    [-2.5007149562228954, -3.3718237905883526, 1.1014231854409053],
-    [-2.697173514440866, -3.63671749181393, 1.2812786926416686],
-    [-2.8723699203296262, -3.4999928747145006, 1.2812786926416686],
-    [-2.5007149562228954, -3.3718237905883526, 1.1014231854409053],
-    [-2.3322551838937158, -3.490466548221795, 1.1014231854409053],
-    [-2.5154793813113234, -3.764680937936746, 1.2812786926416686],
-    [-2.697173514440866, -3.63671749181393, 1.2812786926416686],
-    [-2.3322551838937158, -3.490466548221795, 1.1014231854409053],
-    [-2.158176804545109, -3.6007004662118103, 1.1014231854409053],
-    [-2.3277252380222295, -3.883574937932967, 1.2812786926416686],
-    [-2.5154793813113234, -3.764680937936746, 1.2812786926416686],
-    [-2.158176804545109, -3.6007004662118103, 1.1014231854409053],
-    [-1.9788991882403903, -3.7022599813946093, 1.1014231854409053],
-    [-2.1343634007500896, -3.993113065742718, 1.2812786926416686],
-    [-2.3277252380222295, -3.883574937932967, 1.2812786926416686],
-    [-1.9788991882403903, -3.7022599813946093, 1.1014231854409053],
-    [-1.7948542304601034, -3.7949004280025522, 1.1014231854409053],
-    [-1.9358596950973883, -4.093031434421698, 1.2812786926416686],
-    [-2.1343634007500896, -3.993113065742718, 1.2812786926416686],
-    [-1.7948542304601034, -3.7949004280025522, 1.1014231854409053],
-    [-1.6064853116279747, -3.878398627085418, 1.1014231854409053],
-    [-1.732692333877914, -4.1830893318679445, 1.2812786926416686],
-    [-1.9358596950973883, -4.093031434421698, 1.2812786926416686],
-    [-1.6064853116279747, -3.878398627085418, 1.1014231854409053],
-    [-1.4142462289686364, -3.9525534241680376, 1.1014231854409053],
-    [-1.5253507650602003, -4.26306980071837, 1.2812786926416686],
-    [-1.732692333877914, -4.1830893318679445, 1.2812786926416686],
-    [-1.4142462289686364, -3.9525534241680376, 1.1014231854409053],
-    [-1.2186001032703764, -4.017186173849043, 1.1014231854409053],
-    [-1.3143344926445124, -4.332780161018049, 1.2812786926416686],
-    [-1.5253507650602003, -4.26306980071837, 1.2812786926416686],
-    [-1.2186001032703764, -4.017186173849043, 1.1014231854409053],
-    [-1.020018263186575, -4.072141170173294, 1.1014231854409053],
-    [-1.1001518733139388, -4.3920524744031155, 1.2812786926416686],
-    [-1.3143344926445124, -4.332780161018049, 1.2812786926416686],
-    [-1.020018263186575, -4.072141170173294, 1.1014231854409053],
-    [-0.8189791097637095, -4.117286021741157, 1.1014231854409053],
-    [-0.8833188917586289, -4.440743948678983, 1.2812786926416686],
-    [-1.1001518733139388, -4.3920524744031155, 1.2812786926416686],
-    [-0.8189791097637095, -4.117286021741157, 1.1014231854409053],
-    [-0.6159669639313251, -4.152511970650983, 1.1014231854409053],
-    [-0.6643579176234751, -4.478737281819251, 1.2812786926416686],
-    [-0.8833188917586289, -4.440743948678983, 1.2812786926416686],
-    [-0.6159669639313251, -4.152511970650983, 1.1014231854409053],
-    [-0.41147089973049267, -4.177734154506419, 1.1014231854409053],
-    [-0.4437964470738815, -4.5059409445565635, 1.2812786926416686],
-    [-0.6643579176234751, -4.478737281819251, 1.2812786926416686],
-    [-0.41147089973049267, -4.177734154506419, 1.1014231854409053],
-    [-0.20598356609161883, -4.192891810857355, 1.1014231854409053],
-    [-0.2221658320113131, -4.522289400884625, 1.2812786926416686],
-    [-0.4437964470738815, -4.5059409445565635, 1.2812786926416686],
-    [-0.20598356609161883, -4.192891810857355, 1.1014231854409053],
-    [
-        -7.711506149888035e-16,
-        -4.197948423581987,
-        1.1014231854409053,
-    ],
-    [
-        -8.317329446991947e-16,
-        -4.5277432659401855,
-        1.2812786926416686,
-    ],
-    [-0.2221658320113131, -4.522289400884625, 1.2812786926416686],
-    [
-        -7.711506149888035e-16,
-        -4.197948423581987,
-        1.1014231854409053,
-    ],
-    [0.20598356609161728, -4.192891810857355, 1.1014231854409053],
-    [0.22216583201131143, -4.522289400884625, 1.2812786926416686],
-    [
-        -8.317329446991947e-16,
-        -4.5277432659401855,
-        1.2812786926416686,
-    ],
-    [0.20598356609161728, -4.192891810857355, 1.1014231854409053],
-    [0.41147089973049117, -4.177734154506419, 1.1014231854409053],
-    [0.4437964470738799, -4.5059409445565635, 1.2812786926416686],
-    [0.22216583201131143, -4.522289400884625, 1.2812786926416686],
-    [0.41147089973049117, -4.177734154506419, 1.1014231854409053],
-    [0.6159669639313237, -4.152511970650983, 1.1014231854409053],
-    [0.6643579176234734, -4.478737281819251, 1.2812786926416686],
-    [0.4437964470738799, -4.5059409445565635, 1.2812786926416686],
-    [0.6159669639313237, -4.152511970650983, 1.1014231854409053],
-    [0.8189791097637079, -4.117286021741158, 1.1014231854409053],
-    [0.8833188917586272, -4.440743948678983, 1.2812786926416686],
-    [0.6643579176234734, -4.478737281819251, 1.2812786926416686],
-    [0.8189791097637079, -4.117286021741158, 1.1014231854409053],
-    [1.0200182631865735, -4.072141170173294, 1.1014231854409053],
-    [1.1001518733139373, -4.3920524744031155, 1.2812786926416686],
-    [0.8833188917586272, -4.440743948678983, 1.2812786926416686],
-    [1.0200182631865735, -4.072141170173294, 1.1014231854409053],
-    [1.2186001032703748, -4.017186173849043, 1.1014231854409053],
-    [1.3143344926445106, -4.332780161018049, 1.2812786926416686],
-    [1.1001518733139373, -4.3920524744031155, 1.2812786926416686],
-    [1.2186001032703748, -4.017186173849043, 1.1014231854409053],
-    [1.4142462289686348, -3.952553424168038, 1.1014231854409053],
-    [1.5253507650601987, -4.26306980071837, 1.2812786926416686],
-    [1.3143344926445106, -4.332780161018049, 1.2812786926416686],
-    [1.4142462289686348, -3.952553424168038, 1.1014231854409053],
-    [1.6064853116279734, -3.8783986270854185, 1.1014231854409053],
-    [1.7326923338779128, -4.1830893318679445, 1.2812786926416686],
-    [1.5253507650601987, -4.26306980071837, 1.2812786926416686],
-    [1.6064853116279734, -3.8783986270854185, 1.1014231854409053],
-    [1.7948542304601018, -3.7949004280025536, 1.1014231854409053],
-    [1.9358596950973868, -4.093031434421699, 1.2812786926416686],
-    [1.7326923338779128, -4.1830893318679445, 1.2812786926416686],
-    [1.7948542304601018, -3.7949004280025536, 1.1014231854409053],
-    [1.9788991882403892, -3.7022599813946098, 1.1014231854409053],
-    [2.1343634007500882, -3.993113065742719, 1.2812786926416686],
-    [1.9358596950973868, -4.093031434421699, 1.2812786926416686],
-    [1.9788991882403892, -3.7022599813946098, 1.1014231854409053],
-    [2.1581768045451075, -3.6007004662118116, 1.1014231854409053],
-    [2.3277252380222286, -3.883574937932968, 1.2812786926416686],
-    [2.1343634007500882, -3.993113065742719, 1.2812786926416686],
-    [2.1581768045451075, -3.6007004662118116, 1.1014231854409053],
-    [2.3322551838937144, -3.4904665482217965, 1.1014231854409053],
-    [2.515479381311322, -3.764680937936747, 1.2812786926416686],
-    [2.3277252380222286, -3.883574937932968, 1.2812786926416686],
-    [2.3322551838937144, -3.4904665482217965, 1.1014231854409053],
-    [2.500714956222894, -3.371823790588354, 1.1014231854409053],
-    [2.697173514440865, -3.6367174918139313, 1.2812786926416686],
-    [2.515479381311322, -3.764680937936747, 1.2812786926416686],
-    [2.500714956222894, -3.371823790588354, 1.1014231854409053],
-    [2.6631502871857755, -3.245058014106498, 1.1014231854409053],
-    [2.872369920329625, -3.499992874714502, 1.2812786926416686],
-    [2.697173514440865, -3.6367174918139313, 1.2812786926416686],
-    [2.6631502871857755, -3.245058014106498, 1.1014231854409053],
-    [2.819169855842407, -3.1104746086351494, 1.1014231854409053],
-    [3.040646535490416, -3.3548364682167184, 1.2812786926416686],
-    [2.872369920329625, -3.499992874714502, 1.2812786926416686],
-    [2.819169855842407, -3.1104746086351494, 1.1014231854409053],
-    [2.9683977973861992, -2.9683977973862006, 1.1014231854409053],
-    [3.20159796681803, -3.2015979668180314, 1.2812786926416686],
-    [3.040646535490416, -3.3548364682167184, 1.2812786926416686],
-    [2.9683977973861992, -2.9683977973862006, 1.1014231854409053],
-    [3.1104746086351485, -2.8191698558424085, 1.1014231854409053],
-    [3.354836468216717, -3.040646535490418, 1.2812786926416686],
-    [3.20159796681803, -3.2015979668180314, 1.2812786926416686],
-    [3.1104746086351485, -2.8191698558424085, 1.1014231854409053],
-    [3.2450580141064966, -2.663150287185777, 1.1014231854409053],
-    [3.4999928747145, -2.8723699203296262, 1.2812786926416686],
-    [3.354836468216717, -3.040646535490418, 1.2812786926416686],
-    [3.2450580141064966, -2.663150287185777, 1.1014231854409053],
-    [3.3718237905883526, -2.500714956222896, 1.1014231854409053],
-    [3.63671749181393, -2.6971735144408666, 1.2812786926416686],
-    [3.4999928747145, -2.8723699203296262, 1.2812786926416686],
-    [3.3718237905883526, -2.500714956222896, 1.1014231854409053],
-    [3.490466548221795, -2.3322551838937158, 1.1014231854409053],
-    [3.764680937936746, -2.5154793813113234, 1.2812786926416686],
-    [3.63671749181393, -2.6971735144408666, 1.2812786926416686],
-    [3.490466548221795, -2.3322551838937158, 1.1014231854409053],
-    [3.6007004662118103, -2.1581768045451093, 1.1014231854409053],
-    [3.883574937932967, -2.32772523802223, 1.2812786926416686],
-    [3.764680937936746, -2.5154793813113234, 1.2812786926416686],
-    [3.6007004662118103, -2.1581768045451093, 1.1014231854409053],
-    [3.7022599813946084, -1.9788991882403906, 1.1014231854409053],
-    [3.9931130657427176, -2.13436340075009, 1.2812786926416686],
-    [3.883574937932967, -2.32772523802223, 1.2812786926416686],
-    [3.7022599813946084, -1.9788991882403906, 1.1014231854409053],
-    [3.7949004280025522, -1.7948542304601036, 1.1014231854409053],
-    [4.093031434421698, -1.9358596950973885, 1.2812786926416686],
-    [3.9931130657427176, -2.13436340075009, 1.2812786926416686],
-    [3.7949004280025522, -1.7948542304601036, 1.1014231854409053],
-    [3.878398627085418, -1.606485311627975, 1.1014231854409053],
-    [4.1830893318679445, -1.7326923338779145, 1.2812786926416686],
-    [4.093031434421698, -1.9358596950973885, 1.2812786926416686],
-    [3.878398627085418, -1.606485311627975, 1.1014231854409053],
-    [3.9525534241680376, -1.4142462289686366, 1.1014231854409053],
-    [4.26306980071837, -1.5253507650602005, 1.2812786926416686],
-    [4.1830893318679445, -1.7326923338779145, 1.2812786926416686],
-    [3.9525534241680376, -1.4142462289686366, 1.1014231854409053],
-    [4.017186173849043, -1.2186001032703766, 1.1014231854409053],
-    [4.332780161018049, -1.3143344926445126, 1.2812786926416686],
-    [4.26306980071837, -1.5253507650602005, 1.2812786926416686],
-    [4.017186173849043, -1.2186001032703766, 1.1014231854409053],
-    [4.072141170173294, -1.0200182631865753, 1.1014231854409053],
-    [4.3920524744031155, -1.1001518733139393, 1.2812786926416686],
-    [4.332780161018049, -1.3143344926445126, 1.2812786926416686],
-    [4.072141170173294, -1.0200182631865753, 1.1014231854409053],
-    [4.117286021741157, -0.8189791097637097, 1.1014231854409053],
-    [4.440743948678983, -0.8833188917586291, 1.2812786926416686],
-    [4.3920524744031155, -1.1001518733139393, 1.2812786926416686],
-    [4.117286021741157, -0.8189791097637097, 1.1014231854409053],
-    [4.152511970650983, -0.6159669639313254, 1.1014231854409053],
-    [4.478737281819251, -0.6643579176234754, 1.2812786926416686],
-    [4.440743948678983, -0.8833188917586291, 1.2812786926416686],
-    [4.152511970650983, -0.6159669639313254, 1.1014231854409053],
-    [4.177734154506419, -0.4114708997304929, 1.1014231854409053],
-    [4.5059409445565635, -0.4437964470738818, 1.2812786926416686],
-    [4.478737281819251, -0.6643579176234754, 1.2812786926416686],
-    [4.177734154506419, -0.4114708997304929, 1.1014231854409053],
-    [4.192891810857355, -0.20598356609161908, 1.1014231854409053],
-    [4.522289400884625, -0.22216583201131337, 1.2812786926416686],
-    [4.5059409445565635, -0.4437964470738818, 1.2812786926416686],
-    [4.192891810857355, -0.20598356609161908, 1.1014231854409053],
-    [
-        4.197948423581987,
-        -1.0282008199850715e-15,
-        1.1014231854409053,
-    ],
-    [
-        4.5277432659401855,
-        -1.1089772595989262e-15,
-        1.2812786926416686,
-    ],
-    [4.522289400884625, -0.22216583201131337, 1.2812786926416686],
-    [4.5277432659401855, 0.0, 1.2812786926416686],
-    [4.522289400884625, 0.22216583201131304, 1.2812786926416686],
-    [4.851686990911895, 0.2383480979310073, 1.4747297795981904],
-    [4.857538108298384, 0.0, 1.4747297795981904],
-    [4.522289400884625, 0.22216583201131304, 1.2812786926416686],
-    [4.5059409445565635, 0.4437964470738822, 1.2812786926416686],
-    [4.8341477346067085, 0.4761219944172711, 1.4747297795981904],
-    [4.851686990911895, 0.2383480979310073, 1.4747297795981904],
-    [4.5059409445565635, 0.4437964470738822, 1.2812786926416686],
-    [4.478737281819252, 0.6643579176234725, 1.2812786926416686],
-    [4.80496259298752, 0.7127488713156224, 1.4747297795981904],
-    [4.8341477346067085, 0.4761219944172711, 1.4747297795981904],
-    [4.478737281819252, 0.6643579176234725, 1.2812786926416686],
-    [4.440743948678983, 0.883318891758627, 1.2812786926416686],
-    [4.764201875616808, 0.9476586737535463, 1.4747297795981904],
-    [4.80496259298752, 0.7127488713156224, 1.4747297795981904],
-    [4.440743948678983, 0.883318891758627, 1.2812786926416686],
-    [4.3920524744031155, 1.1001518733139377, 1.2812786926416686],
-    [4.711963778632937, 1.1802854834413015, 1.4747297795981904],
-    [4.764201875616808, 0.9476586737535463, 1.4747297795981904],
-    [4.3920524744031155, 1.1001518733139377, 1.2812786926416686],
-    [4.332780161018049, 1.314334492644512, 1.2812786926416686],
-    [4.648374148187054, 1.410068882018648, 1.4747297795981904],
-    [4.711963778632937, 1.1802854834413015, 1.4747297795981904],
-    [4.332780161018049, 1.314334492644512, 1.2812786926416686],
-    [4.26306980071837, 1.5253507650602007, 1.2812786926416686],
-    [4.573586177268702, 1.6364553011517646, 1.4747297795981904],
-    [4.648374148187054, 1.410068882018648, 1.4747297795981904],
-    [4.26306980071837, 1.5253507650602007, 1.2812786926416686],
-    [4.183089331867945, 1.7326923338779117, 1.2812786926416686],
-    [4.487780036650472, 1.8588993561278508, 1.4747297795981904],
-    [4.573586177268702, 1.6364553011517646, 1.4747297795981904],
-    [4.183089331867945, 1.7326923338779117, 1.2812786926416686],
-    [4.093031434421699, 1.9358596950973865, 1.2812786926416686],
-    [4.391162440840844, 2.0768651597346715, 1.4747297795981904],
-    [4.487780036650472, 1.8588993561278508, 1.4747297795981904],
-    [4.093031434421699, 1.9358596950973865, 1.2812786926416686],
-    [3.993113065742719, 2.1343634007500887, 1.2812786926416686],
-    [4.283966150090828, 2.289827613259788, 1.4747297795981904],
-    [4.391162440840844, 2.0768651597346715, 1.4747297795981904],
-    [3.993113065742719, 2.1343634007500887, 1.2812786926416686],
-    [3.8835749379329676, 2.327725238022229, 1.2812786926416686],
-    [4.166449409654125, 2.49727367149935, 1.4747297795981904],
-    [4.283966150090828, 2.289827613259788, 1.4747297795981904],
-    [3.8835749379329676, 2.327725238022229, 1.2812786926416686],
-    [3.764680937936746, 2.5154793813113234, 1.2812786926416686],
-    [4.038895327651697, 2.698703578728931, 1.4747297795981904],
-    [4.166449409654125, 2.49727367149935, 1.4747297795981904],
-    [3.764680937936746, 2.5154793813113234, 1.2812786926416686],
-    [3.6367174918139296, 2.697173514440867, 1.2812786926416686],
-    [3.901611193039507, 2.893632072658838, 1.4747297795981904],
-    [4.038895327651697, 2.698703578728931, 1.4747297795981904],
-    [3.6367174918139296, 2.697173514440867, 1.2812786926416686],
-    [3.4999928747145024, 2.8723699203296245, 1.2812786926416686],
-    [3.754927735322506, 3.081589553473474, 1.4747297795981904],
-    [3.901611193039507, 2.893632072658838, 1.4747297795981904],
-    [3.4999928747145024, 2.8723699203296245, 1.2812786926416686],
-    [3.354836468216719, 3.040646535490416, 1.2812786926416686],
-    [3.5991983277982875, 3.2621232151384256, 1.4747297795981904],
-    [3.754927735322506, 3.081589553473474, 1.4747297795981904],
-    [3.354836468216719, 3.040646535490416, 1.2812786926416686],
-    [3.201597966818031, 3.201597966818031, 1.2812786926416686],
-    [3.434798136249862, 3.434798136249862, 1.4747297795981904],
-    [3.5991983277982875, 3.2621232151384256, 1.4747297795981904],
-    [3.201597966818031, 3.201597966818031, 1.2812786926416686],
-    [3.040646535490416, 3.3548364682167184, 1.2812786926416686],
-    [3.2621232151384256, 3.599198327798287, 1.4747297795981904],
-    [3.434798136249862, 3.434798136249862, 1.4747297795981904],
-    [3.040646535490416, 3.3548364682167184, 1.2812786926416686],
-    [2.8723699203296245, 3.499992874714502, 1.2812786926416686],
-    [3.081589553473474, 3.7549277353225055, 1.4747297795981904],
-    [3.2621232151384256, 3.599198327798287, 1.4747297795981904],
-    [2.8723699203296245, 3.499992874714502, 1.2812786926416686],
-    [2.697173514440868, 3.636717491813929, 1.2812786926416686],
-    [2.8936320726588387, 3.9016111930395065, 1.4747297795981904],
-    [3.081589553473474, 3.7549277353225055, 1.4747297795981904],
-    [2.697173514440868, 3.636717491813929, 1.2812786926416686],
-    [2.515479381311324, 3.764680937936746, 1.2812786926416686],
-    [2.6987035787289315, 4.038895327651697, 1.4747297795981904],
-    [2.8936320726588387, 3.9016111930395065, 1.4747297795981904],
-    [2.515479381311324, 3.764680937936746, 1.2812786926416686],
-    [2.327725238022229, 3.8835749379329676, 1.2812786926416686],
-    [2.49727367149935, 4.166449409654125, 1.4747297795981904],
-    [2.6987035787289315, 4.038895327651697, 1.4747297795981904],
-    [2.327725238022229, 3.8835749379329676, 1.2812786926416686],
-    [2.1343634007500896, 3.993113065742718, 1.2812786926416686],
-    [2.2898276132597886, 4.283966150090827, 1.4747297795981904],
-    [2.49727367149935, 4.166449409654125, 1.4747297795981904],
-    [2.1343634007500896, 3.993113065742718, 1.2812786926416686],
-    [1.935859695097387, 4.093031434421699, 1.2812786926416686],
-    [2.076865159734672, 4.391162440840844, 1.4747297795981904],
-    [2.2898276132597886, 4.283966150090827, 1.4747297795981904],
-    [1.935859695097387, 4.093031434421699, 1.2812786926416686],
-    [1.7326923338779119, 4.183089331867945, 1.2812786926416686],
-    [1.8588993561278513, 4.487780036650472, 1.4747297795981904],
-    [2.076865159734672, 4.391162440840844, 1.4747297795981904],
-    [1.7326923338779119, 4.183089331867945, 1.2812786926416686],
-    [1.5253507650602007, 4.26306980071837, 1.2812786926416686],
-    [1.6364553011517646, 4.573586177268702, 1.4747297795981904],
-    [1.8588993561278513, 4.487780036650472, 1.4747297795981904],
-    [1.5253507650602007, 4.26306980071837, 1.2812786926416686],
-    [1.314334492644512, 4.332780161018049, 1.2812786926416686],
-    [1.410068882018648, 4.648374148187055, 1.4747297795981904],
-    [1.6364553011517646, 4.573586177268702, 1.4747297795981904],
-    [1.314334492644512, 4.332780161018049, 1.2812786926416686],
-    [1.1001518733139384, 4.3920524744031155, 1.2812786926416686],
-    [1.1802854834413021, 4.711963778632937, 1.4747297795981904],
-    [1.410068882018648, 4.648374148187055, 1.4747297795981904],
-    [1.1001518733139384, 4.3920524744031155, 1.2812786926416686],
-    [0.8833188917586273, 4.440743948678983, 1.2812786926416686],
-    [0.9476586737535466, 4.764201875616808, 1.4747297795981904],
-    [1.1802854834413021, 4.711963778632937, 1.4747297795981904],
-    [0.8833188917586273, 4.440743948678983, 1.2812786926416686],
-    [0.6643579176234725, 4.478737281819252, 1.2812786926416686],
-    [0.7127488713156224, 4.80496259298752, 1.4747297795981904],
-    [0.9476586737535466, 4.764201875616808, 1.4747297795981904],
-    [0.6643579176234725, 4.478737281819252, 1.2812786926416686],
-    [0.44379644707388294, 4.5059409445565635, 1.2812786926416686],
-    [0.47612199441727193, 4.834147734606708, 1.4747297795981904],
-    [0.7127488713156224, 4.80496259298752, 1.4747297795981904],
-    [0.44379644707388294, 4.5059409445565635, 1.2812786926416686],
-    [0.22216583201131354, 4.522289400884625, 1.2812786926416686],
-    [0.23834809793100784, 4.851686990911895, 1.4747297795981904],
-    [0.47612199441727193, 4.834147734606708, 1.4747297795981904],
-    [0.22216583201131354, 4.522289400884625, 1.2812786926416686],
-    [
-        2.7724431489973155e-16,
-        4.5277432659401855,
-        1.2812786926416686,
-    ],
-    [
-        2.9743842480319527e-16,
-        4.857538108298384,
-        1.4747297795981904,
-    ],
-    [0.23834809793100784, 4.851686990911895, 1.4747297795981904],
-    [
-        2.7724431489973155e-16,
-        4.5277432659401855,
-        1.2812786926416686,
-    ],
-    [-0.222165832011313, 4.522289400884625, 1.2812786926416686],
-    [-0.23834809793100728, 4.851686990911895, 1.4747297795981904],
-    [
-        2.9743842480319527e-16,
-        4.857538108298384,
-        1.4747297795981904,
-    ],
-    [-0.222165832011313, 4.522289400884625, 1.2812786926416686],
-    [-0.4437964470738824, 4.5059409445565635, 1.2812786926416686],
-    [-0.4761219944172713, 4.8341477346067085, 1.4747297795981904],
-    [-0.23834809793100728, 4.851686990911895, 1.4747297795981904],
-    [-0.4437964470738824, 4.5059409445565635, 1.2812786926416686],
-    [-0.6643579176234721, 4.478737281819252, 1.2812786926416686],
-    [-0.7127488713156218, 4.80496259298752, 1.4747297795981904],
-    [-0.4761219944172713, 4.8341477346067085, 1.4747297795981904],
-    [-0.6643579176234721, 4.478737281819252, 1.2812786926416686],
-    [-0.8833188917586268, 4.440743948678983, 1.2812786926416686],
-    [-0.947658673753546, 4.764201875616808, 1.4747297795981904],
-    [-0.7127488713156218, 4.80496259298752, 1.4747297795981904],
-    [-0.8833188917586268, 4.440743948678983, 1.2812786926416686],
-    [-1.1001518733139377, 4.3920524744031155, 1.2812786926416686],
-    [-1.1802854834413015, 4.711963778632937, 1.4747297795981904],
-    [-0.947658673753546, 4.764201875616808, 1.4747297795981904],
-    [-1.1001518733139377, 4.3920524744031155, 1.2812786926416686],
-    [-1.3143344926445113, 4.332780161018049, 1.2812786926416686],
-    [-1.410068882018647, 4.648374148187055, 1.4747297795981904],
-    [-1.1802854834413015, 4.711963778632937, 1.4747297795981904],
-    [-1.3143344926445113, 4.332780161018049, 1.2812786926416686],
-    [-1.5253507650602003, 4.26306980071837, 1.2812786926416686],
-    [-1.6364553011517642, 4.573586177268702, 1.4747297795981904],
-    [-1.410068882018647, 4.648374148187055, 1.4747297795981904],
-    [-1.5253507650602003, 4.26306980071837, 1.2812786926416686],
-    [-1.7326923338779114, 4.183089331867945, 1.2812786926416686],
-    [-1.8588993561278506, 4.487780036650472, 1.4747297795981904],
-    [-1.6364553011517642, 4.573586177268702, 1.4747297795981904],
-    [-1.7326923338779114, 4.183089331867945, 1.2812786926416686],
-    [-1.9358596950973856, 4.093031434421699, 1.2812786926416686],
-    [-2.0768651597346706, 4.391162440840845, 1.4747297795981904],
-    [-1.8588993561278506, 4.487780036650472, 1.4747297795981904],
-    [-1.9358596950973856, 4.093031434421699, 1.2812786926416686],
-    [-2.134363400750089, 3.993113065742719, 1.2812786926416686],
-    [-2.289827613259788, 4.283966150090828, 1.4747297795981904],
-    [-2.0768651597346706, 4.391162440840845, 1.4747297795981904],
-    [-2.134363400750089, 3.993113065742719, 1.2812786926416686],
-    [-2.3277252380222286, 3.8835749379329676, 1.2812786926416686],
-    [-2.4972736714993498, 4.166449409654125, 1.4747297795981904],
-    [-2.289827613259788, 4.283966150090828, 1.4747297795981904],
-    [-2.3277252380222286, 3.8835749379329676, 1.2812786926416686],
-    [-2.5154793813113225, 3.7646809379367463, 1.2812786926416686],
-    [-2.6987035787289297, 4.038895327651697, 1.4747297795981904],
-    [-2.4972736714993498, 4.166449409654125, 1.4747297795981904],
-    [-2.5154793813113225, 3.7646809379367463, 1.2812786926416686],
-    [-2.697173514440867, 3.6367174918139296, 1.2812786926416686],
-    [-2.893632072658838, 3.901611193039507, 1.4747297795981904],
-    [-2.6987035787289297, 4.038895327651697, 1.4747297795981904],
-    [-2.697173514440867, 3.6367174918139296, 1.2812786926416686],
-    [-2.872369920329624, 3.499992874714503, 1.2812786926416686],
-    [-3.0815895534734734, 3.7549277353225063, 1.4747297795981904],
-    [-2.893632072658838, 3.901611193039507, 1.4747297795981904],
-    [-2.872369920329624, 3.499992874714503, 1.2812786926416686],
-    [-3.0406465354904166, 3.354836468216718, 1.2812786926416686],
-    [-3.262123215138426, 3.5991983277982866, 1.4747297795981904],
-    [-3.0815895534734734, 3.7549277353225063, 1.4747297795981904],
-    [-3.0406465354904166, 3.354836468216718, 1.2812786926416686],
-    [-3.2015979668180305, 3.201597966818031, 1.2812786926416686],
-    [-3.4347981362498614, 3.434798136249862, 1.4747297795981904],
-    [-3.262123215138426, 3.5991983277982866, 1.4747297795981904],
-    [-3.2015979668180305, 3.201597966818031, 1.2812786926416686],
-    [-3.354836468216717, 3.0406465354904175, 1.2812786926416686],
-    [-3.599198327798286, 3.2621232151384265, 1.4747297795981904],
-    [-3.4347981362498614, 3.434798136249862, 1.4747297795981904],
-    [-3.354836468216717, 3.0406465354904175, 1.2812786926416686],
-    [-3.4999928747145024, 2.8723699203296245, 1.2812786926416686],
-    [-3.754927735322506, 3.081589553473474, 1.4747297795981904],
-    [-3.599198327798286, 3.2621232151384265, 1.4747297795981904],
-    [-3.4999928747145024, 2.8723699203296245, 1.2812786926416686],
-    [-3.636717491813929, 2.697173514440868, 1.2812786926416686],
-    [-3.9016111930395065, 2.8936320726588387, 1.4747297795981904],
-    [-3.754927735322506, 3.081589553473474, 1.4747297795981904],
-    [-3.636717491813929, 2.697173514440868, 1.2812786926416686],
-    [-3.7646809379367463, 2.5154793813113234, 1.2812786926416686],
-    [-4.038895327651697, 2.698703578728931, 1.4747297795981904],
-    [-3.9016111930395065, 2.8936320726588387, 1.4747297795981904],
-    [-3.7646809379367463, 2.5154793813113234, 1.2812786926416686],
-    [-3.883574937932967, 2.3277252380222295, 1.2812786926416686],
-    [-4.166449409654124, 2.4972736714993506, 1.4747297795981904],
-    [-4.038895327651697, 2.698703578728931, 1.4747297795981904],
-    [-3.883574937932967, 2.3277252380222295, 1.2812786926416686],
-    [-3.993113065742718, 2.1343634007500896, 1.2812786926416686],
-    [-4.283966150090827, 2.2898276132597886, 1.4747297795981904],
-    [-4.166449409654124, 2.4972736714993506, 1.4747297795981904],
-    [-3.993113065742718, 2.1343634007500896, 1.2812786926416686],
-    [-4.093031434421699, 1.9358596950973863, 1.2812786926416686],
-    [-4.391162440840844, 2.076865159734671, 1.4747297795981904],
-    [-4.283966150090827, 2.2898276132597886, 1.4747297795981904],
-    [-4.093031434421699, 1.9358596950973863, 1.2812786926416686],
-    [-4.183089331867945, 1.732692333877912, 1.2812786926416686],
-    [-4.487780036650472, 1.8588993561278515, 1.4747297795981904],
-    [-4.391162440840844, 2.076865159734671, 1.4747297795981904],
-    [-4.183089331867945, 1.732692333877912, 1.2812786926416686],
-    [-4.263069800718369, 1.525350765060202, 1.2812786926416686],
-    [-4.573586177268701, 1.636455301151766, 1.4747297795981904],
-    [-4.487780036650472, 1.8588993561278515, 1.4747297795981904],
-    [-4.263069800718369, 1.525350765060202, 1.2812786926416686],
-    [-4.332780161018049, 1.3143344926445122, 1.2812786926416686],
-    [-4.648374148187054, 1.4100688820186482, 1.4747297795981904],
-    [-4.573586177268701, 1.636455301151766, 1.4747297795981904],
-    [-4.332780161018049, 1.3143344926445122, 1.2812786926416686],
-    [-4.3920524744031155, 1.1001518733139386, 1.2812786926416686],
-    [-4.711963778632937, 1.1802854834413024, 1.4747297795981904],
-    [-4.648374148187054, 1.4100688820186482, 1.4747297795981904],
-    [-4.3920524744031155, 1.1001518733139386, 1.2812786926416686],
-    [-4.440743948678983, 0.8833188917586287, 1.2812786926416686],
-    [-4.764201875616808, 0.9476586737535481, 1.4747297795981904],
-    [-4.711963778632937, 1.1802854834413024, 1.4747297795981904],
-    [-4.440743948678983, 0.8833188917586287, 1.2812786926416686],
-    [-4.478737281819252, 0.6643579176234728, 1.2812786926416686],
-    [-4.80496259298752, 0.7127488713156226, 1.4747297795981904],
-    [-4.764201875616808, 0.9476586737535481, 1.4747297795981904],
-    [-4.478737281819252, 0.6643579176234728, 1.2812786926416686],
-    [-4.5059409445565635, 0.4437964470738832, 1.2812786926416686],
-    [-4.834147734606708, 0.47612199441727215, 1.4747297795981904],
-    [-4.80496259298752, 0.7127488713156226, 1.4747297795981904],
-    [-4.5059409445565635, 0.4437964470738832, 1.2812786926416686],
-    [-4.522289400884625, 0.22216583201131282, 1.2812786926416686],
-    [-4.851686990911895, 0.23834809793100706, 1.4747297795981904],
-    [-4.834147734606708, 0.47612199441727215, 1.4747297795981904],
-    [-4.522289400884625, 0.22216583201131282, 1.2812786926416686],
-    [
-        -4.5277432659401855,
-        5.544886297994631e-16,
-        1.2812786926416686,
-    ],
-    [
-        -4.857538108298384,
-        5.948768496063905e-16,
-        1.4747297795981904,
-    ],
-    [-4.851686990911895, 0.23834809793100706, 1.4747297795981904],
-    [
-        -4.5277432659401855,
-        5.544886297994631e-16,
-        1.2812786926416686,
-    ],
-    [-4.522289400884625, -0.2221658320113117, 1.2812786926416686],
-    [-4.851686990911895, -0.2383480979310059, 1.4747297795981904],
-    [
-        -4.857538108298384,
-        5.948768496063905e-16,
-        1.4747297795981904,
-    ],
-    [-4.522289400884625, -0.2221658320113117, 1.2812786926416686],
-    [
-        -4.5059409445565635,
-        -0.44379644707388216,
-        1.2812786926416686,
-    ],
-    [
-        -4.8341477346067085,
-        -0.47612199441727104,
-        1.4747297795981904,
-    ],
-    [-4.851686990911895, -0.2383480979310059, 1.4747297795981904],
-    [
-        -4.5059409445565635,
-        -0.44379644707388216,
-        1.2812786926416686,
-    ],
-    [-4.478737281819252, -0.6643579176234718, 1.2812786926416686],
-    [-4.80496259298752, -0.7127488713156215, 1.4747297795981904],
-    [
-        -4.8341477346067085,
-        -0.47612199441727104,
-        1.4747297795981904,
-    ],
-    [-4.478737281819252, -0.6643579176234718, 1.2812786926416686],
-    [-4.440743948678983, -0.8833188917586275, 1.2812786926416686],
-    [-4.764201875616808, -0.9476586737535468, 1.4747297795981904],
-    [-4.80496259298752, -0.7127488713156215, 1.4747297795981904],
-    [-4.440743948678983, -0.8833188917586275, 1.2812786926416686],
-    [-4.3920524744031155, -1.1001518733139375, 1.2812786926416686],
-    [-4.711963778632937, -1.1802854834413012, 1.4747297795981904],
-    [-4.764201875616808, -0.9476586737535468, 1.4747297795981904],
-    [-4.3920524744031155, -1.1001518733139375, 1.2812786926416686],
-    [-4.332780161018049, -1.3143344926445109, 1.2812786926416686],
-    [-4.648374148187055, -1.4100688820186469, 1.4747297795981904],
-    [-4.711963778632937, -1.1802854834413012, 1.4747297795981904],
-    [-4.332780161018049, -1.3143344926445109, 1.2812786926416686],
-    [-4.26306980071837, -1.525350765060201, 1.2812786926416686],
-    [-4.573586177268702, -1.6364553011517649, 1.4747297795981904],
-    [-4.648374148187055, -1.4100688820186469, 1.4747297795981904],
-    [-4.26306980071837, -1.525350765060201, 1.2812786926416686],
-    [-4.183089331867945, -1.7326923338779112, 1.2812786926416686],
-    [-4.487780036650472, -1.8588993561278504, 1.4747297795981904],
-    [-4.573586177268702, -1.6364553011517649, 1.4747297795981904],
-    [-4.183089331867945, -1.7326923338779112, 1.2812786926416686],
-    [-4.093031434421699, -1.9358596950973852, 1.2812786926416686],
-    [-4.391162440840845, -2.07686515973467, 1.4747297795981904],
-    [-4.487780036650472, -1.8588993561278504, 1.4747297795981904],
-    [-4.093031434421699, -1.9358596950973852, 1.2812786926416686],
-    [-3.993113065742719, -2.1343634007500887, 1.2812786926416686],
-    [-4.283966150090828, -2.289827613259788, 1.4747297795981904],
-    [-4.391162440840845, -2.07686515973467, 1.4747297795981904],
-    [-3.993113065742719, -2.1343634007500887, 1.2812786926416686],
-    [-3.8835749379329676, -2.3277252380222286, 1.2812786926416686],
-    [-4.166449409654125, -2.4972736714993498, 1.4747297795981904],
-    [-4.283966150090828, -2.289827613259788, 1.4747297795981904],
-    [-3.8835749379329676, -2.3277252380222286, 1.2812786926416686],
-    [-3.764680937936747, -2.5154793813113225, 1.2812786926416686],
-    [-4.038895327651698, -2.6987035787289297, 1.4747297795981904],
-    [-4.166449409654125, -2.4972736714993498, 1.4747297795981904],
-    [-3.764680937936747, -2.5154793813113225, 1.2812786926416686],
-    [-3.6367174918139296, -2.6971735144408666, 1.2812786926416686],
-    [-3.901611193039507, -2.8936320726588374, 1.4747297795981904],
-    [-4.038895327651698, -2.6987035787289297, 1.4747297795981904],
-    [-3.6367174918139296, -2.6971735144408666, 1.2812786926416686],
-    [-3.499992874714503, -2.8723699203296236, 1.2812786926416686],
-    [-3.7549277353225063, -3.081589553473473, 1.4747297795981904],
-    [-3.901611193039507, -2.8936320726588374, 1.4747297795981904],
-    [-3.499992874714503, -2.8723699203296236, 1.2812786926416686],
-    [-3.3548364682167184, -3.0406465354904166, 1.2812786926416686],
-    [-3.599198327798287, -3.262123215138426, 1.4747297795981904],
-    [-3.7549277353225063, -3.081589553473473, 1.4747297795981904],
-    [-3.3548364682167184, -3.0406465354904166, 1.2812786926416686],
-    [-3.2015979668180314, -3.2015979668180305, 1.2812786926416686],
-    [-3.4347981362498623, -3.4347981362498614, 1.4747297795981904],
-    [-3.599198327798287, -3.262123215138426, 1.4747297795981904],
-    [-3.2015979668180314, -3.2015979668180305, 1.2812786926416686],
-    [-3.040646535490418, -3.354836468216717, 1.2812786926416686],
-    [-3.262123215138427, -3.599198327798286, 1.4747297795981904],
-    [-3.4347981362498623, -3.4347981362498614, 1.4747297795981904],
-    [-3.040646535490418, -3.354836468216717, 1.2812786926416686],
-    [-2.8723699203296262, -3.4999928747145006, 1.2812786926416686],
-    [-3.081589553473476, -3.754927735322504, 1.4747297795981904],
-    [-3.262123215138427, -3.599198327798286, 1.4747297795981904],
-    [-2.8723699203296262, -3.4999928747145006, 1.2812786926416686],
-    [-2.697173514440866, -3.63671749181393, 1.2812786926416686],
-    [-2.893632072658837, -3.9016111930395074, 1.4747297795981904],
-    [-3.081589553473476, -3.754927735322504, 1.4747297795981904],
-    [-2.697173514440866, -3.63671749181393, 1.2812786926416686],
-    [-2.5154793813113234, -3.764680937936746, 1.2812786926416686],
-    [-2.698703578728931, -4.038895327651697, 1.4747297795981904],
-    [-2.893632072658837, -3.9016111930395074, 1.4747297795981904],
-    [-2.5154793813113234, -3.764680937936746, 1.2812786926416686],
-    [-2.3277252380222295, -3.883574937932967, 1.2812786926416686],
-    [-2.4972736714993506, -4.166449409654124, 1.4747297795981904],
-    [-2.698703578728931, -4.038895327651697, 1.4747297795981904],
-    [-2.3277252380222295, -3.883574937932967, 1.2812786926416686],
-    [-2.1343634007500896, -3.993113065742718, 1.2812786926416686],
-    [-2.289827613259789, -4.283966150090827, 1.4747297795981904],
-    [-2.4972736714993506, -4.166449409654124, 1.4747297795981904],
-    [-2.1343634007500896, -3.993113065742718, 1.2812786926416686],
-    [-1.9358596950973883, -4.093031434421698, 1.2812786926416686],
-    [-2.0768651597346732, -4.391162440840843, 1.4747297795981904],
-    [-2.289827613259789, -4.283966150090827, 1.4747297795981904],
-    [-1.9358596950973883, -4.093031434421698, 1.2812786926416686],
-    [-1.732692333877914, -4.1830893318679445, 1.2812786926416686],
-    [-1.8588993561278537, -4.48778003665047, 1.4747297795981904],
-    [-2.0768651597346732, -4.391162440840843, 1.4747297795981904],
-    [-1.732692333877914, -4.1830893318679445, 1.2812786926416686],
-    [-1.5253507650602003, -4.26306980071837, 1.2812786926416686],
-    [-1.6364553011517642, -4.573586177268702, 1.4747297795981904],
-    [-1.8588993561278537, -4.48778003665047, 1.4747297795981904],
-    [-1.5253507650602003, -4.26306980071837, 1.2812786926416686],
-    [-1.3143344926445124, -4.332780161018049, 1.2812786926416686],
-    [-1.4100688820186484, -4.648374148187054, 1.4747297795981904],
-    [-1.6364553011517642, -4.573586177268702, 1.4747297795981904],
-    [-1.3143344926445124, -4.332780161018049, 1.2812786926416686],
-    [-1.1001518733139388, -4.3920524744031155, 1.2812786926416686],
-    [-1.1802854834413028, -4.711963778632937, 1.4747297795981904],
-    [-1.4100688820186484, -4.648374148187054, 1.4747297795981904],
-    [-1.1001518733139388, -4.3920524744031155, 1.2812786926416686],
-    [-0.8833188917586289, -4.440743948678983, 1.2812786926416686],
-    [-0.9476586737535483, -4.764201875616808, 1.4747297795981904],
-    [-1.1802854834413028, -4.711963778632937, 1.4747297795981904],
-    [-0.8833188917586289, -4.440743948678983, 1.2812786926416686],
-    [-0.6643579176234751, -4.478737281819251, 1.2812786926416686],
-    [-0.712748871315625, -4.80496259298752, 1.4747297795981904],
-    [-0.9476586737535483, -4.764201875616808, 1.4747297795981904],
-    [-0.6643579176234751, -4.478737281819251, 1.2812786926416686],
-    [-0.4437964470738815, -4.5059409445565635, 1.2812786926416686],
-    [-0.4761219944172704, -4.8341477346067085, 1.4747297795981904],
-    [-0.712748871315625, -4.80496259298752, 1.4747297795981904],
-    [-0.4437964470738815, -4.5059409445565635, 1.2812786926416686],
-    [-0.2221658320113131, -4.522289400884625, 1.2812786926416686],
-    [-0.23834809793100736, -4.851686990911895, 1.4747297795981904],
-    [-0.4761219944172704, -4.8341477346067085, 1.4747297795981904],
-    [-0.2221658320113131, -4.522289400884625, 1.2812786926416686],
-    [
-        -8.317329446991947e-16,
-        -4.5277432659401855,
-        1.2812786926416686,
-    ],
-    [
-        -8.923152744095859e-16,
-        -4.857538108298384,
-        1.4747297795981904,
-    ],
-    [-0.23834809793100736, -4.851686990911895, 1.4747297795981904],
-    [
-        -8.317329446991947e-16,
-        -4.5277432659401855,
-        1.2812786926416686,
-    ],
-    [0.22216583201131143, -4.522289400884625, 1.2812786926416686],
-    [0.2383480979310056, -4.851686990911895, 1.4747297795981904],
-    [
-        -8.923152744095859e-16,
-        -4.857538108298384,
-        1.4747297795981904,
-    ],
-    [0.22216583201131143, -4.522289400884625, 1.2812786926416686],
-    [0.4437964470738799, -4.5059409445565635, 1.2812786926416686],
-    [0.4761219944172686, -4.8341477346067085, 1.4747297795981904],
-    [0.2383480979310056, -4.851686990911895, 1.4747297795981904],
-    [0.4437964470738799, -4.5059409445565635, 1.2812786926416686],
-    [0.6643579176234734, -4.478737281819251, 1.2812786926416686],
-    [0.7127488713156233, -4.80496259298752, 1.4747297795981904],
-    [0.4761219944172686, -4.8341477346067085, 1.4747297795981904],
-    [0.6643579176234734, -4.478737281819251, 1.2812786926416686],
-    [0.8833188917586272, -4.440743948678983, 1.2812786926416686],
-    [0.9476586737535465, -4.764201875616808, 1.4747297795981904],
-    [0.7127488713156233, -4.80496259298752, 1.4747297795981904],
-    [0.8833188917586272, -4.440743948678983, 1.2812786926416686],
-    [1.1001518733139373, -4.3920524744031155, 1.2812786926416686],
-    [1.180285483441301, -4.711963778632937, 1.4747297795981904],
-    [0.9476586737535465, -4.764201875616808, 1.4747297795981904],
-    [1.1001518733139373, -4.3920524744031155, 1.2812786926416686],
-    [1.3143344926445106, -4.332780161018049, 1.2812786926416686],
-    [1.4100688820186467, -4.648374148187055, 1.4747297795981904],
-    [1.180285483441301, -4.711963778632937, 1.4747297795981904],
-    [1.3143344926445106, -4.332780161018049, 1.2812786926416686],
-    [1.5253507650601987, -4.26306980071837, 1.2812786926416686],
-    [1.6364553011517624, -4.5735861772687025, 1.4747297795981904],
-    [1.4100688820186467, -4.648374148187055, 1.4747297795981904],
-    [1.5253507650601987, -4.26306980071837, 1.2812786926416686],
-    [1.7326923338779128, -4.1830893318679445, 1.2812786926416686],
-    [1.858899356127852, -4.487780036650471, 1.4747297795981904],
-    [1.6364553011517624, -4.5735861772687025, 1.4747297795981904],
-    [1.7326923338779128, -4.1830893318679445, 1.2812786926416686],
-    [1.9358596950973868, -4.093031434421699, 1.2812786926416686],
-    [2.076865159734672, -4.391162440840844, 1.4747297795981904],
-    [1.858899356127852, -4.487780036650471, 1.4747297795981904],
-    [1.9358596950973868, -4.093031434421699, 1.2812786926416686],
-    [2.1343634007500882, -3.993113065742719, 1.2812786926416686],
-    [2.2898276132597877, -4.283966150090828, 1.4747297795981904],
-    [2.076865159734672, -4.391162440840844, 1.4747297795981904],
-    [2.1343634007500882, -3.993113065742719, 1.2812786926416686],
-    [2.3277252380222286, -3.883574937932968, 1.2812786926416686],
-    [2.4972736714993498, -4.166449409654125, 1.4747297795981904],
-    [2.2898276132597877, -4.283966150090828, 1.4747297795981904],
-    [2.3277252380222286, -3.883574937932968, 1.2812786926416686],
-    [2.515479381311322, -3.764680937936747, 1.2812786926416686],
-    [2.6987035787289293, -4.038895327651698, 1.4747297795981904],
-    [2.4972736714993498, -4.166449409654125, 1.4747297795981904],
-    [2.515479381311322, -3.764680937936747, 1.2812786926416686],
-    [2.697173514440865, -3.6367174918139313, 1.2812786926416686],
-    [2.893632072658835, -3.9016111930395088, 1.4747297795981904],
-    [2.6987035787289293, -4.038895327651698, 1.4747297795981904],
-    [2.697173514440865, -3.6367174918139313, 1.2812786926416686],
-    [2.872369920329625, -3.499992874714502, 1.2812786926416686],
-    [3.0815895534734743, -3.7549277353225055, 1.4747297795981904],
-    [2.893632072658835, -3.9016111930395088, 1.4747297795981904],
-    [2.872369920329625, -3.499992874714502, 1.2812786926416686],
-    [3.040646535490416, -3.3548364682167184, 1.2812786926416686],
-    [3.2621232151384256, -3.599198327798287, 1.4747297795981904],
-    [3.0815895534734743, -3.7549277353225055, 1.4747297795981904],
-    [3.040646535490416, -3.3548364682167184, 1.2812786926416686],
-    [3.20159796681803, -3.2015979668180314, 1.2812786926416686],
-    [3.434798136249861, -3.4347981362498623, 1.4747297795981904],
-    [3.2621232151384256, -3.599198327798287, 1.4747297795981904],
-    [3.20159796681803, -3.2015979668180314, 1.2812786926416686],
-    [3.354836468216717, -3.040646535490418, 1.2812786926416686],
-    [3.599198327798286, -3.262123215138427, 1.4747297795981904],
-    [3.434798136249861, -3.4347981362498623, 1.4747297795981904],
-    [3.354836468216717, -3.040646535490418, 1.2812786926416686],
-    [3.4999928747145, -2.8723699203296262, 1.2812786926416686],
-    [3.7549277353225037, -3.081589553473476, 1.4747297795981904],
-    [3.599198327798286, -3.262123215138427, 1.4747297795981904],
-    [3.4999928747145, -2.8723699203296262, 1.2812786926416686],
-    [3.63671749181393, -2.6971735144408666, 1.2812786926416686],
-    [3.9016111930395074, -2.8936320726588374, 1.4747297795981904],
-    [3.7549277353225037, -3.081589553473476, 1.4747297795981904],
-    [3.63671749181393, -2.6971735144408666, 1.2812786926416686],
-    [3.764680937936746, -2.5154793813113234, 1.2812786926416686],
-    [4.038895327651697, -2.698703578728931, 1.4747297795981904],
-    [3.9016111930395074, -2.8936320726588374, 1.4747297795981904],
-    [3.764680937936746, -2.5154793813113234, 1.2812786926416686],
-    [3.883574937932967, -2.32772523802223, 1.2812786926416686],
-    [4.166449409654124, -2.497273671499351, 1.4747297795981904],
-    [4.038895327651697, -2.698703578728931, 1.4747297795981904],
-    [3.883574937932967, -2.32772523802223, 1.2812786926416686],
-    [3.9931130657427176, -2.13436340075009, 1.2812786926416686],
-    [4.283966150090827, -2.2898276132597895, 1.4747297795981904],
-    [4.166449409654124, -2.497273671499351, 1.4747297795981904],
-    [3.9931130657427176, -2.13436340075009, 1.2812786926416686],
-    [4.093031434421698, -1.9358596950973885, 1.2812786926416686],
-    [4.391162440840843, -2.0768651597346737, 1.4747297795981904],
-    [4.283966150090827, -2.2898276132597895, 1.4747297795981904],
-    [4.093031434421698, -1.9358596950973885, 1.2812786926416686],
-    [4.1830893318679445, -1.7326923338779145, 1.2812786926416686],
-    [4.48778003665047, -1.858899356127854, 1.4747297795981904],
-    [4.391162440840843, -2.0768651597346737, 1.4747297795981904],
-    [4.1830893318679445, -1.7326923338779145, 1.2812786926416686],
-    [4.26306980071837, -1.5253507650602005, 1.2812786926416686],
-    [4.573586177268702, -1.6364553011517644, 1.4747297795981904],
-    [4.48778003665047, -1.858899356127854, 1.4747297795981904],
-    [4.26306980071837, -1.5253507650602005, 1.2812786926416686],
-    [4.332780161018049, -1.3143344926445126, 1.2812786926416686],
-    [4.648374148187054, -1.4100688820186489, 1.4747297795981904],
-    [4.573586177268702, -1.6364553011517644, 1.4747297795981904],
-    [4.332780161018049, -1.3143344926445126, 1.2812786926416686],
-    [4.3920524744031155, -1.1001518733139393, 1.2812786926416686],
-    [4.711963778632937, -1.180285483441303, 1.4747297795981904],
-    [4.648374148187054, -1.4100688820186489, 1.4747297795981904],
-    [4.3920524744031155, -1.1001518733139393, 1.2812786926416686],
-    [4.440743948678983, -0.8833188917586291, 1.2812786926416686],
-    [4.764201875616808, -0.9476586737535485, 1.4747297795981904],
-    [4.711963778632937, -1.180285483441303, 1.4747297795981904],
-    [4.440743948678983, -0.8833188917586291, 1.2812786926416686],
-    [4.478737281819251, -0.6643579176234754, 1.2812786926416686],
-    [4.80496259298752, -0.7127488713156254, 1.4747297795981904],
-    [4.764201875616808, -0.9476586737535485, 1.4747297795981904],
-    [4.478737281819251, -0.6643579176234754, 1.2812786926416686],
-    [4.5059409445565635, -0.4437964470738818, 1.2812786926416686],
-    [4.8341477346067085, -0.4761219944172706, 1.4747297795981904],
-    [4.80496259298752, -0.7127488713156254, 1.4747297795981904],
-    [4.5059409445565635, -0.4437964470738818, 1.2812786926416686],
-    [4.522289400884625, -0.22216583201131337, 1.2812786926416686],
-    [4.851686990911895, -0.23834809793100767, 1.4747297795981904],
-    [4.8341477346067085, -0.4761219944172706, 1.4747297795981904],
-    [4.522289400884625, -0.22216583201131337, 1.2812786926416686],
-    [
-        4.5277432659401855,
-        -1.1089772595989262e-15,
-        1.2812786926416686,
-    ],
-    [
-        4.857538108298384,
-        -1.189753699212781e-15,
-        1.4747297795981904,
-    ],
-    [4.851686990911895, -0.23834809793100767, 1.4747297795981904],
-    [4.857538108298384, 0.0, 1.4747297795981904],
-    [4.851686990911895, 0.2383480979310073, 1.4747297795981904],
-    [5.1810845809391655, 0.25453036385070155, 1.681776446310471],
-    [5.187332950656583, 0.0, 1.681776446310471],
-    [4.851686990911895, 0.2383480979310073, 1.4747297795981904],
-    [4.8341477346067085, 0.4761219944172711, 1.4747297795981904],
-    [5.162354524656853, 0.50844754176066, 1.681776446310471],
-    [5.1810845809391655, 0.25453036385070155, 1.681776446310471],
-    [4.8341477346067085, 0.4761219944172711, 1.4747297795981904],
-    [4.80496259298752, 0.7127488713156224, 1.4747297795981904],
-    [5.131187904155789, 0.7611398250077721, 1.681776446310471],
-    [5.162354524656853, 0.50844754176066, 1.681776446310471],
-    [4.80496259298752, 0.7127488713156224, 1.4747297795981904],
-    [4.764201875616808, 0.9476586737535463, 1.4747297795981904],
-    [5.087659802554634, 1.0119984557484656, 1.681776446310471],
-    [5.131187904155789, 0.7611398250077721, 1.681776446310471],
-    [4.764201875616808, 0.9476586737535463, 1.4747297795981904],
-    [4.711963778632937, 1.1802854834413015, 1.4747297795981904],
-    [5.031875082862757, 1.2604190935686652, 1.681776446310471],
-    [5.087659802554634, 1.0119984557484656, 1.681776446310471],
-    [4.711963778632937, 1.1802854834413015, 1.4747297795981904],
-    [4.648374148187054, 1.410068882018648, 1.4747297795981904],
-    [4.96396813535606, 1.505803271392784, 1.681776446310471],
-    [5.031875082862757, 1.2604190935686652, 1.681776446310471],
-    [4.648374148187054, 1.410068882018648, 1.4747297795981904],
-    [4.573586177268702, 1.6364553011517646, 1.4747297795981904],
-    [4.884102553819034, 1.7475598372433285, 1.681776446310471],
-    [4.96396813535606, 1.505803271392784, 1.681776446310471],
-    [4.573586177268702, 1.6364553011517646, 1.4747297795981904],
-    [4.487780036650472, 1.8588993561278508, 1.4747297795981904],
-    [4.7924707414329974, 1.9851063783777902, 1.681776446310471],
-    [4.884102553819034, 1.7475598372433285, 1.681776446310471],
-    [4.487780036650472, 1.8588993561278508, 1.4747297795981904],
-    [4.391162440840844, 2.0768651597346715, 1.4747297795981904],
-    [4.68929344725999, 2.2178706243719564, 1.681776446310471],
-    [4.7924707414329974, 1.9851063783777902, 1.681776446310471],
-    [4.391162440840844, 2.0768651597346715, 1.4747297795981904],
-    [4.283966150090828, 2.289827613259788, 1.4747297795981904],
-    [4.574819234438937, 2.445291825769487, 1.681776446310471],
-    [4.68929344725999, 2.2178706243719564, 1.681776446310471],
-    [4.283966150090828, 2.289827613259788, 1.4747297795981904],
-    [4.166449409654125, 2.49727367149935, 1.4747297795981904],
-    [4.449323881375281, 2.6668221049764713, 1.681776446310471],
-    [4.574819234438937, 2.445291825769487, 1.681776446310471],
-    [4.166449409654125, 2.49727367149935, 1.4747297795981904],
-    [4.038895327651697, 2.698703578728931, 1.4747297795981904],
-    [4.313109717366648, 2.8819277761465387, 1.681776446310471],
-    [4.449323881375281, 2.6668221049764713, 1.681776446310471],
-    [4.038895327651697, 2.698703578728931, 1.4747297795981904],
-    [3.901611193039507, 2.893632072658838, 1.4747297795981904],
-    [4.166504894265085, 3.0900906308768086, 1.681776446310471],
-    [4.313109717366648, 2.8819277761465387, 1.681776446310471],
-    [3.901611193039507, 2.893632072658838, 1.4747297795981904],
-    [3.754927735322506, 3.081589553473474, 1.4747297795981904],
-    [4.009862595930509, 3.2908091866173232, 1.681776446310471],
-    [4.166504894265085, 3.0900906308768086, 1.681776446310471],
-    [3.754927735322506, 3.081589553473474, 1.4747297795981904],
-    [3.5991983277982875, 3.2621232151384256, 1.4747297795981904],
-    [3.8435601873798566, 3.4835998947864346, 1.681776446310471],
-    [4.009862595930509, 3.2908091866173232, 1.681776446310471],
-    [3.5991983277982875, 3.2621232151384256, 1.4747297795981904],
-    [3.434798136249862, 3.434798136249862, 1.4747297795981904],
-    [3.6679983056816927, 3.6679983056816927, 1.681776446310471],
-    [3.8435601873798566, 3.4835998947864346, 1.681776446310471],
-    [3.434798136249862, 3.434798136249862, 1.4747297795981904],
-    [3.2621232151384256, 3.599198327798287, 1.4747297795981904],
-    [3.4835998947864346, 3.8435601873798557, 1.681776446310471],
-    [3.6679983056816927, 3.6679983056816927, 1.681776446310471],
-    [3.2621232151384256, 3.599198327798287, 1.4747297795981904],
-    [3.081589553473474, 3.7549277353225055, 1.4747297795981904],
-    [3.2908091866173232, 4.009862595930509, 1.681776446310471],
-    [3.4835998947864346, 3.8435601873798557, 1.681776446310471],
-    [3.081589553473474, 3.7549277353225055, 1.4747297795981904],
-    [2.8936320726588387, 3.9016111930395065, 1.4747297795981904],
-    [3.0900906308768095, 4.166504894265084, 1.681776446310471],
-    [3.2908091866173232, 4.009862595930509, 1.681776446310471],
-    [2.8936320726588387, 3.9016111930395065, 1.4747297795981904],
-    [2.6987035787289315, 4.038895327651697, 1.4747297795981904],
-    [2.881927776146539, 4.313109717366648, 1.681776446310471],
-    [3.0900906308768095, 4.166504894265084, 1.681776446310471],
-    [2.6987035787289315, 4.038895327651697, 1.4747297795981904],
-    [2.49727367149935, 4.166449409654125, 1.4747297795981904],
-    [2.6668221049764713, 4.449323881375281, 1.681776446310471],
-    [2.881927776146539, 4.313109717366648, 1.681776446310471],
-    [2.49727367149935, 4.166449409654125, 1.4747297795981904],
-    [2.2898276132597886, 4.283966150090827, 1.4747297795981904],
-    [2.445291825769488, 4.5748192344389365, 1.681776446310471],
-    [2.6668221049764713, 4.449323881375281, 1.681776446310471],
-    [2.2898276132597886, 4.283966150090827, 1.4747297795981904],
-    [2.076865159734672, 4.391162440840844, 1.4747297795981904],
-    [2.217870624371957, 4.68929344725999, 1.681776446310471],
-    [2.445291825769488, 4.5748192344389365, 1.681776446310471],
-    [2.076865159734672, 4.391162440840844, 1.4747297795981904],
-    [1.8588993561278513, 4.487780036650472, 1.4747297795981904],
-    [1.9851063783777905, 4.7924707414329974, 1.681776446310471],
-    [2.217870624371957, 4.68929344725999, 1.681776446310471],
-    [1.8588993561278513, 4.487780036650472, 1.4747297795981904],
-    [1.6364553011517646, 4.573586177268702, 1.4747297795981904],
-    [1.7475598372433285, 4.884102553819034, 1.681776446310471],
-    [1.9851063783777905, 4.7924707414329974, 1.681776446310471],
-    [1.6364553011517646, 4.573586177268702, 1.4747297795981904],
-    [1.410068882018648, 4.648374148187055, 1.4747297795981904],
-    [1.505803271392784, 4.963968135356061, 1.681776446310471],
-    [1.7475598372433285, 4.884102553819034, 1.681776446310471],
-    [1.410068882018648, 4.648374148187055, 1.4747297795981904],
-    [1.1802854834413021, 4.711963778632937, 1.4747297795981904],
-    [1.2604190935686659, 5.031875082862757, 1.681776446310471],
-    [1.505803271392784, 4.963968135356061, 1.681776446310471],
-    [1.1802854834413021, 4.711963778632937, 1.4747297795981904],
-    [0.9476586737535466, 4.764201875616808, 1.4747297795981904],
-    [1.011998455748466, 5.087659802554634, 1.681776446310471],
-    [1.2604190935686659, 5.031875082862757, 1.681776446310471],
-    [0.9476586737535466, 4.764201875616808, 1.4747297795981904],
-    [0.7127488713156224, 4.80496259298752, 1.4747297795981904],
-    [0.7611398250077721, 5.131187904155789, 1.681776446310471],
-    [1.011998455748466, 5.087659802554634, 1.681776446310471],
-    [0.7127488713156224, 4.80496259298752, 1.4747297795981904],
-    [0.47612199441727193, 4.834147734606708, 1.4747297795981904],
-    [0.5084475417606609, 5.162354524656852, 1.681776446310471],
-    [0.7611398250077721, 5.131187904155789, 1.681776446310471],
-    [0.47612199441727193, 4.834147734606708, 1.4747297795981904],
-    [0.23834809793100784, 4.851686990911895, 1.4747297795981904],
-    [0.25453036385070216, 5.1810845809391655, 1.681776446310471],
-    [0.5084475417606609, 5.162354524656852, 1.681776446310471],
-    [0.23834809793100784, 4.851686990911895, 1.4747297795981904],
-    [
-        2.9743842480319527e-16,
-        4.857538108298384,
-        1.4747297795981904,
-    ],
-    [3.17632534706659e-16, 5.187332950656583, 1.681776446310471],
-    [0.25453036385070216, 5.1810845809391655, 1.681776446310471],
-    [
-        2.9743842480319527e-16,
-        4.857538108298384,
-        1.4747297795981904,
-    ],
-    [-0.23834809793100728, 4.851686990911895, 1.4747297795981904],
-    [-0.25453036385070155, 5.1810845809391655, 1.681776446310471],
-    [3.17632534706659e-16, 5.187332950656583, 1.681776446310471],
-    [-0.23834809793100728, 4.851686990911895, 1.4747297795981904],
-    [-0.4761219944172713, 4.8341477346067085, 1.4747297795981904],
-    [-0.5084475417606602, 5.162354524656853, 1.681776446310471],
-    [-0.25453036385070155, 5.1810845809391655, 1.681776446310471],
-    [-0.4761219944172713, 4.8341477346067085, 1.4747297795981904],
-    [-0.7127488713156218, 4.80496259298752, 1.4747297795981904],
-    [-0.7611398250077716, 5.131187904155789, 1.681776446310471],
-    [-0.5084475417606602, 5.162354524656853, 1.681776446310471],
-    [-0.7127488713156218, 4.80496259298752, 1.4747297795981904],
-    [-0.947658673753546, 4.764201875616808, 1.4747297795981904],
-    [-1.0119984557484651, 5.087659802554634, 1.681776446310471],
-    [-0.7611398250077716, 5.131187904155789, 1.681776446310471],
-    [-0.947658673753546, 4.764201875616808, 1.4747297795981904],
-    [-1.1802854834413015, 4.711963778632937, 1.4747297795981904],
-    [-1.2604190935686652, 5.031875082862757, 1.681776446310471],
-    [-1.0119984557484651, 5.087659802554634, 1.681776446310471],
-    [-1.1802854834413015, 4.711963778632937, 1.4747297795981904],
-    [-1.410068882018647, 4.648374148187055, 1.4747297795981904],
-    [-1.5058032713927831, 4.963968135356061, 1.681776446310471],
-    [-1.2604190935686652, 5.031875082862757, 1.681776446310471],
-    [-1.410068882018647, 4.648374148187055, 1.4747297795981904],
-    [-1.6364553011517642, 4.573586177268702, 1.4747297795981904],
-    [-1.747559837243328, 4.884102553819034, 1.681776446310471],
-    [-1.5058032713927831, 4.963968135356061, 1.681776446310471],
-    [-1.6364553011517642, 4.573586177268702, 1.4747297795981904],
-    [-1.8588993561278506, 4.487780036650472, 1.4747297795981904],
-    [-1.9851063783777898, 4.7924707414329974, 1.681776446310471],
-    [-1.747559837243328, 4.884102553819034, 1.681776446310471],
-    [-1.8588993561278506, 4.487780036650472, 1.4747297795981904],
-    [-2.0768651597346706, 4.391162440840845, 1.4747297795981904],
-    [-2.217870624371955, 4.689293447259991, 1.681776446310471],
-    [-1.9851063783777898, 4.7924707414329974, 1.681776446310471],
-    [-2.0768651597346706, 4.391162440840845, 1.4747297795981904],
-    [-2.289827613259788, 4.283966150090828, 1.4747297795981904],
-    [-2.4452918257694876, 4.574819234438937, 1.681776446310471],
-    [-2.217870624371955, 4.689293447259991, 1.681776446310471],
-    [-2.289827613259788, 4.283966150090828, 1.4747297795981904],
-    [-2.4972736714993498, 4.166449409654125, 1.4747297795981904],
-    [-2.6668221049764704, 4.449323881375281, 1.681776446310471],
-    [-2.4452918257694876, 4.574819234438937, 1.681776446310471],
-    [-2.4972736714993498, 4.166449409654125, 1.4747297795981904],
-    [-2.6987035787289297, 4.038895327651697, 1.4747297795981904],
-    [-2.8819277761465374, 4.313109717366648, 1.681776446310471],
-    [-2.6668221049764704, 4.449323881375281, 1.681776446310471],
-    [-2.6987035787289297, 4.038895327651697, 1.4747297795981904],
-    [-2.893632072658838, 3.901611193039507, 1.4747297795981904],
-    [-3.0900906308768086, 4.166504894265085, 1.681776446310471],
-    [-2.8819277761465374, 4.313109717366648, 1.681776446310471],
-    [-2.893632072658838, 3.901611193039507, 1.4747297795981904],
-    [-3.0815895534734734, 3.7549277353225063, 1.4747297795981904],
-    [-3.290809186617323, 4.00986259593051, 1.681776446310471],
-    [-3.0900906308768086, 4.166504894265085, 1.681776446310471],
-    [-3.0815895534734734, 3.7549277353225063, 1.4747297795981904],
-    [-3.262123215138426, 3.5991983277982866, 1.4747297795981904],
-    [-3.4835998947864355, 3.8435601873798553, 1.681776446310471],
-    [-3.290809186617323, 4.00986259593051, 1.681776446310471],
-    [-3.262123215138426, 3.5991983277982866, 1.4747297795981904],
-    [-3.4347981362498614, 3.434798136249862, 1.4747297795981904],
-    [-3.6679983056816923, 3.6679983056816927, 1.681776446310471],
-    [-3.4835998947864355, 3.8435601873798553, 1.681776446310471],
-    [-3.4347981362498614, 3.434798136249862, 1.4747297795981904],
-    [-3.599198327798286, 3.2621232151384265, 1.4747297795981904],
-    [-3.843560187379855, 3.483599894786436, 1.681776446310471],
-    [-3.6679983056816923, 3.6679983056816927, 1.681776446310471],
-    [-3.599198327798286, 3.2621232151384265, 1.4747297795981904],
-    [-3.754927735322506, 3.081589553473474, 1.4747297795981904],
-    [-4.009862595930509, 3.2908091866173232, 1.681776446310471],
-    [-3.843560187379855, 3.483599894786436, 1.681776446310471],
-    [-3.754927735322506, 3.081589553473474, 1.4747297795981904],
-    [-3.9016111930395065, 2.8936320726588387, 1.4747297795981904],
-    [-4.166504894265084, 3.0900906308768095, 1.681776446310471],
-    [-4.009862595930509, 3.2908091866173232, 1.681776446310471],
-    [-3.9016111930395065, 2.8936320726588387, 1.4747297795981904],
-    [-4.038895327651697, 2.698703578728931, 1.4747297795981904],
-    [-4.313109717366648, 2.8819277761465387, 1.681776446310471],
-    [-4.166504894265084, 3.0900906308768095, 1.681776446310471],
-    [-4.038895327651697, 2.698703578728931, 1.4747297795981904],
-    [-4.166449409654124, 2.4972736714993506, 1.4747297795981904],
-    [-4.449323881375281, 2.6668221049764718, 1.681776446310471],
-    [-4.313109717366648, 2.8819277761465387, 1.681776446310471],
-    [-4.166449409654124, 2.4972736714993506, 1.4747297795981904],
-    [-4.283966150090827, 2.2898276132597886, 1.4747297795981904],
-    [-4.5748192344389365, 2.445291825769488, 1.681776446310471],
-    [-4.449323881375281, 2.6668221049764718, 1.681776446310471],
-    [-4.283966150090827, 2.2898276132597886, 1.4747297795981904],
-    [-4.391162440840844, 2.076865159734671, 1.4747297795981904],
-    [-4.68929344725999, 2.217870624371956, 1.681776446310471],
-    [-4.5748192344389365, 2.445291825769488, 1.681776446310471],
-    [-4.391162440840844, 2.076865159734671, 1.4747297795981904],
-    [-4.487780036650472, 1.8588993561278515, 1.4747297795981904],
-    [-4.7924707414329974, 1.9851063783777907, 1.681776446310471],
-    [-4.68929344725999, 2.217870624371956, 1.681776446310471],
-    [-4.487780036650472, 1.8588993561278515, 1.4747297795981904],
-    [-4.573586177268701, 1.636455301151766, 1.4747297795981904],
-    [-4.884102553819033, 1.74755983724333, 1.681776446310471],
-    [-4.7924707414329974, 1.9851063783777907, 1.681776446310471],
-    [-4.573586177268701, 1.636455301151766, 1.4747297795981904],
-    [-4.648374148187054, 1.4100688820186482, 1.4747297795981904],
-    [-4.96396813535606, 1.5058032713927842, 1.681776446310471],
-    [-4.884102553819033, 1.74755983724333, 1.681776446310471],
-    [-4.648374148187054, 1.4100688820186482, 1.4747297795981904],
-    [-4.711963778632937, 1.1802854834413024, 1.4747297795981904],
-    [-5.031875082862757, 1.2604190935686663, 1.681776446310471],
-    [-4.96396813535606, 1.5058032713927842, 1.681776446310471],
-    [-4.711963778632937, 1.1802854834413024, 1.4747297795981904],
-    [-4.764201875616808, 0.9476586737535481, 1.4747297795981904],
-    [-5.087659802554634, 1.0119984557484674, 1.681776446310471],
-    [-5.031875082862757, 1.2604190935686663, 1.681776446310471],
-    [-4.764201875616808, 0.9476586737535481, 1.4747297795981904],
-    [-4.80496259298752, 0.7127488713156226, 1.4747297795981904],
-    [-5.131187904155789, 0.7611398250077723, 1.681776446310471],
-    [-5.087659802554634, 1.0119984557484674, 1.681776446310471],
-    [-4.80496259298752, 0.7127488713156226, 1.4747297795981904],
-    [-4.834147734606708, 0.47612199441727215, 1.4747297795981904],
-    [-5.162354524656852, 0.5084475417606611, 1.681776446310471],
-    [-5.131187904155789, 0.7611398250077723, 1.681776446310471],
-    [-4.834147734606708, 0.47612199441727215, 1.4747297795981904],
-    [-4.851686990911895, 0.23834809793100706, 1.4747297795981904],
-    [-5.1810845809391655, 0.2545303638507013, 1.681776446310471],
-    [-5.162354524656852, 0.5084475417606611, 1.681776446310471],
-    [-4.851686990911895, 0.23834809793100706, 1.4747297795981904],
-    [
-        -4.857538108298384,
-        5.948768496063905e-16,
-        1.4747297795981904,
-    ],
-    [-5.187332950656583, 6.35265069413318e-16, 1.681776446310471],
-    [-5.1810845809391655, 0.2545303638507013, 1.681776446310471],
-    [
-        -4.857538108298384,
-        5.948768496063905e-16,
-        1.4747297795981904,
-    ],
-    [-4.851686990911895, -0.2383480979310059, 1.4747297795981904],
-    [-5.1810845809391655, -0.25453036385070005, 1.681776446310471],
-    [-5.187332950656583, 6.35265069413318e-16, 1.681776446310471],
-    [-4.851686990911895, -0.2383480979310059, 1.4747297795981904],
-    [
-        -4.8341477346067085,
-        -0.47612199441727104,
-        1.4747297795981904,
-    ],
-    [-5.162354524656853, -0.5084475417606599, 1.681776446310471],
-    [-5.1810845809391655, -0.25453036385070005, 1.681776446310471],
-    [
-        -4.8341477346067085,
-        -0.47612199441727104,
-        1.4747297795981904,
-    ],
-    [-4.80496259298752, -0.7127488713156215, 1.4747297795981904],
-    [-5.131187904155789, -0.7611398250077712, 1.681776446310471],
-    [-5.162354524656853, -0.5084475417606599, 1.681776446310471],
-    [-4.80496259298752, -0.7127488713156215, 1.4747297795981904],
-    [-4.764201875616808, -0.9476586737535468, 1.4747297795981904],
-    [-5.087659802554634, -1.011998455748466, 1.681776446310471],
-    [-5.131187904155789, -0.7611398250077712, 1.681776446310471],
-    [-4.764201875616808, -0.9476586737535468, 1.4747297795981904],
-    [-4.711963778632937, -1.1802854834413012, 1.4747297795981904],
-    [-5.031875082862757, -1.260419093568665, 1.681776446310471],
-    [-5.087659802554634, -1.011998455748466, 1.681776446310471],
-    [-4.711963778632937, -1.1802854834413012, 1.4747297795981904],
-    [-4.648374148187055, -1.4100688820186469, 1.4747297795981904],
-    [-4.963968135356061, -1.505803271392783, 1.681776446310471],
-    [-5.031875082862757, -1.260419093568665, 1.681776446310471],
-    [-4.648374148187055, -1.4100688820186469, 1.4747297795981904],
-    [-4.573586177268702, -1.6364553011517649, 1.4747297795981904],
-    [-4.884102553819034, -1.747559837243329, 1.681776446310471],
-    [-4.963968135356061, -1.505803271392783, 1.681776446310471],
-    [-4.573586177268702, -1.6364553011517649, 1.4747297795981904],
-    [-4.487780036650472, -1.8588993561278504, 1.4747297795981904],
-    [-4.792470741432998, -1.9851063783777896, 1.681776446310471],
-    [-4.884102553819034, -1.747559837243329, 1.681776446310471],
-    [-4.487780036650472, -1.8588993561278504, 1.4747297795981904],
-    [-4.391162440840845, -2.07686515973467, 1.4747297795981904],
-    [-4.689293447259991, -2.217870624371955, 1.681776446310471],
-    [-4.792470741432998, -1.9851063783777896, 1.681776446310471],
-    [-4.391162440840845, -2.07686515973467, 1.4747297795981904],
-    [-4.283966150090828, -2.289827613259788, 1.4747297795981904],
-    [-4.574819234438937, -2.445291825769487, 1.681776446310471],
-    [-4.689293447259991, -2.217870624371955, 1.681776446310471],
-    [-4.283966150090828, -2.289827613259788, 1.4747297795981904],
-    [-4.166449409654125, -2.4972736714993498, 1.4747297795981904],
-    [-4.449323881375281, -2.6668221049764704, 1.681776446310471],
-    [-4.574819234438937, -2.445291825769487, 1.681776446310471],
-    [-4.166449409654125, -2.4972736714993498, 1.4747297795981904],
-    [-4.038895327651698, -2.6987035787289297, 1.4747297795981904],
-    [-4.313109717366649, -2.8819277761465374, 1.681776446310471],
-    [-4.449323881375281, -2.6668221049764704, 1.681776446310471],
-    [-4.038895327651698, -2.6987035787289297, 1.4747297795981904],
-    [-3.901611193039507, -2.8936320726588374, 1.4747297795981904],
-    [-4.166504894265085, -3.090090630876808, 1.681776446310471],
-    [-4.313109717366649, -2.8819277761465374, 1.681776446310471],
-    [-3.901611193039507, -2.8936320726588374, 1.4747297795981904],
-    [-3.7549277353225063, -3.081589553473473, 1.4747297795981904],
-    [-4.00986259593051, -3.2908091866173224, 1.681776446310471],
-    [-4.166504894265085, -3.090090630876808, 1.681776446310471],
-    [-3.7549277353225063, -3.081589553473473, 1.4747297795981904],
-    [-3.599198327798287, -3.262123215138426, 1.4747297795981904],
-    [-3.8435601873798557, -3.4835998947864355, 1.681776446310471],
-    [-4.00986259593051, -3.2908091866173224, 1.681776446310471],
-    [-3.599198327798287, -3.262123215138426, 1.4747297795981904],
-    [-3.4347981362498623, -3.4347981362498614, 1.4747297795981904],
-    [-3.6679983056816936, -3.6679983056816923, 1.681776446310471],
-    [-3.8435601873798557, -3.4835998947864355, 1.681776446310471],
-    [-3.4347981362498623, -3.4347981362498614, 1.4747297795981904],
-    [-3.262123215138427, -3.599198327798286, 1.4747297795981904],
-    [-3.4835998947864364, -3.843560187379855, 1.681776446310471],
-    [-3.6679983056816936, -3.6679983056816923, 1.681776446310471],
-    [-3.262123215138427, -3.599198327798286, 1.4747297795981904],
-    [-3.081589553473476, -3.754927735322504, 1.4747297795981904],
-    [-3.290809186617326, -4.009862595930508, 1.681776446310471],
-    [-3.4835998947864364, -3.843560187379855, 1.681776446310471],
-    [-3.081589553473476, -3.754927735322504, 1.4747297795981904],
-    [-2.893632072658837, -3.9016111930395074, 1.4747297795981904],
-    [-3.0900906308768077, -4.166504894265085, 1.681776446310471],
-    [-3.290809186617326, -4.009862595930508, 1.681776446310471],
-    [-2.893632072658837, -3.9016111930395074, 1.4747297795981904],
-    [-2.698703578728931, -4.038895327651697, 1.4747297795981904],
-    [-2.8819277761465387, -4.313109717366648, 1.681776446310471],
-    [-3.0900906308768077, -4.166504894265085, 1.681776446310471],
-    [-2.698703578728931, -4.038895327651697, 1.4747297795981904],
-    [-2.4972736714993506, -4.166449409654124, 1.4747297795981904],
-    [-2.6668221049764718, -4.449323881375281, 1.681776446310471],
-    [-2.8819277761465387, -4.313109717366648, 1.681776446310471],
-    [-2.4972736714993506, -4.166449409654124, 1.4747297795981904],
-    [-2.289827613259789, -4.283966150090827, 1.4747297795981904],
-    [-2.4452918257694884, -4.5748192344389365, 1.681776446310471],
-    [-2.6668221049764718, -4.449323881375281, 1.681776446310471],
-    [-2.289827613259789, -4.283966150090827, 1.4747297795981904],
-    [-2.0768651597346732, -4.391162440840843, 1.4747297795981904],
-    [-2.217870624371958, -4.689293447259989, 1.681776446310471],
-    [-2.4452918257694884, -4.5748192344389365, 1.681776446310471],
-    [-2.0768651597346732, -4.391162440840843, 1.4747297795981904],
-    [-1.8588993561278537, -4.48778003665047, 1.4747297795981904],
-    [-1.9851063783777931, -4.792470741432997, 1.681776446310471],
-    [-2.217870624371958, -4.689293447259989, 1.681776446310471],
-    [-1.8588993561278537, -4.48778003665047, 1.4747297795981904],
-    [-1.6364553011517642, -4.573586177268702, 1.4747297795981904],
-    [-1.747559837243328, -4.884102553819034, 1.681776446310471],
-    [-1.9851063783777931, -4.792470741432997, 1.681776446310471],
-    [-1.6364553011517642, -4.573586177268702, 1.4747297795981904],
-    [-1.4100688820186484, -4.648374148187054, 1.4747297795981904],
-    [-1.5058032713927847, -4.96396813535606, 1.681776446310471],
-    [-1.747559837243328, -4.884102553819034, 1.681776446310471],
-    [-1.4100688820186484, -4.648374148187054, 1.4747297795981904],
-    [-1.1802854834413028, -4.711963778632937, 1.4747297795981904],
-    [-1.2604190935686665, -5.031875082862757, 1.681776446310471],
-    [-1.5058032713927847, -4.96396813535606, 1.681776446310471],
-    [-1.1802854834413028, -4.711963778632937, 1.4747297795981904],
-    [-0.9476586737535483, -4.764201875616808, 1.4747297795981904],
-    [-1.0119984557484678, -5.087659802554633, 1.681776446310471],
-    [-1.2604190935686665, -5.031875082862757, 1.681776446310471],
-    [-0.9476586737535483, -4.764201875616808, 1.4747297795981904],
-    [-0.712748871315625, -4.80496259298752, 1.4747297795981904],
-    [-0.761139825007775, -5.131187904155788, 1.681776446310471],
-    [-1.0119984557484678, -5.087659802554633, 1.681776446310471],
-    [-0.712748871315625, -4.80496259298752, 1.4747297795981904],
-    [-0.4761219944172704, -4.8341477346067085, 1.4747297795981904],
-    [-0.5084475417606592, -5.162354524656853, 1.681776446310471],
-    [-0.761139825007775, -5.131187904155788, 1.681776446310471],
-    [-0.4761219944172704, -4.8341477346067085, 1.4747297795981904],
-    [-0.23834809793100736, -4.851686990911895, 1.4747297795981904],
-    [-0.25453036385070166, -5.1810845809391655, 1.681776446310471],
-    [-0.5084475417606592, -5.162354524656853, 1.681776446310471],
-    [-0.23834809793100736, -4.851686990911895, 1.4747297795981904],
-    [
-        -8.923152744095859e-16,
-        -4.857538108298384,
-        1.4747297795981904,
-    ],
-    [-9.52897604119977e-16, -5.187332950656583, 1.681776446310471],
-    [-0.25453036385070166, -5.1810845809391655, 1.681776446310471],
-    [
-        -8.923152744095859e-16,
-        -4.857538108298384,
-        1.4747297795981904,
-    ],
-    [0.2383480979310056, -4.851686990911895, 1.4747297795981904],
-    [0.2545303638506997, -5.1810845809391655, 1.681776446310471],
-    [-9.52897604119977e-16, -5.187332950656583, 1.681776446310471],
-    [0.2383480979310056, -4.851686990911895, 1.4747297795981904],
-    [0.4761219944172686, -4.8341477346067085, 1.4747297795981904],
-    [0.5084475417606573, -5.162354524656853, 1.681776446310471],
-    [0.2545303638506997, -5.1810845809391655, 1.681776446310471],
-    [0.4761219944172686, -4.8341477346067085, 1.4747297795981904],
-    [0.7127488713156233, -4.80496259298752, 1.4747297795981904],
-    [0.7611398250077731, -5.131187904155788, 1.681776446310471],
-    [0.5084475417606573, -5.162354524656853, 1.681776446310471],
-    [0.7127488713156233, -4.80496259298752, 1.4747297795981904],
-    [0.9476586737535465, -4.764201875616808, 1.4747297795981904],
-    [1.0119984557484658, -5.087659802554634, 1.681776446310471],
-    [0.7611398250077731, -5.131187904155788, 1.681776446310471],
-    [0.9476586737535465, -4.764201875616808, 1.4747297795981904],
-    [1.180285483441301, -4.711963778632937, 1.4747297795981904],
-    [1.2604190935686646, -5.031875082862757, 1.681776446310471],
-    [1.0119984557484658, -5.087659802554634, 1.681776446310471],
-    [1.180285483441301, -4.711963778632937, 1.4747297795981904],
-    [1.4100688820186467, -4.648374148187055, 1.4747297795981904],
-    [1.5058032713927827, -4.963968135356061, 1.681776446310471],
-    [1.2604190935686646, -5.031875082862757, 1.681776446310471],
-    [1.4100688820186467, -4.648374148187055, 1.4747297795981904],
-    [1.6364553011517624, -4.5735861772687025, 1.4747297795981904],
-    [1.7475598372433263, -4.884102553819035, 1.681776446310471],
-    [1.5058032713927827, -4.963968135356061, 1.681776446310471],
-    [1.6364553011517624, -4.5735861772687025, 1.4747297795981904],
-    [1.858899356127852, -4.487780036650471, 1.4747297795981904],
-    [1.9851063783777914, -4.7924707414329974, 1.681776446310471],
-    [1.7475598372433263, -4.884102553819035, 1.681776446310471],
-    [1.858899356127852, -4.487780036650471, 1.4747297795981904],
-    [2.076865159734672, -4.391162440840844, 1.4747297795981904],
-    [2.217870624371957, -4.68929344725999, 1.681776446310471],
-    [1.9851063783777914, -4.7924707414329974, 1.681776446310471],
-    [2.076865159734672, -4.391162440840844, 1.4747297795981904],
-    [2.2898276132597877, -4.283966150090828, 1.4747297795981904],
-    [2.445291825769487, -4.574819234438937, 1.681776446310471],
-    [2.217870624371957, -4.68929344725999, 1.681776446310471],
-    [2.2898276132597877, -4.283966150090828, 1.4747297795981904],
-    [2.4972736714993498, -4.166449409654125, 1.4747297795981904],
-    [2.6668221049764704, -4.449323881375282, 1.681776446310471],
-    [2.445291825769487, -4.574819234438937, 1.681776446310471],
-    [2.4972736714993498, -4.166449409654125, 1.4747297795981904],
-    [2.6987035787289293, -4.038895327651698, 1.4747297795981904],
-    [2.881927776146537, -4.313109717366649, 1.681776446310471],
-    [2.6668221049764704, -4.449323881375282, 1.681776446310471],
-    [2.6987035787289293, -4.038895327651698, 1.4747297795981904],
-    [2.893632072658835, -3.9016111930395088, 1.4747297795981904],
-    [3.090090630876806, -4.166504894265086, 1.681776446310471],
-    [2.881927776146537, -4.313109717366649, 1.681776446310471],
-    [2.893632072658835, -3.9016111930395088, 1.4747297795981904],
-    [3.0815895534734743, -3.7549277353225055, 1.4747297795981904],
-    [3.290809186617324, -4.009862595930509, 1.681776446310471],
-    [3.090090630876806, -4.166504894265086, 1.681776446310471],
-    [3.0815895534734743, -3.7549277353225055, 1.4747297795981904],
-    [3.2621232151384256, -3.599198327798287, 1.4747297795981904],
-    [3.4835998947864346, -3.8435601873798557, 1.681776446310471],
-    [3.290809186617324, -4.009862595930509, 1.681776446310471],
-    [3.2621232151384256, -3.599198327798287, 1.4747297795981904],
-    [3.434798136249861, -3.4347981362498623, 1.4747297795981904],
-    [3.667998305681692, -3.6679983056816936, 1.681776446310471],
-    [3.4835998947864346, -3.8435601873798557, 1.681776446310471],
-    [3.434798136249861, -3.4347981362498623, 1.4747297795981904],
-    [3.599198327798286, -3.262123215138427, 1.4747297795981904],
-    [3.843560187379855, -3.4835998947864364, 1.681776446310471],
-    [3.667998305681692, -3.6679983056816936, 1.681776446310471],
-    [3.599198327798286, -3.262123215138427, 1.4747297795981904],
-    [3.7549277353225037, -3.081589553473476, 1.4747297795981904],
-    [4.009862595930508, -3.290809186617326, 1.681776446310471],
-    [3.843560187379855, -3.4835998947864364, 1.681776446310471],
-    [3.7549277353225037, -3.081589553473476, 1.4747297795981904],
-    [3.9016111930395074, -2.8936320726588374, 1.4747297795981904],
-    [4.166504894265085, -3.090090630876808, 1.681776446310471],
-    [4.009862595930508, -3.290809186617326, 1.681776446310471],
-    [3.9016111930395074, -2.8936320726588374, 1.4747297795981904],
-    [4.038895327651697, -2.698703578728931, 1.4747297795981904],
-    [4.313109717366648, -2.8819277761465387, 1.681776446310471],
-    [4.166504894265085, -3.090090630876808, 1.681776446310471],
-    [4.038895327651697, -2.698703578728931, 1.4747297795981904],
-    [4.166449409654124, -2.497273671499351, 1.4747297795981904],
-    [4.449323881375281, -2.666822104976472, 1.681776446310471],
-    [4.313109717366648, -2.8819277761465387, 1.681776446310471],
-    [4.166449409654124, -2.497273671499351, 1.4747297795981904],
-    [4.283966150090827, -2.2898276132597895, 1.4747297795981904],
-    [4.5748192344389365, -2.4452918257694884, 1.681776446310471],
-    [4.449323881375281, -2.666822104976472, 1.681776446310471],
-    [4.283966150090827, -2.2898276132597895, 1.4747297795981904],
-    [4.391162440840843, -2.0768651597346737, 1.4747297795981904],
-    [4.689293447259989, -2.2178706243719586, 1.681776446310471],
-    [4.5748192344389365, -2.4452918257694884, 1.681776446310471],
-    [4.391162440840843, -2.0768651597346737, 1.4747297795981904],
-    [4.48778003665047, -1.858899356127854, 1.4747297795981904],
-    [4.792470741432997, -1.9851063783777934, 1.681776446310471],
-    [4.689293447259989, -2.2178706243719586, 1.681776446310471],
-    [4.48778003665047, -1.858899356127854, 1.4747297795981904],
-    [4.573586177268702, -1.6364553011517644, 1.4747297795981904],
-    [4.884102553819034, -1.7475598372433283, 1.681776446310471],
-    [4.792470741432997, -1.9851063783777934, 1.681776446310471],
-    [4.573586177268702, -1.6364553011517644, 1.4747297795981904],
-    [4.648374148187054, -1.4100688820186489, 1.4747297795981904],
-    [4.96396813535606, -1.505803271392785, 1.681776446310471],
-    [4.884102553819034, -1.7475598372433283, 1.681776446310471],
-    [4.648374148187054, -1.4100688820186489, 1.4747297795981904],
-    [4.711963778632937, -1.180285483441303, 1.4747297795981904],
-    [5.031875082862757, -1.2604190935686668, 1.681776446310471],
-    [4.96396813535606, -1.505803271392785, 1.681776446310471],
-    [4.711963778632937, -1.180285483441303, 1.4747297795981904],
-    [4.764201875616808, -0.9476586737535485, 1.4747297795981904],
-    [5.087659802554633, -1.011998455748468, 1.681776446310471],
-    [5.031875082862757, -1.2604190935686668, 1.681776446310471],
-    [4.764201875616808, -0.9476586737535485, 1.4747297795981904],
-    [4.80496259298752, -0.7127488713156254, 1.4747297795981904],
-    [5.131187904155788, -0.7611398250077754, 1.681776446310471],
-    [5.087659802554633, -1.011998455748468, 1.681776446310471],
-    [4.80496259298752, -0.7127488713156254, 1.4747297795981904],
-    [4.8341477346067085, -0.4761219944172706, 1.4747297795981904],
-    [5.162354524656853, -0.5084475417606595, 1.681776446310471],
-    [5.131187904155788, -0.7611398250077754, 1.681776446310471],
-    [4.8341477346067085, -0.4761219944172706, 1.4747297795981904],
-    [4.851686990911895, -0.23834809793100767, 1.4747297795981904],
-    [5.1810845809391655, -0.254530363850702, 1.681776446310471],
-    [5.162354524656853, -0.5084475417606595, 1.681776446310471],
-    [4.851686990911895, -0.23834809793100767, 1.4747297795981904],
-    [
-        4.857538108298384,
-        -1.189753699212781e-15,
-        1.4747297795981904,
-    ],
-    [5.187332950656583, -1.270530138826636e-15, 1.681776446310471],
-    [5.1810845809391655, -0.254530363850702, 1.681776446310471],
-    [5.187332950656583, 0.0, 1.681776446310471],
-    [5.1810845809391655, 0.25453036385070155, 1.681776446310471],
-    [5.5104821709664344, 0.2707126297703958, 1.9024186927785094],
-    [5.517127793014781, 0.0, 1.9024186927785094],
-    [5.1810845809391655, 0.25453036385070155, 1.681776446310471],
-    [5.162354524656853, 0.50844754176066, 1.681776446310471],
-    [5.490561314706996, 0.5407730891040488, 1.9024186927785094],
-    [5.5104821709664344, 0.2707126297703958, 1.9024186927785094],
-    [5.162354524656853, 0.50844754176066, 1.681776446310471],
-    [5.131187904155789, 0.7611398250077721, 1.681776446310471],
-    [5.457413215324056, 0.8095307786999217, 1.9024186927785094],
-    [5.490561314706996, 0.5407730891040488, 1.9024186927785094],
-    [5.131187904155789, 0.7611398250077721, 1.681776446310471],
-    [5.087659802554634, 1.0119984557484656, 1.681776446310471],
-    [5.411117729492458, 1.0763382377433846, 1.9024186927785094],
-    [5.457413215324056, 0.8095307786999217, 1.9024186927785094],
-    [5.087659802554634, 1.0119984557484656, 1.681776446310471],
-    [5.031875082862757, 1.2604190935686652, 1.681776446310471],
-    [5.351786387092577, 1.3405527036960287, 1.9024186927785094],
-    [5.411117729492458, 1.0763382377433846, 1.9024186927785094],
-    [5.031875082862757, 1.2604190935686652, 1.681776446310471],
-    [4.96396813535606, 1.505803271392784, 1.681776446310471],
-    [5.279562122525065, 1.6015376607669198, 1.9024186927785094],
-    [5.351786387092577, 1.3405527036960287, 1.9024186927785094],
-    [4.96396813535606, 1.505803271392784, 1.681776446310471],
-    [4.884102553819034, 1.7475598372433285, 1.681776446310471],
-    [5.194618930369365, 1.8586643733348922, 1.9024186927785094],
-    [5.279562122525065, 1.6015376607669198, 1.9024186927785094],
-    [4.884102553819034, 1.7475598372433285, 1.681776446310471],
-    [4.7924707414329974, 1.9851063783777902, 1.681776446310471],
-    [5.097161446215523, 2.111313400627729, 1.9024186927785094],
-    [5.194618930369365, 1.8586643733348922, 1.9024186927785094],
-    [4.7924707414329974, 1.9851063783777902, 1.681776446310471],
-    [4.68929344725999, 2.2178706243719564, 1.681776446310471],
-    [4.987424453679135, 2.358876089009241, 1.9024186927785094],
-    [5.097161446215523, 2.111313400627729, 1.9024186927785094],
-    [4.68929344725999, 2.2178706243719564, 1.681776446310471],
-    [4.574819234438937, 2.445291825769487, 1.681776446310471],
-    [4.865672318787046, 2.600756038279186, 1.9024186927785094],
-    [4.987424453679135, 2.358876089009241, 1.9024186927785094],
-    [4.574819234438937, 2.445291825769487, 1.681776446310471],
-    [4.449323881375281, 2.6668221049764713, 1.681776446310471],
-    [4.732198353096438, 2.8363705384535915, 1.9024186927785094],
-    [4.865672318787046, 2.600756038279186, 1.9024186927785094],
-    [4.449323881375281, 2.6668221049764713, 1.681776446310471],
-    [4.313109717366648, 2.8819277761465387, 1.681776446310471],
-    [4.5873241070815975, 3.0651519735641455, 1.9024186927785094],
-    [4.732198353096438, 2.8363705384535915, 1.9024186927785094],
-    [4.313109717366648, 2.8819277761465387, 1.681776446310471],
-    [4.166504894265085, 3.0900906308768086, 1.681776446310471],
-    [4.431398595490661, 3.286549189094779, 1.9024186927785094],
-    [4.5873241070815975, 3.0651519735641455, 1.9024186927785094],
-    [4.166504894265085, 3.0900906308768086, 1.681776446310471],
-    [4.009862595930509, 3.2908091866173232, 1.681776446310471],
-    [4.2647974565385125, 3.5000288197611726, 1.9024186927785094],
-    [4.431398595490661, 3.286549189094779, 1.9024186927785094],
-    [4.009862595930509, 3.2908091866173232, 1.681776446310471],
-    [3.8435601873798566, 3.4835998947864346, 1.681776446310471],
-    [4.087922046961425, 3.7050765744344436, 1.9024186927785094],
-    [4.2647974565385125, 3.5000288197611726, 1.9024186927785094],
-    [3.8435601873798566, 3.4835998947864346, 1.681776446310471],
-    [3.6679983056816927, 3.6679983056816927, 1.681776446310471],
-    [3.901198475113523, 3.901198475113523, 1.9024186927785094],
-    [4.087922046961425, 3.7050765744344436, 1.9024186927785094],
-    [3.6679983056816927, 3.6679983056816927, 1.681776446310471],
-    [3.4835998947864346, 3.8435601873798557, 1.681776446310471],
-    [3.7050765744344436, 4.087922046961424, 1.9024186927785094],
-    [3.901198475113523, 3.901198475113523, 1.9024186927785094],
-    [3.4835998947864346, 3.8435601873798557, 1.681776446310471],
-    [3.2908091866173232, 4.009862595930509, 1.681776446310471],
-    [3.5000288197611726, 4.2647974565385125, 1.9024186927785094],
-    [3.7050765744344436, 4.087922046961424, 1.9024186927785094],
-    [3.2908091866173232, 4.009862595930509, 1.681776446310471],
-    [3.0900906308768095, 4.166504894265084, 1.681776446310471],
-    [3.2865491890947798, 4.43139859549066, 1.9024186927785094],
-    [3.5000288197611726, 4.2647974565385125, 1.9024186927785094],
-    [3.0900906308768095, 4.166504894265084, 1.681776446310471],
-    [2.881927776146539, 4.313109717366648, 1.681776446310471],
-    [3.065151973564146, 4.5873241070815975, 1.9024186927785094],
-    [3.2865491890947798, 4.43139859549066, 1.9024186927785094],
-    [2.881927776146539, 4.313109717366648, 1.681776446310471],
-    [2.6668221049764713, 4.449323881375281, 1.681776446310471],
-    [2.8363705384535915, 4.732198353096438, 1.9024186927785094],
-    [3.065151973564146, 4.5873241070815975, 1.9024186927785094],
-    [2.6668221049764713, 4.449323881375281, 1.681776446310471],
-    [2.445291825769488, 4.5748192344389365, 1.681776446310471],
-    [2.600756038279187, 4.865672318787045, 1.9024186927785094],
-    [2.8363705384535915, 4.732198353096438, 1.9024186927785094],
-    [2.445291825769488, 4.5748192344389365, 1.681776446310471],
-    [2.217870624371957, 4.68929344725999, 1.681776446310471],
-    [2.3588760890092413, 4.987424453679135, 1.9024186927785094],
-    [2.600756038279187, 4.865672318787045, 1.9024186927785094],
-    [2.217870624371957, 4.68929344725999, 1.681776446310471],
-    [1.9851063783777905, 4.7924707414329974, 1.681776446310471],
-    [2.111313400627729, 5.097161446215523, 1.9024186927785094],
-    [2.3588760890092413, 4.987424453679135, 1.9024186927785094],
-    [1.9851063783777905, 4.7924707414329974, 1.681776446310471],
-    [1.7475598372433285, 4.884102553819034, 1.681776446310471],
-    [1.8586643733348922, 5.194618930369365, 1.9024186927785094],
-    [2.111313400627729, 5.097161446215523, 1.9024186927785094],
-    [1.7475598372433285, 4.884102553819034, 1.681776446310471],
-    [1.505803271392784, 4.963968135356061, 1.681776446310471],
-    [1.6015376607669198, 5.279562122525066, 1.9024186927785094],
-    [1.8586643733348922, 5.194618930369365, 1.9024186927785094],
-    [1.505803271392784, 4.963968135356061, 1.681776446310471],
-    [1.2604190935686659, 5.031875082862757, 1.681776446310471],
-    [1.3405527036960294, 5.351786387092577, 1.9024186927785094],
-    [1.6015376607669198, 5.279562122525066, 1.9024186927785094],
-    [1.2604190935686659, 5.031875082862757, 1.681776446310471],
-    [1.011998455748466, 5.087659802554634, 1.681776446310471],
-    [1.076338237743385, 5.411117729492458, 1.9024186927785094],
-    [1.3405527036960294, 5.351786387092577, 1.9024186927785094],
-    [1.011998455748466, 5.087659802554634, 1.681776446310471],
-    [0.7611398250077721, 5.131187904155789, 1.681776446310471],
-    [0.8095307786999217, 5.457413215324056, 1.9024186927785094],
-    [1.076338237743385, 5.411117729492458, 1.9024186927785094],
-    [0.7611398250077721, 5.131187904155789, 1.681776446310471],
-    [0.5084475417606609, 5.162354524656852, 1.681776446310471],
-    [0.5407730891040498, 5.490561314706995, 1.9024186927785094],
-    [0.8095307786999217, 5.457413215324056, 1.9024186927785094],
-    [0.5084475417606609, 5.162354524656852, 1.681776446310471],
-    [0.25453036385070216, 5.1810845809391655, 1.681776446310471],
-    [0.2707126297703964, 5.5104821709664344, 1.9024186927785094],
-    [0.5407730891040498, 5.490561314706995, 1.9024186927785094],
-    [0.25453036385070216, 5.1810845809391655, 1.681776446310471],
-    [3.17632534706659e-16, 5.187332950656583, 1.681776446310471],
-    [
-        3.3782664461012263e-16,
-        5.517127793014781,
-        1.9024186927785094,
-    ],
-    [0.2707126297703964, 5.5104821709664344, 1.9024186927785094],
-    [3.17632534706659e-16, 5.187332950656583, 1.681776446310471],
-    [-0.25453036385070155, 5.1810845809391655, 1.681776446310471],
-    [-0.27071262977039573, 5.5104821709664344, 1.9024186927785094],
-    [
-        3.3782664461012263e-16,
-        5.517127793014781,
-        1.9024186927785094,
-    ],
-    [-0.25453036385070155, 5.1810845809391655, 1.681776446310471],
-    [-0.5084475417606602, 5.162354524656853, 1.681776446310471],
-    [-0.5407730891040491, 5.490561314706996, 1.9024186927785094],
-    [-0.27071262977039573, 5.5104821709664344, 1.9024186927785094],
-    [-0.5084475417606602, 5.162354524656853, 1.681776446310471],
-    [-0.7611398250077716, 5.131187904155789, 1.681776446310471],
-    [-0.8095307786999211, 5.457413215324056, 1.9024186927785094],
-    [-0.5407730891040491, 5.490561314706996, 1.9024186927785094],
-    [-0.7611398250077716, 5.131187904155789, 1.681776446310471],
-    [-1.0119984557484651, 5.087659802554634, 1.681776446310471],
-    [-1.0763382377433843, 5.411117729492458, 1.9024186927785094],
-    [-0.8095307786999211, 5.457413215324056, 1.9024186927785094],
-    [-1.0119984557484651, 5.087659802554634, 1.681776446310471],
-    [-1.2604190935686652, 5.031875082862757, 1.681776446310471],
-    [-1.3405527036960287, 5.351786387092577, 1.9024186927785094],
-    [-1.0763382377433843, 5.411117729492458, 1.9024186927785094],
-    [-1.2604190935686652, 5.031875082862757, 1.681776446310471],
-    [-1.5058032713927831, 4.963968135356061, 1.681776446310471],
-    [-1.601537660766919, 5.279562122525066, 1.9024186927785094],
-    [-1.3405527036960287, 5.351786387092577, 1.9024186927785094],
-    [-1.5058032713927831, 4.963968135356061, 1.681776446310471],
-    [-1.747559837243328, 4.884102553819034, 1.681776446310471],
-    [-1.8586643733348915, 5.194618930369365, 1.9024186927785094],
-    [-1.601537660766919, 5.279562122525066, 1.9024186927785094],
-    [-1.747559837243328, 4.884102553819034, 1.681776446310471],
-    [-1.9851063783777898, 4.7924707414329974, 1.681776446310471],
-    [-2.1113134006277288, 5.097161446215523, 1.9024186927785094],
-    [-1.8586643733348915, 5.194618930369365, 1.9024186927785094],
-    [-1.9851063783777898, 4.7924707414329974, 1.681776446310471],
-    [-2.217870624371955, 4.689293447259991, 1.681776446310471],
-    [-2.3588760890092395, 4.987424453679136, 1.9024186927785094],
-    [-2.1113134006277288, 5.097161446215523, 1.9024186927785094],
-    [-2.217870624371955, 4.689293447259991, 1.681776446310471],
-    [-2.4452918257694876, 4.574819234438937, 1.681776446310471],
-    [-2.6007560382791866, 4.865672318787046, 1.9024186927785094],
-    [-2.3588760890092395, 4.987424453679136, 1.9024186927785094],
-    [-2.4452918257694876, 4.574819234438937, 1.681776446310471],
-    [-2.6668221049764704, 4.449323881375281, 1.681776446310471],
-    [-2.836370538453591, 4.732198353096438, 1.9024186927785094],
-    [-2.6007560382791866, 4.865672318787046, 1.9024186927785094],
-    [-2.6668221049764704, 4.449323881375281, 1.681776446310471],
-    [-2.8819277761465374, 4.313109717366648, 1.681776446310471],
-    [-3.065151973564144, 4.5873241070815975, 1.9024186927785094],
-    [-2.836370538453591, 4.732198353096438, 1.9024186927785094],
-    [-2.8819277761465374, 4.313109717366648, 1.681776446310471],
-    [-3.0900906308768086, 4.166504894265085, 1.681776446310471],
-    [-3.286549189094779, 4.431398595490661, 1.9024186927785094],
-    [-3.065151973564144, 4.5873241070815975, 1.9024186927785094],
-    [-3.0900906308768086, 4.166504894265085, 1.681776446310471],
-    [-3.290809186617323, 4.00986259593051, 1.681776446310471],
-    [-3.5000288197611718, 4.264797456538513, 1.9024186927785094],
-    [-3.286549189094779, 4.431398595490661, 1.9024186927785094],
-    [-3.290809186617323, 4.00986259593051, 1.681776446310471],
-    [-3.4835998947864355, 3.8435601873798553, 1.681776446310471],
-    [-3.705076574434444, 4.087922046961424, 1.9024186927785094],
-    [-3.5000288197611718, 4.264797456538513, 1.9024186927785094],
-    [-3.4835998947864355, 3.8435601873798553, 1.681776446310471],
-    [-3.6679983056816923, 3.6679983056816927, 1.681776446310471],
-    [-3.9011984751135227, 3.901198475113523, 1.9024186927785094],
-    [-3.705076574434444, 4.087922046961424, 1.9024186927785094],
-    [-3.6679983056816923, 3.6679983056816927, 1.681776446310471],
-    [-3.843560187379855, 3.483599894786436, 1.681776446310471],
-    [-4.087922046961423, 3.7050765744344445, 1.9024186927785094],
-    [-3.9011984751135227, 3.901198475113523, 1.9024186927785094],
-    [-3.843560187379855, 3.483599894786436, 1.681776446310471],
-    [-4.009862595930509, 3.2908091866173232, 1.681776446310471],
-    [-4.2647974565385125, 3.5000288197611726, 1.9024186927785094],
-    [-4.087922046961423, 3.7050765744344445, 1.9024186927785094],
-    [-4.009862595930509, 3.2908091866173232, 1.681776446310471],
-    [-4.166504894265084, 3.0900906308768095, 1.681776446310471],
-    [-4.43139859549066, 3.2865491890947798, 1.9024186927785094],
-    [-4.2647974565385125, 3.5000288197611726, 1.9024186927785094],
-    [-4.166504894265084, 3.0900906308768095, 1.681776446310471],
-    [-4.313109717366648, 2.8819277761465387, 1.681776446310471],
-    [-4.5873241070815975, 3.0651519735641455, 1.9024186927785094],
-    [-4.43139859549066, 3.2865491890947798, 1.9024186927785094],
-    [-4.313109717366648, 2.8819277761465387, 1.681776446310471],
-    [-4.449323881375281, 2.6668221049764718, 1.681776446310471],
-    [-4.732198353096437, 2.8363705384535924, 1.9024186927785094],
-    [-4.5873241070815975, 3.0651519735641455, 1.9024186927785094],
-    [-4.449323881375281, 2.6668221049764718, 1.681776446310471],
-    [-4.5748192344389365, 2.445291825769488, 1.681776446310471],
-    [-4.865672318787045, 2.600756038279187, 1.9024186927785094],
-    [-4.732198353096437, 2.8363705384535924, 1.9024186927785094],
-    [-4.5748192344389365, 2.445291825769488, 1.681776446310471],
-    [-4.68929344725999, 2.217870624371956, 1.681776446310471],
-    [-4.987424453679135, 2.3588760890092404, 1.9024186927785094],
-    [-4.865672318787045, 2.600756038279187, 1.9024186927785094],
-    [-4.68929344725999, 2.217870624371956, 1.681776446310471],
-    [-4.7924707414329974, 1.9851063783777907, 1.681776446310471],
-    [-5.097161446215523, 2.1113134006277297, 1.9024186927785094],
-    [-4.987424453679135, 2.3588760890092404, 1.9024186927785094],
-    [-4.7924707414329974, 1.9851063783777907, 1.681776446310471],
-    [-4.884102553819033, 1.74755983724333, 1.681776446310471],
-    [-5.194618930369364, 1.8586643733348938, 1.9024186927785094],
-    [-5.097161446215523, 2.1113134006277297, 1.9024186927785094],
-    [-4.884102553819033, 1.74755983724333, 1.681776446310471],
-    [-4.96396813535606, 1.5058032713927842, 1.681776446310471],
-    [-5.279562122525065, 1.60153766076692, 1.9024186927785094],
-    [-5.194618930369364, 1.8586643733348938, 1.9024186927785094],
-    [-4.96396813535606, 1.5058032713927842, 1.681776446310471],
-    [-5.031875082862757, 1.2604190935686663, 1.681776446310471],
-    [-5.351786387092577, 1.3405527036960299, 1.9024186927785094],
-    [-5.279562122525065, 1.60153766076692, 1.9024186927785094],
-    [-5.031875082862757, 1.2604190935686663, 1.681776446310471],
-    [-5.087659802554634, 1.0119984557484674, 1.681776446310471],
-    [-5.411117729492458, 1.0763382377433865, 1.9024186927785094],
-    [-5.351786387092577, 1.3405527036960299, 1.9024186927785094],
-    [-5.087659802554634, 1.0119984557484674, 1.681776446310471],
-    [-5.131187904155789, 0.7611398250077723, 1.681776446310471],
-    [-5.457413215324056, 0.809530778699922, 1.9024186927785094],
-    [-5.411117729492458, 1.0763382377433865, 1.9024186927785094],
-    [-5.131187904155789, 0.7611398250077723, 1.681776446310471],
-    [-5.162354524656852, 0.5084475417606611, 1.681776446310471],
-    [-5.490561314706995, 0.54077308910405, 1.9024186927785094],
-    [-5.457413215324056, 0.809530778699922, 1.9024186927785094],
-    [-5.162354524656852, 0.5084475417606611, 1.681776446310471],
-    [-5.1810845809391655, 0.2545303638507013, 1.681776446310471],
-    [-5.5104821709664344, 0.2707126297703955, 1.9024186927785094],
-    [-5.490561314706995, 0.54077308910405, 1.9024186927785094],
-    [-5.1810845809391655, 0.2545303638507013, 1.681776446310471],
-    [-5.187332950656583, 6.35265069413318e-16, 1.681776446310471],
-    [
-        -5.517127793014781,
-        6.756532892202453e-16,
-        1.9024186927785094,
-    ],
-    [-5.5104821709664344, 0.2707126297703955, 1.9024186927785094],
-    [-5.187332950656583, 6.35265069413318e-16, 1.681776446310471],
-    [-5.1810845809391655, -0.25453036385070005, 1.681776446310471],
-    [-5.5104821709664344, -0.2707126297703942, 1.9024186927785094],
-    [
-        -5.517127793014781,
-        6.756532892202453e-16,
-        1.9024186927785094,
-    ],
-    [-5.1810845809391655, -0.25453036385070005, 1.681776446310471],
-    [-5.162354524656853, -0.5084475417606599, 1.681776446310471],
-    [-5.490561314706996, -0.5407730891040488, 1.9024186927785094],
-    [-5.5104821709664344, -0.2707126297703942, 1.9024186927785094],
-    [-5.162354524656853, -0.5084475417606599, 1.681776446310471],
-    [-5.131187904155789, -0.7611398250077712, 1.681776446310471],
-    [-5.457413215324056, -0.8095307786999207, 1.9024186927785094],
-    [-5.490561314706996, -0.5407730891040488, 1.9024186927785094],
-    [-5.131187904155789, -0.7611398250077712, 1.681776446310471],
-    [-5.087659802554634, -1.011998455748466, 1.681776446310471],
-    [-5.411117729492458, -1.0763382377433852, 1.9024186927785094],
-    [-5.457413215324056, -0.8095307786999207, 1.9024186927785094],
-    [-5.087659802554634, -1.011998455748466, 1.681776446310471],
-    [-5.031875082862757, -1.260419093568665, 1.681776446310471],
-    [-5.351786387092577, -1.3405527036960283, 1.9024186927785094],
-    [-5.411117729492458, -1.0763382377433852, 1.9024186927785094],
-    [-5.031875082862757, -1.260419093568665, 1.681776446310471],
-    [-4.963968135356061, -1.505803271392783, 1.681776446310471],
-    [-5.279562122525066, -1.6015376607669187, 1.9024186927785094],
-    [-5.351786387092577, -1.3405527036960283, 1.9024186927785094],
-    [-4.963968135356061, -1.505803271392783, 1.681776446310471],
-    [-4.884102553819034, -1.747559837243329, 1.681776446310471],
-    [-5.194618930369365, -1.8586643733348926, 1.9024186927785094],
-    [-5.279562122525066, -1.6015376607669187, 1.9024186927785094],
-    [-4.884102553819034, -1.747559837243329, 1.681776446310471],
-    [-4.792470741432998, -1.9851063783777896, 1.681776446310471],
-    [-5.097161446215524, -2.1113134006277283, 1.9024186927785094],
-    [-5.194618930369365, -1.8586643733348926, 1.9024186927785094],
-    [-4.792470741432998, -1.9851063783777896, 1.681776446310471],
-    [-4.689293447259991, -2.217870624371955, 1.681776446310471],
-    [-4.987424453679136, -2.358876089009239, 1.9024186927785094],
-    [-5.097161446215524, -2.1113134006277283, 1.9024186927785094],
-    [-4.689293447259991, -2.217870624371955, 1.681776446310471],
-    [-4.574819234438937, -2.445291825769487, 1.681776446310471],
-    [-4.865672318787046, -2.600756038279186, 1.9024186927785094],
-    [-4.987424453679136, -2.358876089009239, 1.9024186927785094],
-    [-4.574819234438937, -2.445291825769487, 1.681776446310471],
-    [-4.449323881375281, -2.6668221049764704, 1.681776446310471],
-    [-4.732198353096438, -2.836370538453591, 1.9024186927785094],
-    [-4.865672318787046, -2.600756038279186, 1.9024186927785094],
-    [-4.449323881375281, -2.6668221049764704, 1.681776446310471],
-    [-4.313109717366649, -2.8819277761465374, 1.681776446310471],
-    [-4.587324107081598, -3.065151973564144, 1.9024186927785094],
-    [-4.732198353096438, -2.836370538453591, 1.9024186927785094],
-    [-4.313109717366649, -2.8819277761465374, 1.681776446310471],
-    [-4.166504894265085, -3.090090630876808, 1.681776446310471],
-    [-4.431398595490661, -3.2865491890947784, 1.9024186927785094],
-    [-4.587324107081598, -3.065151973564144, 1.9024186927785094],
-    [-4.166504894265085, -3.090090630876808, 1.681776446310471],
-    [-4.00986259593051, -3.2908091866173224, 1.681776446310471],
-    [-4.264797456538513, -3.5000288197611713, 1.9024186927785094],
-    [-4.431398595490661, -3.2865491890947784, 1.9024186927785094],
-    [-4.00986259593051, -3.2908091866173224, 1.681776446310471],
-    [-3.8435601873798557, -3.4835998947864355, 1.681776446310471],
-    [-4.087922046961424, -3.705076574434444, 1.9024186927785094],
-    [-4.264797456538513, -3.5000288197611713, 1.9024186927785094],
-    [-3.8435601873798557, -3.4835998947864355, 1.681776446310471],
-    [-3.6679983056816936, -3.6679983056816923, 1.681776446310471],
-    [-3.9011984751135236, -3.9011984751135227, 1.9024186927785094],
-    [-4.087922046961424, -3.705076574434444, 1.9024186927785094],
-    [-3.6679983056816936, -3.6679983056816923, 1.681776446310471],
-    [-3.4835998947864364, -3.843560187379855, 1.681776446310471],
-    [-3.7050765744344454, -4.087922046961423, 1.9024186927785094],
-    [-3.9011984751135236, -3.9011984751135227, 1.9024186927785094],
-    [-3.4835998947864364, -3.843560187379855, 1.681776446310471],
-    [-3.290809186617326, -4.009862595930508, 1.681776446310471],
-    [-3.500028819761175, -4.264797456538511, 1.9024186927785094],
-    [-3.7050765744344454, -4.087922046961423, 1.9024186927785094],
-    [-3.290809186617326, -4.009862595930508, 1.681776446310471],
-    [-3.0900906308768077, -4.166504894265085, 1.681776446310471],
-    [-3.286549189094778, -4.431398595490662, 1.9024186927785094],
-    [-3.500028819761175, -4.264797456538511, 1.9024186927785094],
-    [-3.0900906308768077, -4.166504894265085, 1.681776446310471],
-    [-2.8819277761465387, -4.313109717366648, 1.681776446310471],
-    [-3.0651519735641455, -4.5873241070815975, 1.9024186927785094],
-    [-3.286549189094778, -4.431398595490662, 1.9024186927785094],
-    [-2.8819277761465387, -4.313109717366648, 1.681776446310471],
-    [-2.6668221049764718, -4.449323881375281, 1.681776446310471],
-    [-2.8363705384535924, -4.732198353096437, 1.9024186927785094],
-    [-3.0651519735641455, -4.5873241070815975, 1.9024186927785094],
-    [-2.6668221049764718, -4.449323881375281, 1.681776446310471],
-    [-2.4452918257694884, -4.5748192344389365, 1.681776446310471],
-    [-2.6007560382791874, -4.865672318787045, 1.9024186927785094],
-    [-2.8363705384535924, -4.732198353096437, 1.9024186927785094],
-    [-2.4452918257694884, -4.5748192344389365, 1.681776446310471],
-    [-2.217870624371958, -4.689293447259989, 1.681776446310471],
-    [-2.358876089009243, -4.987424453679134, 1.9024186927785094],
-    [-2.6007560382791874, -4.865672318787045, 1.9024186927785094],
-    [-2.217870624371958, -4.689293447259989, 1.681776446310471],
-    [-1.9851063783777931, -4.792470741432997, 1.681776446310471],
-    [-2.1113134006277323, -5.097161446215522, 1.9024186927785094],
-    [-2.358876089009243, -4.987424453679134, 1.9024186927785094],
-    [-1.9851063783777931, -4.792470741432997, 1.681776446310471],
-    [-1.747559837243328, -4.884102553819034, 1.681776446310471],
-    [-1.8586643733348915, -5.194618930369365, 1.9024186927785094],
-    [-2.1113134006277323, -5.097161446215522, 1.9024186927785094],
-    [-1.747559837243328, -4.884102553819034, 1.681776446310471],
-    [-1.5058032713927847, -4.96396813535606, 1.681776446310471],
-    [-1.6015376607669205, -5.279562122525065, 1.9024186927785094],
-    [-1.8586643733348915, -5.194618930369365, 1.9024186927785094],
-    [-1.5058032713927847, -4.96396813535606, 1.681776446310471],
-    [-1.2604190935686665, -5.031875082862757, 1.681776446310471],
-    [-1.34055270369603, -5.351786387092577, 1.9024186927785094],
-    [-1.6015376607669205, -5.279562122525065, 1.9024186927785094],
-    [-1.2604190935686665, -5.031875082862757, 1.681776446310471],
-    [-1.0119984557484678, -5.087659802554633, 1.681776446310471],
-    [-1.076338237743387, -5.4111177294924575, 1.9024186927785094],
-    [-1.34055270369603, -5.351786387092577, 1.9024186927785094],
-    [-1.0119984557484678, -5.087659802554633, 1.681776446310471],
-    [-0.761139825007775, -5.131187904155788, 1.681776446310471],
-    [-0.8095307786999247, -5.457413215324055, 1.9024186927785094],
-    [-1.076338237743387, -5.4111177294924575, 1.9024186927785094],
-    [-0.761139825007775, -5.131187904155788, 1.681776446310471],
-    [-0.5084475417606592, -5.162354524656853, 1.681776446310471],
-    [-0.540773089104048, -5.490561314706996, 1.9024186927785094],
-    [-0.8095307786999247, -5.457413215324055, 1.9024186927785094],
-    [-0.5084475417606592, -5.162354524656853, 1.681776446310471],
-    [-0.25453036385070166, -5.1810845809391655, 1.681776446310471],
-    [-0.2707126297703959, -5.5104821709664344, 1.9024186927785094],
-    [-0.540773089104048, -5.490561314706996, 1.9024186927785094],
-    [-0.25453036385070166, -5.1810845809391655, 1.681776446310471],
-    [-9.52897604119977e-16, -5.187332950656583, 1.681776446310471],
-    [
-        -1.0134799338303678e-15,
-        -5.517127793014781,
-        1.9024186927785094,
-    ],
-    [-0.2707126297703959, -5.5104821709664344, 1.9024186927785094],
-    [-9.52897604119977e-16, -5.187332950656583, 1.681776446310471],
-    [0.2545303638506997, -5.1810845809391655, 1.681776446310471],
-    [0.27071262977039384, -5.5104821709664344, 1.9024186927785094],
-    [
-        -1.0134799338303678e-15,
-        -5.517127793014781,
-        1.9024186927785094,
-    ],
-    [0.2545303638506997, -5.1810845809391655, 1.681776446310471],
-    [0.5084475417606573, -5.162354524656853, 1.681776446310471],
-    [0.540773089104046, -5.490561314706996, 1.9024186927785094],
-    [0.27071262977039384, -5.5104821709664344, 1.9024186927785094],
-    [0.5084475417606573, -5.162354524656853, 1.681776446310471],
-    [0.7611398250077731, -5.131187904155788, 1.681776446310471],
-    [0.8095307786999227, -5.457413215324055, 1.9024186927785094],
-    [0.540773089104046, -5.490561314706996, 1.9024186927785094],
-    [0.7611398250077731, -5.131187904155788, 1.681776446310471],
-    [1.0119984557484658, -5.087659802554634, 1.681776446310471],
-    [1.076338237743385, -5.411117729492458, 1.9024186927785094],
-    [0.8095307786999227, -5.457413215324055, 1.9024186927785094],
-    [1.0119984557484658, -5.087659802554634, 1.681776446310471],
-    [1.2604190935686646, -5.031875082862757, 1.681776446310471],
-    [1.340552703696028, -5.351786387092577, 1.9024186927785094],
-    [1.076338237743385, -5.411117729492458, 1.9024186927785094],
-    [1.2604190935686646, -5.031875082862757, 1.681776446310471],
-    [1.5058032713927827, -4.963968135356061, 1.681776446310471],
-    [1.6015376607669183, -5.279562122525066, 1.9024186927785094],
-    [1.340552703696028, -5.351786387092577, 1.9024186927785094],
-    [1.5058032713927827, -4.963968135356061, 1.681776446310471],
-    [1.7475598372433263, -4.884102553819035, 1.681776446310471],
-    [1.8586643733348898, -5.194618930369366, 1.9024186927785094],
-    [1.6015376607669183, -5.279562122525066, 1.9024186927785094],
-    [1.7475598372433263, -4.884102553819035, 1.681776446310471],
-    [1.9851063783777914, -4.7924707414329974, 1.681776446310471],
-    [2.1113134006277305, -5.097161446215523, 1.9024186927785094],
-    [1.8586643733348898, -5.194618930369366, 1.9024186927785094],
-    [1.9851063783777914, -4.7924707414329974, 1.681776446310471],
-    [2.217870624371957, -4.68929344725999, 1.681776446310471],
-    [2.3588760890092413, -4.987424453679135, 1.9024186927785094],
-    [2.1113134006277305, -5.097161446215523, 1.9024186927785094],
-    [2.217870624371957, -4.68929344725999, 1.681776446310471],
-    [2.445291825769487, -4.574819234438937, 1.681776446310471],
-    [2.6007560382791857, -4.865672318787046, 1.9024186927785094],
-    [2.3588760890092413, -4.987424453679135, 1.9024186927785094],
-    [2.445291825769487, -4.574819234438937, 1.681776446310471],
-    [2.6668221049764704, -4.449323881375282, 1.681776446310471],
-    [2.836370538453591, -4.732198353096438, 1.9024186927785094],
-    [2.6007560382791857, -4.865672318787046, 1.9024186927785094],
-    [2.6668221049764704, -4.449323881375282, 1.681776446310471],
-    [2.881927776146537, -4.313109717366649, 1.681776446310471],
-    [3.0651519735641437, -4.587324107081598, 1.9024186927785094],
-    [2.836370538453591, -4.732198353096438, 1.9024186927785094],
-    [2.881927776146537, -4.313109717366649, 1.681776446310471],
-    [3.090090630876806, -4.166504894265086, 1.681776446310471],
-    [3.286549189094776, -4.431398595490663, 1.9024186927785094],
-    [3.0651519735641437, -4.587324107081598, 1.9024186927785094],
-    [3.090090630876806, -4.166504894265086, 1.681776446310471],
-    [3.290809186617324, -4.009862595930509, 1.681776446310471],
-    [3.500028819761173, -4.2647974565385125, 1.9024186927785094],
-    [3.286549189094776, -4.431398595490663, 1.9024186927785094],
-    [3.290809186617324, -4.009862595930509, 1.681776446310471],
-    [3.4835998947864346, -3.8435601873798557, 1.681776446310471],
-    [3.7050765744344436, -4.087922046961424, 1.9024186927785094],
-    [3.500028819761173, -4.2647974565385125, 1.9024186927785094],
-    [3.4835998947864346, -3.8435601873798557, 1.681776446310471],
-    [3.667998305681692, -3.6679983056816936, 1.681776446310471],
-    [3.901198475113522, -3.9011984751135236, 1.9024186927785094],
-    [3.7050765744344436, -4.087922046961424, 1.9024186927785094],
-    [3.667998305681692, -3.6679983056816936, 1.681776446310471],
-    [3.843560187379855, -3.4835998947864364, 1.681776446310471],
-    [4.087922046961423, -3.7050765744344454, 1.9024186927785094],
-    [3.901198475113522, -3.9011984751135236, 1.9024186927785094],
-    [3.843560187379855, -3.4835998947864364, 1.681776446310471],
-    [4.009862595930508, -3.290809186617326, 1.681776446310471],
-    [4.26479745653851, -3.500028819761175, 1.9024186927785094],
-    [4.087922046961423, -3.7050765744344454, 1.9024186927785094],
-    [4.009862595930508, -3.290809186617326, 1.681776446310471],
-    [4.166504894265085, -3.090090630876808, 1.681776446310471],
-    [4.431398595490662, -3.2865491890947784, 1.9024186927785094],
-    [4.26479745653851, -3.500028819761175, 1.9024186927785094],
-    [4.166504894265085, -3.090090630876808, 1.681776446310471],
-    [4.313109717366648, -2.8819277761465387, 1.681776446310471],
-    [4.5873241070815975, -3.0651519735641455, 1.9024186927785094],
-    [4.431398595490662, -3.2865491890947784, 1.9024186927785094],
-    [4.313109717366648, -2.8819277761465387, 1.681776446310471],
-    [4.449323881375281, -2.666822104976472, 1.681776446310471],
-    [4.732198353096437, -2.836370538453593, 1.9024186927785094],
-    [4.5873241070815975, -3.0651519735641455, 1.9024186927785094],
-    [4.449323881375281, -2.666822104976472, 1.681776446310471],
-    [4.5748192344389365, -2.4452918257694884, 1.681776446310471],
-    [4.865672318787045, -2.6007560382791874, 1.9024186927785094],
-    [4.732198353096437, -2.836370538453593, 1.9024186927785094],
-    [4.5748192344389365, -2.4452918257694884, 1.681776446310471],
-    [4.689293447259989, -2.2178706243719586, 1.681776446310471],
-    [4.987424453679134, -2.358876089009243, 1.9024186927785094],
-    [4.865672318787045, -2.6007560382791874, 1.9024186927785094],
-    [4.689293447259989, -2.2178706243719586, 1.681776446310471],
-    [4.792470741432997, -1.9851063783777934, 1.681776446310471],
-    [5.097161446215522, -2.1113134006277323, 1.9024186927785094],
-    [4.987424453679134, -2.358876089009243, 1.9024186927785094],
-    [4.792470741432997, -1.9851063783777934, 1.681776446310471],
-    [4.884102553819034, -1.7475598372433283, 1.681776446310471],
-    [5.194618930369365, -1.858664373334892, 1.9024186927785094],
-    [5.097161446215522, -2.1113134006277323, 1.9024186927785094],
-    [4.884102553819034, -1.7475598372433283, 1.681776446310471],
-    [4.96396813535606, -1.505803271392785, 1.681776446310471],
-    [5.279562122525065, -1.6015376607669207, 1.9024186927785094],
-    [5.194618930369365, -1.858664373334892, 1.9024186927785094],
-    [4.96396813535606, -1.505803271392785, 1.681776446310471],
-    [5.031875082862757, -1.2604190935686668, 1.681776446310471],
-    [5.351786387092577, -1.3405527036960303, 1.9024186927785094],
-    [5.279562122525065, -1.6015376607669207, 1.9024186927785094],
-    [5.031875082862757, -1.2604190935686668, 1.681776446310471],
-    [5.087659802554633, -1.011998455748468, 1.681776446310471],
-    [5.4111177294924575, -1.0763382377433872, 1.9024186927785094],
-    [5.351786387092577, -1.3405527036960303, 1.9024186927785094],
-    [5.087659802554633, -1.011998455748468, 1.681776446310471],
-    [5.131187904155788, -0.7611398250077754, 1.681776446310471],
-    [5.457413215324055, -0.8095307786999252, 1.9024186927785094],
-    [5.4111177294924575, -1.0763382377433872, 1.9024186927785094],
-    [5.131187904155788, -0.7611398250077754, 1.681776446310471],
-    [5.162354524656853, -0.5084475417606595, 1.681776446310471],
-    [5.490561314706996, -0.5407730891040483, 1.9024186927785094],
-    [5.457413215324055, -0.8095307786999252, 1.9024186927785094],
-    [5.162354524656853, -0.5084475417606595, 1.681776446310471],
-    [5.1810845809391655, -0.254530363850702, 1.681776446310471],
-    [5.5104821709664344, -0.27071262977039623, 1.9024186927785094],
-    [5.490561314706996, -0.5407730891040483, 1.9024186927785094],
-    [5.1810845809391655, -0.254530363850702, 1.681776446310471],
-    [5.187332950656583, -1.270530138826636e-15, 1.681776446310471],
-    [
-        5.517127793014781,
-        -1.3513065784404905e-15,
-        1.9024186927785094,
-    ],
-    [5.5104821709664344, -0.27071262977039623, 1.9024186927785094],
-    [5.517127793014781, 0.0, 1.9024186927785094],
-    [5.5104821709664344, 0.2707126297703958, 1.9024186927785094],
-    [5.839879760993704, 0.2868948956900901, 2.1366565190023072],
-    [5.84692263537298, 0.0, 2.1366565190023072],
-    [5.5104821709664344, 0.2707126297703958, 1.9024186927785094],
-    [5.490561314706996, 0.5407730891040488, 1.9024186927785094],
-    [5.81876810475714, 0.5730986364474377, 2.1366565190023072],
-    [5.839879760993704, 0.2868948956900901, 2.1366565190023072],
-    [5.490561314706996, 0.5407730891040488, 1.9024186927785094],
-    [5.457413215324056, 0.8095307786999217, 1.9024186927785094],
-    [5.783638526492324, 0.8579217323920715, 2.1366565190023072],
-    [5.81876810475714, 0.5730986364474377, 2.1366565190023072],
-    [5.457413215324056, 0.8095307786999217, 1.9024186927785094],
-    [5.411117729492458, 1.0763382377433846, 1.9024186927785094],
-    [5.734575656430283, 1.140678019738304, 2.1366565190023072],
-    [5.783638526492324, 0.8579217323920715, 2.1366565190023072],
-    [5.411117729492458, 1.0763382377433846, 1.9024186927785094],
-    [5.351786387092577, 1.3405527036960287, 1.9024186927785094],
-    [5.6716976913223975, 1.4206863138233925, 2.1366565190023072],
-    [5.734575656430283, 1.140678019738304, 2.1366565190023072],
-    [5.351786387092577, 1.3405527036960287, 1.9024186927785094],
-    [5.279562122525065, 1.6015376607669198, 1.9024186927785094],
-    [5.595156109694071, 1.6972720501410559, 2.1366565190023072],
-    [5.6716976913223975, 1.4206863138233925, 2.1366565190023072],
-    [5.279562122525065, 1.6015376607669198, 1.9024186927785094],
-    [5.194618930369365, 1.8586643733348922, 1.9024186927785094],
-    [5.505135306919697, 1.969768909426456, 2.1366565190023072],
-    [5.595156109694071, 1.6972720501410559, 2.1366565190023072],
-    [5.194618930369365, 1.8586643733348922, 1.9024186927785094],
-    [5.097161446215523, 2.111313400627729, 1.9024186927785094],
-    [5.4018521509980495, 2.2375204228776684, 2.1366565190023072],
-    [5.505135306919697, 1.969768909426456, 2.1366565190023072],
-    [5.097161446215523, 2.111313400627729, 1.9024186927785094],
-    [4.987424453679135, 2.358876089009241, 1.9024186927785094],
-    [5.285555460098281, 2.4998815536465258, 2.1366565190023072],
-    [5.4018521509980495, 2.2375204228776684, 2.1366565190023072],
-    [4.987424453679135, 2.358876089009241, 1.9024186927785094],
-    [4.865672318787046, 2.600756038279186, 1.9024186927785094],
-    [5.156525403135155, 2.756220250788885, 2.1366565190023072],
-    [5.285555460098281, 2.4998815536465258, 2.1366565190023072],
-    [4.865672318787046, 2.600756038279186, 1.9024186927785094],
-    [4.732198353096438, 2.8363705384535915, 1.9024186927785094],
-    [5.015072824817594, 3.0059189719307127, 2.1366565190023072],
-    [5.156525403135155, 2.756220250788885, 2.1366565190023072],
-    [4.732198353096438, 2.8363705384535915, 1.9024186927785094],
-    [4.5873241070815975, 3.0651519735641455, 1.9024186927785094],
-    [4.861538496796548, 3.248376170981753, 2.1366565190023072],
-    [5.015072824817594, 3.0059189719307127, 2.1366565190023072],
-    [4.5873241070815975, 3.0651519735641455, 1.9024186927785094],
-    [4.431398595490661, 3.286549189094779, 1.9024186927785094],
-    [4.696292296716239, 3.4830077473127496, 2.1366565190023072],
-    [4.861538496796548, 3.248376170981753, 2.1366565190023072],
-    [4.431398595490661, 3.286549189094779, 1.9024186927785094],
-    [4.2647974565385125, 3.5000288197611726, 1.9024186927785094],
-    [4.5197323171465165, 3.709248452905022, 2.1366565190023072],
-    [4.696292296716239, 3.4830077473127496, 2.1366565190023072],
-    [4.2647974565385125, 3.5000288197611726, 1.9024186927785094],
-    [4.087922046961425, 3.7050765744344436, 1.9024186927785094],
-    [4.332283906542994, 3.9265532540824526, 2.1366565190023072],
-    [4.5197323171465165, 3.709248452905022, 2.1366565190023072],
-    [4.087922046961425, 3.7050765744344436, 1.9024186927785094],
-    [3.901198475113523, 3.901198475113523, 1.9024186927785094],
-    [4.134398644545354, 4.134398644545354, 2.1366565190023072],
-    [4.332283906542994, 3.9265532540824526, 2.1366565190023072],
-    [3.901198475113523, 3.901198475113523, 1.9024186927785094],
-    [3.7050765744344436, 4.087922046961424, 1.9024186927785094],
-    [3.9265532540824526, 4.332283906542993, 2.1366565190023072],
-    [4.134398644545354, 4.134398644545354, 2.1366565190023072],
-    [3.7050765744344436, 4.087922046961424, 1.9024186927785094],
-    [3.5000288197611726, 4.2647974565385125, 1.9024186927785094],
-    [3.709248452905022, 4.519732317146516, 2.1366565190023072],
-    [3.9265532540824526, 4.332283906542993, 2.1366565190023072],
-    [3.5000288197611726, 4.2647974565385125, 1.9024186927785094],
-    [3.2865491890947798, 4.43139859549066, 1.9024186927785094],
-    [3.4830077473127505, 4.696292296716238, 2.1366565190023072],
-    [3.709248452905022, 4.519732317146516, 2.1366565190023072],
-    [3.2865491890947798, 4.43139859549066, 1.9024186927785094],
-    [3.065151973564146, 4.5873241070815975, 1.9024186927785094],
-    [3.2483761709817536, 4.861538496796548, 2.1366565190023072],
-    [3.4830077473127505, 4.696292296716238, 2.1366565190023072],
-    [3.065151973564146, 4.5873241070815975, 1.9024186927785094],
-    [2.8363705384535915, 4.732198353096438, 1.9024186927785094],
-    [3.0059189719307127, 5.015072824817594, 2.1366565190023072],
-    [3.2483761709817536, 4.861538496796548, 2.1366565190023072],
-    [2.8363705384535915, 4.732198353096438, 1.9024186927785094],
-    [2.600756038279187, 4.865672318787045, 1.9024186927785094],
-    [2.7562202507888864, 5.156525403135154, 2.1366565190023072],
-    [3.0059189719307127, 5.015072824817594, 2.1366565190023072],
-    [2.600756038279187, 4.865672318787045, 1.9024186927785094],
-    [2.3588760890092413, 4.987424453679135, 1.9024186927785094],
-    [2.499881553646526, 5.285555460098281, 2.1366565190023072],
-    [2.7562202507888864, 5.156525403135154, 2.1366565190023072],
-    [2.3588760890092413, 4.987424453679135, 1.9024186927785094],
-    [2.111313400627729, 5.097161446215523, 1.9024186927785094],
-    [2.237520422877669, 5.4018521509980495, 2.1366565190023072],
-    [2.499881553646526, 5.285555460098281, 2.1366565190023072],
-    [2.111313400627729, 5.097161446215523, 1.9024186927785094],
-    [1.8586643733348922, 5.194618930369365, 1.9024186927785094],
-    [1.969768909426456, 5.505135306919697, 2.1366565190023072],
-    [2.237520422877669, 5.4018521509980495, 2.1366565190023072],
-    [1.8586643733348922, 5.194618930369365, 1.9024186927785094],
-    [1.6015376607669198, 5.279562122525066, 1.9024186927785094],
-    [1.6972720501410559, 5.595156109694072, 2.1366565190023072],
-    [1.969768909426456, 5.505135306919697, 2.1366565190023072],
-    [1.6015376607669198, 5.279562122525066, 1.9024186927785094],
-    [1.3405527036960294, 5.351786387092577, 1.9024186927785094],
-    [1.4206863138233932, 5.6716976913223975, 2.1366565190023072],
-    [1.6972720501410559, 5.595156109694072, 2.1366565190023072],
-    [1.3405527036960294, 5.351786387092577, 1.9024186927785094],
-    [1.076338237743385, 5.411117729492458, 1.9024186927785094],
-    [1.1406780197383044, 5.734575656430283, 2.1366565190023072],
-    [1.4206863138233932, 5.6716976913223975, 2.1366565190023072],
-    [1.076338237743385, 5.411117729492458, 1.9024186927785094],
-    [0.8095307786999217, 5.457413215324056, 1.9024186927785094],
-    [0.8579217323920715, 5.783638526492324, 2.1366565190023072],
-    [1.1406780197383044, 5.734575656430283, 2.1366565190023072],
-    [0.8095307786999217, 5.457413215324056, 1.9024186927785094],
-    [0.5407730891040498, 5.490561314706995, 1.9024186927785094],
-    [0.5730986364474386, 5.81876810475714, 2.1366565190023072],
-    [0.8579217323920715, 5.783638526492324, 2.1366565190023072],
-    [0.5407730891040498, 5.490561314706995, 1.9024186927785094],
-    [0.2707126297703964, 5.5104821709664344, 1.9024186927785094],
-    [0.2868948956900907, 5.839879760993704, 2.1366565190023072],
-    [0.5730986364474386, 5.81876810475714, 2.1366565190023072],
-    [0.2707126297703964, 5.5104821709664344, 1.9024186927785094],
-    [
-        3.3782664461012263e-16,
-        5.517127793014781,
-        1.9024186927785094,
-    ],
-    [3.5802075451358635e-16, 5.84692263537298, 2.1366565190023072],
-    [0.2868948956900907, 5.839879760993704, 2.1366565190023072],
-    [
-        3.3782664461012263e-16,
-        5.517127793014781,
-        1.9024186927785094,
-    ],
-    [-0.27071262977039573, 5.5104821709664344, 1.9024186927785094],
-    [-0.28689489569009, 5.839879760993704, 2.1366565190023072],
-    [3.5802075451358635e-16, 5.84692263537298, 2.1366565190023072],
-    [-0.27071262977039573, 5.5104821709664344, 1.9024186927785094],
-    [-0.5407730891040491, 5.490561314706996, 1.9024186927785094],
-    [-0.573098636447438, 5.81876810475714, 2.1366565190023072],
-    [-0.28689489569009, 5.839879760993704, 2.1366565190023072],
-    [-0.5407730891040491, 5.490561314706996, 1.9024186927785094],
-    [-0.8095307786999211, 5.457413215324056, 1.9024186927785094],
-    [-0.8579217323920708, 5.783638526492324, 2.1366565190023072],
-    [-0.573098636447438, 5.81876810475714, 2.1366565190023072],
-    [-0.8095307786999211, 5.457413215324056, 1.9024186927785094],
-    [-1.0763382377433843, 5.411117729492458, 1.9024186927785094],
-    [-1.1406780197383035, 5.734575656430283, 2.1366565190023072],
-    [-0.8579217323920708, 5.783638526492324, 2.1366565190023072],
-    [-1.0763382377433843, 5.411117729492458, 1.9024186927785094],
-    [-1.3405527036960287, 5.351786387092577, 1.9024186927785094],
-    [-1.4206863138233925, 5.6716976913223975, 2.1366565190023072],
-    [-1.1406780197383035, 5.734575656430283, 2.1366565190023072],
-    [-1.3405527036960287, 5.351786387092577, 1.9024186927785094],
-    [-1.601537660766919, 5.279562122525066, 1.9024186927785094],
-    [-1.697272050141055, 5.595156109694072, 2.1366565190023072],
-    [-1.4206863138233925, 5.6716976913223975, 2.1366565190023072],
-    [-1.601537660766919, 5.279562122525066, 1.9024186927785094],
-    [-1.8586643733348915, 5.194618930369365, 1.9024186927785094],
-    [-1.9697689094264554, 5.505135306919697, 2.1366565190023072],
-    [-1.697272050141055, 5.595156109694072, 2.1366565190023072],
-    [-1.8586643733348915, 5.194618930369365, 1.9024186927785094],
-    [-2.1113134006277288, 5.097161446215523, 1.9024186927785094],
-    [-2.237520422877668, 5.4018521509980495, 2.1366565190023072],
-    [-1.9697689094264554, 5.505135306919697, 2.1366565190023072],
-    [-2.1113134006277288, 5.097161446215523, 1.9024186927785094],
-    [-2.3588760890092395, 4.987424453679136, 1.9024186927785094],
-    [-2.4998815536465244, 5.285555460098282, 2.1366565190023072],
-    [-2.237520422877668, 5.4018521509980495, 2.1366565190023072],
-    [-2.3588760890092395, 4.987424453679136, 1.9024186927785094],
-    [-2.6007560382791866, 4.865672318787046, 1.9024186927785094],
-    [-2.7562202507888856, 5.156525403135155, 2.1366565190023072],
-    [-2.4998815536465244, 5.285555460098282, 2.1366565190023072],
-    [-2.6007560382791866, 4.865672318787046, 1.9024186927785094],
-    [-2.836370538453591, 4.732198353096438, 1.9024186927785094],
-    [-3.0059189719307122, 5.015072824817594, 2.1366565190023072],
-    [-2.7562202507888856, 5.156525403135155, 2.1366565190023072],
-    [-2.836370538453591, 4.732198353096438, 1.9024186927785094],
-    [-3.065151973564144, 4.5873241070815975, 1.9024186927785094],
-    [-3.248376170981752, 4.861538496796548, 2.1366565190023072],
-    [-3.0059189719307122, 5.015072824817594, 2.1366565190023072],
-    [-3.065151973564144, 4.5873241070815975, 1.9024186927785094],
-    [-3.286549189094779, 4.431398595490661, 1.9024186927785094],
-    [-3.4830077473127496, 4.696292296716239, 2.1366565190023072],
-    [-3.248376170981752, 4.861538496796548, 2.1366565190023072],
-    [-3.286549189094779, 4.431398595490661, 1.9024186927785094],
-    [-3.5000288197611718, 4.264797456538513, 1.9024186927785094],
-    [-3.709248452905021, 4.519732317146517, 2.1366565190023072],
-    [-3.4830077473127496, 4.696292296716239, 2.1366565190023072],
-    [-3.5000288197611718, 4.264797456538513, 1.9024186927785094],
-    [-3.705076574434444, 4.087922046961424, 1.9024186927785094],
-    [-3.9265532540824535, 4.332283906542992, 2.1366565190023072],
-    [-3.709248452905021, 4.519732317146517, 2.1366565190023072],
-    [-3.705076574434444, 4.087922046961424, 1.9024186927785094],
-    [-3.9011984751135227, 3.901198475113523, 1.9024186927785094],
-    [-4.134398644545353, 4.134398644545354, 2.1366565190023072],
-    [-3.9265532540824535, 4.332283906542992, 2.1366565190023072],
-    [-3.9011984751135227, 3.901198475113523, 1.9024186927785094],
-    [-4.087922046961423, 3.7050765744344445, 1.9024186927785094],
-    [-4.332283906542991, 3.926553254082454, 2.1366565190023072],
-    [-4.134398644545353, 4.134398644545354, 2.1366565190023072],
-    [-4.087922046961423, 3.7050765744344445, 1.9024186927785094],
-    [-4.2647974565385125, 3.5000288197611726, 1.9024186927785094],
-    [-4.5197323171465165, 3.709248452905022, 2.1366565190023072],
-    [-4.332283906542991, 3.926553254082454, 2.1366565190023072],
-    [-4.2647974565385125, 3.5000288197611726, 1.9024186927785094],
-    [-4.43139859549066, 3.2865491890947798, 1.9024186927785094],
-    [-4.696292296716238, 3.4830077473127505, 2.1366565190023072],
-    [-4.5197323171465165, 3.709248452905022, 2.1366565190023072],
-    [-4.43139859549066, 3.2865491890947798, 1.9024186927785094],
-    [-4.5873241070815975, 3.0651519735641455, 1.9024186927785094],
-    [-4.861538496796548, 3.248376170981753, 2.1366565190023072],
-    [-4.696292296716238, 3.4830077473127505, 2.1366565190023072],
-    [-4.5873241070815975, 3.0651519735641455, 1.9024186927785094],
-    [-4.732198353096437, 2.8363705384535924, 1.9024186927785094],
-    [-5.015072824817594, 3.005918971930713, 2.1366565190023072],
-    [-4.861538496796548, 3.248376170981753, 2.1366565190023072],
-    [-4.732198353096437, 2.8363705384535924, 1.9024186927785094],
-    [-4.865672318787045, 2.600756038279187, 1.9024186927785094],
-    [-5.156525403135154, 2.7562202507888864, 2.1366565190023072],
-    [-5.015072824817594, 3.005918971930713, 2.1366565190023072],
-    [-4.865672318787045, 2.600756038279187, 1.9024186927785094],
-    [-4.987424453679135, 2.3588760890092404, 1.9024186927785094],
-    [-5.285555460098281, 2.4998815536465253, 2.1366565190023072],
-    [-5.156525403135154, 2.7562202507888864, 2.1366565190023072],
-    [-4.987424453679135, 2.3588760890092404, 1.9024186927785094],
-    [-5.097161446215523, 2.1113134006277297, 1.9024186927785094],
-    [-5.4018521509980495, 2.237520422877669, 2.1366565190023072],
-    [-5.285555460098281, 2.4998815536465253, 2.1366565190023072],
-    [-5.097161446215523, 2.1113134006277297, 1.9024186927785094],
-    [-5.194618930369364, 1.8586643733348938, 1.9024186927785094],
-    [-5.505135306919696, 1.9697689094264579, 2.1366565190023072],
-    [-5.4018521509980495, 2.237520422877669, 2.1366565190023072],
-    [-5.194618930369364, 1.8586643733348938, 1.9024186927785094],
-    [-5.279562122525065, 1.60153766076692, 1.9024186927785094],
-    [-5.595156109694071, 1.6972720501410563, 2.1366565190023072],
-    [-5.505135306919696, 1.9697689094264579, 2.1366565190023072],
-    [-5.279562122525065, 1.60153766076692, 1.9024186927785094],
-    [-5.351786387092577, 1.3405527036960299, 1.9024186927785094],
-    [-5.6716976913223975, 1.4206863138233936, 2.1366565190023072],
-    [-5.595156109694071, 1.6972720501410563, 2.1366565190023072],
-    [-5.351786387092577, 1.3405527036960299, 1.9024186927785094],
-    [-5.411117729492458, 1.0763382377433865, 1.9024186927785094],
-    [-5.734575656430283, 1.140678019738306, 2.1366565190023072],
-    [-5.6716976913223975, 1.4206863138233936, 2.1366565190023072],
-    [-5.411117729492458, 1.0763382377433865, 1.9024186927785094],
-    [-5.457413215324056, 0.809530778699922, 1.9024186927785094],
-    [-5.783638526492324, 0.8579217323920718, 2.1366565190023072],
-    [-5.734575656430283, 1.140678019738306, 2.1366565190023072],
-    [-5.457413215324056, 0.809530778699922, 1.9024186927785094],
-    [-5.490561314706995, 0.54077308910405, 1.9024186927785094],
-    [-5.81876810475714, 0.573098636447439, 2.1366565190023072],
-    [-5.783638526492324, 0.8579217323920718, 2.1366565190023072],
-    [-5.490561314706995, 0.54077308910405, 1.9024186927785094],
-    [-5.5104821709664344, 0.2707126297703955, 1.9024186927785094],
-    [-5.839879760993704, 0.2868948956900898, 2.1366565190023072],
-    [-5.81876810475714, 0.573098636447439, 2.1366565190023072],
-    [-5.5104821709664344, 0.2707126297703955, 1.9024186927785094],
-    [
-        -5.517127793014781,
-        6.756532892202453e-16,
-        1.9024186927785094,
-    ],
-    [-5.84692263537298, 7.160415090271727e-16, 2.1366565190023072],
-    [-5.839879760993704, 0.2868948956900898, 2.1366565190023072],
-    [
-        -5.517127793014781,
-        6.756532892202453e-16,
-        1.9024186927785094,
-    ],
-    [-5.5104821709664344, -0.2707126297703942, 1.9024186927785094],
-    [-5.839879760993704, -0.28689489569008836, 2.1366565190023072],
-    [-5.84692263537298, 7.160415090271727e-16, 2.1366565190023072],
-    [-5.5104821709664344, -0.2707126297703942, 1.9024186927785094],
-    [-5.490561314706996, -0.5407730891040488, 1.9024186927785094],
-    [-5.81876810475714, -0.5730986364474376, 2.1366565190023072],
-    [-5.839879760993704, -0.28689489569008836, 2.1366565190023072],
-    [-5.490561314706996, -0.5407730891040488, 1.9024186927785094],
-    [-5.457413215324056, -0.8095307786999207, 1.9024186927785094],
-    [-5.783638526492324, -0.8579217323920705, 2.1366565190023072],
-    [-5.81876810475714, -0.5730986364474376, 2.1366565190023072],
-    [-5.457413215324056, -0.8095307786999207, 1.9024186927785094],
-    [-5.411117729492458, -1.0763382377433852, 1.9024186927785094],
-    [-5.734575656430283, -1.1406780197383046, 2.1366565190023072],
-    [-5.783638526492324, -0.8579217323920705, 2.1366565190023072],
-    [-5.411117729492458, -1.0763382377433852, 1.9024186927785094],
-    [-5.351786387092577, -1.3405527036960283, 1.9024186927785094],
-    [-5.6716976913223975, -1.420686313823392, 2.1366565190023072],
-    [-5.734575656430283, -1.1406780197383046, 2.1366565190023072],
-    [-5.351786387092577, -1.3405527036960283, 1.9024186927785094],
-    [-5.279562122525066, -1.6015376607669187, 1.9024186927785094],
-    [-5.595156109694072, -1.6972720501410545, 2.1366565190023072],
-    [-5.6716976913223975, -1.420686313823392, 2.1366565190023072],
-    [-5.279562122525066, -1.6015376607669187, 1.9024186927785094],
-    [-5.194618930369365, -1.8586643733348926, 1.9024186927785094],
-    [-5.505135306919697, -1.9697689094264565, 2.1366565190023072],
-    [-5.595156109694072, -1.6972720501410545, 2.1366565190023072],
-    [-5.194618930369365, -1.8586643733348926, 1.9024186927785094],
-    [-5.097161446215524, -2.1113134006277283, 1.9024186927785094],
-    [-5.40185215099805, -2.2375204228776675, 2.1366565190023072],
-    [-5.505135306919697, -1.9697689094264565, 2.1366565190023072],
-    [-5.097161446215524, -2.1113134006277283, 1.9024186927785094],
-    [-4.987424453679136, -2.358876089009239, 1.9024186927785094],
-    [-5.285555460098282, -2.499881553646524, 2.1366565190023072],
-    [-5.40185215099805, -2.2375204228776675, 2.1366565190023072],
-    [-4.987424453679136, -2.358876089009239, 1.9024186927785094],
-    [-4.865672318787046, -2.600756038279186, 1.9024186927785094],
-    [-5.156525403135155, -2.756220250788885, 2.1366565190023072],
-    [-5.285555460098282, -2.499881553646524, 2.1366565190023072],
-    [-4.865672318787046, -2.600756038279186, 1.9024186927785094],
-    [-4.732198353096438, -2.836370538453591, 1.9024186927785094],
-    [-5.015072824817594, -3.0059189719307122, 2.1366565190023072],
-    [-5.156525403135155, -2.756220250788885, 2.1366565190023072],
-    [-4.732198353096438, -2.836370538453591, 1.9024186927785094],
-    [-4.587324107081598, -3.065151973564144, 1.9024186927785094],
-    [-4.861538496796549, -3.248376170981752, 2.1366565190023072],
-    [-5.015072824817594, -3.0059189719307122, 2.1366565190023072],
-    [-4.587324107081598, -3.065151973564144, 1.9024186927785094],
-    [-4.431398595490661, -3.2865491890947784, 1.9024186927785094],
-    [-4.696292296716239, -3.483007747312749, 2.1366565190023072],
-    [-4.861538496796549, -3.248376170981752, 2.1366565190023072],
-    [-4.431398595490661, -3.2865491890947784, 1.9024186927785094],
-    [-4.264797456538513, -3.5000288197611713, 1.9024186927785094],
-    [-4.519732317146517, -3.7092484529050207, 2.1366565190023072],
-    [-4.696292296716239, -3.483007747312749, 2.1366565190023072],
-    [-4.264797456538513, -3.5000288197611713, 1.9024186927785094],
-    [-4.087922046961424, -3.705076574434444, 1.9024186927785094],
-    [-4.332283906542993, -3.9265532540824535, 2.1366565190023072],
-    [-4.519732317146517, -3.7092484529050207, 2.1366565190023072],
-    [-4.087922046961424, -3.705076574434444, 1.9024186927785094],
-    [-3.9011984751135236, -3.9011984751135227, 1.9024186927785094],
-    [-4.134398644545355, -4.134398644545353, 2.1366565190023072],
-    [-4.332283906542993, -3.9265532540824535, 2.1366565190023072],
-    [-3.9011984751135236, -3.9011984751135227, 1.9024186927785094],
-    [-3.7050765744344454, -4.087922046961423, 1.9024186927785094],
-    [-3.9265532540824544, -4.332283906542991, 2.1366565190023072],
-    [-4.134398644545355, -4.134398644545353, 2.1366565190023072],
-    [-3.7050765744344454, -4.087922046961423, 1.9024186927785094],
-    [-3.500028819761175, -4.264797456538511, 1.9024186927785094],
-    [-3.7092484529050247, -4.519732317146515, 2.1366565190023072],
-    [-3.9265532540824544, -4.332283906542991, 2.1366565190023072],
-    [-3.500028819761175, -4.264797456538511, 1.9024186927785094],
-    [-3.286549189094778, -4.431398595490662, 1.9024186927785094],
-    [-3.4830077473127483, -4.696292296716239, 2.1366565190023072],
-    [-3.7092484529050247, -4.519732317146515, 2.1366565190023072],
-    [-3.286549189094778, -4.431398595490662, 1.9024186927785094],
-    [-3.0651519735641455, -4.5873241070815975, 1.9024186927785094],
-    [-3.248376170981753, -4.861538496796548, 2.1366565190023072],
-    [-3.4830077473127483, -4.696292296716239, 2.1366565190023072],
-    [-3.0651519735641455, -4.5873241070815975, 1.9024186927785094],
-    [-2.8363705384535924, -4.732198353096437, 1.9024186927785094],
-    [-3.005918971930713, -5.015072824817594, 2.1366565190023072],
-    [-3.248376170981753, -4.861538496796548, 2.1366565190023072],
-    [-2.8363705384535924, -4.732198353096437, 1.9024186927785094],
-    [-2.6007560382791874, -4.865672318787045, 1.9024186927785094],
-    [-2.7562202507888864, -5.156525403135154, 2.1366565190023072],
-    [-3.005918971930713, -5.015072824817594, 2.1366565190023072],
-    [-2.6007560382791874, -4.865672318787045, 1.9024186927785094],
-    [-2.358876089009243, -4.987424453679134, 1.9024186927785094],
-    [-2.499881553646528, -5.28555546009828, 2.1366565190023072],
-    [-2.7562202507888864, -5.156525403135154, 2.1366565190023072],
-    [-2.358876089009243, -4.987424453679134, 1.9024186927785094],
-    [-2.1113134006277323, -5.097161446215522, 1.9024186927785094],
-    [-2.2375204228776715, -5.401852150998049, 2.1366565190023072],
-    [-2.499881553646528, -5.28555546009828, 2.1366565190023072],
-    [-2.1113134006277323, -5.097161446215522, 1.9024186927785094],
-    [-1.8586643733348915, -5.194618930369365, 1.9024186927785094],
-    [-1.9697689094264554, -5.505135306919697, 2.1366565190023072],
-    [-2.2375204228776715, -5.401852150998049, 2.1366565190023072],
-    [-1.8586643733348915, -5.194618930369365, 1.9024186927785094],
-    [-1.6015376607669205, -5.279562122525065, 1.9024186927785094],
-    [-1.6972720501410565, -5.595156109694071, 2.1366565190023072],
-    [-1.9697689094264554, -5.505135306919697, 2.1366565190023072],
-    [-1.6015376607669205, -5.279562122525065, 1.9024186927785094],
-    [-1.34055270369603, -5.351786387092577, 1.9024186927785094],
-    [-1.4206863138233938, -5.6716976913223975, 2.1366565190023072],
-    [-1.6972720501410565, -5.595156109694071, 2.1366565190023072],
-    [-1.34055270369603, -5.351786387092577, 1.9024186927785094],
-    [-1.076338237743387, -5.4111177294924575, 1.9024186927785094],
-    [-1.1406780197383064, -5.734575656430283, 2.1366565190023072],
-    [-1.4206863138233938, -5.6716976913223975, 2.1366565190023072],
-    [-1.076338237743387, -5.4111177294924575, 1.9024186927785094],
-    [-0.8095307786999247, -5.457413215324055, 1.9024186927785094],
-    [-0.8579217323920747, -5.783638526492323, 2.1366565190023072],
-    [-1.1406780197383064, -5.734575656430283, 2.1366565190023072],
-    [-0.8095307786999247, -5.457413215324055, 1.9024186927785094],
-    [-0.540773089104048, -5.490561314706996, 1.9024186927785094],
-    [-0.5730986364474369, -5.81876810475714, 2.1366565190023072],
-    [-0.8579217323920747, -5.783638526492323, 2.1366565190023072],
-    [-0.540773089104048, -5.490561314706996, 1.9024186927785094],
-    [-0.2707126297703959, -5.5104821709664344, 1.9024186927785094],
-    [-0.28689489569009013, -5.839879760993704, 2.1366565190023072],
-    [-0.5730986364474369, -5.81876810475714, 2.1366565190023072],
-    [-0.2707126297703959, -5.5104821709664344, 1.9024186927785094],
-    [
-        -1.0134799338303678e-15,
-        -5.517127793014781,
-        1.9024186927785094,
-    ],
-    [
-        -1.074062263540759e-15,
-        -5.84692263537298,
-        2.1366565190023072,
-    ],
-    [-0.28689489569009013, -5.839879760993704, 2.1366565190023072],
-    [
-        -1.0134799338303678e-15,
-        -5.517127793014781,
-        1.9024186927785094,
-    ],
-    [0.27071262977039384, -5.5104821709664344, 1.9024186927785094],
-    [0.28689489569008797, -5.839879760993704, 2.1366565190023072],
-    [
-        -1.074062263540759e-15,
-        -5.84692263537298,
-        2.1366565190023072,
-    ],
-    [0.27071262977039384, -5.5104821709664344, 1.9024186927785094],
-    [0.540773089104046, -5.490561314706996, 1.9024186927785094],
-    [0.5730986364474348, -5.81876810475714, 2.1366565190023072],
-    [0.28689489569008797, -5.839879760993704, 2.1366565190023072],
-    [0.540773089104046, -5.490561314706996, 1.9024186927785094],
-    [0.8095307786999227, -5.457413215324055, 1.9024186927785094],
-    [0.8579217323920726, -5.783638526492323, 2.1366565190023072],
-    [0.5730986364474348, -5.81876810475714, 2.1366565190023072],
-    [0.8095307786999227, -5.457413215324055, 1.9024186927785094],
-    [1.076338237743385, -5.411117729492458, 1.9024186927785094],
-    [1.1406780197383042, -5.734575656430283, 2.1366565190023072],
-    [0.8579217323920726, -5.783638526492323, 2.1366565190023072],
-    [1.076338237743385, -5.411117729492458, 1.9024186927785094],
-    [1.340552703696028, -5.351786387092577, 1.9024186927785094],
-    [1.4206863138233918, -5.6716976913223975, 2.1366565190023072],
-    [1.1406780197383042, -5.734575656430283, 2.1366565190023072],
-    [1.340552703696028, -5.351786387092577, 1.9024186927785094],
-    [1.6015376607669183, -5.279562122525066, 1.9024186927785094],
-    [1.6972720501410543, -5.595156109694072, 2.1366565190023072],
-    [1.4206863138233918, -5.6716976913223975, 2.1366565190023072],
-    [1.6015376607669183, -5.279562122525066, 1.9024186927785094],
-    [1.8586643733348898, -5.194618930369366, 1.9024186927785094],
-    [1.9697689094264537, -5.505135306919698, 2.1366565190023072],
-    [1.6972720501410543, -5.595156109694072, 2.1366565190023072],
-    [1.8586643733348898, -5.194618930369366, 1.9024186927785094],
-    [2.1113134006277305, -5.097161446215523, 1.9024186927785094],
-    [2.2375204228776697, -5.401852150998049, 2.1366565190023072],
-    [1.9697689094264537, -5.505135306919698, 2.1366565190023072],
-    [2.1113134006277305, -5.097161446215523, 1.9024186927785094],
-    [2.3588760890092413, -4.987424453679135, 1.9024186927785094],
-    [2.499881553646526, -5.285555460098281, 2.1366565190023072],
-    [2.2375204228776697, -5.401852150998049, 2.1366565190023072],
-    [2.3588760890092413, -4.987424453679135, 1.9024186927785094],
-    [2.6007560382791857, -4.865672318787046, 1.9024186927785094],
-    [2.756220250788885, -5.156525403135155, 2.1366565190023072],
-    [2.499881553646526, -5.285555460098281, 2.1366565190023072],
-    [2.6007560382791857, -4.865672318787046, 1.9024186927785094],
-    [2.836370538453591, -4.732198353096438, 1.9024186927785094],
-    [3.0059189719307122, -5.015072824817595, 2.1366565190023072],
-    [2.756220250788885, -5.156525403135155, 2.1366565190023072],
-    [2.836370538453591, -4.732198353096438, 1.9024186927785094],
-    [3.0651519735641437, -4.587324107081598, 1.9024186927785094],
-    [3.248376170981751, -4.861538496796549, 2.1366565190023072],
-    [3.0059189719307122, -5.015072824817595, 2.1366565190023072],
-    [3.0651519735641437, -4.587324107081598, 1.9024186927785094],
-    [3.286549189094776, -4.431398595490663, 1.9024186927785094],
-    [3.4830077473127465, -4.696292296716241, 2.1366565190023072],
-    [3.248376170981751, -4.861538496796549, 2.1366565190023072],
-    [3.286549189094776, -4.431398595490663, 1.9024186927785094],
-    [3.500028819761173, -4.2647974565385125, 1.9024186927785094],
-    [3.7092484529050225, -4.519732317146516, 2.1366565190023072],
-    [3.4830077473127465, -4.696292296716241, 2.1366565190023072],
-    [3.500028819761173, -4.2647974565385125, 1.9024186927785094],
-    [3.7050765744344436, -4.087922046961424, 1.9024186927785094],
-    [3.9265532540824526, -4.332283906542993, 2.1366565190023072],
-    [3.7092484529050225, -4.519732317146516, 2.1366565190023072],
-    [3.7050765744344436, -4.087922046961424, 1.9024186927785094],
-    [3.901198475113522, -3.9011984751135236, 1.9024186927785094],
-    [4.134398644545353, -4.134398644545355, 2.1366565190023072],
-    [3.9265532540824526, -4.332283906542993, 2.1366565190023072],
-    [3.901198475113522, -3.9011984751135236, 1.9024186927785094],
-    [4.087922046961423, -3.7050765744344454, 1.9024186927785094],
-    [4.332283906542991, -3.9265532540824544, 2.1366565190023072],
-    [4.134398644545353, -4.134398644545355, 2.1366565190023072],
-    [4.087922046961423, -3.7050765744344454, 1.9024186927785094],
-    [4.26479745653851, -3.500028819761175, 1.9024186927785094],
-    [4.519732317146514, -3.7092484529050247, 2.1366565190023072],
-    [4.332283906542991, -3.9265532540824544, 2.1366565190023072],
-    [4.26479745653851, -3.500028819761175, 1.9024186927785094],
-    [4.431398595490662, -3.2865491890947784, 1.9024186927785094],
-    [4.696292296716239, -3.483007747312749, 2.1366565190023072],
-    [4.519732317146514, -3.7092484529050247, 2.1366565190023072],
-    [4.431398595490662, -3.2865491890947784, 1.9024186927785094],
-    [4.5873241070815975, -3.0651519735641455, 1.9024186927785094],
-    [4.861538496796548, -3.248376170981753, 2.1366565190023072],
-    [4.696292296716239, -3.483007747312749, 2.1366565190023072],
-    [4.5873241070815975, -3.0651519735641455, 1.9024186927785094],
-    [4.732198353096437, -2.836370538453593, 1.9024186927785094],
-    [5.015072824817594, -3.005918971930714, 2.1366565190023072],
-    [4.861538496796548, -3.248376170981753, 2.1366565190023072],
-    [4.732198353096437, -2.836370538453593, 1.9024186927785094],
-    [4.865672318787045, -2.6007560382791874, 1.9024186927785094],
-    [5.156525403135154, -2.756220250788887, 2.1366565190023072],
-    [5.015072824817594, -3.005918971930714, 2.1366565190023072],
-    [4.865672318787045, -2.6007560382791874, 1.9024186927785094],
-    [4.987424453679134, -2.358876089009243, 1.9024186927785094],
-    [5.28555546009828, -2.4998815536465284, 2.1366565190023072],
-    [5.156525403135154, -2.756220250788887, 2.1366565190023072],
-    [4.987424453679134, -2.358876089009243, 1.9024186927785094],
-    [5.097161446215522, -2.1113134006277323, 1.9024186927785094],
-    [5.401852150998049, -2.237520422877672, 2.1366565190023072],
-    [5.28555546009828, -2.4998815536465284, 2.1366565190023072],
-    [5.097161446215522, -2.1113134006277323, 1.9024186927785094],
-    [5.194618930369365, -1.858664373334892, 1.9024186927785094],
-    [5.505135306919697, -1.9697689094264559, 2.1366565190023072],
-    [5.401852150998049, -2.237520422877672, 2.1366565190023072],
-    [5.194618930369365, -1.858664373334892, 1.9024186927785094],
-    [5.279562122525065, -1.6015376607669207, 1.9024186927785094],
-    [5.595156109694071, -1.6972720501410568, 2.1366565190023072],
-    [5.505135306919697, -1.9697689094264559, 2.1366565190023072],
-    [5.279562122525065, -1.6015376607669207, 1.9024186927785094],
-    [5.351786387092577, -1.3405527036960303, 1.9024186927785094],
-    [5.6716976913223975, -1.4206863138233943, 2.1366565190023072],
-    [5.595156109694071, -1.6972720501410568, 2.1366565190023072],
-    [5.351786387092577, -1.3405527036960303, 1.9024186927785094],
-    [5.4111177294924575, -1.0763382377433872, 1.9024186927785094],
-    [5.734575656430283, -1.1406780197383066, 2.1366565190023072],
-    [5.6716976913223975, -1.4206863138233943, 2.1366565190023072],
-    [5.4111177294924575, -1.0763382377433872, 1.9024186927785094],
-    [5.457413215324055, -0.8095307786999252, 1.9024186927785094],
-    [5.783638526492323, -0.8579217323920751, 2.1366565190023072],
-    [5.734575656430283, -1.1406780197383066, 2.1366565190023072],
-    [5.457413215324055, -0.8095307786999252, 1.9024186927785094],
-    [5.490561314706996, -0.5407730891040483, 1.9024186927785094],
-    [5.81876810475714, -0.5730986364474371, 2.1366565190023072],
-    [5.783638526492323, -0.8579217323920751, 2.1366565190023072],
-    [5.490561314706996, -0.5407730891040483, 1.9024186927785094],
-    [5.5104821709664344, -0.27071262977039623, 1.9024186927785094],
-    [5.839879760993704, -0.2868948956900905, 2.1366565190023072],
-    [5.81876810475714, -0.5730986364474371, 2.1366565190023072],
-    [5.5104821709664344, -0.27071262977039623, 1.9024186927785094],
-    [
-        5.517127793014781,
-        -1.3513065784404905e-15,
-        1.9024186927785094,
-    ],
-    [
-        5.84692263537298,
-        -1.4320830180543454e-15,
-        2.1366565190023072,
-    ],
-    [5.839879760993704, -0.2868948956900905, 2.1366565190023072],
-    [5.84692263537298, 0.0, 2.1366565190023072],
-    [5.839879760993704, 0.2868948956900901, 2.1366565190023072],
-    [6.169277351020975, 0.3030771616097843, 2.384489924981864],
-    [6.176717477731179, 0.0, 2.384489924981864],
-    [5.839879760993704, 0.2868948956900901, 2.1366565190023072],
-    [5.81876810475714, 0.5730986364474377, 2.1366565190023072],
-    [6.146974894807285, 0.6054241837908266, 2.384489924981864],
-    [6.169277351020975, 0.3030771616097843, 2.384489924981864],
-    [5.81876810475714, 0.5730986364474377, 2.1366565190023072],
-    [5.783638526492324, 0.8579217323920715, 2.1366565190023072],
-    [6.109863837660592, 0.9063126860842212, 2.384489924981864],
-    [6.146974894807285, 0.6054241837908266, 2.384489924981864],
-    [5.783638526492324, 0.8579217323920715, 2.1366565190023072],
-    [5.734575656430283, 1.140678019738304, 2.1366565190023072],
-    [6.0580335833681085, 1.2050178017332231, 2.384489924981864],
-    [6.109863837660592, 0.9063126860842212, 2.384489924981864],
-    [5.734575656430283, 1.140678019738304, 2.1366565190023072],
-    [5.6716976913223975, 1.4206863138233925, 2.1366565190023072],
-    [5.991608995552219, 1.5008199239507563, 2.384489924981864],
-    [6.0580335833681085, 1.2050178017332231, 2.384489924981864],
-    [5.6716976913223975, 1.4206863138233925, 2.1366565190023072],
-    [5.595156109694071, 1.6972720501410559, 2.1366565190023072],
-    [5.910750096863077, 1.793006439515192, 2.384489924981864],
-    [5.991608995552219, 1.5008199239507563, 2.384489924981864],
-    [5.595156109694071, 1.6972720501410559, 2.1366565190023072],
-    [5.505135306919697, 1.969768909426456, 2.1366565190023072],
-    [5.815651683470029, 2.0808734455180202, 2.384489924981864],
-    [5.910750096863077, 1.793006439515192, 2.384489924981864],
-    [5.505135306919697, 1.969768909426456, 2.1366565190023072],
-    [5.4018521509980495, 2.2375204228776684, 2.1366565190023072],
-    [5.706542855780576, 2.3637274451276076, 2.384489924981864],
-    [5.815651683470029, 2.0808734455180202, 2.384489924981864],
-    [5.4018521509980495, 2.2375204228776684, 2.1366565190023072],
-    [5.285555460098281, 2.4998815536465258, 2.1366565190023072],
-    [5.583686466517427, 2.6408870182838107, 2.384489924981864],
-    [5.706542855780576, 2.3637274451276076, 2.384489924981864],
-    [5.285555460098281, 2.4998815536465258, 2.1366565190023072],
-    [5.156525403135155, 2.756220250788885, 2.1366565190023072],
-    [5.447378487483264, 2.9116844632985845, 2.384489924981864],
-    [5.583686466517427, 2.6408870182838107, 2.384489924981864],
-    [5.156525403135155, 2.756220250788885, 2.1366565190023072],
-    [5.015072824817594, 3.0059189719307127, 2.1366565190023072],
-    [5.297947296538751, 3.175467405407834, 2.384489924981864],
-    [5.447378487483264, 2.9116844632985845, 2.384489924981864],
-    [5.015072824817594, 3.0059189719307127, 2.1366565190023072],
-    [4.861538496796548, 3.248376170981753, 2.1366565190023072],
-    [5.135752886511499, 3.431600368399361, 2.384489924981864],
-    [5.297947296538751, 3.175467405407834, 2.384489924981864],
-    [4.861538496796548, 3.248376170981753, 2.1366565190023072],
-    [4.696292296716239, 3.4830077473127496, 2.1366565190023072],
-    [4.961185997941816, 3.6794663055307204, 2.384489924981864],
-    [5.135752886511499, 3.431600368399361, 2.384489924981864],
-    [4.696292296716239, 3.4830077473127496, 2.1366565190023072],
-    [4.5197323171465165, 3.709248452905022, 2.1366565190023072],
-    [4.77466717775452, 3.9184680860488714, 2.384489924981864],
-    [4.961185997941816, 3.6794663055307204, 2.384489924981864],
-    [4.5197323171465165, 3.709248452905022, 2.1366565190023072],
-    [4.332283906542994, 3.9265532540824526, 2.1366565190023072],
-    [4.576645766124562, 4.148029933730462, 2.384489924981864],
-    [4.77466717775452, 3.9184680860488714, 2.384489924981864],
-    [4.332283906542994, 3.9265532540824526, 2.1366565190023072],
-    [4.134398644545354, 4.134398644545354, 2.1366565190023072],
-    [4.367598813977185, 4.367598813977185, 2.384489924981864],
-    [4.576645766124562, 4.148029933730462, 2.384489924981864],
-    [4.134398644545354, 4.134398644545354, 2.1366565190023072],
-    [3.9265532540824526, 4.332283906542993, 2.1366565190023072],
-    [4.148029933730462, 4.576645766124562, 2.384489924981864],
-    [4.367598813977185, 4.367598813977185, 2.384489924981864],
-    [3.9265532540824526, 4.332283906542993, 2.1366565190023072],
-    [3.709248452905022, 4.519732317146516, 2.1366565190023072],
-    [3.9184680860488714, 4.7746671777545195, 2.384489924981864],
-    [4.148029933730462, 4.576645766124562, 2.384489924981864],
-    [3.709248452905022, 4.519732317146516, 2.1366565190023072],
-    [3.4830077473127505, 4.696292296716238, 2.1366565190023072],
-    [3.6794663055307213, 4.961185997941815, 2.384489924981864],
-    [3.9184680860488714, 4.7746671777545195, 2.384489924981864],
-    [3.4830077473127505, 4.696292296716238, 2.1366565190023072],
-    [3.2483761709817536, 4.861538496796548, 2.1366565190023072],
-    [3.4316003683993612, 5.135752886511499, 2.384489924981864],
-    [3.6794663055307213, 4.961185997941815, 2.384489924981864],
-    [3.2483761709817536, 4.861538496796548, 2.1366565190023072],
-    [3.0059189719307127, 5.015072824817594, 2.1366565190023072],
-    [3.175467405407834, 5.297947296538751, 2.384489924981864],
-    [3.4316003683993612, 5.135752886511499, 2.384489924981864],
-    [3.0059189719307127, 5.015072824817594, 2.1366565190023072],
-    [2.7562202507888864, 5.156525403135154, 2.1366565190023072],
-    [2.9116844632985854, 5.447378487483263, 2.384489924981864],
-    [3.175467405407834, 5.297947296538751, 2.384489924981864],
-    [2.7562202507888864, 5.156525403135154, 2.1366565190023072],
-    [2.499881553646526, 5.285555460098281, 2.1366565190023072],
-    [2.640887018283811, 5.583686466517427, 2.384489924981864],
-    [2.9116844632985854, 5.447378487483263, 2.384489924981864],
-    [2.499881553646526, 5.285555460098281, 2.1366565190023072],
-    [2.237520422877669, 5.4018521509980495, 2.1366565190023072],
-    [2.363727445127608, 5.706542855780576, 2.384489924981864],
-    [2.640887018283811, 5.583686466517427, 2.384489924981864],
-    [2.237520422877669, 5.4018521509980495, 2.1366565190023072],
-    [1.969768909426456, 5.505135306919697, 2.1366565190023072],
-    [2.0808734455180202, 5.815651683470029, 2.384489924981864],
-    [2.363727445127608, 5.706542855780576, 2.384489924981864],
-    [1.969768909426456, 5.505135306919697, 2.1366565190023072],
-    [1.6972720501410559, 5.595156109694072, 2.1366565190023072],
-    [1.793006439515192, 5.910750096863077, 2.384489924981864],
-    [2.0808734455180202, 5.815651683470029, 2.384489924981864],
-    [1.6972720501410559, 5.595156109694072, 2.1366565190023072],
-    [1.4206863138233932, 5.6716976913223975, 2.1366565190023072],
-    [1.500819923950757, 5.991608995552219, 2.384489924981864],
-    [1.793006439515192, 5.910750096863077, 2.384489924981864],
-    [1.4206863138233932, 5.6716976913223975, 2.1366565190023072],
-    [1.1406780197383044, 5.734575656430283, 2.1366565190023072],
-    [1.2050178017332236, 6.0580335833681085, 2.384489924981864],
-    [1.500819923950757, 5.991608995552219, 2.384489924981864],
-    [1.1406780197383044, 5.734575656430283, 2.1366565190023072],
-    [0.8579217323920715, 5.783638526492324, 2.1366565190023072],
-    [0.9063126860842212, 6.109863837660592, 2.384489924981864],
-    [1.2050178017332236, 6.0580335833681085, 2.384489924981864],
-    [0.8579217323920715, 5.783638526492324, 2.1366565190023072],
-    [0.5730986364474386, 5.81876810475714, 2.1366565190023072],
-    [0.6054241837908276, 6.146974894807284, 2.384489924981864],
-    [0.9063126860842212, 6.109863837660592, 2.384489924981864],
-    [0.5730986364474386, 5.81876810475714, 2.1366565190023072],
-    [0.2868948956900907, 5.839879760993704, 2.1366565190023072],
-    [0.30307716160978504, 6.169277351020975, 2.384489924981864],
-    [0.6054241837908276, 6.146974894807284, 2.384489924981864],
-    [0.2868948956900907, 5.839879760993704, 2.1366565190023072],
-    [3.5802075451358635e-16, 5.84692263537298, 2.1366565190023072],
-    [3.782148644170501e-16, 6.176717477731179, 2.384489924981864],
-    [0.30307716160978504, 6.169277351020975, 2.384489924981864],
-    [3.5802075451358635e-16, 5.84692263537298, 2.1366565190023072],
-    [-0.28689489569009, 5.839879760993704, 2.1366565190023072],
-    [-0.30307716160978426, 6.169277351020975, 2.384489924981864],
-    [3.782148644170501e-16, 6.176717477731179, 2.384489924981864],
-    [-0.28689489569009, 5.839879760993704, 2.1366565190023072],
-    [-0.573098636447438, 5.81876810475714, 2.1366565190023072],
-    [-0.6054241837908269, 6.146974894807285, 2.384489924981864],
-    [-0.30307716160978426, 6.169277351020975, 2.384489924981864],
-    [-0.573098636447438, 5.81876810475714, 2.1366565190023072],
-    [-0.8579217323920708, 5.783638526492324, 2.1366565190023072],
-    [-0.9063126860842206, 6.109863837660592, 2.384489924981864],
-    [-0.6054241837908269, 6.146974894807285, 2.384489924981864],
-    [-0.8579217323920708, 5.783638526492324, 2.1366565190023072],
-    [-1.1406780197383035, 5.734575656430283, 2.1366565190023072],
-    [-1.205017801733223, 6.0580335833681085, 2.384489924981864],
-    [-0.9063126860842206, 6.109863837660592, 2.384489924981864],
-    [-1.1406780197383035, 5.734575656430283, 2.1366565190023072],
-    [-1.4206863138233925, 5.6716976913223975, 2.1366565190023072],
-    [-1.5008199239507563, 5.991608995552219, 2.384489924981864],
-    [-1.205017801733223, 6.0580335833681085, 2.384489924981864],
-    [-1.4206863138233925, 5.6716976913223975, 2.1366565190023072],
-    [-1.697272050141055, 5.595156109694072, 2.1366565190023072],
-    [-1.793006439515191, 5.910750096863077, 2.384489924981864],
-    [-1.5008199239507563, 5.991608995552219, 2.384489924981864],
-    [-1.697272050141055, 5.595156109694072, 2.1366565190023072],
-    [-1.9697689094264554, 5.505135306919697, 2.1366565190023072],
-    [-2.0808734455180193, 5.815651683470029, 2.384489924981864],
-    [-1.793006439515191, 5.910750096863077, 2.384489924981864],
-    [-1.9697689094264554, 5.505135306919697, 2.1366565190023072],
-    [-2.237520422877668, 5.4018521509980495, 2.1366565190023072],
-    [-2.363727445127607, 5.706542855780576, 2.384489924981864],
-    [-2.0808734455180193, 5.815651683470029, 2.384489924981864],
-    [-2.237520422877668, 5.4018521509980495, 2.1366565190023072],
-    [-2.4998815536465244, 5.285555460098282, 2.1366565190023072],
-    [-2.6408870182838093, 5.5836864665174275, 2.384489924981864],
-    [-2.363727445127607, 5.706542855780576, 2.384489924981864],
-    [-2.4998815536465244, 5.285555460098282, 2.1366565190023072],
-    [-2.7562202507888856, 5.156525403135155, 2.1366565190023072],
-    [-2.911684463298585, 5.447378487483264, 2.384489924981864],
-    [-2.6408870182838093, 5.5836864665174275, 2.384489924981864],
-    [-2.7562202507888856, 5.156525403135155, 2.1366565190023072],
-    [-3.0059189719307122, 5.015072824817594, 2.1366565190023072],
-    [-3.175467405407833, 5.297947296538751, 2.384489924981864],
-    [-2.911684463298585, 5.447378487483264, 2.384489924981864],
-    [-3.0059189719307122, 5.015072824817594, 2.1366565190023072],
-    [-3.248376170981752, 4.861538496796548, 2.1366565190023072],
-    [-3.431600368399359, 5.135752886511499, 2.384489924981864],
-    [-3.175467405407833, 5.297947296538751, 2.384489924981864],
-    [-3.248376170981752, 4.861538496796548, 2.1366565190023072],
-    [-3.4830077473127496, 4.696292296716239, 2.1366565190023072],
-    [-3.6794663055307204, 4.961185997941816, 2.384489924981864],
-    [-3.431600368399359, 5.135752886511499, 2.384489924981864],
-    [-3.4830077473127496, 4.696292296716239, 2.1366565190023072],
-    [-3.709248452905021, 4.519732317146517, 2.1366565190023072],
-    [-3.918468086048871, 4.77466717775452, 2.384489924981864],
-    [-3.6794663055307204, 4.961185997941816, 2.384489924981864],
-    [-3.709248452905021, 4.519732317146517, 2.1366565190023072],
-    [-3.9265532540824535, 4.332283906542992, 2.1366565190023072],
-    [-4.148029933730463, 4.576645766124561, 2.384489924981864],
-    [-3.918468086048871, 4.77466717775452, 2.384489924981864],
-    [-3.9265532540824535, 4.332283906542992, 2.1366565190023072],
-    [-4.134398644545353, 4.134398644545354, 2.1366565190023072],
-    [-4.367598813977184, 4.367598813977185, 2.384489924981864],
-    [-4.148029933730463, 4.576645766124561, 2.384489924981864],
-    [-4.134398644545353, 4.134398644545354, 2.1366565190023072],
-    [-4.332283906542991, 3.926553254082454, 2.1366565190023072],
-    [-4.57664576612456, 4.148029933730463, 2.384489924981864],
-    [-4.367598813977184, 4.367598813977185, 2.384489924981864],
-    [-4.332283906542991, 3.926553254082454, 2.1366565190023072],
-    [-4.5197323171465165, 3.709248452905022, 2.1366565190023072],
-    [-4.77466717775452, 3.9184680860488714, 2.384489924981864],
-    [-4.57664576612456, 4.148029933730463, 2.384489924981864],
-    [-4.5197323171465165, 3.709248452905022, 2.1366565190023072],
-    [-4.696292296716238, 3.4830077473127505, 2.1366565190023072],
-    [-4.961185997941815, 3.6794663055307213, 2.384489924981864],
-    [-4.77466717775452, 3.9184680860488714, 2.384489924981864],
-    [-4.696292296716238, 3.4830077473127505, 2.1366565190023072],
-    [-4.861538496796548, 3.248376170981753, 2.1366565190023072],
-    [-5.135752886511499, 3.431600368399361, 2.384489924981864],
-    [-4.961185997941815, 3.6794663055307213, 2.384489924981864],
-    [-4.861538496796548, 3.248376170981753, 2.1366565190023072],
-    [-5.015072824817594, 3.005918971930713, 2.1366565190023072],
-    [-5.297947296538751, 3.1754674054078342, 2.384489924981864],
-    [-5.135752886511499, 3.431600368399361, 2.384489924981864],
-    [-5.015072824817594, 3.005918971930713, 2.1366565190023072],
-    [-5.156525403135154, 2.7562202507888864, 2.1366565190023072],
-    [-5.447378487483263, 2.9116844632985854, 2.384489924981864],
-    [-5.297947296538751, 3.1754674054078342, 2.384489924981864],
-    [-5.156525403135154, 2.7562202507888864, 2.1366565190023072],
-    [-5.285555460098281, 2.4998815536465253, 2.1366565190023072],
-    [-5.583686466517427, 2.6408870182838102, 2.384489924981864],
-    [-5.447378487483263, 2.9116844632985854, 2.384489924981864],
-    [-5.285555460098281, 2.4998815536465253, 2.1366565190023072],
-    [-5.4018521509980495, 2.237520422877669, 2.1366565190023072],
-    [-5.706542855780576, 2.3637274451276085, 2.384489924981864],
-    [-5.583686466517427, 2.6408870182838102, 2.384489924981864],
-    [-5.4018521509980495, 2.237520422877669, 2.1366565190023072],
-    [-5.505135306919696, 1.9697689094264579, 2.1366565190023072],
-    [-5.815651683470028, 2.080873445518022, 2.384489924981864],
-    [-5.706542855780576, 2.3637274451276085, 2.384489924981864],
-    [-5.505135306919696, 1.9697689094264579, 2.1366565190023072],
-    [-5.595156109694071, 1.6972720501410563, 2.1366565190023072],
-    [-5.910750096863077, 1.7930064395151923, 2.384489924981864],
-    [-5.815651683470028, 2.080873445518022, 2.384489924981864],
-    [-5.595156109694071, 1.6972720501410563, 2.1366565190023072],
-    [-5.6716976913223975, 1.4206863138233936, 2.1366565190023072],
-    [-5.991608995552219, 1.5008199239507574, 2.384489924981864],
-    [-5.910750096863077, 1.7930064395151923, 2.384489924981864],
-    [-5.6716976913223975, 1.4206863138233936, 2.1366565190023072],
-    [-5.734575656430283, 1.140678019738306, 2.1366565190023072],
-    [-6.0580335833681085, 1.2050178017332254, 2.384489924981864],
-    [-5.991608995552219, 1.5008199239507574, 2.384489924981864],
-    [-5.734575656430283, 1.140678019738306, 2.1366565190023072],
-    [-5.783638526492324, 0.8579217323920718, 2.1366565190023072],
-    [-6.109863837660592, 0.9063126860842216, 2.384489924981864],
-    [-6.0580335833681085, 1.2050178017332254, 2.384489924981864],
-    [-5.783638526492324, 0.8579217323920718, 2.1366565190023072],
-    [-5.81876810475714, 0.573098636447439, 2.1366565190023072],
-    [-6.146974894807284, 0.605424183790828, 2.384489924981864],
-    [-6.109863837660592, 0.9063126860842216, 2.384489924981864],
-    [-5.81876810475714, 0.573098636447439, 2.1366565190023072],
-    [-5.839879760993704, 0.2868948956900898, 2.1366565190023072],
-    [-6.169277351020975, 0.30307716160978404, 2.384489924981864],
-    [-6.146974894807284, 0.605424183790828, 2.384489924981864],
-    [-5.839879760993704, 0.2868948956900898, 2.1366565190023072],
-    [-5.84692263537298, 7.160415090271727e-16, 2.1366565190023072],
-    [-6.176717477731179, 7.564297288341002e-16, 2.384489924981864],
-    [-6.169277351020975, 0.30307716160978404, 2.384489924981864],
-    [-5.84692263537298, 7.160415090271727e-16, 2.1366565190023072],
-    [-5.839879760993704, -0.28689489569008836, 2.1366565190023072],
-    [-6.169277351020975, -0.30307716160978254, 2.384489924981864],
-    [-6.176717477731179, 7.564297288341002e-16, 2.384489924981864],
-    [-5.839879760993704, -0.28689489569008836, 2.1366565190023072],
-    [-5.81876810475714, -0.5730986364474376, 2.1366565190023072],
-    [-6.146974894807285, -0.6054241837908265, 2.384489924981864],
-    [-6.169277351020975, -0.30307716160978254, 2.384489924981864],
-    [-5.81876810475714, -0.5730986364474376, 2.1366565190023072],
-    [-5.783638526492324, -0.8579217323920705, 2.1366565190023072],
-    [-6.109863837660592, -0.9063126860842202, 2.384489924981864],
-    [-6.146974894807285, -0.6054241837908265, 2.384489924981864],
-    [-5.783638526492324, -0.8579217323920705, 2.1366565190023072],
-    [-5.734575656430283, -1.1406780197383046, 2.1366565190023072],
-    [-6.0580335833681085, -1.2050178017332238, 2.384489924981864],
-    [-6.109863837660592, -0.9063126860842202, 2.384489924981864],
-    [-5.734575656430283, -1.1406780197383046, 2.1366565190023072],
-    [-5.6716976913223975, -1.420686313823392, 2.1366565190023072],
-    [-5.991608995552219, -1.5008199239507558, 2.384489924981864],
-    [-6.0580335833681085, -1.2050178017332238, 2.384489924981864],
-    [-5.6716976913223975, -1.420686313823392, 2.1366565190023072],
-    [-5.595156109694072, -1.6972720501410545, 2.1366565190023072],
-    [-5.910750096863077, -1.7930064395151906, 2.384489924981864],
-    [-5.991608995552219, -1.5008199239507558, 2.384489924981864],
-    [-5.595156109694072, -1.6972720501410545, 2.1366565190023072],
-    [-5.505135306919697, -1.9697689094264565, 2.1366565190023072],
-    [-5.815651683470029, -2.0808734455180207, 2.384489924981864],
-    [-5.910750096863077, -1.7930064395151906, 2.384489924981864],
-    [-5.505135306919697, -1.9697689094264565, 2.1366565190023072],
-    [-5.40185215099805, -2.2375204228776675, 2.1366565190023072],
-    [-5.706542855780577, -2.363727445127607, 2.384489924981864],
-    [-5.815651683470029, -2.0808734455180207, 2.384489924981864],
-    [-5.40185215099805, -2.2375204228776675, 2.1366565190023072],
-    [-5.285555460098282, -2.499881553646524, 2.1366565190023072],
-    [-5.5836864665174275, -2.640887018283809, 2.384489924981864],
-    [-5.706542855780577, -2.363727445127607, 2.384489924981864],
-    [-5.285555460098282, -2.499881553646524, 2.1366565190023072],
-    [-5.156525403135155, -2.756220250788885, 2.1366565190023072],
-    [-5.447378487483264, -2.9116844632985845, 2.384489924981864],
-    [-5.5836864665174275, -2.640887018283809, 2.384489924981864],
-    [-5.156525403135155, -2.756220250788885, 2.1366565190023072],
-    [-5.015072824817594, -3.0059189719307122, 2.1366565190023072],
-    [-5.297947296538751, -3.175467405407833, 2.384489924981864],
-    [-5.447378487483264, -2.9116844632985845, 2.384489924981864],
-    [-5.015072824817594, -3.0059189719307122, 2.1366565190023072],
-    [-4.861538496796549, -3.248376170981752, 2.1366565190023072],
-    [-5.1357528865115, -3.431600368399359, 2.384489924981864],
-    [-5.297947296538751, -3.175467405407833, 2.384489924981864],
-    [-4.861538496796549, -3.248376170981752, 2.1366565190023072],
-    [-4.696292296716239, -3.483007747312749, 2.1366565190023072],
-    [-4.961185997941816, -3.67946630553072, 2.384489924981864],
-    [-5.1357528865115, -3.431600368399359, 2.384489924981864],
-    [-4.696292296716239, -3.483007747312749, 2.1366565190023072],
-    [-4.519732317146517, -3.7092484529050207, 2.1366565190023072],
-    [-4.77466717775452, -3.91846808604887, 2.384489924981864],
-    [-4.961185997941816, -3.67946630553072, 2.384489924981864],
-    [-4.519732317146517, -3.7092484529050207, 2.1366565190023072],
-    [-4.332283906542993, -3.9265532540824535, 2.1366565190023072],
-    [-4.576645766124562, -4.148029933730463, 2.384489924981864],
-    [-4.77466717775452, -3.91846808604887, 2.384489924981864],
-    [-4.332283906542993, -3.9265532540824535, 2.1366565190023072],
-    [-4.134398644545355, -4.134398644545353, 2.1366565190023072],
-    [-4.367598813977185, -4.367598813977184, 2.384489924981864],
-    [-4.576645766124562, -4.148029933730463, 2.384489924981864],
-    [-4.134398644545355, -4.134398644545353, 2.1366565190023072],
-    [-3.9265532540824544, -4.332283906542991, 2.1366565190023072],
-    [-4.148029933730464, -4.57664576612456, 2.384489924981864],
-    [-4.367598813977185, -4.367598813977184, 2.384489924981864],
-    [-3.9265532540824544, -4.332283906542991, 2.1366565190023072],
-    [-3.7092484529050247, -4.519732317146515, 2.1366565190023072],
-    [-3.918468086048874, -4.774667177754518, 2.384489924981864],
-    [-4.148029933730464, -4.57664576612456, 2.384489924981864],
-    [-3.7092484529050247, -4.519732317146515, 2.1366565190023072],
-    [-3.4830077473127483, -4.696292296716239, 2.1366565190023072],
-    [-3.679466305530719, -4.961185997941817, 2.384489924981864],
-    [-3.918468086048874, -4.774667177754518, 2.384489924981864],
-    [-3.4830077473127483, -4.696292296716239, 2.1366565190023072],
-    [-3.248376170981753, -4.861538496796548, 2.1366565190023072],
-    [-3.431600368399361, -5.135752886511499, 2.384489924981864],
-    [-3.679466305530719, -4.961185997941817, 2.384489924981864],
-    [-3.248376170981753, -4.861538496796548, 2.1366565190023072],
-    [-3.005918971930713, -5.015072824817594, 2.1366565190023072],
-    [-3.1754674054078342, -5.297947296538751, 2.384489924981864],
-    [-3.431600368399361, -5.135752886511499, 2.384489924981864],
-    [-3.005918971930713, -5.015072824817594, 2.1366565190023072],
-    [-2.7562202507888864, -5.156525403135154, 2.1366565190023072],
-    [-2.911684463298586, -5.447378487483263, 2.384489924981864],
-    [-3.1754674054078342, -5.297947296538751, 2.384489924981864],
-    [-2.7562202507888864, -5.156525403135154, 2.1366565190023072],
-    [-2.499881553646528, -5.28555546009828, 2.1366565190023072],
-    [-2.640887018283813, -5.583686466517425, 2.384489924981864],
-    [-2.911684463298586, -5.447378487483263, 2.384489924981864],
-    [-2.499881553646528, -5.28555546009828, 2.1366565190023072],
-    [-2.2375204228776715, -5.401852150998049, 2.1366565190023072],
-    [-2.363727445127611, -5.706542855780574, 2.384489924981864],
-    [-2.640887018283813, -5.583686466517425, 2.384489924981864],
-    [-2.2375204228776715, -5.401852150998049, 2.1366565190023072],
-    [-1.9697689094264554, -5.505135306919697, 2.1366565190023072],
-    [-2.0808734455180193, -5.815651683470029, 2.384489924981864],
-    [-2.363727445127611, -5.706542855780574, 2.384489924981864],
-    [-1.9697689094264554, -5.505135306919697, 2.1366565190023072],
-    [-1.6972720501410565, -5.595156109694071, 2.1366565190023072],
-    [-1.7930064395151926, -5.910750096863077, 2.384489924981864],
-    [-2.0808734455180193, -5.815651683470029, 2.384489924981864],
-    [-1.6972720501410565, -5.595156109694071, 2.1366565190023072],
-    [-1.4206863138233938, -5.6716976913223975, 2.1366565190023072],
-    [-1.5008199239507576, -5.991608995552219, 2.384489924981864],
-    [-1.7930064395151926, -5.910750096863077, 2.384489924981864],
-    [-1.4206863138233938, -5.6716976913223975, 2.1366565190023072],
-    [-1.1406780197383064, -5.734575656430283, 2.1366565190023072],
-    [-1.2050178017332258, -6.058033583368108, 2.384489924981864],
-    [-1.5008199239507576, -5.991608995552219, 2.384489924981864],
-    [-1.1406780197383064, -5.734575656430283, 2.1366565190023072],
-    [-0.8579217323920747, -5.783638526492323, 2.1366565190023072],
-    [-0.9063126860842247, -6.109863837660592, 2.384489924981864],
-    [-1.2050178017332258, -6.058033583368108, 2.384489924981864],
-    [-0.8579217323920747, -5.783638526492323, 2.1366565190023072],
-    [-0.5730986364474369, -5.81876810475714, 2.1366565190023072],
-    [-0.6054241837908256, -6.146974894807285, 2.384489924981864],
-    [-0.9063126860842247, -6.109863837660592, 2.384489924981864],
-    [-0.5730986364474369, -5.81876810475714, 2.1366565190023072],
-    [-0.28689489569009013, -5.839879760993704, 2.1366565190023072],
-    [-0.30307716160978443, -6.169277351020975, 2.384489924981864],
-    [-0.6054241837908256, -6.146974894807285, 2.384489924981864],
-    [-0.28689489569009013, -5.839879760993704, 2.1366565190023072],
-    [
-        -1.074062263540759e-15,
-        -5.84692263537298,
-        2.1366565190023072,
-    ],
-    [
-        -1.1346445932511502e-15,
-        -6.176717477731179,
-        2.384489924981864,
-    ],
-    [-0.30307716160978443, -6.169277351020975, 2.384489924981864],
-    [
-        -1.074062263540759e-15,
-        -5.84692263537298,
-        2.1366565190023072,
-    ],
-    [0.28689489569008797, -5.839879760993704, 2.1366565190023072],
-    [0.30307716160978215, -6.169277351020975, 2.384489924981864],
-    [
-        -1.1346445932511502e-15,
-        -6.176717477731179,
-        2.384489924981864,
-    ],
-    [0.28689489569008797, -5.839879760993704, 2.1366565190023072],
-    [0.5730986364474348, -5.81876810475714, 2.1366565190023072],
-    [0.6054241837908234, -6.146974894807285, 2.384489924981864],
-    [0.30307716160978215, -6.169277351020975, 2.384489924981864],
-    [0.5730986364474348, -5.81876810475714, 2.1366565190023072],
-    [0.8579217323920726, -5.783638526492323, 2.1366565190023072],
-    [0.9063126860842224, -6.109863837660592, 2.384489924981864],
-    [0.6054241837908234, -6.146974894807285, 2.384489924981864],
-    [0.8579217323920726, -5.783638526492323, 2.1366565190023072],
-    [1.1406780197383042, -5.734575656430283, 2.1366565190023072],
-    [1.2050178017332236, -6.0580335833681085, 2.384489924981864],
-    [0.9063126860842224, -6.109863837660592, 2.384489924981864],
-    [1.1406780197383042, -5.734575656430283, 2.1366565190023072],
-    [1.4206863138233918, -5.6716976913223975, 2.1366565190023072],
-    [1.5008199239507554, -5.991608995552219, 2.384489924981864],
-    [1.2050178017332236, -6.0580335833681085, 2.384489924981864],
-    [1.4206863138233918, -5.6716976913223975, 2.1366565190023072],
-    [1.6972720501410543, -5.595156109694072, 2.1366565190023072],
-    [1.7930064395151901, -5.910750096863077, 2.384489924981864],
-    [1.5008199239507554, -5.991608995552219, 2.384489924981864],
-    [1.6972720501410543, -5.595156109694072, 2.1366565190023072],
-    [1.9697689094264537, -5.505135306919698, 2.1366565190023072],
-    [2.0808734455180176, -5.81565168347003, 2.384489924981864],
-    [1.7930064395151901, -5.910750096863077, 2.384489924981864],
-    [1.9697689094264537, -5.505135306919698, 2.1366565190023072],
-    [2.2375204228776697, -5.401852150998049, 2.1366565190023072],
-    [2.363727445127609, -5.706542855780575, 2.384489924981864],
-    [2.0808734455180176, -5.81565168347003, 2.384489924981864],
-    [2.2375204228776697, -5.401852150998049, 2.1366565190023072],
-    [2.499881553646526, -5.285555460098281, 2.1366565190023072],
-    [2.640887018283811, -5.583686466517427, 2.384489924981864],
-    [2.363727445127609, -5.706542855780575, 2.384489924981864],
-    [2.499881553646526, -5.285555460098281, 2.1366565190023072],
-    [2.756220250788885, -5.156525403135155, 2.1366565190023072],
-    [2.911684463298584, -5.447378487483264, 2.384489924981864],
-    [2.640887018283811, -5.583686466517427, 2.384489924981864],
-    [2.756220250788885, -5.156525403135155, 2.1366565190023072],
-    [3.0059189719307122, -5.015072824817595, 2.1366565190023072],
-    [3.175467405407833, -5.2979472965387515, 2.384489924981864],
-    [2.911684463298584, -5.447378487483264, 2.384489924981864],
-    [3.0059189719307122, -5.015072824817595, 2.1366565190023072],
-    [3.248376170981751, -4.861538496796549, 2.1366565190023072],
-    [3.4316003683993586, -5.1357528865115, 2.384489924981864],
-    [3.175467405407833, -5.2979472965387515, 2.384489924981864],
-    [3.248376170981751, -4.861538496796549, 2.1366565190023072],
-    [3.4830077473127465, -4.696292296716241, 2.1366565190023072],
-    [3.6794663055307173, -4.961185997941818, 2.384489924981864],
-    [3.4316003683993586, -5.1357528865115, 2.384489924981864],
-    [3.4830077473127465, -4.696292296716241, 2.1366565190023072],
-    [3.7092484529050225, -4.519732317146516, 2.1366565190023072],
-    [3.9184680860488723, -4.7746671777545195, 2.384489924981864],
-    [3.6794663055307173, -4.961185997941818, 2.384489924981864],
-    [3.7092484529050225, -4.519732317146516, 2.1366565190023072],
-    [3.9265532540824526, -4.332283906542993, 2.1366565190023072],
-    [4.148029933730462, -4.576645766124562, 2.384489924981864],
-    [3.9184680860488723, -4.7746671777545195, 2.384489924981864],
-    [3.9265532540824526, -4.332283906542993, 2.1366565190023072],
-    [4.134398644545353, -4.134398644545355, 2.1366565190023072],
-    [4.3675988139771835, -4.367598813977185, 2.384489924981864],
-    [4.148029933730462, -4.576645766124562, 2.384489924981864],
-    [4.134398644545353, -4.134398644545355, 2.1366565190023072],
-    [4.332283906542991, -3.9265532540824544, 2.1366565190023072],
-    [4.57664576612456, -4.148029933730464, 2.384489924981864],
-    [4.3675988139771835, -4.367598813977185, 2.384489924981864],
-    [4.332283906542991, -3.9265532540824544, 2.1366565190023072],
-    [4.519732317146514, -3.7092484529050247, 2.1366565190023072],
-    [4.774667177754518, -3.918468086048874, 2.384489924981864],
-    [4.57664576612456, -4.148029933730464, 2.384489924981864],
-    [4.519732317146514, -3.7092484529050247, 2.1366565190023072],
-    [4.696292296716239, -3.483007747312749, 2.1366565190023072],
-    [4.961185997941817, -3.67946630553072, 2.384489924981864],
-    [4.774667177754518, -3.918468086048874, 2.384489924981864],
-    [4.696292296716239, -3.483007747312749, 2.1366565190023072],
-    [4.861538496796548, -3.248376170981753, 2.1366565190023072],
-    [5.135752886511499, -3.431600368399361, 2.384489924981864],
-    [4.961185997941817, -3.67946630553072, 2.384489924981864],
-    [4.861538496796548, -3.248376170981753, 2.1366565190023072],
-    [5.015072824817594, -3.005918971930714, 2.1366565190023072],
-    [5.297947296538751, -3.175467405407835, 2.384489924981864],
-    [5.135752886511499, -3.431600368399361, 2.384489924981864],
-    [5.015072824817594, -3.005918971930714, 2.1366565190023072],
-    [5.156525403135154, -2.756220250788887, 2.1366565190023072],
-    [5.447378487483262, -2.9116844632985863, 2.384489924981864],
-    [5.297947296538751, -3.175467405407835, 2.384489924981864],
-    [5.156525403135154, -2.756220250788887, 2.1366565190023072],
-    [5.28555546009828, -2.4998815536465284, 2.1366565190023072],
-    [5.583686466517425, -2.6408870182838133, 2.384489924981864],
-    [5.447378487483262, -2.9116844632985863, 2.384489924981864],
-    [5.28555546009828, -2.4998815536465284, 2.1366565190023072],
-    [5.401852150998049, -2.237520422877672, 2.1366565190023072],
-    [5.706542855780574, -2.3637274451276116, 2.384489924981864],
-    [5.583686466517425, -2.6408870182838133, 2.384489924981864],
-    [5.401852150998049, -2.237520422877672, 2.1366565190023072],
-    [5.505135306919697, -1.9697689094264559, 2.1366565190023072],
-    [5.815651683470029, -2.08087344551802, 2.384489924981864],
-    [5.706542855780574, -2.3637274451276116, 2.384489924981864],
-    [5.505135306919697, -1.9697689094264559, 2.1366565190023072],
-    [5.595156109694071, -1.6972720501410568, 2.1366565190023072],
-    [5.910750096863077, -1.793006439515193, 2.384489924981864],
-    [5.815651683470029, -2.08087344551802, 2.384489924981864],
-    [5.595156109694071, -1.6972720501410568, 2.1366565190023072],
-    [5.6716976913223975, -1.4206863138233943, 2.1366565190023072],
-    [5.991608995552219, -1.500819923950758, 2.384489924981864],
-    [5.910750096863077, -1.793006439515193, 2.384489924981864],
-    [5.6716976913223975, -1.4206863138233943, 2.1366565190023072],
-    [5.734575656430283, -1.1406780197383066, 2.1366565190023072],
-    [6.058033583368108, -1.205017801733226, 2.384489924981864],
-    [5.991608995552219, -1.500819923950758, 2.384489924981864],
-    [5.734575656430283, -1.1406780197383066, 2.1366565190023072],
-    [5.783638526492323, -0.8579217323920751, 2.1366565190023072],
-    [6.109863837660592, -0.9063126860842251, 2.384489924981864],
-    [6.058033583368108, -1.205017801733226, 2.384489924981864],
-    [5.783638526492323, -0.8579217323920751, 2.1366565190023072],
-    [5.81876810475714, -0.5730986364474371, 2.1366565190023072],
-    [6.146974894807285, -0.605424183790826, 2.384489924981864],
-    [6.109863837660592, -0.9063126860842251, 2.384489924981864],
-    [5.81876810475714, -0.5730986364474371, 2.1366565190023072],
-    [5.839879760993704, -0.2868948956900905, 2.1366565190023072],
-    [6.169277351020975, -0.3030771616097848, 2.384489924981864],
-    [6.146974894807285, -0.605424183790826, 2.384489924981864],
-    [5.839879760993704, -0.2868948956900905, 2.1366565190023072],
-    [
-        5.84692263537298,
-        -1.4320830180543454e-15,
-        2.1366565190023072,
-    ],
-    [
-        6.176717477731179,
-        -1.5128594576682003e-15,
-        2.384489924981864,
-    ],
-    [6.169277351020975, -0.3030771616097848, 2.384489924981864],
-    [6.176717477731179, 0.0, 2.384489924981864],
-    [6.169277351020975, 0.3030771616097843, 2.384489924981864],
-    [6.498674941048245, 0.3192594275294786, 2.6459189107171786],
-    [6.506512320089378, 0.0, 2.6459189107171786],
-    [6.169277351020975, 0.3030771616097843, 2.384489924981864],
-    [6.146974894807285, 0.6054241837908266, 2.384489924981864],
-    [6.4751816848574295, 0.6377497311342155, 2.6459189107171786],
-    [6.498674941048245, 0.3192594275294786, 2.6459189107171786],
-    [6.146974894807285, 0.6054241837908266, 2.384489924981864],
-    [6.109863837660592, 0.9063126860842212, 2.384489924981864],
-    [6.436089148828861, 0.954703639776371, 2.6459189107171786],
-    [6.4751816848574295, 0.6377497311342155, 2.6459189107171786],
-    [6.109863837660592, 0.9063126860842212, 2.384489924981864],
-    [6.0580335833681085, 1.2050178017332231, 2.384489924981864],
-    [6.381491510305934, 1.2693575837281426, 2.6459189107171786],
-    [6.436089148828861, 0.954703639776371, 2.6459189107171786],
-    [6.0580335833681085, 1.2050178017332231, 2.384489924981864],
-    [5.991608995552219, 1.5008199239507563, 2.384489924981864],
-    [6.311520299782039, 1.5809535340781198, 2.6459189107171786],
-    [6.381491510305934, 1.2693575837281426, 2.6459189107171786],
-    [5.991608995552219, 1.5008199239507563, 2.384489924981864],
-    [5.910750096863077, 1.793006439515192, 2.384489924981864],
-    [6.226344084032083, 1.888740828889328, 2.6459189107171786],
-    [6.311520299782039, 1.5809535340781198, 2.6459189107171786],
-    [5.910750096863077, 1.793006439515192, 2.384489924981864],
-    [5.815651683470029, 2.0808734455180202, 2.384489924981864],
-    [6.126168060020361, 2.191977981609584, 2.6459189107171786],
-    [6.226344084032083, 1.888740828889328, 2.6459189107171786],
-    [5.815651683470029, 2.0808734455180202, 2.384489924981864],
-    [5.706542855780576, 2.3637274451276076, 2.384489924981864],
-    [6.011233560563102, 2.4899344673775468, 2.6459189107171786],
-    [6.126168060020361, 2.191977981609584, 2.6459189107171786],
-    [5.706542855780576, 2.3637274451276076, 2.384489924981864],
-    [5.583686466517427, 2.6408870182838107, 2.384489924981864],
-    [5.881817472936572, 2.7818924829210956, 2.6459189107171786],
-    [6.011233560563102, 2.4899344673775468, 2.6459189107171786],
-    [5.583686466517427, 2.6408870182838107, 2.384489924981864],
-    [5.447378487483264, 2.9116844632985845, 2.384489924981864],
-    [5.738231571831373, 3.067148675808284, 2.6459189107171786],
-    [5.881817472936572, 2.7818924829210956, 2.6459189107171786],
-    [5.447378487483264, 2.9116844632985845, 2.384489924981864],
-    [5.297947296538751, 3.175467405407834, 2.384489924981864],
-    [5.5808217682599075, 3.3450158388849545, 2.6459189107171786],
-    [5.738231571831373, 3.067148675808284, 2.6459189107171786],
-    [5.297947296538751, 3.175467405407834, 2.384489924981864],
-    [5.135752886511499, 3.431600368399361, 2.384489924981864],
-    [5.409967276226449, 3.614824565816968, 2.6459189107171786],
-    [5.5808217682599075, 3.3450158388849545, 2.6459189107171786],
-    [5.135752886511499, 3.431600368399361, 2.384489924981864],
-    [4.961185997941816, 3.6794663055307204, 2.384489924981864],
-    [5.226079699167393, 3.875924863748691, 2.6459189107171786],
-    [5.409967276226449, 3.614824565816968, 2.6459189107171786],
-    [4.961185997941816, 3.6794663055307204, 2.384489924981864],
-    [4.77466717775452, 3.9184680860488714, 2.384489924981864],
-    [5.0296020383625235, 4.127687719192721, 2.6459189107171786],
-    [5.226079699167393, 3.875924863748691, 2.6459189107171786],
-    [4.77466717775452, 3.9184680860488714, 2.384489924981864],
-    [4.576645766124562, 4.148029933730462, 2.384489924981864],
-    [4.821007625706131, 4.369506613378471, 2.6459189107171786],
-    [5.0296020383625235, 4.127687719192721, 2.6459189107171786],
-    [4.576645766124562, 4.148029933730462, 2.384489924981864],
-    [4.367598813977185, 4.367598813977185, 2.384489924981864],
-    [4.600798983409016, 4.600798983409016, 2.6459189107171786],
-    [4.821007625706131, 4.369506613378471, 2.6459189107171786],
-    [4.367598813977185, 4.367598813977185, 2.384489924981864],
-    [4.148029933730462, 4.576645766124562, 2.384489924981864],
-    [4.369506613378471, 4.82100762570613, 2.6459189107171786],
-    [4.600798983409016, 4.600798983409016, 2.6459189107171786],
-    [4.148029933730462, 4.576645766124562, 2.384489924981864],
-    [3.9184680860488714, 4.7746671777545195, 2.384489924981864],
-    [4.127687719192721, 5.029602038362523, 2.6459189107171786],
-    [4.369506613378471, 4.82100762570613, 2.6459189107171786],
-    [3.9184680860488714, 4.7746671777545195, 2.384489924981864],
-    [3.6794663055307213, 4.961185997941815, 2.384489924981864],
-    [3.875924863748692, 5.226079699167393, 2.6459189107171786],
-    [4.127687719192721, 5.029602038362523, 2.6459189107171786],
-    [3.6794663055307213, 4.961185997941815, 2.384489924981864],
-    [3.4316003683993612, 5.135752886511499, 2.384489924981864],
-    [3.614824565816969, 5.409967276226449, 2.6459189107171786],
-    [3.875924863748692, 5.226079699167393, 2.6459189107171786],
-    [3.4316003683993612, 5.135752886511499, 2.384489924981864],
-    [3.175467405407834, 5.297947296538751, 2.384489924981864],
-    [3.3450158388849545, 5.5808217682599075, 2.6459189107171786],
-    [3.614824565816969, 5.409967276226449, 2.6459189107171786],
-    [3.175467405407834, 5.297947296538751, 2.384489924981864],
-    [2.9116844632985854, 5.447378487483263, 2.384489924981864],
-    [3.067148675808285, 5.738231571831372, 2.6459189107171786],
-    [3.3450158388849545, 5.5808217682599075, 2.6459189107171786],
-    [2.9116844632985854, 5.447378487483263, 2.384489924981864],
-    [2.640887018283811, 5.583686466517427, 2.384489924981864],
-    [2.781892482921096, 5.881817472936572, 2.6459189107171786],
-    [3.067148675808285, 5.738231571831372, 2.6459189107171786],
-    [2.640887018283811, 5.583686466517427, 2.384489924981864],
-    [2.363727445127608, 5.706542855780576, 2.384489924981864],
-    [2.489934467377547, 6.011233560563102, 2.6459189107171786],
-    [2.781892482921096, 5.881817472936572, 2.6459189107171786],
-    [2.363727445127608, 5.706542855780576, 2.384489924981864],
-    [2.0808734455180202, 5.815651683470029, 2.384489924981864],
-    [2.191977981609584, 6.126168060020361, 2.6459189107171786],
-    [2.489934467377547, 6.011233560563102, 2.6459189107171786],
-    [2.0808734455180202, 5.815651683470029, 2.384489924981864],
-    [1.793006439515192, 5.910750096863077, 2.384489924981864],
-    [1.888740828889328, 6.226344084032083, 2.6459189107171786],
-    [2.191977981609584, 6.126168060020361, 2.6459189107171786],
-    [1.793006439515192, 5.910750096863077, 2.384489924981864],
-    [1.500819923950757, 5.991608995552219, 2.384489924981864],
-    [1.5809535340781207, 6.311520299782039, 2.6459189107171786],
-    [1.888740828889328, 6.226344084032083, 2.6459189107171786],
-    [1.500819923950757, 5.991608995552219, 2.384489924981864],
-    [1.2050178017332236, 6.0580335833681085, 2.384489924981864],
-    [1.269357583728143, 6.381491510305934, 2.6459189107171786],
-    [1.5809535340781207, 6.311520299782039, 2.6459189107171786],
-    [1.2050178017332236, 6.0580335833681085, 2.384489924981864],
-    [0.9063126860842212, 6.109863837660592, 2.384489924981864],
-    [0.954703639776371, 6.436089148828861, 2.6459189107171786],
-    [1.269357583728143, 6.381491510305934, 2.6459189107171786],
-    [0.9063126860842212, 6.109863837660592, 2.384489924981864],
-    [0.6054241837908276, 6.146974894807284, 2.384489924981864],
-    [0.6377497311342166, 6.475181684857429, 2.6459189107171786],
-    [0.954703639776371, 6.436089148828861, 2.6459189107171786],
-    [0.6054241837908276, 6.146974894807284, 2.384489924981864],
-    [0.30307716160978504, 6.169277351020975, 2.384489924981864],
-    [0.31925942752947933, 6.498674941048245, 2.6459189107171786],
-    [0.6377497311342166, 6.475181684857429, 2.6459189107171786],
-    [0.30307716160978504, 6.169277351020975, 2.384489924981864],
-    [3.782148644170501e-16, 6.176717477731179, 2.384489924981864],
-    [
-        3.9840897432051376e-16,
-        6.506512320089378,
-        2.6459189107171786,
-    ],
-    [0.31925942752947933, 6.498674941048245, 2.6459189107171786],
-    [3.782148644170501e-16, 6.176717477731179, 2.384489924981864],
-    [-0.30307716160978426, 6.169277351020975, 2.384489924981864],
-    [-0.31925942752947856, 6.498674941048245, 2.6459189107171786],
-    [
-        3.9840897432051376e-16,
-        6.506512320089378,
-        2.6459189107171786,
-    ],
-    [-0.30307716160978426, 6.169277351020975, 2.384489924981864],
-    [-0.6054241837908269, 6.146974894807285, 2.384489924981864],
-    [-0.6377497311342157, 6.4751816848574295, 2.6459189107171786],
-    [-0.31925942752947856, 6.498674941048245, 2.6459189107171786],
-    [-0.6054241837908269, 6.146974894807285, 2.384489924981864],
-    [-0.9063126860842206, 6.109863837660592, 2.384489924981864],
-    [-0.9547036397763703, 6.436089148828861, 2.6459189107171786],
-    [-0.6377497311342157, 6.4751816848574295, 2.6459189107171786],
-    [-0.9063126860842206, 6.109863837660592, 2.384489924981864],
-    [-1.205017801733223, 6.0580335833681085, 2.384489924981864],
-    [-1.2693575837281421, 6.381491510305934, 2.6459189107171786],
-    [-0.9547036397763703, 6.436089148828861, 2.6459189107171786],
-    [-1.205017801733223, 6.0580335833681085, 2.384489924981864],
-    [-1.5008199239507563, 5.991608995552219, 2.384489924981864],
-    [-1.5809535340781198, 6.311520299782039, 2.6459189107171786],
-    [-1.2693575837281421, 6.381491510305934, 2.6459189107171786],
-    [-1.5008199239507563, 5.991608995552219, 2.384489924981864],
-    [-1.793006439515191, 5.910750096863077, 2.384489924981864],
-    [-1.8887408288893268, 6.226344084032083, 2.6459189107171786],
-    [-1.5809535340781198, 6.311520299782039, 2.6459189107171786],
-    [-1.793006439515191, 5.910750096863077, 2.384489924981864],
-    [-2.0808734455180193, 5.815651683470029, 2.384489924981864],
-    [-2.1919779816095835, 6.126168060020361, 2.6459189107171786],
-    [-1.8887408288893268, 6.226344084032083, 2.6459189107171786],
-    [-2.0808734455180193, 5.815651683470029, 2.384489924981864],
-    [-2.363727445127607, 5.706542855780576, 2.384489924981864],
-    [-2.4899344673775463, 6.011233560563102, 2.6459189107171786],
-    [-2.1919779816095835, 6.126168060020361, 2.6459189107171786],
-    [-2.363727445127607, 5.706542855780576, 2.384489924981864],
-    [-2.6408870182838093, 5.5836864665174275, 2.384489924981864],
-    [-2.781892482921094, 5.881817472936572, 2.6459189107171786],
-    [-2.4899344673775463, 6.011233560563102, 2.6459189107171786],
-    [-2.6408870182838093, 5.5836864665174275, 2.384489924981864],
-    [-2.911684463298585, 5.447378487483264, 2.384489924981864],
-    [-3.067148675808284, 5.738231571831373, 2.6459189107171786],
-    [-2.781892482921094, 5.881817472936572, 2.6459189107171786],
-    [-2.911684463298585, 5.447378487483264, 2.384489924981864],
-    [-3.175467405407833, 5.297947296538751, 2.384489924981864],
-    [-3.345015838884954, 5.5808217682599075, 2.6459189107171786],
-    [-3.067148675808284, 5.738231571831373, 2.6459189107171786],
-    [-3.175467405407833, 5.297947296538751, 2.384489924981864],
-    [-3.431600368399359, 5.135752886511499, 2.384489924981864],
-    [-3.6148245658169667, 5.40996727622645, 2.6459189107171786],
-    [-3.345015838884954, 5.5808217682599075, 2.6459189107171786],
-    [-3.431600368399359, 5.135752886511499, 2.384489924981864],
-    [-3.6794663055307204, 4.961185997941816, 2.384489924981864],
-    [-3.875924863748691, 5.226079699167393, 2.6459189107171786],
-    [-3.6148245658169667, 5.40996727622645, 2.6459189107171786],
-    [-3.6794663055307204, 4.961185997941816, 2.384489924981864],
-    [-3.918468086048871, 4.77466717775452, 2.384489924981864],
-    [-4.12768771919272, 5.029602038362524, 2.6459189107171786],
-    [-3.875924863748691, 5.226079699167393, 2.6459189107171786],
-    [-3.918468086048871, 4.77466717775452, 2.384489924981864],
-    [-4.148029933730463, 4.576645766124561, 2.384489924981864],
-    [-4.369506613378472, 4.821007625706129, 2.6459189107171786],
-    [-4.12768771919272, 5.029602038362524, 2.6459189107171786],
-    [-4.148029933730463, 4.576645766124561, 2.384489924981864],
-    [-4.367598813977184, 4.367598813977185, 2.384489924981864],
-    [-4.600798983409015, 4.600798983409016, 2.6459189107171786],
-    [-4.369506613378472, 4.821007625706129, 2.6459189107171786],
-    [-4.367598813977184, 4.367598813977185, 2.384489924981864],
-    [-4.57664576612456, 4.148029933730463, 2.384489924981864],
-    [-4.821007625706129, 4.369506613378473, 2.6459189107171786],
-    [-4.600798983409015, 4.600798983409016, 2.6459189107171786],
-    [-4.57664576612456, 4.148029933730463, 2.384489924981864],
-    [-4.77466717775452, 3.9184680860488714, 2.384489924981864],
-    [-5.0296020383625235, 4.127687719192721, 2.6459189107171786],
-    [-4.821007625706129, 4.369506613378473, 2.6459189107171786],
-    [-4.77466717775452, 3.9184680860488714, 2.384489924981864],
-    [-4.961185997941815, 3.6794663055307213, 2.384489924981864],
-    [-5.226079699167393, 3.875924863748692, 2.6459189107171786],
-    [-5.0296020383625235, 4.127687719192721, 2.6459189107171786],
-    [-4.961185997941815, 3.6794663055307213, 2.384489924981864],
-    [-5.135752886511499, 3.431600368399361, 2.384489924981864],
-    [-5.40996727622645, 3.614824565816968, 2.6459189107171786],
-    [-5.226079699167393, 3.875924863748692, 2.6459189107171786],
-    [-5.135752886511499, 3.431600368399361, 2.384489924981864],
-    [-5.297947296538751, 3.1754674054078342, 2.384489924981864],
-    [-5.580821768259907, 3.3450158388849554, 2.6459189107171786],
-    [-5.40996727622645, 3.614824565816968, 2.6459189107171786],
-    [-5.297947296538751, 3.1754674054078342, 2.384489924981864],
-    [-5.447378487483263, 2.9116844632985854, 2.384489924981864],
-    [-5.738231571831372, 3.067148675808285, 2.6459189107171786],
-    [-5.580821768259907, 3.3450158388849554, 2.6459189107171786],
-    [-5.447378487483263, 2.9116844632985854, 2.384489924981864],
-    [-5.583686466517427, 2.6408870182838102, 2.384489924981864],
-    [-5.881817472936572, 2.781892482921095, 2.6459189107171786],
-    [-5.738231571831372, 3.067148675808285, 2.6459189107171786],
-    [-5.583686466517427, 2.6408870182838102, 2.384489924981864],
-    [-5.706542855780576, 2.3637274451276085, 2.384489924981864],
-    [-6.011233560563102, 2.4899344673775476, 2.6459189107171786],
-    [-5.881817472936572, 2.781892482921095, 2.6459189107171786],
-    [-5.706542855780576, 2.3637274451276085, 2.384489924981864],
-    [-5.815651683470028, 2.080873445518022, 2.384489924981864],
-    [-6.12616806002036, 2.1919779816095857, 2.6459189107171786],
-    [-6.011233560563102, 2.4899344673775476, 2.6459189107171786],
-    [-5.815651683470028, 2.080873445518022, 2.384489924981864],
-    [-5.910750096863077, 1.7930064395151923, 2.384489924981864],
-    [-6.226344084032083, 1.8887408288893284, 2.6459189107171786],
-    [-6.12616806002036, 2.1919779816095857, 2.6459189107171786],
-    [-5.910750096863077, 1.7930064395151923, 2.384489924981864],
-    [-5.991608995552219, 1.5008199239507574, 2.384489924981864],
-    [-6.311520299782039, 1.5809535340781211, 2.6459189107171786],
-    [-6.226344084032083, 1.8887408288893284, 2.6459189107171786],
-    [-5.991608995552219, 1.5008199239507574, 2.384489924981864],
-    [-6.0580335833681085, 1.2050178017332254, 2.384489924981864],
-    [-6.381491510305934, 1.2693575837281448, 2.6459189107171786],
-    [-6.311520299782039, 1.5809535340781211, 2.6459189107171786],
-    [-6.0580335833681085, 1.2050178017332254, 2.384489924981864],
-    [-6.109863837660592, 0.9063126860842216, 2.384489924981864],
-    [-6.436089148828861, 0.9547036397763713, 2.6459189107171786],
-    [-6.381491510305934, 1.2693575837281448, 2.6459189107171786],
-    [-6.109863837660592, 0.9063126860842216, 2.384489924981864],
-    [-6.146974894807284, 0.605424183790828, 2.384489924981864],
-    [-6.475181684857429, 0.637749731134217, 2.6459189107171786],
-    [-6.436089148828861, 0.9547036397763713, 2.6459189107171786],
-    [-6.146974894807284, 0.605424183790828, 2.384489924981864],
-    [-6.169277351020975, 0.30307716160978404, 2.384489924981864],
-    [-6.498674941048245, 0.3192594275294783, 2.6459189107171786],
-    [-6.475181684857429, 0.637749731134217, 2.6459189107171786],
-    [-6.169277351020975, 0.30307716160978404, 2.384489924981864],
-    [-6.176717477731179, 7.564297288341002e-16, 2.384489924981864],
-    [
-        -6.506512320089378,
-        7.968179486410275e-16,
-        2.6459189107171786,
-    ],
-    [-6.498674941048245, 0.3192594275294783, 2.6459189107171786],
-    [-6.176717477731179, 7.564297288341002e-16, 2.384489924981864],
-    [-6.169277351020975, -0.30307716160978254, 2.384489924981864],
-    [-6.498674941048245, -0.3192594275294767, 2.6459189107171786],
-    [
-        -6.506512320089378,
-        7.968179486410275e-16,
-        2.6459189107171786,
-    ],
-    [-6.169277351020975, -0.30307716160978254, 2.384489924981864],
-    [-6.146974894807285, -0.6054241837908265, 2.384489924981864],
-    [-6.4751816848574295, -0.6377497311342154, 2.6459189107171786],
-    [-6.498674941048245, -0.3192594275294767, 2.6459189107171786],
-    [-6.146974894807285, -0.6054241837908265, 2.384489924981864],
-    [-6.109863837660592, -0.9063126860842202, 2.384489924981864],
-    [-6.436089148828861, -0.9547036397763699, 2.6459189107171786],
-    [-6.4751816848574295, -0.6377497311342154, 2.6459189107171786],
-    [-6.109863837660592, -0.9063126860842202, 2.384489924981864],
-    [-6.0580335833681085, -1.2050178017332238, 2.384489924981864],
-    [-6.381491510305934, -1.2693575837281432, 2.6459189107171786],
-    [-6.436089148828861, -0.9547036397763699, 2.6459189107171786],
-    [-6.0580335833681085, -1.2050178017332238, 2.384489924981864],
-    [-5.991608995552219, -1.5008199239507558, 2.384489924981864],
-    [-6.311520299782039, -1.5809535340781196, 2.6459189107171786],
-    [-6.381491510305934, -1.2693575837281432, 2.6459189107171786],
-    [-5.991608995552219, -1.5008199239507558, 2.384489924981864],
-    [-5.910750096863077, -1.7930064395151906, 2.384489924981864],
-    [-6.226344084032083, -1.8887408288893266, 2.6459189107171786],
-    [-6.311520299782039, -1.5809535340781196, 2.6459189107171786],
-    [-5.910750096863077, -1.7930064395151906, 2.384489924981864],
-    [-5.815651683470029, -2.0808734455180207, 2.384489924981864],
-    [-6.126168060020361, -2.1919779816095843, 2.6459189107171786],
-    [-6.226344084032083, -1.8887408288893266, 2.6459189107171786],
-    [-5.815651683470029, -2.0808734455180207, 2.384489924981864],
-    [-5.706542855780577, -2.363727445127607, 2.384489924981864],
-    [-6.011233560563103, -2.4899344673775463, 2.6459189107171786],
-    [-6.126168060020361, -2.1919779816095843, 2.6459189107171786],
-    [-5.706542855780577, -2.363727445127607, 2.384489924981864],
-    [-5.5836864665174275, -2.640887018283809, 2.384489924981864],
-    [-5.881817472936572, -2.781892482921094, 2.6459189107171786],
-    [-6.011233560563103, -2.4899344673775463, 2.6459189107171786],
-    [-5.5836864665174275, -2.640887018283809, 2.384489924981864],
-    [-5.447378487483264, -2.9116844632985845, 2.384489924981864],
-    [-5.738231571831373, -3.067148675808284, 2.6459189107171786],
-    [-5.881817472936572, -2.781892482921094, 2.6459189107171786],
-    [-5.447378487483264, -2.9116844632985845, 2.384489924981864],
-    [-5.297947296538751, -3.175467405407833, 2.384489924981864],
-    [-5.5808217682599075, -3.345015838884954, 2.6459189107171786],
-    [-5.738231571831373, -3.067148675808284, 2.6459189107171786],
-    [-5.297947296538751, -3.175467405407833, 2.384489924981864],
-    [-5.1357528865115, -3.431600368399359, 2.384489924981864],
-    [-5.409967276226451, -3.6148245658169667, 2.6459189107171786],
-    [-5.5808217682599075, -3.345015838884954, 2.6459189107171786],
-    [-5.1357528865115, -3.431600368399359, 2.384489924981864],
-    [-4.961185997941816, -3.67946630553072, 2.384489924981864],
-    [-5.226079699167393, -3.8759248637486907, 2.6459189107171786],
-    [-5.409967276226451, -3.6148245658169667, 2.6459189107171786],
-    [-4.961185997941816, -3.67946630553072, 2.384489924981864],
-    [-4.77466717775452, -3.91846808604887, 2.384489924981864],
-    [-5.029602038362524, -4.1276877191927195, 2.6459189107171786],
-    [-5.226079699167393, -3.8759248637486907, 2.6459189107171786],
-    [-4.77466717775452, -3.91846808604887, 2.384489924981864],
-    [-4.576645766124562, -4.148029933730463, 2.384489924981864],
-    [-4.82100762570613, -4.369506613378472, 2.6459189107171786],
-    [-5.029602038362524, -4.1276877191927195, 2.6459189107171786],
-    [-4.576645766124562, -4.148029933730463, 2.384489924981864],
-    [-4.367598813977185, -4.367598813977184, 2.384489924981864],
-    [-4.600798983409017, -4.600798983409015, 2.6459189107171786],
-    [-4.82100762570613, -4.369506613378472, 2.6459189107171786],
-    [-4.367598813977185, -4.367598813977184, 2.384489924981864],
-    [-4.148029933730464, -4.57664576612456, 2.384489924981864],
-    [-4.369506613378473, -4.821007625706129, 2.6459189107171786],
-    [-4.600798983409017, -4.600798983409015, 2.6459189107171786],
-    [-4.148029933730464, -4.57664576612456, 2.384489924981864],
-    [-3.918468086048874, -4.774667177754518, 2.384489924981864],
-    [-4.127687719192724, -5.029602038362522, 2.6459189107171786],
-    [-4.369506613378473, -4.821007625706129, 2.6459189107171786],
-    [-3.918468086048874, -4.774667177754518, 2.384489924981864],
-    [-3.679466305530719, -4.961185997941817, 2.384489924981864],
-    [-3.87592486374869, -5.226079699167394, 2.6459189107171786],
-    [-4.127687719192724, -5.029602038362522, 2.6459189107171786],
-    [-3.679466305530719, -4.961185997941817, 2.384489924981864],
-    [-3.431600368399361, -5.135752886511499, 2.384489924981864],
-    [-3.614824565816968, -5.409967276226449, 2.6459189107171786],
-    [-3.87592486374869, -5.226079699167394, 2.6459189107171786],
-    [-3.431600368399361, -5.135752886511499, 2.384489924981864],
-    [-3.1754674054078342, -5.297947296538751, 2.384489924981864],
-    [-3.3450158388849554, -5.580821768259907, 2.6459189107171786],
-    [-3.614824565816968, -5.409967276226449, 2.6459189107171786],
-    [-3.1754674054078342, -5.297947296538751, 2.384489924981864],
-    [-2.911684463298586, -5.447378487483263, 2.384489924981864],
-    [-3.0671486758082853, -5.738231571831372, 2.6459189107171786],
-    [-3.3450158388849554, -5.580821768259907, 2.6459189107171786],
-    [-2.911684463298586, -5.447378487483263, 2.384489924981864],
-    [-2.640887018283813, -5.583686466517425, 2.384489924981864],
-    [-2.781892482921098, -5.881817472936571, 2.6459189107171786],
-    [-3.0671486758082853, -5.738231571831372, 2.6459189107171786],
-    [-2.640887018283813, -5.583686466517425, 2.384489924981864],
-    [-2.363727445127611, -5.706542855780574, 2.384489924981864],
-    [-2.4899344673775503, -6.011233560563101, 2.6459189107171786],
-    [-2.781892482921098, -5.881817472936571, 2.6459189107171786],
-    [-2.363727445127611, -5.706542855780574, 2.384489924981864],
-    [-2.0808734455180193, -5.815651683470029, 2.384489924981864],
-    [-2.1919779816095835, -6.126168060020361, 2.6459189107171786],
-    [-2.4899344673775503, -6.011233560563101, 2.6459189107171786],
-    [-2.0808734455180193, -5.815651683470029, 2.384489924981864],
-    [-1.7930064395151926, -5.910750096863077, 2.384489924981864],
-    [-1.8887408288893288, -6.226344084032083, 2.6459189107171786],
-    [-2.1919779816095835, -6.126168060020361, 2.6459189107171786],
-    [-1.7930064395151926, -5.910750096863077, 2.384489924981864],
-    [-1.5008199239507576, -5.991608995552219, 2.384489924981864],
-    [-1.5809535340781216, -6.311520299782039, 2.6459189107171786],
-    [-1.8887408288893288, -6.226344084032083, 2.6459189107171786],
-    [-1.5008199239507576, -5.991608995552219, 2.384489924981864],
-    [-1.2050178017332258, -6.058033583368108, 2.384489924981864],
-    [-1.2693575837281452, -6.381491510305933, 2.6459189107171786],
-    [-1.5809535340781216, -6.311520299782039, 2.6459189107171786],
-    [-1.2050178017332258, -6.058033583368108, 2.384489924981864],
-    [-0.9063126860842247, -6.109863837660592, 2.384489924981864],
-    [-0.9547036397763746, -6.43608914882886, 2.6459189107171786],
-    [-1.2693575837281452, -6.381491510305933, 2.6459189107171786],
-    [-0.9063126860842247, -6.109863837660592, 2.384489924981864],
-    [-0.6054241837908256, -6.146974894807285, 2.384489924981864],
-    [-0.6377497311342145, -6.4751816848574295, 2.6459189107171786],
-    [-0.9547036397763746, -6.43608914882886, 2.6459189107171786],
-    [-0.6054241837908256, -6.146974894807285, 2.384489924981864],
-    [-0.30307716160978443, -6.169277351020975, 2.384489924981864],
-    [-0.31925942752947867, -6.498674941048245, 2.6459189107171786],
-    [-0.6377497311342145, -6.4751816848574295, 2.6459189107171786],
-    [-0.30307716160978443, -6.169277351020975, 2.384489924981864],
-    [
-        -1.1346445932511502e-15,
-        -6.176717477731179,
-        2.384489924981864,
-    ],
-    [
-        -1.1952269229615413e-15,
-        -6.506512320089378,
-        2.6459189107171786,
-    ],
-    [-0.31925942752947867, -6.498674941048245, 2.6459189107171786],
-    [
-        -1.1346445932511502e-15,
-        -6.176717477731179,
-        2.384489924981864,
-    ],
-    [0.30307716160978215, -6.169277351020975, 2.384489924981864],
-    [0.3192594275294763, -6.498674941048245, 2.6459189107171786],
-    [
-        -1.1952269229615413e-15,
-        -6.506512320089378,
-        2.6459189107171786,
-    ],
-    [0.30307716160978215, -6.169277351020975, 2.384489924981864],
-    [0.6054241837908234, -6.146974894807285, 2.384489924981864],
-    [0.6377497311342122, -6.4751816848574295, 2.6459189107171786],
-    [0.3192594275294763, -6.498674941048245, 2.6459189107171786],
-    [0.6054241837908234, -6.146974894807285, 2.384489924981864],
-    [0.9063126860842224, -6.109863837660592, 2.384489924981864],
-    [0.9547036397763723, -6.43608914882886, 2.6459189107171786],
-    [0.6377497311342122, -6.4751816848574295, 2.6459189107171786],
-    [0.9063126860842224, -6.109863837660592, 2.384489924981864],
-    [1.2050178017332236, -6.0580335833681085, 2.384489924981864],
-    [1.2693575837281428, -6.381491510305934, 2.6459189107171786],
-    [0.9547036397763723, -6.43608914882886, 2.6459189107171786],
-    [1.2050178017332236, -6.0580335833681085, 2.384489924981864],
-    [1.5008199239507554, -5.991608995552219, 2.384489924981864],
-    [1.5809535340781191, -6.311520299782039, 2.6459189107171786],
-    [1.2693575837281428, -6.381491510305934, 2.6459189107171786],
-    [1.5008199239507554, -5.991608995552219, 2.384489924981864],
-    [1.7930064395151901, -5.910750096863077, 2.384489924981864],
-    [1.8887408288893262, -6.226344084032083, 2.6459189107171786],
-    [1.5809535340781191, -6.311520299782039, 2.6459189107171786],
-    [1.7930064395151901, -5.910750096863077, 2.384489924981864],
-    [2.0808734455180176, -5.81565168347003, 2.384489924981864],
-    [2.1919779816095812, -6.126168060020362, 2.6459189107171786],
-    [1.8887408288893262, -6.226344084032083, 2.6459189107171786],
-    [2.0808734455180176, -5.81565168347003, 2.384489924981864],
-    [2.363727445127609, -5.706542855780575, 2.384489924981864],
-    [2.4899344673775485, -6.0112335605631015, 2.6459189107171786],
-    [2.1919779816095812, -6.126168060020362, 2.6459189107171786],
-    [2.363727445127609, -5.706542855780575, 2.384489924981864],
-    [2.640887018283811, -5.583686466517427, 2.384489924981864],
-    [2.781892482921096, -5.881817472936572, 2.6459189107171786],
-    [2.4899344673775485, -6.0112335605631015, 2.6459189107171786],
-    [2.640887018283811, -5.583686466517427, 2.384489924981864],
-    [2.911684463298584, -5.447378487483264, 2.384489924981864],
-    [3.0671486758082835, -5.738231571831373, 2.6459189107171786],
-    [2.781892482921096, -5.881817472936572, 2.6459189107171786],
-    [2.911684463298584, -5.447378487483264, 2.384489924981864],
-    [3.175467405407833, -5.2979472965387515, 2.384489924981864],
-    [3.345015838884954, -5.580821768259908, 2.6459189107171786],
-    [3.0671486758082835, -5.738231571831373, 2.6459189107171786],
-    [3.175467405407833, -5.2979472965387515, 2.384489924981864],
-    [3.4316003683993586, -5.1357528865115, 2.384489924981864],
-    [3.6148245658169658, -5.409967276226451, 2.6459189107171786],
-    [3.345015838884954, -5.580821768259908, 2.6459189107171786],
-    [3.4316003683993586, -5.1357528865115, 2.384489924981864],
-    [3.6794663055307173, -4.961185997941818, 2.384489924981864],
-    [3.8759248637486876, -5.2260796991673955, 2.6459189107171786],
-    [3.6148245658169658, -5.409967276226451, 2.6459189107171786],
-    [3.6794663055307173, -4.961185997941818, 2.384489924981864],
-    [3.9184680860488723, -4.7746671777545195, 2.384489924981864],
-    [4.127687719192722, -5.029602038362523, 2.6459189107171786],
-    [3.8759248637486876, -5.2260796991673955, 2.6459189107171786],
-    [3.9184680860488723, -4.7746671777545195, 2.384489924981864],
-    [4.148029933730462, -4.576645766124562, 2.384489924981864],
-    [4.369506613378471, -4.82100762570613, 2.6459189107171786],
-    [4.127687719192722, -5.029602038362523, 2.6459189107171786],
-    [4.148029933730462, -4.576645766124562, 2.384489924981864],
-    [4.3675988139771835, -4.367598813977185, 2.384489924981864],
-    [4.600798983409014, -4.600798983409017, 2.6459189107171786],
-    [4.369506613378471, -4.82100762570613, 2.6459189107171786],
-    [4.3675988139771835, -4.367598813977185, 2.384489924981864],
-    [4.57664576612456, -4.148029933730464, 2.384489924981864],
-    [4.821007625706129, -4.369506613378473, 2.6459189107171786],
-    [4.600798983409014, -4.600798983409017, 2.6459189107171786],
-    [4.57664576612456, -4.148029933730464, 2.384489924981864],
-    [4.774667177754518, -3.918468086048874, 2.384489924981864],
-    [5.029602038362521, -4.127687719192724, 2.6459189107171786],
-    [4.821007625706129, -4.369506613378473, 2.6459189107171786],
-    [4.774667177754518, -3.918468086048874, 2.384489924981864],
-    [4.961185997941817, -3.67946630553072, 2.384489924981864],
-    [5.226079699167394, -3.8759248637486907, 2.6459189107171786],
-    [5.029602038362521, -4.127687719192724, 2.6459189107171786],
-    [4.961185997941817, -3.67946630553072, 2.384489924981864],
-    [5.135752886511499, -3.431600368399361, 2.384489924981864],
-    [5.409967276226449, -3.614824565816968, 2.6459189107171786],
-    [5.226079699167394, -3.8759248637486907, 2.6459189107171786],
-    [5.135752886511499, -3.431600368399361, 2.384489924981864],
-    [5.297947296538751, -3.175467405407835, 2.384489924981864],
-    [5.580821768259907, -3.3450158388849562, 2.6459189107171786],
-    [5.409967276226449, -3.614824565816968, 2.6459189107171786],
-    [5.297947296538751, -3.175467405407835, 2.384489924981864],
-    [5.447378487483262, -2.9116844632985863, 2.384489924981864],
-    [5.7382315718313714, -3.0671486758082858, 2.6459189107171786],
-    [5.580821768259907, -3.3450158388849562, 2.6459189107171786],
-    [5.447378487483262, -2.9116844632985863, 2.384489924981864],
-    [5.583686466517425, -2.6408870182838133, 2.384489924981864],
-    [5.881817472936571, -2.7818924829210983, 2.6459189107171786],
-    [5.7382315718313714, -3.0671486758082858, 2.6459189107171786],
-    [5.583686466517425, -2.6408870182838133, 2.384489924981864],
-    [5.706542855780574, -2.3637274451276116, 2.384489924981864],
-    [6.011233560563101, -2.4899344673775508, 2.6459189107171786],
-    [5.881817472936571, -2.7818924829210983, 2.6459189107171786],
-    [5.706542855780574, -2.3637274451276116, 2.384489924981864],
-    [5.815651683470029, -2.08087344551802, 2.384489924981864],
-    [6.126168060020361, -2.191977981609584, 2.6459189107171786],
-    [6.011233560563101, -2.4899344673775508, 2.6459189107171786],
-    [5.815651683470029, -2.08087344551802, 2.384489924981864],
-    [5.910750096863077, -1.793006439515193, 2.384489924981864],
-    [6.226344084032083, -1.888740828889329, 2.6459189107171786],
-    [6.126168060020361, -2.191977981609584, 2.6459189107171786],
-    [5.910750096863077, -1.793006439515193, 2.384489924981864],
-    [5.991608995552219, -1.500819923950758, 2.384489924981864],
-    [6.311520299782039, -1.5809535340781218, 2.6459189107171786],
-    [6.226344084032083, -1.888740828889329, 2.6459189107171786],
-    [5.991608995552219, -1.500819923950758, 2.384489924981864],
-    [6.058033583368108, -1.205017801733226, 2.384489924981864],
-    [6.381491510305933, -1.2693575837281454, 2.6459189107171786],
-    [6.311520299782039, -1.5809535340781218, 2.6459189107171786],
-    [6.058033583368108, -1.205017801733226, 2.384489924981864],
-    [6.109863837660592, -0.9063126860842251, 2.384489924981864],
-    [6.43608914882886, -0.9547036397763752, 2.6459189107171786],
-    [6.381491510305933, -1.2693575837281454, 2.6459189107171786],
-    [6.109863837660592, -0.9063126860842251, 2.384489924981864],
-    [6.146974894807285, -0.605424183790826, 2.384489924981864],
-    [6.4751816848574295, -0.6377497311342148, 2.6459189107171786],
-    [6.43608914882886, -0.9547036397763752, 2.6459189107171786],
-    [6.146974894807285, -0.605424183790826, 2.384489924981864],
-    [6.169277351020975, -0.3030771616097848, 2.384489924981864],
-    [6.498674941048245, -0.3192594275294791, 2.6459189107171786],
-    [6.4751816848574295, -0.6377497311342148, 2.6459189107171786],
-    [6.169277351020975, -0.3030771616097848, 2.384489924981864],
-    [
-        6.176717477731179,
-        -1.5128594576682003e-15,
-        2.384489924981864,
-    ],
-    [
-        6.506512320089378,
-        -1.593635897282055e-15,
-        2.6459189107171786,
-    ],
-    [6.498674941048245, -0.3192594275294791, 2.6459189107171786],
-    [6.506512320089378, 0.0, 2.6459189107171786],
-    [6.498674941048245, 0.3192594275294786, 2.6459189107171786],
-    [6.828072531075515, 0.33544169344917285, 2.9209434762082527],
-    [6.836307162447577, 0.0, 2.9209434762082527],
-    [6.498674941048245, 0.3192594275294786, 2.6459189107171786],
-    [6.4751816848574295, 0.6377497311342155, 2.6459189107171786],
-    [6.803388474907574, 0.6700752784776044, 2.9209434762082527],
-    [6.828072531075515, 0.33544169344917285, 2.9209434762082527],
-    [6.4751816848574295, 0.6377497311342155, 2.6459189107171786],
-    [6.436089148828861, 0.954703639776371, 2.6459189107171786],
-    [6.762314459997129, 1.0030945934685207, 2.9209434762082527],
-    [6.803388474907574, 0.6700752784776044, 2.9209434762082527],
-    [6.436089148828861, 0.954703639776371, 2.6459189107171786],
-    [6.381491510305934, 1.2693575837281426, 2.6459189107171786],
-    [6.7049494372437595, 1.3336973657230617, 2.9209434762082527],
-    [6.762314459997129, 1.0030945934685207, 2.9209434762082527],
-    [6.381491510305934, 1.2693575837281426, 2.6459189107171786],
-    [6.311520299782039, 1.5809535340781198, 2.6459189107171786],
-    [6.63143160401186, 1.6610871442054835, 2.9209434762082527],
-    [6.7049494372437595, 1.3336973657230617, 2.9209434762082527],
-    [6.311520299782039, 1.5809535340781198, 2.6459189107171786],
-    [6.226344084032083, 1.888740828889328, 2.6459189107171786],
-    [6.541938071201088, 1.984475218263464, 2.9209434762082527],
-    [6.63143160401186, 1.6610871442054835, 2.9209434762082527],
-    [6.226344084032083, 1.888740828889328, 2.6459189107171786],
-    [6.126168060020361, 2.191977981609584, 2.6459189107171786],
-    [6.436684436570693, 2.303082517701148, 2.9209434762082527],
-    [6.541938071201088, 1.984475218263464, 2.9209434762082527],
-    [6.126168060020361, 2.191977981609584, 2.6459189107171786],
-    [6.011233560563102, 2.4899344673775468, 2.6459189107171786],
-    [6.315924265345629, 2.616141489627486, 2.9209434762082527],
-    [6.436684436570693, 2.303082517701148, 2.9209434762082527],
-    [6.011233560563102, 2.4899344673775468, 2.6459189107171786],
-    [5.881817472936572, 2.7818924829210956, 2.6459189107171786],
-    [6.179948479355717, 2.9228979475583805, 2.9209434762082527],
-    [6.315924265345629, 2.616141489627486, 2.9209434762082527],
-    [5.881817472936572, 2.7818924829210956, 2.6459189107171786],
-    [5.738231571831373, 3.067148675808284, 2.6459189107171786],
-    [6.029084656179482, 3.222612888317983, 2.9209434762082527],
-    [6.179948479355717, 2.9228979475583805, 2.9209434762082527],
-    [5.738231571831373, 3.067148675808284, 2.6459189107171786],
-    [5.5808217682599075, 3.3450158388849545, 2.6459189107171786],
-    [5.863696239981064, 3.5145642723620756, 2.9209434762082527],
-    [6.029084656179482, 3.222612888317983, 2.9209434762082527],
-    [5.5808217682599075, 3.3450158388849545, 2.6459189107171786],
-    [5.409967276226449, 3.614824565816968, 2.6459189107171786],
-    [5.6841816659413995, 3.7980487632345756, 2.9209434762082527],
-    [5.863696239981064, 3.5145642723620756, 2.9209434762082527],
-    [5.409967276226449, 3.614824565816968, 2.6459189107171786],
-    [5.226079699167393, 3.875924863748691, 2.6459189107171786],
-    [5.490973400392971, 4.072383421966662, 2.9209434762082527],
-    [5.6841816659413995, 3.7980487632345756, 2.9209434762082527],
-    [5.226079699167393, 3.875924863748691, 2.6459189107171786],
-    [5.0296020383625235, 4.127687719192721, 2.6459189107171786],
-    [5.2845368989705275, 4.336907352336571, 2.9209434762082527],
-    [5.490973400392971, 4.072383421966662, 2.9209434762082527],
-    [5.0296020383625235, 4.127687719192721, 2.6459189107171786],
-    [4.821007625706131, 4.369506613378471, 2.6459189107171786],
-    [5.0653694852877, 4.59098329302648, 2.9209434762082527],
-    [5.2845368989705275, 4.336907352336571, 2.9209434762082527],
-    [4.821007625706131, 4.369506613378471, 2.6459189107171786],
-    [4.600798983409016, 4.600798983409016, 2.6459189107171786],
-    [4.833999152840847, 4.833999152840847, 2.9209434762082527],
-    [5.0653694852877, 4.59098329302648, 2.9209434762082527],
-    [4.600798983409016, 4.600798983409016, 2.6459189107171786],
-    [4.369506613378471, 4.82100762570613, 2.6459189107171786],
-    [4.59098329302648, 5.065369485287699, 2.9209434762082527],
-    [4.833999152840847, 4.833999152840847, 2.9209434762082527],
-    [4.369506613378471, 4.82100762570613, 2.6459189107171786],
-    [4.127687719192721, 5.029602038362523, 2.6459189107171786],
-    [4.336907352336571, 5.284536898970527, 2.9209434762082527],
-    [4.59098329302648, 5.065369485287699, 2.9209434762082527],
-    [4.127687719192721, 5.029602038362523, 2.6459189107171786],
-    [3.875924863748692, 5.226079699167393, 2.6459189107171786],
-    [4.072383421966663, 5.49097340039297, 2.9209434762082527],
-    [4.336907352336571, 5.284536898970527, 2.9209434762082527],
-    [3.875924863748692, 5.226079699167393, 2.6459189107171786],
-    [3.614824565816969, 5.409967276226449, 2.6459189107171786],
-    [3.7980487632345765, 5.6841816659413995, 2.9209434762082527],
-    [4.072383421966663, 5.49097340039297, 2.9209434762082527],
-    [3.614824565816969, 5.409967276226449, 2.6459189107171786],
-    [3.3450158388849545, 5.5808217682599075, 2.6459189107171786],
-    [3.5145642723620756, 5.863696239981064, 2.9209434762082527],
-    [3.7980487632345765, 5.6841816659413995, 2.9209434762082527],
-    [3.3450158388849545, 5.5808217682599075, 2.6459189107171786],
-    [3.067148675808285, 5.738231571831372, 2.6459189107171786],
-    [3.2226128883179843, 6.0290846561794815, 2.9209434762082527],
-    [3.5145642723620756, 5.863696239981064, 2.9209434762082527],
-    [3.067148675808285, 5.738231571831372, 2.6459189107171786],
-    [2.781892482921096, 5.881817472936572, 2.6459189107171786],
-    [2.922897947558381, 6.179948479355717, 2.9209434762082527],
-    [3.2226128883179843, 6.0290846561794815, 2.9209434762082527],
-    [2.781892482921096, 5.881817472936572, 2.6459189107171786],
-    [2.489934467377547, 6.011233560563102, 2.6459189107171786],
-    [2.6161414896274864, 6.315924265345629, 2.9209434762082527],
-    [2.922897947558381, 6.179948479355717, 2.9209434762082527],
-    [2.489934467377547, 6.011233560563102, 2.6459189107171786],
-    [2.191977981609584, 6.126168060020361, 2.6459189107171786],
-    [2.303082517701148, 6.436684436570693, 2.9209434762082527],
-    [2.6161414896274864, 6.315924265345629, 2.9209434762082527],
-    [2.191977981609584, 6.126168060020361, 2.6459189107171786],
-    [1.888740828889328, 6.226344084032083, 2.6459189107171786],
-    [1.984475218263464, 6.541938071201089, 2.9209434762082527],
-    [2.303082517701148, 6.436684436570693, 2.9209434762082527],
-    [1.888740828889328, 6.226344084032083, 2.6459189107171786],
-    [1.5809535340781207, 6.311520299782039, 2.6459189107171786],
-    [1.6610871442054844, 6.63143160401186, 2.9209434762082527],
-    [1.984475218263464, 6.541938071201089, 2.9209434762082527],
-    [1.5809535340781207, 6.311520299782039, 2.6459189107171786],
-    [1.269357583728143, 6.381491510305934, 2.6459189107171786],
-    [1.3336973657230624, 6.7049494372437595, 2.9209434762082527],
-    [1.6610871442054844, 6.63143160401186, 2.9209434762082527],
-    [1.269357583728143, 6.381491510305934, 2.6459189107171786],
-    [0.954703639776371, 6.436089148828861, 2.6459189107171786],
-    [1.0030945934685207, 6.762314459997129, 2.9209434762082527],
-    [1.3336973657230624, 6.7049494372437595, 2.9209434762082527],
-    [0.954703639776371, 6.436089148828861, 2.6459189107171786],
-    [0.6377497311342166, 6.475181684857429, 2.6459189107171786],
-    [0.6700752784776055, 6.803388474907573, 2.9209434762082527],
-    [1.0030945934685207, 6.762314459997129, 2.9209434762082527],
-    [0.6377497311342166, 6.475181684857429, 2.6459189107171786],
-    [0.31925942752947933, 6.498674941048245, 2.6459189107171786],
-    [0.33544169344917363, 6.828072531075515, 2.9209434762082527],
-    [0.6700752784776055, 6.803388474907573, 2.9209434762082527],
-    [0.31925942752947933, 6.498674941048245, 2.6459189107171786],
-    [
-        3.9840897432051376e-16,
-        6.506512320089378,
-        2.6459189107171786,
-    ],
-    [4.186030842239775e-16, 6.836307162447577, 2.9209434762082527],
-    [0.33544169344917363, 6.828072531075515, 2.9209434762082527],
-    [
-        3.9840897432051376e-16,
-        6.506512320089378,
-        2.6459189107171786,
-    ],
-    [-0.31925942752947856, 6.498674941048245, 2.6459189107171786],
-    [-0.3354416934491728, 6.828072531075515, 2.9209434762082527],
-    [4.186030842239775e-16, 6.836307162447577, 2.9209434762082527],
-    [-0.31925942752947856, 6.498674941048245, 2.6459189107171786],
-    [-0.6377497311342157, 6.4751816848574295, 2.6459189107171786],
-    [-0.6700752784776047, 6.803388474907574, 2.9209434762082527],
-    [-0.3354416934491728, 6.828072531075515, 2.9209434762082527],
-    [-0.6377497311342157, 6.4751816848574295, 2.6459189107171786],
-    [-0.9547036397763703, 6.436089148828861, 2.6459189107171786],
-    [-1.00309459346852, 6.762314459997129, 2.9209434762082527],
-    [-0.6700752784776047, 6.803388474907574, 2.9209434762082527],
-    [-0.9547036397763703, 6.436089148828861, 2.6459189107171786],
-    [-1.2693575837281421, 6.381491510305934, 2.6459189107171786],
-    [-1.3336973657230613, 6.7049494372437595, 2.9209434762082527],
-    [-1.00309459346852, 6.762314459997129, 2.9209434762082527],
-    [-1.2693575837281421, 6.381491510305934, 2.6459189107171786],
-    [-1.5809535340781198, 6.311520299782039, 2.6459189107171786],
-    [-1.6610871442054835, 6.63143160401186, 2.9209434762082527],
-    [-1.3336973657230613, 6.7049494372437595, 2.9209434762082527],
-    [-1.5809535340781198, 6.311520299782039, 2.6459189107171786],
-    [-1.8887408288893268, 6.226344084032083, 2.6459189107171786],
-    [-1.9844752182634628, 6.541938071201089, 2.9209434762082527],
-    [-1.6610871442054835, 6.63143160401186, 2.9209434762082527],
-    [-1.8887408288893268, 6.226344084032083, 2.6459189107171786],
-    [-2.1919779816095835, 6.126168060020361, 2.6459189107171786],
-    [-2.303082517701147, 6.436684436570693, 2.9209434762082527],
-    [-1.9844752182634628, 6.541938071201089, 2.9209434762082527],
-    [-2.1919779816095835, 6.126168060020361, 2.6459189107171786],
-    [-2.4899344673775463, 6.011233560563102, 2.6459189107171786],
-    [-2.616141489627486, 6.315924265345629, 2.9209434762082527],
-    [-2.303082517701147, 6.436684436570693, 2.9209434762082527],
-    [-2.4899344673775463, 6.011233560563102, 2.6459189107171786],
-    [-2.781892482921094, 5.881817472936572, 2.6459189107171786],
-    [-2.9228979475583787, 6.179948479355718, 2.9209434762082527],
-    [-2.616141489627486, 6.315924265345629, 2.9209434762082527],
-    [-2.781892482921094, 5.881817472936572, 2.6459189107171786],
-    [-3.067148675808284, 5.738231571831373, 2.6459189107171786],
-    [-3.2226128883179834, 6.029084656179482, 2.9209434762082527],
-    [-2.9228979475583787, 6.179948479355718, 2.9209434762082527],
-    [-3.067148675808284, 5.738231571831373, 2.6459189107171786],
-    [-3.345015838884954, 5.5808217682599075, 2.6459189107171786],
-    [-3.5145642723620747, 5.863696239981064, 2.9209434762082527],
-    [-3.2226128883179834, 6.029084656179482, 2.9209434762082527],
-    [-3.345015838884954, 5.5808217682599075, 2.6459189107171786],
-    [-3.6148245658169667, 5.40996727622645, 2.6459189107171786],
-    [-3.7980487632345743, 5.6841816659414, 2.9209434762082527],
-    [-3.5145642723620747, 5.863696239981064, 2.9209434762082527],
-    [-3.6148245658169667, 5.40996727622645, 2.6459189107171786],
-    [-3.875924863748691, 5.226079699167393, 2.6459189107171786],
-    [-4.072383421966662, 5.490973400392971, 2.9209434762082527],
-    [-3.7980487632345743, 5.6841816659414, 2.9209434762082527],
-    [-3.875924863748691, 5.226079699167393, 2.6459189107171786],
-    [-4.12768771919272, 5.029602038362524, 2.6459189107171786],
-    [-4.33690735233657, 5.284536898970528, 2.9209434762082527],
-    [-4.072383421966662, 5.490973400392971, 2.9209434762082527],
-    [-4.12768771919272, 5.029602038362524, 2.6459189107171786],
-    [-4.369506613378472, 4.821007625706129, 2.6459189107171786],
-    [-4.590983293026481, 5.0653694852876985, 2.9209434762082527],
-    [-4.33690735233657, 5.284536898970528, 2.9209434762082527],
-    [-4.369506613378472, 4.821007625706129, 2.6459189107171786],
-    [-4.600798983409015, 4.600798983409016, 2.6459189107171786],
-    [-4.833999152840846, 4.833999152840847, 2.9209434762082527],
-    [-4.590983293026481, 5.0653694852876985, 2.9209434762082527],
-    [-4.600798983409015, 4.600798983409016, 2.6459189107171786],
-    [-4.821007625706129, 4.369506613378473, 2.6459189107171786],
-    [-5.065369485287698, 4.590983293026482, 2.9209434762082527],
-    [-4.833999152840846, 4.833999152840847, 2.9209434762082527],
-    [-4.821007625706129, 4.369506613378473, 2.6459189107171786],
-    [-5.0296020383625235, 4.127687719192721, 2.6459189107171786],
-    [-5.2845368989705275, 4.336907352336571, 2.9209434762082527],
-    [-5.065369485287698, 4.590983293026482, 2.9209434762082527],
-    [-5.0296020383625235, 4.127687719192721, 2.6459189107171786],
-    [-5.226079699167393, 3.875924863748692, 2.6459189107171786],
-    [-5.49097340039297, 4.072383421966663, 2.9209434762082527],
-    [-5.2845368989705275, 4.336907352336571, 2.9209434762082527],
-    [-5.226079699167393, 3.875924863748692, 2.6459189107171786],
-    [-5.40996727622645, 3.614824565816968, 2.6459189107171786],
-    [-5.6841816659414, 3.7980487632345756, 2.9209434762082527],
-    [-5.49097340039297, 4.072383421966663, 2.9209434762082527],
-    [-5.40996727622645, 3.614824565816968, 2.6459189107171786],
-    [-5.580821768259907, 3.3450158388849554, 2.6459189107171786],
-    [-5.8636962399810635, 3.5145642723620765, 2.9209434762082527],
-    [-5.6841816659414, 3.7980487632345756, 2.9209434762082527],
-    [-5.580821768259907, 3.3450158388849554, 2.6459189107171786],
-    [-5.738231571831372, 3.067148675808285, 2.6459189107171786],
-    [-6.0290846561794815, 3.2226128883179843, 2.9209434762082527],
-    [-5.8636962399810635, 3.5145642723620765, 2.9209434762082527],
-    [-5.738231571831372, 3.067148675808285, 2.6459189107171786],
-    [-5.881817472936572, 2.781892482921095, 2.6459189107171786],
-    [-6.179948479355717, 2.92289794755838, 2.9209434762082527],
-    [-6.0290846561794815, 3.2226128883179843, 2.9209434762082527],
-    [-5.881817472936572, 2.781892482921095, 2.6459189107171786],
-    [-6.011233560563102, 2.4899344673775476, 2.6459189107171786],
-    [-6.315924265345629, 2.616141489627487, 2.9209434762082527],
-    [-6.179948479355717, 2.92289794755838, 2.9209434762082527],
-    [-6.011233560563102, 2.4899344673775476, 2.6459189107171786],
-    [-6.12616806002036, 2.1919779816095857, 2.6459189107171786],
-    [-6.436684436570692, 2.30308251770115, 2.9209434762082527],
-    [-6.315924265345629, 2.616141489627487, 2.9209434762082527],
-    [-6.12616806002036, 2.1919779816095857, 2.6459189107171786],
-    [-6.226344084032083, 1.8887408288893284, 2.6459189107171786],
-    [-6.541938071201088, 1.9844752182634644, 2.9209434762082527],
-    [-6.436684436570692, 2.30308251770115, 2.9209434762082527],
-    [-6.226344084032083, 1.8887408288893284, 2.6459189107171786],
-    [-6.311520299782039, 1.5809535340781211, 2.6459189107171786],
-    [-6.63143160401186, 1.6610871442054849, 2.9209434762082527],
-    [-6.541938071201088, 1.9844752182634644, 2.9209434762082527],
-    [-6.311520299782039, 1.5809535340781211, 2.6459189107171786],
-    [-6.381491510305934, 1.2693575837281448, 2.6459189107171786],
-    [-6.7049494372437595, 1.3336973657230642, 2.9209434762082527],
-    [-6.63143160401186, 1.6610871442054849, 2.9209434762082527],
-    [-6.381491510305934, 1.2693575837281448, 2.6459189107171786],
-    [-6.436089148828861, 0.9547036397763713, 2.6459189107171786],
-    [-6.762314459997129, 1.0030945934685211, 2.9209434762082527],
-    [-6.7049494372437595, 1.3336973657230642, 2.9209434762082527],
-    [-6.436089148828861, 0.9547036397763713, 2.6459189107171786],
-    [-6.475181684857429, 0.637749731134217, 2.6459189107171786],
-    [-6.803388474907573, 0.670075278477606, 2.9209434762082527],
-    [-6.762314459997129, 1.0030945934685211, 2.9209434762082527],
-    [-6.475181684857429, 0.637749731134217, 2.6459189107171786],
-    [-6.498674941048245, 0.3192594275294783, 2.6459189107171786],
-    [-6.828072531075515, 0.3354416934491725, 2.9209434762082527],
-    [-6.803388474907573, 0.670075278477606, 2.9209434762082527],
-    [-6.498674941048245, 0.3192594275294783, 2.6459189107171786],
-    [
-        -6.506512320089378,
-        7.968179486410275e-16,
-        2.6459189107171786,
-    ],
-    [-6.836307162447577, 8.37206168447955e-16, 2.9209434762082527],
-    [-6.828072531075515, 0.3354416934491725, 2.9209434762082527],
-    [
-        -6.506512320089378,
-        7.968179486410275e-16,
-        2.6459189107171786,
-    ],
-    [-6.498674941048245, -0.3192594275294767, 2.6459189107171786],
-    [-6.828072531075515, -0.33544169344917085, 2.9209434762082527],
-    [-6.836307162447577, 8.37206168447955e-16, 2.9209434762082527],
-    [-6.498674941048245, -0.3192594275294767, 2.6459189107171786],
-    [-6.4751816848574295, -0.6377497311342154, 2.6459189107171786],
-    [-6.803388474907574, -0.6700752784776043, 2.9209434762082527],
-    [-6.828072531075515, -0.33544169344917085, 2.9209434762082527],
-    [-6.4751816848574295, -0.6377497311342154, 2.6459189107171786],
-    [-6.436089148828861, -0.9547036397763699, 2.6459189107171786],
-    [-6.762314459997129, -1.0030945934685196, 2.9209434762082527],
-    [-6.803388474907574, -0.6700752784776043, 2.9209434762082527],
-    [-6.436089148828861, -0.9547036397763699, 2.6459189107171786],
-    [-6.381491510305934, -1.2693575837281432, 2.6459189107171786],
-    [-6.7049494372437595, -1.3336973657230624, 2.9209434762082527],
-    [-6.762314459997129, -1.0030945934685196, 2.9209434762082527],
-    [-6.381491510305934, -1.2693575837281432, 2.6459189107171786],
-    [-6.311520299782039, -1.5809535340781196, 2.6459189107171786],
-    [-6.63143160401186, -1.661087144205483, 2.9209434762082527],
-    [-6.7049494372437595, -1.3336973657230624, 2.9209434762082527],
-    [-6.311520299782039, -1.5809535340781196, 2.6459189107171786],
-    [-6.226344084032083, -1.8887408288893266, 2.6459189107171786],
-    [-6.541938071201089, -1.9844752182634626, 2.9209434762082527],
-    [-6.63143160401186, -1.661087144205483, 2.9209434762082527],
-    [-6.226344084032083, -1.8887408288893266, 2.6459189107171786],
-    [-6.126168060020361, -2.1919779816095843, 2.6459189107171786],
-    [-6.436684436570693, -2.3030825177011485, 2.9209434762082527],
-    [-6.541938071201089, -1.9844752182634626, 2.9209434762082527],
-    [-6.126168060020361, -2.1919779816095843, 2.6459189107171786],
-    [-6.011233560563103, -2.4899344673775463, 2.6459189107171786],
-    [-6.315924265345629, -2.6161414896274855, 2.9209434762082527],
-    [-6.436684436570693, -2.3030825177011485, 2.9209434762082527],
-    [-6.011233560563103, -2.4899344673775463, 2.6459189107171786],
-    [-5.881817472936572, -2.781892482921094, 2.6459189107171786],
-    [-6.179948479355718, -2.9228979475583783, 2.9209434762082527],
-    [-6.315924265345629, -2.6161414896274855, 2.9209434762082527],
-    [-5.881817472936572, -2.781892482921094, 2.6459189107171786],
-    [-5.738231571831373, -3.067148675808284, 2.6459189107171786],
-    [-6.029084656179482, -3.222612888317983, 2.9209434762082527],
-    [-6.179948479355718, -2.9228979475583783, 2.9209434762082527],
-    [-5.738231571831373, -3.067148675808284, 2.6459189107171786],
-    [-5.5808217682599075, -3.345015838884954, 2.6459189107171786],
-    [-5.863696239981064, -3.5145642723620747, 2.9209434762082527],
-    [-6.029084656179482, -3.222612888317983, 2.9209434762082527],
-    [-5.5808217682599075, -3.345015838884954, 2.6459189107171786],
-    [-5.409967276226451, -3.6148245658169667, 2.6459189107171786],
-    [-5.684181665941401, -3.7980487632345743, 2.9209434762082527],
-    [-5.863696239981064, -3.5145642723620747, 2.9209434762082527],
-    [-5.409967276226451, -3.6148245658169667, 2.6459189107171786],
-    [-5.226079699167393, -3.8759248637486907, 2.6459189107171786],
-    [-5.490973400392971, -4.072383421966661, 2.9209434762082527],
-    [-5.684181665941401, -3.7980487632345743, 2.9209434762082527],
-    [-5.226079699167393, -3.8759248637486907, 2.6459189107171786],
-    [-5.029602038362524, -4.1276877191927195, 2.6459189107171786],
-    [-5.284536898970528, -4.336907352336569, 2.9209434762082527],
-    [-5.490973400392971, -4.072383421966661, 2.9209434762082527],
-    [-5.029602038362524, -4.1276877191927195, 2.6459189107171786],
-    [-4.82100762570613, -4.369506613378472, 2.6459189107171786],
-    [-5.065369485287699, -4.590983293026481, 2.9209434762082527],
-    [-5.284536898970528, -4.336907352336569, 2.9209434762082527],
-    [-4.82100762570613, -4.369506613378472, 2.6459189107171786],
-    [-4.600798983409017, -4.600798983409015, 2.6459189107171786],
-    [-4.833999152840847, -4.833999152840846, 2.9209434762082527],
-    [-5.065369485287699, -4.590983293026481, 2.9209434762082527],
-    [-4.600798983409017, -4.600798983409015, 2.6459189107171786],
-    [-4.369506613378473, -4.821007625706129, 2.6459189107171786],
-    [-4.590983293026483, -5.065369485287698, 2.9209434762082527],
-    [-4.833999152840847, -4.833999152840846, 2.9209434762082527],
-    [-4.369506613378473, -4.821007625706129, 2.6459189107171786],
-    [-4.127687719192724, -5.029602038362522, 2.6459189107171786],
-    [-4.336907352336573, -5.284536898970525, 2.9209434762082527],
-    [-4.590983293026483, -5.065369485287698, 2.9209434762082527],
-    [-4.127687719192724, -5.029602038362522, 2.6459189107171786],
-    [-3.87592486374869, -5.226079699167394, 2.6459189107171786],
-    [-4.072383421966661, -5.4909734003929715, 2.9209434762082527],
-    [-4.336907352336573, -5.284536898970525, 2.9209434762082527],
-    [-3.87592486374869, -5.226079699167394, 2.6459189107171786],
-    [-3.614824565816968, -5.409967276226449, 2.6459189107171786],
-    [-3.7980487632345756, -5.6841816659413995, 2.9209434762082527],
-    [-4.072383421966661, -5.4909734003929715, 2.9209434762082527],
-    [-3.614824565816968, -5.409967276226449, 2.6459189107171786],
-    [-3.3450158388849554, -5.580821768259907, 2.6459189107171786],
-    [-3.5145642723620765, -5.8636962399810635, 2.9209434762082527],
-    [-3.7980487632345756, -5.6841816659413995, 2.9209434762082527],
-    [-3.3450158388849554, -5.580821768259907, 2.6459189107171786],
-    [-3.0671486758082853, -5.738231571831372, 2.6459189107171786],
-    [-3.2226128883179848, -6.0290846561794815, 2.9209434762082527],
-    [-3.5145642723620765, -5.8636962399810635, 2.9209434762082527],
-    [-3.0671486758082853, -5.738231571831372, 2.6459189107171786],
-    [-2.781892482921098, -5.881817472936571, 2.6459189107171786],
-    [-2.922897947558383, -6.1799484793557165, 2.9209434762082527],
-    [-3.2226128883179848, -6.0290846561794815, 2.9209434762082527],
-    [-2.781892482921098, -5.881817472936571, 2.6459189107171786],
-    [-2.4899344673775503, -6.011233560563101, 2.6459189107171786],
-    [-2.61614148962749, -6.315924265345627, 2.9209434762082527],
-    [-2.922897947558383, -6.1799484793557165, 2.9209434762082527],
-    [-2.4899344673775503, -6.011233560563101, 2.6459189107171786],
-    [-2.1919779816095835, -6.126168060020361, 2.6459189107171786],
-    [-2.303082517701147, -6.436684436570693, 2.9209434762082527],
-    [-2.61614148962749, -6.315924265345627, 2.9209434762082527],
-    [-2.1919779816095835, -6.126168060020361, 2.6459189107171786],
-    [-1.8887408288893288, -6.226344084032083, 2.6459189107171786],
-    [-1.9844752182634648, -6.541938071201088, 2.9209434762082527],
-    [-2.303082517701147, -6.436684436570693, 2.9209434762082527],
-    [-1.8887408288893288, -6.226344084032083, 2.6459189107171786],
-    [-1.5809535340781216, -6.311520299782039, 2.6459189107171786],
-    [-1.6610871442054853, -6.63143160401186, 2.9209434762082527],
-    [-1.9844752182634648, -6.541938071201088, 2.9209434762082527],
-    [-1.5809535340781216, -6.311520299782039, 2.6459189107171786],
-    [-1.2693575837281452, -6.381491510305933, 2.6459189107171786],
-    [-1.3336973657230646, -6.704949437243759, 2.9209434762082527],
-    [-1.6610871442054853, -6.63143160401186, 2.9209434762082527],
-    [-1.2693575837281452, -6.381491510305933, 2.6459189107171786],
-    [-0.9547036397763746, -6.43608914882886, 2.6459189107171786],
-    [-1.0030945934685245, -6.762314459997128, 2.9209434762082527],
-    [-1.3336973657230646, -6.704949437243759, 2.9209434762082527],
-    [-0.9547036397763746, -6.43608914882886, 2.6459189107171786],
-    [-0.6377497311342145, -6.4751816848574295, 2.6459189107171786],
-    [-0.6700752784776034, -6.803388474907574, 2.9209434762082527],
-    [-1.0030945934685245, -6.762314459997128, 2.9209434762082527],
-    [-0.6377497311342145, -6.4751816848574295, 2.6459189107171786],
-    [-0.31925942752947867, -6.498674941048245, 2.6459189107171786],
-    [-0.33544169344917296, -6.828072531075515, 2.9209434762082527],
-    [-0.6700752784776034, -6.803388474907574, 2.9209434762082527],
-    [-0.31925942752947867, -6.498674941048245, 2.6459189107171786],
-    [
-        -1.1952269229615413e-15,
-        -6.506512320089378,
-        2.6459189107171786,
-    ],
-    [
-        -1.2558092526719324e-15,
-        -6.836307162447577,
-        2.9209434762082527,
-    ],
-    [-0.33544169344917296, -6.828072531075515, 2.9209434762082527],
-    [
-        -1.1952269229615413e-15,
-        -6.506512320089378,
-        2.6459189107171786,
-    ],
-    [0.3192594275294763, -6.498674941048245, 2.6459189107171786],
-    [0.33544169344917046, -6.828072531075515, 2.9209434762082527],
-    [
-        -1.2558092526719324e-15,
-        -6.836307162447577,
-        2.9209434762082527,
-    ],
-    [0.3192594275294763, -6.498674941048245, 2.6459189107171786],
-    [0.6377497311342122, -6.4751816848574295, 2.6459189107171786],
-    [0.6700752784776008, -6.803388474907574, 2.9209434762082527],
-    [0.33544169344917046, -6.828072531075515, 2.9209434762082527],
-    [0.6377497311342122, -6.4751816848574295, 2.6459189107171786],
-    [0.9547036397763723, -6.43608914882886, 2.6459189107171786],
-    [1.003094593468522, -6.762314459997128, 2.9209434762082527],
-    [0.6700752784776008, -6.803388474907574, 2.9209434762082527],
-    [0.9547036397763723, -6.43608914882886, 2.6459189107171786],
-    [1.2693575837281428, -6.381491510305934, 2.6459189107171786],
-    [1.3336973657230622, -6.7049494372437595, 2.9209434762082527],
-    [1.003094593468522, -6.762314459997128, 2.9209434762082527],
-    [1.2693575837281428, -6.381491510305934, 2.6459189107171786],
-    [1.5809535340781191, -6.311520299782039, 2.6459189107171786],
-    [1.6610871442054829, -6.63143160401186, 2.9209434762082527],
-    [1.3336973657230622, -6.7049494372437595, 2.9209434762082527],
-    [1.5809535340781191, -6.311520299782039, 2.6459189107171786],
-    [1.8887408288893262, -6.226344084032083, 2.6459189107171786],
-    [1.9844752182634622, -6.541938071201089, 2.9209434762082527],
-    [1.6610871442054829, -6.63143160401186, 2.9209434762082527],
-    [1.8887408288893262, -6.226344084032083, 2.6459189107171786],
-    [2.1919779816095812, -6.126168060020362, 2.6459189107171786],
-    [2.303082517701145, -6.436684436570694, 2.9209434762082527],
-    [1.9844752182634622, -6.541938071201089, 2.9209434762082527],
-    [2.1919779816095812, -6.126168060020362, 2.6459189107171786],
-    [2.4899344673775485, -6.0112335605631015, 2.6459189107171786],
-    [2.6161414896274877, -6.315924265345628, 2.9209434762082527],
-    [2.303082517701145, -6.436684436570694, 2.9209434762082527],
-    [2.4899344673775485, -6.0112335605631015, 2.6459189107171786],
-    [2.781892482921096, -5.881817472936572, 2.6459189107171786],
-    [2.9228979475583805, -6.179948479355717, 2.9209434762082527],
-    [2.6161414896274877, -6.315924265345628, 2.9209434762082527],
-    [2.781892482921096, -5.881817472936572, 2.6459189107171786],
-    [3.0671486758082835, -5.738231571831373, 2.6459189107171786],
-    [3.2226128883179825, -6.029084656179482, 2.9209434762082527],
-    [2.9228979475583805, -6.179948479355717, 2.9209434762082527],
-    [3.0671486758082835, -5.738231571831373, 2.6459189107171786],
-    [3.345015838884954, -5.580821768259908, 2.6459189107171786],
-    [3.5145642723620747, -5.863696239981065, 2.9209434762082527],
-    [3.2226128883179825, -6.029084656179482, 2.9209434762082527],
-    [3.345015838884954, -5.580821768259908, 2.6459189107171786],
-    [3.6148245658169658, -5.409967276226451, 2.6459189107171786],
-    [3.7980487632345734, -5.684181665941401, 2.9209434762082527],
-    [3.5145642723620747, -5.863696239981065, 2.9209434762082527],
-    [3.6148245658169658, -5.409967276226451, 2.6459189107171786],
-    [3.8759248637486876, -5.2260796991673955, 2.6459189107171786],
-    [4.072383421966658, -5.490973400392972, 2.9209434762082527],
-    [3.7980487632345734, -5.684181665941401, 2.9209434762082527],
-    [3.8759248637486876, -5.2260796991673955, 2.6459189107171786],
-    [4.127687719192722, -5.029602038362523, 2.6459189107171786],
-    [4.3369073523365715, -5.284536898970527, 2.9209434762082527],
-    [4.072383421966658, -5.490973400392972, 2.9209434762082527],
-    [4.127687719192722, -5.029602038362523, 2.6459189107171786],
-    [4.369506613378471, -4.82100762570613, 2.6459189107171786],
-    [4.59098329302648, -5.065369485287699, 2.9209434762082527],
-    [4.3369073523365715, -5.284536898970527, 2.9209434762082527],
-    [4.369506613378471, -4.82100762570613, 2.6459189107171786],
-    [4.600798983409014, -4.600798983409017, 2.6459189107171786],
-    [4.833999152840845, -4.833999152840847, 2.9209434762082527],
-    [4.59098329302648, -5.065369485287699, 2.9209434762082527],
-    [4.600798983409014, -4.600798983409017, 2.6459189107171786],
-    [4.821007625706129, -4.369506613378473, 2.6459189107171786],
-    [5.065369485287698, -4.590983293026483, 2.9209434762082527],
-    [4.833999152840845, -4.833999152840847, 2.9209434762082527],
-    [4.821007625706129, -4.369506613378473, 2.6459189107171786],
-    [5.029602038362521, -4.127687719192724, 2.6459189107171786],
-    [5.284536898970525, -4.336907352336573, 2.9209434762082527],
-    [5.065369485287698, -4.590983293026483, 2.9209434762082527],
-    [5.029602038362521, -4.127687719192724, 2.6459189107171786],
-    [5.226079699167394, -3.8759248637486907, 2.6459189107171786],
-    [5.4909734003929715, -4.072383421966661, 2.9209434762082527],
-    [5.284536898970525, -4.336907352336573, 2.9209434762082527],
-    [5.226079699167394, -3.8759248637486907, 2.6459189107171786],
-    [5.409967276226449, -3.614824565816968, 2.6459189107171786],
-    [5.6841816659413995, -3.7980487632345756, 2.9209434762082527],
-    [5.4909734003929715, -4.072383421966661, 2.9209434762082527],
-    [5.409967276226449, -3.614824565816968, 2.6459189107171786],
-    [5.580821768259907, -3.3450158388849562, 2.6459189107171786],
-    [5.8636962399810635, -3.5145642723620774, 2.9209434762082527],
-    [5.6841816659413995, -3.7980487632345756, 2.9209434762082527],
-    [5.580821768259907, -3.3450158388849562, 2.6459189107171786],
-    [5.7382315718313714, -3.0671486758082858, 2.6459189107171786],
-    [6.029084656179481, -3.222612888317985, 2.9209434762082527],
-    [5.8636962399810635, -3.5145642723620774, 2.9209434762082527],
-    [5.7382315718313714, -3.0671486758082858, 2.6459189107171786],
-    [5.881817472936571, -2.7818924829210983, 2.6459189107171786],
-    [6.1799484793557165, -2.922897947558383, 2.9209434762082527],
-    [6.029084656179481, -3.222612888317985, 2.9209434762082527],
-    [5.881817472936571, -2.7818924829210983, 2.6459189107171786],
-    [6.011233560563101, -2.4899344673775508, 2.6459189107171786],
-    [6.315924265345627, -2.6161414896274904, 2.9209434762082527],
-    [6.1799484793557165, -2.922897947558383, 2.9209434762082527],
-    [6.011233560563101, -2.4899344673775508, 2.6459189107171786],
-    [6.126168060020361, -2.191977981609584, 2.6459189107171786],
-    [6.436684436570693, -2.3030825177011476, 2.9209434762082527],
-    [6.315924265345627, -2.6161414896274904, 2.9209434762082527],
-    [6.126168060020361, -2.191977981609584, 2.6459189107171786],
-    [6.226344084032083, -1.888740828889329, 2.6459189107171786],
-    [6.541938071201088, -1.984475218263465, 2.9209434762082527],
-    [6.436684436570693, -2.3030825177011476, 2.9209434762082527],
-    [6.226344084032083, -1.888740828889329, 2.6459189107171786],
-    [6.311520299782039, -1.5809535340781218, 2.6459189107171786],
-    [6.63143160401186, -1.6610871442054858, 2.9209434762082527],
-    [6.541938071201088, -1.984475218263465, 2.9209434762082527],
-    [6.311520299782039, -1.5809535340781218, 2.6459189107171786],
-    [6.381491510305933, -1.2693575837281454, 2.6459189107171786],
-    [6.704949437243759, -1.3336973657230649, 2.9209434762082527],
-    [6.63143160401186, -1.6610871442054858, 2.9209434762082527],
-    [6.381491510305933, -1.2693575837281454, 2.6459189107171786],
-    [6.43608914882886, -0.9547036397763752, 2.6459189107171786],
-    [6.762314459997128, -1.0030945934685251, 2.9209434762082527],
-    [6.704949437243759, -1.3336973657230649, 2.9209434762082527],
-    [6.43608914882886, -0.9547036397763752, 2.6459189107171786],
-    [6.4751816848574295, -0.6377497311342148, 2.6459189107171786],
-    [6.803388474907574, -0.6700752784776037, 2.9209434762082527],
-    [6.762314459997128, -1.0030945934685251, 2.9209434762082527],
-    [6.4751816848574295, -0.6377497311342148, 2.6459189107171786],
-    [6.498674941048245, -0.3192594275294791, 2.6459189107171786],
-    [6.828072531075515, -0.3354416934491734, 2.9209434762082527],
-    [6.803388474907574, -0.6700752784776037, 2.9209434762082527],
-    [6.498674941048245, -0.3192594275294791, 2.6459189107171786],
-    [
-        6.506512320089378,
-        -1.593635897282055e-15,
-        2.6459189107171786,
-    ],
-    [6.836307162447577, -1.67441233689591e-15, 2.9209434762082527],
-    [6.828072531075515, -0.3354416934491734, 2.9209434762082527],
-    [6.836307162447577, 0.0, 2.9209434762082527],
-    [6.828072531075515, 0.33544169344917285, 2.9209434762082527],
-    [7.157470121102785, 0.3516239593688671, 3.209563621455084],
-    [7.166102004805775, 0.0, 3.209563621455084],
-    [6.828072531075515, 0.33544169344917285, 2.9209434762082527],
-    [6.803388474907574, 0.6700752784776044, 2.9209434762082527],
-    [7.131595264957718, 0.7024008258209932, 3.209563621455084],
-    [7.157470121102785, 0.3516239593688671, 3.209563621455084],
-    [6.803388474907574, 0.6700752784776044, 2.9209434762082527],
-    [6.762314459997129, 1.0030945934685207, 2.9209434762082527],
-    [7.088539771165396, 1.0514855471606703, 3.209563621455084],
-    [7.131595264957718, 0.7024008258209932, 3.209563621455084],
-    [6.762314459997129, 1.0030945934685207, 2.9209434762082527],
-    [6.7049494372437595, 1.3336973657230617, 2.9209434762082527],
-    [7.028407364181584, 1.398037147717981, 3.209563621455084],
-    [7.088539771165396, 1.0514855471606703, 3.209563621455084],
-    [6.7049494372437595, 1.3336973657230617, 2.9209434762082527],
-    [6.63143160401186, 1.6610871442054835, 2.9209434762082527],
-    [6.9513429082416796, 1.741220754332847, 3.209563621455084],
-    [7.028407364181584, 1.398037147717981, 3.209563621455084],
-    [6.63143160401186, 1.6610871442054835, 2.9209434762082527],
-    [6.541938071201088, 1.984475218263464, 2.9209434762082527],
-    [6.857532058370093, 2.0802096076375998, 3.209563621455084],
-    [6.9513429082416796, 1.741220754332847, 3.209563621455084],
-    [6.541938071201088, 1.984475218263464, 2.9209434762082527],
-    [6.436684436570693, 2.303082517701148, 2.9209434762082527],
-    [6.747200813121024, 2.4141870537927117, 3.209563621455084],
-    [6.857532058370093, 2.0802096076375998, 3.209563621455084],
-    [6.436684436570693, 2.303082517701148, 2.9209434762082527],
-    [6.315924265345629, 2.616141489627486, 2.9209434762082527],
-    [6.620614970128154, 2.742348511877425, 3.209563621455084],
-    [6.747200813121024, 2.4141870537927117, 3.209563621455084],
-    [6.315924265345629, 2.616141489627486, 2.9209434762082527],
-    [6.179948479355717, 2.9228979475583805, 2.9209434762082527],
-    [6.478079485774862, 3.063903412195665, 3.209563621455084],
-    [6.620614970128154, 2.742348511877425, 3.209563621455084],
-    [6.179948479355717, 2.9228979475583805, 2.9209434762082527],
-    [6.029084656179482, 3.222612888317983, 2.9209434762082527],
-    [6.319937740527591, 3.378077100827682, 3.209563621455084],
-    [6.478079485774862, 3.063903412195665, 3.209563621455084],
-    [6.029084656179482, 3.222612888317983, 2.9209434762082527],
-    [5.863696239981064, 3.5145642723620756, 2.9209434762082527],
-    [6.14657071170222, 3.6841127058391963, 3.209563621455084],
-    [6.319937740527591, 3.378077100827682, 3.209563621455084],
-    [5.863696239981064, 3.5145642723620756, 2.9209434762082527],
-    [5.6841816659413995, 3.7980487632345756, 2.9209434762082527],
-    [5.95839605565635, 3.981272960652183, 3.209563621455084],
-    [6.14657071170222, 3.6841127058391963, 3.209563621455084],
-    [5.6841816659413995, 3.7980487632345756, 2.9209434762082527],
-    [5.490973400392971, 4.072383421966662, 2.9209434762082527],
-    [5.755867101618548, 4.268841980184632, 3.209563621455084],
-    [5.95839605565635, 3.981272960652183, 3.209563621455084],
-    [5.490973400392971, 4.072383421966662, 2.9209434762082527],
-    [5.2845368989705275, 4.336907352336571, 2.9209434762082527],
-    [5.5394717595785306, 4.546126985480419, 3.209563621455084],
-    [5.755867101618548, 4.268841980184632, 3.209563621455084],
-    [5.2845368989705275, 4.336907352336571, 2.9209434762082527],
-    [5.0653694852877, 4.59098329302648, 2.9209434762082527],
-    [5.3097313448692685, 4.812459972674489, 3.209563621455084],
-    [5.5394717595785306, 4.546126985480419, 3.209563621455084],
-    [5.0653694852877, 4.59098329302648, 2.9209434762082527],
-    [4.833999152840847, 4.833999152840847, 2.9209434762082527],
-    [5.0671993222726766, 5.0671993222726766, 3.209563621455084],
-    [5.3097313448692685, 4.812459972674489, 3.209563621455084],
-    [4.833999152840847, 4.833999152840847, 2.9209434762082527],
-    [4.59098329302648, 5.065369485287699, 2.9209434762082527],
-    [4.812459972674489, 5.309731344869268, 3.209563621455084],
-    [5.0671993222726766, 5.0671993222726766, 3.209563621455084],
-    [4.59098329302648, 5.065369485287699, 2.9209434762082527],
-    [4.336907352336571, 5.284536898970527, 2.9209434762082527],
-    [4.546126985480419, 5.53947175957853, 3.209563621455084],
-    [4.812459972674489, 5.309731344869268, 3.209563621455084],
-    [4.336907352336571, 5.284536898970527, 2.9209434762082527],
-    [4.072383421966663, 5.49097340039297, 2.9209434762082527],
-    [4.268841980184633, 5.755867101618547, 3.209563621455084],
-    [4.546126985480419, 5.53947175957853, 3.209563621455084],
-    [4.072383421966663, 5.49097340039297, 2.9209434762082527],
-    [3.7980487632345765, 5.6841816659413995, 2.9209434762082527],
-    [3.9812729606521833, 5.95839605565635, 3.209563621455084],
-    [4.268841980184633, 5.755867101618547, 3.209563621455084],
-    [3.7980487632345765, 5.6841816659413995, 2.9209434762082527],
-    [3.5145642723620756, 5.863696239981064, 2.9209434762082527],
-    [3.6841127058391963, 6.14657071170222, 3.209563621455084],
-    [3.9812729606521833, 5.95839605565635, 3.209563621455084],
-    [3.5145642723620756, 5.863696239981064, 2.9209434762082527],
-    [3.2226128883179843, 6.0290846561794815, 2.9209434762082527],
-    [3.3780771008276833, 6.31993774052759, 3.209563621455084],
-    [3.6841127058391963, 6.14657071170222, 3.209563621455084],
-    [3.2226128883179843, 6.0290846561794815, 2.9209434762082527],
-    [2.922897947558381, 6.179948479355717, 2.9209434762082527],
-    [3.0639034121956654, 6.478079485774862, 3.209563621455084],
-    [3.3780771008276833, 6.31993774052759, 3.209563621455084],
-    [2.922897947558381, 6.179948479355717, 2.9209434762082527],
-    [2.6161414896274864, 6.315924265345629, 2.9209434762082527],
-    [2.7423485118774256, 6.620614970128154, 3.209563621455084],
-    [3.0639034121956654, 6.478079485774862, 3.209563621455084],
-    [2.6161414896274864, 6.315924265345629, 2.9209434762082527],
-    [2.303082517701148, 6.436684436570693, 2.9209434762082527],
-    [2.4141870537927117, 6.747200813121024, 3.209563621455084],
-    [2.7423485118774256, 6.620614970128154, 3.209563621455084],
-    [2.303082517701148, 6.436684436570693, 2.9209434762082527],
-    [1.984475218263464, 6.541938071201089, 2.9209434762082527],
-    [2.0802096076375998, 6.857532058370094, 3.209563621455084],
-    [2.4141870537927117, 6.747200813121024, 3.209563621455084],
-    [1.984475218263464, 6.541938071201089, 2.9209434762082527],
-    [1.6610871442054844, 6.63143160401186, 2.9209434762082527],
-    [1.741220754332848, 6.9513429082416796, 3.209563621455084],
-    [2.0802096076375998, 6.857532058370094, 3.209563621455084],
-    [1.6610871442054844, 6.63143160401186, 2.9209434762082527],
-    [1.3336973657230624, 6.7049494372437595, 2.9209434762082527],
-    [1.3980371477179814, 7.028407364181584, 3.209563621455084],
-    [1.741220754332848, 6.9513429082416796, 3.209563621455084],
-    [1.3336973657230624, 6.7049494372437595, 2.9209434762082527],
-    [1.0030945934685207, 6.762314459997129, 2.9209434762082527],
-    [1.0514855471606703, 7.088539771165396, 3.209563621455084],
-    [1.3980371477179814, 7.028407364181584, 3.209563621455084],
-    [1.0030945934685207, 6.762314459997129, 2.9209434762082527],
-    [0.6700752784776055, 6.803388474907573, 2.9209434762082527],
-    [0.7024008258209944, 7.131595264957717, 3.209563621455084],
-    [1.0514855471606703, 7.088539771165396, 3.209563621455084],
-    [0.6700752784776055, 6.803388474907573, 2.9209434762082527],
-    [0.33544169344917363, 6.828072531075515, 2.9209434762082527],
-    [0.35162395936886787, 7.157470121102785, 3.209563621455084],
-    [0.7024008258209944, 7.131595264957717, 3.209563621455084],
-    [0.33544169344917363, 6.828072531075515, 2.9209434762082527],
-    [4.186030842239775e-16, 6.836307162447577, 2.9209434762082527],
-    [4.3879719412744116e-16, 7.166102004805775, 3.209563621455084],
-    [0.35162395936886787, 7.157470121102785, 3.209563621455084],
-    [4.186030842239775e-16, 6.836307162447577, 2.9209434762082527],
-    [-0.3354416934491728, 6.828072531075515, 2.9209434762082527],
-    [-0.35162395936886703, 7.157470121102785, 3.209563621455084],
-    [4.3879719412744116e-16, 7.166102004805775, 3.209563621455084],
-    [-0.3354416934491728, 6.828072531075515, 2.9209434762082527],
-    [-0.6700752784776047, 6.803388474907574, 2.9209434762082527],
-    [-0.7024008258209935, 7.131595264957718, 3.209563621455084],
-    [-0.35162395936886703, 7.157470121102785, 3.209563621455084],
-    [-0.6700752784776047, 6.803388474907574, 2.9209434762082527],
-    [-1.00309459346852, 6.762314459997129, 2.9209434762082527],
-    [-1.0514855471606697, 7.088539771165396, 3.209563621455084],
-    [-0.7024008258209935, 7.131595264957718, 3.209563621455084],
-    [-1.00309459346852, 6.762314459997129, 2.9209434762082527],
-    [-1.3336973657230613, 6.7049494372437595, 2.9209434762082527],
-    [-1.3980371477179805, 7.028407364181584, 3.209563621455084],
-    [-1.0514855471606697, 7.088539771165396, 3.209563621455084],
-    [-1.3336973657230613, 6.7049494372437595, 2.9209434762082527],
-    [-1.6610871442054835, 6.63143160401186, 2.9209434762082527],
-    [-1.741220754332847, 6.9513429082416796, 3.209563621455084],
-    [-1.3980371477179805, 7.028407364181584, 3.209563621455084],
-    [-1.6610871442054835, 6.63143160401186, 2.9209434762082527],
-    [-1.9844752182634628, 6.541938071201089, 2.9209434762082527],
-    [-2.0802096076375984, 6.857532058370094, 3.209563621455084],
-    [-1.741220754332847, 6.9513429082416796, 3.209563621455084],
-    [-1.9844752182634628, 6.541938071201089, 2.9209434762082527],
-    [-2.303082517701147, 6.436684436570693, 2.9209434762082527],
-    [-2.414187053792711, 6.747200813121024, 3.209563621455084],
-    [-2.0802096076375984, 6.857532058370094, 3.209563621455084],
-    [-2.303082517701147, 6.436684436570693, 2.9209434762082527],
-    [-2.616141489627486, 6.315924265345629, 2.9209434762082527],
-    [-2.7423485118774247, 6.620614970128154, 3.209563621455084],
-    [-2.414187053792711, 6.747200813121024, 3.209563621455084],
-    [-2.616141489627486, 6.315924265345629, 2.9209434762082527],
-    [-2.9228979475583787, 6.179948479355718, 2.9209434762082527],
-    [-3.063903412195663, 6.478079485774863, 3.209563621455084],
-    [-2.7423485118774247, 6.620614970128154, 3.209563621455084],
-    [-2.9228979475583787, 6.179948479355718, 2.9209434762082527],
-    [-3.2226128883179834, 6.029084656179482, 2.9209434762082527],
-    [-3.3780771008276824, 6.319937740527591, 3.209563621455084],
-    [-3.063903412195663, 6.478079485774863, 3.209563621455084],
-    [-3.2226128883179834, 6.029084656179482, 2.9209434762082527],
-    [-3.5145642723620747, 5.863696239981064, 2.9209434762082527],
-    [-3.6841127058391954, 6.14657071170222, 3.209563621455084],
-    [-3.3780771008276824, 6.319937740527591, 3.209563621455084],
-    [-3.5145642723620747, 5.863696239981064, 2.9209434762082527],
-    [-3.7980487632345743, 5.6841816659414, 2.9209434762082527],
-    [-3.981272960652181, 5.95839605565635, 3.209563621455084],
-    [-3.6841127058391954, 6.14657071170222, 3.209563621455084],
-    [-3.7980487632345743, 5.6841816659414, 2.9209434762082527],
-    [-4.072383421966662, 5.490973400392971, 2.9209434762082527],
-    [-4.268841980184632, 5.755867101618548, 3.209563621455084],
-    [-3.981272960652181, 5.95839605565635, 3.209563621455084],
-    [-4.072383421966662, 5.490973400392971, 2.9209434762082527],
-    [-4.33690735233657, 5.284536898970528, 2.9209434762082527],
-    [-4.546126985480419, 5.539471759578531, 3.209563621455084],
-    [-4.268841980184632, 5.755867101618548, 3.209563621455084],
-    [-4.33690735233657, 5.284536898970528, 2.9209434762082527],
-    [-4.590983293026481, 5.0653694852876985, 2.9209434762082527],
-    [-4.81245997267449, 5.309731344869267, 3.209563621455084],
-    [-4.546126985480419, 5.539471759578531, 3.209563621455084],
-    [-4.590983293026481, 5.0653694852876985, 2.9209434762082527],
-    [-4.833999152840846, 4.833999152840847, 2.9209434762082527],
-    [-5.0671993222726766, 5.0671993222726766, 3.209563621455084],
-    [-4.81245997267449, 5.309731344869267, 3.209563621455084],
-    [-4.833999152840846, 4.833999152840847, 2.9209434762082527],
-    [-5.065369485287698, 4.590983293026482, 2.9209434762082527],
-    [-5.309731344869266, 4.812459972674491, 3.209563621455084],
-    [-5.0671993222726766, 5.0671993222726766, 3.209563621455084],
-    [-5.065369485287698, 4.590983293026482, 2.9209434762082527],
-    [-5.2845368989705275, 4.336907352336571, 2.9209434762082527],
-    [-5.5394717595785306, 4.546126985480419, 3.209563621455084],
-    [-5.309731344869266, 4.812459972674491, 3.209563621455084],
-    [-5.2845368989705275, 4.336907352336571, 2.9209434762082527],
-    [-5.49097340039297, 4.072383421966663, 2.9209434762082527],
-    [-5.755867101618547, 4.268841980184633, 3.209563621455084],
-    [-5.5394717595785306, 4.546126985480419, 3.209563621455084],
-    [-5.49097340039297, 4.072383421966663, 2.9209434762082527],
-    [-5.6841816659414, 3.7980487632345756, 2.9209434762082527],
-    [-5.95839605565635, 3.981272960652183, 3.209563621455084],
-    [-5.755867101618547, 4.268841980184633, 3.209563621455084],
-    [-5.6841816659414, 3.7980487632345756, 2.9209434762082527],
-    [-5.8636962399810635, 3.5145642723620765, 2.9209434762082527],
-    [-6.1465707117022195, 3.684112705839197, 3.209563621455084],
-    [-5.95839605565635, 3.981272960652183, 3.209563621455084],
-    [-5.8636962399810635, 3.5145642723620765, 2.9209434762082527],
-    [-6.0290846561794815, 3.2226128883179843, 2.9209434762082527],
-    [-6.31993774052759, 3.3780771008276833, 3.209563621455084],
-    [-6.1465707117022195, 3.684112705839197, 3.209563621455084],
-    [-6.0290846561794815, 3.2226128883179843, 2.9209434762082527],
-    [-6.179948479355717, 2.92289794755838, 2.9209434762082527],
-    [-6.478079485774862, 3.0639034121956645, 3.209563621455084],
-    [-6.31993774052759, 3.3780771008276833, 3.209563621455084],
-    [-6.179948479355717, 2.92289794755838, 2.9209434762082527],
-    [-6.315924265345629, 2.616141489627487, 2.9209434762082527],
-    [-6.620614970128154, 2.742348511877426, 3.209563621455084],
-    [-6.478079485774862, 3.0639034121956645, 3.209563621455084],
-    [-6.315924265345629, 2.616141489627487, 2.9209434762082527],
-    [-6.436684436570692, 2.30308251770115, 2.9209434762082527],
-    [-6.747200813121024, 2.4141870537927135, 3.209563621455084],
-    [-6.620614970128154, 2.742348511877426, 3.209563621455084],
-    [-6.436684436570692, 2.30308251770115, 2.9209434762082527],
-    [-6.541938071201088, 1.9844752182634644, 2.9209434762082527],
-    [-6.857532058370093, 2.0802096076376, 3.209563621455084],
-    [-6.747200813121024, 2.4141870537927135, 3.209563621455084],
-    [-6.541938071201088, 1.9844752182634644, 2.9209434762082527],
-    [-6.63143160401186, 1.6610871442054849, 2.9209434762082527],
-    [-6.9513429082416796, 1.7412207543328484, 3.209563621455084],
-    [-6.857532058370093, 2.0802096076376, 3.209563621455084],
-    [-6.63143160401186, 1.6610871442054849, 2.9209434762082527],
-    [-6.7049494372437595, 1.3336973657230642, 2.9209434762082527],
-    [-7.028407364181584, 1.3980371477179834, 3.209563621455084],
-    [-6.9513429082416796, 1.7412207543328484, 3.209563621455084],
-    [-6.7049494372437595, 1.3336973657230642, 2.9209434762082527],
-    [-6.762314459997129, 1.0030945934685211, 2.9209434762082527],
-    [-7.088539771165396, 1.0514855471606708, 3.209563621455084],
-    [-7.028407364181584, 1.3980371477179834, 3.209563621455084],
-    [-6.762314459997129, 1.0030945934685211, 2.9209434762082527],
-    [-6.803388474907573, 0.670075278477606, 2.9209434762082527],
-    [-7.131595264957717, 0.7024008258209948, 3.209563621455084],
-    [-7.088539771165396, 1.0514855471606708, 3.209563621455084],
-    [-6.803388474907573, 0.670075278477606, 2.9209434762082527],
-    [-6.828072531075515, 0.3354416934491725, 2.9209434762082527],
-    [-7.157470121102785, 0.35162395936886676, 3.209563621455084],
-    [-7.131595264957717, 0.7024008258209948, 3.209563621455084],
-    [-6.828072531075515, 0.3354416934491725, 2.9209434762082527],
-    [-6.836307162447577, 8.37206168447955e-16, 2.9209434762082527],
-    [-7.166102004805775, 8.775943882548823e-16, 3.209563621455084],
-    [-7.157470121102785, 0.35162395936886676, 3.209563621455084],
-    [-6.836307162447577, 8.37206168447955e-16, 2.9209434762082527],
-    [-6.828072531075515, -0.33544169344917085, 2.9209434762082527],
-    [-7.157470121102785, -0.351623959368865, 3.209563621455084],
-    [-7.166102004805775, 8.775943882548823e-16, 3.209563621455084],
-    [-6.828072531075515, -0.33544169344917085, 2.9209434762082527],
-    [-6.803388474907574, -0.6700752784776043, 2.9209434762082527],
-    [-7.131595264957718, -0.7024008258209931, 3.209563621455084],
-    [-7.157470121102785, -0.351623959368865, 3.209563621455084],
-    [-6.803388474907574, -0.6700752784776043, 2.9209434762082527],
-    [-6.762314459997129, -1.0030945934685196, 2.9209434762082527],
-    [-7.088539771165396, -1.0514855471606692, 3.209563621455084],
-    [-7.131595264957718, -0.7024008258209931, 3.209563621455084],
-    [-6.762314459997129, -1.0030945934685196, 2.9209434762082527],
-    [-6.7049494372437595, -1.3336973657230624, 2.9209434762082527],
-    [-7.028407364181584, -1.3980371477179816, 3.209563621455084],
-    [-7.088539771165396, -1.0514855471606692, 3.209563621455084],
-    [-6.7049494372437595, -1.3336973657230624, 2.9209434762082527],
-    [-6.63143160401186, -1.661087144205483, 2.9209434762082527],
-    [-6.9513429082416796, -1.7412207543328466, 3.209563621455084],
-    [-7.028407364181584, -1.3980371477179816, 3.209563621455084],
-    [-6.63143160401186, -1.661087144205483, 2.9209434762082527],
-    [-6.541938071201089, -1.9844752182634626, 2.9209434762082527],
-    [-6.857532058370094, -2.0802096076375984, 3.209563621455084],
-    [-6.9513429082416796, -1.7412207543328466, 3.209563621455084],
-    [-6.541938071201089, -1.9844752182634626, 2.9209434762082527],
-    [-6.436684436570693, -2.3030825177011485, 2.9209434762082527],
-    [-6.747200813121024, -2.414187053792712, 3.209563621455084],
-    [-6.857532058370094, -2.0802096076375984, 3.209563621455084],
-    [-6.436684436570693, -2.3030825177011485, 2.9209434762082527],
-    [-6.315924265345629, -2.6161414896274855, 2.9209434762082527],
-    [-6.6206149701281545, -2.7423485118774242, 3.209563621455084],
-    [-6.747200813121024, -2.414187053792712, 3.209563621455084],
-    [-6.315924265345629, -2.6161414896274855, 2.9209434762082527],
-    [-6.179948479355718, -2.9228979475583783, 2.9209434762082527],
-    [-6.478079485774863, -3.0639034121956628, 3.209563621455084],
-    [-6.6206149701281545, -2.7423485118774242, 3.209563621455084],
-    [-6.179948479355718, -2.9228979475583783, 2.9209434762082527],
-    [-6.029084656179482, -3.222612888317983, 2.9209434762082527],
-    [-6.319937740527591, -3.378077100827682, 3.209563621455084],
-    [-6.478079485774863, -3.0639034121956628, 3.209563621455084],
-    [-6.029084656179482, -3.222612888317983, 2.9209434762082527],
-    [-5.863696239981064, -3.5145642723620747, 2.9209434762082527],
-    [-6.14657071170222, -3.6841127058391954, 3.209563621455084],
-    [-6.319937740527591, -3.378077100827682, 3.209563621455084],
-    [-5.863696239981064, -3.5145642723620747, 2.9209434762082527],
-    [-5.684181665941401, -3.7980487632345743, 2.9209434762082527],
-    [-5.958396055656351, -3.981272960652181, 3.209563621455084],
-    [-6.14657071170222, -3.6841127058391954, 3.209563621455084],
-    [-5.684181665941401, -3.7980487632345743, 2.9209434762082527],
-    [-5.490973400392971, -4.072383421966661, 2.9209434762082527],
-    [-5.755867101618548, -4.268841980184631, 3.209563621455084],
-    [-5.958396055656351, -3.981272960652181, 3.209563621455084],
-    [-5.490973400392971, -4.072383421966661, 2.9209434762082527],
-    [-5.284536898970528, -4.336907352336569, 2.9209434762082527],
-    [-5.539471759578531, -4.546126985480418, 3.209563621455084],
-    [-5.755867101618548, -4.268841980184631, 3.209563621455084],
-    [-5.284536898970528, -4.336907352336569, 2.9209434762082527],
-    [-5.065369485287699, -4.590983293026481, 2.9209434762082527],
-    [-5.309731344869268, -4.81245997267449, 3.209563621455084],
-    [-5.539471759578531, -4.546126985480418, 3.209563621455084],
-    [-5.065369485287699, -4.590983293026481, 2.9209434762082527],
-    [-4.833999152840847, -4.833999152840846, 2.9209434762082527],
-    [-5.067199322272677, -5.0671993222726766, 3.209563621455084],
-    [-5.309731344869268, -4.81245997267449, 3.209563621455084],
-    [-4.833999152840847, -4.833999152840846, 2.9209434762082527],
-    [-4.590983293026483, -5.065369485287698, 2.9209434762082527],
-    [-4.812459972674492, -5.309731344869266, 3.209563621455084],
-    [-5.067199322272677, -5.0671993222726766, 3.209563621455084],
-    [-4.590983293026483, -5.065369485287698, 2.9209434762082527],
-    [-4.336907352336573, -5.284536898970525, 2.9209434762082527],
-    [-4.546126985480423, -5.539471759578528, 3.209563621455084],
-    [-4.812459972674492, -5.309731344869266, 3.209563621455084],
-    [-4.336907352336573, -5.284536898970525, 2.9209434762082527],
-    [-4.072383421966661, -5.4909734003929715, 2.9209434762082527],
-    [-4.2688419801846305, -5.755867101618548, 3.209563621455084],
-    [-4.546126985480423, -5.539471759578528, 3.209563621455084],
-    [-4.072383421966661, -5.4909734003929715, 2.9209434762082527],
-    [-3.7980487632345756, -5.6841816659413995, 2.9209434762082527],
-    [-3.981272960652183, -5.95839605565635, 3.209563621455084],
-    [-4.2688419801846305, -5.755867101618548, 3.209563621455084],
-    [-3.7980487632345756, -5.6841816659413995, 2.9209434762082527],
-    [-3.5145642723620765, -5.8636962399810635, 2.9209434762082527],
-    [-3.684112705839197, -6.1465707117022195, 3.209563621455084],
-    [-3.981272960652183, -5.95839605565635, 3.209563621455084],
-    [-3.5145642723620765, -5.8636962399810635, 2.9209434762082527],
-    [-3.2226128883179848, -6.0290846561794815, 2.9209434762082527],
-    [-3.3780771008276838, -6.31993774052759, 3.209563621455084],
-    [-3.684112705839197, -6.1465707117022195, 3.209563621455084],
-    [-3.2226128883179848, -6.0290846561794815, 2.9209434762082527],
-    [-2.922897947558383, -6.1799484793557165, 2.9209434762082527],
-    [-3.0639034121956676, -6.478079485774861, 3.209563621455084],
-    [-3.3780771008276838, -6.31993774052759, 3.209563621455084],
-    [-2.922897947558383, -6.1799484793557165, 2.9209434762082527],
-    [-2.61614148962749, -6.315924265345627, 2.9209434762082527],
-    [-2.742348511877429, -6.620614970128153, 3.209563621455084],
-    [-3.0639034121956676, -6.478079485774861, 3.209563621455084],
-    [-2.61614148962749, -6.315924265345627, 2.9209434762082527],
-    [-2.303082517701147, -6.436684436570693, 2.9209434762082527],
-    [-2.414187053792711, -6.747200813121024, 3.209563621455084],
-    [-2.742348511877429, -6.620614970128153, 3.209563621455084],
-    [-2.303082517701147, -6.436684436570693, 2.9209434762082527],
-    [-1.9844752182634648, -6.541938071201088, 2.9209434762082527],
-    [-2.0802096076376007, -6.857532058370093, 3.209563621455084],
-    [-2.414187053792711, -6.747200813121024, 3.209563621455084],
-    [-1.9844752182634648, -6.541938071201088, 2.9209434762082527],
-    [-1.6610871442054853, -6.63143160401186, 2.9209434762082527],
-    [-1.7412207543328488, -6.9513429082416796, 3.209563621455084],
-    [-2.0802096076376007, -6.857532058370093, 3.209563621455084],
-    [-1.6610871442054853, -6.63143160401186, 2.9209434762082527],
-    [-1.3336973657230646, -6.704949437243759, 2.9209434762082527],
-    [-1.3980371477179838, -7.028407364181583, 3.209563621455084],
-    [-1.7412207543328488, -6.9513429082416796, 3.209563621455084],
-    [-1.3336973657230646, -6.704949437243759, 2.9209434762082527],
-    [-1.0030945934685245, -6.762314459997128, 2.9209434762082527],
-    [-1.0514855471606743, -7.088539771165396, 3.209563621455084],
-    [-1.3980371477179838, -7.028407364181583, 3.209563621455084],
-    [-1.0030945934685245, -6.762314459997128, 2.9209434762082527],
-    [-0.6700752784776034, -6.803388474907574, 2.9209434762082527],
-    [-0.7024008258209922, -7.131595264957718, 3.209563621455084],
-    [-1.0514855471606743, -7.088539771165396, 3.209563621455084],
-    [-0.6700752784776034, -6.803388474907574, 2.9209434762082527],
-    [-0.33544169344917296, -6.828072531075515, 2.9209434762082527],
-    [-0.3516239593688672, -7.157470121102785, 3.209563621455084],
-    [-0.7024008258209922, -7.131595264957718, 3.209563621455084],
-    [-0.33544169344917296, -6.828072531075515, 2.9209434762082527],
-    [
-        -1.2558092526719324e-15,
-        -6.836307162447577,
-        2.9209434762082527,
-    ],
-    [
-        -1.3163915823823234e-15,
-        -7.166102004805775,
-        3.209563621455084,
-    ],
-    [-0.3516239593688672, -7.157470121102785, 3.209563621455084],
-    [
-        -1.2558092526719324e-15,
-        -6.836307162447577,
-        2.9209434762082527,
-    ],
-    [0.33544169344917046, -6.828072531075515, 2.9209434762082527],
-    [0.35162395936886454, -7.157470121102785, 3.209563621455084],
-    [
-        -1.3163915823823234e-15,
-        -7.166102004805775,
-        3.209563621455084,
-    ],
-    [0.33544169344917046, -6.828072531075515, 2.9209434762082527],
-    [0.6700752784776008, -6.803388474907574, 2.9209434762082527],
-    [0.7024008258209895, -7.131595264957718, 3.209563621455084],
-    [0.35162395936886454, -7.157470121102785, 3.209563621455084],
-    [0.6700752784776008, -6.803388474907574, 2.9209434762082527],
-    [1.003094593468522, -6.762314459997128, 2.9209434762082527],
-    [1.0514855471606717, -7.088539771165396, 3.209563621455084],
-    [0.7024008258209895, -7.131595264957718, 3.209563621455084],
-    [1.003094593468522, -6.762314459997128, 2.9209434762082527],
-    [1.3336973657230622, -6.7049494372437595, 2.9209434762082527],
-    [1.3980371477179812, -7.028407364181584, 3.209563621455084],
-    [1.0514855471606717, -7.088539771165396, 3.209563621455084],
-    [1.3336973657230622, -6.7049494372437595, 2.9209434762082527],
-    [1.6610871442054829, -6.63143160401186, 2.9209434762082527],
-    [1.7412207543328462, -6.9513429082416796, 3.209563621455084],
-    [1.3980371477179812, -7.028407364181584, 3.209563621455084],
-    [1.6610871442054829, -6.63143160401186, 2.9209434762082527],
-    [1.9844752182634622, -6.541938071201089, 2.9209434762082527],
-    [2.080209607637598, -6.857532058370094, 3.209563621455084],
-    [1.7412207543328462, -6.9513429082416796, 3.209563621455084],
-    [1.9844752182634622, -6.541938071201089, 2.9209434762082527],
-    [2.303082517701145, -6.436684436570694, 2.9209434762082527],
-    [2.4141870537927086, -6.747200813121025, 3.209563621455084],
-    [2.080209607637598, -6.857532058370094, 3.209563621455084],
-    [2.303082517701145, -6.436684436570694, 2.9209434762082527],
-    [2.6161414896274877, -6.315924265345628, 2.9209434762082527],
-    [2.7423485118774265, -6.620614970128154, 3.209563621455084],
-    [2.4141870537927086, -6.747200813121025, 3.209563621455084],
-    [2.6161414896274877, -6.315924265345628, 2.9209434762082527],
-    [2.9228979475583805, -6.179948479355717, 2.9209434762082527],
-    [3.0639034121956654, -6.478079485774862, 3.209563621455084],
-    [2.7423485118774265, -6.620614970128154, 3.209563621455084],
-    [2.9228979475583805, -6.179948479355717, 2.9209434762082527],
-    [3.2226128883179825, -6.029084656179482, 2.9209434762082527],
-    [3.3780771008276815, -6.319937740527591, 3.209563621455084],
-    [3.0639034121956654, -6.478079485774862, 3.209563621455084],
-    [3.2226128883179825, -6.029084656179482, 2.9209434762082527],
-    [3.5145642723620747, -5.863696239981065, 2.9209434762082527],
-    [3.6841127058391954, -6.146570711702221, 3.209563621455084],
-    [3.3780771008276815, -6.319937740527591, 3.209563621455084],
-    [3.5145642723620747, -5.863696239981065, 2.9209434762082527],
-    [3.7980487632345734, -5.684181665941401, 2.9209434762082527],
-    [3.98127296065218, -5.958396055656351, 3.209563621455084],
-    [3.6841127058391954, -6.146570711702221, 3.209563621455084],
-    [3.7980487632345734, -5.684181665941401, 2.9209434762082527],
-    [4.072383421966658, -5.490973400392972, 2.9209434762082527],
-    [4.268841980184629, -5.755867101618549, 3.209563621455084],
-    [3.98127296065218, -5.958396055656351, 3.209563621455084],
-    [4.072383421966658, -5.490973400392972, 2.9209434762082527],
-    [4.3369073523365715, -5.284536898970527, 2.9209434762082527],
-    [4.54612698548042, -5.53947175957853, 3.209563621455084],
-    [4.268841980184629, -5.755867101618549, 3.209563621455084],
-    [4.3369073523365715, -5.284536898970527, 2.9209434762082527],
-    [4.59098329302648, -5.065369485287699, 2.9209434762082527],
-    [4.812459972674489, -5.309731344869268, 3.209563621455084],
-    [4.54612698548042, -5.53947175957853, 3.209563621455084],
-    [4.59098329302648, -5.065369485287699, 2.9209434762082527],
-    [4.833999152840845, -4.833999152840847, 2.9209434762082527],
-    [5.067199322272676, -5.067199322272677, 3.209563621455084],
-    [4.812459972674489, -5.309731344869268, 3.209563621455084],
-    [4.833999152840845, -4.833999152840847, 2.9209434762082527],
-    [5.065369485287698, -4.590983293026483, 2.9209434762082527],
-    [5.309731344869266, -4.812459972674492, 3.209563621455084],
-    [5.067199322272676, -5.067199322272677, 3.209563621455084],
-    [5.065369485287698, -4.590983293026483, 2.9209434762082527],
-    [5.284536898970525, -4.336907352336573, 2.9209434762082527],
-    [5.539471759578527, -4.546126985480423, 3.209563621455084],
-    [5.309731344869266, -4.812459972674492, 3.209563621455084],
-    [5.284536898970525, -4.336907352336573, 2.9209434762082527],
-    [5.4909734003929715, -4.072383421966661, 2.9209434762082527],
-    [5.755867101618548, -4.268841980184631, 3.209563621455084],
-    [5.539471759578527, -4.546126985480423, 3.209563621455084],
-    [5.4909734003929715, -4.072383421966661, 2.9209434762082527],
-    [5.6841816659413995, -3.7980487632345756, 2.9209434762082527],
-    [5.95839605565635, -3.981272960652183, 3.209563621455084],
-    [5.755867101618548, -4.268841980184631, 3.209563621455084],
-    [5.6841816659413995, -3.7980487632345756, 2.9209434762082527],
-    [5.8636962399810635, -3.5145642723620774, 2.9209434762082527],
-    [6.1465707117022195, -3.6841127058391976, 3.209563621455084],
-    [5.95839605565635, -3.981272960652183, 3.209563621455084],
-    [5.8636962399810635, -3.5145642723620774, 2.9209434762082527],
-    [6.029084656179481, -3.222612888317985, 2.9209434762082527],
-    [6.319937740527589, -3.3780771008276838, 3.209563621455084],
-    [6.1465707117022195, -3.6841127058391976, 3.209563621455084],
-    [6.029084656179481, -3.222612888317985, 2.9209434762082527],
-    [6.1799484793557165, -2.922897947558383, 2.9209434762082527],
-    [6.478079485774861, -3.063903412195668, 3.209563621455084],
-    [6.319937740527589, -3.3780771008276838, 3.209563621455084],
-    [6.1799484793557165, -2.922897947558383, 2.9209434762082527],
-    [6.315924265345627, -2.6161414896274904, 2.9209434762082527],
-    [6.620614970128153, -2.7423485118774296, 3.209563621455084],
-    [6.478079485774861, -3.063903412195668, 3.209563621455084],
-    [6.315924265345627, -2.6161414896274904, 2.9209434762082527],
-    [6.436684436570693, -2.3030825177011476, 2.9209434762082527],
-    [6.747200813121024, -2.4141870537927113, 3.209563621455084],
-    [6.620614970128153, -2.7423485118774296, 3.209563621455084],
-    [6.436684436570693, -2.3030825177011476, 2.9209434762082527],
-    [6.541938071201088, -1.984475218263465, 2.9209434762082527],
-    [6.857532058370093, -2.080209607637601, 3.209563621455084],
-    [6.747200813121024, -2.4141870537927113, 3.209563621455084],
-    [6.541938071201088, -1.984475218263465, 2.9209434762082527],
-    [6.63143160401186, -1.6610871442054858, 2.9209434762082527],
-    [6.9513429082416796, -1.7412207543328493, 3.209563621455084],
-    [6.857532058370093, -2.080209607637601, 3.209563621455084],
-    [6.63143160401186, -1.6610871442054858, 2.9209434762082527],
-    [6.704949437243759, -1.3336973657230649, 2.9209434762082527],
-    [7.028407364181583, -1.3980371477179843, 3.209563621455084],
-    [6.9513429082416796, -1.7412207543328493, 3.209563621455084],
-    [6.704949437243759, -1.3336973657230649, 2.9209434762082527],
-    [6.762314459997128, -1.0030945934685251, 2.9209434762082527],
-    [7.088539771165396, -1.051485547160675, 3.209563621455084],
-    [7.028407364181583, -1.3980371477179843, 3.209563621455084],
-    [6.762314459997128, -1.0030945934685251, 2.9209434762082527],
-    [6.803388474907574, -0.6700752784776037, 2.9209434762082527],
-    [7.131595264957718, -0.7024008258209925, 3.209563621455084],
-    [7.088539771165396, -1.051485547160675, 3.209563621455084],
-    [6.803388474907574, -0.6700752784776037, 2.9209434762082527],
-    [6.828072531075515, -0.3354416934491734, 2.9209434762082527],
-    [7.157470121102785, -0.35162395936886764, 3.209563621455084],
-    [7.131595264957718, -0.7024008258209925, 3.209563621455084],
-    [6.828072531075515, -0.3354416934491734, 2.9209434762082527],
-    [6.836307162447577, -1.67441233689591e-15, 2.9209434762082527],
-    [
-        7.166102004805775,
-        -1.7551887765097647e-15,
-        3.209563621455084,
-    ],
-    [7.157470121102785, -0.35162395936886764, 3.209563621455084],
-    [7.166102004805775, 0.0, 3.209563621455084],
-    [7.157470121102785, 0.3516239593688671, 3.209563621455084],
-    [7.486867711130055, 0.36780622528856133, 3.511779346457675],
-    [7.495896847163974, 0.0, 3.511779346457675],
-    [7.157470121102785, 0.3516239593688671, 3.209563621455084],
-    [7.131595264957718, 0.7024008258209932, 3.209563621455084],
-    [7.459802055007862, 0.7347263731643822, 3.511779346457675],
-    [7.486867711130055, 0.36780622528856133, 3.511779346457675],
-    [7.131595264957718, 0.7024008258209932, 3.209563621455084],
-    [7.088539771165396, 1.0514855471606703, 3.209563621455084],
-    [7.414765082333665, 1.0998765008528202, 3.511779346457675],
-    [7.459802055007862, 0.7347263731643822, 3.511779346457675],
-    [7.088539771165396, 1.0514855471606703, 3.209563621455084],
-    [7.028407364181584, 1.398037147717981, 3.209563621455084],
-    [7.351865291119409, 1.4623769297129001, 3.511779346457675],
-    [7.414765082333665, 1.0998765008528202, 3.511779346457675],
-    [7.028407364181584, 1.398037147717981, 3.209563621455084],
-    [6.9513429082416796, 1.741220754332847, 3.209563621455084],
-    [7.271254212471501, 1.8213543644602108, 3.511779346457675],
-    [7.351865291119409, 1.4623769297129001, 3.511779346457675],
-    [6.9513429082416796, 1.741220754332847, 3.209563621455084],
-    [6.857532058370093, 2.0802096076375998, 3.209563621455084],
-    [7.173126045539099, 2.175943997011736, 3.511779346457675],
-    [7.271254212471501, 1.8213543644602108, 3.511779346457675],
-    [6.857532058370093, 2.0802096076375998, 3.209563621455084],
-    [6.747200813121024, 2.4141870537927117, 3.209563621455084],
-    [7.057717189671357, 2.525291589884276, 3.511779346457675],
-    [7.173126045539099, 2.175943997011736, 3.511779346457675],
-    [6.747200813121024, 2.4141870537927117, 3.209563621455084],
-    [6.620614970128154, 2.742348511877425, 3.209563621455084],
-    [6.92530567491068, 2.8685555341273643, 3.511779346457675],
-    [7.057717189671357, 2.525291589884276, 3.511779346457675],
-    [6.620614970128154, 2.742348511877425, 3.209563621455084],
-    [6.478079485774862, 3.063903412195665, 3.209563621455084],
-    [6.776210492194008, 3.20490887683295, 3.511779346457675],
-    [6.92530567491068, 2.8685555341273643, 3.511779346457675],
-    [6.478079485774862, 3.063903412195665, 3.209563621455084],
-    [6.319937740527591, 3.378077100827682, 3.209563621455084],
-    [6.6107908248757, 3.5335413133373814, 3.511779346457675],
-    [6.776210492194008, 3.20490887683295, 3.511779346457675],
-    [6.319937740527591, 3.378077100827682, 3.209563621455084],
-    [6.14657071170222, 3.6841127058391963, 3.209563621455084],
-    [6.429445183423377, 3.8536611393163174, 3.511779346457675],
-    [6.6107908248757, 3.5335413133373814, 3.511779346457675],
-    [6.14657071170222, 3.6841127058391963, 3.209563621455084],
-    [5.95839605565635, 3.981272960652183, 3.209563621455084],
-    [6.2326104453713, 4.16449715806979, 3.511779346457675],
-    [6.429445183423377, 3.8536611393163174, 3.511779346457675],
-    [5.95839605565635, 3.981272960652183, 3.209563621455084],
-    [5.755867101618548, 4.268841980184632, 3.209563621455084],
-    [6.020760802844125, 4.465300538402603, 3.511779346457675],
-    [6.2326104453713, 4.16449715806979, 3.511779346457675],
-    [5.755867101618548, 4.268841980184632, 3.209563621455084],
-    [5.5394717595785306, 4.546126985480419, 3.209563621455084],
-    [5.7944066201865345, 4.755346618624269, 3.511779346457675],
-    [6.020760802844125, 4.465300538402603, 3.511779346457675],
-    [5.5394717595785306, 4.546126985480419, 3.209563621455084],
-    [5.3097313448692685, 4.812459972674489, 3.209563621455084],
-    [5.554093204450837, 5.033936652322498, 3.511779346457675],
-    [5.7944066201865345, 4.755346618624269, 3.511779346457675],
-    [5.3097313448692685, 4.812459972674489, 3.209563621455084],
-    [5.0671993222726766, 5.0671993222726766, 3.209563621455084],
-    [5.300399491704508, 5.300399491704508, 3.511779346457675],
-    [5.554093204450837, 5.033936652322498, 3.511779346457675],
-    [5.0671993222726766, 5.0671993222726766, 3.209563621455084],
-    [4.812459972674489, 5.309731344869268, 3.209563621455084],
-    [5.033936652322498, 5.554093204450837, 3.511779346457675],
-    [5.300399491704508, 5.300399491704508, 3.511779346457675],
-    [4.812459972674489, 5.309731344869268, 3.209563621455084],
-    [4.546126985480419, 5.53947175957853, 3.209563621455084],
-    [4.755346618624269, 5.794406620186534, 3.511779346457675],
-    [5.033936652322498, 5.554093204450837, 3.511779346457675],
-    [4.546126985480419, 5.53947175957853, 3.209563621455084],
-    [4.268841980184633, 5.755867101618547, 3.209563621455084],
-    [4.465300538402604, 6.020760802844124, 3.511779346457675],
-    [4.755346618624269, 5.794406620186534, 3.511779346457675],
-    [4.268841980184633, 5.755867101618547, 3.209563621455084],
-    [3.9812729606521833, 5.95839605565635, 3.209563621455084],
-    [4.164497158069791, 6.2326104453713, 3.511779346457675],
-    [4.465300538402604, 6.020760802844124, 3.511779346457675],
-    [3.9812729606521833, 5.95839605565635, 3.209563621455084],
-    [3.6841127058391963, 6.14657071170222, 3.209563621455084],
-    [3.8536611393163174, 6.429445183423377, 3.511779346457675],
-    [4.164497158069791, 6.2326104453713, 3.511779346457675],
-    [3.6841127058391963, 6.14657071170222, 3.209563621455084],
-    [3.3780771008276833, 6.31993774052759, 3.209563621455084],
-    [3.5335413133373823, 6.610790824875699, 3.511779346457675],
-    [3.8536611393163174, 6.429445183423377, 3.511779346457675],
-    [3.3780771008276833, 6.31993774052759, 3.209563621455084],
-    [3.0639034121956654, 6.478079485774862, 3.209563621455084],
-    [3.2049088768329503, 6.776210492194008, 3.511779346457675],
-    [3.5335413133373823, 6.610790824875699, 3.511779346457675],
-    [3.0639034121956654, 6.478079485774862, 3.209563621455084],
-    [2.7423485118774256, 6.620614970128154, 3.209563621455084],
-    [2.8685555341273647, 6.92530567491068, 3.511779346457675],
-    [3.2049088768329503, 6.776210492194008, 3.511779346457675],
-    [2.7423485118774256, 6.620614970128154, 3.209563621455084],
-    [2.4141870537927117, 6.747200813121024, 3.209563621455084],
-    [2.525291589884276, 7.057717189671357, 3.511779346457675],
-    [2.8685555341273647, 6.92530567491068, 3.511779346457675],
-    [2.4141870537927117, 6.747200813121024, 3.209563621455084],
-    [2.0802096076375998, 6.857532058370094, 3.209563621455084],
-    [2.175943997011736, 7.1731260455390995, 3.511779346457675],
-    [2.525291589884276, 7.057717189671357, 3.511779346457675],
-    [2.0802096076375998, 6.857532058370094, 3.209563621455084],
-    [1.741220754332848, 6.9513429082416796, 3.209563621455084],
-    [1.8213543644602117, 7.271254212471501, 3.511779346457675],
-    [2.175943997011736, 7.1731260455390995, 3.511779346457675],
-    [1.741220754332848, 6.9513429082416796, 3.209563621455084],
-    [1.3980371477179814, 7.028407364181584, 3.209563621455084],
-    [1.4623769297129008, 7.351865291119409, 3.511779346457675],
-    [1.8213543644602117, 7.271254212471501, 3.511779346457675],
-    [1.3980371477179814, 7.028407364181584, 3.209563621455084],
-    [1.0514855471606703, 7.088539771165396, 3.209563621455084],
-    [1.0998765008528202, 7.414765082333665, 3.511779346457675],
-    [1.4623769297129008, 7.351865291119409, 3.511779346457675],
-    [1.0514855471606703, 7.088539771165396, 3.209563621455084],
-    [0.7024008258209944, 7.131595264957717, 3.209563621455084],
-    [0.7347263731643834, 7.459802055007861, 3.511779346457675],
-    [1.0998765008528202, 7.414765082333665, 3.511779346457675],
-    [0.7024008258209944, 7.131595264957717, 3.209563621455084],
-    [0.35162395936886787, 7.157470121102785, 3.209563621455084],
-    [0.36780622528856216, 7.486867711130055, 3.511779346457675],
-    [0.7347263731643834, 7.459802055007861, 3.511779346457675],
-    [0.35162395936886787, 7.157470121102785, 3.209563621455084],
-    [4.3879719412744116e-16, 7.166102004805775, 3.209563621455084],
-    [4.589913040309048e-16, 7.495896847163974, 3.511779346457675],
-    [0.36780622528856216, 7.486867711130055, 3.511779346457675],
-    [4.3879719412744116e-16, 7.166102004805775, 3.209563621455084],
-    [-0.35162395936886703, 7.157470121102785, 3.209563621455084],
-    [-0.36780622528856133, 7.486867711130055, 3.511779346457675],
-    [4.589913040309048e-16, 7.495896847163974, 3.511779346457675],
-    [-0.35162395936886703, 7.157470121102785, 3.209563621455084],
-    [-0.7024008258209935, 7.131595264957718, 3.209563621455084],
-    [-0.7347263731643824, 7.459802055007862, 3.511779346457675],
-    [-0.36780622528856133, 7.486867711130055, 3.511779346457675],
-    [-0.7024008258209935, 7.131595264957718, 3.209563621455084],
-    [-1.0514855471606697, 7.088539771165396, 3.209563621455084],
-    [-1.0998765008528193, 7.414765082333665, 3.511779346457675],
-    [-0.7347263731643824, 7.459802055007862, 3.511779346457675],
-    [-1.0514855471606697, 7.088539771165396, 3.209563621455084],
-    [-1.3980371477179805, 7.028407364181584, 3.209563621455084],
-    [-1.4623769297128997, 7.351865291119409, 3.511779346457675],
-    [-1.0998765008528193, 7.414765082333665, 3.511779346457675],
-    [-1.3980371477179805, 7.028407364181584, 3.209563621455084],
-    [-1.741220754332847, 6.9513429082416796, 3.209563621455084],
-    [-1.8213543644602108, 7.271254212471501, 3.511779346457675],
-    [-1.4623769297128997, 7.351865291119409, 3.511779346457675],
-    [-1.741220754332847, 6.9513429082416796, 3.209563621455084],
-    [-2.0802096076375984, 6.857532058370094, 3.209563621455084],
-    [-2.1759439970117347, 7.1731260455390995, 3.511779346457675],
-    [-1.8213543644602108, 7.271254212471501, 3.511779346457675],
-    [-2.0802096076375984, 6.857532058370094, 3.209563621455084],
-    [-2.414187053792711, 6.747200813121024, 3.209563621455084],
-    [-2.525291589884275, 7.057717189671357, 3.511779346457675],
-    [-2.1759439970117347, 7.1731260455390995, 3.511779346457675],
-    [-2.414187053792711, 6.747200813121024, 3.209563621455084],
-    [-2.7423485118774247, 6.620614970128154, 3.209563621455084],
-    [-2.868555534127364, 6.92530567491068, 3.511779346457675],
-    [-2.525291589884275, 7.057717189671357, 3.511779346457675],
-    [-2.7423485118774247, 6.620614970128154, 3.209563621455084],
-    [-3.063903412195663, 6.478079485774863, 3.209563621455084],
-    [-3.204908876832948, 6.776210492194009, 3.511779346457675],
-    [-2.868555534127364, 6.92530567491068, 3.511779346457675],
-    [-3.063903412195663, 6.478079485774863, 3.209563621455084],
-    [-3.3780771008276824, 6.319937740527591, 3.209563621455084],
-    [-3.5335413133373814, 6.6107908248757, 3.511779346457675],
-    [-3.204908876832948, 6.776210492194009, 3.511779346457675],
-    [-3.3780771008276824, 6.319937740527591, 3.209563621455084],
-    [-3.6841127058391954, 6.14657071170222, 3.209563621455084],
-    [-3.8536611393163165, 6.429445183423377, 3.511779346457675],
-    [-3.5335413133373814, 6.6107908248757, 3.511779346457675],
-    [-3.6841127058391954, 6.14657071170222, 3.209563621455084],
-    [-3.981272960652181, 5.95839605565635, 3.209563621455084],
-    [-4.164497158069788, 6.232610445371301, 3.511779346457675],
-    [-3.8536611393163165, 6.429445183423377, 3.511779346457675],
-    [-3.981272960652181, 5.95839605565635, 3.209563621455084],
-    [-4.268841980184632, 5.755867101618548, 3.209563621455084],
-    [-4.465300538402603, 6.020760802844125, 3.511779346457675],
-    [-4.164497158069788, 6.232610445371301, 3.511779346457675],
-    [-4.268841980184632, 5.755867101618548, 3.209563621455084],
-    [-4.546126985480419, 5.539471759578531, 3.209563621455084],
-    [-4.7553466186242686, 5.794406620186535, 3.511779346457675],
-    [-4.465300538402603, 6.020760802844125, 3.511779346457675],
-    [-4.546126985480419, 5.539471759578531, 3.209563621455084],
-    [-4.81245997267449, 5.309731344869267, 3.209563621455084],
-    [-5.033936652322499, 5.554093204450836, 3.511779346457675],
-    [-4.7553466186242686, 5.794406620186535, 3.511779346457675],
-    [-4.81245997267449, 5.309731344869267, 3.209563621455084],
-    [-5.0671993222726766, 5.0671993222726766, 3.209563621455084],
-    [-5.300399491704507, 5.300399491704508, 3.511779346457675],
-    [-5.033936652322499, 5.554093204450836, 3.511779346457675],
-    [-5.0671993222726766, 5.0671993222726766, 3.209563621455084],
-    [-5.309731344869266, 4.812459972674491, 3.209563621455084],
-    [-5.554093204450835, 5.0339366523225, 3.511779346457675],
-    [-5.300399491704507, 5.300399491704508, 3.511779346457675],
-    [-5.309731344869266, 4.812459972674491, 3.209563621455084],
-    [-5.5394717595785306, 4.546126985480419, 3.209563621455084],
-    [-5.7944066201865345, 4.755346618624269, 3.511779346457675],
-    [-5.554093204450835, 5.0339366523225, 3.511779346457675],
-    [-5.5394717595785306, 4.546126985480419, 3.209563621455084],
-    [-5.755867101618547, 4.268841980184633, 3.209563621455084],
-    [-6.020760802844124, 4.465300538402604, 3.511779346457675],
-    [-5.7944066201865345, 4.755346618624269, 3.511779346457675],
-    [-5.755867101618547, 4.268841980184633, 3.209563621455084],
-    [-5.95839605565635, 3.981272960652183, 3.209563621455084],
-    [-6.232610445371301, 4.16449715806979, 3.511779346457675],
-    [-6.020760802844124, 4.465300538402604, 3.511779346457675],
-    [-5.95839605565635, 3.981272960652183, 3.209563621455084],
-    [-6.1465707117022195, 3.684112705839197, 3.209563621455084],
-    [-6.429445183423376, 3.853661139316318, 3.511779346457675],
-    [-6.232610445371301, 4.16449715806979, 3.511779346457675],
-    [-6.1465707117022195, 3.684112705839197, 3.209563621455084],
-    [-6.31993774052759, 3.3780771008276833, 3.209563621455084],
-    [-6.610790824875699, 3.5335413133373823, 3.511779346457675],
-    [-6.429445183423376, 3.853661139316318, 3.511779346457675],
-    [-6.31993774052759, 3.3780771008276833, 3.209563621455084],
-    [-6.478079485774862, 3.0639034121956645, 3.209563621455084],
-    [-6.776210492194008, 3.2049088768329494, 3.511779346457675],
-    [-6.610790824875699, 3.5335413133373823, 3.511779346457675],
-    [-6.478079485774862, 3.0639034121956645, 3.209563621455084],
-    [-6.620614970128154, 2.742348511877426, 3.209563621455084],
-    [-6.92530567491068, 2.868555534127365, 3.511779346457675],
-    [-6.776210492194008, 3.2049088768329494, 3.511779346457675],
-    [-6.620614970128154, 2.742348511877426, 3.209563621455084],
-    [-6.747200813121024, 2.4141870537927135, 3.209563621455084],
-    [-7.057717189671356, 2.5252915898842776, 3.511779346457675],
-    [-6.92530567491068, 2.868555534127365, 3.511779346457675],
-    [-6.747200813121024, 2.4141870537927135, 3.209563621455084],
-    [-6.857532058370093, 2.0802096076376, 3.209563621455084],
-    [-7.173126045539099, 2.1759439970117365, 3.511779346457675],
-    [-7.057717189671356, 2.5252915898842776, 3.511779346457675],
-    [-6.857532058370093, 2.0802096076376, 3.209563621455084],
-    [-6.9513429082416796, 1.7412207543328484, 3.209563621455084],
-    [-7.271254212471501, 1.8213543644602121, 3.511779346457675],
-    [-7.173126045539099, 2.1759439970117365, 3.511779346457675],
-    [-6.9513429082416796, 1.7412207543328484, 3.209563621455084],
-    [-7.028407364181584, 1.3980371477179834, 3.209563621455084],
-    [-7.351865291119409, 1.4623769297129028, 3.511779346457675],
-    [-7.271254212471501, 1.8213543644602121, 3.511779346457675],
-    [-7.028407364181584, 1.3980371477179834, 3.209563621455084],
-    [-7.088539771165396, 1.0514855471606708, 3.209563621455084],
-    [-7.414765082333665, 1.0998765008528206, 3.511779346457675],
-    [-7.351865291119409, 1.4623769297129028, 3.511779346457675],
-    [-7.088539771165396, 1.0514855471606708, 3.209563621455084],
-    [-7.131595264957717, 0.7024008258209948, 3.209563621455084],
-    [-7.459802055007861, 0.7347263731643837, 3.511779346457675],
-    [-7.414765082333665, 1.0998765008528206, 3.511779346457675],
-    [-7.131595264957717, 0.7024008258209948, 3.209563621455084],
-    [-7.157470121102785, 0.35162395936886676, 3.209563621455084],
-    [-7.486867711130055, 0.367806225288561, 3.511779346457675],
-    [-7.459802055007861, 0.7347263731643837, 3.511779346457675],
-    [-7.157470121102785, 0.35162395936886676, 3.209563621455084],
-    [-7.166102004805775, 8.775943882548823e-16, 3.209563621455084],
-    [-7.495896847163974, 9.179826080618097e-16, 3.511779346457675],
-    [-7.486867711130055, 0.367806225288561, 3.511779346457675],
-    [-7.166102004805775, 8.775943882548823e-16, 3.209563621455084],
-    [-7.157470121102785, -0.351623959368865, 3.209563621455084],
-    [-7.486867711130055, -0.36780622528855916, 3.511779346457675],
-    [-7.495896847163974, 9.179826080618097e-16, 3.511779346457675],
-    [-7.157470121102785, -0.351623959368865, 3.209563621455084],
-    [-7.131595264957718, -0.7024008258209931, 3.209563621455084],
-    [-7.459802055007862, -0.734726373164382, 3.511779346457675],
-    [-7.486867711130055, -0.36780622528855916, 3.511779346457675],
-    [-7.131595264957718, -0.7024008258209931, 3.209563621455084],
-    [-7.088539771165396, -1.0514855471606692, 3.209563621455084],
-    [-7.414765082333665, -1.0998765008528189, 3.511779346457675],
-    [-7.459802055007862, -0.734726373164382, 3.511779346457675],
-    [-7.088539771165396, -1.0514855471606692, 3.209563621455084],
-    [-7.028407364181584, -1.3980371477179816, 3.209563621455084],
-    [-7.351865291119409, -1.462376929712901, 3.511779346457675],
-    [-7.414765082333665, -1.0998765008528189, 3.511779346457675],
-    [-7.028407364181584, -1.3980371477179816, 3.209563621455084],
-    [-6.9513429082416796, -1.7412207543328466, 3.209563621455084],
-    [-7.271254212471501, -1.8213543644602104, 3.511779346457675],
-    [-7.351865291119409, -1.462376929712901, 3.511779346457675],
-    [-6.9513429082416796, -1.7412207543328466, 3.209563621455084],
-    [-6.857532058370094, -2.0802096076375984, 3.209563621455084],
-    [-7.1731260455390995, -2.1759439970117342, 3.511779346457675],
-    [-7.271254212471501, -1.8213543644602104, 3.511779346457675],
-    [-6.857532058370094, -2.0802096076375984, 3.209563621455084],
-    [-6.747200813121024, -2.414187053792712, 3.209563621455084],
-    [-7.057717189671357, -2.5252915898842763, 3.511779346457675],
-    [-7.1731260455390995, -2.1759439970117342, 3.511779346457675],
-    [-6.747200813121024, -2.414187053792712, 3.209563621455084],
-    [-6.6206149701281545, -2.7423485118774242, 3.209563621455084],
-    [-6.925305674910681, -2.8685555341273634, 3.511779346457675],
-    [-7.057717189671357, -2.5252915898842763, 3.511779346457675],
-    [-6.6206149701281545, -2.7423485118774242, 3.209563621455084],
-    [-6.478079485774863, -3.0639034121956628, 3.209563621455084],
-    [-6.776210492194009, -3.2049088768329477, 3.511779346457675],
-    [-6.925305674910681, -2.8685555341273634, 3.511779346457675],
-    [-6.478079485774863, -3.0639034121956628, 3.209563621455084],
-    [-6.319937740527591, -3.378077100827682, 3.209563621455084],
-    [-6.6107908248757, -3.5335413133373814, 3.511779346457675],
-    [-6.776210492194009, -3.2049088768329477, 3.511779346457675],
-    [-6.319937740527591, -3.378077100827682, 3.209563621455084],
-    [-6.14657071170222, -3.6841127058391954, 3.209563621455084],
-    [-6.429445183423377, -3.8536611393163165, 3.511779346457675],
-    [-6.6107908248757, -3.5335413133373814, 3.511779346457675],
-    [-6.14657071170222, -3.6841127058391954, 3.209563621455084],
-    [-5.958396055656351, -3.981272960652181, 3.209563621455084],
-    [-6.232610445371302, -4.164497158069788, 3.511779346457675],
-    [-6.429445183423377, -3.8536611393163165, 3.511779346457675],
-    [-5.958396055656351, -3.981272960652181, 3.209563621455084],
-    [-5.755867101618548, -4.268841980184631, 3.209563621455084],
-    [-6.020760802844125, -4.4653005384026025, 3.511779346457675],
-    [-6.232610445371302, -4.164497158069788, 3.511779346457675],
-    [-5.755867101618548, -4.268841980184631, 3.209563621455084],
-    [-5.539471759578531, -4.546126985480418, 3.209563621455084],
-    [-5.794406620186535, -4.755346618624268, 3.511779346457675],
-    [-6.020760802844125, -4.4653005384026025, 3.511779346457675],
-    [-5.539471759578531, -4.546126985480418, 3.209563621455084],
-    [-5.309731344869268, -4.81245997267449, 3.209563621455084],
-    [-5.554093204450837, -5.033936652322499, 3.511779346457675],
-    [-5.794406620186535, -4.755346618624268, 3.511779346457675],
-    [-5.309731344869268, -4.81245997267449, 3.209563621455084],
-    [-5.067199322272677, -5.0671993222726766, 3.209563621455084],
-    [-5.300399491704509, -5.300399491704507, 3.511779346457675],
-    [-5.554093204450837, -5.033936652322499, 3.511779346457675],
-    [-5.067199322272677, -5.0671993222726766, 3.209563621455084],
-    [-4.812459972674492, -5.309731344869266, 3.209563621455084],
-    [-5.033936652322501, -5.554093204450835, 3.511779346457675],
-    [-5.300399491704509, -5.300399491704507, 3.511779346457675],
-    [-4.812459972674492, -5.309731344869266, 3.209563621455084],
-    [-4.546126985480423, -5.539471759578528, 3.209563621455084],
-    [-4.755346618624272, -5.794406620186532, 3.511779346457675],
-    [-5.033936652322501, -5.554093204450835, 3.511779346457675],
-    [-4.546126985480423, -5.539471759578528, 3.209563621455084],
-    [-4.2688419801846305, -5.755867101618548, 3.209563621455084],
-    [-4.465300538402602, -6.0207608028441255, 3.511779346457675],
-    [-4.755346618624272, -5.794406620186532, 3.511779346457675],
-    [-4.2688419801846305, -5.755867101618548, 3.209563621455084],
-    [-3.981272960652183, -5.95839605565635, 3.209563621455084],
-    [-4.16449715806979, -6.2326104453713, 3.511779346457675],
-    [-4.465300538402602, -6.0207608028441255, 3.511779346457675],
-    [-3.981272960652183, -5.95839605565635, 3.209563621455084],
-    [-3.684112705839197, -6.1465707117022195, 3.209563621455084],
-    [-3.853661139316318, -6.429445183423376, 3.511779346457675],
-    [-4.16449715806979, -6.2326104453713, 3.511779346457675],
-    [-3.684112705839197, -6.1465707117022195, 3.209563621455084],
-    [-3.3780771008276838, -6.31993774052759, 3.209563621455084],
-    [-3.5335413133373827, -6.610790824875699, 3.511779346457675],
-    [-3.853661139316318, -6.429445183423376, 3.511779346457675],
-    [-3.3780771008276838, -6.31993774052759, 3.209563621455084],
-    [-3.0639034121956676, -6.478079485774861, 3.209563621455084],
-    [-3.2049088768329526, -6.776210492194006, 3.511779346457675],
-    [-3.5335413133373827, -6.610790824875699, 3.511779346457675],
-    [-3.0639034121956676, -6.478079485774861, 3.209563621455084],
-    [-2.742348511877429, -6.620614970128153, 3.209563621455084],
-    [-2.8685555341273683, -6.925305674910678, 3.511779346457675],
-    [-3.2049088768329526, -6.776210492194006, 3.511779346457675],
-    [-2.742348511877429, -6.620614970128153, 3.209563621455084],
-    [-2.414187053792711, -6.747200813121024, 3.209563621455084],
-    [-2.525291589884275, -7.057717189671357, 3.511779346457675],
-    [-2.8685555341273683, -6.925305674910678, 3.511779346457675],
-    [-2.414187053792711, -6.747200813121024, 3.209563621455084],
-    [-2.0802096076376007, -6.857532058370093, 3.209563621455084],
-    [-2.175943997011737, -7.173126045539099, 3.511779346457675],
-    [-2.525291589884275, -7.057717189671357, 3.511779346457675],
-    [-2.0802096076376007, -6.857532058370093, 3.209563621455084],
-    [-1.7412207543328488, -6.9513429082416796, 3.209563621455084],
-    [-1.8213543644602126, -7.271254212471501, 3.511779346457675],
-    [-2.175943997011737, -7.173126045539099, 3.511779346457675],
-    [-1.7412207543328488, -6.9513429082416796, 3.209563621455084],
-    [-1.3980371477179838, -7.028407364181583, 3.209563621455084],
-    [-1.4623769297129032, -7.351865291119408, 3.511779346457675],
-    [-1.8213543644602126, -7.271254212471501, 3.511779346457675],
-    [-1.3980371477179838, -7.028407364181583, 3.209563621455084],
-    [-1.0514855471606743, -7.088539771165396, 3.209563621455084],
-    [-1.0998765008528242, -7.414765082333664, 3.511779346457675],
-    [-1.4623769297129032, -7.351865291119408, 3.511779346457675],
-    [-1.0514855471606743, -7.088539771165396, 3.209563621455084],
-    [-0.7024008258209922, -7.131595264957718, 3.209563621455084],
-    [-0.7347263731643809, -7.459802055007862, 3.511779346457675],
-    [-1.0998765008528242, -7.414765082333664, 3.511779346457675],
-    [-0.7024008258209922, -7.131595264957718, 3.209563621455084],
-    [-0.3516239593688672, -7.157470121102785, 3.209563621455084],
-    [-0.36780622528856144, -7.486867711130055, 3.511779346457675],
-    [-0.7347263731643809, -7.459802055007862, 3.511779346457675],
-    [-0.3516239593688672, -7.157470121102785, 3.209563621455084],
-    [
-        -1.3163915823823234e-15,
-        -7.166102004805775,
-        3.209563621455084,
-    ],
-    [
-        -1.3769739120927145e-15,
-        -7.495896847163974,
-        3.511779346457675,
-    ],
-    [-0.36780622528856144, -7.486867711130055, 3.511779346457675],
-    [
-        -1.3163915823823234e-15,
-        -7.166102004805775,
-        3.209563621455084,
-    ],
-    [0.35162395936886454, -7.157470121102785, 3.209563621455084],
-    [0.3678062252885587, -7.486867711130055, 3.511779346457675],
-    [
-        -1.3769739120927145e-15,
-        -7.495896847163974,
-        3.511779346457675,
-    ],
-    [0.35162395936886454, -7.157470121102785, 3.209563621455084],
-    [0.7024008258209895, -7.131595264957718, 3.209563621455084],
-    [0.7347263731643783, -7.459802055007862, 3.511779346457675],
-    [0.3678062252885587, -7.486867711130055, 3.511779346457675],
-    [0.7024008258209895, -7.131595264957718, 3.209563621455084],
-    [1.0514855471606717, -7.088539771165396, 3.209563621455084],
-    [1.0998765008528215, -7.414765082333664, 3.511779346457675],
-    [0.7347263731643783, -7.459802055007862, 3.511779346457675],
-    [1.0514855471606717, -7.088539771165396, 3.209563621455084],
-    [1.3980371477179812, -7.028407364181584, 3.209563621455084],
-    [1.4623769297129006, -7.351865291119409, 3.511779346457675],
-    [1.0998765008528215, -7.414765082333664, 3.511779346457675],
-    [1.3980371477179812, -7.028407364181584, 3.209563621455084],
-    [1.7412207543328462, -6.9513429082416796, 3.209563621455084],
-    [1.82135436446021, -7.271254212471501, 3.511779346457675],
-    [1.4623769297129006, -7.351865291119409, 3.511779346457675],
-    [1.7412207543328462, -6.9513429082416796, 3.209563621455084],
-    [2.080209607637598, -6.857532058370094, 3.209563621455084],
-    [2.175943997011734, -7.1731260455390995, 3.511779346457675],
-    [1.82135436446021, -7.271254212471501, 3.511779346457675],
-    [2.080209607637598, -6.857532058370094, 3.209563621455084],
-    [2.4141870537927086, -6.747200813121025, 3.209563621455084],
-    [2.5252915898842723, -7.057717189671357, 3.511779346457675],
-    [2.175943997011734, -7.1731260455390995, 3.511779346457675],
-    [2.4141870537927086, -6.747200813121025, 3.209563621455084],
-    [2.7423485118774265, -6.620614970128154, 3.209563621455084],
-    [2.868555534127366, -6.925305674910679, 3.511779346457675],
-    [2.5252915898842723, -7.057717189671357, 3.511779346457675],
-    [2.7423485118774265, -6.620614970128154, 3.209563621455084],
-    [3.0639034121956654, -6.478079485774862, 3.209563621455084],
-    [3.2049088768329503, -6.776210492194008, 3.511779346457675],
-    [2.868555534127366, -6.925305674910679, 3.511779346457675],
-    [3.0639034121956654, -6.478079485774862, 3.209563621455084],
-    [3.3780771008276815, -6.319937740527591, 3.209563621455084],
-    [3.533541313337381, -6.6107908248757, 3.511779346457675],
-    [3.2049088768329503, -6.776210492194008, 3.511779346457675],
-    [3.3780771008276815, -6.319937740527591, 3.209563621455084],
-    [3.6841127058391954, -6.146570711702221, 3.209563621455084],
-    [3.8536611393163165, -6.429445183423378, 3.511779346457675],
-    [3.533541313337381, -6.6107908248757, 3.511779346457675],
-    [3.6841127058391954, -6.146570711702221, 3.209563621455084],
-    [3.98127296065218, -5.958396055656351, 3.209563621455084],
-    [4.164497158069787, -6.232610445371302, 3.511779346457675],
-    [3.8536611393163165, -6.429445183423378, 3.511779346457675],
-    [3.98127296065218, -5.958396055656351, 3.209563621455084],
-    [4.268841980184629, -5.755867101618549, 3.209563621455084],
-    [4.465300538402599, -6.020760802844127, 3.511779346457675],
-    [4.164497158069787, -6.232610445371302, 3.511779346457675],
-    [4.268841980184629, -5.755867101618549, 3.209563621455084],
-    [4.54612698548042, -5.53947175957853, 3.209563621455084],
-    [4.75534661862427, -5.794406620186534, 3.511779346457675],
-    [4.465300538402599, -6.020760802844127, 3.511779346457675],
-    [4.54612698548042, -5.53947175957853, 3.209563621455084],
-    [4.812459972674489, -5.309731344869268, 3.209563621455084],
-    [5.033936652322498, -5.554093204450837, 3.511779346457675],
-    [4.75534661862427, -5.794406620186534, 3.511779346457675],
-    [4.812459972674489, -5.309731344869268, 3.209563621455084],
-    [5.067199322272676, -5.067199322272677, 3.209563621455084],
-    [5.300399491704506, -5.300399491704509, 3.511779346457675],
-    [5.033936652322498, -5.554093204450837, 3.511779346457675],
-    [5.067199322272676, -5.067199322272677, 3.209563621455084],
-    [5.309731344869266, -4.812459972674492, 3.209563621455084],
-    [5.554093204450835, -5.033936652322501, 3.511779346457675],
-    [5.300399491704506, -5.300399491704509, 3.511779346457675],
-    [5.309731344869266, -4.812459972674492, 3.209563621455084],
-    [5.539471759578527, -4.546126985480423, 3.209563621455084],
-    [5.794406620186531, -4.755346618624272, 3.511779346457675],
-    [5.554093204450835, -5.033936652322501, 3.511779346457675],
-    [5.539471759578527, -4.546126985480423, 3.209563621455084],
-    [5.755867101618548, -4.268841980184631, 3.209563621455084],
-    [6.0207608028441255, -4.4653005384026025, 3.511779346457675],
-    [5.794406620186531, -4.755346618624272, 3.511779346457675],
-    [5.755867101618548, -4.268841980184631, 3.209563621455084],
-    [5.95839605565635, -3.981272960652183, 3.209563621455084],
-    [6.2326104453713, -4.16449715806979, 3.511779346457675],
-    [6.0207608028441255, -4.4653005384026025, 3.511779346457675],
-    [5.95839605565635, -3.981272960652183, 3.209563621455084],
-    [6.1465707117022195, -3.6841127058391976, 3.209563621455084],
-    [6.429445183423376, -3.8536611393163187, 3.511779346457675],
-    [6.2326104453713, -4.16449715806979, 3.511779346457675],
-    [6.1465707117022195, -3.6841127058391976, 3.209563621455084],
-    [6.319937740527589, -3.3780771008276838, 3.209563621455084],
-    [6.610790824875698, -3.533541313337383, 3.511779346457675],
-    [6.429445183423376, -3.8536611393163187, 3.511779346457675],
-    [6.319937740527589, -3.3780771008276838, 3.209563621455084],
-    [6.478079485774861, -3.063903412195668, 3.209563621455084],
-    [6.776210492194006, -3.204908876832953, 3.511779346457675],
-    [6.610790824875698, -3.533541313337383, 3.511779346457675],
-    [6.478079485774861, -3.063903412195668, 3.209563621455084],
-    [6.620614970128153, -2.7423485118774296, 3.209563621455084],
-    [6.925305674910678, -2.8685555341273687, 3.511779346457675],
-    [6.776210492194006, -3.204908876832953, 3.511779346457675],
-    [6.620614970128153, -2.7423485118774296, 3.209563621455084],
-    [6.747200813121024, -2.4141870537927113, 3.209563621455084],
-    [7.057717189671357, -2.5252915898842754, 3.511779346457675],
-    [6.925305674910678, -2.8685555341273687, 3.511779346457675],
-    [6.747200813121024, -2.4141870537927113, 3.209563621455084],
-    [6.857532058370093, -2.080209607637601, 3.209563621455084],
-    [7.173126045539099, -2.175943997011737, 3.511779346457675],
-    [7.057717189671357, -2.5252915898842754, 3.511779346457675],
-    [6.857532058370093, -2.080209607637601, 3.209563621455084],
-    [6.9513429082416796, -1.7412207543328493, 3.209563621455084],
-    [7.271254212471501, -1.821354364460213, 3.511779346457675],
-    [7.173126045539099, -2.175943997011737, 3.511779346457675],
-    [6.9513429082416796, -1.7412207543328493, 3.209563621455084],
-    [7.028407364181583, -1.3980371477179843, 3.209563621455084],
-    [7.351865291119408, -1.4623769297129037, 3.511779346457675],
-    [7.271254212471501, -1.821354364460213, 3.511779346457675],
-    [7.028407364181583, -1.3980371477179843, 3.209563621455084],
-    [7.088539771165396, -1.051485547160675, 3.209563621455084],
-    [7.414765082333664, -1.0998765008528248, 3.511779346457675],
-    [7.351865291119408, -1.4623769297129037, 3.511779346457675],
-    [7.088539771165396, -1.051485547160675, 3.209563621455084],
-    [7.131595264957718, -0.7024008258209925, 3.209563621455084],
-    [7.459802055007862, -0.7347263731643814, 3.511779346457675],
-    [7.414765082333664, -1.0998765008528248, 3.511779346457675],
-    [7.131595264957718, -0.7024008258209925, 3.209563621455084],
-    [7.157470121102785, -0.35162395936886764, 3.209563621455084],
-    [7.486867711130055, -0.36780622528856194, 3.511779346457675],
-    [7.459802055007862, -0.7347263731643814, 3.511779346457675],
-    [7.157470121102785, -0.35162395936886764, 3.209563621455084],
-    [
-        7.166102004805775,
-        -1.7551887765097647e-15,
-        3.209563621455084,
-    ],
-    [
-        7.495896847163974,
-        -1.8359652161236194e-15,
-        3.511779346457675,
-    ],
-    [7.486867711130055, -0.36780622528856194, 3.511779346457675],
-    [7.495896847163974, 0.0, 3.511779346457675],
-    [7.486867711130055, 0.36780622528856133, 3.511779346457675],
-    [7.816265301157324, 0.3839884912082556, 3.827590651216025],
-    [7.825691689522173, 0.0, 3.827590651216025],
-    [7.486867711130055, 0.36780622528856133, 3.511779346457675],
-    [7.459802055007862, 0.7347263731643822, 3.511779346457675],
-    [7.788008845058006, 0.767051920507771, 3.827590651216025],
-    [7.816265301157324, 0.3839884912082556, 3.827590651216025],
-    [7.459802055007862, 0.7347263731643822, 3.511779346457675],
-    [7.414765082333665, 1.0998765008528202, 3.511779346457675],
-    [7.740990393501933, 1.1482674545449698, 3.827590651216025],
-    [7.788008845058006, 0.767051920507771, 3.827590651216025],
-    [7.414765082333665, 1.0998765008528202, 3.511779346457675],
-    [7.351865291119409, 1.4623769297129001, 3.511779346457675],
-    [7.675323218057234, 1.5267167117078193, 3.827590651216025],
-    [7.740990393501933, 1.1482674545449698, 3.827590651216025],
-    [7.351865291119409, 1.4623769297129001, 3.511779346457675],
-    [7.271254212471501, 1.8213543644602108, 3.511779346457675],
-    [7.591165516701321, 1.9014879745875746, 3.827590651216025],
-    [7.675323218057234, 1.5267167117078193, 3.827590651216025],
-    [7.271254212471501, 1.8213543644602108, 3.511779346457675],
-    [7.173126045539099, 2.175943997011736, 3.511779346457675],
-    [7.4887200327081045, 2.271678386385872, 3.827590651216025],
-    [7.591165516701321, 1.9014879745875746, 3.827590651216025],
-    [7.173126045539099, 2.175943997011736, 3.511779346457675],
-    [7.057717189671357, 2.525291589884276, 3.511779346457675],
-    [7.368233566221689, 2.6363961259758395, 3.827590651216025],
-    [7.4887200327081045, 2.271678386385872, 3.827590651216025],
-    [7.057717189671357, 2.525291589884276, 3.511779346457675],
-    [6.92530567491068, 2.8685555341273643, 3.511779346457675],
-    [7.2299963796932065, 2.9947625563773035, 3.827590651216025],
-    [7.368233566221689, 2.6363961259758395, 3.827590651216025],
-    [6.92530567491068, 2.8685555341273643, 3.511779346457675],
-    [6.776210492194008, 3.20490887683295, 3.511779346457675],
-    [7.074341498613154, 3.3459143414702344, 3.827590651216025],
-    [7.2299963796932065, 2.9947625563773035, 3.827590651216025],
-    [6.776210492194008, 3.20490887683295, 3.511779346457675],
-    [6.6107908248757, 3.5335413133373814, 3.511779346457675],
-    [6.901643909223809, 3.6890055258470804, 3.827590651216025],
-    [7.074341498613154, 3.3459143414702344, 3.827590651216025],
-    [6.6107908248757, 3.5335413133373814, 3.511779346457675],
-    [6.429445183423377, 3.8536611393163174, 3.511779346457675],
-    [6.712319655144534, 4.023209572793438, 3.827590651216025],
-    [6.901643909223809, 3.6890055258470804, 3.827590651216025],
-    [6.429445183423377, 3.8536611393163174, 3.511779346457675],
-    [6.2326104453713, 4.16449715806979, 3.511779346457675],
-    [6.506824835086251, 4.347721355487398, 3.827590651216025],
-    [6.712319655144534, 4.023209572793438, 3.827590651216025],
-    [6.2326104453713, 4.16449715806979, 3.511779346457675],
-    [6.020760802844125, 4.465300538402603, 3.511779346457675],
-    [6.285654504069702, 4.661759096620574, 3.827590651216025],
-    [6.506824835086251, 4.347721355487398, 3.827590651216025],
-    [6.020760802844125, 4.465300538402603, 3.511779346457675],
-    [5.7944066201865345, 4.755346618624269, 3.511779346457675],
-    [6.049341480794538, 4.964566251768119, 3.827590651216025],
-    [6.285654504069702, 4.661759096620574, 3.827590651216025],
-    [5.7944066201865345, 4.755346618624269, 3.511779346457675],
-    [5.554093204450837, 5.033936652322498, 3.511779346457675],
-    [5.798455064032406, 5.255413331970507, 3.827590651216025],
-    [6.049341480794538, 4.964566251768119, 3.827590651216025],
-    [5.554093204450837, 5.033936652322498, 3.511779346457675],
-    [5.300399491704508, 5.300399491704508, 3.511779346457675],
-    [5.533599661136338, 5.533599661136338, 3.827590651216025],
-    [5.798455064032406, 5.255413331970507, 3.827590651216025],
-    [5.300399491704508, 5.300399491704508, 3.511779346457675],
-    [5.033936652322498, 5.554093204450837, 3.511779346457675],
-    [5.255413331970507, 5.798455064032405, 3.827590651216025],
-    [5.533599661136338, 5.533599661136338, 3.827590651216025],
-    [5.033936652322498, 5.554093204450837, 3.511779346457675],
-    [4.755346618624269, 5.794406620186534, 3.511779346457675],
-    [4.964566251768119, 6.049341480794537, 3.827590651216025],
-    [5.255413331970507, 5.798455064032405, 3.827590651216025],
-    [4.755346618624269, 5.794406620186534, 3.511779346457675],
-    [4.465300538402604, 6.020760802844124, 3.511779346457675],
-    [4.661759096620575, 6.285654504069701, 3.827590651216025],
-    [4.964566251768119, 6.049341480794537, 3.827590651216025],
-    [4.465300538402604, 6.020760802844124, 3.511779346457675],
-    [4.164497158069791, 6.2326104453713, 3.511779346457675],
-    [4.347721355487399, 6.506824835086251, 3.827590651216025],
-    [4.661759096620575, 6.285654504069701, 3.827590651216025],
-    [4.164497158069791, 6.2326104453713, 3.511779346457675],
-    [3.8536611393163174, 6.429445183423377, 3.511779346457675],
-    [4.023209572793438, 6.712319655144534, 3.827590651216025],
-    [4.347721355487399, 6.506824835086251, 3.827590651216025],
-    [3.8536611393163174, 6.429445183423377, 3.511779346457675],
-    [3.5335413133373823, 6.610790824875699, 3.511779346457675],
-    [3.6890055258470817, 6.901643909223808, 3.827590651216025],
-    [4.023209572793438, 6.712319655144534, 3.827590651216025],
-    [3.5335413133373823, 6.610790824875699, 3.511779346457675],
-    [3.2049088768329503, 6.776210492194008, 3.511779346457675],
-    [3.3459143414702353, 7.074341498613154, 3.827590651216025],
-    [3.6890055258470817, 6.901643909223808, 3.827590651216025],
-    [3.2049088768329503, 6.776210492194008, 3.511779346457675],
-    [2.8685555341273647, 6.92530567491068, 3.511779346457675],
-    [2.994762556377304, 7.2299963796932065, 3.827590651216025],
-    [3.3459143414702353, 7.074341498613154, 3.827590651216025],
-    [2.8685555341273647, 6.92530567491068, 3.511779346457675],
-    [2.525291589884276, 7.057717189671357, 3.511779346457675],
-    [2.6363961259758395, 7.368233566221689, 3.827590651216025],
-    [2.994762556377304, 7.2299963796932065, 3.827590651216025],
-    [2.525291589884276, 7.057717189671357, 3.511779346457675],
-    [2.175943997011736, 7.1731260455390995, 3.511779346457675],
-    [2.271678386385872, 7.488720032708105, 3.827590651216025],
-    [2.6363961259758395, 7.368233566221689, 3.827590651216025],
-    [2.175943997011736, 7.1731260455390995, 3.511779346457675],
-    [1.8213543644602117, 7.271254212471501, 3.511779346457675],
-    [1.9014879745875755, 7.591165516701321, 3.827590651216025],
-    [2.271678386385872, 7.488720032708105, 3.827590651216025],
-    [1.8213543644602117, 7.271254212471501, 3.511779346457675],
-    [1.4623769297129008, 7.351865291119409, 3.511779346457675],
-    [1.52671671170782, 7.675323218057234, 3.827590651216025],
-    [1.9014879745875755, 7.591165516701321, 3.827590651216025],
-    [1.4623769297129008, 7.351865291119409, 3.511779346457675],
-    [1.0998765008528202, 7.414765082333665, 3.511779346457675],
-    [1.1482674545449698, 7.740990393501933, 3.827590651216025],
-    [1.52671671170782, 7.675323218057234, 3.827590651216025],
-    [1.0998765008528202, 7.414765082333665, 3.511779346457675],
-    [0.7347263731643834, 7.459802055007861, 3.511779346457675],
-    [0.7670519205077723, 7.788008845058005, 3.827590651216025],
-    [1.1482674545449698, 7.740990393501933, 3.827590651216025],
-    [0.7347263731643834, 7.459802055007861, 3.511779346457675],
-    [0.36780622528856216, 7.486867711130055, 3.511779346457675],
-    [0.3839884912082565, 7.816265301157324, 3.827590651216025],
-    [0.7670519205077723, 7.788008845058005, 3.827590651216025],
-    [0.36780622528856216, 7.486867711130055, 3.511779346457675],
-    [4.589913040309048e-16, 7.495896847163974, 3.511779346457675],
-    [4.791854139343686e-16, 7.825691689522173, 3.827590651216025],
-    [0.3839884912082565, 7.816265301157324, 3.827590651216025],
-    [4.589913040309048e-16, 7.495896847163974, 3.511779346457675],
-    [-0.36780622528856133, 7.486867711130055, 3.511779346457675],
-    [-0.38398849120825557, 7.816265301157324, 3.827590651216025],
-    [4.791854139343686e-16, 7.825691689522173, 3.827590651216025],
-    [-0.36780622528856133, 7.486867711130055, 3.511779346457675],
-    [-0.7347263731643824, 7.459802055007862, 3.511779346457675],
-    [-0.7670519205077714, 7.788008845058006, 3.827590651216025],
-    [-0.38398849120825557, 7.816265301157324, 3.827590651216025],
-    [-0.7347263731643824, 7.459802055007862, 3.511779346457675],
-    [-1.0998765008528193, 7.414765082333665, 3.511779346457675],
-    [-1.148267454544969, 7.740990393501933, 3.827590651216025],
-    [-0.7670519205077714, 7.788008845058006, 3.827590651216025],
-    [-1.0998765008528193, 7.414765082333665, 3.511779346457675],
-    [-1.4623769297128997, 7.351865291119409, 3.511779346457675],
-    [-1.5267167117078189, 7.675323218057234, 3.827590651216025],
-    [-1.148267454544969, 7.740990393501933, 3.827590651216025],
-    [-1.4623769297128997, 7.351865291119409, 3.511779346457675],
-    [-1.8213543644602108, 7.271254212471501, 3.511779346457675],
-    [-1.9014879745875746, 7.591165516701321, 3.827590651216025],
-    [-1.5267167117078189, 7.675323218057234, 3.827590651216025],
-    [-1.8213543644602108, 7.271254212471501, 3.511779346457675],
-    [-2.1759439970117347, 7.1731260455390995, 3.511779346457675],
-    [-2.2716783863858705, 7.488720032708105, 3.827590651216025],
-    [-1.9014879745875746, 7.591165516701321, 3.827590651216025],
-    [-2.1759439970117347, 7.1731260455390995, 3.511779346457675],
-    [-2.525291589884275, 7.057717189671357, 3.511779346457675],
-    [-2.6363961259758386, 7.368233566221689, 3.827590651216025],
-    [-2.2716783863858705, 7.488720032708105, 3.827590651216025],
-    [-2.525291589884275, 7.057717189671357, 3.511779346457675],
-    [-2.868555534127364, 6.92530567491068, 3.511779346457675],
-    [-2.994762556377303, 7.2299963796932065, 3.827590651216025],
-    [-2.6363961259758386, 7.368233566221689, 3.827590651216025],
-    [-2.868555534127364, 6.92530567491068, 3.511779346457675],
-    [-3.204908876832948, 6.776210492194009, 3.511779346457675],
-    [-3.345914341470233, 7.074341498613155, 3.827590651216025],
-    [-2.994762556377303, 7.2299963796932065, 3.827590651216025],
-    [-3.204908876832948, 6.776210492194009, 3.511779346457675],
-    [-3.5335413133373814, 6.6107908248757, 3.511779346457675],
-    [-3.689005525847081, 6.901643909223809, 3.827590651216025],
-    [-3.345914341470233, 7.074341498613155, 3.827590651216025],
-    [-3.5335413133373814, 6.6107908248757, 3.511779346457675],
-    [-3.8536611393163165, 6.429445183423377, 3.511779346457675],
-    [-4.023209572793437, 6.712319655144534, 3.827590651216025],
-    [-3.689005525847081, 6.901643909223809, 3.827590651216025],
-    [-3.8536611393163165, 6.429445183423377, 3.511779346457675],
-    [-4.164497158069788, 6.232610445371301, 3.511779346457675],
-    [-4.347721355487396, 6.506824835086252, 3.827590651216025],
-    [-4.023209572793437, 6.712319655144534, 3.827590651216025],
-    [-4.164497158069788, 6.232610445371301, 3.511779346457675],
-    [-4.465300538402603, 6.020760802844125, 3.511779346457675],
-    [-4.661759096620574, 6.285654504069702, 3.827590651216025],
-    [-4.347721355487396, 6.506824835086252, 3.827590651216025],
-    [-4.465300538402603, 6.020760802844125, 3.511779346457675],
-    [-4.7553466186242686, 5.794406620186535, 3.511779346457675],
-    [-4.964566251768118, 6.0493414807945385, 3.827590651216025],
-    [-4.661759096620574, 6.285654504069702, 3.827590651216025],
-    [-4.7553466186242686, 5.794406620186535, 3.511779346457675],
-    [-5.033936652322499, 5.554093204450836, 3.511779346457675],
-    [-5.255413331970508, 5.798455064032404, 3.827590651216025],
-    [-4.964566251768118, 6.0493414807945385, 3.827590651216025],
-    [-5.033936652322499, 5.554093204450836, 3.511779346457675],
-    [-5.300399491704507, 5.300399491704508, 3.511779346457675],
-    [-5.533599661136338, 5.533599661136338, 3.827590651216025],
-    [-5.255413331970508, 5.798455064032404, 3.827590651216025],
-    [-5.300399491704507, 5.300399491704508, 3.511779346457675],
-    [-5.554093204450835, 5.0339366523225, 3.511779346457675],
-    [-5.798455064032403, 5.255413331970509, 3.827590651216025],
-    [-5.533599661136338, 5.533599661136338, 3.827590651216025],
-    [-5.554093204450835, 5.0339366523225, 3.511779346457675],
-    [-5.7944066201865345, 4.755346618624269, 3.511779346457675],
-    [-6.049341480794538, 4.964566251768119, 3.827590651216025],
-    [-5.798455064032403, 5.255413331970509, 3.827590651216025],
-    [-5.7944066201865345, 4.755346618624269, 3.511779346457675],
-    [-6.020760802844124, 4.465300538402604, 3.511779346457675],
-    [-6.285654504069701, 4.661759096620575, 3.827590651216025],
-    [-6.049341480794538, 4.964566251768119, 3.827590651216025],
-    [-6.020760802844124, 4.465300538402604, 3.511779346457675],
-    [-6.232610445371301, 4.16449715806979, 3.511779346457675],
-    [-6.506824835086252, 4.347721355487398, 3.827590651216025],
-    [-6.285654504069701, 4.661759096620575, 3.827590651216025],
-    [-6.232610445371301, 4.16449715806979, 3.511779346457675],
-    [-6.429445183423376, 3.853661139316318, 3.511779346457675],
-    [-6.712319655144533, 4.023209572793439, 3.827590651216025],
-    [-6.506824835086252, 4.347721355487398, 3.827590651216025],
-    [-6.429445183423376, 3.853661139316318, 3.511779346457675],
-    [-6.610790824875699, 3.5335413133373823, 3.511779346457675],
-    [-6.901643909223808, 3.6890055258470817, 3.827590651216025],
-    [-6.712319655144533, 4.023209572793439, 3.827590651216025],
-    [-6.610790824875699, 3.5335413133373823, 3.511779346457675],
-    [-6.776210492194008, 3.2049088768329494, 3.511779346457675],
-    [-7.074341498613154, 3.3459143414702344, 3.827590651216025],
-    [-6.901643909223808, 3.6890055258470817, 3.827590651216025],
-    [-6.776210492194008, 3.2049088768329494, 3.511779346457675],
-    [-6.92530567491068, 2.868555534127365, 3.511779346457675],
-    [-7.2299963796932065, 2.9947625563773044, 3.827590651216025],
-    [-7.074341498613154, 3.3459143414702344, 3.827590651216025],
-    [-6.92530567491068, 2.868555534127365, 3.511779346457675],
-    [-7.057717189671356, 2.5252915898842776, 3.511779346457675],
-    [-7.368233566221688, 2.6363961259758417, 3.827590651216025],
-    [-7.2299963796932065, 2.9947625563773044, 3.827590651216025],
-    [-7.057717189671356, 2.5252915898842776, 3.511779346457675],
-    [-7.173126045539099, 2.1759439970117365, 3.511779346457675],
-    [-7.4887200327081045, 2.2716783863858723, 3.827590651216025],
-    [-7.368233566221688, 2.6363961259758417, 3.827590651216025],
-    [-7.173126045539099, 2.1759439970117365, 3.511779346457675],
-    [-7.271254212471501, 1.8213543644602121, 3.511779346457675],
-    [-7.591165516701321, 1.9014879745875761, 3.827590651216025],
-    [-7.4887200327081045, 2.2716783863858723, 3.827590651216025],
-    [-7.271254212471501, 1.8213543644602121, 3.511779346457675],
-    [-7.351865291119409, 1.4623769297129028, 3.511779346457675],
-    [-7.675323218057234, 1.5267167117078222, 3.827590651216025],
-    [-7.591165516701321, 1.9014879745875761, 3.827590651216025],
-    [-7.351865291119409, 1.4623769297129028, 3.511779346457675],
-    [-7.414765082333665, 1.0998765008528206, 3.511779346457675],
-    [-7.740990393501933, 1.1482674545449703, 3.827590651216025],
-    [-7.675323218057234, 1.5267167117078222, 3.827590651216025],
-    [-7.414765082333665, 1.0998765008528206, 3.511779346457675],
-    [-7.459802055007861, 0.7347263731643837, 3.511779346457675],
-    [-7.788008845058005, 0.7670519205077727, 3.827590651216025],
-    [-7.740990393501933, 1.1482674545449703, 3.827590651216025],
-    [-7.459802055007861, 0.7347263731643837, 3.511779346457675],
-    [-7.486867711130055, 0.367806225288561, 3.511779346457675],
-    [-7.816265301157324, 0.38398849120825523, 3.827590651216025],
-    [-7.788008845058005, 0.7670519205077727, 3.827590651216025],
-    [-7.486867711130055, 0.367806225288561, 3.511779346457675],
-    [-7.495896847163974, 9.179826080618097e-16, 3.511779346457675],
-    [-7.825691689522173, 9.583708278687371e-16, 3.827590651216025],
-    [-7.816265301157324, 0.38398849120825523, 3.827590651216025],
-    [-7.495896847163974, 9.179826080618097e-16, 3.511779346457675],
-    [-7.486867711130055, -0.36780622528855916, 3.511779346457675],
-    [-7.816265301157324, -0.38398849120825335, 3.827590651216025],
-    [-7.825691689522173, 9.583708278687371e-16, 3.827590651216025],
-    [-7.486867711130055, -0.36780622528855916, 3.511779346457675],
-    [-7.459802055007862, -0.734726373164382, 3.511779346457675],
-    [-7.788008845058006, -0.7670519205077709, 3.827590651216025],
-    [-7.816265301157324, -0.38398849120825335, 3.827590651216025],
-    [-7.459802055007862, -0.734726373164382, 3.511779346457675],
-    [-7.414765082333665, -1.0998765008528189, 3.511779346457675],
-    [-7.740990393501933, -1.1482674545449685, 3.827590651216025],
-    [-7.788008845058006, -0.7670519205077709, 3.827590651216025],
-    [-7.414765082333665, -1.0998765008528189, 3.511779346457675],
-    [-7.351865291119409, -1.462376929712901, 3.511779346457675],
-    [-7.675323218057234, -1.5267167117078202, 3.827590651216025],
-    [-7.740990393501933, -1.1482674545449685, 3.827590651216025],
-    [-7.351865291119409, -1.462376929712901, 3.511779346457675],
-    [-7.271254212471501, -1.8213543644602104, 3.511779346457675],
-    [-7.591165516701321, -1.9014879745875741, 3.827590651216025],
-    [-7.675323218057234, -1.5267167117078202, 3.827590651216025],
-    [-7.271254212471501, -1.8213543644602104, 3.511779346457675],
-    [-7.1731260455390995, -2.1759439970117342, 3.511779346457675],
-    [-7.488720032708105, -2.27167838638587, 3.827590651216025],
-    [-7.591165516701321, -1.9014879745875741, 3.827590651216025],
-    [-7.1731260455390995, -2.1759439970117342, 3.511779346457675],
-    [-7.057717189671357, -2.5252915898842763, 3.511779346457675],
-    [-7.368233566221689, -2.63639612597584, 3.827590651216025],
-    [-7.488720032708105, -2.27167838638587, 3.827590651216025],
-    [-7.057717189671357, -2.5252915898842763, 3.511779346457675],
-    [-6.925305674910681, -2.8685555341273634, 3.511779346457675],
-    [-7.229996379693207, -2.9947625563773026, 3.827590651216025],
-    [-7.368233566221689, -2.63639612597584, 3.827590651216025],
-    [-6.925305674910681, -2.8685555341273634, 3.511779346457675],
-    [-6.776210492194009, -3.2049088768329477, 3.511779346457675],
-    [-7.074341498613155, -3.3459143414702326, 3.827590651216025],
-    [-7.229996379693207, -2.9947625563773026, 3.827590651216025],
-    [-6.776210492194009, -3.2049088768329477, 3.511779346457675],
-    [-6.6107908248757, -3.5335413133373814, 3.511779346457675],
-    [-6.901643909223809, -3.6890055258470804, 3.827590651216025],
-    [-7.074341498613155, -3.3459143414702326, 3.827590651216025],
-    [-6.6107908248757, -3.5335413133373814, 3.511779346457675],
-    [-6.429445183423377, -3.8536611393163165, 3.511779346457675],
-    [-6.712319655144534, -4.023209572793437, 3.827590651216025],
-    [-6.901643909223809, -3.6890055258470804, 3.827590651216025],
-    [-6.429445183423377, -3.8536611393163165, 3.511779346457675],
-    [-6.232610445371302, -4.164497158069788, 3.511779346457675],
-    [-6.5068248350862525, -4.347721355487396, 3.827590651216025],
-    [-6.712319655144534, -4.023209572793437, 3.827590651216025],
-    [-6.232610445371302, -4.164497158069788, 3.511779346457675],
-    [-6.020760802844125, -4.4653005384026025, 3.511779346457675],
-    [-6.285654504069702, -4.661759096620573, 3.827590651216025],
-    [-6.5068248350862525, -4.347721355487396, 3.827590651216025],
-    [-6.020760802844125, -4.4653005384026025, 3.511779346457675],
-    [-5.794406620186535, -4.755346618624268, 3.511779346457675],
-    [-6.0493414807945385, -4.964566251768117, 3.827590651216025],
-    [-6.285654504069702, -4.661759096620573, 3.827590651216025],
-    [-5.794406620186535, -4.755346618624268, 3.511779346457675],
-    [-5.554093204450837, -5.033936652322499, 3.511779346457675],
-    [-5.798455064032405, -5.255413331970508, 3.827590651216025],
-    [-6.0493414807945385, -4.964566251768117, 3.827590651216025],
-    [-5.554093204450837, -5.033936652322499, 3.511779346457675],
-    [-5.300399491704509, -5.300399491704507, 3.511779346457675],
-    [-5.533599661136339, -5.533599661136338, 3.827590651216025],
-    [-5.798455064032405, -5.255413331970508, 3.827590651216025],
-    [-5.300399491704509, -5.300399491704507, 3.511779346457675],
-    [-5.033936652322501, -5.554093204450835, 3.511779346457675],
-    [-5.25541333197051, -5.798455064032403, 3.827590651216025],
-    [-5.533599661136339, -5.533599661136338, 3.827590651216025],
-    [-5.033936652322501, -5.554093204450835, 3.511779346457675],
-    [-4.755346618624272, -5.794406620186532, 3.511779346457675],
-    [-4.964566251768122, -6.049341480794535, 3.827590651216025],
-    [-5.25541333197051, -5.798455064032403, 3.827590651216025],
-    [-4.755346618624272, -5.794406620186532, 3.511779346457675],
-    [-4.465300538402602, -6.0207608028441255, 3.511779346457675],
-    [-4.661759096620572, -6.2856545040697025, 3.827590651216025],
-    [-4.964566251768122, -6.049341480794535, 3.827590651216025],
-    [-4.465300538402602, -6.0207608028441255, 3.511779346457675],
-    [-4.16449715806979, -6.2326104453713, 3.511779346457675],
-    [-4.347721355487398, -6.506824835086251, 3.827590651216025],
-    [-4.661759096620572, -6.2856545040697025, 3.827590651216025],
-    [-4.16449715806979, -6.2326104453713, 3.511779346457675],
-    [-3.853661139316318, -6.429445183423376, 3.511779346457675],
-    [-4.023209572793439, -6.712319655144533, 3.827590651216025],
-    [-4.347721355487398, -6.506824835086251, 3.827590651216025],
-    [-3.853661139316318, -6.429445183423376, 3.511779346457675],
-    [-3.5335413133373827, -6.610790824875699, 3.511779346457675],
-    [-3.689005525847082, -6.901643909223808, 3.827590651216025],
-    [-4.023209572793439, -6.712319655144533, 3.827590651216025],
-    [-3.5335413133373827, -6.610790824875699, 3.511779346457675],
-    [-3.2049088768329526, -6.776210492194006, 3.511779346457675],
-    [-3.3459143414702375, -7.074341498613152, 3.827590651216025],
-    [-3.689005525847082, -6.901643909223808, 3.827590651216025],
-    [-3.2049088768329526, -6.776210492194006, 3.511779346457675],
-    [-2.8685555341273683, -6.925305674910678, 3.511779346457675],
-    [-2.994762556377308, -7.229996379693205, 3.827590651216025],
-    [-3.3459143414702375, -7.074341498613152, 3.827590651216025],
-    [-2.8685555341273683, -6.925305674910678, 3.511779346457675],
-    [-2.525291589884275, -7.057717189671357, 3.511779346457675],
-    [-2.6363961259758386, -7.368233566221689, 3.827590651216025],
-    [-2.994762556377308, -7.229996379693205, 3.827590651216025],
-    [-2.525291589884275, -7.057717189671357, 3.511779346457675],
-    [-2.175943997011737, -7.173126045539099, 3.511779346457675],
-    [-2.2716783863858727, -7.4887200327081045, 3.827590651216025],
-    [-2.6363961259758386, -7.368233566221689, 3.827590651216025],
-    [-2.175943997011737, -7.173126045539099, 3.511779346457675],
-    [-1.8213543644602126, -7.271254212471501, 3.511779346457675],
-    [-1.9014879745875763, -7.591165516701321, 3.827590651216025],
-    [-2.2716783863858727, -7.4887200327081045, 3.827590651216025],
-    [-1.8213543644602126, -7.271254212471501, 3.511779346457675],
-    [-1.4623769297129032, -7.351865291119408, 3.511779346457675],
-    [-1.5267167117078226, -7.675323218057233, 3.827590651216025],
-    [-1.9014879745875763, -7.591165516701321, 3.827590651216025],
-    [-1.4623769297129032, -7.351865291119408, 3.511779346457675],
-    [-1.0998765008528242, -7.414765082333664, 3.511779346457675],
-    [-1.1482674545449743, -7.740990393501932, 3.827590651216025],
-    [-1.5267167117078226, -7.675323218057233, 3.827590651216025],
-    [-1.0998765008528242, -7.414765082333664, 3.511779346457675],
-    [-0.7347263731643809, -7.459802055007862, 3.511779346457675],
-    [-0.7670519205077698, -7.788008845058006, 3.827590651216025],
-    [-1.1482674545449743, -7.740990393501932, 3.827590651216025],
-    [-0.7347263731643809, -7.459802055007862, 3.511779346457675],
-    [-0.36780622528856144, -7.486867711130055, 3.511779346457675],
-    [-0.38398849120825573, -7.816265301157324, 3.827590651216025],
-    [-0.7670519205077698, -7.788008845058006, 3.827590651216025],
-    [-0.36780622528856144, -7.486867711130055, 3.511779346457675],
-    [
-        -1.3769739120927145e-15,
-        -7.495896847163974,
-        3.511779346457675,
-    ],
-    [
-        -1.4375562418031056e-15,
-        -7.825691689522173,
-        3.827590651216025,
-    ],
-    [-0.38398849120825573, -7.816265301157324, 3.827590651216025],
-    [
-        -1.3769739120927145e-15,
-        -7.495896847163974,
-        3.511779346457675,
-    ],
-    [0.3678062252885587, -7.486867711130055, 3.511779346457675],
-    [0.38398849120825285, -7.816265301157324, 3.827590651216025],
-    [
-        -1.4375562418031056e-15,
-        -7.825691689522173,
-        3.827590651216025,
-    ],
-    [0.3678062252885587, -7.486867711130055, 3.511779346457675],
-    [0.7347263731643783, -7.459802055007862, 3.511779346457675],
-    [0.7670519205077669, -7.788008845058006, 3.827590651216025],
-    [0.38398849120825285, -7.816265301157324, 3.827590651216025],
-    [0.7347263731643783, -7.459802055007862, 3.511779346457675],
-    [1.0998765008528215, -7.414765082333664, 3.511779346457675],
-    [1.1482674545449714, -7.740990393501932, 3.827590651216025],
-    [0.7670519205077669, -7.788008845058006, 3.827590651216025],
-    [1.0998765008528215, -7.414765082333664, 3.511779346457675],
-    [1.4623769297129006, -7.351865291119409, 3.511779346457675],
-    [1.5267167117078198, -7.675323218057234, 3.827590651216025],
-    [1.1482674545449714, -7.740990393501932, 3.827590651216025],
-    [1.4623769297129006, -7.351865291119409, 3.511779346457675],
-    [1.82135436446021, -7.271254212471501, 3.511779346457675],
-    [1.9014879745875737, -7.591165516701321, 3.827590651216025],
-    [1.5267167117078198, -7.675323218057234, 3.827590651216025],
-    [1.82135436446021, -7.271254212471501, 3.511779346457675],
-    [2.175943997011734, -7.1731260455390995, 3.511779346457675],
-    [2.2716783863858696, -7.488720032708105, 3.827590651216025],
-    [1.9014879745875737, -7.591165516701321, 3.827590651216025],
-    [2.175943997011734, -7.1731260455390995, 3.511779346457675],
-    [2.5252915898842723, -7.057717189671357, 3.511779346457675],
-    [2.636396125975836, -7.36823356622169, 3.827590651216025],
-    [2.2716783863858696, -7.488720032708105, 3.827590651216025],
-    [2.5252915898842723, -7.057717189671357, 3.511779346457675],
-    [2.868555534127366, -6.925305674910679, 3.511779346457675],
-    [2.9947625563773053, -7.229996379693206, 3.827590651216025],
-    [2.636396125975836, -7.36823356622169, 3.827590651216025],
-    [2.868555534127366, -6.925305674910679, 3.511779346457675],
-    [3.2049088768329503, -6.776210492194008, 3.511779346457675],
-    [3.345914341470235, -7.074341498613154, 3.827590651216025],
-    [2.9947625563773053, -7.229996379693206, 3.827590651216025],
-    [3.2049088768329503, -6.776210492194008, 3.511779346457675],
-    [3.533541313337381, -6.6107908248757, 3.511779346457675],
-    [3.68900552584708, -6.901643909223809, 3.827590651216025],
-    [3.345914341470235, -7.074341498613154, 3.827590651216025],
-    [3.533541313337381, -6.6107908248757, 3.511779346457675],
-    [3.8536611393163165, -6.429445183423378, 3.511779346457675],
-    [4.023209572793437, -6.712319655144535, 3.827590651216025],
-    [3.68900552584708, -6.901643909223809, 3.827590651216025],
-    [3.8536611393163165, -6.429445183423378, 3.511779346457675],
-    [4.164497158069787, -6.232610445371302, 3.511779346457675],
-    [4.347721355487395, -6.5068248350862525, 3.827590651216025],
-    [4.023209572793437, -6.712319655144535, 3.827590651216025],
-    [4.164497158069787, -6.232610445371302, 3.511779346457675],
-    [4.465300538402599, -6.020760802844127, 3.511779346457675],
-    [4.661759096620569, -6.285654504069704, 3.827590651216025],
-    [4.347721355487395, -6.5068248350862525, 3.827590651216025],
-    [4.465300538402599, -6.020760802844127, 3.511779346457675],
-    [4.75534661862427, -5.794406620186534, 3.511779346457675],
-    [4.96456625176812, -6.049341480794537, 3.827590651216025],
-    [4.661759096620569, -6.285654504069704, 3.827590651216025],
-    [4.75534661862427, -5.794406620186534, 3.511779346457675],
-    [5.033936652322498, -5.554093204450837, 3.511779346457675],
-    [5.255413331970507, -5.798455064032405, 3.827590651216025],
-    [4.96456625176812, -6.049341480794537, 3.827590651216025],
-    [5.033936652322498, -5.554093204450837, 3.511779346457675],
-    [5.300399491704506, -5.300399491704509, 3.511779346457675],
-    [5.533599661136337, -5.533599661136339, 3.827590651216025],
-    [5.255413331970507, -5.798455064032405, 3.827590651216025],
-    [5.300399491704506, -5.300399491704509, 3.511779346457675],
-    [5.554093204450835, -5.033936652322501, 3.511779346457675],
-    [5.798455064032403, -5.25541333197051, 3.827590651216025],
-    [5.533599661136337, -5.533599661136339, 3.827590651216025],
-    [5.554093204450835, -5.033936652322501, 3.511779346457675],
-    [5.794406620186531, -4.755346618624272, 3.511779346457675],
-    [6.049341480794534, -4.964566251768122, 3.827590651216025],
-    [5.798455064032403, -5.25541333197051, 3.827590651216025],
-    [5.794406620186531, -4.755346618624272, 3.511779346457675],
-    [6.0207608028441255, -4.4653005384026025, 3.511779346457675],
-    [6.2856545040697025, -4.661759096620573, 3.827590651216025],
-    [6.049341480794534, -4.964566251768122, 3.827590651216025],
-    [6.0207608028441255, -4.4653005384026025, 3.511779346457675],
-    [6.2326104453713, -4.16449715806979, 3.511779346457675],
-    [6.506824835086251, -4.347721355487398, 3.827590651216025],
-    [6.2856545040697025, -4.661759096620573, 3.827590651216025],
-    [6.2326104453713, -4.16449715806979, 3.511779346457675],
-    [6.429445183423376, -3.8536611393163187, 3.511779346457675],
-    [6.712319655144533, -4.02320957279344, 3.827590651216025],
-    [6.506824835086251, -4.347721355487398, 3.827590651216025],
-    [6.429445183423376, -3.8536611393163187, 3.511779346457675],
-    [6.610790824875698, -3.533541313337383, 3.511779346457675],
-    [6.901643909223807, -3.6890055258470826, 3.827590651216025],
-    [6.712319655144533, -4.02320957279344, 3.827590651216025],
-    [6.610790824875698, -3.533541313337383, 3.511779346457675],
-    [6.776210492194006, -3.204908876832953, 3.511779346457675],
-    [7.074341498613152, -3.345914341470238, 3.827590651216025],
-    [6.901643909223807, -3.6890055258470826, 3.827590651216025],
-    [6.776210492194006, -3.204908876832953, 3.511779346457675],
-    [6.925305674910678, -2.8685555341273687, 3.511779346457675],
-    [7.229996379693205, -2.9947625563773084, 3.827590651216025],
-    [7.074341498613152, -3.345914341470238, 3.827590651216025],
-    [6.925305674910678, -2.8685555341273687, 3.511779346457675],
-    [7.057717189671357, -2.5252915898842754, 3.511779346457675],
-    [7.368233566221689, -2.636396125975839, 3.827590651216025],
-    [7.229996379693205, -2.9947625563773084, 3.827590651216025],
-    [7.057717189671357, -2.5252915898842754, 3.511779346457675],
-    [7.173126045539099, -2.175943997011737, 3.511779346457675],
-    [7.4887200327081045, -2.271678386385873, 3.827590651216025],
-    [7.368233566221689, -2.636396125975839, 3.827590651216025],
-    [7.173126045539099, -2.175943997011737, 3.511779346457675],
-    [7.271254212471501, -1.821354364460213, 3.511779346457675],
-    [7.591165516701321, -1.9014879745875768, 3.827590651216025],
-    [7.4887200327081045, -2.271678386385873, 3.827590651216025],
-    [7.271254212471501, -1.821354364460213, 3.511779346457675],
-    [7.351865291119408, -1.4623769297129037, 3.511779346457675],
-    [7.675323218057233, -1.526716711707823, 3.827590651216025],
-    [7.591165516701321, -1.9014879745875768, 3.827590651216025],
-    [7.351865291119408, -1.4623769297129037, 3.511779346457675],
-    [7.414765082333664, -1.0998765008528248, 3.511779346457675],
-    [7.740990393501932, -1.148267454544975, 3.827590651216025],
-    [7.675323218057233, -1.526716711707823, 3.827590651216025],
-    [7.414765082333664, -1.0998765008528248, 3.511779346457675],
-    [7.459802055007862, -0.7347263731643814, 3.511779346457675],
-    [7.788008845058006, -0.7670519205077703, 3.827590651216025],
-    [7.740990393501932, -1.148267454544975, 3.827590651216025],
-    [7.459802055007862, -0.7347263731643814, 3.511779346457675],
-    [7.486867711130055, -0.36780622528856194, 3.511779346457675],
-    [7.816265301157324, -0.38398849120825623, 3.827590651216025],
-    [7.788008845058006, -0.7670519205077703, 3.827590651216025],
-    [7.486867711130055, -0.36780622528856194, 3.511779346457675],
-    [
-        7.495896847163974,
-        -1.8359652161236194e-15,
-        3.511779346457675,
-    ],
-    [
-        7.825691689522173,
-        -1.9167416557374743e-15,
-        3.827590651216025,
-    ],
-    [7.816265301157324, -0.38398849120825623, 3.827590651216025],
-    [7.825691689522173, 0.0, 3.827590651216025],
-    [7.816265301157324, 0.3839884912082556, 3.827590651216025],
-    [8.145662891184594, 0.40017075712794986, 4.156997535730132],
-    [8.15548653188037, 0.0, 4.156997535730132],
-    [7.816265301157324, 0.3839884912082556, 3.827590651216025],
-    [7.788008845058006, 0.767051920507771, 3.827590651216025],
-    [8.11621563510815, 0.7993774678511598, 4.156997535730132],
-    [8.145662891184594, 0.40017075712794986, 4.156997535730132],
-    [7.788008845058006, 0.767051920507771, 3.827590651216025],
-    [7.740990393501933, 1.1482674545449698, 3.827590651216025],
-    [8.0672157046702, 1.1966584082371194, 4.156997535730132],
-    [8.11621563510815, 0.7993774678511598, 4.156997535730132],
-    [7.740990393501933, 1.1482674545449698, 3.827590651216025],
-    [7.675323218057234, 1.5267167117078193, 3.827590651216025],
-    [7.998781144995059, 1.5910564937027385, 4.156997535730132],
-    [8.0672157046702, 1.1966584082371194, 4.156997535730132],
-    [7.675323218057234, 1.5267167117078193, 3.827590651216025],
-    [7.591165516701321, 1.9014879745875746, 3.827590651216025],
-    [7.911076820931141, 1.981621584714938, 4.156997535730132],
-    [7.998781144995059, 1.5910564937027385, 4.156997535730132],
-    [7.591165516701321, 1.9014879745875746, 3.827590651216025],
-    [7.4887200327081045, 2.271678386385872, 3.827590651216025],
-    [7.8043140198771095, 2.3674127757600076, 4.156997535730132],
-    [7.911076820931141, 1.981621584714938, 4.156997535730132],
-    [7.4887200327081045, 2.271678386385872, 3.827590651216025],
-    [7.368233566221689, 2.6363961259758395, 3.827590651216025],
-    [7.67874994277202, 2.747500662067403, 4.156997535730132],
-    [7.8043140198771095, 2.3674127757600076, 4.156997535730132],
-    [7.368233566221689, 2.6363961259758395, 3.827590651216025],
-    [7.2299963796932065, 2.9947625563773035, 3.827590651216025],
-    [7.534687084475732, 3.1209695786272427, 4.156997535730132],
-    [7.67874994277202, 2.747500662067403, 4.156997535730132],
-    [7.2299963796932065, 2.9947625563773035, 3.827590651216025],
-    [7.074341498613154, 3.3459143414702344, 3.827590651216025],
-    [7.372472505032299, 3.4869198061075193, 4.156997535730132],
-    [7.534687084475732, 3.1209695786272427, 4.156997535730132],
-    [7.074341498613154, 3.3459143414702344, 3.827590651216025],
-    [6.901643909223809, 3.6890055258470804, 3.827590651216025],
-    [7.192496993571917, 3.8444697383567794, 4.156997535730132],
-    [7.372472505032299, 3.4869198061075193, 4.156997535730132],
-    [6.901643909223809, 3.6890055258470804, 3.827590651216025],
-    [6.712319655144534, 4.023209572793438, 3.827590651216025],
-    [6.99519412686569, 4.192758006270559, 4.156997535730132],
-    [7.192496993571917, 3.8444697383567794, 4.156997535730132],
-    [6.712319655144534, 4.023209572793438, 3.827590651216025],
-    [6.506824835086251, 4.347721355487398, 3.827590651216025],
-    [6.781039224801201, 4.5309455529050044, 4.156997535730132],
-    [6.99519412686569, 4.192758006270559, 4.156997535730132],
-    [6.506824835086251, 4.347721355487398, 3.827590651216025],
-    [6.285654504069702, 4.661759096620574, 3.827590651216025],
-    [6.550548205295279, 4.858217654838544, 4.156997535730132],
-    [6.781039224801201, 4.5309455529050044, 4.156997535730132],
-    [6.285654504069702, 4.661759096620574, 3.827590651216025],
-    [6.049341480794538, 4.964566251768119, 3.827590651216025],
-    [6.304276341402541, 5.173785884911967, 4.156997535730132],
-    [6.550548205295279, 4.858217654838544, 4.156997535730132],
-    [6.049341480794538, 4.964566251768119, 3.827590651216025],
-    [5.798455064032406, 5.255413331970507, 3.827590651216025],
-    [6.042816923613974, 5.476890011618516, 4.156997535730132],
-    [6.304276341402541, 5.173785884911967, 4.156997535730132],
-    [5.798455064032406, 5.255413331970507, 3.827590651216025],
-    [5.533599661136338, 5.533599661136338, 3.827590651216025],
-    [5.766799830568169, 5.766799830568169, 4.156997535730132],
-    [6.042816923613974, 5.476890011618516, 4.156997535730132],
-    [5.533599661136338, 5.533599661136338, 3.827590651216025],
-    [5.255413331970507, 5.798455064032405, 3.827590651216025],
-    [5.476890011618516, 6.042816923613973, 4.156997535730132],
-    [5.766799830568169, 5.766799830568169, 4.156997535730132],
-    [5.255413331970507, 5.798455064032405, 3.827590651216025],
-    [4.964566251768119, 6.049341480794537, 3.827590651216025],
-    [5.173785884911967, 6.30427634140254, 4.156997535730132],
-    [5.476890011618516, 6.042816923613973, 4.156997535730132],
-    [4.964566251768119, 6.049341480794537, 3.827590651216025],
-    [4.661759096620575, 6.285654504069701, 3.827590651216025],
-    [4.858217654838545, 6.550548205295278, 4.156997535730132],
-    [5.173785884911967, 6.30427634140254, 4.156997535730132],
-    [4.661759096620575, 6.285654504069701, 3.827590651216025],
-    [4.347721355487399, 6.506824835086251, 3.827590651216025],
-    [4.530945552905005, 6.781039224801201, 4.156997535730132],
-    [4.858217654838545, 6.550548205295278, 4.156997535730132],
-    [4.347721355487399, 6.506824835086251, 3.827590651216025],
-    [4.023209572793438, 6.712319655144534, 3.827590651216025],
-    [4.192758006270559, 6.99519412686569, 4.156997535730132],
-    [4.530945552905005, 6.781039224801201, 4.156997535730132],
-    [4.023209572793438, 6.712319655144534, 3.827590651216025],
-    [3.6890055258470817, 6.901643909223808, 3.827590651216025],
-    [3.8444697383567807, 7.1924969935719165, 4.156997535730132],
-    [4.192758006270559, 6.99519412686569, 4.156997535730132],
-    [3.6890055258470817, 6.901643909223808, 3.827590651216025],
-    [3.3459143414702353, 7.074341498613154, 3.827590651216025],
-    [3.48691980610752, 7.372472505032299, 4.156997535730132],
-    [3.8444697383567807, 7.1924969935719165, 4.156997535730132],
-    [3.3459143414702353, 7.074341498613154, 3.827590651216025],
-    [2.994762556377304, 7.2299963796932065, 3.827590651216025],
-    [3.120969578627243, 7.534687084475732, 4.156997535730132],
-    [3.48691980610752, 7.372472505032299, 4.156997535730132],
-    [2.994762556377304, 7.2299963796932065, 3.827590651216025],
-    [2.6363961259758395, 7.368233566221689, 3.827590651216025],
-    [2.747500662067403, 7.67874994277202, 4.156997535730132],
-    [3.120969578627243, 7.534687084475732, 4.156997535730132],
-    [2.6363961259758395, 7.368233566221689, 3.827590651216025],
-    [2.271678386385872, 7.488720032708105, 3.827590651216025],
-    [2.3674127757600076, 7.80431401987711, 4.156997535730132],
-    [2.747500662067403, 7.67874994277202, 4.156997535730132],
-    [2.271678386385872, 7.488720032708105, 3.827590651216025],
-    [1.9014879745875755, 7.591165516701321, 3.827590651216025],
-    [1.981621584714939, 7.911076820931141, 4.156997535730132],
-    [2.3674127757600076, 7.80431401987711, 4.156997535730132],
-    [1.9014879745875755, 7.591165516701321, 3.827590651216025],
-    [1.52671671170782, 7.675323218057234, 3.827590651216025],
-    [1.5910564937027392, 7.998781144995059, 4.156997535730132],
-    [1.981621584714939, 7.911076820931141, 4.156997535730132],
-    [1.52671671170782, 7.675323218057234, 3.827590651216025],
-    [1.1482674545449698, 7.740990393501933, 3.827590651216025],
-    [1.1966584082371194, 8.0672157046702, 4.156997535730132],
-    [1.5910564937027392, 7.998781144995059, 4.156997535730132],
-    [1.1482674545449698, 7.740990393501933, 3.827590651216025],
-    [0.7670519205077723, 7.788008845058005, 3.827590651216025],
-    [0.7993774678511611, 8.11621563510815, 4.156997535730132],
-    [1.1966584082371194, 8.0672157046702, 4.156997535730132],
-    [0.7670519205077723, 7.788008845058005, 3.827590651216025],
-    [0.3839884912082565, 7.816265301157324, 3.827590651216025],
-    [0.40017075712795075, 8.145662891184594, 4.156997535730132],
-    [0.7993774678511611, 8.11621563510815, 4.156997535730132],
-    [0.3839884912082565, 7.816265301157324, 3.827590651216025],
-    [4.791854139343686e-16, 7.825691689522173, 3.827590651216025],
-    [4.993795238378322e-16, 8.15548653188037, 4.156997535730132],
-    [0.40017075712795075, 8.145662891184594, 4.156997535730132],
-    [4.791854139343686e-16, 7.825691689522173, 3.827590651216025],
-    [-0.38398849120825557, 7.816265301157324, 3.827590651216025],
-    [-0.4001707571279498, 8.145662891184594, 4.156997535730132],
-    [4.993795238378322e-16, 8.15548653188037, 4.156997535730132],
-    [-0.38398849120825557, 7.816265301157324, 3.827590651216025],
-    [-0.7670519205077714, 7.788008845058006, 3.827590651216025],
-    [-0.7993774678511602, 8.11621563510815, 4.156997535730132],
-    [-0.4001707571279498, 8.145662891184594, 4.156997535730132],
-    [-0.7670519205077714, 7.788008845058006, 3.827590651216025],
-    [-1.148267454544969, 7.740990393501933, 3.827590651216025],
-    [-1.1966584082371186, 8.0672157046702, 4.156997535730132],
-    [-0.7993774678511602, 8.11621563510815, 4.156997535730132],
-    [-1.148267454544969, 7.740990393501933, 3.827590651216025],
-    [-1.5267167117078189, 7.675323218057234, 3.827590651216025],
-    [-1.591056493702738, 7.998781144995059, 4.156997535730132],
-    [-1.1966584082371186, 8.0672157046702, 4.156997535730132],
-    [-1.5267167117078189, 7.675323218057234, 3.827590651216025],
-    [-1.9014879745875746, 7.591165516701321, 3.827590651216025],
-    [-1.981621584714938, 7.911076820931141, 4.156997535730132],
-    [-1.591056493702738, 7.998781144995059, 4.156997535730132],
-    [-1.9014879745875746, 7.591165516701321, 3.827590651216025],
-    [-2.2716783863858705, 7.488720032708105, 3.827590651216025],
-    [-2.3674127757600063, 7.80431401987711, 4.156997535730132],
-    [-1.981621584714938, 7.911076820931141, 4.156997535730132],
-    [-2.2716783863858705, 7.488720032708105, 3.827590651216025],
-    [-2.6363961259758386, 7.368233566221689, 3.827590651216025],
-    [-2.7475006620674023, 7.67874994277202, 4.156997535730132],
-    [-2.3674127757600063, 7.80431401987711, 4.156997535730132],
-    [-2.6363961259758386, 7.368233566221689, 3.827590651216025],
-    [-2.994762556377303, 7.2299963796932065, 3.827590651216025],
-    [-3.120969578627242, 7.534687084475732, 4.156997535730132],
-    [-2.7475006620674023, 7.67874994277202, 4.156997535730132],
-    [-2.994762556377303, 7.2299963796932065, 3.827590651216025],
-    [-3.345914341470233, 7.074341498613155, 3.827590651216025],
-    [-3.486919806107517, 7.3724725050323, 4.156997535730132],
-    [-3.120969578627242, 7.534687084475732, 4.156997535730132],
-    [-3.345914341470233, 7.074341498613155, 3.827590651216025],
-    [-3.689005525847081, 6.901643909223809, 3.827590651216025],
-    [-3.84446973835678, 7.192496993571917, 4.156997535730132],
-    [-3.486919806107517, 7.3724725050323, 4.156997535730132],
-    [-3.689005525847081, 6.901643909223809, 3.827590651216025],
-    [-4.023209572793437, 6.712319655144534, 3.827590651216025],
-    [-4.192758006270558, 6.99519412686569, 4.156997535730132],
-    [-3.84446973835678, 7.192496993571917, 4.156997535730132],
-    [-4.023209572793437, 6.712319655144534, 3.827590651216025],
-    [-4.347721355487396, 6.506824835086252, 3.827590651216025],
-    [-4.530945552905003, 6.7810392248012015, 4.156997535730132],
-    [-4.192758006270558, 6.99519412686569, 4.156997535730132],
-    [-4.347721355487396, 6.506824835086252, 3.827590651216025],
-    [-4.661759096620574, 6.285654504069702, 3.827590651216025],
-    [-4.858217654838544, 6.550548205295279, 4.156997535730132],
-    [-4.530945552905003, 6.7810392248012015, 4.156997535730132],
-    [-4.661759096620574, 6.285654504069702, 3.827590651216025],
-    [-4.964566251768118, 6.0493414807945385, 3.827590651216025],
-    [-5.1737858849119664, 6.304276341402542, 4.156997535730132],
-    [-4.858217654838544, 6.550548205295279, 4.156997535730132],
-    [-4.964566251768118, 6.0493414807945385, 3.827590651216025],
-    [-5.255413331970508, 5.798455064032404, 3.827590651216025],
-    [-5.476890011618517, 6.042816923613972, 4.156997535730132],
-    [-5.1737858849119664, 6.304276341402542, 4.156997535730132],
-    [-5.255413331970508, 5.798455064032404, 3.827590651216025],
-    [-5.533599661136338, 5.533599661136338, 3.827590651216025],
-    [-5.766799830568168, 5.766799830568169, 4.156997535730132],
-    [-5.476890011618517, 6.042816923613972, 4.156997535730132],
-    [-5.533599661136338, 5.533599661136338, 3.827590651216025],
-    [-5.798455064032403, 5.255413331970509, 3.827590651216025],
-    [-6.042816923613971, 5.476890011618518, 4.156997535730132],
-    [-5.766799830568168, 5.766799830568169, 4.156997535730132],
-    [-5.798455064032403, 5.255413331970509, 3.827590651216025],
-    [-6.049341480794538, 4.964566251768119, 3.827590651216025],
-    [-6.304276341402541, 5.173785884911967, 4.156997535730132],
-    [-6.042816923613971, 5.476890011618518, 4.156997535730132],
-    [-6.049341480794538, 4.964566251768119, 3.827590651216025],
-    [-6.285654504069701, 4.661759096620575, 3.827590651216025],
-    [-6.550548205295278, 4.858217654838545, 4.156997535730132],
-    [-6.304276341402541, 5.173785884911967, 4.156997535730132],
-    [-6.285654504069701, 4.661759096620575, 3.827590651216025],
-    [-6.506824835086252, 4.347721355487398, 3.827590651216025],
-    [-6.7810392248012015, 4.5309455529050044, 4.156997535730132],
-    [-6.550548205295278, 4.858217654838545, 4.156997535730132],
-    [-6.506824835086252, 4.347721355487398, 3.827590651216025],
-    [-6.712319655144533, 4.023209572793439, 3.827590651216025],
-    [-6.995194126865689, 4.19275800627056, 4.156997535730132],
-    [-6.7810392248012015, 4.5309455529050044, 4.156997535730132],
-    [-6.712319655144533, 4.023209572793439, 3.827590651216025],
-    [-6.901643909223808, 3.6890055258470817, 3.827590651216025],
-    [-7.1924969935719165, 3.8444697383567807, 4.156997535730132],
-    [-6.995194126865689, 4.19275800627056, 4.156997535730132],
-    [-6.901643909223808, 3.6890055258470817, 3.827590651216025],
-    [-7.074341498613154, 3.3459143414702344, 3.827590651216025],
-    [-7.372472505032299, 3.486919806107519, 4.156997535730132],
-    [-7.1924969935719165, 3.8444697383567807, 4.156997535730132],
-    [-7.074341498613154, 3.3459143414702344, 3.827590651216025],
-    [-7.2299963796932065, 2.9947625563773044, 3.827590651216025],
-    [-7.534687084475732, 3.1209695786272436, 4.156997535730132],
-    [-7.372472505032299, 3.486919806107519, 4.156997535730132],
-    [-7.2299963796932065, 2.9947625563773044, 3.827590651216025],
-    [-7.368233566221688, 2.6363961259758417, 3.827590651216025],
-    [-7.678749942772019, 2.7475006620674054, 4.156997535730132],
-    [-7.534687084475732, 3.1209695786272436, 4.156997535730132],
-    [-7.368233566221688, 2.6363961259758417, 3.827590651216025],
-    [-7.4887200327081045, 2.2716783863858723, 3.827590651216025],
-    [-7.8043140198771095, 2.367412775760008, 4.156997535730132],
-    [-7.678749942772019, 2.7475006620674054, 4.156997535730132],
-    [-7.4887200327081045, 2.2716783863858723, 3.827590651216025],
-    [-7.591165516701321, 1.9014879745875761, 3.827590651216025],
-    [-7.911076820931141, 1.9816215847149397, 4.156997535730132],
-    [-7.8043140198771095, 2.367412775760008, 4.156997535730132],
-    [-7.591165516701321, 1.9014879745875761, 3.827590651216025],
-    [-7.675323218057234, 1.5267167117078222, 3.827590651216025],
-    [-7.998781144995059, 1.5910564937027414, 4.156997535730132],
-    [-7.911076820931141, 1.9816215847149397, 4.156997535730132],
-    [-7.675323218057234, 1.5267167117078222, 3.827590651216025],
-    [-7.740990393501933, 1.1482674545449703, 3.827590651216025],
-    [-8.0672157046702, 1.1966584082371199, 4.156997535730132],
-    [-7.998781144995059, 1.5910564937027414, 4.156997535730132],
-    [-7.740990393501933, 1.1482674545449703, 3.827590651216025],
-    [-7.788008845058005, 0.7670519205077727, 3.827590651216025],
-    [-8.11621563510815, 0.7993774678511616, 4.156997535730132],
-    [-8.0672157046702, 1.1966584082371199, 4.156997535730132],
-    [-7.788008845058005, 0.7670519205077727, 3.827590651216025],
-    [-7.816265301157324, 0.38398849120825523, 3.827590651216025],
-    [-8.145662891184594, 0.4001707571279495, 4.156997535730132],
-    [-8.11621563510815, 0.7993774678511616, 4.156997535730132],
-    [-7.816265301157324, 0.38398849120825523, 3.827590651216025],
-    [-7.825691689522173, 9.583708278687371e-16, 3.827590651216025],
-    [-8.15548653188037, 9.987590476756644e-16, 4.156997535730132],
-    [-8.145662891184594, 0.4001707571279495, 4.156997535730132],
-    [-7.825691689522173, 9.583708278687371e-16, 3.827590651216025],
-    [-7.816265301157324, -0.38398849120825335, 3.827590651216025],
-    [-8.145662891184594, -0.4001707571279475, 4.156997535730132],
-    [-8.15548653188037, 9.987590476756644e-16, 4.156997535730132],
-    [-7.816265301157324, -0.38398849120825335, 3.827590651216025],
-    [-7.788008845058006, -0.7670519205077709, 3.827590651216025],
-    [-8.11621563510815, -0.7993774678511597, 4.156997535730132],
-    [-8.145662891184594, -0.4001707571279475, 4.156997535730132],
-    [-7.788008845058006, -0.7670519205077709, 3.827590651216025],
-    [-7.740990393501933, -1.1482674545449685, 3.827590651216025],
-    [-8.0672157046702, -1.196658408237118, 4.156997535730132],
-    [-8.11621563510815, -0.7993774678511597, 4.156997535730132],
-    [-7.740990393501933, -1.1482674545449685, 3.827590651216025],
-    [-7.675323218057234, -1.5267167117078202, 3.827590651216025],
-    [-7.998781144995059, -1.5910564937027394, 4.156997535730132],
-    [-8.0672157046702, -1.196658408237118, 4.156997535730132],
-    [-7.675323218057234, -1.5267167117078202, 3.827590651216025],
-    [-7.591165516701321, -1.9014879745875741, 3.827590651216025],
-    [-7.911076820931141, -1.9816215847149377, 4.156997535730132],
-    [-7.998781144995059, -1.5910564937027394, 4.156997535730132],
-    [-7.591165516701321, -1.9014879745875741, 3.827590651216025],
-    [-7.488720032708105, -2.27167838638587, 3.827590651216025],
-    [-7.80431401987711, -2.367412775760006, 4.156997535730132],
-    [-7.911076820931141, -1.9816215847149377, 4.156997535730132],
-    [-7.488720032708105, -2.27167838638587, 3.827590651216025],
-    [-7.368233566221689, -2.63639612597584, 3.827590651216025],
-    [-7.67874994277202, -2.7475006620674036, 4.156997535730132],
-    [-7.80431401987711, -2.367412775760006, 4.156997535730132],
-    [-7.368233566221689, -2.63639612597584, 3.827590651216025],
-    [-7.229996379693207, -2.9947625563773026, 3.827590651216025],
-    [-7.534687084475733, -3.1209695786272413, 4.156997535730132],
-    [-7.67874994277202, -2.7475006620674036, 4.156997535730132],
-    [-7.229996379693207, -2.9947625563773026, 3.827590651216025],
-    [-7.074341498613155, -3.3459143414702326, 3.827590651216025],
-    [-7.3724725050323, -3.4869198061075166, 4.156997535730132],
-    [-7.534687084475733, -3.1209695786272413, 4.156997535730132],
-    [-7.074341498613155, -3.3459143414702326, 3.827590651216025],
-    [-6.901643909223809, -3.6890055258470804, 3.827590651216025],
-    [-7.192496993571917, -3.8444697383567794, 4.156997535730132],
-    [-7.3724725050323, -3.4869198061075166, 4.156997535730132],
-    [-6.901643909223809, -3.6890055258470804, 3.827590651216025],
-    [-6.712319655144534, -4.023209572793437, 3.827590651216025],
-    [-6.99519412686569, -4.192758006270558, 4.156997535730132],
-    [-7.192496993571917, -3.8444697383567794, 4.156997535730132],
-    [-6.712319655144534, -4.023209572793437, 3.827590651216025],
-    [-6.5068248350862525, -4.347721355487396, 3.827590651216025],
-    [-6.781039224801203, -4.530945552905003, 4.156997535730132],
-    [-6.99519412686569, -4.192758006270558, 4.156997535730132],
-    [-6.5068248350862525, -4.347721355487396, 3.827590651216025],
-    [-6.285654504069702, -4.661759096620573, 3.827590651216025],
-    [-6.550548205295279, -4.858217654838543, 4.156997535730132],
-    [-6.781039224801203, -4.530945552905003, 4.156997535730132],
-    [-6.285654504069702, -4.661759096620573, 3.827590651216025],
-    [-6.0493414807945385, -4.964566251768117, 3.827590651216025],
-    [-6.304276341402542, -5.173785884911966, 4.156997535730132],
-    [-6.550548205295279, -4.858217654838543, 4.156997535730132],
-    [-6.0493414807945385, -4.964566251768117, 3.827590651216025],
-    [-5.798455064032405, -5.255413331970508, 3.827590651216025],
-    [-6.042816923613973, -5.476890011618517, 4.156997535730132],
-    [-6.304276341402542, -5.173785884911966, 4.156997535730132],
-    [-5.798455064032405, -5.255413331970508, 3.827590651216025],
-    [-5.533599661136339, -5.533599661136338, 3.827590651216025],
-    [-5.76679983056817, -5.766799830568168, 4.156997535730132],
-    [-6.042816923613973, -5.476890011618517, 4.156997535730132],
-    [-5.533599661136339, -5.533599661136338, 3.827590651216025],
-    [-5.25541333197051, -5.798455064032403, 3.827590651216025],
-    [-5.476890011618519, -6.042816923613971, 4.156997535730132],
-    [-5.76679983056817, -5.766799830568168, 4.156997535730132],
-    [-5.25541333197051, -5.798455064032403, 3.827590651216025],
-    [-4.964566251768122, -6.049341480794535, 3.827590651216025],
-    [-5.173785884911971, -6.304276341402538, 4.156997535730132],
-    [-5.476890011618519, -6.042816923613971, 4.156997535730132],
-    [-4.964566251768122, -6.049341480794535, 3.827590651216025],
-    [-4.661759096620572, -6.2856545040697025, 3.827590651216025],
-    [-4.858217654838542, -6.5505482052952795, 4.156997535730132],
-    [-5.173785884911971, -6.304276341402538, 4.156997535730132],
-    [-4.661759096620572, -6.2856545040697025, 3.827590651216025],
-    [-4.347721355487398, -6.506824835086251, 3.827590651216025],
-    [-4.5309455529050044, -6.781039224801201, 4.156997535730132],
-    [-4.858217654838542, -6.5505482052952795, 4.156997535730132],
-    [-4.347721355487398, -6.506824835086251, 3.827590651216025],
-    [-4.023209572793439, -6.712319655144533, 3.827590651216025],
-    [-4.19275800627056, -6.995194126865689, 4.156997535730132],
-    [-4.5309455529050044, -6.781039224801201, 4.156997535730132],
-    [-4.023209572793439, -6.712319655144533, 3.827590651216025],
-    [-3.689005525847082, -6.901643909223808, 3.827590651216025],
-    [-3.844469738356781, -7.1924969935719165, 4.156997535730132],
-    [-4.19275800627056, -6.995194126865689, 4.156997535730132],
-    [-3.689005525847082, -6.901643909223808, 3.827590651216025],
-    [-3.3459143414702375, -7.074341498613152, 3.827590651216025],
-    [-3.4869198061075224, -7.372472505032297, 4.156997535730132],
-    [-3.844469738356781, -7.1924969935719165, 4.156997535730132],
-    [-3.3459143414702375, -7.074341498613152, 3.827590651216025],
-    [-2.994762556377308, -7.229996379693205, 3.827590651216025],
-    [-3.120969578627247, -7.53468708447573, 4.156997535730132],
-    [-3.4869198061075224, -7.372472505032297, 4.156997535730132],
-    [-2.994762556377308, -7.229996379693205, 3.827590651216025],
-    [-2.6363961259758386, -7.368233566221689, 3.827590651216025],
-    [-2.7475006620674023, -7.67874994277202, 4.156997535730132],
-    [-3.120969578627247, -7.53468708447573, 4.156997535730132],
-    [-2.6363961259758386, -7.368233566221689, 3.827590651216025],
-    [-2.2716783863858727, -7.4887200327081045, 3.827590651216025],
-    [-2.3674127757600085, -7.8043140198771095, 4.156997535730132],
-    [-2.7475006620674023, -7.67874994277202, 4.156997535730132],
-    [-2.2716783863858727, -7.4887200327081045, 3.827590651216025],
-    [-1.9014879745875763, -7.591165516701321, 3.827590651216025],
-    [-1.98162158471494, -7.911076820931141, 4.156997535730132],
-    [-2.3674127757600085, -7.8043140198771095, 4.156997535730132],
-    [-1.9014879745875763, -7.591165516701321, 3.827590651216025],
-    [-1.5267167117078226, -7.675323218057233, 3.827590651216025],
-    [-1.5910564937027418, -7.998781144995058, 4.156997535730132],
-    [-1.98162158471494, -7.911076820931141, 4.156997535730132],
-    [-1.5267167117078226, -7.675323218057233, 3.827590651216025],
-    [-1.1482674545449743, -7.740990393501932, 3.827590651216025],
-    [-1.196658408237124, -8.0672157046702, 4.156997535730132],
-    [-1.5910564937027418, -7.998781144995058, 4.156997535730132],
-    [-1.1482674545449743, -7.740990393501932, 3.827590651216025],
-    [-0.7670519205077698, -7.788008845058006, 3.827590651216025],
-    [-0.7993774678511586, -8.11621563510815, 4.156997535730132],
-    [-1.196658408237124, -8.0672157046702, 4.156997535730132],
-    [-0.7670519205077698, -7.788008845058006, 3.827590651216025],
-    [-0.38398849120825573, -7.816265301157324, 3.827590651216025],
-    [-0.40017075712794997, -8.145662891184594, 4.156997535730132],
-    [-0.7993774678511586, -8.11621563510815, 4.156997535730132],
-    [-0.38398849120825573, -7.816265301157324, 3.827590651216025],
-    [
-        -1.4375562418031056e-15,
-        -7.825691689522173,
-        3.827590651216025,
-    ],
-    [
-        -1.4981385715134965e-15,
-        -8.15548653188037,
-        4.156997535730132,
-    ],
-    [-0.40017075712794997, -8.145662891184594, 4.156997535730132],
-    [
-        -1.4375562418031056e-15,
-        -7.825691689522173,
-        3.827590651216025,
-    ],
-    [0.38398849120825285, -7.816265301157324, 3.827590651216025],
-    [0.400170757127947, -8.145662891184594, 4.156997535730132],
-    [
-        -1.4981385715134965e-15,
-        -8.15548653188037,
-        4.156997535730132,
-    ],
-    [0.38398849120825285, -7.816265301157324, 3.827590651216025],
-    [0.7670519205077669, -7.788008845058006, 3.827590651216025],
-    [0.7993774678511556, -8.11621563510815, 4.156997535730132],
-    [0.400170757127947, -8.145662891184594, 4.156997535730132],
-    [0.7670519205077669, -7.788008845058006, 3.827590651216025],
-    [1.1482674545449714, -7.740990393501932, 3.827590651216025],
-    [1.196658408237121, -8.0672157046702, 4.156997535730132],
-    [0.7993774678511556, -8.11621563510815, 4.156997535730132],
-    [1.1482674545449714, -7.740990393501932, 3.827590651216025],
-    [1.5267167117078198, -7.675323218057234, 3.827590651216025],
-    [1.591056493702739, -7.998781144995059, 4.156997535730132],
-    [1.196658408237121, -8.0672157046702, 4.156997535730132],
-    [1.5267167117078198, -7.675323218057234, 3.827590651216025],
-    [1.9014879745875737, -7.591165516701321, 3.827590651216025],
-    [1.981621584714937, -7.911076820931141, 4.156997535730132],
-    [1.591056493702739, -7.998781144995059, 4.156997535730132],
-    [1.9014879745875737, -7.591165516701321, 3.827590651216025],
-    [2.2716783863858696, -7.488720032708105, 3.827590651216025],
-    [2.3674127757600054, -7.80431401987711, 4.156997535730132],
-    [1.981621584714937, -7.911076820931141, 4.156997535730132],
-    [2.2716783863858696, -7.488720032708105, 3.827590651216025],
-    [2.636396125975836, -7.36823356622169, 3.827590651216025],
-    [2.7475006620673996, -7.678749942772021, 4.156997535730132],
-    [2.3674127757600054, -7.80431401987711, 4.156997535730132],
-    [2.636396125975836, -7.36823356622169, 3.827590651216025],
-    [2.9947625563773053, -7.229996379693206, 3.827590651216025],
-    [3.1209695786272444, -7.534687084475731, 4.156997535730132],
-    [2.7475006620673996, -7.678749942772021, 4.156997535730132],
-    [2.9947625563773053, -7.229996379693206, 3.827590651216025],
-    [3.345914341470235, -7.074341498613154, 3.827590651216025],
-    [3.4869198061075197, -7.372472505032299, 4.156997535730132],
-    [3.1209695786272444, -7.534687084475731, 4.156997535730132],
-    [3.345914341470235, -7.074341498613154, 3.827590651216025],
-    [3.68900552584708, -6.901643909223809, 3.827590651216025],
-    [3.844469738356779, -7.192496993571917, 4.156997535730132],
-    [3.4869198061075197, -7.372472505032299, 4.156997535730132],
-    [3.68900552584708, -6.901643909223809, 3.827590651216025],
-    [4.023209572793437, -6.712319655144535, 3.827590651216025],
-    [4.192758006270558, -6.995194126865691, 4.156997535730132],
-    [3.844469738356779, -7.192496993571917, 4.156997535730132],
-    [4.023209572793437, -6.712319655144535, 3.827590651216025],
-    [4.347721355487395, -6.5068248350862525, 3.827590651216025],
-    [4.530945552905002, -6.781039224801203, 4.156997535730132],
-    [4.192758006270558, -6.995194126865691, 4.156997535730132],
-    [4.347721355487395, -6.5068248350862525, 3.827590651216025],
-    [4.661759096620569, -6.285654504069704, 3.827590651216025],
-    [4.85821765483854, -6.550548205295281, 4.156997535730132],
-    [4.530945552905002, -6.781039224801203, 4.156997535730132],
-    [4.661759096620569, -6.285654504069704, 3.827590651216025],
-    [4.96456625176812, -6.049341480794537, 3.827590651216025],
-    [5.173785884911968, -6.30427634140254, 4.156997535730132],
-    [4.85821765483854, -6.550548205295281, 4.156997535730132],
-    [4.96456625176812, -6.049341480794537, 3.827590651216025],
-    [5.255413331970507, -5.798455064032405, 3.827590651216025],
-    [5.476890011618516, -6.042816923613973, 4.156997535730132],
-    [5.173785884911968, -6.30427634140254, 4.156997535730132],
-    [5.255413331970507, -5.798455064032405, 3.827590651216025],
-    [5.533599661136337, -5.533599661136339, 3.827590651216025],
-    [5.766799830568167, -5.76679983056817, 4.156997535730132],
-    [5.476890011618516, -6.042816923613973, 4.156997535730132],
-    [5.533599661136337, -5.533599661136339, 3.827590651216025],
-    [5.798455064032403, -5.25541333197051, 3.827590651216025],
-    [6.042816923613971, -5.476890011618519, 4.156997535730132],
-    [5.766799830568167, -5.76679983056817, 4.156997535730132],
-    [5.798455064032403, -5.25541333197051, 3.827590651216025],
-    [6.049341480794534, -4.964566251768122, 3.827590651216025],
-    [6.304276341402537, -5.173785884911971, 4.156997535730132],
-    [6.042816923613971, -5.476890011618519, 4.156997535730132],
-    [6.049341480794534, -4.964566251768122, 3.827590651216025],
-    [6.2856545040697025, -4.661759096620573, 3.827590651216025],
-    [6.5505482052952795, -4.858217654838543, 4.156997535730132],
-    [6.304276341402537, -5.173785884911971, 4.156997535730132],
-    [6.2856545040697025, -4.661759096620573, 3.827590651216025],
-    [6.506824835086251, -4.347721355487398, 3.827590651216025],
-    [6.781039224801201, -4.5309455529050044, 4.156997535730132],
-    [6.5505482052952795, -4.858217654838543, 4.156997535730132],
-    [6.506824835086251, -4.347721355487398, 3.827590651216025],
-    [6.712319655144533, -4.02320957279344, 3.827590651216025],
-    [6.995194126865689, -4.1927580062705605, 4.156997535730132],
-    [6.781039224801201, -4.5309455529050044, 4.156997535730132],
-    [6.712319655144533, -4.02320957279344, 3.827590651216025],
-    [6.901643909223807, -3.6890055258470826, 3.827590651216025],
-    [7.192496993571916, -3.8444697383567816, 4.156997535730132],
-    [6.995194126865689, -4.1927580062705605, 4.156997535730132],
-    [6.901643909223807, -3.6890055258470826, 3.827590651216025],
-    [7.074341498613152, -3.345914341470238, 3.827590651216025],
-    [7.372472505032297, -3.486919806107523, 4.156997535730132],
-    [7.192496993571916, -3.8444697383567816, 4.156997535730132],
-    [7.074341498613152, -3.345914341470238, 3.827590651216025],
-    [7.229996379693205, -2.9947625563773084, 3.827590651216025],
-    [7.53468708447573, -3.1209695786272476, 4.156997535730132],
-    [7.372472505032297, -3.486919806107523, 4.156997535730132],
-    [7.229996379693205, -2.9947625563773084, 3.827590651216025],
-    [7.368233566221689, -2.636396125975839, 3.827590651216025],
-    [7.67874994277202, -2.7475006620674027, 4.156997535730132],
-    [7.53468708447573, -3.1209695786272476, 4.156997535730132],
-    [7.368233566221689, -2.636396125975839, 3.827590651216025],
-    [7.4887200327081045, -2.271678386385873, 3.827590651216025],
-    [7.8043140198771095, -2.367412775760009, 4.156997535730132],
-    [7.67874994277202, -2.7475006620674027, 4.156997535730132],
-    [7.4887200327081045, -2.271678386385873, 3.827590651216025],
-    [7.591165516701321, -1.9014879745875768, 3.827590651216025],
-    [7.911076820931141, -1.9816215847149405, 4.156997535730132],
-    [7.8043140198771095, -2.367412775760009, 4.156997535730132],
-    [7.591165516701321, -1.9014879745875768, 3.827590651216025],
-    [7.675323218057233, -1.526716711707823, 3.827590651216025],
-    [7.998781144995058, -1.5910564937027423, 4.156997535730132],
-    [7.911076820931141, -1.9816215847149405, 4.156997535730132],
-    [7.675323218057233, -1.526716711707823, 3.827590651216025],
-    [7.740990393501932, -1.148267454544975, 3.827590651216025],
-    [8.0672157046702, -1.1966584082371248, 4.156997535730132],
-    [7.998781144995058, -1.5910564937027423, 4.156997535730132],
-    [7.740990393501932, -1.148267454544975, 3.827590651216025],
-    [7.788008845058006, -0.7670519205077703, 3.827590651216025],
-    [8.11621563510815, -0.799377467851159, 4.156997535730132],
-    [8.0672157046702, -1.1966584082371248, 4.156997535730132],
-    [7.788008845058006, -0.7670519205077703, 3.827590651216025],
-    [7.816265301157324, -0.38398849120825623, 3.827590651216025],
-    [8.145662891184594, -0.40017075712795047, 4.156997535730132],
-    [8.11621563510815, -0.799377467851159, 4.156997535730132],
-    [7.816265301157324, -0.38398849120825623, 3.827590651216025],
-    [
-        7.825691689522173,
-        -1.9167416557374743e-15,
-        3.827590651216025,
-    ],
-    [8.15548653188037, -1.9975180953513288e-15, 4.156997535730132],
-    [8.145662891184594, -0.40017075712795047, 4.156997535730132],
-    [8.15548653188037, 0.0, 4.156997535730132],
-    [8.145662891184594, 0.40017075712794986, 4.156997535730132],
-    [8.475060481211864, 0.4163530230476441, 4.499999999999999],
-    [8.48528137423857, 0.0, 4.499999999999999],
-    [8.145662891184594, 0.40017075712794986, 4.156997535730132],
-    [8.11621563510815, 0.7993774678511598, 4.156997535730132],
-    [8.444422425158294, 0.8317030151945487, 4.499999999999999],
-    [8.475060481211864, 0.4163530230476441, 4.499999999999999],
-    [8.11621563510815, 0.7993774678511598, 4.156997535730132],
-    [8.0672157046702, 1.1966584082371194, 4.156997535730132],
-    [8.39344101583847, 1.2450493619292693, 4.499999999999999],
-    [8.444422425158294, 0.8317030151945487, 4.499999999999999],
-    [8.0672157046702, 1.1966584082371194, 4.156997535730132],
-    [7.998781144995059, 1.5910564937027385, 4.156997535730132],
-    [8.322239071932884, 1.6553962756976577, 4.499999999999999],
-    [8.39344101583847, 1.2450493619292693, 4.499999999999999],
-    [7.998781144995059, 1.5910564937027385, 4.156997535730132],
-    [7.911076820931141, 1.981621584714938, 4.156997535730132],
-    [8.230988125160962, 2.0617551948423016, 4.499999999999999],
-    [8.322239071932884, 1.6553962756976577, 4.499999999999999],
-    [7.911076820931141, 1.981621584714938, 4.156997535730132],
-    [7.8043140198771095, 2.3674127757600076, 4.156997535730132],
-    [8.119908007046115, 2.463147165134144, 4.499999999999999],
-    [8.230988125160962, 2.0617551948423016, 4.499999999999999],
-    [7.8043140198771095, 2.3674127757600076, 4.156997535730132],
-    [7.67874994277202, 2.747500662067403, 4.156997535730132],
-    [7.989266319322352, 2.8586051981589673, 4.499999999999999],
-    [8.119908007046115, 2.463147165134144, 4.499999999999999],
-    [7.67874994277202, 2.747500662067403, 4.156997535730132],
-    [7.534687084475732, 3.1209695786272427, 4.156997535730132],
-    [7.839377789258259, 3.247176600877182, 4.499999999999999],
-    [7.989266319322352, 2.8586051981589673, 4.499999999999999],
-    [7.534687084475732, 3.1209695786272427, 4.156997535730132],
-    [7.372472505032299, 3.4869198061075193, 4.156997535730132],
-    [7.670603511451445, 3.6279252707448038, 4.499999999999999],
-    [7.839377789258259, 3.247176600877182, 4.499999999999999],
-    [7.372472505032299, 3.4869198061075193, 4.156997535730132],
-    [7.192496993571917, 3.8444697383567794, 4.156997535730132],
-    [7.483350077920027, 3.9999339508664784, 4.499999999999999],
-    [7.670603511451445, 3.6279252707448038, 4.499999999999999],
-    [7.192496993571917, 3.8444697383567794, 4.156997535730132],
-    [6.99519412686569, 4.192758006270559, 4.156997535730132],
-    [7.278068598586847, 4.362306439747679, 4.499999999999999],
-    [7.483350077920027, 3.9999339508664784, 4.499999999999999],
-    [6.99519412686569, 4.192758006270559, 4.156997535730132],
-    [6.781039224801201, 4.5309455529050044, 4.156997535730132],
-    [7.055253614516151, 4.714169750322612, 4.499999999999999],
-    [7.278068598586847, 4.362306439747679, 4.499999999999999],
-    [6.781039224801201, 4.5309455529050044, 4.156997535730132],
-    [6.550548205295279, 4.858217654838544, 4.156997535730132],
-    [6.8154419065208565, 5.054676213056515, 4.499999999999999],
-    [7.055253614516151, 4.714169750322612, 4.499999999999999],
-    [6.550548205295279, 4.858217654838544, 4.156997535730132],
-    [6.304276341402541, 5.173785884911967, 4.156997535730132],
-    [6.559211202010545, 5.383005518055817, 4.499999999999999],
-    [6.8154419065208565, 5.054676213056515, 4.499999999999999],
-    [6.304276341402541, 5.173785884911967, 4.156997535730132],
-    [6.042816923613974, 5.476890011618516, 4.156997535730132],
-    [6.287178783195543, 5.698366691266525, 4.499999999999999],
-    [6.559211202010545, 5.383005518055817, 4.499999999999999],
-    [6.042816923613974, 5.476890011618516, 4.156997535730132],
-    [5.766799830568169, 5.766799830568169, 4.156997535730132],
-    [6.0, 6.0, 4.499999999999999],
-    [6.287178783195543, 5.698366691266525, 4.499999999999999],
-    [5.766799830568169, 5.766799830568169, 4.156997535730132],
-    [5.476890011618516, 6.042816923613973, 4.156997535730132],
-    [5.698366691266525, 6.287178783195542, 4.499999999999999],
-    [6.0, 6.0, 4.499999999999999],
-    [5.476890011618516, 6.042816923613973, 4.156997535730132],
-    [5.173785884911967, 6.30427634140254, 4.156997535730132],
-    [5.383005518055817, 6.559211202010544, 4.499999999999999],
-    [5.698366691266525, 6.287178783195542, 4.499999999999999],
-    [5.173785884911967, 6.30427634140254, 4.156997535730132],
-    [4.858217654838545, 6.550548205295278, 4.156997535730132],
-    [5.054676213056516, 6.815441906520855, 4.499999999999999],
-    [5.383005518055817, 6.559211202010544, 4.499999999999999],
-    [4.858217654838545, 6.550548205295278, 4.156997535730132],
-    [4.530945552905005, 6.781039224801201, 4.156997535730132],
-    [4.714169750322613, 7.055253614516151, 4.499999999999999],
-    [5.054676213056516, 6.815441906520855, 4.499999999999999],
-    [4.530945552905005, 6.781039224801201, 4.156997535730132],
-    [4.192758006270559, 6.99519412686569, 4.156997535730132],
-    [4.362306439747679, 7.278068598586847, 4.499999999999999],
-    [4.714169750322613, 7.055253614516151, 4.499999999999999],
-    [4.192758006270559, 6.99519412686569, 4.156997535730132],
-    [3.8444697383567807, 7.1924969935719165, 4.156997535730132],
-    [3.99993395086648, 7.483350077920026, 4.499999999999999],
-    [4.362306439747679, 7.278068598586847, 4.499999999999999],
-    [3.8444697383567807, 7.1924969935719165, 4.156997535730132],
-    [3.48691980610752, 7.372472505032299, 4.156997535730132],
-    [3.627925270744805, 7.670603511451445, 4.499999999999999],
-    [3.99993395086648, 7.483350077920026, 4.499999999999999],
-    [3.48691980610752, 7.372472505032299, 4.156997535730132],
-    [3.120969578627243, 7.534687084475732, 4.156997535730132],
-    [3.2471766008771823, 7.839377789258259, 4.499999999999999],
-    [3.627925270744805, 7.670603511451445, 4.499999999999999],
-    [3.120969578627243, 7.534687084475732, 4.156997535730132],
-    [2.747500662067403, 7.67874994277202, 4.156997535730132],
-    [2.8586051981589673, 7.989266319322352, 4.499999999999999],
-    [3.2471766008771823, 7.839377789258259, 4.499999999999999],
-    [2.747500662067403, 7.67874994277202, 4.156997535730132],
-    [2.3674127757600076, 7.80431401987711, 4.156997535730132],
-    [2.463147165134144, 8.119908007046115, 4.499999999999999],
-    [2.8586051981589673, 7.989266319322352, 4.499999999999999],
-    [2.3674127757600076, 7.80431401987711, 4.156997535730132],
-    [1.981621584714939, 7.911076820931141, 4.156997535730132],
-    [2.0617551948423025, 8.230988125160962, 4.499999999999999],
-    [2.463147165134144, 8.119908007046115, 4.499999999999999],
-    [1.981621584714939, 7.911076820931141, 4.156997535730132],
-    [1.5910564937027392, 7.998781144995059, 4.156997535730132],
-    [1.6553962756976586, 8.322239071932884, 4.499999999999999],
-    [2.0617551948423025, 8.230988125160962, 4.499999999999999],
-    [1.5910564937027392, 7.998781144995059, 4.156997535730132],
-    [1.1966584082371194, 8.0672157046702, 4.156997535730132],
-    [1.2450493619292693, 8.39344101583847, 4.499999999999999],
-    [1.6553962756976586, 8.322239071932884, 4.499999999999999],
-    [1.1966584082371194, 8.0672157046702, 4.156997535730132],
-    [0.7993774678511611, 8.11621563510815, 4.156997535730132],
-    [0.8317030151945501, 8.444422425158294, 4.499999999999999],
-    [1.2450493619292693, 8.39344101583847, 4.499999999999999],
-    [0.7993774678511611, 8.11621563510815, 4.156997535730132],
-    [0.40017075712795075, 8.145662891184594, 4.156997535730132],
-    [0.41635302304764504, 8.475060481211864, 4.499999999999999],
-    [0.8317030151945501, 8.444422425158294, 4.499999999999999],
-    [0.40017075712795075, 8.145662891184594, 4.156997535730132],
-    [4.993795238378322e-16, 8.15548653188037, 4.156997535730132],
-    [5.195736337412959e-16, 8.48528137423857, 4.499999999999999],
-    [0.41635302304764504, 8.475060481211864, 4.499999999999999],
-    [4.993795238378322e-16, 8.15548653188037, 4.156997535730132],
-    [-0.4001707571279498, 8.145662891184594, 4.156997535730132],
-    [-0.41635302304764404, 8.475060481211864, 4.499999999999999],
-    [5.195736337412959e-16, 8.48528137423857, 4.499999999999999],
-    [-0.4001707571279498, 8.145662891184594, 4.156997535730132],
-    [-0.7993774678511602, 8.11621563510815, 4.156997535730132],
-    [-0.831703015194549, 8.444422425158294, 4.499999999999999],
-    [-0.41635302304764404, 8.475060481211864, 4.499999999999999],
-    [-0.7993774678511602, 8.11621563510815, 4.156997535730132],
-    [-1.1966584082371186, 8.0672157046702, 4.156997535730132],
-    [-1.2450493619292684, 8.39344101583847, 4.499999999999999],
-    [-0.831703015194549, 8.444422425158294, 4.499999999999999],
-    [-1.1966584082371186, 8.0672157046702, 4.156997535730132],
-    [-1.591056493702738, 7.998781144995059, 4.156997535730132],
-    [-1.6553962756976572, 8.322239071932884, 4.499999999999999],
-    [-1.2450493619292684, 8.39344101583847, 4.499999999999999],
-    [-1.591056493702738, 7.998781144995059, 4.156997535730132],
-    [-1.981621584714938, 7.911076820931141, 4.156997535730132],
-    [-2.0617551948423016, 8.230988125160962, 4.499999999999999],
-    [-1.6553962756976572, 8.322239071932884, 4.499999999999999],
-    [-1.981621584714938, 7.911076820931141, 4.156997535730132],
-    [-2.3674127757600063, 7.80431401987711, 4.156997535730132],
-    [-2.4631471651341426, 8.119908007046115, 4.499999999999999],
-    [-2.0617551948423016, 8.230988125160962, 4.499999999999999],
-    [-2.3674127757600063, 7.80431401987711, 4.156997535730132],
-    [-2.7475006620674023, 7.67874994277202, 4.156997535730132],
-    [-2.8586051981589664, 7.989266319322352, 4.499999999999999],
-    [-2.4631471651341426, 8.119908007046115, 4.499999999999999],
-    [-2.7475006620674023, 7.67874994277202, 4.156997535730132],
-    [-3.120969578627242, 7.534687084475732, 4.156997535730132],
-    [-3.2471766008771814, 7.839377789258259, 4.499999999999999],
-    [-2.8586051981589664, 7.989266319322352, 4.499999999999999],
-    [-3.120969578627242, 7.534687084475732, 4.156997535730132],
-    [-3.486919806107517, 7.3724725050323, 4.156997535730132],
-    [-3.627925270744802, 7.6706035114514455, 4.499999999999999],
-    [-3.2471766008771814, 7.839377789258259, 4.499999999999999],
-    [-3.486919806107517, 7.3724725050323, 4.156997535730132],
-    [-3.84446973835678, 7.192496993571917, 4.156997535730132],
-    [-3.999933950866479, 7.483350077920027, 4.499999999999999],
-    [-3.627925270744802, 7.6706035114514455, 4.499999999999999],
-    [-3.84446973835678, 7.192496993571917, 4.156997535730132],
-    [-4.192758006270558, 6.99519412686569, 4.156997535730132],
-    [-4.3623064397476785, 7.278068598586847, 4.499999999999999],
-    [-3.999933950866479, 7.483350077920027, 4.499999999999999],
-    [-4.192758006270558, 6.99519412686569, 4.156997535730132],
-    [-4.530945552905003, 6.7810392248012015, 4.156997535730132],
-    [-4.71416975032261, 7.055253614516152, 4.499999999999999],
-    [-4.3623064397476785, 7.278068598586847, 4.499999999999999],
-    [-4.530945552905003, 6.7810392248012015, 4.156997535730132],
-    [-4.858217654838544, 6.550548205295279, 4.156997535730132],
-    [-5.054676213056515, 6.8154419065208565, 4.499999999999999],
-    [-4.71416975032261, 7.055253614516152, 4.499999999999999],
-    [-4.858217654838544, 6.550548205295279, 4.156997535730132],
-    [-5.1737858849119664, 6.304276341402542, 4.156997535730132],
-    [-5.383005518055816, 6.5592112020105455, 4.499999999999999],
-    [-5.054676213056515, 6.8154419065208565, 4.499999999999999],
-    [-5.1737858849119664, 6.304276341402542, 4.156997535730132],
-    [-5.476890011618517, 6.042816923613972, 4.156997535730132],
-    [-5.698366691266526, 6.287178783195541, 4.499999999999999],
-    [-5.383005518055816, 6.5592112020105455, 4.499999999999999],
-    [-5.476890011618517, 6.042816923613972, 4.156997535730132],
-    [-5.766799830568168, 5.766799830568169, 4.156997535730132],
-    [-5.999999999999999, 6.0, 4.499999999999999],
-    [-5.698366691266526, 6.287178783195541, 4.499999999999999],
-    [-5.766799830568168, 5.766799830568169, 4.156997535730132],
-    [-6.042816923613971, 5.476890011618518, 4.156997535730132],
-    [-6.28717878319554, 5.698366691266527, 4.499999999999999],
-    [-5.999999999999999, 6.0, 4.499999999999999],
-    [-6.042816923613971, 5.476890011618518, 4.156997535730132],
-    [-6.304276341402541, 5.173785884911967, 4.156997535730132],
-    [-6.559211202010545, 5.383005518055817, 4.499999999999999],
-    [-6.28717878319554, 5.698366691266527, 4.499999999999999],
-    [-6.304276341402541, 5.173785884911967, 4.156997535730132],
-    [-6.550548205295278, 4.858217654838545, 4.156997535730132],
-    [-6.815441906520855, 5.054676213056516, 4.499999999999999],
-    [-6.559211202010545, 5.383005518055817, 4.499999999999999],
-    [-6.550548205295278, 4.858217654838545, 4.156997535730132],
-    [-6.7810392248012015, 4.5309455529050044, 4.156997535730132],
-    [-7.055253614516152, 4.714169750322612, 4.499999999999999],
-    [-6.815441906520855, 5.054676213056516, 4.499999999999999],
-    [-6.7810392248012015, 4.5309455529050044, 4.156997535730132],
-    [-6.995194126865689, 4.19275800627056, 4.156997535730132],
-    [-7.278068598586846, 4.36230643974768, 4.499999999999999],
-    [-7.055253614516152, 4.714169750322612, 4.499999999999999],
-    [-6.995194126865689, 4.19275800627056, 4.156997535730132],
-    [-7.1924969935719165, 3.8444697383567807, 4.156997535730132],
-    [-7.483350077920026, 3.99993395086648, 4.499999999999999],
-    [-7.278068598586846, 4.36230643974768, 4.499999999999999],
-    [-7.1924969935719165, 3.8444697383567807, 4.156997535730132],
-    [-7.372472505032299, 3.486919806107519, 4.156997535730132],
-    [-7.670603511451445, 3.6279252707448033, 4.499999999999999],
-    [-7.483350077920026, 3.99993395086648, 4.499999999999999],
-    [-7.372472505032299, 3.486919806107519, 4.156997535730132],
-    [-7.534687084475732, 3.1209695786272436, 4.156997535730132],
-    [-7.839377789258259, 3.2471766008771827, 4.499999999999999],
-    [-7.670603511451445, 3.6279252707448033, 4.499999999999999],
-    [-7.534687084475732, 3.1209695786272436, 4.156997535730132],
-    [-7.678749942772019, 2.7475006620674054, 4.156997535730132],
-    [-7.989266319322351, 2.8586051981589695, 4.499999999999999],
-    [-7.839377789258259, 3.2471766008771827, 4.499999999999999],
-    [-7.678749942772019, 2.7475006620674054, 4.156997535730132],
-    [-7.8043140198771095, 2.367412775760008, 4.156997535730132],
-    [-8.119908007046115, 2.4631471651341443, 4.499999999999999],
-    [-7.989266319322351, 2.8586051981589695, 4.499999999999999],
-    [-7.8043140198771095, 2.367412775760008, 4.156997535730132],
-    [-7.911076820931141, 1.9816215847149397, 4.156997535730132],
-    [-8.230988125160962, 2.0617551948423034, 4.499999999999999],
-    [-8.119908007046115, 2.4631471651341443, 4.499999999999999],
-    [-7.911076820931141, 1.9816215847149397, 4.156997535730132],
-    [-7.998781144995059, 1.5910564937027414, 4.156997535730132],
-    [-8.322239071932884, 1.6553962756976608, 4.499999999999999],
-    [-8.230988125160962, 2.0617551948423034, 4.499999999999999],
-    [-7.998781144995059, 1.5910564937027414, 4.156997535730132],
-    [-8.0672157046702, 1.1966584082371199, 4.156997535730132],
-    [-8.39344101583847, 1.2450493619292697, 4.499999999999999],
-    [-8.322239071932884, 1.6553962756976608, 4.499999999999999],
-    [-8.0672157046702, 1.1966584082371199, 4.156997535730132],
-    [-8.11621563510815, 0.7993774678511616, 4.156997535730132],
-    [-8.444422425158294, 0.8317030151945506, 4.499999999999999],
-    [-8.39344101583847, 1.2450493619292697, 4.499999999999999],
-    [-8.11621563510815, 0.7993774678511616, 4.156997535730132],
-    [-8.145662891184594, 0.4001707571279495, 4.156997535730132],
-    [-8.475060481211864, 0.4163530230476437, 4.499999999999999],
-    [-8.444422425158294, 0.8317030151945506, 4.499999999999999],
-    [-8.145662891184594, 0.4001707571279495, 4.156997535730132],
-    [-8.15548653188037, 9.987590476756644e-16, 4.156997535730132],
-    [-8.48528137423857, 1.0391472674825918e-15, 4.499999999999999],
-    [-8.475060481211864, 0.4163530230476437, 4.499999999999999],
-    [-8.15548653188037, 9.987590476756644e-16, 4.156997535730132],
-    [-8.145662891184594, -0.4001707571279475, 4.156997535730132],
-    [-8.475060481211864, -0.41635302304764166, 4.499999999999999],
-    [-8.48528137423857, 1.0391472674825918e-15, 4.499999999999999],
-    [-8.145662891184594, -0.4001707571279475, 4.156997535730132],
-    [-8.11621563510815, -0.7993774678511597, 4.156997535730132],
-    [-8.444422425158294, -0.8317030151945486, 4.499999999999999],
-    [-8.475060481211864, -0.41635302304764166, 4.499999999999999],
-    [-8.11621563510815, -0.7993774678511597, 4.156997535730132],
-    [-8.0672157046702, -1.196658408237118, 4.156997535730132],
-    [-8.39344101583847, -1.2450493619292677, 4.499999999999999],
-    [-8.444422425158294, -0.8317030151945486, 4.499999999999999],
-    [-8.0672157046702, -1.196658408237118, 4.156997535730132],
-    [-7.998781144995059, -1.5910564937027394, 4.156997535730132],
-    [-8.322239071932884, -1.6553962756976588, 4.499999999999999],
-    [-8.39344101583847, -1.2450493619292677, 4.499999999999999],
-    [-7.998781144995059, -1.5910564937027394, 4.156997535730132],
-    [-7.911076820931141, -1.9816215847149377, 4.156997535730132],
-    [-8.230988125160962, -2.061755194842301, 4.499999999999999],
-    [-8.322239071932884, -1.6553962756976588, 4.499999999999999],
-    [-7.911076820931141, -1.9816215847149377, 4.156997535730132],
-    [-7.80431401987711, -2.367412775760006, 4.156997535730132],
-    [-8.119908007046115, -2.4631471651341417, 4.499999999999999],
-    [-8.230988125160962, -2.061755194842301, 4.499999999999999],
-    [-7.80431401987711, -2.367412775760006, 4.156997535730132],
-    [-7.67874994277202, -2.7475006620674036, 4.156997535730132],
-    [-7.989266319322352, -2.8586051981589677, 4.499999999999999],
-    [-8.119908007046115, -2.4631471651341417, 4.499999999999999],
-    [-7.67874994277202, -2.7475006620674036, 4.156997535730132],
-    [-7.534687084475733, -3.1209695786272413, 4.156997535730132],
-    [-7.8393777892582595, -3.247176600877181, 4.499999999999999],
-    [-7.989266319322352, -2.8586051981589677, 4.499999999999999],
-    [-7.534687084475733, -3.1209695786272413, 4.156997535730132],
-    [-7.3724725050323, -3.4869198061075166, 4.156997535730132],
-    [-7.6706035114514455, -3.6279252707448015, 4.499999999999999],
-    [-7.8393777892582595, -3.247176600877181, 4.499999999999999],
-    [-7.3724725050323, -3.4869198061075166, 4.156997535730132],
-    [-7.192496993571917, -3.8444697383567794, 4.156997535730132],
-    [-7.483350077920027, -3.9999339508664784, 4.499999999999999],
-    [-7.6706035114514455, -3.6279252707448015, 4.499999999999999],
-    [-7.192496993571917, -3.8444697383567794, 4.156997535730132],
-    [-6.99519412686569, -4.192758006270558, 4.156997535730132],
-    [-7.278068598586847, -4.3623064397476785, 4.499999999999999],
-    [-7.483350077920027, -3.9999339508664784, 4.499999999999999],
-    [-6.99519412686569, -4.192758006270558, 4.156997535730132],
-    [-6.781039224801203, -4.530945552905003, 4.156997535730132],
-    [-7.055253614516154, -4.71416975032261, 4.499999999999999],
-    [-7.278068598586847, -4.3623064397476785, 4.499999999999999],
-    [-6.781039224801203, -4.530945552905003, 4.156997535730132],
-    [-6.550548205295279, -4.858217654838543, 4.156997535730132],
-    [-6.8154419065208565, -5.054676213056514, 4.499999999999999],
-    [-7.055253614516154, -4.71416975032261, 4.499999999999999],
-    [-6.550548205295279, -4.858217654838543, 4.156997535730132],
-    [-6.304276341402542, -5.173785884911966, 4.156997535730132],
-    [-6.5592112020105455, -5.383005518055815, 4.499999999999999],
-    [-6.8154419065208565, -5.054676213056514, 4.499999999999999],
-    [-6.304276341402542, -5.173785884911966, 4.156997535730132],
-    [-6.042816923613973, -5.476890011618517, 4.156997535730132],
-    [-6.287178783195542, -5.698366691266526, 4.499999999999999],
-    [-6.5592112020105455, -5.383005518055815, 4.499999999999999],
-    [-6.042816923613973, -5.476890011618517, 4.156997535730132],
-    [-5.76679983056817, -5.766799830568168, 4.156997535730132],
-    [-6.000000000000001, -5.999999999999999, 4.499999999999999],
-    [-6.287178783195542, -5.698366691266526, 4.499999999999999],
-    [-5.76679983056817, -5.766799830568168, 4.156997535730132],
-    [-5.476890011618519, -6.042816923613971, 4.156997535730132],
-    [-5.698366691266528, -6.28717878319554, 4.499999999999999],
-    [-6.000000000000001, -5.999999999999999, 4.499999999999999],
-    [-5.476890011618519, -6.042816923613971, 4.156997535730132],
-    [-5.173785884911971, -6.304276341402538, 4.156997535730132],
-    [-5.383005518055821, -6.559211202010542, 4.499999999999999],
-    [-5.698366691266528, -6.28717878319554, 4.499999999999999],
-    [-5.173785884911971, -6.304276341402538, 4.156997535730132],
-    [-4.858217654838542, -6.5505482052952795, 4.156997535730132],
-    [-5.0546762130565135, -6.815441906520857, 4.499999999999999],
-    [-5.383005518055821, -6.559211202010542, 4.499999999999999],
-    [-4.858217654838542, -6.5505482052952795, 4.156997535730132],
-    [-4.5309455529050044, -6.781039224801201, 4.156997535730132],
-    [-4.714169750322612, -7.055253614516151, 4.499999999999999],
-    [-5.0546762130565135, -6.815441906520857, 4.499999999999999],
-    [-4.5309455529050044, -6.781039224801201, 4.156997535730132],
-    [-4.19275800627056, -6.995194126865689, 4.156997535730132],
-    [-4.36230643974768, -7.278068598586846, 4.499999999999999],
-    [-4.714169750322612, -7.055253614516151, 4.499999999999999],
-    [-4.19275800627056, -6.995194126865689, 4.156997535730132],
-    [-3.844469738356781, -7.1924969935719165, 4.156997535730132],
-    [-3.9999339508664806, -7.483350077920026, 4.499999999999999],
-    [-4.36230643974768, -7.278068598586846, 4.499999999999999],
-    [-3.844469738356781, -7.1924969935719165, 4.156997535730132],
-    [-3.4869198061075224, -7.372472505032297, 4.156997535730132],
-    [-3.6279252707448073, -7.670603511451443, 4.499999999999999],
-    [-3.9999339508664806, -7.483350077920026, 4.499999999999999],
-    [-3.4869198061075224, -7.372472505032297, 4.156997535730132],
-    [-3.120969578627247, -7.53468708447573, 4.156997535730132],
-    [-3.2471766008771863, -7.839377789258257, 4.499999999999999],
-    [-3.6279252707448073, -7.670603511451443, 4.499999999999999],
-    [-3.120969578627247, -7.53468708447573, 4.156997535730132],
-    [-2.7475006620674023, -7.67874994277202, 4.156997535730132],
-    [-2.8586051981589664, -7.989266319322352, 4.499999999999999],
-    [-3.2471766008771863, -7.839377789258257, 4.499999999999999],
-    [-2.7475006620674023, -7.67874994277202, 4.156997535730132],
-    [-2.3674127757600085, -7.8043140198771095, 4.156997535730132],
-    [-2.463147165134145, -8.119908007046115, 4.499999999999999],
-    [-2.8586051981589664, -7.989266319322352, 4.499999999999999],
-    [-2.3674127757600085, -7.8043140198771095, 4.156997535730132],
-    [-1.98162158471494, -7.911076820931141, 4.156997535730132],
-    [-2.061755194842304, -8.230988125160962, 4.499999999999999],
-    [-2.463147165134145, -8.119908007046115, 4.499999999999999],
-    [-1.98162158471494, -7.911076820931141, 4.156997535730132],
-    [-1.5910564937027418, -7.998781144995058, 4.156997535730132],
-    [-1.6553962756976612, -8.322239071932882, 4.499999999999999],
-    [-2.061755194842304, -8.230988125160962, 4.499999999999999],
-    [-1.5910564937027418, -7.998781144995058, 4.156997535730132],
-    [-1.196658408237124, -8.0672157046702, 4.156997535730132],
-    [-1.245049361929274, -8.393441015838468, 4.499999999999999],
-    [-1.6553962756976612, -8.322239071932882, 4.499999999999999],
-    [-1.196658408237124, -8.0672157046702, 4.156997535730132],
-    [-0.7993774678511586, -8.11621563510815, 4.156997535730132],
-    [-0.8317030151945474, -8.444422425158294, 4.499999999999999],
-    [-1.245049361929274, -8.393441015838468, 4.499999999999999],
-    [-0.7993774678511586, -8.11621563510815, 4.156997535730132],
-    [-0.40017075712794997, -8.145662891184594, 4.156997535730132],
-    [-0.4163530230476442, -8.475060481211864, 4.499999999999999],
-    [-0.8317030151945474, -8.444422425158294, 4.499999999999999],
-    [-0.40017075712794997, -8.145662891184594, 4.156997535730132],
-    [
-        -1.4981385715134965e-15,
-        -8.15548653188037,
-        4.156997535730132,
-    ],
-    [
-        -1.5587209012238878e-15,
-        -8.48528137423857,
-        4.499999999999999,
-    ],
-    [-0.4163530230476442, -8.475060481211864, 4.499999999999999],
-    [
-        -1.4981385715134965e-15,
-        -8.15548653188037,
-        4.156997535730132,
-    ],
-    [0.400170757127947, -8.145662891184594, 4.156997535730132],
-    [0.4163530230476411, -8.475060481211864, 4.499999999999999],
-    [
-        -1.5587209012238878e-15,
-        -8.48528137423857,
-        4.499999999999999,
-    ],
-    [0.400170757127947, -8.145662891184594, 4.156997535730132],
-    [0.7993774678511556, -8.11621563510815, 4.156997535730132],
-    [0.8317030151945444, -8.444422425158294, 4.499999999999999],
-    [0.4163530230476411, -8.475060481211864, 4.499999999999999],
-    [0.7993774678511556, -8.11621563510815, 4.156997535730132],
-    [1.196658408237121, -8.0672157046702, 4.156997535730132],
-    [1.2450493619292708, -8.393441015838468, 4.499999999999999],
-    [0.8317030151945444, -8.444422425158294, 4.499999999999999],
-    [1.196658408237121, -8.0672157046702, 4.156997535730132],
-    [1.591056493702739, -7.998781144995059, 4.156997535730132],
-    [1.6553962756976581, -8.322239071932884, 4.499999999999999],
-    [1.2450493619292708, -8.393441015838468, 4.499999999999999],
-    [1.591056493702739, -7.998781144995059, 4.156997535730132],
-    [1.981621584714937, -7.911076820931141, 4.156997535730132],
-    [2.0617551948423007, -8.230988125160962, 4.499999999999999],
-    [1.6553962756976581, -8.322239071932884, 4.499999999999999],
-    [1.981621584714937, -7.911076820931141, 4.156997535730132],
-    [2.3674127757600054, -7.80431401987711, 4.156997535730132],
-    [2.4631471651341412, -8.119908007046115, 4.499999999999999],
-    [2.0617551948423007, -8.230988125160962, 4.499999999999999],
-    [2.3674127757600054, -7.80431401987711, 4.156997535730132],
-    [2.7475006620673996, -7.678749942772021, 4.156997535730132],
-    [2.8586051981589633, -7.989266319322353, 4.499999999999999],
-    [2.4631471651341412, -8.119908007046115, 4.499999999999999],
-    [2.7475006620673996, -7.678749942772021, 4.156997535730132],
-    [3.1209695786272444, -7.534687084475731, 4.156997535730132],
-    [3.2471766008771836, -7.839377789258258, 4.499999999999999],
-    [2.8586051981589633, -7.989266319322353, 4.499999999999999],
-    [3.1209695786272444, -7.534687084475731, 4.156997535730132],
-    [3.4869198061075197, -7.372472505032299, 4.156997535730132],
-    [3.6279252707448046, -7.670603511451445, 4.499999999999999],
-    [3.2471766008771836, -7.839377789258258, 4.499999999999999],
-    [3.4869198061075197, -7.372472505032299, 4.156997535730132],
-    [3.844469738356779, -7.192496993571917, 4.156997535730132],
-    [3.999933950866478, -7.483350077920027, 4.499999999999999],
-    [3.6279252707448046, -7.670603511451445, 4.499999999999999],
-    [3.844469738356779, -7.192496993571917, 4.156997535730132],
-    [4.192758006270558, -6.995194126865691, 4.156997535730132],
-    [4.3623064397476785, -7.278068598586848, 4.499999999999999],
-    [3.999933950866478, -7.483350077920027, 4.499999999999999],
-    [4.192758006270558, -6.995194126865691, 4.156997535730132],
-    [4.530945552905002, -6.781039224801203, 4.156997535730132],
-    [4.714169750322609, -7.055253614516154, 4.499999999999999],
-    [4.3623064397476785, -7.278068598586848, 4.499999999999999],
-    [4.530945552905002, -6.781039224801203, 4.156997535730132],
-    [4.85821765483854, -6.550548205295281, 4.156997535730132],
-    [5.05467621305651, -6.815441906520859, 4.499999999999999],
-    [4.714169750322609, -7.055253614516154, 4.499999999999999],
-    [4.85821765483854, -6.550548205295281, 4.156997535730132],
-    [5.173785884911968, -6.30427634140254, 4.156997535730132],
-    [5.3830055180558185, -6.559211202010544, 4.499999999999999],
-    [5.05467621305651, -6.815441906520859, 4.499999999999999],
-    [5.173785884911968, -6.30427634140254, 4.156997535730132],
-    [5.476890011618516, -6.042816923613973, 4.156997535730132],
-    [5.698366691266525, -6.287178783195542, 4.499999999999999],
-    [5.3830055180558185, -6.559211202010544, 4.499999999999999],
-    [5.476890011618516, -6.042816923613973, 4.156997535730132],
-    [5.766799830568167, -5.76679983056817, 4.156997535730132],
-    [5.999999999999998, -6.000000000000001, 4.499999999999999],
-    [5.698366691266525, -6.287178783195542, 4.499999999999999],
-    [5.766799830568167, -5.76679983056817, 4.156997535730132],
-    [6.042816923613971, -5.476890011618519, 4.156997535730132],
-    [6.28717878319554, -5.698366691266528, 4.499999999999999],
-    [5.999999999999998, -6.000000000000001, 4.499999999999999],
-    [6.042816923613971, -5.476890011618519, 4.156997535730132],
-    [6.304276341402537, -5.173785884911971, 4.156997535730132],
-    [6.559211202010541, -5.383005518055821, 4.499999999999999],
-    [6.28717878319554, -5.698366691266528, 4.499999999999999],
-    [6.304276341402537, -5.173785884911971, 4.156997535730132],
-    [6.5505482052952795, -4.858217654838543, 4.156997535730132],
-    [6.815441906520857, -5.054676213056514, 4.499999999999999],
-    [6.559211202010541, -5.383005518055821, 4.499999999999999],
-    [6.5505482052952795, -4.858217654838543, 4.156997535730132],
-    [6.781039224801201, -4.5309455529050044, 4.156997535730132],
-    [7.055253614516151, -4.714169750322612, 4.499999999999999],
-    [6.815441906520857, -5.054676213056514, 4.499999999999999],
-    [6.781039224801201, -4.5309455529050044, 4.156997535730132],
-    [6.995194126865689, -4.1927580062705605, 4.156997535730132],
-    [7.278068598586846, -4.362306439747681, 4.499999999999999],
-    [7.055253614516151, -4.714169750322612, 4.499999999999999],
-    [6.995194126865689, -4.1927580062705605, 4.156997535730132],
-    [7.192496993571916, -3.8444697383567816, 4.156997535730132],
-    [7.483350077920025, -3.999933950866481, 4.499999999999999],
-    [7.278068598586846, -4.362306439747681, 4.499999999999999],
-    [7.192496993571916, -3.8444697383567816, 4.156997535730132],
-    [7.372472505032297, -3.486919806107523, 4.156997535730132],
-    [7.670603511451443, -3.6279252707448078, 4.499999999999999],
-    [7.483350077920025, -3.999933950866481, 4.499999999999999],
-    [7.372472505032297, -3.486919806107523, 4.156997535730132],
-    [7.53468708447573, -3.1209695786272476, 4.156997535730132],
-    [7.839377789258257, -3.2471766008771867, 4.499999999999999],
-    [7.670603511451443, -3.6279252707448078, 4.499999999999999],
-    [7.53468708447573, -3.1209695786272476, 4.156997535730132],
-    [7.67874994277202, -2.7475006620674027, 4.156997535730132],
-    [7.989266319322352, -2.858605198158967, 4.499999999999999],
-    [7.839377789258257, -3.2471766008771867, 4.499999999999999],
-    [7.67874994277202, -2.7475006620674027, 4.156997535730132],
-    [7.8043140198771095, -2.367412775760009, 4.156997535730132],
-    [8.119908007046115, -2.4631471651341452, 4.499999999999999],
-    [7.989266319322352, -2.858605198158967, 4.499999999999999],
-    [7.8043140198771095, -2.367412775760009, 4.156997535730132],
-    [7.911076820931141, -1.9816215847149405, 4.156997535730132],
-    [8.230988125160962, -2.0617551948423043, 4.499999999999999],
-    [8.119908007046115, -2.4631471651341452, 4.499999999999999],
-    [7.911076820931141, -1.9816215847149405, 4.156997535730132],
-    [7.998781144995058, -1.5910564937027423, 4.156997535730132],
-    [8.322239071932882, -1.6553962756976617, 4.499999999999999],
-    [8.230988125160962, -2.0617551948423043, 4.499999999999999],
-    [7.998781144995058, -1.5910564937027423, 4.156997535730132],
-    [8.0672157046702, -1.1966584082371248, 4.156997535730132],
-    [8.393441015838468, -1.2450493619292746, 4.499999999999999],
-    [8.322239071932882, -1.6553962756976617, 4.499999999999999],
-    [8.0672157046702, -1.1966584082371248, 4.156997535730132],
-    [8.11621563510815, -0.799377467851159, 4.156997535730132],
-    [8.444422425158294, -0.8317030151945479, 4.499999999999999],
-    [8.393441015838468, -1.2450493619292746, 4.499999999999999],
-    [8.11621563510815, -0.799377467851159, 4.156997535730132],
-    [8.145662891184594, -0.40017075712795047, 4.156997535730132],
-    [8.475060481211864, -0.41635302304764477, 4.499999999999999],
-    [8.444422425158294, -0.8317030151945479, 4.499999999999999],
-    [8.145662891184594, -0.40017075712795047, 4.156997535730132],
-    [8.15548653188037, -1.9975180953513288e-15, 4.156997535730132],
-    [8.48528137423857, -2.0782945349651837e-15, 4.499999999999999],
-    [8.475060481211864, -0.41635302304764477, 4.499999999999999],
-];
-
-pub const dounat_blocks: [i32; 23] = [
-    32, 32, 32, 32, 64, 64, 64, 64, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
-    128, 128, 128,
-];
@@ -0,0 +1,505 @@
+//! 温度梯度形变模拟
+//!
+//! 由给定的温度分布计算面板与背架的热膨胀，作为 [`MockingDataFn::Thermal`] 的高度。
+//! 每个促动器处有前表面温度 `T_f` 与背架背面温度 `T_b`（相对装配温度的温升），高度为
+//!
+//! ```text
+//! h = α_p·t·T_f + α_b·(d + z)·(T_f + T_b)/2 + α_b·(T_f - T_b)·r²/(2d)
+//! ```
+//!
+//! 三项依次为面板厚度方向的膨胀、背架在该处的轴向伸长，以及前后温差使背架弯曲引起的
+//! 下垂。`t` 为面板厚度，`d` 为背架高度，`z`、`r` 为促动器相对顶点的轴向高度与半径。
+//!
+//! [`MockingDataFn::Thermal`]: crate::MockingDataFn::Thermal
+
+use crate::analysis::beam::APERTURE_DIAMETER_METERS;
+use crate::mount::Mount;
+use crate::{actuator::ActuatorTopology, ButtonID, CustomTextFont, MockingDataFn};
+use bevy::{color::palettes::css::*, prelude::*, text::FontSmoothing};
+use std::fmt::{self, Formatter};
+
+/// 温差每次调整的步长（K）
+const DELTA_T_STEP: f32 = 0.5;
+/// 太阳方位、仰角每次调整的步长（度）
+const SUN_AZIMUTH_STEP: f32 = 15.0;
+const SUN_ELEVATION_STEP: f32 = 5.0;
+/// 膨胀系数每次调整的倍数
+const EXPANSION_FACTOR: f32 = 1.25;
+
+pub struct ThermalPlugin;
+
+impl Plugin for ThermalPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ThermalModel>()
+            .init_resource::<ThermalSettings>()
+            .add_systems(PostStartup, spawn_thermal_panel)
+            .add_systems(
+                Update,
+                (
+                    update_thermal_panel.run_if(resource_changed::<ThermalSettings>),
+                    toggle_thermal_panel.run_if(state_changed::<MockingDataFn>),
+                ),
+            );
+    }
+}
+
+/// 温度分布
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum ThermalGradient {
+    // 前表面比背架背面高 ΔT
+    #[default]
+    FrontToBack,
+    // 前后温度相同，由中心向边缘线性升高到 ΔT
+    Radial,
+    // 太阳照射前表面，温升正比于入射角余弦
+    Solar,
+}
+
+impl fmt::Display for ThermalGradient {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThermalGradient::FrontToBack => write!(f, "前后温差"),
+            ThermalGradient::Radial => write!(f, "径向温差"),
+            ThermalGradient::Solar => write!(f, "日照"),
+        }
+    }
+}
+
+/// 热模型参数
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct ThermalSettings {
+    pub gradient: ThermalGradient,
+    /// 温差（K）
+    pub delta_t: f32,
+    /// 太阳方位、仰角（度），方位从北经东计量
+    pub sun_azimuth: f32,
+    pub sun_elevation: f32,
+    /// 面板线膨胀系数（1/K），默认为铝
+    pub panel_expansion: f32,
+    /// 背架线膨胀系数（1/K），默认为钢
+    pub backing_expansion: f32,
+    /// 面板厚度（米）
+    pub panel_thickness: f32,
+    /// 背架高度（米）
+    pub backing_depth: f32,
+}
+
+impl Default for ThermalSettings {
+    fn default() -> Self {
+        ThermalSettings {
+            gradient: ThermalGradient::default(),
+            delta_t: 2.0,
+            sun_azimuth: 180.0,
+            sun_elevation: 45.0,
+            panel_expansion: 23e-6,
+            backing_expansion: 12e-6,
+            panel_thickness: 0.1,
+            backing_depth: 6.0,
+        }
+    }
+}
+
+/// 各促动器的几何量，几何不变，启动时计算一次
+#[derive(Resource)]
+pub struct ThermalModel {
+    /// 相对口径半径的归一化半径
+    rho: Vec<f32>,
+    /// 半径与相对顶点的轴向高度（米）
+    radius: Vec<f32>,
+    height: Vec<f32>,
+    /// 指向天顶时的法线
+    normals: Vec<Vec3>,
+}
+
+impl FromWorld for ThermalModel {
+    fn from_world(world: &mut World) -> Self {
+        ThermalModel::new(world.resource::<ActuatorTopology>())
+    }
+}
+
+impl ThermalModel {
+    pub fn new(topology: &ActuatorTopology) -> Self {
+        let positions = topology.positions();
+        let outer = positions
+            .iter()
+            .map(|p| p.truncate().length())
+            .fold(0.0, f32::max);
+        let vertex = positions.iter().map(|p| p.z).fold(f32::INFINITY, f32::min);
+        let meters = APERTURE_DIAMETER_METERS as f32 / (2.0 * outer);
+        ThermalModel {
+            rho: positions
+                .iter()
+                .map(|p| p.truncate().length() / outer)
+                .collect(),
+            radius: positions
+                .iter()
+                .map(|p| p.truncate().length() * meters)
+                .collect(),
+            height: positions.iter().map(|p| (p.z - vertex) * meters).collect(),
+            normals: topology.normals().to_vec(),
+        }
+    }
+
+    /// 各促动器的前表面与背面温升（K）；日照按天线座当前指向计算入射角
+    pub fn temperatures(&self, settings: &ThermalSettings, mount: &Mount) -> Vec<(f32, f32)> {
+        match settings.gradient {
+            ThermalGradient::FrontToBack => vec![(settings.delta_t, 0.0); self.rho.len()],
+            ThermalGradient::Radial => self
+                .rho
+                .iter()
+                .map(|&rho| (settings.delta_t * rho, settings.delta_t * rho))
+                .collect(),
+            ThermalGradient::Solar => {
+                let (sin_az, cos_az) = settings.sun_azimuth.to_radians().sin_cos();
+                let (sin_el, cos_el) = settings.sun_elevation.to_radians().sin_cos();
+                let sun = Vec3::new(cos_el * sin_az, cos_el * cos_az, sin_el);
+                let rotation = mount.rotation();
+                self.normals
+                    .iter()
+                    .map(|&n| {
+                        let incidence = if settings.sun_elevation > 0.0 {
+                            (rotation * n).dot(sun).max(0.0)
+                        } else {
+                            0.0
+                        };
+                        (settings.delta_t * incidence, 0.0)
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// 热膨胀引起的高度（毫米）
+    pub fn surface(&self, settings: &ThermalSettings, mount: &Mount) -> Vec<f32> {
+        let d = settings.backing_depth.max(f32::EPSILON);
+        self.temperatures(settings, mount)
+            .iter()
+            .zip(self.radius.iter().zip(&self.height))
+            .map(|(&(front, back), (&r, &z))| {
+                let panel = settings.panel_expansion * settings.panel_thickness * front;
+                let backing = settings.backing_expansion * (d + z) * (front + back) / 2.0;
+                let bending = settings.backing_expansion * (front - back) * r * r / (2.0 * d);
+                (panel + backing + bending) * 1e3
+            })
+            .collect()
+    }
+}
+
+#[derive(Component)]
+struct ThermalPanel;
+
+#[derive(Component)]
+struct ThermalParameterText(ButtonID);
+
+// 面板在左上角，仅在选中温度梯度模拟时显示
+fn spawn_thermal_panel(
+    mut commands: Commands,
+    font: Res<CustomTextFont>,
+    data_fn: Res<State<MockingDataFn>>,
+) {
+    let text_font = TextFont {
+        font: font.0.clone(),
+        font_size: 16.0,
+        font_smoothing: FontSmoothing::AntiAliased,
+    };
+    commands
+        .spawn((
+            ThermalPanel,
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(72.0),
+                left: Val::Px(12.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                ..default()
+            },
+            BackgroundColor(BLACK.with_alpha(0.5).into()),
+            thermal_panel_visibility(data_fn.get()),
+        ))
+        .with_children(|p| {
+            p.spawn((
+                Text::new("温度梯度模拟"),
+                text_font.clone(),
+                TextColor(WHITE.into()),
+            ));
+            p.spawn(Node {
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(4.0),
+                ..default()
+            })
+            .with_children(|p1| {
+                crate::spawn_button(
+                    p1,
+                    "切换",
+                    text_font.clone(),
+                    ButtonID::SwitchThermalGradient,
+                    get_switch_thermal_fn(ButtonID::SwitchThermalGradient),
+                );
+                p1.spawn((
+                    Text::default(),
+                    ThermalParameterText(ButtonID::SwitchThermalGradient),
+                    text_font.clone(),
+                    TextColor(WHITE.into()),
+                ));
+            });
+            for (decrease, increase) in [
+                (
+                    ButtonID::SwitchThermalDeltaDecrease,
+                    ButtonID::SwitchThermalDeltaIncrease,
+                ),
+                (
+                    ButtonID::SwitchThermalSunAzimuthDecrease,
+                    ButtonID::SwitchThermalSunAzimuthIncrease,
+                ),
+                (
+                    ButtonID::SwitchThermalSunElevationDecrease,
+                    ButtonID::SwitchThermalSunElevationIncrease,
+                ),
+                (
+                    ButtonID::SwitchThermalPanelExpansionDecrease,
+                    ButtonID::SwitchThermalPanelExpansionIncrease,
+                ),
+                (
+                    ButtonID::SwitchThermalBackingExpansionDecrease,
+                    ButtonID::SwitchThermalBackingExpansionIncrease,
+                ),
+            ] {
+                p.spawn(Node {
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(4.0),
+                    ..default()
+                })
+                .with_children(|p1| {
+                    crate::spawn_button(
+                        p1,
+                        "-",
+                        text_font.clone(),
+                        decrease,
+                        get_switch_thermal_fn(decrease),
+                    );
+                    crate::spawn_button(
+                        p1,
+                        "+",
+                        text_font.clone(),
+                        increase,
+                        get_switch_thermal_fn(increase),
+                    );
+                    p1.spawn((
+                        Text::default(),
+                        ThermalParameterText(increase),
+                        text_font.clone(),
+                        TextColor(WHITE.into()),
+                    ));
+                });
+            }
+        });
+}
+
+fn thermal_panel_visibility(data_fn: &MockingDataFn) -> Visibility {
+    match data_fn {
+        MockingDataFn::Thermal => Visibility::Visible,
+        _ => Visibility::Hidden,
+    }
+}
+
+fn toggle_thermal_panel(
+    data_fn: Res<State<MockingDataFn>>,
+    mut panel: Query<&mut Visibility, With<ThermalPanel>>,
+) {
+    for mut visibility in panel.iter_mut() {
+        *visibility = thermal_panel_visibility(data_fn.get());
+    }
+}
+
+fn get_switch_thermal_fn(
+    typ: ButtonID,
+) -> impl FnMut(Trigger<Pointer<Down>>, ResMut<ThermalSettings>) {
+    move |_trigger: Trigger<Pointer<Down>>, mut settings: ResMut<ThermalSettings>| match typ {
+        ButtonID::SwitchThermalGradient => {
+            settings.gradient = match settings.gradient {
+                ThermalGradient::FrontToBack => ThermalGradient::Radial,
+                ThermalGradient::Radial => ThermalGradient::Solar,
+                ThermalGradient::Solar => ThermalGradient::FrontToBack,
+            }
+        }
+        ButtonID::SwitchThermalDeltaDecrease => settings.delta_t -= DELTA_T_STEP,
+        ButtonID::SwitchThermalDeltaIncrease => settings.delta_t += DELTA_T_STEP,
+        ButtonID::SwitchThermalSunAzimuthDecrease => {
+            settings.sun_azimuth = (settings.sun_azimuth - SUN_AZIMUTH_STEP).rem_euclid(360.0)
+        }
+        ButtonID::SwitchThermalSunAzimuthIncrease => {
+            settings.sun_azimuth = (settings.sun_azimuth + SUN_AZIMUTH_STEP).rem_euclid(360.0)
+        }
+        ButtonID::SwitchThermalSunElevationDecrease => {
+            settings.sun_elevation = (settings.sun_elevation - SUN_ELEVATION_STEP).max(-90.0)
+        }
+        ButtonID::SwitchThermalSunElevationIncrease => {
+            settings.sun_elevation = (settings.sun_elevation + SUN_ELEVATION_STEP).min(90.0)
+        }
+        ButtonID::SwitchThermalPanelExpansionDecrease => {
+            settings.panel_expansion /= EXPANSION_FACTOR
+        }
+        ButtonID::SwitchThermalPanelExpansionIncrease => {
+            settings.panel_expansion *= EXPANSION_FACTOR
+        }
+        ButtonID::SwitchThermalBackingExpansionDecrease => {
+            settings.backing_expansion /= EXPANSION_FACTOR
+        }
+        ButtonID::SwitchThermalBackingExpansionIncrease => {
+            settings.backing_expansion *= EXPANSION_FACTOR
+        }
+        _ => {}
+    }
+}
+
+fn update_thermal_panel(
+    settings: Res<ThermalSettings>,
+    mut texts: Query<(&ThermalParameterText, &mut Text)>,
+) {
+    for (parameter, mut text) in texts.iter_mut() {
+        text.0 = match parameter.0 {
+            ButtonID::SwitchThermalGradient => format!("温度分布: {}", settings.gradient),
+            ButtonID::SwitchThermalDeltaIncrease => format!("温差: {:.1} K", settings.delta_t),
+            ButtonID::SwitchThermalSunAzimuthIncrease => {
+                format!("太阳方位: {:.0}°", settings.sun_azimuth)
+            }
+            ButtonID::SwitchThermalSunElevationIncrease => {
+                format!("太阳仰角: {:.0}°", settings.sun_elevation)
+            }
+            ButtonID::SwitchThermalPanelExpansionIncrease => {
+                format!("面板膨胀系数: {:.2e} /K", settings.panel_expansion)
+            }
+            ButtonID::SwitchThermalBackingExpansionIncrease => {
+                format!("背架膨胀系数: {:.2e} /K", settings.backing_expansion)
+            }
+            _ => continue,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::ReflectorGeometry;
+
+    fn topology_model() -> ThermalModel {
+        ThermalModel::new(&ActuatorTopology::from_geometry(
+            &ReflectorGeometry::default(),
+        ))
+    }
+
+    // 顶点处（r = z = 0）与另一个轴外的促动器
+    fn point_model() -> ThermalModel {
+        ThermalModel {
+            rho: vec![0.0, 0.5],
+            radius: vec![0.0, 20.0],
+            height: vec![0.0, 3.0],
+            normals: vec![Vec3::Z, Vec3::Z],
+        }
+    }
+
+    #[test]
+    fn front_to_back_matches_hand_computed_value() {
+        let settings = ThermalSettings::default();
+        let (alpha_p, alpha_b) = (settings.panel_expansion, settings.backing_expansion);
+        let (t, d, delta_t) = (
+            settings.panel_thickness,
+            settings.backing_depth,
+            settings.delta_t,
+        );
+        let surface = point_model().surface(&settings, &Mount::ZENITH);
+
+        // r = 0 处只有面板膨胀与背架伸长：α_p·t·ΔT + α_b·d·ΔT/2
+        let vertex = (alpha_p * t * delta_t + alpha_b * d * delta_t / 2.0) * 1e3;
+        assert!(
+            (surface[0] - vertex).abs() < 1e-6,
+            "{} != {}",
+            surface[0],
+            vertex
+        );
+        // 2 K·(0.1 m·23e-6 + 6 m·12e-6/2) = 76.6 μm
+        assert!((surface[0] - 0.0766).abs() < 1e-6, "{}", surface[0]);
+
+        // 轴外再加上背架伸长的 z 项与弯曲项
+        let (r, z) = (20.0, 3.0);
+        let expected = (alpha_p * t * delta_t
+            + alpha_b * (d + z) * delta_t / 2.0
+            + alpha_b * delta_t * r * r / (2.0 * d))
+            * 1e3;
+        assert!(
+            (surface[1] - expected).abs() < 1e-5,
+            "{} != {}",
+            surface[1],
+            expected
+        );
+    }
+
+    #[test]
+    fn radial_gradient_does_not_bend() {
+        let settings = ThermalSettings {
+            gradient: ThermalGradient::Radial,
+            ..default()
+        };
+        let model = point_model();
+        assert_eq!(
+            model.temperatures(&settings, &Mount::ZENITH),
+            vec![(0.0, 0.0), (1.0, 1.0)]
+        );
+        // 前后温度相同，只有面板膨胀与背架伸长
+        let surface = model.surface(&settings, &Mount::ZENITH);
+        assert_eq!(surface[0], 0.0);
+        let expected = (settings.panel_expansion * settings.panel_thickness
+            + settings.backing_expansion * (settings.backing_depth + 3.0))
+            * 1e3;
+        assert!((surface[1] - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn sun_behind_dish_does_not_heat() {
+        let model = topology_model();
+        // 指向北方低仰角，太阳在南方地平线附近，照在反射面背面
+        let mount = Mount {
+            azimuth: 0.0,
+            elevation: 30.0,
+        };
+        let settings = ThermalSettings {
+            gradient: ThermalGradient::Solar,
+            sun_azimuth: 180.0,
+            sun_elevation: 5.0,
+            ..default()
+        };
+        assert!(model
+            .temperatures(&settings, &mount)
+            .iter()
+            .all(|&(front, back)| front == 0.0 && back == 0.0));
+        assert!(model.surface(&settings, &mount).iter().all(|&h| h == 0.0));
+
+        // 太阳在地平线以下时同样没有日照
+        let night = ThermalSettings {
+            sun_elevation: -10.0,
+            ..settings
+        };
+        assert!(model
+            .temperatures(&night, &Mount::ZENITH)
+            .iter()
+            .all(|&(front, _)| front == 0.0));
+    }
+
+    #[test]
+    fn sun_on_boresight_heats_vertex_fully() {
+        let settings = ThermalSettings {
+            gradient: ThermalGradient::Solar,
+            sun_azimuth: 90.0,
+            sun_elevation: 40.0,
+            ..default()
+        };
+        let mount = Mount {
+            azimuth: 90.0,
+            elevation: 40.0,
+        };
+        let temperatures = point_model().temperatures(&settings, &mount);
+        assert!((temperatures[0].0 - settings.delta_t).abs() < 1e-5);
+        assert_eq!(temperatures[0].1, 0.0);
+    }
+}
@@ -0,0 +1,395 @@
+//! 仿真时间轴
+//!
+//! 模拟函数与录制回放统一由 [`SimulationClock`] 驱动，而不是窗口的 `Time`。
+//! 时钟在 [`MockingState::Start`] 时按 [`MockingSpeed`] 前进，可以拖动进度条跳转、
+//! 单步前进后退，并在任意时刻添加标记。
+
+use crate::data_source::{recording::Playback, DataSource, HeightSet};
+use crate::{MockingSpeed, MockingState};
+use bevy::{color::palettes::css::*, prelude::*};
+
+/// 模拟数据的时间轴长度（秒）
+pub const MOCK_TIMELINE_SECS: f64 = 600.0;
+/// 模拟数据单步前进或后退的时长（秒），回放时按录制帧单步
+pub const MOCK_STEP_SECS: f64 = 0.1;
+/// 两个标记间隔小于该值时视为同一个
+const MARKER_TOLERANCE: f64 = 1e-3;
+
+pub struct TimelinePlugin;
+
+impl Plugin for TimelinePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SimulationClock>()
+            .add_systems(OnExit(DataSource::Playback), reset_mock_duration)
+            .add_systems(
+                Update,
+                (
+                    advance_clock
+                        .before(HeightSet::Source)
+                        .run_if(in_state(MockingState::Start)),
+                    (update_timeline_progress, update_timeline_markers)
+                        .run_if(resource_changed::<SimulationClock>),
+                ),
+            );
+    }
+}
+
+#[derive(Resource, Debug, Clone)]
+pub struct SimulationClock {
+    elapsed: f64,
+    duration: f64,
+    /// 到达结尾后是否从头开始
+    pub looping: bool,
+    /// 标记的时刻，按时间排序
+    markers: Vec<f64>,
+}
+
+impl Default for SimulationClock {
+    fn default() -> Self {
+        SimulationClock {
+            elapsed: 0.0,
+            duration: MOCK_TIMELINE_SECS,
+            looping: true,
+            markers: vec![],
+        }
+    }
+}
+
+impl SimulationClock {
+    pub fn elapsed(&self) -> f64 {
+        self.elapsed
+    }
+
+    pub fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    pub fn markers(&self) -> &[f64] {
+        &self.markers
+    }
+
+    /// 当前进度，取值 0..=1
+    pub fn progress(&self) -> f64 {
+        if self.duration > 0.0 {
+            self.elapsed / self.duration
+        } else {
+            0.0
+        }
+    }
+
+    pub fn seek(&mut self, t: f64) {
+        self.elapsed = t.clamp(0.0, self.duration);
+    }
+
+    /// 设置时间轴长度，超出新长度的进度与标记会被截掉
+    pub fn set_duration(&mut self, duration: f64) {
+        self.duration = duration.max(0.0);
+        self.elapsed = self.elapsed.min(self.duration);
+        self.markers.retain(|&t| t <= self.duration);
+    }
+
+    pub fn advance(&mut self, dt: f64) {
+        let t = self.elapsed + dt;
+        self.elapsed = if t <= self.duration {
+            t
+        } else if self.looping && self.duration > 0.0 {
+            t % self.duration
+        } else {
+            self.duration
+        };
+    }
+
+    /// 在当前时刻添加标记
+    pub fn add_marker(&mut self) {
+        let t = self.elapsed;
+        if self
+            .markers
+            .iter()
+            .any(|&m| (m - t).abs() < MARKER_TOLERANCE)
+        {
+            return;
+        }
+        let index = self.markers.partition_point(|&m| m < t);
+        self.markers.insert(index, t);
+    }
+
+    pub fn clear_markers(&mut self) {
+        self.markers.clear();
+    }
+
+    /// 当前时刻之后的第一个标记，没有时回到第一个标记
+    pub fn next_marker(&self) -> Option<f64> {
+        self.markers
+            .iter()
+            .copied()
+            .find(|&m| m > self.elapsed + MARKER_TOLERANCE)
+            .or(self.markers.first().copied())
+    }
+}
+
+#[derive(Component)]
+pub struct TimelineText;
+
+/// 时间轴进度条，按下或拖动时跳转到对应时刻
+#[derive(Component)]
+pub struct TimelineBar;
+
+#[derive(Component)]
+pub struct TimelineFill;
+
+#[derive(Component)]
+pub struct TimelineMarker;
+
+fn advance_clock(mut clock: ResMut<SimulationClock>, speed: Res<MockingSpeed>, time: Res<Time>) {
+    clock.advance(time.delta_secs_f64() * speed.0 as f64);
+}
+
+fn reset_mock_duration(mut clock: ResMut<SimulationClock>) {
+    clock.set_duration(MOCK_TIMELINE_SECS);
+}
+
+/// 回放时按录制帧单步，其余数据源按 [`MOCK_STEP_SECS`] 单步
+pub fn step_clock(clock: &mut SimulationClock, playback: Option<&Playback>, forward: bool) {
+    let t = clock.elapsed();
+    let target = match playback {
+        Some(playback) => {
+            let timestamps = &playback.recording.timestamps;
+            if forward {
+                timestamps.iter().copied().find(|&ts| ts > t + 1e-9)
+            } else {
+                timestamps.iter().copied().rev().find(|&ts| ts < t - 1e-9)
+            }
+            .unwrap_or(t)
+        }
+        None if forward => t + MOCK_STEP_SECS,
+        None => t - MOCK_STEP_SECS,
+    };
+    clock.seek(target);
+}
+
+/// 在控制栏中添加时间显示与进度条
+pub fn spawn_timeline_bar(parent: &mut ChildBuilder<'_>, text_font: TextFont) {
+    parent.spawn((
+        Text::default(),
+        TimelineText,
+        text_font,
+        TextColor(BLACK.into()),
+    ));
+    parent
+        .spawn((
+            TimelineBar,
+            Node {
+                width: Val::Px(320.0),
+                height: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(DIM_GRAY.into()),
+        ))
+        .observe(on_timeline_pressed)
+        .observe(on_timeline_dragged)
+        .with_children(|p| {
+            p.spawn((
+                TimelineFill,
+                Node {
+                    width: Val::Percent(0.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(DODGER_BLUE.into()),
+            ));
+        });
+}
+
+fn on_timeline_pressed(
+    trigger: Trigger<Pointer<Down>>,
+    bars: Query<(&ComputedNode, &GlobalTransform), With<TimelineBar>>,
+    clock: ResMut<SimulationClock>,
+) {
+    seek_to_pointer(
+        trigger.entity(),
+        trigger.pointer_location.position,
+        bars,
+        clock,
+    );
+}
+
+fn on_timeline_dragged(
+    trigger: Trigger<Pointer<Drag>>,
+    bars: Query<(&ComputedNode, &GlobalTransform), With<TimelineBar>>,
+    clock: ResMut<SimulationClock>,
+) {
+    seek_to_pointer(
+        trigger.entity(),
+        trigger.pointer_location.position,
+        bars,
+        clock,
+    );
+}
+
+// 指针位置为逻辑像素，节点的位置与大小为物理像素
+fn seek_to_pointer(
+    bar: Entity,
+    pointer: Vec2,
+    bars: Query<(&ComputedNode, &GlobalTransform), With<TimelineBar>>,
+    mut clock: ResMut<SimulationClock>,
+) {
+    let Ok((node, transform)) = bars.get(bar) else {
+        return;
+    };
+    let scale = node.inverse_scale_factor();
+    let width = node.size().x * scale;
+    if width <= 0.0 {
+        return;
+    }
+    let left = transform.translation().x * scale - width / 2.0;
+    let fraction = ((pointer.x - left) / width).clamp(0.0, 1.0) as f64;
+    let duration = clock.duration();
+    clock.seek(fraction * duration);
+}
+
+fn update_timeline_progress(
+    clock: Res<SimulationClock>,
+    mut text: Query<&mut Text, With<TimelineText>>,
+    mut fill: Query<&mut Node, With<TimelineFill>>,
+) {
+    for mut text in text.iter_mut() {
+        text.0 = format!("{:.1} / {:.1}s", clock.elapsed(), clock.duration());
+    }
+    for mut node in fill.iter_mut() {
+        node.width = Val::Percent(clock.progress() as f32 * 100.0);
+    }
+}
+
+// 标记或时间轴长度变化时重建进度条上的标记
+fn update_timeline_markers(
+    mut commands: Commands,
+    clock: Res<SimulationClock>,
+    bars: Query<Entity, With<TimelineBar>>,
+    markers: Query<Entity, With<TimelineMarker>>,
+    mut shown: Local<(Vec<f64>, f64)>,
+) {
+    if shown.0 == clock.markers() && shown.1 == clock.duration() {
+        return;
+    }
+    *shown = (clock.markers().to_vec(), clock.duration());
+    for marker in markers.iter() {
+        commands.entity(marker).despawn_recursive();
+    }
+    if clock.duration() <= 0.0 {
+        return;
+    }
+    for bar in bars.iter() {
+        commands.entity(bar).with_children(|p| {
+            for &t in clock.markers() {
+                p.spawn((
+                    TimelineMarker,
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Percent((t / clock.duration()) as f32 * 100.0),
+                        width: Val::Px(2.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(YELLOW.into()),
+                ));
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    fn clock(duration: f64, looping: bool) -> SimulationClock {
+        let mut clock = SimulationClock {
+            looping,
+            ..default()
+        };
+        clock.set_duration(duration);
+        clock
+    }
+
+    #[test]
+    fn looping_clock_wraps() {
+        let mut clock = clock(10.0, true);
+        clock.advance(7.5);
+        assert_close(clock.elapsed(), 7.5);
+        clock.advance(4.0);
+        assert_close(clock.elapsed(), 1.5);
+        // 一次前进超过多个周期
+        clock.advance(25.0);
+        assert_close(clock.elapsed(), 6.5);
+        // 恰好到达结尾时停在结尾，不回到 0
+        clock.advance(3.5);
+        assert_close(clock.elapsed(), 10.0);
+        assert_close(clock.progress(), 1.0);
+    }
+
+    #[test]
+    fn non_looping_clock_clamps() {
+        let mut clock = clock(10.0, false);
+        clock.advance(12.0);
+        assert_close(clock.elapsed(), 10.0);
+        clock.advance(1.0);
+        assert_close(clock.elapsed(), 10.0);
+
+        clock.seek(-3.0);
+        assert_close(clock.elapsed(), 0.0);
+        clock.seek(30.0);
+        assert_close(clock.elapsed(), 10.0);
+        step_clock(&mut clock, None, true);
+        assert_close(clock.elapsed(), 10.0);
+        step_clock(&mut clock, None, false);
+        assert_close(clock.elapsed(), 10.0 - MOCK_STEP_SECS);
+    }
+
+    #[test]
+    fn set_duration_keeps_markers_within_range() {
+        let mut clock = clock(100.0, true);
+        for t in [80.0, 20.0, 50.0, 50.0 + MARKER_TOLERANCE * 0.5] {
+            clock.seek(t);
+            clock.add_marker();
+        }
+        // 按时间排序，过近的标记只保留一个
+        assert_eq!(clock.markers(), &[20.0, 50.0, 80.0]);
+
+        clock.seek(90.0);
+        clock.set_duration(50.0);
+        assert_eq!(clock.markers(), &[20.0, 50.0]);
+        assert_close(clock.elapsed(), 50.0);
+
+        // 延长后被截掉的标记不会恢复
+        clock.set_duration(100.0);
+        assert_eq!(clock.markers(), &[20.0, 50.0]);
+
+        clock.set_duration(-1.0);
+        assert_close(clock.duration(), 0.0);
+        assert!(clock.markers().is_empty());
+        assert_close(clock.progress(), 0.0);
+    }
+
+    #[test]
+    fn next_marker_wraps_to_first() {
+        let mut clock = clock(100.0, true);
+        assert_eq!(clock.next_marker(), None);
+        for t in [20.0, 50.0] {
+            clock.seek(t);
+            clock.add_marker();
+        }
+        clock.seek(0.0);
+        assert_eq!(clock.next_marker(), Some(20.0));
+        // 正好停在标记上时跳到下一个
+        clock.seek(20.0);
+        assert_eq!(clock.next_marker(), Some(50.0));
+        clock.seek(60.0);
+        assert_eq!(clock.next_marker(), Some(20.0));
+
+        clock.clear_markers();
+        assert_eq!(clock.next_marker(), None);
+    }
+}
@@ -0,0 +1,414 @@
+//! 风载形变模拟
+//!
+//! 作为 [`MockingDataFn::Wind`] 的高度。风压为 `q = ½ρ(U + u')²`，`U` 为平均风速，`u'`
+//! 为脉动风速。反射面各处的形变正比于当地风压与体型系数：正面来风时整个口径被压向背面，
+//! 侧风时迎风一侧的边缘受载更大，
+//!
+//! ```text
+//! h = -C·q·(cosβ·ρ² + sinβ·ρ³·cosθ)
+//! ```
+//!
+//! 其中 `β` 为风向与反射面指向的夹角（0 为正面来风，180° 为背面来风），`θ` 为口径面内
+//! 相对迎风方向的方位角，`C` 为柔度。
+//!
+//! 脉动风速按 von Kármán 纵向谱做谱合成，各频率分量的相位由种子确定，因此相同的参数与
+//! 种子总是给出相同的风场。空间上按冻结湍流假设，阵风以平均风速沿风向扫过口径。
+//!
+//! [`MockingDataFn::Wind`]: crate::MockingDataFn::Wind
+
+use crate::analysis::beam::APERTURE_DIAMETER_METERS;
+use crate::helpers::rng::SplitMix64;
+use crate::{actuator::ActuatorTopology, cli::CliArgs, ButtonID, CustomTextFont, MockingDataFn};
+use bevy::{color::palettes::css::*, prelude::*, text::FontSmoothing};
+use std::f64::consts::TAU;
+
+/// 空气密度（kg/m³）
+const AIR_DENSITY: f64 = 1.225;
+/// 柔度：单位风压引起的边缘形变（毫米/帕），10 m/s 正面来风时边缘约 0.3 mm
+const COMPLIANCE: f64 = 5e-3;
+/// von Kármán 谱的湍流积分尺度（米）
+const INTEGRAL_LENGTH: f64 = 100.0;
+/// 谱合成的频率分量数与频率范围（Hz）
+const SPECTRAL_COMPONENTS: usize = 64;
+const MIN_FREQUENCY: f64 = 0.002;
+const MAX_FREQUENCY: f64 = 2.0;
+/// 面板上各参数每次调整的步长
+const SPEED_STEP: f32 = 1.0;
+const DIRECTION_STEP: f32 = 15.0;
+const TURBULENCE_STEP: f32 = 0.05;
+
+pub struct WindPlugin;
+
+impl Plugin for WindPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WindSettings>()
+            .init_resource::<WindModel>()
+            .add_systems(PostStartup, spawn_wind_panel)
+            .add_systems(
+                Update,
+                (
+                    (rebuild_wind_field, update_wind_panel)
+                        .run_if(resource_changed::<WindSettings>),
+                    toggle_wind_panel.run_if(state_changed::<MockingDataFn>),
+                ),
+            );
+    }
+}
+
+/// 风载参数
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct WindSettings {
+    /// 平均风速（m/s）
+    pub speed: f32,
+    /// 风向与反射面指向的夹角（度），0 为正面来风
+    pub direction: f32,
+    /// 湍流强度，脉动风速标准差与平均风速之比
+    pub turbulence: f32,
+    /// 脉动风场的随机种子，可通过 `--wind-seed` 指定
+    pub seed: u64,
+}
+
+impl FromWorld for WindSettings {
+    fn from_world(world: &mut World) -> Self {
+        WindSettings {
+            speed: 10.0,
+            direction: 30.0,
+            turbulence: 0.15,
+            seed: world
+                .get_resource::<CliArgs>()
+                .and_then(|cli| cli.wind_seed)
+                .unwrap_or(1),
+        }
+    }
+}
+
+/// von Kármán 纵向脉动风速功率谱（m²/s²/Hz）
+pub fn von_karman_spectrum(frequency: f64, speed: f64, sigma: f64) -> f64 {
+    if speed <= 0.0 {
+        return 0.0;
+    }
+    let n = frequency * INTEGRAL_LENGTH / speed;
+    4.0 * sigma * sigma * INTEGRAL_LENGTH / speed / (1.0 + 70.8 * n * n).powf(5.0 / 6.0)
+}
+
+/// 脉动风速的一个频率分量
+#[derive(Debug, Clone, Copy)]
+struct WindComponent {
+    frequency: f64,
+    amplitude: f64,
+    phase: f64,
+}
+
+/// 促动器几何与当前参数下的脉动风场
+#[derive(Resource)]
+pub struct WindModel {
+    /// 口径面内的位置（米）与归一化半径
+    positions: Vec<Vec2>,
+    rho: Vec<f32>,
+    components: Vec<WindComponent>,
+}
+
+impl FromWorld for WindModel {
+    fn from_world(world: &mut World) -> Self {
+        let settings = *world.resource::<WindSettings>();
+        let mut model = WindModel::new(world.resource::<ActuatorTopology>());
+        model.rebuild(&settings);
+        model
+    }
+}
+
+impl WindModel {
+    pub fn new(topology: &ActuatorTopology) -> Self {
+        let outer = topology
+            .positions()
+            .iter()
+            .map(|p| p.truncate().length())
+            .fold(0.0, f32::max);
+        let meters = APERTURE_DIAMETER_METERS as f32 / (2.0 * outer);
+        WindModel {
+            positions: topology
+                .positions()
+                .iter()
+                .map(|p| p.truncate() * meters)
+                .collect(),
+            rho: topology
+                .positions()
+                .iter()
+                .map(|p| p.truncate().length() / outer)
+                .collect(),
+            components: vec![],
+        }
+    }
+
+    /// 按 von Kármán 谱在对数均匀的频率上合成，相位由种子决定
+    pub fn rebuild(&mut self, settings: &WindSettings) {
+        let speed = settings.speed.max(0.0) as f64;
+        let sigma = settings.turbulence.max(0.0) as f64 * speed;
+        let mut rng = SplitMix64::new(settings.seed);
+        let ratio = (MAX_FREQUENCY / MIN_FREQUENCY).powf(1.0 / SPECTRAL_COMPONENTS as f64);
+        self.components = (0..SPECTRAL_COMPONENTS)
+            .map(|k| {
+                let low = MIN_FREQUENCY * ratio.powi(k as i32);
+                let high = low * ratio;
+                // 在频带内随机取频率，避免各分量公倍数周期造成的重复
+                let frequency = low + (high - low) * rng.next_f64();
+                let amplitude =
+                    (2.0 * von_karman_spectrum(frequency, speed, sigma) * (high - low)).sqrt();
+                WindComponent {
+                    frequency,
+                    amplitude,
+                    phase: TAU * rng.next_f64(),
+                }
+            })
+            .collect();
+    }
+
+    /// 时刻 `t`、沿风向坐标 `s`（米）处的脉动风速
+    fn gust(&self, t: f64, s: f64, speed: f64) -> f64 {
+        let delay = if speed > 0.0 { s / speed } else { 0.0 };
+        self.components
+            .iter()
+            .map(|c| c.amplitude * (TAU * c.frequency * (t - delay) + c.phase).cos())
+            .sum()
+    }
+
+    /// 时刻 `t`（秒）的风载形变（毫米）
+    pub fn surface(&self, settings: &WindSettings, t: f64) -> Vec<f32> {
+        let speed = settings.speed.max(0.0) as f64;
+        let (sin_beta, cos_beta) = (settings.direction as f64).to_radians().sin_cos();
+        // 侧风在口径面内沿 +x 吹过，正面来风的阵风沿轴线同时到达
+        self.positions
+            .iter()
+            .zip(&self.rho)
+            .map(|(p, &rho)| {
+                let rho = rho as f64;
+                let r = p.length() as f64;
+                let cos_theta = if r > 0.0 { -p.x as f64 / r } else { 0.0 };
+                let along = p.x as f64 * sin_beta;
+                let u = (speed + self.gust(t, along, speed)).max(0.0);
+                let q = 0.5 * AIR_DENSITY * u * u;
+                let shape = cos_beta * rho * rho + sin_beta * rho.powi(3) * cos_theta;
+                (-COMPLIANCE * q * shape) as f32
+            })
+            .collect()
+    }
+}
+
+fn rebuild_wind_field(settings: Res<WindSettings>, mut model: ResMut<WindModel>) {
+    model.rebuild(&settings);
+}
+
+#[derive(Component)]
+struct WindPanel;
+
+#[derive(Component)]
+struct WindParameterText(ButtonID);
+
+// 面板在左上角，仅在选中风载模拟时显示
+fn spawn_wind_panel(
+    mut commands: Commands,
+    font: Res<CustomTextFont>,
+    data_fn: Res<State<MockingDataFn>>,
+) {
+    let text_font = TextFont {
+        font: font.0.clone(),
+        font_size: 16.0,
+        font_smoothing: FontSmoothing::AntiAliased,
+    };
+    commands
+        .spawn((
+            WindPanel,
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(72.0),
+                left: Val::Px(12.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                ..default()
+            },
+            BackgroundColor(BLACK.with_alpha(0.5).into()),
+            wind_panel_visibility(data_fn.get()),
+        ))
+        .with_children(|p| {
+            p.spawn((
+                Text::new("风载模拟"),
+                text_font.clone(),
+                TextColor(WHITE.into()),
+            ));
+            for (decrease, increase) in [
+                (
+                    ButtonID::SwitchWindSpeedDecrease,
+                    ButtonID::SwitchWindSpeedIncrease,
+                ),
+                (
+                    ButtonID::SwitchWindDirectionDecrease,
+                    ButtonID::SwitchWindDirectionIncrease,
+                ),
+                (
+                    ButtonID::SwitchWindTurbulenceDecrease,
+                    ButtonID::SwitchWindTurbulenceIncrease,
+                ),
+                (
+                    ButtonID::SwitchWindSeedDecrease,
+                    ButtonID::SwitchWindSeedIncrease,
+                ),
+            ] {
+                p.spawn(Node {
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(4.0),
+                    ..default()
+                })
+                .with_children(|p1| {
+                    crate::spawn_button(
+                        p1,
+                        "-",
+                        text_font.clone(),
+                        decrease,
+                        get_switch_wind_fn(decrease),
+                    );
+                    crate::spawn_button(
+                        p1,
+                        "+",
+                        text_font.clone(),
+                        increase,
+                        get_switch_wind_fn(increase),
+                    );
+                    p1.spawn((
+                        Text::default(),
+                        WindParameterText(increase),
+                        text_font.clone(),
+                        TextColor(WHITE.into()),
+                    ));
+                });
+            }
+        });
+}
+
+fn wind_panel_visibility(data_fn: &MockingDataFn) -> Visibility {
+    match data_fn {
+        MockingDataFn::Wind => Visibility::Visible,
+        _ => Visibility::Hidden,
+    }
+}
+
+fn toggle_wind_panel(
+    data_fn: Res<State<MockingDataFn>>,
+    mut panel: Query<&mut Visibility, With<WindPanel>>,
+) {
+    for mut visibility in panel.iter_mut() {
+        *visibility = wind_panel_visibility(data_fn.get());
+    }
+}
+
+fn get_switch_wind_fn(typ: ButtonID) -> impl FnMut(Trigger<Pointer<Down>>, ResMut<WindSettings>) {
+    move |_trigger: Trigger<Pointer<Down>>, mut settings: ResMut<WindSettings>| match typ {
+        ButtonID::SwitchWindSpeedDecrease => {
+            settings.speed = (settings.speed - SPEED_STEP).max(0.0)
+        }
+        ButtonID::SwitchWindSpeedIncrease => settings.speed += SPEED_STEP,
+        ButtonID::SwitchWindDirectionDecrease => {
+            settings.direction = (settings.direction - DIRECTION_STEP).rem_euclid(360.0)
+        }
+        ButtonID::SwitchWindDirectionIncrease => {
+            settings.direction = (settings.direction + DIRECTION_STEP).rem_euclid(360.0)
+        }
+        ButtonID::SwitchWindTurbulenceDecrease => {
+            settings.turbulence = (settings.turbulence - TURBULENCE_STEP).max(0.0)
+        }
+        ButtonID::SwitchWindTurbulenceIncrease => settings.turbulence += TURBULENCE_STEP,
+        ButtonID::SwitchWindSeedDecrease => settings.seed = settings.seed.wrapping_sub(1),
+        ButtonID::SwitchWindSeedIncrease => settings.seed = settings.seed.wrapping_add(1),
+        _ => {}
+    }
+}
+
+fn update_wind_panel(
+    settings: Res<WindSettings>,
+    mut texts: Query<(&WindParameterText, &mut Text)>,
+) {
+    for (parameter, mut text) in texts.iter_mut() {
+        text.0 = match parameter.0 {
+            ButtonID::SwitchWindSpeedIncrease => format!("平均风速: {:.0} m/s", settings.speed),
+            ButtonID::SwitchWindDirectionIncrease => {
+                format!("风向(相对指向): {:.0}°", settings.direction)
+            }
+            ButtonID::SwitchWindTurbulenceIncrease => {
+                format!("湍流强度: {:.0}%", settings.turbulence * 100.0)
+            }
+            ButtonID::SwitchWindSeedIncrease => format!("随机种子: {}", settings.seed),
+            _ => continue,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::ReflectorGeometry;
+
+    fn model(settings: &WindSettings) -> WindModel {
+        let topology = ActuatorTopology::from_geometry(&ReflectorGeometry::default());
+        let mut model = WindModel::new(&topology);
+        model.rebuild(settings);
+        model
+    }
+
+    fn settings(seed: u64) -> WindSettings {
+        WindSettings {
+            speed: 12.0,
+            direction: 45.0,
+            turbulence: 0.2,
+            seed,
+        }
+    }
+
+    #[test]
+    fn same_seed_gives_same_surface() {
+        let a = model(&settings(7));
+        let b = model(&settings(7));
+        for t in [0.0, 3.7, 120.0] {
+            assert_eq!(a.surface(&settings(7), t), b.surface(&settings(7), t));
+        }
+    }
+
+    #[test]
+    fn different_seed_gives_different_surface() {
+        let a = model(&settings(7)).surface(&settings(7), 3.7);
+        let b = model(&settings(8)).surface(&settings(8), 3.7);
+        assert_ne!(a, b);
+        // 没有湍流时风场与种子无关
+        let calm = |seed| WindSettings {
+            turbulence: 0.0,
+            ..settings(seed)
+        };
+        assert_eq!(
+            model(&calm(7)).surface(&calm(7), 3.7),
+            model(&calm(8)).surface(&calm(8), 3.7)
+        );
+    }
+
+    #[test]
+    fn spectrum_integrates_to_variance() {
+        let (speed, sigma) = (10.0, 1.5);
+        // 在对数频率上做梯形积分，频率范围足够宽，两端的截断可以忽略
+        let (low, high) = (1e-9f64.ln(), 1e9f64.ln());
+        let steps = 20_000;
+        let dx = (high - low) / steps as f64;
+        let integrand = |x: f64| {
+            let f = x.exp();
+            von_karman_spectrum(f, speed, sigma) * f
+        };
+        let integral = (0..steps)
+            .map(|i| 0.5 * (integrand(low + i as f64 * dx) + integrand(low + (i + 1) as f64 * dx)))
+            .sum::<f64>()
+            * dx;
+        assert!(
+            (integral / (sigma * sigma) - 1.0).abs() < 1e-2,
+            "{} != {}",
+            integral,
+            sigma * sigma
+        );
+        assert_eq!(von_karman_spectrum(1.0, 0.0, sigma), 0.0);
+    }
+}